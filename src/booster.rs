@@ -1,20 +1,42 @@
 //! Provides some boosting algorithms.
 
 mod core;
+mod certificate;
+mod early_stopping;
 
 // ------------------------------------------------
 // Classification
 mod smoothboost;
 mod adaboost;
+mod asymmetric_adaboost;
+mod adaboost_mh;
+mod adaboost_mr;
 mod adaboostv;
+mod anyboost;
+mod assemble;
+mod bagging;
+mod brownboost;
 mod cerlpboost;
+mod deepboost;
+mod ecoc;
+mod epsilonboost;
 mod gradient_boost;
 mod graph_separation_boosting;
+mod logitboost;
 mod madaboost;
+mod milboost;
+mod ngboost;
+mod odmboost;
+mod one_vs_all;
+mod rankboost;
+mod rusboost;
+mod samme;
 // mod branching_program;
 mod lpboost;
 mod mlpboost;
 mod erlpboost;
+mod filterboost;
+mod gameboost;
 mod softboost;
 mod totalboost;
 
@@ -22,6 +44,9 @@ mod totalboost;
 
 /// Booster trait
 pub use self::core::Booster;
+pub use self::core::Resumable;
+pub use self::certificate::Certificate;
+pub use self::early_stopping::{EarlyStopping, ValidationLoss};
 
 // ------------------------------------------------
 // Regression
@@ -32,6 +57,44 @@ pub use self::core::Booster;
 // Empirical Risk Minimization
 pub use self::adaboost::AdaBoost;
 pub use self::madaboost::MadaBoost;
+pub use self::logitboost::LogitBoost;
+pub use self::brownboost::BrownBoost;
+pub use self::filterboost::FilterBoost;
+pub use self::anyboost::{AnyBoost, StepSize};
+pub use self::epsilonboost::EpsilonBoost;
+pub use self::deepboost::DeepBoost;
+
+
+// Bagging
+pub use self::bagging::Bagging;
+
+
+// Cost-sensitive learning
+pub use self::asymmetric_adaboost::AsymmetricAdaBoost;
+
+
+// Imbalanced data
+pub use self::rusboost::{RUSBoost, Resampling};
+
+
+// Semi-supervised learning
+pub use self::assemble::ASSEMBLE;
+
+
+// Multiclass/Multi-label
+pub use self::adaboost_mh::AdaBoostMH;
+pub use self::adaboost_mr::AdaBoostMR;
+pub use self::samme::{SAMME, SAMMER};
+pub use self::one_vs_all::OneVsAll;
+pub use self::ecoc::ECOC;
+
+
+// Ranking
+pub use self::rankboost::RankBoost;
+
+
+// Multi-instance learning
+pub use self::milboost::MILBoost;
 
 
 // Hard Margin Maximization
@@ -45,13 +108,24 @@ pub use self::mlpboost::MLPBoost;
 pub use self::erlpboost::ERLPBoost;
 pub use self::cerlpboost::CERLPBoost;
 pub use self::softboost::SoftBoost;
+pub use self::gameboost::GameBoost;
 
 pub use self::smoothboost::SmoothBoost;
 
 
+// Margin Distribution Optimization
+pub use self::odmboost::ODMBoost;
+
+
 
 
-pub use self::gradient_boost::GBM;
+pub use self::gradient_boost::{
+    GBM,
+    DART,
+    OrderedBoost,
+    RGF,
+};
+pub use self::ngboost::NGBoost;
 pub use self::graph_separation_boosting::GraphSepBoost;
 
 // // ------------------------------------------------
@@ -4,15 +4,21 @@ use rayon::prelude::*;
 
 use crate::{
     Booster,
+    Resumable,
     WeakLearner,
     Classifier,
     WeightedMajority,
     Sample,
 
     common::utils,
+    common::checker,
+    common::selection::{self, Selection},
     research::Research,
+    booster::lpboost::soft_margin_weights,
 };
 
+use rand::prelude::*;
+
 use std::ops::ControlFlow;
 
 
@@ -36,6 +42,66 @@ use std::ops::ControlFlow;
 /// whose empirical loss is less than `ε`
 /// in `O( ln(m) / ε² )` iterations.
 /// 
+/// # Confidence-rated hypotheses
+/// `AdaBoost` accepts the weak hypotheses
+/// with real-valued confidences `h(x) ∈ [-1, 1]`
+/// (see [`Classifier::confidence`]),
+/// not only the ones with hard predictions `h(x) ∈ {-1, +1}`.
+/// Following the paper
+/// [
+/// Improved Boosting Algorithms Using Confidence-rated Predictions
+/// ](https://link.springer.com/article/10.1023/A:1007614523901)
+/// by Robert E. Schapire and Yoram Singer,
+/// `AdaBoost` sets the weight on `h` to
+/// `α = 0.5 ln( (1 + r) / (1 - r) )`,
+/// where `r = Σ_i d_i y_i h(x_i)` is the edge of `h`,
+/// and updates the distribution as
+/// `d_i ← d_i exp(- α y_i h(x_i)) / Z`.
+/// This `α` minimizes the upper bound
+/// `Z ≤ (1 - r) exp(α) / 2 + (1 + r) exp(- α) / 2`
+/// of the normalization factor `Z`,
+/// and coincides with the exact minimizer
+/// for the hypotheses with hard predictions.
+/// 
+/// # Abstaining hypotheses
+/// A weak hypothesis may abstain on some examples
+/// by the confidence `h(x_i) = 0`.
+/// For the hypotheses with predictions in `{-1, 0, +1}`
+/// that abstain on some examples,
+/// `AdaBoost` sets the weight to the exact minimizer of `Z`
+/// given in the same paper by Schapire and Singer:
+/// `α = 0.5 ln( (W+ + ε) / (W- + ε) )`,
+/// where `W+` and `W-` are the weights on which `h` is correct and wrong,
+/// respectively.
+/// The smoothing term `ε = 1 / (2m)` for `m` examples
+/// keeps `α` finite even if `W- = 0`.
+/// The abstained examples keep their weights in the update.
+/// 
+/// # Shrinkage
+/// [`AdaBoost::shrinkage`] sets the shrinkage parameter
+/// (a.k.a. learning rate) `η ∈ (0, 1]`.
+/// `AdaBoost` multiplies the weight `α` on each hypothesis by `η`
+/// and updates the distribution with `η α`.
+/// Since `Z(η α) ≤ 1 - η (1 - Z(α))` by the convexity of `Z`,
+/// the iteration bound grows by the factor `1 / η`
+/// (see [`AdaBoost::max_loop`]).
+/// 
+/// # Fully-corrective postprocess
+/// [`AdaBoost::fully_corrective_postprocess`] re-optimizes
+/// the weights on the hypotheses after the boosting
+/// by the soft margin optimization
+/// (the one [`LPBoost`](crate::booster::LPBoost) solves)
+/// with capping parameter `ν`,
+/// as [`WeightedMajority::refit_weights`] does.
+/// The hypotheses with zero weight are removed,
+/// so that the resulting ensemble is often smaller.
+/// 
+/// # Instance weights
+/// If the training sample has instance weights
+/// (see [`Sample::with_weights`]),
+/// the initial distribution is proportional to them
+/// instead of the uniform one.
+/// 
 /// # Related information
 /// - As some papers proved, 
 /// `AdaBoost` **approximately maximizes the hard margin.**
@@ -98,6 +164,12 @@ pub struct AdaBoost<'a, F> {
     // Tolerance parameter
     tolerance: f64,
 
+    // Shrinkage parameter
+    shrinkage: f64,
+
+    // The capping parameter of the fully-corrective postprocess.
+    corrective_nu: Option<f64>,
+
 
     // Weights on hypotheses in `hypotheses`
     weights: Vec<f64>,
@@ -118,6 +190,16 @@ pub struct AdaBoost<'a, F> {
     // AdaBoost terminates in eary step 
     // if the training set is linearly separable.
     terminated: usize,
+
+    // The rule to pick a hypothesis
+    // from the candidates of the weak learner.
+    selection: Selection,
+
+    // The seed of the random number generator.
+    seed: u64,
+
+    // Random number generator for `selection`.
+    rng: StdRng,
 }
 
 
@@ -134,6 +216,8 @@ impl<'a, F> AdaBoost<'a, F> {
 
             dist: Vec::new(),
             tolerance: 1.0 / (n_sample as f64 + 1.0),
+            shrinkage: 1.0,
+            corrective_nu: None,
 
             weights: Vec::new(),
             hypotheses: Vec::new(),
@@ -141,6 +225,10 @@ impl<'a, F> AdaBoost<'a, F> {
             max_iter: usize::MAX,
             force_quit_at: None,
             terminated: usize::MAX,
+
+            selection: Selection::Best,
+            seed: selection::SEED,
+            rng: StdRng::seed_from_u64(selection::SEED),
         }
     }
 
@@ -151,12 +239,14 @@ impl<'a, F> AdaBoost<'a, F> {
     /// After the `self.max_loop()` iterations,
     /// `AdaBoost` guarantees zero training error in terms of zero-one loss
     /// if the training examples are linearly separable.
+    /// With the shrinkage parameter `η`,
+    /// the bound is multiplied by `1 / η`.
     /// 
     /// Time complexity: `O(1)`.
     pub fn max_loop(&self) -> usize {
         let n_sample = self.sample.shape().0 as f64;
 
-        (n_sample.ln() / self.tolerance.powi(2)) as usize
+        (n_sample.ln() / (self.shrinkage * self.tolerance.powi(2))) as usize
     }
 
 
@@ -171,6 +261,30 @@ impl<'a, F> AdaBoost<'a, F> {
     }
 
 
+    /// Set the rule to pick a hypothesis
+    /// from the candidates of the weak learner.
+    /// By default, `AdaBoost` always takes the best hypothesis,
+    /// i.e., `Selection::Best`.
+    /// See [`Selection`] for other rules.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn selection(mut self, selection: Selection) -> Self {
+        selection.check();
+        self.selection = selection;
+        self
+    }
+
+
+    /// Set the seed of the randomness for the selection rule.
+    /// Default value is `1234`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
     /// Set the tolerance parameter.
     /// `AdaBoostV` terminates immediately
     /// after reaching the specified `tolerance`.
@@ -182,6 +296,52 @@ impl<'a, F> AdaBoost<'a, F> {
     }
 
 
+    /// Set the shrinkage parameter (a.k.a. learning rate)
+    /// `η ∈ (0, 1]`.
+    /// The weight on each hypothesis is multiplied by `η`.
+    /// Default is `1`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn shrinkage(mut self, eta: f64) -> Self {
+        assert!(
+            0f64 < eta && eta <= 1f64,
+            "The shrinkage parameter must be in (0, 1]"
+        );
+        self.shrinkage = eta;
+        self
+    }
+
+
+    /// Re-optimize the weights on the hypotheses after the boosting
+    /// by the soft margin optimization with capping parameter
+    /// `nu ∈ [1, m]`,
+    /// where `m` is the number of training examples.
+    /// By default, `AdaBoost` returns the weights of the boosting.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn fully_corrective_postprocess(mut self, nu: f64) -> Self {
+        checker::check_nu(nu, self.sample.shape().0);
+        self.corrective_nu = Some(nu);
+        self
+    }
+
+
+    /// Returns the weight on the hypothesis of the given margins,
+    /// scaled by the shrinkage parameter.
+    /// For the abstaining hypotheses,
+    /// the weight is the smoothed one by Schapire and Singer.
+    fn weight_of(&self, margins: &[f64], edge: f64) -> f64 {
+        let weight = match utils::abstaining_weights(margins, &self.dist) {
+            Some((correct, wrong, abstain)) if abstain > 0f64 => {
+                let eps = 0.5 / self.sample.shape().0 as f64;
+                ((correct + eps) / (wrong + eps)).ln() / 2.0
+            },
+            _ => ((1.0 + edge) / (1.0 - edge)).ln() / 2.0,
+        };
+        self.shrinkage * weight
+    }
+
+
     /// Returns a weight on the new hypothesis.
     /// `update_params` also updates `self.dist`.
     /// 
@@ -203,7 +363,7 @@ impl<'a, F> AdaBoost<'a, F> {
 
         // Compute the weight on new hypothesis.
         // This is the returned value of this function.
-        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+        let weight = self.weight_of(&margins, edge);
 
 
         // To prevent overflow, take the logarithm.
@@ -261,12 +421,19 @@ impl<F> Booster<F> for AdaBoost<'_, F>
         } else {
             format!("-")
         };
+        let corrective = match self.corrective_nu {
+            Some(nu) => format!("nu = {nu}"),
+            None => "-".to_string(),
+        };
         let info = Vec::from([
             ("# of examples", format!("{}", n_sample)),
             ("# of features", format!("{}", n_feature)),
             ("Tolerance", format!("{}", self.tolerance)),
+            ("Shrinkage", format!("{}", self.shrinkage)),
             ("Max iteration", format!("{}", self.max_loop())),
             ("Force quit", quit),
+            ("Selection", format!("{}", self.selection)),
+            ("Corrective postprocess", corrective),
         ]);
         Some(info)
     }
@@ -280,9 +447,7 @@ impl<F> Booster<F> for AdaBoost<'_, F>
     {
         self.sample.is_valid_binary_instance();
         // Initialize parameters
-        let n_sample = self.sample.shape().0;
-        let uni = 1.0 / n_sample as f64;
-        self.dist = vec![uni; n_sample];
+        self.dist = self.sample.weight_distribution();
 
         self.weights = Vec::new();
         self.hypotheses = Vec::new();
@@ -293,6 +458,8 @@ impl<F> Booster<F> for AdaBoost<'_, F>
         if let Some(it) = self.force_quit_at {
             self.max_iter = it;
         }
+
+        self.rng = StdRng::seed_from_u64(self.seed);
     }
 
 
@@ -309,7 +476,9 @@ impl<F> Booster<F> for AdaBoost<'_, F>
 
 
         // Get a new hypothesis
-        let h = weak_learner.produce(self.sample, &self.dist);
+        let h = self.selection.produce(
+            weak_learner, self.sample, &self.dist, &mut self.rng
+        );
 
 
         // Each element in `margins` is the product of
@@ -345,7 +514,39 @@ impl<F> Booster<F> for AdaBoost<'_, F>
     ) -> Self::Output
         where W: WeakLearner<Hypothesis = F>
     {
-        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+        let Some(nu) = self.corrective_nu else {
+            return self.current_hypothesis();
+        };
+        if self.hypotheses.is_empty() {
+            return self.current_hypothesis();
+        }
+
+
+        // Re-optimize the weights by the soft margin optimization.
+        let margins = self.hypotheses.iter()
+            .map(|h| utils::margins_of_hypothesis(self.sample, h))
+            .collect::<Vec<_>>();
+        let weights = soft_margin_weights(margins, nu);
+        WeightedMajority::from_slices(&weights[..], &self.hypotheses[..])
+    }
+}
+
+
+impl<F> Resumable<F> for AdaBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    fn n_rounds(&self) -> usize {
+        self.hypotheses.len()
+    }
+
+
+    fn resume(&mut self, n_rounds: usize) {
+        assert!(
+            !self.dist.is_empty(),
+            "`AdaBoost::resume` is called before `AdaBoost::run`"
+        );
+        self.max_iter = self.n_rounds() + n_rounds;
+        self.terminated = usize::MAX;
     }
 }
 
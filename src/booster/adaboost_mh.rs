@@ -0,0 +1,12 @@
+//! The AdaBoost.MH algorithm proposed
+//! by Robert E. Schapire and Yoram Singer.
+//! This algorithm is based on the paper: 
+//! [
+//! Improved Boosting Algorithms Using Confidence-rated Predictions
+//! ](https://link.springer.com/article/10.1023/A:1007614523901)
+//! 
+//! AdaBoost.MH is a boosting algorithm for multiclass/multi-label problems
+//! that minimizes the Hamming loss.
+pub mod adaboost_mh_algorithm;
+
+pub use adaboost_mh_algorithm::AdaBoostMH;
@@ -0,0 +1,298 @@
+//! Provides [`AdaBoostMH`] by Schapire & Singer, 1999.
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    LabelRanker,
+    MultiLabelSample,
+
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The AdaBoost.MH algorithm
+/// proposed by Robert E. Schapire and Yoram Singer.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Improved Boosting Algorithms Using Confidence-rated Predictions
+/// ](https://link.springer.com/article/10.1023/A:1007614523901)
+/// by Robert E. Schapire and Yoram Singer.
+///
+/// AdaBoost.MH reduces a multiclass (or multi-label) problem
+/// to the binary problem over the pairs `(i, l)`
+/// of an example `i` and a label `l`,
+/// and minimizes the **Hamming loss**,
+/// the fraction of the pairs whose relevance is mispredicted.
+/// `AdaBoostMH` runs AdaBoost over the expanded binary sample
+/// [`MultiLabelSample::sample`],
+/// so any weak learner for the binary [`Sample`](crate::Sample)
+/// can be used.
+///
+/// For a multiclass problem,
+/// build the [`MultiLabelSample`] by
+/// [`MultiLabelSample::from_multiclass`]
+/// and predict the class by [`LabelRanker::predict_class`].
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // The column `class` has the class labels `0, 1, 2, ...`.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// let sample = MultiLabelSample::from_multiclass(&sample).unwrap();
+///
+/// let mut booster = AdaBoostMH::init(&sample)
+///     .force_quit_at(100);
+///
+/// // The weak learner works on the expanded sample.
+/// let weak_learner = DecisionTreeBuilder::new(sample.sample())
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_classes(&sample);
+/// println!("Hamming loss is: {}", f.hamming_loss(&sample));
+/// ```
+pub struct AdaBoostMH<'a, F> {
+    // Training sample
+    sample: &'a MultiLabelSample,
+
+    // Distribution over the pairs `(i, l)` of the expanded sample.
+    dist: Vec<f64>,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration until AdaBoost.MH guarantees the optimality.
+    max_iter: usize,
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> AdaBoostMH<'a, F> {
+    /// Constructs a new instance of `AdaBoostMH`.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a MultiLabelSample) -> Self {
+        let n_pairs = sample.n_examples() * sample.n_labels();
+
+        Self {
+            sample,
+
+            dist: Vec::new(),
+
+            tolerance: 1.0 / (n_pairs as f64 + 1.0),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: usize::MAX,
+            force_quit_at: None,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Returns the maximum iteration
+    /// of the `AdaBoostMH` to find a combined hypothesis
+    /// whose Hamming loss is at most `tolerance`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_loop(&self) -> usize {
+        let n_pairs = self.sample.n_examples() * self.sample.n_labels();
+        let n_pairs = n_pairs.max(1) as f64;
+
+        (n_pairs.ln() / self.tolerance.powi(2)) as usize
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Update the distribution over the pairs `(i, l)`
+    /// by `D(i, l) ∝ D(i, l) exp(- α y(i, l) h(i, l))`.
+    fn update_dist_mut(&mut self, weight: f64, confidences: &[f64]) {
+        let target = self.sample.sample().target();
+        self.dist.iter_mut()
+            .zip(target)
+            .zip(confidences)
+            .for_each(|((d, y), h)| { *d = d.ln() - weight * y * h; });
+
+        // Normalize the distribution by log-sum-exp.
+        let max = self.dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let normalizer = self.dist.iter()
+            .map(|&d| (d - max).exp())
+            .sum::<f64>()
+            .ln()
+            + max;
+        self.dist.iter_mut()
+            .for_each(|d| { *d = (*d - normalizer).exp(); });
+    }
+}
+
+
+impl<F> Booster<F> for AdaBoostMH<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = LabelRanker<F>;
+
+
+    fn name(&self) -> &str {
+        "AdaBoost.MH"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let n_feature = self.sample.sample().shape().1 - self.sample.n_labels();
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{}", self.sample.n_examples())),
+            ("# of features", format!("{n_feature}")),
+            ("# of labels", format!("{}", self.sample.n_labels())),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_loop())),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let n_pairs = self.sample.n_examples() * self.sample.n_labels();
+        self.dist = vec![1f64 / n_pairs as f64; n_pairs];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.max_iter = self.max_loop();
+        if let Some(it) = self.force_quit_at {
+            self.max_iter = it;
+        }
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+        let expanded = self.sample.sample();
+        let h = weak_learner.produce(expanded, &self.dist);
+        let confidences = h.confidence_all(expanded);
+
+        let edge = expanded.target()
+            .iter()
+            .zip(&confidences)
+            .zip(&self.dist)
+            .map(|((y, h), d)| d * y * h)
+            .sum::<f64>();
+
+
+        // If `h` predicts all the pairs correctly,
+        // use it as the combined hypothesis.
+        if edge.abs() >= 1.0 {
+            self.terminated = iteration;
+            self.weights = vec![edge.signum()];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+        self.update_dist_mut(weight, &confidences);
+
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for AdaBoostMH<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = LabelRanker<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        let f = WeightedMajority::from_slices(
+            &self.weights[..], &self.hypotheses[..]
+        );
+        LabelRanker::new(f, self.sample.n_labels())
+    }
+}
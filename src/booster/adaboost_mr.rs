@@ -0,0 +1,12 @@
+//! The AdaBoost.MR algorithm proposed
+//! by Robert E. Schapire and Yoram Singer.
+//! This algorithm is based on the paper: 
+//! [
+//! Improved Boosting Algorithms Using Confidence-rated Predictions
+//! ](https://link.springer.com/article/10.1023/A:1007614523901)
+//! 
+//! AdaBoost.MR is a boosting algorithm for multi-label problems
+//! that minimizes the ranking loss.
+pub mod adaboost_mr_algorithm;
+
+pub use adaboost_mr_algorithm::AdaBoostMR;
@@ -0,0 +1,374 @@
+//! Provides [`AdaBoostMR`] by Schapire & Singer, 1999.
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    LabelRanker,
+    MultiLabelSample,
+
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The AdaBoost.MR algorithm
+/// proposed by Robert E. Schapire and Yoram Singer.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Improved Boosting Algorithms Using Confidence-rated Predictions
+/// ](https://link.springer.com/article/10.1023/A:1007614523901)
+/// by Robert E. Schapire and Yoram Singer.
+///
+/// AdaBoost.MR is a boosting algorithm for multi-label problems
+/// that minimizes the **ranking loss**,
+/// the fraction of the pairs of an irrelevant label `l0`
+/// and a relevant label `l1` such that `l0` is ranked above `l1`.
+/// `AdaBoostMR` maintains a distribution over the triples `(i, l0, l1)`
+/// and passes the induced distribution over
+/// the pairs `(i, l)` to the weak learner.
+/// The weak learner works on the expanded binary sample
+/// [`MultiLabelSample::sample`].
+///
+/// The examples whose labels are all relevant or all irrelevant
+/// have no label pairs, so they do not affect the training.
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use polars::prelude::*;
+///
+/// # let data = DataFrame::default();
+/// # let targets = DataFrame::default();
+/// let sample = MultiLabelSample::from_dataframe(data, targets).unwrap();
+///
+/// let mut booster = AdaBoostMR::init(&sample)
+///     .force_quit_at(100);
+///
+/// // The weak learner works on the expanded sample.
+/// let weak_learner = DecisionTreeBuilder::new(sample.sample())
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// println!("Ranking loss is: {}", f.ranking_loss(&sample));
+/// ```
+pub struct AdaBoostMR<'a, F> {
+    // Training sample
+    sample: &'a MultiLabelSample,
+
+    // Distribution over the pairs `(i, l)` of the expanded sample.
+    dist: Vec<f64>,
+
+    // Logarithm of the distribution over the triples `(i, l0, l1)`.
+    // `log_pair_dist[i]` is the row-major matrix of size
+    // `irrelevant[i].len() x relevant[i].len()`.
+    log_pair_dist: Vec<Vec<f64>>,
+
+    // Relevant/irrelevant labels of each example.
+    relevant: Vec<Vec<usize>>,
+    irrelevant: Vec<Vec<usize>>,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration until AdaBoost.MR guarantees the optimality.
+    max_iter: usize,
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> AdaBoostMR<'a, F> {
+    /// Constructs a new instance of `AdaBoostMR`.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a MultiLabelSample) -> Self {
+        let n_examples = sample.n_examples();
+
+        Self {
+            sample,
+
+            dist: Vec::new(),
+            log_pair_dist: Vec::new(),
+            relevant: Vec::new(),
+            irrelevant: Vec::new(),
+
+            tolerance: 1.0 / (n_examples as f64 + 1.0),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: usize::MAX,
+            force_quit_at: None,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Returns the number of the triples `(i, l0, l1)`.
+    fn n_pairs(&self) -> usize {
+        (0..self.sample.n_examples())
+            .map(|i| {
+                let n_relevant = self.sample.relevant_labels(i).len();
+                n_relevant * (self.sample.n_labels() - n_relevant)
+            })
+            .sum::<usize>()
+    }
+
+
+    /// Returns the maximum iteration
+    /// of the `AdaBoostMR` to find a combined hypothesis
+    /// whose ranking loss is at most `tolerance`.
+    ///
+    /// Time complexity: `O(m k)`,
+    /// where `m` is the number of examples
+    /// and `k` is the number of labels.
+    pub fn max_loop(&self) -> usize {
+        let n_pairs = self.n_pairs().max(1) as f64;
+
+        (n_pairs.ln() / self.tolerance.powi(2)) as usize
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Compute the distribution over the pairs `(i, l)`
+    /// from the one over the triples `(i, l0, l1)`.
+    /// Each triple gives the half of its mass to `(i, l0)`
+    /// and the rest to `(i, l1)`.
+    fn update_dist_mut(&mut self) {
+        let n_labels = self.sample.n_labels();
+        self.dist.iter_mut().for_each(|d| { *d = 0f64; });
+        for (i, log_dist) in self.log_pair_dist.iter().enumerate() {
+            let n_relevant = self.relevant[i].len();
+            for (k, &l0) in self.irrelevant[i].iter().enumerate() {
+                for (j, &l1) in self.relevant[i].iter().enumerate() {
+                    let d = log_dist[k * n_relevant + j].exp() / 2f64;
+                    self.dist[i * n_labels + l0] += d;
+                    self.dist[i * n_labels + l1] += d;
+                }
+            }
+        }
+    }
+
+
+    /// Update the distribution over the triples `(i, l0, l1)`
+    /// by `D(i, l0, l1) ∝ D(i, l0, l1) exp(α (h(i, l0) - h(i, l1)) / 2)`.
+    fn update_pair_dist_mut(&mut self, weight: f64, confidences: &[f64]) {
+        let n_labels = self.sample.n_labels();
+        for (i, log_dist) in self.log_pair_dist.iter_mut().enumerate() {
+            let n_relevant = self.relevant[i].len();
+            for (k, &l0) in self.irrelevant[i].iter().enumerate() {
+                let h0 = confidences[i * n_labels + l0];
+                for (j, &l1) in self.relevant[i].iter().enumerate() {
+                    let h1 = confidences[i * n_labels + l1];
+                    log_dist[k * n_relevant + j] += weight * (h0 - h1) / 2f64;
+                }
+            }
+        }
+
+        // Normalize the distribution by log-sum-exp.
+        let max = self.log_pair_dist.iter()
+            .flatten()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let normalizer = self.log_pair_dist.iter()
+            .flatten()
+            .map(|&d| (d - max).exp())
+            .sum::<f64>()
+            .ln()
+            + max;
+        self.log_pair_dist.iter_mut()
+            .flatten()
+            .for_each(|d| { *d -= normalizer; });
+    }
+}
+
+
+impl<F> Booster<F> for AdaBoostMR<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = LabelRanker<F>;
+
+
+    fn name(&self) -> &str {
+        "AdaBoost.MR"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let n_feature = self.sample.sample().shape().1 - self.sample.n_labels();
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{}", self.sample.n_examples())),
+            ("# of features", format!("{n_feature}")),
+            ("# of labels", format!("{}", self.sample.n_labels())),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_loop())),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let n_examples = self.sample.n_examples();
+        let n_labels = self.sample.n_labels();
+
+        self.relevant = (0..n_examples)
+            .map(|i| self.sample.relevant_labels(i))
+            .collect();
+        self.irrelevant = (0..n_examples)
+            .map(|i| self.sample.irrelevant_labels(i))
+            .collect();
+
+        // `D(i, l0, l1) = 1 / (m |Y_i| |Y_i^c|)`,
+        // where `m` is the number of examples that have label pairs.
+        let n_active = (0..n_examples)
+            .filter(|&i| {
+                !self.relevant[i].is_empty() && !self.irrelevant[i].is_empty()
+            })
+            .count();
+        assert!(
+            n_active > 0,
+            "Every example has all or no relevant labels"
+        );
+        self.log_pair_dist = (0..n_examples)
+            .map(|i| {
+                let size = self.relevant[i].len() * self.irrelevant[i].len();
+                let log_d = -((n_active * size.max(1)) as f64).ln();
+                vec![log_d; size]
+            })
+            .collect();
+        self.dist = vec![0f64; n_examples * n_labels];
+        self.update_dist_mut();
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.max_iter = self.max_loop();
+        if let Some(it) = self.force_quit_at {
+            self.max_iter = it;
+        }
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+        let expanded = self.sample.sample();
+        let h = weak_learner.produce(expanded, &self.dist);
+        let confidences = h.confidence_all(expanded);
+
+        // `edge` equals to `Σ D(i, l0, l1) (h(i, l1) - h(i, l0)) / 2`.
+        let edge = expanded.target()
+            .iter()
+            .zip(&confidences)
+            .zip(&self.dist)
+            .map(|((y, h), d)| d * y * h)
+            .sum::<f64>();
+
+
+        // If `h` ranks all the pairs correctly,
+        // use it as the combined hypothesis.
+        if edge.abs() >= 1.0 {
+            self.terminated = iteration;
+            self.weights = vec![edge.signum()];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        let weight = ((1.0 + edge) / (1.0 - edge)).ln() / 2.0;
+        self.update_pair_dist_mut(weight, &confidences);
+        self.update_dist_mut();
+
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for AdaBoostMR<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = LabelRanker<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        let f = WeightedMajority::from_slices(
+            &self.weights[..], &self.hypotheses[..]
+        );
+        LabelRanker::new(f, self.sample.n_labels())
+    }
+}
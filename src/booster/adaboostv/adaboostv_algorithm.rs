@@ -55,9 +55,22 @@ use std::ops::ControlFlow;
 /// the hard-margin optimization problem
 /// in `O( ln(m) / ε² )` iterations.
 /// 
+/// `AdaBoostV` keeps the smallest edge `γ̂` of the hypotheses so far
+/// and adaptively sets the target margin `ρ = γ̂ - ε`,
+/// where `ε` is the tolerance parameter.
+/// Since the optimal margin is at most `γ̂`,
+/// the margin of the resulting combined hypothesis,
+/// [`AdaBoostV::margin`],
+/// is compared with [`AdaBoostV::edge_estimate`]
+/// to certify the optimality.
+/// 
 /// # Related information
 /// 
 /// - `AdaBoostV` does not use the weak learnability parameter.
+/// - The margin is the soft margin objective with `ν = 1`
+///   (see [`HardMarginObjective`](crate::HardMarginObjective)),
+///   so that it is comparable with
+///   the objective value of [`LPBoost`](crate::booster::LPBoost).
 /// 
 /// # Example
 /// The following code shows a small example 
@@ -135,6 +148,10 @@ pub struct AdaBoostV<'a, F> {
     force_quit_at: Option<usize>,
 
     terminated: usize,
+
+    /// The minimum margin of the combined hypothesis
+    /// computed at the end of the boosting.
+    margin: Option<f64>,
 }
 
 
@@ -160,6 +177,8 @@ impl<'a, F> AdaBoostV<'a, F> {
             max_iter: usize::MAX,
             terminated: usize::MAX,
             force_quit_at: None,
+
+            margin: None,
         }
     }
 
@@ -215,6 +234,48 @@ impl<'a, F> AdaBoostV<'a, F> {
     }
 
 
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the current target margin `ρ = γ̂ - ε`.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn rho(&self) -> f64 {
+        self.rho
+    }
+
+
+    /// Returns the smallest edge `γ̂`
+    /// of the hypotheses obtained so far.
+    /// This value is an upper bound of the optimal margin.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn edge_estimate(&self) -> f64 {
+        self.gamma
+    }
+
+
+    /// Returns the minimum margin
+    /// `min_i y_i Σ_h w_h h(x_i)`
+    /// of the combined hypothesis returned by the last run.
+    /// This method returns `None` before the postprocess step.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn margin(&self) -> Option<f64> {
+        self.margin
+    }
+
+
     /// Returns a weight on the new hypothesis.
     /// `update_params` also updates `self.dist`.
     /// 
@@ -317,6 +378,7 @@ impl<F> Booster<F> for AdaBoostV<'_, F>
 
         self.rho = 1.0;
         self.gamma = 1.0;
+        self.margin = None;
 
 
         self.weights = Vec::new();
@@ -328,6 +390,7 @@ impl<F> Booster<F> for AdaBoostV<'_, F>
         if let Some(it) = self.force_quit_at {
             self.max_iter = it;
         }
+        self.terminated = self.max_iter;
     }
 
 
@@ -379,7 +442,17 @@ impl<F> Booster<F> for AdaBoostV<'_, F>
     ) -> Self::Output
         where W: WeakLearner<Hypothesis = F>
     {
-        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+        let f = self.current_hypothesis();
+
+        let target = self.sample.target();
+        let margin = f.confidence_all(self.sample)
+            .into_iter()
+            .zip(target)
+            .map(|(fx, y)| y * fx)
+            .fold(f64::MAX, f64::min);
+        self.margin = Some(margin);
+
+        f
     }
 }
 
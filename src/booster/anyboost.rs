@@ -0,0 +1,12 @@
+//! The AnyBoost algorithm proposed by
+//! Llew Mason, Jonathan Baxter, Peter Bartlett, and Marcus Frean.
+//! This algorithm is based on the paper: 
+//! [
+//! Boosting Algorithms as Gradient Descent
+//! ](https://proceedings.neurips.cc/paper/1999/hash/96a93ba89a5b5c6c226e49b88973f46e-Abstract.html)
+//! 
+//! AnyBoost is a functional gradient descent framework
+//! that works with any differentiable loss function.
+pub mod anyboost_algorithm;
+
+pub use anyboost_algorithm::{AnyBoost, StepSize};
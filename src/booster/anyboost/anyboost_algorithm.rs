@@ -0,0 +1,448 @@
+//! Provides [`AnyBoost`] by Mason et al., 1999.
+use rayon::prelude::*;
+
+use crate::{
+    common::loss_functions::LossFunction,
+    Sample,
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+
+    common::utils,
+    research::Research,
+};
+
+use std::fmt;
+use std::ops::ControlFlow;
+
+
+/// The maximum number of iterations for the step size search.
+const MAX_SEARCH: usize = 100;
+/// The constant of the sufficient decrease condition (Armijo rule).
+const ARMIJO_C: f64 = 1e-4;
+/// The shrinkage factor of the backtracking line search.
+const ARMIJO_BETA: f64 = 0.5;
+
+
+/// Step size rules for [`AnyBoost`].
+#[derive(Clone, Copy)]
+pub enum StepSize {
+    /// Fixed step size `α > 0`.
+    Fixed(f64),
+
+    /// Exact line search.
+    /// Adopt the step size that minimizes the loss
+    /// along the new hypothesis.
+    LineSearch,
+
+    /// Backtracking line search with the Armijo rule.
+    /// Starting from `α = 1`, shrink `α` by half
+    /// until the loss decreases sufficiently.
+    Armijo,
+}
+
+
+impl fmt::Display for StepSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(alpha) => write!(f, "Fixed ({alpha})"),
+            Self::LineSearch => write!(f, "Line search"),
+            Self::Armijo => write!(f, "Armijo rule"),
+        }
+    }
+}
+
+
+/// The AnyBoost algorithm proposed by
+/// Llew Mason, Jonathan Baxter, Peter Bartlett, and Marcus Frean.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Boosting Algorithms as Gradient Descent
+/// ](https://proceedings.neurips.cc/paper/1999/hash/96a93ba89a5b5c6c226e49b88973f46e-Abstract.html)
+/// by Llew Mason, Jonathan Baxter, Peter Bartlett, and Marcus Frean.
+///
+/// AnyBoost minimizes the empirical loss
+/// `L(F) = (1/m) Σ_i ℓ(F(x_i), y_i)`
+/// of the combined hypothesis `F(x) = Σ_t α_t h_t (x)`
+/// by the gradient descent in the function space,
+/// where `ℓ` is any differentiable loss function
+/// that implements [`LossFunction`].
+/// At each round, `AnyBoost` computes the gradient
+/// `g_i = ∂ℓ(F(x_i), y_i) / ∂F(x_i)`
+/// and passes the distribution
+/// `d_i ∝ max(0, - y_i g_i)`
+/// to the weak learner,
+/// so that the weak learner that maximizes the edge
+/// also maximizes the inner product `- Σ_i g_i h(x_i)`
+/// if the loss is a margin loss.
+/// Then, the step size `α_t` is chosen by the [`StepSize`] rule.
+///
+/// `AnyBoost` terminates
+/// if the new hypothesis is not a descent direction,
+/// if the decrease of the training loss
+/// is less than the tolerance parameter,
+/// or after `max_iter` iterations.
+///
+/// Note that [`LossFunction::best_coefficient`] is not used.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Minimize the logistic loss with the Armijo rule.
+/// let mut booster = AnyBoost::init_with_loss(&sample, GBMLoss::LogLoss)
+///     .step_size(StepSize::Armijo)
+///     .max_iter(200);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct AnyBoost<'a, F, L> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Some struct that implements `LossFunction` trait
+    loss: L,
+
+    // Step size rule
+    step_size: StepSize,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration of AnyBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+    // The current scores `F(x)` on the training examples.
+    scores: Vec<f64>,
+
+    // The current training loss.
+    objval: f64,
+}
+
+
+impl<'a, F, L> AnyBoost<'a, F, L> {
+    /// Constructs a new instance of `AnyBoost`
+    /// that minimizes the given loss function.
+    /// By default, `AnyBoost` uses [`StepSize::LineSearch`].
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    pub fn init_with_loss(sample: &'a Sample, loss: L) -> Self {
+        let n_sample = sample.shape().0;
+
+        Self {
+            sample,
+
+            loss,
+            step_size: StepSize::LineSearch,
+            tolerance: 1e-6,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            scores: vec![0f64; n_sample],
+            objval: f64::MAX,
+        }
+    }
+
+
+    /// Set the loss function.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn loss(mut self, loss: L) -> Self {
+        self.loss = loss;
+        self
+    }
+
+
+    /// Set the step size rule.
+    /// See [`StepSize`].
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn step_size(mut self, step_size: StepSize) -> Self {
+        if let StepSize::Fixed(alpha) = step_size {
+            assert!(alpha > 0f64, "The step size must be positive");
+        }
+        self.step_size = step_size;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `AnyBoost` terminates if the decrease of
+    /// the training loss is less than `tolerance`.
+    /// Default is `1e-6`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance >= 0f64, "The tolerance must be non-negative");
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the current training loss.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn objective_value(&self) -> f64 {
+        self.objval
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+}
+
+
+impl<F, L> AnyBoost<'_, F, L>
+    where L: LossFunction,
+{
+    /// Returns the training loss at `F + α h`,
+    /// where `predictions` is the predictions of `h`.
+    fn loss_at(&self, predictions: &[f64], alpha: f64) -> f64 {
+        let scores = self.scores.iter()
+            .zip(predictions)
+            .map(|(s, p)| s + alpha * p)
+            .collect::<Vec<_>>();
+        self.loss.eval(&scores[..], self.sample.target())
+    }
+
+
+    /// Returns the directional derivative of the training loss
+    /// at `F + α h` along `h`,
+    /// where `predictions` is the predictions of `h`.
+    fn derivative_at(&self, predictions: &[f64], alpha: f64) -> f64 {
+        let scores = self.scores.iter()
+            .zip(predictions)
+            .map(|(s, p)| s + alpha * p)
+            .collect::<Vec<_>>();
+        let n_sample = scores.len() as f64;
+        self.loss.gradient(&scores[..], self.sample.target())
+            .into_iter()
+            .zip(predictions)
+            .map(|(g, p)| g * p)
+            .sum::<f64>()
+            / n_sample
+    }
+
+
+    /// Returns the step size along `h`
+    /// with the negative directional derivative `slope < 0`.
+    fn step(&self, predictions: &[f64], slope: f64) -> f64 {
+        match self.step_size {
+            StepSize::Fixed(alpha) => alpha,
+            StepSize::LineSearch => {
+                // Find `ub` such that the derivative is non-negative,
+                // and then bisect `[lb, ub]`.
+                let mut lb = 0f64;
+                let mut ub = 1f64;
+                for _ in 0..MAX_SEARCH {
+                    if self.derivative_at(predictions, ub) >= 0f64 {
+                        break;
+                    }
+                    lb = ub;
+                    ub *= 2f64;
+                }
+                for _ in 0..MAX_SEARCH {
+                    let mid = (lb + ub) / 2f64;
+                    if self.derivative_at(predictions, mid) < 0f64 {
+                        lb = mid;
+                    } else {
+                        ub = mid;
+                    }
+                    if ub - lb < 1e-9 {
+                        break;
+                    }
+                }
+                (lb + ub) / 2f64
+            },
+            StepSize::Armijo => {
+                let mut alpha = 1f64;
+                for _ in 0..MAX_SEARCH {
+                    let loss = self.loss_at(predictions, alpha);
+                    if loss <= self.objval + ARMIJO_C * alpha * slope {
+                        break;
+                    }
+                    alpha *= ARMIJO_BETA;
+                }
+                alpha
+            },
+        }
+    }
+}
+
+
+impl<F, L> Booster<F> for AnyBoost<'_, F, L>
+    where F: Classifier + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "AnyBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Loss", self.loss.name().to_string()),
+            ("Step size", format!("{}", self.step_size)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+        self.weights = Vec::with_capacity(self.max_iter);
+        self.hypotheses = Vec::with_capacity(self.max_iter);
+
+        self.terminated = self.max_iter;
+        self.scores = vec![0f64; n_sample];
+        self.objval = self.loss.eval(&self.scores[..], self.sample.target());
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // The distribution is proportional to
+        // the negative gradient along the labels.
+        let target = self.sample.target();
+        let mut dist = self.loss.gradient(&self.scores[..], target)
+            .into_iter()
+            .zip(target)
+            .map(|(g, y)| (- y * g).max(0f64))
+            .collect::<Vec<_>>();
+
+        // If the gradient vanishes, `F` is optimal.
+        if dist.iter().all(|d| *d == 0f64) {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+        utils::normalize(&mut dist[..]);
+
+
+        let h = weak_learner.produce(self.sample, &dist[..]);
+        let predictions = h.confidence_all(self.sample);
+
+
+        // If `h` is not a descent direction,
+        // AnyBoost cannot make progress.
+        let slope = self.derivative_at(&predictions[..], 0f64);
+        if slope >= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let alpha = self.step(&predictions[..], slope);
+        self.scores.par_iter_mut()
+            .zip(predictions)
+            .for_each(|(s, p)| { *s += alpha * p; });
+
+        self.weights.push(alpha);
+        self.hypotheses.push(h);
+
+
+        let objval = self.loss.eval(&self.scores[..], target);
+        let decrease = self.objval - objval;
+        self.objval = objval;
+        if decrease < self.tolerance {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H, L> Research for AnyBoost<'_, H, L>
+    where H: Classifier + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
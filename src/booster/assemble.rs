@@ -0,0 +1,13 @@
+//! A semi-supervised boosting algorithm
+//! that exploits the unlabeled examples.
+//! See also the paper: 
+//! [
+//! Exploiting Unlabeled Data in Ensemble Methods
+//! ](https://doi.org/10.1145/775047.775129)
+//! by Kristin P. Bennett, Ayhan Demiriz, and Richard Maclin.
+//! 
+//! ASSEMBLE is a boosting algorithm for binary classification
+//! on the samples with both labeled and unlabeled examples.
+pub mod assemble_algorithm;
+
+pub use assemble_algorithm::ASSEMBLE;
@@ -0,0 +1,343 @@
+//! Provides [`ASSEMBLE`] by Bennett et al., 2002.
+use rayon::prelude::*;
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The ASSEMBLE algorithm
+/// proposed by Kristin P. Bennett, Ayhan Demiriz, and Richard Maclin.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Exploiting Unlabeled Data in Ensemble Methods
+/// ](https://doi.org/10.1145/775047.775129)
+/// by Kristin P. Bennett, Ayhan Demiriz, and Richard Maclin.
+///
+/// `ASSEMBLE` is a semi-supervised variant of
+/// [`AdaBoost`](crate::booster::AdaBoost).
+/// The training sample may contain unlabeled examples,
+/// whose target values are `f64::NAN`
+/// (see [`Sample::with_unlabeled`]).
+/// `ASSEMBLE` assigns the *pseudo-label* `sign F(x)`
+/// to each unlabeled example `x`,
+/// where `F(x) = Σ_t α_t h_t (x)` is the current combined hypothesis,
+/// and minimizes the exponential loss
+/// ```txt
+/// (1 - w) / ℓ Σ_{labeled} exp(- y_i F(x_i))
+///     + w / u Σ_{unlabeled} exp(- |F(x_i)|),
+/// ```
+/// where `ℓ` and `u` are the numbers of
+/// the labeled and unlabeled examples, respectively,
+/// and `w ∈ [0, 1)` is the total weight on the unlabeled examples
+/// (see [`ASSEMBLE::unlabeled_weight`]).
+/// Thus, the unlabeled examples are down-weighted by `w`,
+/// and the ones with small margins get large weights.
+///
+/// At each round, `ASSEMBLE` passes the pseudo-labeled sample
+/// and the distribution proportional to the above terms
+/// to the weak learner.
+/// Since the pseudo-labels are not available at the first round,
+/// the first hypothesis is trained on the labeled examples only.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// // The rows with the empty label are unlabeled.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = ASSEMBLE::init(&sample)
+///     .unlabeled_weight(0.1)
+///     .max_iter(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct ASSEMBLE<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The training sample with the pseudo-labels.
+    pseudo_sample: Sample,
+
+    // The total weight on the unlabeled examples.
+    unlabeled_weight: f64,
+
+    // Distribution on sample.
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // The current scores `Σ_t α_t h_t (x_i)`.
+    scores: Vec<f64>,
+
+    // Max iteration of ASSEMBLE.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> ASSEMBLE<'a, F> {
+    /// Constructs a new instance of `ASSEMBLE`.
+    ///
+    /// Time complexity: `O(mn)`,
+    /// where `m` is the number of training examples
+    /// and `n` is the number of features.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            pseudo_sample: sample.clone(),
+
+            unlabeled_weight: 0.1,
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+            scores: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the total weight `w ∈ [0, 1)` on the unlabeled examples.
+    /// The labeled examples have the total weight `1 - w`.
+    /// If `w = 0`, `ASSEMBLE` ignores the unlabeled examples.
+    /// Default is `0.1`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn unlabeled_weight(mut self, weight: f64) -> Self {
+        assert!(
+            (0f64..1f64).contains(&weight),
+            "The weight on the unlabeled examples must be in [0, 1)"
+        );
+        self.unlabeled_weight = weight;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Updates the pseudo-labels and the distribution
+    /// from the current scores.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    fn update(&mut self) {
+        let n_sample = self.scores.len();
+        let n_unlabeled = self.sample.unlabeled_rows().len();
+        let n_labeled = n_sample - n_unlabeled;
+
+
+        // The unlabeled examples are ignored
+        // until the pseudo-labels are available.
+        let labeled = ((1f64 - self.unlabeled_weight) / n_labeled as f64).ln();
+        let unlabeled = if self.hypotheses.is_empty() {
+            f64::NEG_INFINITY
+        } else {
+            (self.unlabeled_weight / n_unlabeled as f64).ln()
+        };
+
+
+        // To prevent overflow, take the logarithm.
+        let sample = self.sample;
+        self.pseudo_sample.target_mut()
+            .par_iter_mut()
+            .zip(&mut self.dist[..])
+            .zip(&self.scores[..])
+            .enumerate()
+            .for_each(|(i, ((y, d), s))| {
+                if sample.is_unlabeled(i) {
+                    *y = if *s >= 0f64 { 1f64 } else { -1f64 };
+                    *d = unlabeled - s.abs();
+                } else {
+                    *d = labeled - *y * s;
+                }
+            });
+        let max = self.dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        self.dist.par_iter_mut()
+            .for_each(|d| { *d = (*d - max).exp(); });
+        utils::normalize(&mut self.dist[..]);
+    }
+}
+
+
+impl<F> Booster<F> for ASSEMBLE<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "ASSEMBLE"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let n_unlabeled = self.sample.unlabeled_rows().len();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of unlabeled examples", format!("{n_unlabeled}")),
+            ("# of features", format!("{n_feature}")),
+            ("Unlabeled weight", format!("{}", self.unlabeled_weight)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let labeled = self.sample.labeled_rows();
+        assert!(
+            !labeled.is_empty(),
+            "The sample has no labeled example"
+        );
+        let target = self.sample.target();
+        assert!(
+            labeled.iter().all(|&i| target[i] == 1f64 || target[i] == -1f64),
+            "The labels must be +1 or -1"
+        );
+        let n_sample = self.sample.shape().0;
+
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+        self.scores = vec![0f64; n_sample];
+        self.dist = vec![0f64; n_sample];
+        self.update();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        let h = weak_learner.produce(&self.pseudo_sample, &self.dist[..]);
+        let margins = utils::margins_of_hypothesis(&self.pseudo_sample, &h);
+
+
+        let edge = utils::inner_product(&margins[..], &self.dist[..]);
+
+
+        // If `h` has no advantage on the current distribution,
+        // `ASSEMBLE` cannot make progress.
+        if edge <= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        // If the edge reaches `1`,
+        // `h` classifies all the examples with non-zero weights correctly.
+        if edge >= 1f64 {
+            self.terminated = iteration;
+            self.weights = vec![1f64];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        let alpha = 0.5 * ((1f64 + edge) / (1f64 - edge)).ln();
+
+
+        self.scores.par_iter_mut()
+            .zip(margins)
+            .zip(self.pseudo_sample.target())
+            .for_each(|((s, m), y)| { *s += alpha * m * y; });
+        self.weights.push(alpha);
+        self.hypotheses.push(h);
+
+        self.update();
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for ASSEMBLE<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,13 @@
+//! A cost-sensitive variant of AdaBoost,
+//! which minimizes the cost-weighted exponential loss.
+//! See also the paper: 
+//! [
+//! Cost-sensitive boosting for classification of imbalanced data
+//! ](https://doi.org/10.1016/j.patcog.2007.04.009)
+//! by Yanmin Sun, Mohamed S. Kamel, Andrew K. C. Wong, and Yang Wang.
+//! 
+//! AsymmetricAdaBoost is a boosting algorithm for binary classification
+//! with class-dependent or example-dependent misclassification costs.
+pub mod asymmetric_adaboost_algorithm;
+
+pub use asymmetric_adaboost_algorithm::AsymmetricAdaBoost;
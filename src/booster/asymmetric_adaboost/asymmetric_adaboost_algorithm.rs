@@ -0,0 +1,412 @@
+//! Provides [`AsymmetricAdaBoost`], a cost-sensitive variant of AdaBoost.
+use rayon::prelude::*;
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    ThresholdClassifier,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// A cost-sensitive variant of AdaBoost
+/// for binary classification
+/// where misclassifying the `i`-th example costs `c_i ≥ 0`.
+///
+/// See also the paper:
+///
+/// [
+/// Cost-sensitive boosting for classification of imbalanced data
+/// ](https://doi.org/10.1016/j.patcog.2007.04.009)
+/// by Yanmin Sun, Mohamed S. Kamel, Andrew K. C. Wong, and Yang Wang.
+///
+/// The costs are given per example by [`AsymmetricAdaBoost::costs`]
+/// or per class by [`AsymmetricAdaBoost::class_costs`].
+/// `AsymmetricAdaBoost` minimizes the cost-weighted exponential loss
+/// `Σ_i c_i exp(- y_i F(x_i))`,
+/// which upper-bounds the total misclassification cost of `F`.
+/// To do so, `AsymmetricAdaBoost` starts from the distribution
+/// `d_i ∝ c_i` instead of the uniform one
+/// and updates it in the same way as
+/// [`AdaBoost`](crate::booster::AdaBoost):
+/// `d_i ← d_i exp(- α y_i h(x_i))`,
+/// where `α = 0.5 ln( (1 + r) / (1 - r) )`
+/// and `r = Σ_i d_i y_i h(x_i)` is the edge of `h`.
+/// Thus, the costly examples keep larger weights.
+///
+/// After boosting, `AsymmetricAdaBoost` chooses the decision threshold `θ`
+/// of the combined hypothesis `f`
+/// that minimizes the total misclassification cost
+/// on the training examples
+/// (see [`ThresholdClassifier`]).
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Misclassifying a positive example costs 5 times more
+/// // than misclassifying a negative one.
+/// let mut booster = AsymmetricAdaBoost::init(&sample)
+///     .class_costs(5.0, 1.0)
+///     .max_iter(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct AsymmetricAdaBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The misclassification costs given by the user.
+    costs: Costs,
+
+    // Distribution on sample.
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // The current scores `Σ_t α_t h_t (x_i)`.
+    scores: Vec<f64>,
+
+    // Max iteration of AsymmetricAdaBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+/// The misclassification costs.
+enum Costs {
+    // The same cost for all the examples.
+    Uniform,
+    // The costs of misclassifying a positive/negative example.
+    Class(f64, f64),
+    // The cost of misclassifying each example.
+    Example(Vec<f64>),
+}
+
+
+impl<'a, F> AsymmetricAdaBoost<'a, F> {
+    /// Constructs a new instance of `AsymmetricAdaBoost`.
+    /// By default, all the examples have the same cost.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            costs: Costs::Uniform,
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+            scores: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the costs of misclassifying
+    /// a positive example and a negative example.
+    /// Both costs must be non-negative, and at least one is positive.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn class_costs(mut self, positive: f64, negative: f64) -> Self {
+        assert!(
+            positive >= 0f64 && negative >= 0f64,
+            "The misclassification costs must be non-negative"
+        );
+        assert!(
+            positive > 0f64 || negative > 0f64,
+            "At least one misclassification cost must be positive"
+        );
+        self.costs = Costs::Class(positive, negative);
+        self
+    }
+
+
+    /// Set the cost of misclassifying each example.
+    /// `costs[i]` is the cost of the `i`-th example.
+    /// All the costs must be non-negative, and at least one is positive.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    pub fn costs(mut self, costs: Vec<f64>) -> Self {
+        let n_sample = self.sample.shape().0;
+        assert_eq!(
+            costs.len(), n_sample,
+            "The number of costs must be the same as the number of examples"
+        );
+        assert!(
+            costs.iter().all(|c| *c >= 0f64),
+            "The misclassification costs must be non-negative"
+        );
+        assert!(
+            costs.iter().any(|c| *c > 0f64),
+            "At least one misclassification cost must be positive"
+        );
+        self.costs = Costs::Example(costs);
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the cost of misclassifying each example.
+    fn raw_costs(&self) -> Vec<f64> {
+        let target = self.sample.target();
+        match &self.costs {
+            Costs::Uniform => vec![1f64; target.len()],
+            Costs::Class(positive, negative) => {
+                target.iter()
+                    .map(|y| if *y > 0f64 { *positive } else { *negative })
+                    .collect()
+            },
+            Costs::Example(costs) => costs.clone(),
+        }
+    }
+
+
+    /// Returns the decision threshold `θ` on the confidence
+    /// `f(x) = Σ_t α_t h_t (x) / Σ_t α_t`
+    /// that minimizes the total misclassification cost
+    /// on the training examples.
+    /// The ties are broken by the smallest `|θ|`.
+    ///
+    /// Time complexity: `O(m log m)`,
+    /// where `m` is the number of training examples.
+    fn best_threshold(&self) -> f64 {
+        let total = self.weights.iter().sum::<f64>();
+        if total <= 0f64 {
+            return 0f64;
+        }
+        let target = self.sample.target();
+        let costs = self.raw_costs();
+
+        let mut items = self.scores.iter()
+            .zip(target)
+            .zip(costs)
+            .map(|((s, y), c)| (s / total, *y, c))
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+
+        // The threshold below all the confidences
+        // classifies all the examples as positive.
+        let mut cost = items.iter()
+            .filter(|(_, y, _)| *y <= 0f64)
+            .map(|(_, _, c)| c)
+            .sum::<f64>();
+        let mut best = (cost, -1f64);
+
+        let n_items = items.len();
+        for k in 0..n_items {
+            let (conf, y, c) = items[k];
+            // Move the `k`-th example to the negative side.
+            if y > 0f64 { cost += c; } else { cost -= c; }
+
+            // Skip the ties.
+            if k + 1 < n_items && items[k + 1].0 == conf {
+                continue;
+            }
+
+            let theta = if k + 1 < n_items {
+                0.5 * (conf + items[k + 1].0)
+            } else {
+                1f64
+            };
+            if cost < best.0 || (cost == best.0 && theta.abs() < best.1.abs()) {
+                best = (cost, theta);
+            }
+        }
+        best.1
+    }
+}
+
+
+impl<F> Booster<F> for AsymmetricAdaBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = ThresholdClassifier<F>;
+
+
+    fn name(&self) -> &str {
+        "AsymmetricAdaBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let costs = match &self.costs {
+            Costs::Uniform => "Uniform".to_string(),
+            Costs::Class(p, n) => format!("Positive {p}, Negative {n}"),
+            Costs::Example(_) => "Per example".to_string(),
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Costs", costs),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+
+        // Start from the distribution proportional to the costs.
+        self.dist = self.raw_costs();
+        utils::normalize(&mut self.dist[..]);
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+        self.scores = vec![0f64; n_sample];
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        let h = weak_learner.produce(self.sample, &self.dist[..]);
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+
+
+        let edge = utils::inner_product(&margins[..], &self.dist[..]);
+
+
+        // If `h` has no advantage on the current distribution,
+        // `AsymmetricAdaBoost` cannot make progress.
+        if edge <= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        // If the edge reaches `1`,
+        // `h` classifies all the examples with non-zero weights correctly.
+        if edge >= 1f64 {
+            self.terminated = iteration;
+            self.weights = vec![1f64];
+            self.scores = h.confidence_all(self.sample);
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        let alpha = 0.5 * ((1f64 + edge) / (1f64 - edge)).ln();
+
+
+        // To prevent overflow, take the logarithm.
+        self.dist.par_iter_mut()
+            .zip(&margins[..])
+            .for_each(|(d, m)| { *d = d.ln() - alpha * m; });
+        let max = self.dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        self.dist.par_iter_mut()
+            .for_each(|d| { *d = (*d - max).exp(); });
+        utils::normalize(&mut self.dist[..]);
+
+
+        self.scores.par_iter_mut()
+            .zip(margins)
+            .zip(self.sample.target())
+            .for_each(|((s, m), y)| { *s += alpha * m * y; });
+        self.weights.push(alpha);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for AsymmetricAdaBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = ThresholdClassifier<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        ThresholdClassifier {
+            majority: WeightedMajority::from_slices(
+                &self.weights[..], &self.hypotheses[..]
+            ),
+            threshold: self.best_threshold(),
+        }
+    }
+}
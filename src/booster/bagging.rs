@@ -0,0 +1,6 @@
+//! Bagging module.
+//! This algorithm is based on the paper
+//! "Bagging predictors" by Leo Breiman.
+pub mod bagging_algorithm;
+
+pub use bagging_algorithm::Bagging;
@@ -0,0 +1,291 @@
+//! This file defines `Bagging` based on the paper
+//! "Bagging predictors"
+//! by Leo Breiman.
+//!
+use rand::prelude::*;
+use rayon::prelude::*;
+
+use crate::{
+    Sample,
+    Booster,
+    WeakLearner,
+
+    Classifier,
+    WeightedMajority,
+    common::selection,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// Bootstrap aggregating (`Bagging`), proposed in the following paper:
+///
+/// [Leo Breiman - Bagging predictors](https://doi.org/10.1007/BF00058655)
+///
+/// At each round, `Bagging` draws a bootstrap replicate,
+/// i.e., `n_bag` examples drawn uniformly with replacement
+/// from the training examples,
+/// and trains the weak learner on it.
+/// The output is the uniform majority vote of the hypotheses.
+/// With [`DecisionTree`](crate::weak_learner::DecisionTree),
+/// `Bagging` is a random-forest style ensemble,
+/// which is a common baseline to compare with the boosting algorithms.
+///
+/// The bootstrap replicate is passed to the weak learner
+/// as the distribution `d_i = c_i / n_bag` over the training examples,
+/// where `c_i` is the number of times the `i`-th example is drawn.
+/// Thus, the weak learner must accept a weighted sample.
+/// Unlike the boosting algorithms,
+/// the replicates do not depend on the previous hypotheses,
+/// so [`Bagging::run_parallel`] trains the weak learner in parallel.
+/// The replicates are drawn from [`Bagging::seed`] in the same order,
+/// so [`Booster::run`] and [`Bagging::run_parallel`]
+/// return the same hypothesis.
+///
+/// # Example
+/// The following code shows a small example for running [`Bagging`].
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+///
+/// // Train 200 trees on the bootstrap replicates of size `0.8 m`.
+/// let mut bagging = Bagging::init(&sample)
+///     .n_estimators(200)
+///     .sample_ratio(0.8);
+///
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(5)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// // Run `Bagging` and obtain the resulting hypothesis `f`.
+/// let f = bagging.run_parallel(&weak_learner);
+///
+/// // Get the predictions on the training set.
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct Bagging<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The number of hypotheses.
+    n_estimators: usize,
+
+    // The size of each replicate relative to the training sample.
+    sample_ratio: f64,
+
+    // The seed of the random number generator.
+    seed: u64,
+
+    // Random number generator for the bootstrap.
+    rng: StdRng,
+
+    hypotheses: Vec<F>,
+
+    terminated: usize,
+}
+
+
+impl<'a, F> Bagging<'a, F> {
+    /// Construct a new instance of `Bagging`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            n_estimators: 100,
+            sample_ratio: 1.0,
+            seed: selection::SEED,
+            rng: StdRng::seed_from_u64(selection::SEED),
+
+            hypotheses: Vec::new(),
+
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the number of hypotheses.
+    /// Default value is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn n_estimators(mut self, n_estimators: usize) -> Self {
+        assert!(
+            n_estimators > 0,
+            "The number of estimators must be positive"
+        );
+        self.n_estimators = n_estimators;
+        self
+    }
+
+
+    /// Set the size of each bootstrap replicate
+    /// as the fraction of the number of training examples.
+    /// The fraction must be in `(0, 1]`.
+    /// Default value is `1.0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn sample_ratio(mut self, ratio: f64) -> Self {
+        assert!(
+            0f64 < ratio && ratio <= 1f64,
+            "The sample ratio must be in (0, 1]"
+        );
+        self.sample_ratio = ratio;
+        self
+    }
+
+
+    /// Set the seed of the randomness for the bootstrap.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Draws a bootstrap replicate
+    /// as a distribution over the training examples.
+    ///
+    /// Time complexity: `O(m)`.
+    fn draw_replicate(&mut self) -> Vec<f64> {
+        let n_sample = self.sample.shape().0;
+        let n_bag = ((self.sample_ratio * n_sample as f64).ceil() as usize)
+            .clamp(1, n_sample);
+
+        let unit = 1f64 / n_bag as f64;
+        let mut dist = vec![0f64; n_sample];
+        for _ in 0..n_bag {
+            let i = self.rng.gen_range(0..n_sample);
+            dist[i] += unit;
+        }
+        dist
+    }
+}
+
+
+impl<F> Bagging<'_, F>
+    where F: Classifier + Clone + Send,
+{
+    /// Trains the weak learner on all the bootstrap replicates in parallel
+    /// and returns the uniform majority vote.
+    /// The result is the same as the one of [`Booster::run`].
+    pub fn run_parallel<W>(&mut self, weak_learner: &W) -> WeightedMajority<F>
+        where W: WeakLearner<Hypothesis = F> + Sync,
+    {
+        self.preprocess(weak_learner);
+
+        let replicates = (0..self.n_estimators)
+            .map(|_| self.draw_replicate())
+            .collect::<Vec<_>>();
+        let sample = self.sample;
+        self.hypotheses = replicates.into_par_iter()
+            .map(|dist| weak_learner.produce(sample, &dist[..]))
+            .collect();
+        self.terminated = self.n_estimators;
+
+        self.postprocess(weak_learner)
+    }
+}
+
+
+impl<F> Booster<F> for Bagging<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "Bagging"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("# of estimators", format!("{}", self.n_estimators)),
+            ("Sample ratio", format!("{}", self.sample_ratio)),
+            ("Seed", format!("{}", self.seed)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        self.rng = StdRng::seed_from_u64(self.seed);
+        self.hypotheses = Vec::with_capacity(self.n_estimators);
+        self.terminated = usize::MAX;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.n_estimators < iteration {
+            self.terminated = self.n_estimators;
+            return ControlFlow::Break(self.n_estimators);
+        }
+
+        let dist = self.draw_replicate();
+        let h = weak_learner.produce(self.sample, &dist[..]);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for Bagging<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        let weights = vec![1f64; self.hypotheses.len()];
+        WeightedMajority::from_slices(&weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,11 @@
+//! The BrownBoost algorithm proposed by Yoav Freund.
+//! This algorithm is based on the paper: 
+//! [
+//! An Adaptive Version of the Boost by Majority Algorithm
+//! ](https://doi.org/10.1023/A:1010852229904)
+//! 
+//! BrownBoost is a noise-robust boosting algorithm
+//! that gives up the examples that are repeatedly misclassified.
+pub mod brownboost_algorithm;
+
+pub use brownboost_algorithm::BrownBoost;
@@ -0,0 +1,389 @@
+//! Provides [`BrownBoost`] by Freund, 2001.
+use rayon::prelude::*;
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The step size of `α` to solve the differential equation.
+const STEP_SIZE: f64 = 1e-2;
+/// The maximum number of steps to solve the differential equation.
+const MAX_STEPS: usize = 100_000;
+
+
+/// The BrownBoost algorithm proposed by Yoav Freund.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// An Adaptive Version of the Boost by Majority Algorithm
+/// ](https://doi.org/10.1023/A:1010852229904)
+/// by Yoav Freund.
+///
+/// BrownBoost is a boosting algorithm for binary classification
+/// that is robust to the label noise.
+/// BrownBoost has the **total time** parameter `c > 0`
+/// and keeps the remaining time `s`, which starts at `s = c`.
+/// At each round, the weight on the `i`-th example is
+/// `exp(- (r_i + s)² / c)`,
+/// where `r_i = y_i Σ_t α_t h_t (x_i)` is the current margin.
+/// Thus, the examples with very small margins are given up
+/// as the remaining time decreases.
+///
+/// Given a new hypothesis `h`,
+/// `BrownBoost` solves the differential equation
+/// `dt / dα = γ(α, t)` from `(α, t) = (0, 0)`, where
+/// ```txt
+/// γ(α, t) = Σ_i w_i(α, t) y_i h(x_i) / Σ_i w_i(α, t),
+/// w_i(α, t) = exp(- (r_i + α y_i h(x_i) + s - t)² / c),
+/// ```
+/// until `γ(α, t) <= tolerance` or `t = s`.
+/// Then, `h` gets the weight `α`, and the remaining time becomes `s - t`.
+/// `BrownBoost` terminates when the remaining time hits zero.
+///
+/// The larger `c` is, the more rounds `BrownBoost` runs,
+/// and the closer it behaves to AdaBoost.
+/// The smaller `c` is, the more noise `BrownBoost` tolerates.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Initialize `BrownBoost` and set the total time as `2.0`.
+/// let mut booster = BrownBoost::init(&sample)
+///     .c(2.0);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct BrownBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The total time parameter.
+    c: f64,
+
+    // The remaining time.
+    remaining: f64,
+
+    // The current margins `y_i Σ_t α_t h_t (x_i)`.
+    margins: Vec<f64>,
+
+    // Tolerance parameter.
+    // The differential equation is solved until `γ <= tolerance`.
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration of BrownBoost.
+    max_iter: usize,
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> BrownBoost<'a, F> {
+    /// Constructs a new instance of `BrownBoost`.
+    /// The total time parameter is set as `c = 1.0` by default.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            c: 1f64,
+            remaining: 1f64,
+            margins: Vec::new(),
+
+            tolerance: 1e-3,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: usize::MAX,
+            force_quit_at: None,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the total time parameter `c > 0`.
+    /// Default is `1.0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn c(mut self, c: f64) -> Self {
+        assert!(c > 0f64, "The total time `c` must be positive");
+        self.c = c;
+        self.remaining = c;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// The differential equation for the weight on a new hypothesis
+    /// is solved until the edge `γ` is at most `tolerance`.
+    /// Default is `1e-3`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0f64, "The tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Returns the remaining time.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn remaining_time(&self) -> f64 {
+        self.remaining
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the distribution over the training examples,
+    /// `d_i ∝ exp(- (r_i + s)² / c)`.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    fn distribution(&self) -> Vec<f64> {
+        let s = self.remaining;
+        let mut dist = self.margins.iter()
+            .map(|r| -(r + s).powi(2) / self.c)
+            .collect::<Vec<_>>();
+        let max = dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        dist.iter_mut()
+            .for_each(|d| { *d = (*d - max).exp(); });
+        utils::normalize(&mut dist[..]);
+        dist
+    }
+
+
+    /// Returns `γ(α, t)` for the margins `yh` of the new hypothesis.
+    fn gamma(&self, yh: &[f64], alpha: f64, t: f64) -> f64 {
+        let s = self.remaining;
+        let c = self.c;
+        let log_weights = self.margins.par_iter()
+            .zip(yh)
+            .map(|(r, m)| -(r + alpha * m + s - t).powi(2) / c)
+            .collect::<Vec<_>>();
+        let max = log_weights.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        let (numer, denom) = log_weights.into_iter()
+            .zip(yh)
+            .map(|(l, m)| {
+                let w = (l - max).exp();
+                (w * m, w)
+            })
+            .fold((0f64, 0f64), |(n, d), (wm, w)| (n + wm, d + w));
+        numer / denom
+    }
+
+
+    /// Solves `dt / dα = γ(α, t)` from `(0, 0)`
+    /// by the Runge-Kutta method
+    /// until `γ(α, t) <= tolerance` or `t = s`.
+    /// Returns `None` if `γ(0, 0) <= tolerance`.
+    fn solve(&self, yh: &[f64]) -> Option<(f64, f64)> {
+        if self.gamma(yh, 0f64, 0f64) <= self.tolerance {
+            return None;
+        }
+
+        let s = self.remaining;
+        let h = STEP_SIZE;
+        let mut alpha = 0f64;
+        let mut t = 0f64;
+        for _ in 0..MAX_STEPS {
+            let k1 = self.gamma(yh, alpha, t);
+            let k2 = self.gamma(yh, alpha + h / 2f64, t + h * k1 / 2f64);
+            let k3 = self.gamma(yh, alpha + h / 2f64, t + h * k2 / 2f64);
+            let k4 = self.gamma(yh, alpha + h, t + h * k3);
+            let next = t + h * (k1 + 2f64 * k2 + 2f64 * k3 + k4) / 6f64;
+
+            // If the remaining time runs out in this step,
+            // interpolate `α` at `t = s`.
+            if next >= s {
+                let ratio = (s - t) / (next - t);
+                return Some((alpha + ratio * h, s));
+            }
+            alpha += h;
+            t = next.max(0f64);
+
+            if self.gamma(yh, alpha, t) <= self.tolerance {
+                break;
+            }
+        }
+        Some((alpha, t))
+    }
+}
+
+
+impl<F> Booster<F> for BrownBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "BrownBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Total time (c)", format!("{}", self.c)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+        self.remaining = self.c;
+        self.margins = vec![0f64; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        if let Some(it) = self.force_quit_at {
+            self.max_iter = it;
+        }
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+        let dist = self.distribution();
+        let h = weak_learner.produce(self.sample, &dist[..]);
+        let yh = utils::margins_of_hypothesis(self.sample, &h);
+
+
+        // If `h` has no advantage on the current distribution,
+        // BrownBoost cannot make progress.
+        let Some((alpha, t)) = self.solve(&yh[..]) else {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        };
+
+
+        self.margins.par_iter_mut()
+            .zip(yh)
+            .for_each(|(r, m)| { *r += alpha * m; });
+        self.remaining -= t;
+
+        self.weights.push(alpha);
+        self.hypotheses.push(h);
+
+
+        // Terminate when the remaining time hits zero.
+        if self.remaining <= 0f64 {
+            self.remaining = 0f64;
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for BrownBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -49,6 +49,13 @@ use std::ops::ControlFlow;
 /// - `CERLPBoost` terminates in `O( ln(m/ν) / ε² )` iterations.
 ///
 /// # Related information
+/// - Unlike [`ERLPBoost`][erlpboost],
+///   `CERLPBoost` does not solve a convex program over
+///   all the hypotheses obtained so far.
+///   Each round, it only moves the weights toward
+///   the most recent hypothesis by a Frank-Wolfe step,
+///   so that the constraint set does not grow
+///   and no LP/QP solver is required.
 /// - Running time per round is 
 ///   the fastest among soft-margin boosting algorithms.
 /// - The iteration bound is the same as the one to [`ERLPBoost`][erlpboost].
@@ -210,7 +217,7 @@ impl<'a, F> CERLPBoost<'a, F> {
     /// Time complexity: `O(1)`.
     #[inline(always)]
     fn regularization_param(&mut self) {
-        let m = self.dist.len() as f64;
+        let m = self.sample.shape().0 as f64;
         let ln_part = (m / self.nu).ln();
         self.eta = ln_part / self.half_tolerance;
 
@@ -224,7 +231,7 @@ impl<'a, F> CERLPBoost<'a, F> {
     /// Time complexity: `O(1)`.
     pub fn max_loop(&mut self) -> usize {
 
-        let m = self.dist.len() as f64;
+        let m = self.sample.shape().0 as f64;
 
         let ln_m = (m / self.nu).ln();
         let max_iter = 8.0 * ln_m / self.half_tolerance.powi(2);
@@ -233,6 +240,16 @@ impl<'a, F> CERLPBoost<'a, F> {
     }
 
 
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
     /// Set the Frank-Wolfe rule.
     /// See [`FWType`].
     /// 
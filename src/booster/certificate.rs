@@ -0,0 +1,57 @@
+//! Provides [`Certificate`],
+//! the primal-dual pair of the soft margin optimization.
+use std::fmt;
+
+
+/// The optimality certificate of the soft margin boosters
+/// such as [`LPBoost`](crate::booster::LPBoost) and
+/// [`ERLPBoost`](crate::booster::ERLPBoost).
+///
+/// - `gamma_hat` is the smallest edge
+///   of the hypotheses obtained so far (an upper bound), and
+/// - `gamma_star` is the optimal value of the (regularized)
+///   edge minimization problem over the hypotheses
+///   obtained so far (a lower bound).
+///
+/// If the weak learner always returns the hypothesis
+/// with the largest edge,
+/// the soft margin optimum lies in `[gamma_star, gamma_hat]`,
+/// so [`Certificate::gap`] bounds the sub-optimality
+/// of the current combined hypothesis.
+/// Otherwise, the gap can be negative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Certificate {
+    /// The minimum edge of the hypotheses obtained so far.
+    pub gamma_hat: f64,
+    /// The optimal value of the restricted problem.
+    pub gamma_star: f64,
+}
+
+
+impl Certificate {
+    /// Returns the optimality gap `gamma_hat - gamma_star`.
+    #[inline]
+    pub fn gap(&self) -> f64 {
+        self.gamma_hat - self.gamma_star
+    }
+
+
+    /// Returns `true` if the gap is at most `tolerance`.
+    #[inline]
+    pub fn is_within(&self, tolerance: f64) -> bool {
+        self.gap() <= tolerance
+    }
+}
+
+
+impl fmt::Display for Certificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{gap:.6} (γ^ = {hat:.6}, γ* = {star:.6})",
+            gap = self.gap(),
+            hat = self.gamma_hat,
+            star = self.gamma_star,
+        )
+    }
+}
@@ -1,6 +1,7 @@
 //! Provides `Booster` trait.
 
-use crate::WeakLearner;
+use crate::{Sample, WeakLearner};
+use super::early_stopping::EarlyStopping;
 use std::ops::ControlFlow;
 
 
@@ -88,5 +89,68 @@ pub trait Booster<H> {
         weak_learner: &W,
     ) -> Self::Output
         where W: WeakLearner<Hypothesis = H>;
+
+
+    /// Stops the boosting if the loss on the validation `sample`
+    /// has not improved for `patience` rounds,
+    /// and returns the combined hypothesis at the best round.
+    /// See [`EarlyStopping`] for details.
+    fn validation(self, sample: &Sample, patience: usize)
+        -> EarlyStopping<'_, Self, Self::Output>
+        where Self: Sized
+    {
+        EarlyStopping::new(self, sample, patience)
+    }
 }
 
+
+
+/// The trait [`Resumable`] lets a booster continue boosting
+/// after [`Booster::run`].
+/// A booster keeps its state
+/// (the distribution, the hypotheses, the solver model, etc.)
+/// after [`Booster::run`],
+/// so one can inspect the combined hypothesis
+/// and then add more rounds without starting over.
+/// 
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let mut booster = AdaBoost::init(&sample)
+///     .force_quit_at(100);
+/// let f = booster.run(&weak_learner);
+/// 
+/// // Add 50 more rounds to `f`.
+/// let f = booster.fit_more(&weak_learner, 50);
+/// ```
+pub trait Resumable<H>: Booster<H> {
+    /// Returns the number of boosting rounds performed so far.
+    fn n_rounds(&self) -> usize;
+
+
+    /// Prepares `self` to run at most `n_rounds` additional rounds.
+    /// This method is called at the beginning of [`Resumable::fit_more`].
+    fn resume(&mut self, n_rounds: usize);
+
+
+    /// Continues the boosting for at most `n_rounds` rounds
+    /// and returns the combined hypothesis.
+    /// This method must be called after [`Booster::run`].
+    fn fit_more<W>(
+        &mut self,
+        weak_learner: &W,
+        n_rounds: usize,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = H>
+    {
+        let start = self.n_rounds();
+        self.resume(n_rounds);
+
+        let _ = (start + 1..=start + n_rounds).try_for_each(|iter| {
+            self.boost(weak_learner, iter)
+        });
+
+        self.postprocess(weak_learner)
+    }
+}
@@ -0,0 +1,12 @@
+//! The DeepBoost algorithm proposed by
+//! Corinna Cortes, Mehryar Mohri, and Umar Syed.
+//! This algorithm is based on the paper: 
+//! [
+//! Deep Boosting
+//! ](https://proceedings.mlr.press/v32/cortesb14.html)
+//! 
+//! DeepBoost penalizes the weights on complex hypotheses
+//! by the complexities reported by the weak learner.
+pub mod deepboost_algorithm;
+
+pub use deepboost_algorithm::DeepBoost;
@@ -0,0 +1,417 @@
+//! Provides [`DeepBoost`] by Cortes, Mohri, and Syed, 2014.
+use rayon::prelude::*;
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The DeepBoost algorithm
+/// proposed by Corinna Cortes, Mehryar Mohri, and Umar Syed.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Deep Boosting
+/// ](https://proceedings.mlr.press/v32/cortesb14.html)
+/// by Corinna Cortes, Mehryar Mohri, and Umar Syed.
+///
+/// DeepBoost is a boosting algorithm for binary classification
+/// that can use rich (complex) hypotheses such as deep decision trees
+/// without overfitting.
+/// Given the complexity `r_j` of the `j`-th hypothesis `h_j`
+/// (see [`WeakLearner::complexity`]),
+/// `DeepBoost` minimizes the penalized exponential loss
+/// ```txt
+/// (1/m) Σ_i exp(1 - y_i Σ_j α_j h_j (x_i)) + Σ_j Λ_j |α_j|,
+/// ```
+/// where `Λ_j = λ r_j + β`,
+/// by the coordinate descent.
+/// At each round, `DeepBoost` chooses a hypothesis
+/// among the ones obtained so far and
+/// the candidates given by [`WeakLearner::produce_many`]
+/// that has the steepest penalized descent direction,
+/// and updates the weight on it by the exact line search.
+/// Thus, a complex hypothesis is chosen only if
+/// its edge is large enough to pay the penalty.
+///
+/// `DeepBoost` terminates if no hypothesis decreases the objective,
+/// or after `max_iter` iterations.
+/// Since [`WeightedMajority`] ignores the non-positive weights,
+/// the hypotheses whose weights are zeroed out by the penalty
+/// are removed from the output.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = DeepBoost::init(&sample)
+///     .lambda(1e-3)
+///     .beta(1e-4)
+///     .n_candidates(5)
+///     .max_iter(100);
+///
+/// // `DecisionTree` reports the complexity of a tree
+/// // from the number of its leaves.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(5)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct DeepBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The coefficient of the complexity penalty.
+    lambda: f64,
+
+    // The constant penalty.
+    beta: f64,
+
+    // The number of candidates requested to the weak learner.
+    n_candidates: usize,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Penalties `Λ_j = λ r_j + β` on hypotheses in `hypotheses`.
+    penalties: Vec<f64>,
+
+    // The current scores `Σ_j α_j h_j (x_i)` on the training examples.
+    scores: Vec<f64>,
+
+    // Max iteration of DeepBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> DeepBoost<'a, F> {
+    /// Constructs a new instance of `DeepBoost`.
+    /// By default, `λ = 1e-4`, `β = 0`,
+    /// and `DeepBoost` requests `5` candidates per round.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        let n_sample = sample.shape().0;
+
+        Self {
+            sample,
+
+            lambda: 1e-4,
+            beta: 0f64,
+            n_candidates: 5,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+            penalties: Vec::new(),
+            scores: vec![0f64; n_sample],
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the coefficient `λ ≥ 0` of the complexity penalty.
+    /// Default is `1e-4`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn lambda(mut self, lambda: f64) -> Self {
+        assert!(lambda >= 0f64, "The parameter `lambda` must be non-negative");
+        self.lambda = lambda;
+        self
+    }
+
+
+    /// Set the constant penalty `β ≥ 0`,
+    /// which works as the `L1`-regularization on the weights.
+    /// Default is `0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn beta(mut self, beta: f64) -> Self {
+        assert!(beta >= 0f64, "The parameter `beta` must be non-negative");
+        self.beta = beta;
+        self
+    }
+
+
+    /// Set the number of candidates
+    /// requested to the weak learner per round.
+    /// Default is `5`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn n_candidates(mut self, k: usize) -> Self {
+        assert!(k > 0, "The number of candidates must be positive");
+        self.n_candidates = k;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the current value of the objective,
+    /// `(1/m) Σ_i exp(1 - y_i Σ_j α_j h_j (x_i)) + Σ_j Λ_j |α_j|`.
+    ///
+    /// Time complexity: `O(m + T)`,
+    /// where `m` is the number of training examples
+    /// and `T` is the number of hypotheses.
+    pub fn objective_value(&self) -> f64 {
+        let n_sample = self.scores.len() as f64;
+        let loss = self.sample.target()
+            .iter()
+            .zip(&self.scores[..])
+            .map(|(y, s)| (1f64 - y * s).exp())
+            .sum::<f64>()
+            / n_sample;
+        let penalty = self.weights.iter()
+            .zip(&self.penalties[..])
+            .map(|(w, p)| p * w.abs())
+            .sum::<f64>();
+        loss + penalty
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+}
+
+
+/// Returns the descent direction of a hypothesis
+/// with the weighted error `eps`, the current weight `alpha`,
+/// and the normalized penalty `lm = Λ m / (2 S)`.
+fn direction(eps: f64, alpha: f64, lm: f64) -> f64 {
+    let gap = eps - 0.5;
+    if alpha != 0f64 {
+        gap + alpha.signum() * lm
+    } else if gap.abs() <= lm {
+        0f64
+    } else {
+        gap - gap.signum() * lm
+    }
+}
+
+
+/// Returns the step size that minimizes the objective
+/// along a hypothesis
+/// with the weighted error `eps`, the current weight `alpha`,
+/// and the normalized penalty `lm = Λ m / S`.
+fn step_size(eps: f64, alpha: f64, lm: f64) -> f64 {
+    let slope = (1f64 - eps) * alpha.exp() - eps * (- alpha).exp();
+    if slope.abs() <= lm {
+        return - alpha;
+    }
+
+    // Solve `ε x² ± (Λ m / S) x - (1 - ε) = 0` for `x = exp(η)`.
+    let b = if slope > lm { lm } else { - lm };
+    let x = if eps <= 0f64 {
+        (1f64 - eps) / b
+    } else {
+        let half = b / (2f64 * eps);
+        - half + (half.powi(2) + (1f64 - eps) / eps).sqrt()
+    };
+    x.ln()
+}
+
+
+impl<F> DeepBoost<'_, F>
+    where F: Classifier,
+{
+    /// Returns the weighted error of `h` on `dist`.
+    fn weighted_error(&self, dist: &[f64], h: &F) -> f64 {
+        let edge = utils::edge_of_hypothesis(self.sample, dist, h);
+        (1f64 - edge) / 2f64
+    }
+}
+
+
+impl<F> Booster<F> for DeepBoost<'_, F>
+    where F: Classifier + Clone + PartialEq,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "DeepBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Lambda", format!("{}", self.lambda)),
+            ("Beta", format!("{}", self.beta)),
+            ("# of candidates", format!("{}", self.n_candidates)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+        self.penalties = Vec::new();
+        self.scores = vec![0f64; n_sample];
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Compute `d_i ∝ exp(1 - y_i f(x_i))` and the normalizer `S`.
+        let n_sample = self.sample.shape().0;
+        let target = self.sample.target();
+        let mut dist = target.iter()
+            .zip(&self.scores[..])
+            .map(|(y, s)| (1f64 - y * s).exp())
+            .collect::<Vec<_>>();
+        let total = dist.iter().sum::<f64>();
+        utils::normalize(&mut dist[..]);
+
+
+        // Append the new candidates to the hypotheses with zero weights.
+        let candidates = weak_learner.produce_many(
+            self.sample, &dist[..], self.n_candidates
+        );
+        for h in candidates {
+            if self.hypotheses.contains(&h) { continue; }
+            let r = weak_learner.complexity(self.sample, &h);
+            self.penalties.push(self.lambda * r + self.beta);
+            self.weights.push(0f64);
+            self.hypotheses.push(h);
+        }
+
+
+        // Choose the hypothesis with the steepest descent direction.
+        let scale = n_sample as f64 / total;
+        let errors = self.hypotheses.iter()
+            .map(|h| self.weighted_error(&dist[..], h))
+            .collect::<Vec<_>>();
+        let best = errors.iter()
+            .zip(&self.weights[..])
+            .zip(&self.penalties[..])
+            .map(|((&eps, &alpha), &p)| direction(eps, alpha, p * scale / 2f64))
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.abs().partial_cmp(&b.abs()).unwrap());
+        let Some((k, d)) = best else {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        };
+
+
+        // If no hypothesis decreases the objective,
+        // the current weights are optimal.
+        if d == 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let eps = errors[k];
+        let lm = self.penalties[k] * scale;
+
+        // If the chosen hypothesis is perfect and not penalized,
+        // use it as the combined classifier.
+        if eps <= 0f64 && lm == 0f64 {
+            self.terminated = iteration;
+            self.weights = vec![1f64];
+            self.hypotheses = vec![self.hypotheses.swap_remove(k)];
+            self.penalties = vec![self.penalties[k]];
+            return ControlFlow::Break(iteration);
+        }
+
+        let eta = step_size(eps, self.weights[k], lm);
+        self.weights[k] += eta;
+
+        let confidences = self.hypotheses[k].confidence_all(self.sample);
+        self.scores.par_iter_mut()
+            .zip(confidences)
+            .for_each(|(s, c)| { *s += eta * c; });
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for DeepBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,262 @@
+//! Provides [`EarlyStopping`],
+//! a wrapper that stops a booster by the loss on a validation sample.
+use crate::{
+    Sample,
+    Booster,
+    WeakLearner,
+    Classifier,
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The loss on the validation sample monitored by [`EarlyStopping`].
+///
+/// - `()`, the default, is the zero-one error of a classifier.
+/// - Any closure `Fn(&Sample, &O) -> f64` is a custom loss
+///   (see [`EarlyStopping::loss`]).
+pub trait ValidationLoss<O> {
+    /// Returns the loss of `f` on `sample`.
+    fn eval(&self, sample: &Sample, f: &O) -> f64;
+}
+
+
+impl<O> ValidationLoss<O> for ()
+    where O: Classifier,
+{
+    fn eval(&self, sample: &Sample, f: &O) -> f64 {
+        let (accuracy, _) = utils::validation_score(sample, f);
+        1f64 - accuracy
+    }
+}
+
+
+impl<O, G> ValidationLoss<O> for G
+    where G: Fn(&Sample, &O) -> f64,
+{
+    fn eval(&self, sample: &Sample, f: &O) -> f64 {
+        (self)(sample, f)
+    }
+}
+
+
+/// A wrapper of a booster that monitors the loss
+/// of the current combined hypothesis on a validation sample
+/// after each round.
+/// If the loss has not improved for `patience` rounds,
+/// `EarlyStopping` stops the boosting
+/// and returns the combined hypothesis at the best round.
+///
+/// Since this struct implements [`Booster`],
+/// [`Booster::run`] works as usual.
+/// Any booster that implements [`Research`]
+/// is wrapped by [`Booster::validation`].
+///
+/// By default, the loss is the zero-one error on the validation sample.
+/// Use [`EarlyStopping::loss`] for other losses,
+/// e.g., for regression.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// let mut booster = AdaBoost::init(&train)
+///     .force_quit_at(1_000)
+///     .validation(&valid, 20);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&train)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// println!("Best round: {}", booster.best_round());
+/// ```
+pub struct EarlyStopping<'a, B, O, G = ()> {
+    booster: B,
+    sample: &'a Sample,
+    patience: usize,
+    loss: G,
+
+    // The number of rounds since the last improvement.
+    n_stalls: usize,
+
+    // The best round, its validation loss, and its combined hypothesis.
+    best_round: usize,
+    best_loss: f64,
+    best: Option<O>,
+}
+
+
+impl<'a, B, O> EarlyStopping<'a, B, O> {
+    /// Wraps `booster` so that it stops
+    /// if the loss on `sample` has not improved for `patience` rounds.
+    /// Consider using [`Booster::validation`] instead.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn new(booster: B, sample: &'a Sample, patience: usize) -> Self {
+        assert!(patience > 0, "The patience must be positive");
+        Self {
+            booster,
+            sample,
+            patience,
+            loss: (),
+
+            n_stalls: 0,
+            best_round: 0,
+            best_loss: f64::MAX,
+            best: None,
+        }
+    }
+}
+
+
+impl<'a, B, O, G> EarlyStopping<'a, B, O, G> {
+    /// Set the loss on the validation sample.
+    /// The loss `loss(sample, f)` of the combined hypothesis `f`
+    /// is evaluated after each round.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn loss<L>(self, loss: L) -> EarlyStopping<'a, B, O, L> {
+        EarlyStopping {
+            booster: self.booster,
+            sample: self.sample,
+            patience: self.patience,
+            loss,
+
+            n_stalls: self.n_stalls,
+            best_round: self.best_round,
+            best_loss: self.best_loss,
+            best: self.best,
+        }
+    }
+
+
+    /// Returns the round with the smallest validation loss.
+    /// This method returns `0` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn best_round(&self) -> usize {
+        self.best_round
+    }
+
+
+    /// Returns the smallest validation loss.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn best_loss(&self) -> f64 {
+        self.best_loss
+    }
+
+
+    /// Returns the reference to the wrapped booster.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn booster(&self) -> &B {
+        &self.booster
+    }
+
+
+    /// Returns the wrapped booster.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn into_inner(self) -> B {
+        self.booster
+    }
+}
+
+
+impl<H, B, O, G> Booster<H> for EarlyStopping<'_, B, O, G>
+    where B: Booster<H, Output = O> + Research<Output = O>,
+          G: ValidationLoss<O>,
+{
+    type Output = O;
+
+
+    fn name(&self) -> &str {
+        self.booster.name()
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let mut info = self.booster.info().unwrap_or_default();
+        let n_sample = self.sample.shape().0;
+        info.push(("# of validation examples", format!("{n_sample}")));
+        info.push(("Patience", format!("{}", self.patience)));
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = H>
+    {
+        self.booster.preprocess(weak_learner);
+
+        self.n_stalls = 0;
+        self.best_round = 0;
+        self.best_loss = f64::MAX;
+        self.best = None;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = H>,
+    {
+        let flow = self.booster.boost(weak_learner, iteration);
+
+
+        let f = self.booster.current_hypothesis();
+        let loss = self.loss.eval(self.sample, &f);
+        if loss < self.best_loss {
+            self.best_loss = loss;
+            self.best_round = iteration;
+            self.best = Some(f);
+            self.n_stalls = 0;
+        } else {
+            self.n_stalls += 1;
+        }
+
+
+        if flow.is_break() { return flow; }
+        if self.n_stalls >= self.patience {
+            return ControlFlow::Break(self.best_round);
+        }
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = H>
+    {
+        // Restore the combined hypothesis at the best round.
+        let f = self.booster.postprocess(weak_learner);
+        self.best.take().unwrap_or(f)
+    }
+}
+
+
+impl<B, O, G> Research for EarlyStopping<'_, B, O, G>
+    where B: Research<Output = O>,
+{
+    type Output = O;
+    fn current_hypothesis(&self) -> Self::Output {
+        self.booster.current_hypothesis()
+    }
+
+
+    fn out_of_bag_loss(&self) -> Option<f64> {
+        self.booster.out_of_bag_loss()
+    }
+}
@@ -0,0 +1,9 @@
+//! Error-correcting output codes module.
+//! This module is based on the papers
+//! "Solving Multiclass Learning Problems via Error-Correcting Output Codes"
+//! by Thomas G. Dietterich and Ghulum Bakiri, and
+//! "Reducing Multiclass to Binary: A Unifying Approach for Margin Classifiers"
+//! by Erin L. Allwein, Robert E. Schapire, and Yoram Singer.
+pub mod ecoc_algorithm;
+
+pub use ecoc_algorithm::ECOC;
@@ -0,0 +1,320 @@
+//! This file defines `ECOC`,
+//! a meta-booster that trains one binary booster
+//! per column of a coding matrix.
+use rand::prelude::*;
+use rayon::prelude::*;
+
+use crate::{
+    Sample,
+    Classifier,
+    ECOCClassifier,
+    Decoding,
+    common::selection,
+};
+
+use std::collections::HashSet;
+
+
+/// The maximal number of trials to draw a new column of the code.
+const MAX_TRIALS: usize = 1_000;
+
+
+/// Error-correcting output codes (`ECOC`), proposed in the following papers:
+///
+/// [Thomas G. Dietterich and Ghulum Bakiri - Solving Multiclass Learning Problems via Error-Correcting Output Codes](https://doi.org/10.1613/jair.105)
+///
+/// [Erin L. Allwein, Robert E. Schapire, and Yoram Singer - Reducing Multiclass to Binary: A Unifying Approach for Margin Classifiers](https://www.jmlr.org/papers/v1/allwein00a.html)
+///
+/// `ECOC` reduces a multiclass problem with `K` classes
+/// to the binary ones by a `K × L` coding matrix `M`
+/// with entries `-1`, `0`, and `+1`.
+/// For each column `l`, `ECOC` derives the binary sample
+/// whose label is `M_{k,l}` for the examples of class `k`,
+/// dropping the examples with `M_{k,l} = 0`,
+/// and trains a binary classifier on it.
+/// The binary classifiers are trained in parallel.
+///
+/// The returned [`ECOCClassifier`] predicts the class
+/// whose code word is the nearest to the outputs of the classifiers
+/// (see [`Decoding`]).
+/// Since the code words are far apart from each other,
+/// the prediction is correct
+/// even if some binary classifiers make mistakes.
+///
+/// By default, `ECOC` draws a random dense code
+/// with `⌈10 log₂ K⌉` columns,
+/// rejecting the constant columns and,
+/// if possible, the duplicated or complementary ones.
+/// Use [`ECOC::code`] to give a coding matrix.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // The column `class` has the class labels `0, 1, 2, ...`.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let f = ECOC::new(&sample)
+///     .n_columns(15)
+///     .decoding(Decoding::Loss)
+///     .run(|binary| {
+///         let weak_learner = DecisionTreeBuilder::new(binary)
+///             .max_depth(2)
+///             .criterion(Criterion::Entropy)
+///             .build();
+///         AdaBoost::init(binary)
+///             .force_quit_at(100)
+///             .run(&weak_learner)
+///     });
+/// let predictions = f.predict_class_all(&sample);
+/// ```
+pub struct ECOC<'a> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Class labels in ascending order.
+    classes: Vec<i64>,
+
+    // The coding matrix given by `ECOC::code`.
+    code: Option<Vec<Vec<i8>>>,
+
+    // The number of columns of the random code.
+    n_columns: usize,
+
+    // The seed of the random code.
+    seed: u64,
+
+    // The decoding strategy.
+    decoding: Decoding,
+}
+
+
+impl<'a> ECOC<'a> {
+    /// Constructs a new instance of `ECOC`.
+    /// The target values of `sample` are the class labels.
+    ///
+    /// Time complexity: `O(m log m)`,
+    /// where `m` is the number of training examples.
+    pub fn new(sample: &'a Sample) -> Self {
+        let classes = sample.unique_target()
+            .into_iter()
+            .map(|y| y as i64)
+            .collect::<Vec<_>>();
+        assert!(
+            classes.len() >= 2,
+            "The training sample must have at least two classes"
+        );
+        let n_columns = (10f64 * (classes.len() as f64).log2()).ceil();
+        Self {
+            sample,
+            classes,
+            code: None,
+            n_columns: n_columns as usize,
+            seed: selection::SEED,
+            decoding: Decoding::Loss,
+        }
+    }
+
+
+    /// Set the number of columns of the random code.
+    /// Default is `⌈10 log₂ K⌉`, where `K` is the number of classes.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn n_columns(mut self, n_columns: usize) -> Self {
+        assert!(n_columns > 0, "The number of columns must be positive");
+        self.n_columns = n_columns;
+        self
+    }
+
+
+    /// Set the seed of the random code.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Set the coding matrix.
+    /// `code[k][l]` is the entry of the class `self.classes()[k]`
+    /// and the `l`-th column, which is `-1`, `0`, or `+1`.
+    /// Each column must have both `-1` and `+1`,
+    /// and the code words must be distinct.
+    ///
+    /// Time complexity: `O(K L)`,
+    /// where `K` is the number of classes
+    /// and `L` is the number of columns.
+    pub fn code(mut self, code: Vec<Vec<i8>>) -> Self {
+        check_code(&code, self.classes.len());
+        self.code = Some(code);
+        self
+    }
+
+
+    /// Set the decoding strategy.
+    /// Default is [`Decoding::Loss`].
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn decoding(mut self, decoding: Decoding) -> Self {
+        self.decoding = decoding;
+        self
+    }
+
+
+    /// Returns the class labels in ascending order.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn classes(&self) -> &[i64] {
+        &self.classes[..]
+    }
+
+
+    /// Returns the coding matrix,
+    /// i.e., the one given by [`ECOC::code`] or the random one.
+    ///
+    /// Time complexity: `O(K L)`,
+    /// where `K` is the number of classes
+    /// and `L` is the number of columns.
+    pub fn coding_matrix(&self) -> Vec<Vec<i8>> {
+        match self.code.as_ref() {
+            Some(code) => code.clone(),
+            None => self.random_code(),
+        }
+    }
+
+
+    /// Returns the binary sample of the `column`-th column of `code`.
+    /// The examples of the classes with the zero entries are dropped.
+    /// If there is no such class,
+    /// the returned sample shares the features with the training sample.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    pub fn binary_sample(&self, code: &[Vec<i8>], column: usize) -> Sample {
+        let entries = self.sample.target()
+            .iter()
+            .map(|&y| {
+                let k = self.classes.binary_search(&(y as i64))
+                    .expect("Unknown class label");
+                code[k][column]
+            })
+            .collect::<Vec<_>>();
+
+        if entries.iter().all(|&m| m != 0) {
+            let target = entries.into_iter()
+                .map(f64::from)
+                .collect::<Vec<_>>();
+            return self.sample.with_target(target);
+        }
+
+
+        let (kept, dropped): (Vec<_>, Vec<_>) = (0..entries.len())
+            .partition(|&i| entries[i] != 0);
+        let n_kept = kept.len();
+        let ix = kept.iter()
+            .chain(&dropped[..])
+            .copied()
+            .collect::<Vec<_>>();
+        let (_, mut binary) = self.sample.split(&ix[..], 0, n_kept);
+        binary.target_mut()
+            .iter_mut()
+            .zip(&kept[..])
+            .for_each(|(y, &i)| { *y = f64::from(entries[i]); });
+        binary
+    }
+
+
+    /// Trains a binary classifier `train(binary)`
+    /// on the binary sample of each column in parallel
+    /// and returns the combined multiclass classifier.
+    /// Typically, `train` runs a booster on `binary`.
+    pub fn run<H, G>(&self, train: G) -> ECOCClassifier<H>
+        where G: Fn(&Sample) -> H + Sync,
+              H: Classifier + Send,
+    {
+        let code = self.coding_matrix();
+        let n_columns = code[0].len();
+        let hypotheses = (0..n_columns).into_par_iter()
+            .map(|l| {
+                let binary = self.binary_sample(&code[..], l);
+                train(&binary)
+            })
+            .collect::<Vec<_>>();
+        ECOCClassifier::new(
+            self.classes.clone(), code, hypotheses, self.decoding
+        )
+    }
+
+
+    /// Returns a random dense code with `self.n_columns` columns.
+    fn random_code(&self) -> Vec<Vec<i8>> {
+        let n_classes = self.classes.len();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        // The number of the distinct columns up to the complement.
+        let n_distinct = 2f64.powi(n_classes as i32 - 1) - 1f64;
+        let distinct = n_distinct >= self.n_columns as f64;
+
+        let mut seen = HashSet::new();
+        let mut columns = Vec::with_capacity(self.n_columns);
+        while columns.len() < self.n_columns {
+            let mut column = Vec::new();
+            for _ in 0..MAX_TRIALS {
+                column = (0..n_classes)
+                    .map(|_| if rng.gen::<bool>() { 1i8 } else { -1i8 })
+                    .collect::<Vec<_>>();
+                let constant = column.iter().all(|&m| m == column[0]);
+                if constant { continue; }
+
+                // Identify a column with its complement.
+                let key = column.iter()
+                    .map(|&m| m * column[0])
+                    .collect::<Vec<_>>();
+                if !distinct || seen.insert(key) { break; }
+            }
+            columns.push(column);
+        }
+
+
+        let code = (0..n_classes)
+            .map(|k| columns.iter().map(|column| column[k]).collect())
+            .collect::<Vec<Vec<i8>>>();
+        check_code(&code, n_classes);
+        code
+    }
+}
+
+
+/// Checks whether `code` is a valid coding matrix for `n_classes` classes.
+fn check_code(code: &[Vec<i8>], n_classes: usize) {
+    assert_eq!(
+        code.len(), n_classes,
+        "The number of code words must be the same as the one of classes"
+    );
+    let n_columns = code[0].len();
+    assert!(n_columns > 0, "The code must have at least one column");
+    assert!(
+        code.iter().all(|word| word.len() == n_columns),
+        "The code words must have the same length"
+    );
+    assert!(
+        code.iter().flatten().all(|m| [-1, 0, 1].contains(m)),
+        "The entries of the code must be -1, 0, or +1"
+    );
+    for l in 0..n_columns {
+        let has_positive = code.iter().any(|word| word[l] == 1);
+        let has_negative = code.iter().any(|word| word[l] == -1);
+        assert!(
+            has_positive && has_negative,
+            "The column {l} of the code must have both -1 and +1"
+        );
+    }
+    let words = code.iter().collect::<HashSet<_>>();
+    assert_eq!(words.len(), n_classes, "The code words must be distinct");
+}
@@ -0,0 +1,9 @@
+//! The ε-boosting (forward stagewise) algorithm.
+//! This algorithm is based on the paper:
+//! [
+//! Boosting as a Regularized Path to a Maximum Margin Classifier
+//! ](https://www.jmlr.org/papers/v5/rosset04a.html)
+//! by Saharon Rosset, Ji Zhu, and Trevor Hastie.
+pub mod epsilonboost_algorithm;
+
+pub use epsilonboost_algorithm::EpsilonBoost;
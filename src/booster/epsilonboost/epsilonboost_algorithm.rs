@@ -0,0 +1,370 @@
+//! Provides [`EpsilonBoost`] by Rosset et al., 2004.
+use rayon::prelude::*;
+
+use crate::{
+    common::loss_functions::LossFunction,
+    Sample,
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The ε-boosting algorithm,
+/// a.k.a. the forward stagewise additive modeling
+/// with infinitesimal steps.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Boosting as a Regularized Path to a Maximum Margin Classifier
+/// ](https://www.jmlr.org/papers/v5/rosset04a.html)
+/// by Saharon Rosset, Ji Zhu, and Trevor Hastie.
+///
+/// `EpsilonBoost` minimizes the empirical loss
+/// `L(F) = (1/m) Σ_i ℓ(F(x_i), y_i)`
+/// of the additive model `F(x) = Σ_h β_h h(x)`
+/// by tiny fixed steps.
+/// At each round, `EpsilonBoost` passes the distribution
+/// `d_i ∝ max(0, - y_i g_i)` to the weak learner,
+/// where `g_i` is the gradient of the loss at `F(x_i)`,
+/// and increases the coefficient `β_h` of the returned hypothesis `h`
+/// by `ε`.
+/// If the weak learner returns a hypothesis obtained before,
+/// its coefficient is increased,
+/// so the hypotheses must implement `PartialEq`.
+///
+/// As `ε → 0`, the coefficient path `β(t)` over the rounds
+/// approaches the L1-regularized path
+/// `β(c) = argmin { L(F) : ||β||_1 ≤ c }`
+/// under some conditions,
+/// and the normalized margin converges to
+/// the maximum L1-margin for the exponential and logistic losses.
+/// [`EpsilonBoost::coefficient_path`] returns the whole path.
+/// Use [`HypothesisPool`](crate::weak_learner::HypothesisPool)
+/// to run `EpsilonBoost` over a fixed dictionary of hypotheses.
+///
+/// `EpsilonBoost` terminates
+/// if the new hypothesis is not a descent direction
+/// or after `max_iter` iterations.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = EpsilonBoost::init_with_loss(&sample, GBMLoss::Exponential)
+///     .epsilon(0.01)
+///     .max_iter(5_000);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(1)
+///     .criterion(Criterion::Edge)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+///
+/// // `path[t][j]` is the coefficient on the `j`-th hypothesis
+/// // after `t + 1` rounds.
+/// let path = booster.coefficient_path();
+/// ```
+pub struct EpsilonBoost<'a, F, L> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Some struct that implements `LossFunction` trait
+    loss: L,
+
+    // Step size
+    epsilon: f64,
+
+    // Coefficients on the distinct hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Distinct hypotheses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // The index of the hypothesis chosen at each round.
+    selected: Vec<usize>,
+
+    // Max iteration of EpsilonBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+    // The current scores `F(x)` on the training examples.
+    scores: Vec<f64>,
+
+    // The current training loss.
+    objval: f64,
+}
+
+
+impl<'a, F, L> EpsilonBoost<'a, F, L> {
+    /// Constructs a new instance of `EpsilonBoost`
+    /// that minimizes the given loss function.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    pub fn init_with_loss(sample: &'a Sample, loss: L) -> Self {
+        let n_sample = sample.shape().0;
+
+        Self {
+            sample,
+
+            loss,
+            epsilon: 0.01,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+            selected: Vec::new(),
+
+            max_iter: 1_000,
+            terminated: usize::MAX,
+
+            scores: vec![0f64; n_sample],
+            objval: f64::MAX,
+        }
+    }
+
+
+    /// Set the step size `ε > 0`.
+    /// Default is `0.01`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        assert!(epsilon > 0f64, "The step size must be positive");
+        self.epsilon = epsilon;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `1000`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the current training loss.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn objective_value(&self) -> f64 {
+        self.objval
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the distinct hypotheses obtained so far
+    /// in the order of the first appearance.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn hypotheses(&self) -> &[F] {
+        &self.hypotheses[..]
+    }
+
+
+    /// Returns the current coefficients
+    /// on [`EpsilonBoost::hypotheses`].
+    /// Unlike the output of [`Booster::run`],
+    /// the coefficients are not normalized.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.weights[..]
+    }
+
+
+    /// Returns the coefficient path.
+    /// `path[t][j]` is the coefficient on the `j`-th hypothesis
+    /// of [`EpsilonBoost::hypotheses`] after `t + 1` rounds.
+    /// Each row has the same length;
+    /// the hypotheses not obtained yet have coefficient `0`.
+    ///
+    /// Time complexity: `O(T H)`,
+    /// where `T` is the number of rounds and
+    /// `H` is the number of distinct hypotheses.
+    pub fn coefficient_path(&self) -> Vec<Vec<f64>> {
+        let mut coefficients = vec![0f64; self.hypotheses.len()];
+        self.selected.iter()
+            .map(|&j| {
+                coefficients[j] += self.epsilon;
+                coefficients.clone()
+            })
+            .collect()
+    }
+}
+
+
+impl<F, L> EpsilonBoost<'_, F, L>
+    where L: LossFunction,
+{
+    /// Returns the directional derivative of the training loss
+    /// at `F` along `h`,
+    /// where `predictions` is the predictions of `h`.
+    fn slope(&self, predictions: &[f64]) -> f64 {
+        let n_sample = predictions.len() as f64;
+        self.loss.gradient(&self.scores[..], self.sample.target())
+            .into_iter()
+            .zip(predictions)
+            .map(|(g, p)| g * p)
+            .sum::<f64>()
+            / n_sample
+    }
+}
+
+
+impl<F, L> Booster<F> for EpsilonBoost<'_, F, L>
+    where F: Classifier + Clone + PartialEq,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "EpsilonBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Loss", self.loss.name().to_string()),
+            ("Step size", format!("{}", self.epsilon)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+        self.selected = Vec::with_capacity(self.max_iter);
+
+        self.terminated = self.max_iter;
+        self.scores = vec![0f64; n_sample];
+        self.objval = self.loss.eval(&self.scores[..], self.sample.target());
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // The distribution is proportional to
+        // the negative gradient along the labels.
+        let target = self.sample.target();
+        let mut dist = self.loss.gradient(&self.scores[..], target)
+            .into_iter()
+            .zip(target)
+            .map(|(g, y)| (- y * g).max(0f64))
+            .collect::<Vec<_>>();
+
+        // If the gradient vanishes, `F` is optimal.
+        if dist.iter().all(|d| *d == 0f64) {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+        utils::normalize(&mut dist[..]);
+
+
+        let h = weak_learner.produce(self.sample, &dist[..]);
+        let predictions = h.confidence_all(self.sample);
+
+
+        // If `h` is not a descent direction,
+        // EpsilonBoost cannot make progress.
+        if self.slope(&predictions[..]) >= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let epsilon = self.epsilon;
+        self.scores.par_iter_mut()
+            .zip(predictions)
+            .for_each(|(s, p)| { *s += epsilon * p; });
+
+        let pos = self.hypotheses.iter()
+            .position(|f| *f == h)
+            .unwrap_or(self.hypotheses.len());
+        if pos == self.hypotheses.len() {
+            self.hypotheses.push(h);
+            self.weights.push(0f64);
+        }
+        self.weights[pos] += epsilon;
+        self.selected.push(pos);
+
+        self.objval = self.loss.eval(&self.scores[..], target);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H, L> Research for EpsilonBoost<'_, H, L>
+    where H: Classifier + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -8,9 +8,14 @@ use super::qp_model::QPModel;
 #[cfg(feature="gurobi")]
 use super::gurobi_qp_model::QPModel;
 
+#[cfg(feature="gurobi")]
+use crate::common::gurobi_config::GurobiConfig;
+
 use crate::{
     Sample,
     Booster,
+    Resumable,
+    Certificate,
     WeakLearner,
 
     Classifier,
@@ -18,6 +23,7 @@ use crate::{
     common::utils,
     common::checker,
     research::Research,
+    booster::lpboost::soft_margin_weights_on,
 };
 
 
@@ -25,6 +31,12 @@ use std::cell::RefCell;
 use std::ops::ControlFlow;
 
 
+/// The smallest accuracy of the sub-problems.
+const MIN_SUB_TOLERANCE: f64 = 1e-9;
+/// The ratio of the adaptive sub-tolerance to the optimality gap.
+const SUB_TOLERANCE_RATIO: f64 = 1e-3;
+
+
 
 /// The `ERLPBoost` algorithm proposed in the following paper: 
 /// 
@@ -48,6 +60,14 @@ use std::ops::ControlFlow;
 /// # Convergence rate
 /// - `ERLPBoost` terminates in `O( ln(m/ν) / ε² )` iterations.
 ///
+/// # Instance weights
+/// If the training sample has instance weights
+/// (see [`Sample::with_weights`]),
+/// `ERLPBoost` normalizes them to a distribution `w`,
+/// caps the `i`-th example by `m w_{i} / ν`,
+/// and regularizes by the relative entropy from `w`
+/// instead of the one from the uniform distribution.
+///
 /// # Related information
 /// - Every round, `ERLPBoost` solves a convex program
 ///   by the sequential quadratic minimization technique.
@@ -128,15 +148,37 @@ pub struct ERLPBoost<'a, F> {
 
     qp_model: Option<RefCell<QPModel>>,
 
+
+    // Parameters of the Gurobi environment.
+    #[cfg(feature="gurobi")]
+    gurobi: GurobiConfig,
+
     hypotheses: Vec<F>,
     weights: Vec<f64>,
 
 
-    // an accuracy parameter for the sub-problems
+    // an accuracy parameter for the sub-problems.
+    // `None` means the adaptive one.
+    sub_tolerance: Option<f64>,
+
+
     n_sample: usize,
     nu: f64,
 
 
+    // Annealing schedule of the capping parameter,
+    // the initial value and the number of rounds.
+    anneal: Option<(f64, usize)>,
+
+
+    // Capping parameter of the current QP.
+    current_nu: f64,
+
+
+    // Number of hypotheses added to the QP per round.
+    columns_per_round: usize,
+
+
     terminated: usize,
 
     max_iter: usize,
@@ -164,7 +206,7 @@ impl<'a, F> ERLPBoost<'a, F> {
 
         // Set gamma_hat and gamma_star
         let gamma_hat  = 1.0;
-        let gamma_star = f64::MIN;
+        let gamma_star = -1.0;
 
 
         ERLPBoost {
@@ -176,6 +218,10 @@ impl<'a, F> ERLPBoost<'a, F> {
             eta,
             half_tolerance,
             qp_model: None,
+            sub_tolerance: None,
+
+            #[cfg(feature="gurobi")]
+            gurobi: GurobiConfig::default(),
 
             hypotheses: Vec::new(),
             weights: Vec::new(),
@@ -183,6 +229,9 @@ impl<'a, F> ERLPBoost<'a, F> {
 
             n_sample,
             nu: 1.0,
+            anneal: None,
+            current_nu: 1.0,
+            columns_per_round: 1,
 
             terminated: usize::MAX,
             max_iter: usize::MAX,
@@ -191,13 +240,29 @@ impl<'a, F> ERLPBoost<'a, F> {
 
 
     fn init_solver(&mut self) {
-        checker::check_nu(self.nu, self.n_sample);
+        self.current_nu = self.nu_at(1);
+        self.eta = self.eta_for(self.current_nu);
+        self.init_solver_with(self.current_nu);
+    }
 
 
-        let upper_bound = 1.0 / self.nu;
-        let qp_model = RefCell::new(QPModel::init(
-            self.eta, self.n_sample, upper_bound
-        ));
+    /// Initializes the QP solver with the capping parameter `nu`.
+    fn init_solver_with(&mut self, nu: f64) {
+        checker::check_nu(nu, self.n_sample);
+
+
+        let upper_bound = 1.0 / nu;
+        #[cfg(not(feature="gurobi"))]
+        let mut qp_model = QPModel::init(self.eta, self.n_sample, upper_bound);
+        #[cfg(feature="gurobi")]
+        let mut qp_model = QPModel::init_with_config(
+            self.eta, self.n_sample, upper_bound, &self.gurobi
+        );
+        if self.sample.weights().is_some() {
+            let weights = self.sample.weight_distribution();
+            qp_model.set_example_weights(&weights[..]);
+        }
+        let qp_model = RefCell::new(qp_model);
 
         self.qp_model = Some(qp_model);
     }
@@ -225,6 +290,31 @@ impl<'a, F> ERLPBoost<'a, F> {
     }
 
 
+    /// Returns the current pair of `gamma_hat` and `gamma_star`.
+    /// Here, `gamma_star` is the optimal value of
+    /// the entropy-regularized edge minimization problem,
+    /// so `ERLPBoost` terminates once
+    /// the gap `gamma_hat - gamma_star` is at most `tolerance / 2`.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn certificate(&self) -> Certificate {
+        Certificate {
+            gamma_hat: self.gamma_hat,
+            gamma_star: self.gamma_star,
+        }
+    }
+
+
+    /// Returns the current optimality gap `gamma_hat - gamma_star`.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn gap(&self) -> f64 {
+        self.certificate().gap()
+    }
+
+
     /// Set the tolerance parameter.
     /// 
     /// Time complexity: `O(1)`.
@@ -235,15 +325,144 @@ impl<'a, F> ERLPBoost<'a, F> {
     }
 
 
+    /// Set the accuracy of the sub-problems.
+    /// In each round, `ERLPBoost` minimizes
+    /// the quadratic approximations of the objective repeatedly
+    /// while the decrease of the optimal value is
+    /// greater than `sub_tolerance`.
+    ///
+    /// By default, the sub-tolerance is adaptive:
+    /// `ε_sub = gap / 1000`, clipped to `[1e-9, tolerance / 2]`,
+    /// where `gap` is the current optimality gap
+    /// (see [`ERLPBoost::gap`]).
+    /// Thus, the sub-problems are solved roughly
+    /// while the gap is large, and accurately near the termination.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn sub_tolerance(mut self, sub_tolerance: f64) -> Self {
+        assert!(
+            sub_tolerance > 0f64,
+            "The sub-tolerance must be positive"
+        );
+        self.sub_tolerance = Some(sub_tolerance);
+        self
+    }
+
+
+    /// Returns the accuracy of the sub-problems for the current round.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    fn current_sub_tolerance(&self) -> f64 {
+        match self.sub_tolerance {
+            Some(sub_tolerance) => sub_tolerance,
+            None => {
+                let upper = self.half_tolerance.max(MIN_SUB_TOLERANCE);
+                let gap = self.gap();
+                // `gamma_star` can be `NaN` while `dist` has zeros.
+                // In that case, solve the sub-problems accurately.
+                if !gap.is_finite() { return MIN_SUB_TOLERANCE; }
+                (SUB_TOLERANCE_RATIO * gap).clamp(MIN_SUB_TOLERANCE, upper)
+            },
+        }
+    }
+
+
+    /// Set the accuracy of the sub-problems to the QP model.
+    fn set_sub_tolerance(&self) {
+        let sub_tolerance = self.current_sub_tolerance();
+        self.qp_model.as_ref()
+            .expect("Failed to call `.as_ref()` to `self.qp_model`")
+            .borrow_mut()
+            .set_tolerance(sub_tolerance);
+    }
+
+
     /// Setter method of `self.eta`
     /// 
     /// Time complexity: `O(1)`.
     #[inline(always)]
     fn regularization_param(&mut self) {
-        let ln_n_sample = (self.n_sample as f64 / self.nu).ln();
+        self.eta = self.eta_for(self.nu);
+    }
+
+
+    /// Returns the regularization parameter
+    /// for the capping parameter `nu`.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    fn eta_for(&self, nu: f64) -> f64 {
+        let ln_n_sample = (self.n_sample as f64 / nu).ln();
+
+
+        0.5_f64.max(ln_n_sample / self.half_tolerance)
+    }
+
+
+    /// Anneal the capping parameter
+    /// from `initial_nu` to the one set by [`ERLPBoost::nu`]
+    /// geometrically over the first `n_rounds` rounds.
+    /// Since a large capping parameter makes
+    /// the distribution over examples close to the uniform one,
+    /// early rounds become stable on noisy data.
+    /// Each time the capping parameter changes,
+    /// the QP is re-solved over the hypotheses obtained so far,
+    /// so the final solution is the one for the target capping parameter.
+    /// `ERLPBoost` does not terminate before the annealing finishes,
+    /// and the maximum iteration is extended by `n_rounds`.
+    /// If `initial_nu` is smaller than the target,
+    /// the annealing is disabled.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn anneal_nu(mut self, initial_nu: f64, n_rounds: usize) -> Self {
+        checker::check_nu(initial_nu, self.n_sample);
+        self.anneal = Some((initial_nu, n_rounds));
+        self
+    }
 
 
-        self.eta = 0.5_f64.max(ln_n_sample / self.half_tolerance);
+    /// Add at most `k` hypotheses to the QP per round.
+    /// The hypotheses are obtained by
+    /// [`WeakLearner::produce_many`],
+    /// and the sub-problems are solved only once per round.
+    /// Since the QP gets more columns per solve,
+    /// `ERLPBoost` often terminates in fewer rounds.
+    /// Note that the weak learners that do not override
+    /// [`WeakLearner::produce_many`] return a single hypothesis.
+    /// By default, one hypothesis is added per round.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn columns_per_round(mut self, k: usize) -> Self {
+        assert!(k > 0, "The number of columns must be positive");
+        self.columns_per_round = k;
+        self
+    }
+
+
+    /// Set the parameters of the Gurobi environment
+    /// used to solve the sub-problems.
+    /// By default, `ERLPBoost` silences the solver output,
+    /// sets the numeric focus to `3`,
+    /// and uses the defaults of Gurobi for the others.
+    ///
+    /// Time complexity: `O(1)`.
+    #[cfg(feature="gurobi")]
+    pub fn gurobi_config(mut self, config: GurobiConfig) -> Self {
+        self.gurobi = config;
+        self
+    }
+
+
+    /// Returns the capping parameter used at `iteration`.
+    fn nu_at(&self, iteration: usize) -> f64 {
+        match self.anneal {
+            Some((initial_nu, n_rounds)) if initial_nu > self.nu => {
+                utils::annealed_nu(initial_nu, self.nu, n_rounds, iteration)
+            },
+            _ => self.nu,
+        }
     }
 
 
@@ -272,15 +491,20 @@ impl<'a, F> ERLPBoost<'a, F> {
 impl<F> ERLPBoost<'_, F>
     where F: Classifier
 {
-    /// Update `self.gamma_hat`.
+    /// Update `self.gamma_hat`
+    /// by the best edge among `hypotheses`.
     /// `self.gamma_hat` holds the minimum value of the objective value.
     /// 
-    /// Time complexity: `O(m)`, where `m` is the number of training examples.
+    /// Time complexity: `O(km)`,
+    /// where `k` is the number of hypotheses
+    /// and `m` is the number of training examples.
     #[inline]
-    fn update_gamma_hat_mut(&mut self, h: &F)
+    fn update_gamma_hat_mut(&mut self, hypotheses: &[F])
     {
-        let edge = utils::edge_of_hypothesis(self.sample, &self.dist[..], h);
-        let entropy = utils::entropy_from_uni_distribution(&self.dist[..]);
+        let edge = hypotheses.iter()
+            .map(|h| utils::edge_of_hypothesis(self.sample, &self.dist[..], h))
+            .fold(f64::MIN, f64::max);
+        let entropy = self.relative_entropy();
 
         let obj_val = edge + (entropy / self.eta);
 
@@ -301,11 +525,26 @@ impl<F> ERLPBoost<'_, F>
             )
             .reduce(f64::max)
             .expect("Failed to compute the max-edge");
-        let entropy = utils::entropy_from_uni_distribution(&self.dist);
+        let entropy = self.relative_entropy();
         self.gamma_star = max_edge + (entropy / self.eta);
     }
 
 
+    /// Returns the relative entropy of `self.dist`
+    /// from the distribution proportional to the instance weights.
+    /// 
+    /// Time complexity: `O(m)`, where `m` is the number of training examples.
+    fn relative_entropy(&self) -> f64 {
+        match self.sample.weights() {
+            Some(_) => {
+                let prior = self.sample.weight_distribution();
+                utils::relative_entropy(&self.dist[..], &prior[..])
+            },
+            None => utils::entropy_from_uni_distribution(&self.dist[..]),
+        }
+    }
+
+
     /// Updates `self.dist`
     /// This method repeatedly minimizes the quadratic approximation of 
     /// ERLPB. objective around current distribution `self.dist`.
@@ -314,18 +553,119 @@ impl<F> ERLPBoost<'_, F>
     /// This method continues minimizing the quadratic objective 
     /// while the decrease of the optimal value is 
     /// greater than `self.sub_tolerance`.
-    fn update_distribution_mut(&mut self, clf: &F)
+    fn update_distribution_mut(&mut self, hypotheses: &[F])
     {
-        self.qp_model.as_ref()
-            .expect("Failed to call `.as_ref()` to `self.qp_model`")
-            .borrow_mut()
-            .update(self.sample, &mut self.dist[..], clf);
+        self.set_sub_tolerance();
+        {
+            let mut qp_model = self.qp_model.as_ref()
+                .expect("Failed to call `.as_ref()` to `self.qp_model`")
+                .borrow_mut();
+            match hypotheses {
+                [h] => qp_model.update(self.sample, &mut self.dist[..], h),
+                _ => qp_model.update_many(
+                    self.sample, &mut self.dist[..], hypotheses
+                ),
+            }
+        }
 
         self.dist = self.qp_model.as_ref()
             .expect("Failed to call `.as_ref()` to `self.qp_model`")
             .borrow()
             .distribution();
     }
+
+
+    /// Obtains the hypotheses added to the QP in this round.
+    fn produce_columns<W>(&self, weak_learner: &W) -> Vec<F>
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let k = self.columns_per_round;
+        if k == 1 {
+            return vec![weak_learner.produce(self.sample, &self.dist[..])];
+        }
+        let hypotheses = weak_learner.produce_many(
+            self.sample, &self.dist[..], k
+        );
+        assert!(
+            !hypotheses.is_empty(),
+            "The weak learner returned no hypothesis"
+        );
+        hypotheses
+    }
+}
+
+
+impl<F> ERLPBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    /// Choose the capping parameter `nu` from `grid`
+    /// by the performance on `validation`.
+    /// `ERLPBoost` is trained for each `nu` in `grid` in ascending order.
+    /// The training for each `nu` is warm-started
+    /// with the hypotheses obtained for the previous `nu`.
+    ///
+    /// This method returns the chosen `nu` and
+    /// the combined hypothesis trained with it.
+    /// The model with the best validation accuracy is chosen;
+    /// ties are broken by the average margin on `validation`.
+    /// After the call, `self` holds the chosen `nu`.
+    /// See [`LPBoost::auto_nu`](crate::booster::LPBoost::auto_nu)
+    /// for an example.
+    pub fn auto_nu<W>(
+        &mut self,
+        weak_learner: &W,
+        validation: &Sample,
+        grid: &[f64],
+    ) -> (f64, WeightedMajority<F>)
+        where W: WeakLearner<Hypothesis = F>
+    {
+        assert!(!grid.is_empty(), "The grid of `nu` is empty");
+        let mut grid = grid.to_vec();
+        grid.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        grid.iter().for_each(|&nu| checker::check_nu(nu, self.n_sample));
+
+        let mut pool = Vec::new();
+        let mut best: Option<((f64, f64), f64, WeightedMajority<F>)> = None;
+        for nu in grid {
+            self.nu = nu;
+            self.preprocess(weak_learner);
+            if !pool.is_empty() {
+                self.warm_start(pool);
+            }
+
+            let _ = (1..).try_for_each(|iter| {
+                self.boost(weak_learner, iter)
+            });
+            let f = self.postprocess(weak_learner);
+            pool = self.hypotheses.clone();
+
+            let score = utils::validation_score(validation, &f);
+            let is_better = best.as_ref()
+                .is_none_or(|(best_score, _, _)| score > *best_score);
+            if is_better {
+                best = Some((score, nu, f));
+            }
+        }
+
+        let (_, nu, f) = best.unwrap();
+        self.nu = nu;
+        self.regularization_param();
+        (nu, f)
+    }
+
+
+    /// Add `hypotheses` to the QP
+    /// and update the distribution over the training examples.
+    fn warm_start(&mut self, hypotheses: Vec<F>) {
+        self.set_sub_tolerance();
+        let qp_model = self.qp_model.as_ref()
+            .expect("Failed to call `.as_ref()` to `self.qp_model`");
+        qp_model.borrow_mut()
+            .update_many(self.sample, &mut self.dist[..], &hypotheses[..]);
+        self.dist = qp_model.borrow().distribution();
+        self.hypotheses = hypotheses;
+        self.update_gamma_star_mut();
+    }
 }
 
 
@@ -344,12 +684,28 @@ impl<F> Booster<F> for ERLPBoost<'_, F>
         let (n_sample, n_feature) = self.sample.shape();
         let ratio = self.nu * 100f64 / n_sample as f64;
         let nu = utils::format_unit(self.nu);
+        let anneal = match self.anneal {
+            Some((initial_nu, n_rounds)) => {
+                let initial_nu = utils::format_unit(initial_nu);
+                format!("{initial_nu} -> {nu} ({n_rounds} rounds)")
+            },
+            None => "-".to_string(),
+        };
+        let sub_tolerance = self.sub_tolerance
+            .map(|tol| format!("{tol}"))
+            .unwrap_or("adaptive".to_string());
         let info = Vec::from([
             ("# of examples", format!("{n_sample}")),
             ("# of features", format!("{n_feature}")),
             ("Tolerance", format!("{}", 2f64 * self.half_tolerance)),
             ("Max iteration", format!("{}", self.max_iter)),
-            ("Capping (outliers)", format!("{nu} ({ratio: >7.3} %)"))
+            ("Capping (outliers)", format!("{nu} ({ratio: >7.3} %)")),
+            ("Capping schedule", anneal),
+            ("Sub-problem tolerance", sub_tolerance),
+            ("Columns per round", format!("{}", self.columns_per_round)),
+            ("Optimality gap", format!("{}", self.certificate())),
+            #[cfg(feature="gurobi")]
+            ("Gurobi", format!("{}", self.gurobi)),
         ]);
         Some(info)
     }
@@ -362,12 +718,12 @@ impl<F> Booster<F> for ERLPBoost<'_, F>
         where W: WeakLearner<Hypothesis = F>
     {
         self.sample.is_valid_binary_instance();
-        let n_sample = self.sample.shape().0;
-        let uni = 1.0 / n_sample as f64;
-
-        self.dist = vec![uni; n_sample];
+        self.dist = self.sample.weight_distribution();
 
         self.max_iter = self.max_loop();
+        if let Some((initial_nu, n_rounds)) = self.anneal {
+            if initial_nu > self.nu { self.max_iter += n_rounds; }
+        }
         self.terminated = self.max_iter;
 
         self.hypotheses = Vec::new();
@@ -393,17 +749,42 @@ impl<F> Booster<F> for ERLPBoost<'_, F>
             return ControlFlow::Break(self.max_iter);
         }
 
-        // Receive a hypothesis from the base learner
-        let h = weak_learner.produce(self.sample, &self.dist[..]);
-
+        // Receive the hypotheses from the base learner
+        let hypotheses = self.produce_columns(weak_learner);
+
+
+        // If the capping parameter is annealed,
+        // re-solve the QP over all the hypotheses.
+        // Since `gamma_hat` depends on the regularization parameter,
+        // it is reset as well.
+        let nu = self.nu_at(iteration);
+        if nu != self.current_nu {
+            self.current_nu = nu;
+            self.eta = self.eta_for(nu);
+            self.init_solver_with(nu);
+            self.set_sub_tolerance();
+            self.hypotheses.extend(hypotheses);
+            self.qp_model.as_ref()
+                .expect("Failed to call `.as_ref()` to `self.qp_model`")
+                .borrow_mut()
+                .update_many(
+                    self.sample, &mut self.dist[..], &self.hypotheses[..]
+                );
+            self.dist = self.qp_model.as_ref()
+                .expect("Failed to call `.as_ref()` to `self.qp_model`")
+                .borrow()
+                .distribution();
+            self.gamma_hat = 1.0;
+            self.update_gamma_star_mut();
+            return ControlFlow::Continue(());
+        }
 
         // update `self.gamma_hat`
-        self.update_gamma_hat_mut(&h);
+        self.update_gamma_hat_mut(&hypotheses);
 
 
         // Check the stopping criterion
-        let diff = self.gamma_hat - self.gamma_star;
-        if diff <= self.half_tolerance {
+        if self.certificate().is_within(self.half_tolerance) {
             self.terminated = iteration;
             return ControlFlow::Break(iteration);
         }
@@ -411,11 +792,11 @@ impl<F> Booster<F> for ERLPBoost<'_, F>
         // At this point, the stopping criterion is not satisfied.
 
         // Update the parameters
-        self.update_distribution_mut(&h);
+        self.update_distribution_mut(&hypotheses);
 
 
-        // Append a new hypothesis to `clfs`.
-        self.hypotheses.push(h);
+        // Append the new hypotheses to `clfs`.
+        self.hypotheses.extend(hypotheses);
 
 
         // update `self.gamma_star`.
@@ -431,16 +812,44 @@ impl<F> Booster<F> for ERLPBoost<'_, F>
     ) -> Self::Output
         where W: WeakLearner<Hypothesis = F>
     {
-        self.weights = self.qp_model.as_ref()
-            .expect("Failed to call `.as_ref()` to `self.qp_model`")
-            .borrow_mut()
-            .weight()
-            .collect::<Vec<_>>();
+        // The weights of the regularized problem attain
+        // the soft margin only within the tolerance in theory,
+        // and can be far from it in practice.
+        // So we re-solve the soft margin optimization
+        // over the obtained hypotheses.
+        self.weights = if self.hypotheses.is_empty() {
+            self.qp_model.as_ref()
+                .expect("Failed to call `.as_ref()` to `self.qp_model`")
+                .borrow_mut()
+                .weight()
+                .collect::<Vec<_>>()
+        } else {
+            soft_margin_weights_on(self.sample, &self.hypotheses[..], self.nu)
+        };
 
         WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
     }
 }
 
+impl<F> Resumable<F> for ERLPBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    fn n_rounds(&self) -> usize {
+        self.hypotheses.len()
+    }
+
+
+    fn resume(&mut self, n_rounds: usize) {
+        assert!(
+            self.qp_model.is_some(),
+            "`ERLPBoost::resume` is called before `ERLPBoost::run`"
+        );
+        self.max_iter = self.n_rounds() + n_rounds;
+        self.terminated = self.max_iter;
+    }
+}
+
+
 impl<H> Research for ERLPBoost<'_, H>
     where H: Classifier + Clone,
 {
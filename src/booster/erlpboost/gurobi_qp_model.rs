@@ -3,16 +3,19 @@ use grb::prelude::*;
 
 use crate::Sample;
 use crate::hypothesis::Classifier;
+use crate::common::gurobi_config::GurobiConfig;
 
 const QP_TOLERANCE: f64 = 1e-9;
 
 /// A linear programming model for edge minimization. 
 pub(super) struct QPModel {
     pub(self) eta: f64,
+    pub(self) tolerance: f64,
     pub(self) model: Model,
     pub(self) gamma: Var,
     pub(self) dist: Vec<Var>,
     pub(self) constrs: Vec<Constr>,
+    pub(self) prior: Option<Vec<f64>>,
 }
 
 
@@ -24,14 +27,23 @@ impl QPModel {
     pub(super) fn init(eta: f64, size: usize, upper_bound: f64)
         -> Self
     {
-        let mut env = Env::empty()
-            .expect("Failed to construct a new `Env` for ERLPBoost");
-        env.set(param::OutputFlag, 0)
-            .expect("Failed to set `param::OutputFlag` to `0`");
-        env.set(param::NumericFocus, 3)
-            .expect("Failed to set `NumericFocus` parameter to `3`");
-        let env = env.start()
-            .expect("Failed to construct a new `Env` for ERLPBoost");
+        Self::init_with_config(
+            eta, size, upper_bound, &GurobiConfig::default()
+        )
+    }
+
+
+    /// Initialize the QP model
+    /// with the Gurobi parameters `config`.
+    /// The numeric focus is `3` unless `config` sets it.
+    pub(super) fn init_with_config(
+        eta: f64,
+        size: usize,
+        upper_bound: f64,
+        config: &GurobiConfig,
+    ) -> Self
+    {
+        let env = config.env(Some(3));
 
         let mut model = Model::with_env("ERLPBoost", env)
             .expect("Failed to construct a new model for `ERLPBoost`");
@@ -60,14 +72,42 @@ impl QPModel {
 
         Self {
             eta,
+            tolerance: QP_TOLERANCE,
             model,
             gamma,
             dist,
             constrs: Vec::new(),
+            prior: None,
         }
     }
 
 
+    /// Regularize by the relative entropy from `weights`
+    /// and scale the upper bound on `d[i]` by `m weights[i]`,
+    /// where `weights` is a distribution over the examples.
+    pub(super) fn set_example_weights(&mut self, weights: &[f64]) {
+        assert_eq!(weights.len(), self.dist.len());
+        let n_examples = self.dist.len() as f64;
+        for (d, w) in self.dist.iter().zip(weights) {
+            let ub = self.model.get_obj_attr(attr::UB, d)
+                .expect("Failed to get the upper bound of `d[i]`");
+            self.model.set_obj_attr(attr::UB, d, ub * n_examples * w)
+                .expect("Failed to set the upper bound of `d[i]`");
+        }
+        self.model.update()
+            .expect("Failed to update the model after setting the bounds");
+        self.prior = Some(weights.to_vec());
+    }
+
+
+    /// Set the accuracy of the sub-problems.
+    /// The sequential quadratic approximation stops
+    /// once the decrease of the optimal value is at most `tolerance`.
+    pub(super) fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+
     /// Solve the edge minimization problem 
     /// over the hypotheses `h1, ..., ht` 
     /// and outputs the optimal value.
@@ -82,6 +122,32 @@ impl QPModel {
         // If we got a new hypothesis,
         // 1. append a constraint, and
         // 2. optimize the model.
+        self.push_constraint(sample, clf);
+        self.solve(dist);
+    }
+
+
+    /// Append all the hypotheses in `clfs` to the model
+    /// and solve the edge minimization problem only once.
+    pub(super) fn update_many<F>(
+        &mut self,
+        sample: &Sample,
+        dist: &mut [f64],
+        clfs: &[F],
+    )
+        where F: Classifier
+    {
+        assert!(!clfs.is_empty(), "No hypothesis is given");
+        clfs.iter()
+            .for_each(|clf| { self.push_constraint(sample, clf); });
+        self.solve(dist);
+    }
+
+
+    /// Append the constraint `edge of clf <= gamma`.
+    fn push_constraint<F>(&mut self, sample: &Sample, clf: &F)
+        where F: Classifier
+    {
         let edge = sample.target()
             .iter()
             .enumerate()
@@ -98,6 +164,12 @@ impl QPModel {
             self.model.add_constr(&name, c!(edge <= self.gamma))
                 .expect("Failed to add a new constraint `edge <= gamma`")
         );
+    }
+
+
+    /// Solve the edge minimization problem
+    /// over the hypotheses appended so far.
+    fn solve(&mut self, dist: &mut [f64]) {
         self.model.update()
             .expect("Failed to update the model after adding a new constraint");
 
@@ -106,11 +178,18 @@ impl QPModel {
 
         loop {
             // Set objective function
+            let n_examples = self.dist.len() as f64;
             let regularizer = dist.iter()
                 .copied()
                 .zip(self.dist.iter())
-                .map(|(d, &grb_d)| {
-                    let l_term = d.ln() * grb_d;
+                .enumerate()
+                .map(|(i, (d, &grb_d))| {
+                    // The relative entropy from `prior` is
+                    // `Σ_i d_i ln( d_i ) - Σ_i d_i ln( prior_i )`.
+                    let ln_prior = self.prior.as_ref()
+                        .map(|prior| (n_examples * prior[i]).ln())
+                        .unwrap_or(0f64);
+                    let l_term = (d.ln() - ln_prior) * grb_d;
                     let q_term = (0.5_f64 / d) * (grb_d * grb_d);
 
                     l_term + q_term
@@ -150,7 +229,7 @@ impl QPModel {
                 });
 
 
-            if any_zero || old_objval - objval < QP_TOLERANCE {
+            if any_zero || old_objval - objval < self.tolerance {
                 break;
             }
 
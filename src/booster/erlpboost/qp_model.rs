@@ -80,7 +80,9 @@ pub(super) struct QPModel {
     pub(self) weights: Vec<f64>,        // weight on hypothesis
     pub(self) dist: Vec<f64>,           // distribution over examples
     pub(self) cap_inv: f64,             // the capping parameter, `1/ν.`
+    pub(self) prior: Option<Vec<f64>>,  // distribution of instance weights
     pub(self) eta: f64,                 // regularization parameter
+    pub(self) tolerance: f64,           // accuracy of the sub-problems
 }
 
 
@@ -98,11 +100,30 @@ impl QPModel {
             weights:      Vec::with_capacity(0usize),
             dist:         Vec::with_capacity(0usize),
             cap_inv:      upper_bound,
+            prior:        None,
             eta,
+            tolerance:    QP_TOLERANCE,
         }
     }
 
 
+    /// Regularize by the relative entropy from `weights`
+    /// and scale the cap on `d_i` by `m weights[i]`,
+    /// where `weights` is a distribution over the examples.
+    pub(super) fn set_example_weights(&mut self, weights: &[f64]) {
+        assert_eq!(weights.len(), self.n_examples);
+        self.prior = Some(weights.to_vec());
+    }
+
+
+    /// Set the accuracy of the sub-problems.
+    /// The sequential quadratic approximation stops
+    /// once the decrease of the optimal value is at most `tolerance`.
+    pub(super) fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+
     /// Solve the edge minimization problem 
     /// over the hypotheses `h1, ..., ht` 
     /// and outputs the optimal value.
@@ -113,12 +134,44 @@ impl QPModel {
         clf: &F
     )
         where F: Classifier
+    {
+        self.push_column(sample, clf);
+        self.solve(dist);
+    }
+
+
+    /// Append all the hypotheses in `clfs` to the model
+    /// and solve the edge minimization problem only once.
+    pub(super) fn update_many<F>(
+        &mut self,
+        sample: &Sample,
+        dist: &mut [f64],
+        clfs: &[F]
+    )
+        where F: Classifier
+    {
+        assert!(!clfs.is_empty(), "No hypothesis is given");
+        clfs.iter()
+            .for_each(|clf| { self.push_column(sample, clf); });
+        self.solve(dist);
+    }
+
+
+    /// Append the margins of `clf` to the model.
+    fn push_column<F>(&mut self, sample: &Sample, clf: &F)
+        where F: Classifier
     {
         self.n_hypotheses += 1;
         let margins = utils::margins_of_hypothesis(sample, clf);
         self.margins.iter_mut()
             .zip(margins)
             .for_each(|(mvec, yh)| { mvec.push(yh); });
+    }
+
+
+    /// Solve the edge minimization problem
+    /// over the hypotheses appended so far.
+    fn solve(&mut self, dist: &mut [f64]) {
         let constraint_matrix = self.build_constraint_matrix();
         let sense = self.build_sense();
         let rhs = self.build_rhs();
@@ -126,11 +179,17 @@ impl QPModel {
 
         let mut old_objval = 1e3;
 
-        // Initialize `dist` as the uniform distribution.
-        dist.iter_mut()
-            .for_each(|di| {
-                *di = 1f64 / self.n_examples as f64;
-            });
+        // Initialize `dist` as the uniform distribution
+        // or the distribution of the instance weights.
+        match self.prior.as_ref() {
+            Some(prior) => { dist.copy_from_slice(&prior[..]); },
+            None => {
+                dist.iter_mut()
+                    .for_each(|di| {
+                        *di = 1f64 / self.n_examples as f64;
+                    });
+            },
+        }
         loop {
             let settings = DefaultSettingsBuilder::default()
                 .equilibrate_enable(true)
@@ -153,7 +212,7 @@ impl QPModel {
 
             let objval = solver.solution.obj_val;
             if !self.all_positive(solution) 
-                || old_objval - objval < QP_TOLERANCE
+                || old_objval - objval < self.tolerance
             {
                 self.dist = solver.solution.x[1..].to_vec();
                 let start = 1 + 2 * self.n_examples;
@@ -183,6 +242,17 @@ impl QPModel {
             .copied()
             .map(|di| (1f64 / self.eta) * di.ln());
         linear.extend(iter);
+
+        // The relative entropy from `prior` is
+        // `Σ_i d_i ln( d_i ) - Σ_i d_i ln( prior_i )`.
+        if let Some(prior) = self.prior.as_ref() {
+            let n_examples = self.n_examples as f64;
+            linear[1..].iter_mut()
+                .zip(prior)
+                .for_each(|(l, p)| {
+                    *l -= (1f64 / self.eta) * (n_examples * p).ln();
+                });
+        }
         linear
     }
 
@@ -283,7 +353,17 @@ impl QPModel {
         let mut rhs = Vec::with_capacity(n_constraints);
         rhs.push(1f64);
         rhs.extend(iter::repeat(0f64).take(self.n_examples));
-        rhs.extend(iter::repeat(self.cap_inv).take(self.n_examples));
+        match self.prior.as_ref() {
+            Some(prior) => {
+                let n_examples = self.n_examples as f64;
+                let caps = prior.iter()
+                    .map(|p| self.cap_inv * n_examples * p);
+                rhs.extend(caps);
+            },
+            None => {
+                rhs.extend(iter::repeat(self.cap_inv).take(self.n_examples));
+            },
+        }
         rhs.extend(iter::repeat(0f64).take(self.n_hypotheses));
         rhs
     }
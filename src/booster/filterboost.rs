@@ -0,0 +1,12 @@
+//! The FilterBoost algorithm proposed
+//! by Joseph K. Bradley and Robert E. Schapire.
+//! This algorithm is based on the paper: 
+//! [
+//! FilterBoost: Regression and Classification on Large Datasets
+//! ](https://proceedings.neurips.cc/paper/2007/hash/2ab56412b1163ee131e1246da0955bd1-Abstract.html)
+//! 
+//! FilterBoost draws the training examples by rejection sampling
+//! instead of maintaining a distribution over all the examples.
+pub mod filterboost_algorithm;
+
+pub use filterboost_algorithm::FilterBoost;
@@ -0,0 +1,382 @@
+//! Provides [`FilterBoost`] by Bradley and Schapire, 2007.
+use rand::prelude::*;
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::selection,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The FilterBoost algorithm
+/// proposed by Joseph K. Bradley and Robert E. Schapire.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// FilterBoost: Regression and Classification on Large Datasets
+/// ](https://proceedings.neurips.cc/paper/2007/hash/2ab56412b1163ee131e1246da0955bd1-Abstract.html)
+/// by Joseph K. Bradley and Robert E. Schapire.
+///
+/// FilterBoost is a boosting algorithm for binary classification
+/// that regards the training sample as an oracle of examples.
+/// Instead of maintaining a distribution over all the examples,
+/// `FilterBoost` draws an example `(x, y)` uniformly at random
+/// and accepts it with probability
+/// `q(x, y) = 1 / (1 + exp(y F(x)))`,
+/// where `F(x) = Σ_t α_t h_t (x)` is the current combined hypothesis.
+/// Thus, `FilterBoost` only evaluates `F` on the drawn examples.
+///
+/// At each round, `FilterBoost` filters
+/// `budget` examples for the weak learner
+/// and other `budget` examples to estimate the edge `γ` of
+/// the new hypothesis.
+/// The weak learner receives the distribution
+/// that is uniform over the filtered examples
+/// (with multiplicity) and zero on the others.
+/// The weight on the new hypothesis is
+/// `α = 0.5 ln( (1 + γ) / (1 - γ) )`.
+///
+/// Since the acceptance rate is at least half of
+/// the training error of `F`,
+/// `FilterBoost` terminates if the filter needs
+/// more than `4 budget / ε` draws,
+/// where `ε` is the tolerance parameter.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Filter 500 examples per round.
+/// let mut booster = FilterBoost::init(&sample)
+///     .budget(500)
+///     .tolerance(0.01)
+///     .force_quit_at(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct FilterBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The number of filtered examples per round.
+    budget: usize,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration of FilterBoost.
+    max_iter: usize,
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+
+    // The seed of the random number generator.
+    seed: u64,
+
+    // Random number generator for the filter.
+    rng: StdRng,
+
+    // The total number of examples drawn by the filter.
+    n_draws: usize,
+}
+
+
+impl<'a, F> FilterBoost<'a, F> {
+    /// Constructs a new instance of `FilterBoost`.
+    /// By default, `FilterBoost` filters
+    /// `min(m, 1000)` examples per round,
+    /// where `m` is the number of training examples.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        let n_sample = sample.shape().0;
+
+        Self {
+            sample,
+
+            budget: n_sample.min(1_000),
+            tolerance: 1.0 / (n_sample as f64 + 1.0),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: usize::MAX,
+            force_quit_at: None,
+            terminated: usize::MAX,
+
+            seed: selection::SEED,
+            rng: StdRng::seed_from_u64(selection::SEED),
+            n_draws: 0,
+        }
+    }
+
+
+    /// Set the number of examples filtered per round.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn budget(mut self, budget: usize) -> Self {
+        assert!(budget > 0, "The sampling budget must be positive");
+        self.budget = budget;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `FilterBoost` terminates if the acceptance rate of the filter
+    /// suggests that the training error is less than `tolerance`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0f64, "The tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Set the seed of the randomness for the filter.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the total number of examples drawn by the filter.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn n_draws(&self) -> usize {
+        self.n_draws
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the maximum number of draws
+    /// to filter `budget` examples.
+    fn max_draws(&self) -> usize {
+        (4f64 * self.budget as f64 / self.tolerance).ceil() as usize
+    }
+}
+
+
+impl<F> FilterBoost<'_, F>
+    where F: Classifier,
+{
+    /// Returns `F(x) = Σ_t α_t h_t (x)` for the example at `row`.
+    fn score(&self, row: usize) -> f64 {
+        self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .map(|(w, h)| w * h.confidence(self.sample, row))
+            .sum::<f64>()
+    }
+
+
+    /// Draws the examples by rejection sampling
+    /// until `budget` examples are accepted.
+    /// Returns `None` if the filter needs more than `self.max_draws()` draws.
+    fn filter(&mut self) -> Option<Vec<usize>> {
+        let n_sample = self.sample.shape().0;
+        let target = self.sample.target();
+        let max_draws = self.max_draws();
+
+        let mut accepted = Vec::with_capacity(self.budget);
+        let mut n_draws = 0;
+        while accepted.len() < self.budget {
+            if n_draws >= max_draws {
+                return None;
+            }
+            n_draws += 1;
+            self.n_draws += 1;
+
+            let row = self.rng.gen_range(0..n_sample);
+            let margin = target[row] * self.score(row);
+            let prob = 1f64 / (1f64 + margin.exp());
+            if self.rng.gen::<f64>() < prob {
+                accepted.push(row);
+            }
+        }
+        Some(accepted)
+    }
+}
+
+
+impl<F> Booster<F> for FilterBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "FilterBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Budget per round", format!("{}", self.budget)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        if let Some(it) = self.force_quit_at {
+            self.max_iter = it;
+        }
+        self.terminated = self.max_iter;
+
+        self.rng = StdRng::seed_from_u64(self.seed);
+        self.n_draws = 0;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // If the filter rejects too many examples,
+        // the current combined hypothesis is accurate enough.
+        let Some(rows) = self.filter() else {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        };
+
+
+        let n_sample = self.sample.shape().0;
+        let unit = 1f64 / rows.len() as f64;
+        let mut dist = vec![0f64; n_sample];
+        rows.into_iter()
+            .for_each(|i| { dist[i] += unit; });
+        let h = weak_learner.produce(self.sample, &dist[..]);
+
+
+        // Estimate the edge of `h` on the fresh filtered examples.
+        // The denominator `n + 1` keeps the estimation less than `1`.
+        let Some(rows) = self.filter() else {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        };
+        let target = self.sample.target();
+        let n_rows = rows.len() as f64;
+        let edge = rows.into_iter()
+            .map(|i| target[i] * h.confidence(self.sample, i))
+            .sum::<f64>()
+            / (n_rows + 1f64);
+
+
+        // If `h` has no advantage on the filtered examples,
+        // FilterBoost cannot make progress.
+        if edge <= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let alpha = 0.5 * ((1f64 + edge) / (1f64 - edge)).ln();
+        self.weights.push(alpha);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for FilterBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
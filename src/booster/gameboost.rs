@@ -0,0 +1,7 @@
+//! GameBoost module.
+//! This algorithm is based on the paper
+//! "Adaptive game playing using multiplicative weights"
+//! by Yoav Freund and Robert E. Schapire.
+pub mod gameboost_algorithm;
+
+pub use gameboost_algorithm::GameBoost;
@@ -0,0 +1,402 @@
+//! This file defines `GameBoost` based on the paper
+//! "Adaptive game playing using multiplicative weights"
+//! by Yoav Freund and Robert E. Schapire.
+//!
+use crate::{
+    Sample,
+    Booster,
+    WeakLearner,
+    Certificate,
+
+    Classifier,
+    WeightedMajority,
+    common::utils,
+    common::checker,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// `GameBoost` solves the boosting game,
+/// a two-player zero-sum game between the distribution over examples
+/// and the hypotheses,
+/// by the multiplicative weights update (MW)
+/// versus the best response.
+/// See the following paper:
+///
+/// [Yoav Freund and Robert E. Schapire - Adaptive game playing using multiplicative weights](https://doi.org/10.1006/game.1999.0738)
+///
+/// Given a set `{(x_{1}, y_{1}), (x_{2}, y_{2}), ..., (x_{m}, y_{m})}`
+/// of training examples,
+/// a capping parameters `ν ∈ [1, m]`, and
+/// an accuracy parameter `ε > 0`,
+/// `GameBoost` aims to find an `ε`-approximate solution of
+/// the soft margin optimization problem
+/// ```txt
+///  max  ρ - (1/ν) Σ_{i=1}^{m} ξ_{i}
+/// ρ,w,ξ
+/// s.t. y_{i} Σ_{h ∈ Δ_{H}} w_{h} h(x_{i}) ≥ ρ - ξ_{i},
+///                                         for all i ∈ [m],
+///      w ∈ Δ_{H},
+///      ξ ≥ 0.
+/// ```
+/// which is the value of the game
+/// `min_{d ∈ Δ_{m, ν}} max_{h ∈ H} Σ_i d_i y_i h(x_i)`.
+///
+/// At each round `t`,
+/// the example player plays the distribution
+/// ```txt
+/// d_t ∝ exp( - η Σ_{s < t} y_i h_s (x_i) ),
+/// ```
+/// projected onto the capped simplex `Δ_{m, ν}`,
+/// and the weak learner plays the best response `h_t` to `d_t`.
+/// The combined hypothesis is the average of `h_1, ..., h_T`.
+/// Unlike [`LPBoost`](crate::booster::LPBoost),
+/// `GameBoost` needs only vector arithmetic,
+/// so it serves as a fast approximate baseline
+/// to the LP-based soft margin boosters.
+///
+/// With [`GameBoost::optimistic`],
+/// the example player uses the optimistic update
+/// (Rakhlin and Sridharan, "Optimization, learning, and games
+/// with predictable sequences"),
+/// which counts the latest hypothesis twice:
+/// ```txt
+/// d_t ∝ exp( - η ( Σ_{s < t} y_i h_s (x_i) + y_i h_{t-1} (x_i) ) ).
+/// ```
+///
+/// `GameBoost` terminates once the gap between
+/// the minimum edge `γ̂` of the hypotheses so far
+/// and the soft margin objective `γ*` of the average
+/// is at most `ε` (see [`GameBoost::certificate`]).
+///
+/// # Convergence rate
+/// If the weak learner returns the best response,
+/// `GameBoost` terminates in `O( ln(m/ν) / ε² )` iterations.
+///
+/// # Example
+/// The following code shows a small example for running [`GameBoost`].
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+///
+/// // Get the number of training examples.
+/// let n_sample = sample.shape().0 as f64;
+///
+/// // Initialize `GameBoost` with the optimistic update.
+/// let mut booster = GameBoost::init(&sample)
+///     .tolerance(0.05)
+///     .nu(0.1 * n_sample)
+///     .optimistic(true);
+///
+/// // Set the weak learner with setting parameters.
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Edge)
+///     .build();
+///
+/// // Run `GameBoost` and obtain the resulting hypothesis `f`.
+/// let f = booster.run(&weak_learner);
+///
+/// println!("Optimality gap: {}", booster.certificate());
+/// ```
+pub struct GameBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Distribution over examples
+    dist: Vec<f64>,
+
+    // Learning rate of the multiplicative weights update
+    eta: f64,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Capping parameter
+    nu: f64,
+
+    // `true` if the example player uses the optimistic update.
+    optimistic: bool,
+
+    // The cumulative margins `Σ_s y_i h_s (x_i)`
+    // and the margins of the latest hypothesis.
+    margins: Vec<f64>,
+    last_margins: Vec<f64>,
+
+    // The minimum edge of the hypotheses so far
+    // and the soft margin objective of the average.
+    gamma_hat: f64,
+    gamma_star: f64,
+
+    hypotheses: Vec<F>,
+
+    max_iter: usize,
+    terminated: usize,
+}
+
+
+impl<'a, F> GameBoost<'a, F> {
+    /// Construct a new instance of `GameBoost`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            dist: Vec::new(),
+            eta: 0.005,
+            tolerance: 0.01,
+            nu: 1.0,
+            optimistic: false,
+
+            margins: Vec::new(),
+            last_margins: Vec::new(),
+
+            gamma_hat: 1.0,
+            gamma_star: -1.0,
+
+            hypotheses: Vec::new(),
+
+            max_iter: usize::MAX,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// This method updates the capping parameter.
+    /// This parameter must be in `[1, # of training examples]`.
+    /// Default is `1.0`, i.e., the hard margin optimization.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn nu(mut self, nu: f64) -> Self {
+        let n_sample = self.sample.shape().0;
+        checker::check_nu(nu, n_sample);
+        self.nu = nu;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// Default is `0.01`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0f64, "The tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Use the optimistic multiplicative weights update
+    /// for the distribution over examples.
+    /// Default is `false`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn optimistic(mut self, optimistic: bool) -> Self {
+        self.optimistic = optimistic;
+        self
+    }
+
+
+    /// Returns the maximum iteration of `GameBoost`
+    /// to find an `ε`-approximate solution.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_loop(&self) -> usize {
+        let m = self.sample.shape().0 as f64;
+        let ln_m = (m / self.nu).ln();
+        let max_iter = 8.0 * ln_m / self.tolerance.powi(2);
+
+        (max_iter.ceil() as usize).max(1)
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the current pair of `gamma_hat` and `gamma_star`.
+    /// `gamma_hat` is the minimum edge of the hypotheses so far
+    /// and `gamma_star` is the soft margin objective of their average.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn certificate(&self) -> Certificate {
+        Certificate {
+            gamma_hat: self.gamma_hat,
+            gamma_star: self.gamma_star,
+        }
+    }
+
+
+    /// Returns the soft margin objective
+    /// `min_{d ∈ Δ_{m, ν}} Σ_i d_i y_i f(x_i)`
+    /// of the average `f` of the hypotheses so far.
+    ///
+    /// Time complexity: `O(m ln(m))`.
+    fn soft_margin_objective(&self) -> f64 {
+        let n_rounds = self.hypotheses.len() as f64;
+        let mut margins = self.margins.iter()
+            .map(|yf| yf / n_rounds)
+            .collect::<Vec<_>>();
+        margins.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let ub = 1f64 / self.nu;
+        let mut weight_left = 1f64;
+        let mut objective = 0f64;
+        for yf in margins {
+            let d = ub.min(weight_left);
+            objective += d * yf;
+            weight_left -= d;
+            if weight_left <= 0f64 { break; }
+        }
+        objective
+    }
+
+
+    /// Updates `self.dist` by the multiplicative weights update.
+    fn update_distribution_mut(&mut self) {
+        let eta = self.eta;
+        if self.optimistic {
+            let margins = self.margins.iter()
+                .zip(&self.last_margins[..])
+                .map(|(s, yh)| s + yh);
+            self.dist = utils::exp_distribution_from_margins(
+                eta, self.nu, margins
+            );
+        } else {
+            let margins = self.margins.iter().copied();
+            self.dist = utils::exp_distribution_from_margins(
+                eta, self.nu, margins
+            );
+        }
+    }
+}
+
+
+impl<F> Booster<F> for GameBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "GameBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let ratio = self.nu * 100f64 / n_sample as f64;
+        let nu = utils::format_unit(self.nu);
+        let update = if self.optimistic { "Optimistic" } else { "Standard" };
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_loop())),
+            ("Capping (outliers)", format!("{nu} ({ratio: >7.3} %)")),
+            ("Update", update.to_string()),
+            ("Optimality gap", format!("{}", self.certificate())),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+        let uni = 1.0 / n_sample as f64;
+
+        self.dist = vec![uni; n_sample];
+        self.margins = vec![0f64; n_sample];
+        self.last_margins = vec![0f64; n_sample];
+
+        self.eta = self.tolerance / 2f64;
+        self.max_iter = self.max_loop();
+        self.terminated = self.max_iter;
+
+        self.gamma_hat = 1.0;
+        self.gamma_star = -1.0;
+        self.hypotheses = Vec::new();
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+        self.update_distribution_mut();
+
+        // The best response to the current distribution.
+        let h = weak_learner.produce(self.sample, &self.dist[..]);
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+        let edge = utils::inner_product(&margins[..], &self.dist[..]);
+        self.gamma_hat = self.gamma_hat.min(edge);
+
+        self.margins.iter_mut()
+            .zip(&margins[..])
+            .for_each(|(s, yh)| { *s += yh; });
+        self.last_margins = margins;
+        self.hypotheses.push(h);
+
+        self.gamma_star = self.soft_margin_objective();
+
+
+        if self.certificate().is_within(self.tolerance) {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for GameBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        let weights = vec![1f64; self.hypotheses.len()];
+        WeightedMajority::from_slices(&weights[..], &self.hypotheses[..])
+    }
+}
@@ -1,4 +1,10 @@
-//! Defines Gradient Boosting Machine.
+//! Defines Gradient Boosting Machine and its variants.
 pub mod gbm;
+pub mod dart;
+pub mod ordered_boost;
+pub mod rgf;
 
 pub use gbm::*;
+pub use dart::*;
+pub use ordered_boost::*;
+pub use rgf::*;
@@ -0,0 +1,386 @@
+//! Provides DART, the gradient boosting with dropouts,
+//! by Rashmi and Gilad-Bachrach, 2015.
+use rayon::prelude::*;
+use rand::prelude::*;
+
+use crate::{
+    common::loss_functions::*,
+    common::selection,
+    research::Research,
+    Sample,
+    Booster,
+    WeakLearner,
+    Regressor,
+    WeightedMajority
+};
+
+use std::ops::ControlFlow;
+
+
+/// DART (**D**ropouts meet Multiple **A**dditive **R**egression **T**rees)
+/// proposed in the following paper:
+///
+/// [K. V. Rashmi and Ran Gilad-Bachrach, 2015 - DART: Dropouts meet Multiple Additive Regression Trees](https://proceedings.mlr.press/v38/korlakaivinayak15.html)
+///
+/// `DART` is a variant of [`GBM`](crate::GBM)
+/// that mitigates the *over-specialization*,
+/// where the trees added at the later rounds
+/// affect the predictions of only a few examples.
+/// At each round, `DART` drops each of the trees obtained so far
+/// with probability `drop_rate`
+/// (at least one tree is dropped),
+/// and fits a new tree to the gradient of the loss
+/// at the scores of the remaining trees.
+/// With probability `skip_drop`,
+/// `DART` skips the dropout at that round
+/// and behaves the same as `GBM`.
+///
+/// Since both the dropped trees and the new one
+/// fit the same residuals,
+/// `DART` normalizes their weights.
+/// Let `k` be the number of the dropped trees and
+/// `α` be the coefficient of the new tree found by the line search.
+/// Then, the weight on the new tree is `α / (k + 1)`
+/// and the weights on the dropped trees are scaled by `k / (k + 1)`.
+///
+/// The combined hypothesis is the additive model
+/// `F(x) = Σ_t α_t f_t (x)`,
+/// so that the weights are not normalized.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Drop each tree with probability `0.1`,
+/// // and skip the dropout with probability `0.5`.
+/// let mut booster = DART::init_with_loss(&sample, GBMLoss::L2)
+///     .drop_rate(0.1)
+///     .skip_drop(0.5);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::L2)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct DART<'a, F, L> {
+    // Training data
+    sample: &'a Sample,
+
+
+    // Weights on hypotheses
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // The predictions of each hypothesis on the training examples.
+    // `hypothesis_predictions[t][i]` is the prediction of
+    // `hypotheses[t]` on the `i`-th example.
+    hypothesis_predictions: Vec<Vec<f64>>,
+
+
+    // Some struct that implements `LossFunction` trait
+    loss: L,
+
+
+    // The probability that each tree is dropped.
+    drop_rate: f64,
+
+    // The probability that the dropout is skipped at a round.
+    skip_drop: f64,
+
+
+    // Max iteration of DART.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+
+    // A prediction vector at a state.
+    predictions: Vec<f64>,
+
+
+    // The seed of the random number generator.
+    seed: u64,
+
+    // Random number generator for the dropouts.
+    rng: StdRng,
+}
+
+
+impl<'a, F, L> DART<'a, F, L> {
+    /// Initialize the `DART`.
+    /// By default, `DART` drops each tree with probability `0.1`
+    /// and never skips the dropout.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    pub fn init_with_loss(sample: &'a Sample, loss: L) -> Self {
+        let n_sample = sample.shape().0;
+        let predictions = vec![0.0; n_sample];
+
+        Self {
+            sample,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+            hypothesis_predictions: Vec::new(),
+
+            loss,
+
+            drop_rate: 0.1,
+            skip_drop: 0.0,
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            predictions,
+
+            seed: selection::SEED,
+            rng: StdRng::seed_from_u64(selection::SEED),
+        }
+    }
+
+
+    /// Set the Loss Type.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn loss(mut self, loss_type: L) -> Self {
+        self.loss = loss_type;
+        self
+    }
+
+
+    /// Set the probability `drop_rate ∈ [0, 1]`
+    /// that each tree is dropped at a round.
+    /// Default is `0.1`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn drop_rate(mut self, drop_rate: f64) -> Self {
+        assert!(
+            (0f64..=1f64).contains(&drop_rate),
+            "The drop rate must be in [0, 1]"
+        );
+        self.drop_rate = drop_rate;
+        self
+    }
+
+
+    /// Set the probability `skip_drop ∈ [0, 1]`
+    /// that the dropout is skipped at a round.
+    /// If `skip_drop = 1`, `DART` is the same as `GBM`.
+    /// Default is `0.0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn skip_drop(mut self, skip_drop: f64) -> Self {
+        assert!(
+            (0f64..=1f64).contains(&skip_drop),
+            "The skip probability must be in [0, 1]"
+        );
+        self.skip_drop = skip_drop;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+
+    /// Set the seed of the randomness for the dropouts.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the indices of the trees dropped at the current round.
+    /// If the dropout is not skipped,
+    /// at least one tree is dropped.
+    fn draw_dropped(&mut self) -> Vec<usize> {
+        let n_trees = self.hypotheses.len();
+        if n_trees == 0 || self.rng.gen::<f64>() < self.skip_drop {
+            return Vec::new();
+        }
+
+        let mut dropped = (0..n_trees)
+            .filter(|_| self.rng.gen::<f64>() < self.drop_rate)
+            .collect::<Vec<_>>();
+        if dropped.is_empty() && self.drop_rate > 0f64 {
+            dropped.push(self.rng.gen_range(0..n_trees));
+        }
+        dropped
+    }
+}
+
+
+impl<F, L> Booster<F> for DART<'_, F, L>
+    where F: Regressor + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "DART"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Loss", self.loss.name().to_string()),
+            ("Drop rate", format!("{}", self.drop_rate)),
+            ("Skip drop", format!("{}", self.skip_drop)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.loss.validate_target(self.sample.target());
+
+        let n_sample = self.sample.shape().0;
+
+        self.weights = Vec::with_capacity(self.max_iter);
+        self.hypotheses = Vec::with_capacity(self.max_iter);
+        self.hypothesis_predictions = Vec::with_capacity(self.max_iter);
+
+        self.terminated = self.max_iter;
+        self.predictions = vec![0.0; n_sample];
+
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Remove the contribution of the dropped trees from the scores.
+        let dropped = self.draw_dropped();
+        let mut dropped_scores = vec![0f64; self.predictions.len()];
+        for &t in dropped.iter() {
+            let w = self.weights[t];
+            dropped_scores.par_iter_mut()
+                .zip(&self.hypothesis_predictions[t][..])
+                .for_each(|(s, p)| { *s += w * p; });
+        }
+        let scores = self.predictions.iter()
+            .zip(&dropped_scores[..])
+            .map(|(s, d)| s - d)
+            .collect::<Vec<_>>();
+
+
+        // Get a new hypothesis
+        let h = weak_learner.produce(self.sample, &scores[..]);
+
+        let predictions = h.predict_all(self.sample);
+        let coef = self.loss.best_coefficient_at(
+            &scores[..], self.sample.target(), &predictions[..]
+        );
+
+        // If the best coefficient is zero,
+        // the newly-attained hypothesis `h` do nothing.
+        // Thus, we can terminate the boosting at this point.
+        if coef == 0.0 {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // Normalize the weights on the dropped trees and the new one.
+        let k = dropped.len() as f64;
+        let new_weight = coef / (k + 1f64);
+        let scale = k / (k + 1f64);
+        for &t in dropped.iter() {
+            self.weights[t] *= scale;
+        }
+
+
+        self.predictions.par_iter_mut()
+            .zip(scores)
+            .zip(dropped_scores)
+            .zip(&predictions[..])
+            .for_each(|(((p, s), d), q)| {
+                *p = s + scale * d + new_weight * q;
+            });
+
+        self.weights.push(new_weight);
+        self.hypotheses.push(h);
+        self.hypothesis_predictions.push(predictions);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<F, L> Research for DART<'_, F, L>
+    where F: Regressor + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<F>;
+    fn current_hypothesis(&self) -> Self::Output {
+        // `WeightedMajority::from_slices` normalizes the weights
+        // and removes the negative ones,
+        // so that we construct the additive model directly.
+        WeightedMajority {
+            weights: self.weights.clone(),
+            hypotheses: self.hypotheses.clone(),
+        }
+    }
+}
@@ -1,10 +1,16 @@
 //! Provides Gradient Boosting Machine ([`GBM`]) by Friedman, 2001.
 use rayon::prelude::*;
+use rand::prelude::*;
 
 use crate::{
     common::loss_functions::*,
+    common::checker,
+    common::selection,
+    booster::lpboost::soft_margin_weights,
+    research::Research,
     Sample,
     Booster,
+    Resumable,
     WeakLearner,
     Regressor,
     WeightedMajority
@@ -24,7 +30,77 @@ use std::ops::ControlFlow;
 /// (One can see GBM as coordinate descent algorithm,
 /// where each coordinate corresponds to some function in that space).
 /// 
-/// **Note.** Currently, I only implements GBM for regression.
+/// GBM works for both regression and binary classification.
+/// For binary classification with labels `y ∈ {-1, +1}`,
+/// use [`GBMLoss::LogLoss`] or [`GBMLoss::Exponential`]
+/// for both `GBM` and the weak learner.
+/// If the labels are noisy,
+/// the bounded losses [`GBMLoss::Savage`] and
+/// [`GBMLoss::TruncatedLogLoss`] are robust alternatives.
+/// The weak learner computes the Newton leaf values
+/// `- Σ g_i / (Σ h_i + λ)` from the gradient `g` and the Hessian `h`
+/// at the current scores,
+/// and `GBM` scales the new hypothesis by the Newton step.
+/// Then, [`WeightedMajority::predict_proba`] gives
+/// the probability that an example is positive.
+/// 
+/// For the nonnegative targets such as counts,
+/// use [`GBMLoss::Poisson`] or [`GBMLoss::Tweedie`].
+/// These losses regard the score `F` as the logarithm of the mean,
+/// and [`WeightedMajority::predict_mean`] gives the mean `exp(F)`.
+/// `GBM` panics if some target value is negative.
+/// 
+/// The combined hypothesis is the additive model
+/// `F(x) = Σ_t α_t f_t (x)`,
+/// so that the weights are not normalized.
+/// 
+/// [`GBM::shrinkage`] sets the shrinkage parameter
+/// (a.k.a. learning rate) `η ∈ (0, 1]`.
+/// `GBM` multiplies the coefficient of each hypothesis,
+/// found by the line search or the Newton step, by `η`.
+/// A small `η` often generalizes better
+/// but needs more rounds,
+/// so that increase [`GBM::max_iter`] accordingly.
+/// 
+/// For binary classification,
+/// [`GBM::fully_corrective_postprocess`] re-optimizes
+/// the coefficients after the boosting
+/// by the soft margin optimization
+/// (the one [`LPBoost`](crate::booster::LPBoost) solves)
+/// over the margins `y_i sign(α_t) f_t (x_i)`.
+/// The new coefficients keep the signs and the L1-norm of the old ones,
+/// and the hypotheses with zero coefficient are removed.
+/// 
+/// [`GBM::subsample`] turns `GBM` into
+/// the stochastic gradient boosting
+/// ([Friedman, 2002](https://doi.org/10.1016/S0167-9473(01)00065-2)).
+/// At each round, `GBM` draws a `fraction` of the training examples
+/// without replacement,
+/// and fits the weak learner and the coefficient on them.
+/// The loss on the other examples, *out-of-bag* examples,
+/// is an estimate of the test loss,
+/// which is reported by [`Research::out_of_bag_loss`].
+/// 
+/// [`GBM::goss`] turns on the
+/// *Gradient-based One-Side Sampling* (GOSS) of
+/// [LightGBM](https://proceedings.neurips.cc/paper/2017/hash/6449f44a102fde848669bdd9eb6b76fa-Abstract.html).
+/// At each round, `GBM` keeps the `top_rate` fraction of the examples
+/// with the largest absolute gradients,
+/// draws the `other_rate` fraction of the examples from the rest,
+/// and multiplies the gradients and the Hessians of the drawn examples
+/// by `(1 - top_rate) / other_rate`
+/// (see [`WeakLearner::produce_with_weights`]).
+/// Since the examples with small gradients are already well-fitted,
+/// GOSS reduces the cost per round
+/// without losing much accuracy.
+/// 
+/// If the training sample has instance weights
+/// (see [`Sample::with_weights`]),
+/// `GBM` minimizes the loss weighted by them.
+/// The gradients and the Hessians are multiplied by the weights
+/// (see [`WeakLearner::produce_with_weights`]),
+/// and so is the Newton step for the coefficient
+/// (see [`LossFunction::best_coefficient_with_weights`]).
 /// 
 /// 
 /// # Example
@@ -86,6 +162,31 @@ use std::ops::ControlFlow;
 ///
 /// println!("Training Loss is: {training_loss}");
 /// ```
+/// 
+/// The following code trains `GBM` on a binary sample.
+/// 
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// 
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// 
+/// let mut booster = GBM::init_with_loss(&sample, GBMLoss::LogLoss);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::LogLoss)
+///     .build();
+/// 
+/// let f = booster.run(&weak_learner);
+/// 
+/// // `probabilities[i]` is the probability that
+/// // the `i`-th example is positive.
+/// let probabilities = f.predict_proba_all(&sample, GBMLoss::LogLoss);
+/// ```
 pub struct GBM<'a, F, L> {
     // Training data
     sample: &'a Sample,
@@ -94,6 +195,12 @@ pub struct GBM<'a, F, L> {
     // Tolerance parameter
     tolerance: f64,
 
+    // Shrinkage parameter
+    shrinkage: f64,
+
+    // The capping parameter of the fully-corrective postprocess.
+    corrective_nu: Option<f64>,
+
     // Weights on hypotheses
     weights: Vec<f64>,
 
@@ -116,6 +223,27 @@ pub struct GBM<'a, F, L> {
 
     // A prediction vector at a state.
     predictions: Vec<f64>,
+
+
+    // The fraction of the examples used at each round.
+    subsample: f64,
+
+    // The seed of the random number generator.
+    seed: u64,
+
+    // Random number generator for the subsampling.
+    rng: StdRng,
+
+    // The loss on the out-of-bag examples at the last round.
+    oob_loss: Option<f64>,
+
+
+    // The parameters `(top_rate, other_rate)` of GOSS.
+    goss: Option<(f64, f64)>,
+
+
+    // The instance weights of `sample` scaled so that their mean is `1`.
+    instance_weights: Option<Vec<f64>>,
 }
 
 
@@ -133,6 +261,8 @@ impl<'a, F, L> GBM<'a, F, L>
         Self {
             sample,
             tolerance: 0.0,
+            shrinkage: 1.0,
+            corrective_nu: None,
 
             weights: Vec::new(),
             hypotheses: Vec::new(),
@@ -144,6 +274,15 @@ impl<'a, F, L> GBM<'a, F, L>
             terminated: usize::MAX,
 
             predictions,
+
+            subsample: 1f64,
+            seed: selection::SEED,
+            rng: StdRng::seed_from_u64(selection::SEED),
+            oob_loss: None,
+
+            goss: None,
+
+            instance_weights: None,
         }
     }
 }
@@ -168,11 +307,193 @@ impl<'a, F, L> GBM<'a, F, L> {
     }
 
 
+    /// Set the shrinkage parameter (a.k.a. learning rate)
+    /// `η ∈ (0, 1]`.
+    /// The coefficient of each hypothesis is multiplied by `η`.
+    /// Default is `1`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn shrinkage(mut self, eta: f64) -> Self {
+        assert!(
+            0f64 < eta && eta <= 1f64,
+            "The shrinkage parameter must be in (0, 1]"
+        );
+        self.shrinkage = eta;
+        self
+    }
+
+
+    /// Re-optimize the coefficients after the boosting
+    /// by the soft margin optimization with capping parameter
+    /// `nu ∈ [1, m]`,
+    /// where `m` is the number of training examples.
+    /// This option requires the labels `y ∈ {-1, +1}`.
+    /// By default, `GBM` returns the coefficients of the boosting.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn fully_corrective_postprocess(mut self, nu: f64) -> Self {
+        checker::check_nu(nu, self.sample.shape().0);
+        self.corrective_nu = Some(nu);
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
     /// Set the Loss Type.
     pub fn loss(mut self, loss_type: L) -> Self {
         self.loss = loss_type;
         self
     }
+
+
+    /// Set the fraction of the training examples
+    /// used to fit each weak hypothesis.
+    /// If `fraction < 1`,
+    /// `GBM` draws `⌈fraction * m⌉` examples without replacement
+    /// at each round,
+    /// where `m` is the number of training examples.
+    /// Default is `1.0`, which uses all the examples.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn subsample(mut self, fraction: f64) -> Self {
+        assert!(
+            0f64 < fraction && fraction <= 1f64,
+            "The subsampling fraction must be in (0, 1]"
+        );
+        self.subsample = fraction;
+        self
+    }
+
+
+    /// Turns on the Gradient-based One-Side Sampling (GOSS).
+    /// At each round, `GBM` keeps `⌈top_rate * m⌉` examples
+    /// with the largest absolute gradients
+    /// and draws `⌈other_rate * m⌉` examples from the rest
+    /// without replacement,
+    /// where `m` is the number of training examples.
+    /// The drawn examples are weighted by `(1 - top_rate) / other_rate`.
+    /// This option overrides [`GBM::subsample`].
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn goss(mut self, top_rate: f64, other_rate: f64) -> Self {
+        assert!(
+            top_rate > 0f64 && other_rate > 0f64,
+            "The sampling rates of GOSS must be positive"
+        );
+        assert!(
+            top_rate + other_rate <= 1f64,
+            "The sum of the sampling rates of GOSS must be at most 1"
+        );
+        self.goss = Some((top_rate, other_rate));
+        self
+    }
+
+
+    /// Set the seed of the randomness for the subsampling.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the indices of the examples used at the current round
+    /// and the ones of the out-of-bag examples.
+    fn draw_rows(&mut self) -> (Vec<usize>, Vec<usize>) {
+        let n_sample = self.sample.shape().0;
+        let mut rows = (0..n_sample).collect::<Vec<_>>();
+        if self.subsample >= 1f64 {
+            return (rows, Vec::new());
+        }
+
+        let n_bag = ((self.subsample * n_sample as f64).ceil() as usize)
+            .clamp(1, n_sample);
+        rows.shuffle(&mut self.rng);
+        let oob = rows.split_off(n_bag);
+        (rows, oob)
+    }
+}
+
+
+impl<F, L> GBM<'_, F, L>
+    where L: LossFunction,
+{
+    /// Returns the indices of the examples used at the current round,
+    /// the ones of the out-of-bag examples,
+    /// and the weights on the used examples
+    /// chosen by the Gradient-based One-Side Sampling.
+    fn draw_goss_rows(&mut self, top_rate: f64, other_rate: f64)
+        -> (Vec<usize>, Vec<usize>, Vec<f64>)
+    {
+        let n_sample = self.sample.shape().0;
+        let n_top = ((top_rate * n_sample as f64).ceil() as usize)
+            .min(n_sample);
+        let n_other = ((other_rate * n_sample as f64).ceil() as usize)
+            .min(n_sample - n_top);
+
+        let gradient = self.loss.gradient(
+            &self.predictions[..], self.sample.target()
+        );
+        let mut rows = (0..n_sample).collect::<Vec<_>>();
+        rows.sort_by(|&i, &j| {
+            gradient[j].abs().partial_cmp(&gradient[i].abs()).unwrap()
+        });
+
+
+        // Draw `n_other` examples from the ones with small gradients.
+        let mut rest = rows.split_off(n_top);
+        rest.shuffle(&mut self.rng);
+        let oob = rest.split_off(n_other);
+
+        let amplifier = (1f64 - top_rate) / other_rate;
+        let weights = std::iter::repeat_n(1f64, n_top)
+            .chain(std::iter::repeat_n(amplifier, n_other))
+            .collect::<Vec<_>>();
+        rows.extend(rest);
+        (rows, oob, weights)
+    }
+
+
+    /// Returns the best coefficient of the new hypothesis
+    /// for the loss weighted by `weights` if any.
+    fn best_coefficient(
+        &self,
+        scores: &[f64],
+        target: &[f64],
+        predictions: &[f64],
+        weights: Option<&[f64]>,
+    ) -> f64
+    {
+        match weights {
+            Some(weights) => self.loss.best_coefficient_with_weights(
+                scores, target, predictions, weights
+            ),
+            None => self.loss.best_coefficient_at(
+                scores, target, predictions
+            ),
+        }
+    }
 }
 
 
@@ -190,12 +511,19 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
 
     fn info(&self) -> Option<Vec<(&str, String)>> {
         let (n_sample, n_feature) = self.sample.shape();
+        let goss = match self.goss {
+            Some((top, other)) => format!("top {top}, other {other}"),
+            None => "-".to_string(),
+        };
         let info = Vec::from([
             ("# of examples", format!("{n_sample}")),
             ("# of features", format!("{n_feature}")),
             ("Tolerance", format!("{}", self.tolerance)),
             ("Loss", format!("{}", self.loss.name())),
+            ("Shrinkage", format!("{}", self.shrinkage)),
             ("Max iteration", format!("{}", self.max_iter)),
+            ("Subsample", format!("{}", self.subsample)),
+            ("GOSS", goss),
         ]);
         Some(info)
     }
@@ -207,6 +535,8 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
     )
         where W: WeakLearner<Hypothesis = F>
     {
+        self.loss.validate_target(self.sample.target());
+
         // Initialize parameters
         let n_sample = self.sample.shape().0;
 
@@ -216,6 +546,17 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
 
         self.terminated = self.max_iter;
         self.predictions = vec![0.0; n_sample];
+
+        self.rng = StdRng::seed_from_u64(self.seed);
+        self.oob_loss = None;
+
+        self.instance_weights = self.sample.weights()
+            .map(|_| {
+                self.sample.weight_distribution()
+                    .into_iter()
+                    .map(|w| w * n_sample as f64)
+                    .collect()
+            });
     }
 
 
@@ -231,13 +572,89 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
         }
 
 
-        // Get a new hypothesis
-        let h = weak_learner.produce(self.sample, &self.predictions[..]);
+        let (rows, oob, weights) = match self.goss {
+            Some((top_rate, other_rate)) => {
+                let (rows, oob, weights) = self.draw_goss_rows(
+                    top_rate, other_rate
+                );
+                (rows, oob, Some(weights))
+            },
+            None => {
+                let (rows, oob) = self.draw_rows();
+                (rows, oob, None)
+            },
+        };
+        let target = self.sample.target();
 
-        let predictions = h.predict_all(self.sample);
-        let coef = self.loss.best_coefficient(
-            &self.sample.target(), &predictions[..]
-        );
+
+        // Get a new hypothesis
+        let (h, coef, predictions) = if oob.is_empty() && weights.is_none() {
+            let instance_weights = self.instance_weights.as_deref();
+            let h = match instance_weights {
+                Some(instance_weights) => {
+                    weak_learner.produce_with_weights(
+                        self.sample, &self.predictions[..], instance_weights
+                    )
+                },
+                None => {
+                    weak_learner.produce(self.sample, &self.predictions[..])
+                },
+            };
+
+            let predictions = h.predict_all(self.sample);
+            let coef = self.best_coefficient(
+                &self.predictions[..], target, &predictions[..],
+                instance_weights,
+            );
+            (h, coef, predictions)
+        } else {
+            // Fit the new hypothesis and its coefficient
+            // on the subsample.
+            let n_bag = rows.len();
+            let ix = rows.iter()
+                .chain(&oob[..])
+                .copied()
+                .collect::<Vec<_>>();
+            let (bag, _) = self.sample.split(&ix[..], n_bag, ix.len());
+            let scores = rows.iter()
+                .map(|&i| self.predictions[i])
+                .collect::<Vec<_>>();
+            let bag_weights = self.instance_weights.as_ref()
+                .map(|instance_weights| {
+                    rows.iter()
+                        .map(|&i| instance_weights[i])
+                        .collect::<Vec<_>>()
+                });
+            let weights = match (weights, bag_weights.as_ref()) {
+                (Some(weights), Some(bag_weights)) => {
+                    let weights = weights.into_iter()
+                        .zip(bag_weights)
+                        .map(|(w, v)| w * v)
+                        .collect::<Vec<_>>();
+                    Some(weights)
+                },
+                (weights, None) => weights,
+                (None, bag_weights) => bag_weights.cloned(),
+            };
+            let h = match weights {
+                Some(weights) => {
+                    weak_learner.produce_with_weights(
+                        &bag, &scores[..], &weights[..]
+                    )
+                },
+                None => weak_learner.produce(&bag, &scores[..]),
+            };
+
+            let predictions = h.predict_all(self.sample);
+            let bag_predictions = rows.iter()
+                .map(|&i| predictions[i])
+                .collect::<Vec<_>>();
+            let coef = self.best_coefficient(
+                &scores[..], bag.target(), &bag_predictions[..],
+                bag_weights.as_deref(),
+            );
+            (h, coef, predictions)
+        };
 
         // If the best coefficient is zero,
         // the newly-attained hypothesis `h` do nothing.
@@ -246,6 +663,7 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
             self.terminated = iteration;
             return ControlFlow::Break(iteration);
         }
+        let coef = self.shrinkage * coef;
 
 
         self.weights.push(coef);
@@ -256,6 +674,15 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
             .zip(predictions)
             .for_each(|(p, q)| { *p += coef * q; });
 
+
+        // Estimate the loss on the out-of-bag examples.
+        if !oob.is_empty() {
+            let (scores, oob_target): (Vec<_>, Vec<_>) = oob.iter()
+                .map(|&i| (self.predictions[i], target[i]))
+                .unzip();
+            self.oob_loss = Some(self.loss.eval(&scores[..], &oob_target[..]));
+        }
+
         ControlFlow::Continue(())
     }
 
@@ -266,8 +693,88 @@ impl<F, L> Booster<F> for GBM<'_, F, L>
     ) -> Self::Output
         where W: WeakLearner<Hypothesis = F>
     {
-        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+        match self.corrective_nu {
+            Some(nu) if !self.hypotheses.is_empty() => {
+                self.fully_corrective_hypothesis(nu)
+            },
+            _ => self.current_hypothesis(),
+        }
+    }
+}
+
+
+impl<F, L> GBM<'_, F, L>
+    where F: Regressor + Clone,
+{
+    /// Returns the additive model whose coefficients are
+    /// re-optimized by the soft margin optimization
+    /// with capping parameter `nu`.
+    fn fully_corrective_hypothesis(&self, nu: f64) -> WeightedMajority<F> {
+        self.sample.is_valid_binary_instance();
+        let target = self.sample.target();
+
+
+        // Flip the hypotheses with negative coefficients
+        // so that the weights on them are nonnegative.
+        let margins = self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .map(|(a, h)| {
+                h.predict_all(self.sample)
+                    .into_iter()
+                    .zip(target)
+                    .map(|(p, y)| a.signum() * y * p)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let norm = self.weights.iter()
+            .map(|a| a.abs())
+            .sum::<f64>();
+
+
+        let (weights, hypotheses) = soft_margin_weights(margins, nu)
+            .into_iter()
+            .zip(self.weights.iter().zip(&self.hypotheses[..]))
+            .filter(|(w, _)| *w > 0f64)
+            .map(|(w, (a, h))| (a.signum() * w * norm, h.clone()))
+            .unzip();
+        WeightedMajority { weights, hypotheses }
+    }
+}
+
+
+impl<F, L> Research for GBM<'_, F, L>
+    where F: Regressor + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<F>;
+    fn current_hypothesis(&self) -> Self::Output {
+        // `WeightedMajority::from_slices` normalizes the weights
+        // and removes the negative ones,
+        // so that we construct the additive model directly.
+        WeightedMajority {
+            weights: self.weights.clone(),
+            hypotheses: self.hypotheses.clone(),
+        }
+    }
+
+
+    fn out_of_bag_loss(&self) -> Option<f64> {
+        self.oob_loss
     }
 }
 
 
+impl<F, L> Resumable<F> for GBM<'_, F, L>
+    where F: Regressor + Clone,
+          L: LossFunction,
+{
+    fn n_rounds(&self) -> usize {
+        self.hypotheses.len()
+    }
+
+
+    fn resume(&mut self, n_rounds: usize) {
+        self.max_iter = self.n_rounds() + n_rounds;
+        self.terminated = self.max_iter;
+    }
+}
@@ -0,0 +1,369 @@
+//! Provides the ordered boosting by Prokhorenkova et al., 2018.
+use rayon::prelude::*;
+use rand::prelude::*;
+
+use crate::{
+    common::loss_functions::*,
+    common::selection,
+    research::Research,
+    Sample,
+    Booster,
+    WeakLearner,
+    Regressor,
+    WeightedMajority
+};
+
+use std::ops::ControlFlow;
+
+
+/// The ordered boosting proposed in the following paper:
+///
+/// [Liudmila Prokhorenkova, Gleb Gusev, Aleksandr Vorobev, Anna Veronika Dorogush, and Andrey Gulin, 2018 - CatBoost: unbiased boosting with categorical features](https://proceedings.neurips.cc/paper/2018/hash/14491b756b3a51daac41c24863285549-Abstract.html)
+///
+/// [`GBM`](crate::GBM) computes the gradient on an example
+/// from the model trained on the same example,
+/// so that the gradients on the training examples are biased
+/// (*target leakage*).
+/// `OrderedBoost` removes this bias.
+/// First, `OrderedBoost` draws a random permutation `σ`
+/// of the training examples.
+/// Then, for each `k` with `2^k < m`,
+/// `OrderedBoost` maintains a *supporting model* `M_k`
+/// trained only on the first `2^k` examples in `σ`,
+/// where `m` is the number of training examples.
+/// The gradient on the example at position `p ≥ 1` in `σ`
+/// is computed from `M_k` with `2^k ≤ p < 2^(k+1)`,
+/// which does not see the example.
+/// The gradient on the first example is computed from the zero function.
+///
+/// At each round, `OrderedBoost` fits a new hypothesis
+/// to these ordered gradients
+/// and appends it to the combined hypothesis
+/// with the coefficient found by the line search.
+/// Further, each supporting model `M_k` is updated
+/// by a new hypothesis fitted on its prefix.
+/// Thus, each round calls the weak learner `O(log m)` times.
+///
+/// The combined hypothesis is the additive model
+/// `F(x) = Σ_t α_t f_t (x)`,
+/// so that the weights are not normalized.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = OrderedBoost::init_with_loss(&sample, GBMLoss::L2)
+///     .seed(1234);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::L2)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct OrderedBoost<'a, F, L> {
+    // Training data
+    sample: &'a Sample,
+
+
+    // Weights on hypotheses
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+
+    // Some struct that implements `LossFunction` trait
+    loss: L,
+
+
+    // Max iteration of OrderedBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+
+    // A prediction vector of the combined hypothesis at a state.
+    predictions: Vec<f64>,
+
+
+    // A random permutation of the training examples.
+    // `permutation[p]` is the example at position `p`.
+    permutation: Vec<usize>,
+
+    // The predictions of the supporting models.
+    // `fold_predictions[k][i]` is the prediction of `M_k`
+    // on the `i`-th example.
+    fold_predictions: Vec<Vec<f64>>,
+
+
+    // The seed of the random permutation.
+    seed: u64,
+}
+
+
+impl<'a, F, L> OrderedBoost<'a, F, L> {
+    /// Initialize the `OrderedBoost`.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    pub fn init_with_loss(sample: &'a Sample, loss: L) -> Self {
+        let n_sample = sample.shape().0;
+        let predictions = vec![0.0; n_sample];
+
+        Self {
+            sample,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            loss,
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            predictions,
+
+            permutation: Vec::new(),
+            fold_predictions: Vec::new(),
+
+            seed: selection::SEED,
+        }
+    }
+
+
+    /// Set the Loss Type.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn loss(mut self, loss_type: L) -> Self {
+        self.loss = loss_type;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+
+    /// Set the seed of the random permutation.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the number of the supporting models,
+    /// i.e., the number of `k`'s with `2^k < m`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn n_folds(&self) -> usize {
+        let n_sample = self.sample.shape().0;
+        if n_sample <= 1 {
+            0
+        } else {
+            (n_sample - 1).ilog2() as usize + 1
+        }
+    }
+
+
+    /// Returns the scores used to compute the ordered gradients.
+    /// The score on the example at position `p ≥ 1` is
+    /// the prediction of `M_k` with `2^k ≤ p < 2^(k+1)`.
+    fn ordered_scores(&self) -> Vec<f64> {
+        let mut scores = vec![0f64; self.permutation.len()];
+        for (p, &i) in self.permutation.iter().enumerate().skip(1) {
+            let k = p.ilog2() as usize;
+            scores[i] = self.fold_predictions[k][i];
+        }
+        scores
+    }
+}
+
+
+impl<F, L> OrderedBoost<'_, F, L>
+    where F: Regressor,
+          L: LossFunction,
+{
+    /// Updates the supporting model `M_k`
+    /// by a new hypothesis fitted on the first `2^k` examples.
+    fn update_fold<W>(&mut self, weak_learner: &W, k: usize)
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        let n_sample = self.sample.shape().0;
+        let n_prefix = (1usize << k).min(n_sample);
+
+        let (prefix, _) = self.sample.split(
+            &self.permutation[..], n_prefix, n_sample
+        );
+        let scores = self.permutation[..n_prefix].iter()
+            .map(|&i| self.fold_predictions[k][i])
+            .collect::<Vec<_>>();
+        let h = weak_learner.produce(&prefix, &scores[..]);
+
+        let predictions = h.predict_all(self.sample);
+        let prefix_predictions = self.permutation[..n_prefix].iter()
+            .map(|&i| predictions[i])
+            .collect::<Vec<_>>();
+        let coef = self.loss.best_coefficient_at(
+            &scores[..], prefix.target(), &prefix_predictions[..]
+        );
+
+        self.fold_predictions[k].par_iter_mut()
+            .zip(predictions)
+            .for_each(|(p, q)| { *p += coef * q; });
+    }
+}
+
+
+impl<F, L> Booster<F> for OrderedBoost<'_, F, L>
+    where F: Regressor + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "Ordered Boosting"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Loss", self.loss.name().to_string()),
+            ("# of supporting models", format!("{}", self.n_folds())),
+            ("Max iteration", format!("{}", self.max_iter)),
+            ("Seed", format!("{}", self.seed)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.loss.validate_target(self.sample.target());
+
+        let n_sample = self.sample.shape().0;
+
+        self.weights = Vec::with_capacity(self.max_iter);
+        self.hypotheses = Vec::with_capacity(self.max_iter);
+
+        self.terminated = self.max_iter;
+        self.predictions = vec![0.0; n_sample];
+
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        self.permutation = (0..n_sample).collect();
+        self.permutation.shuffle(&mut rng);
+
+        self.fold_predictions = vec![vec![0.0; n_sample]; self.n_folds()];
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Fit a new hypothesis to the ordered gradients.
+        let scores = self.ordered_scores();
+        let h = weak_learner.produce(self.sample, &scores[..]);
+
+        let predictions = h.predict_all(self.sample);
+        let coef = self.loss.best_coefficient_at(
+            &self.predictions[..], self.sample.target(), &predictions[..]
+        );
+
+        // If the best coefficient is zero,
+        // the newly-attained hypothesis `h` do nothing.
+        // Thus, we can terminate the boosting at this point.
+        if coef == 0.0 {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+
+        self.predictions.par_iter_mut()
+            .zip(predictions)
+            .for_each(|(p, q)| { *p += coef * q; });
+
+        self.weights.push(coef);
+        self.hypotheses.push(h);
+
+
+        // Update the supporting models.
+        for k in 0..self.n_folds() {
+            self.update_fold(weak_learner, k);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<F, L> Research for OrderedBoost<'_, F, L>
+    where F: Regressor + Clone,
+          L: LossFunction,
+{
+    type Output = WeightedMajority<F>;
+    fn current_hypothesis(&self) -> Self::Output {
+        // `WeightedMajority::from_slices` normalizes the weights
+        // and removes the negative ones,
+        // so that we construct the additive model directly.
+        WeightedMajority {
+            weights: self.weights.clone(),
+            hypotheses: self.hypotheses.clone(),
+        }
+    }
+}
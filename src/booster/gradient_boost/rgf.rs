@@ -0,0 +1,414 @@
+//! Provides the Regularized Greedy Forest ([`RGF`])
+//! by Johnson and Zhang, 2014.
+use rayon::prelude::*;
+
+use crate::{
+    common::loss_functions::*,
+    research::Research,
+    Sample,
+    Booster,
+    WeakLearner,
+    RegressionTreeRegressor,
+    WeightedMajority
+};
+
+use std::ops::ControlFlow;
+
+
+/// The Regularized Greedy Forest (RGF) proposed in the following paper:
+///
+/// [Rie Johnson and Tong Zhang, 2014 - Learning Nonlinear Functions Using Regularized Greedy Forest](https://doi.org/10.1109/TPAMI.2013.159)
+///
+/// `RGF` is a variant of [`GBM`](crate::GBM)
+/// that optimizes the values of **all the leaves** of the current forest
+/// instead of the coefficient of the newest tree.
+/// Given the trees `f_1, f_2, ..., f_T`,
+/// the forest is the additive model
+/// `F(x) = Σ_t Σ_l v_{t,l} 1[x reaches the leaf l of f_t]`,
+/// and `RGF` minimizes the L2-regularized loss
+/// ```txt
+/// Σ_i loss(F(x_i), y_i) + (λ/2) Σ_t Σ_l v_{t,l}²
+/// ```
+/// over the leaf values `v`.
+///
+/// `RGF` alternates the following two steps.
+/// - **Structure search.**
+///   The weak learner grows a new tree
+///   on the gradient of the loss at the current scores.
+///   Only the structure of the tree is kept;
+///   its leaf values are reset to zero.
+/// - **Fully-corrective update.**
+///   `RGF` sweeps over the trees
+///   and updates the values of the leaves of each tree
+///   by the regularized Newton step
+///   `v ← v - (Σ_{i ∈ l} g_i + λ v) / (Σ_{i ∈ l} h_i + λ)`,
+///   where `g` and `h` are the gradient and the Hessian
+///   at the current scores.
+///   Since the leaves of a tree are disjoint,
+///   all the leaves of a tree are updated at once.
+///   `RGF` repeats the sweep [`RGF::corrective_steps`] times.
+///
+/// `RGF` terminates if the regularized loss decreases
+/// by at most the tolerance parameter at a round,
+/// or after `max_iter` rounds.
+/// Compared to `GBM`, each round is slower,
+/// but the earlier trees are refined by the later rounds,
+/// so that `RGF` often needs fewer and smaller trees.
+///
+/// The weak learner must be
+/// [`RegressionTree`](crate::weak_learner::RegressionTree)
+/// trained on the same loss as `RGF`.
+/// The combined hypothesis is the additive model
+/// whose weights are all `1`.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = RGF::init_with_loss(&sample, GBMLoss::L2)
+///     .l2_regularization(1.0)
+///     .corrective_steps(5);
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::L2)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct RGF<'a, L> {
+    // Training data
+    sample: &'a Sample,
+
+
+    // Trees obtained by the weak-learner.
+    trees: Vec<RegressionTreeRegressor>,
+
+    // The values of the leaves of each tree.
+    leaf_values: Vec<Vec<f64>>,
+
+    // The leaf of each tree that each example reaches.
+    // `leaf_indices[t][i]` is the index of the leaf of `trees[t]`
+    // that the `i`-th example reaches.
+    leaf_indices: Vec<Vec<usize>>,
+
+
+    // Some struct that implements `LossFunction` trait
+    loss: L,
+
+
+    // The L2-regularization parameter `λ` on the leaf values.
+    l2_regularization: f64,
+
+    // The number of sweeps over the trees at each round.
+    corrective_steps: usize,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+
+    // Max iteration of RGF.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+
+    // A prediction vector at a state.
+    predictions: Vec<f64>,
+
+    // The current regularized loss.
+    objective: f64,
+}
+
+
+impl<'a, L> RGF<'a, L> {
+    /// Initialize the `RGF`.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    pub fn init_with_loss(sample: &'a Sample, loss: L) -> Self {
+        let n_sample = sample.shape().0;
+        let predictions = vec![0.0; n_sample];
+
+        Self {
+            sample,
+
+            trees: Vec::new(),
+            leaf_values: Vec::new(),
+            leaf_indices: Vec::new(),
+
+            loss,
+
+            l2_regularization: 1.0,
+            corrective_steps: 1,
+            tolerance: 0.0,
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            predictions,
+            objective: f64::MAX,
+        }
+    }
+
+
+    /// Set the L2-regularization parameter `λ ≥ 0`
+    /// on the leaf values.
+    /// Default is `1.0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn l2_regularization(mut self, lambda: f64) -> Self {
+        assert!(
+            lambda >= 0f64,
+            "The L2-regularization parameter must be non-negative"
+        );
+        self.l2_regularization = lambda;
+        self
+    }
+
+
+    /// Set the number of sweeps over all the trees
+    /// in the fully-corrective update at each round.
+    /// Default is `1`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn corrective_steps(mut self, steps: usize) -> Self {
+        assert!(steps > 0, "The number of corrective steps must be positive");
+        self.corrective_steps = steps;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `RGF` terminates if the regularized loss decreases
+    /// by at most `tolerance` at a round.
+    /// Default is `0.0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the current regularized loss
+    /// `Σ_i loss(F(x_i), y_i) + (λ/2) Σ_t Σ_l v_{t,l}²`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn objective(&self) -> f64 {
+        self.objective
+    }
+
+
+    /// Returns the total number of leaves in the current forest.
+    ///
+    /// Time complexity: `O(T)`,
+    /// where `T` is the number of trees.
+    pub fn n_leaves(&self) -> usize {
+        self.leaf_values.iter()
+            .map(|values| values.len())
+            .sum::<usize>()
+    }
+}
+
+
+impl<L> RGF<'_, L>
+    where L: LossFunction,
+{
+    /// Returns the regularized loss at the current leaf values.
+    fn eval(&self) -> f64 {
+        let n_sample = self.sample.shape().0 as f64;
+        let loss = self.loss.eval(&self.predictions[..], self.sample.target());
+        let penalty = self.leaf_values.iter()
+            .flatten()
+            .map(|v| v * v)
+            .sum::<f64>();
+        n_sample * loss + 0.5 * self.l2_regularization * penalty
+    }
+
+
+    /// Updates the values of the leaves of all the trees
+    /// by the regularized Newton steps.
+    fn fully_corrective_update(&mut self) {
+        let target = self.sample.target();
+        let lambda = self.l2_regularization;
+        for _ in 0..self.corrective_steps {
+            for (values, indices) in self.leaf_values.iter_mut()
+                .zip(&self.leaf_indices[..])
+            {
+                let gradient = self.loss.gradient(&self.predictions[..], target);
+                let hessian = self.loss.hessian(&self.predictions[..], target);
+
+                let n_leaves = values.len();
+                let mut grad_sum = vec![0f64; n_leaves];
+                let mut hess_sum = vec![0f64; n_leaves];
+                indices.iter()
+                    .zip(gradient.into_iter().zip(hessian))
+                    .for_each(|(&l, (g, h))| {
+                        grad_sum[l] += g;
+                        hess_sum[l] += h;
+                    });
+
+                let deltas = values.iter_mut()
+                    .zip(grad_sum.into_iter().zip(hess_sum))
+                    .map(|(v, (g, h))| {
+                        let denom = h + lambda;
+                        if denom <= 0f64 { return 0f64; }
+                        let delta = - (g + lambda * *v) / denom;
+                        *v += delta;
+                        delta
+                    })
+                    .collect::<Vec<_>>();
+
+                self.predictions.par_iter_mut()
+                    .zip(&indices[..])
+                    .for_each(|(p, &l)| { *p += deltas[l]; });
+            }
+        }
+    }
+}
+
+
+impl<L> Booster<RegressionTreeRegressor> for RGF<'_, L>
+    where L: LossFunction,
+{
+    type Output = WeightedMajority<RegressionTreeRegressor>;
+
+
+    fn name(&self) -> &str {
+        "Regularized Greedy Forest"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Loss", self.loss.name().to_string()),
+            ("L2 regularization", format!("{}", self.l2_regularization)),
+            ("Corrective steps", format!("{}", self.corrective_steps)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = RegressionTreeRegressor>
+    {
+        self.loss.validate_target(self.sample.target());
+
+        let n_sample = self.sample.shape().0;
+
+        self.trees = Vec::with_capacity(self.max_iter);
+        self.leaf_values = Vec::with_capacity(self.max_iter);
+        self.leaf_indices = Vec::with_capacity(self.max_iter);
+
+        self.terminated = self.max_iter;
+        self.predictions = vec![0.0; n_sample];
+        self.objective = self.eval();
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = RegressionTreeRegressor>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Structure search.
+        let tree = weak_learner.produce(self.sample, &self.predictions[..]);
+        let n_sample = self.sample.shape().0;
+        let indices = (0..n_sample).into_par_iter()
+            .map(|i| tree.leaf_index(self.sample, i))
+            .collect::<Vec<_>>();
+        self.leaf_values.push(vec![0f64; tree.n_leaves()]);
+        self.leaf_indices.push(indices);
+        self.trees.push(tree);
+
+
+        // Fully-corrective update.
+        self.fully_corrective_update();
+
+
+        let objective = self.eval();
+        let decrease = self.objective - objective;
+        self.objective = objective;
+        if decrease <= self.tolerance {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = RegressionTreeRegressor>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<L> Research for RGF<'_, L>
+    where L: LossFunction,
+{
+    type Output = WeightedMajority<RegressionTreeRegressor>;
+    fn current_hypothesis(&self) -> Self::Output {
+        let hypotheses = self.trees.iter()
+            .zip(&self.leaf_values[..])
+            .map(|(tree, values)| {
+                let mut tree = tree.clone();
+                tree.set_leaf_values(&values[..]);
+                tree
+            })
+            .collect::<Vec<_>>();
+        WeightedMajority {
+            weights: vec![1f64; hypotheses.len()],
+            hypotheses,
+        }
+    }
+}
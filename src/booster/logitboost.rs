@@ -0,0 +1,12 @@
+//! The LogitBoost algorithm proposed
+//! by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+//! This algorithm is based on the paper: 
+//! [
+//! Additive Logistic Regression: A Statistical View of Boosting
+//! ](https://doi.org/10.1214/aos/1016218223)
+//! 
+//! LogitBoost fits an additive logistic model
+//! by the Newton steps on the logistic loss.
+pub mod logitboost_algorithm;
+
+pub use logitboost_algorithm::LogitBoost;
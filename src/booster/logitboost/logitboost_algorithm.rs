@@ -0,0 +1,317 @@
+//! Provides [`LogitBoost`] by Friedman, Hastie, and Tibshirani, 2000.
+use rayon::prelude::*;
+
+use crate::{
+    common::loss_functions::{GBMLoss, LossFunction},
+    Sample,
+    Booster,
+    Resumable,
+    WeakLearner,
+    Regressor,
+    LogitClassifier,
+
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The LogitBoost algorithm
+/// proposed by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Additive Logistic Regression: A Statistical View of Boosting
+/// ](https://doi.org/10.1214/aos/1016218223)
+/// by Jerome Friedman, Trevor Hastie, and Robert Tibshirani.
+///
+/// LogitBoost fits an additive logistic model
+/// `F(x) = Σ_t η f_t (x)` for the binary labels `y ∈ {-1, +1}`,
+/// where `η` is the shrinkage parameter
+/// and `F(x)` is the logit of `P(y = +1 | x)`.
+/// At each round, LogitBoost computes
+/// the working responses `z = (y* - p) / (p (1 - p))`
+/// and the weights `w = p (1 - p)`,
+/// where `y* = (y + 1) / 2` and `p = 1 / (1 + exp(-F(x)))`,
+/// and fits a regression weak learner `f_t`
+/// to `z` by weighted least squares.
+/// This is exactly the Newton step on the logistic loss,
+/// so that `LogitBoost` passes the current scores `F(x)`
+/// to the weak learner,
+/// and the weak learner must minimize [`GBMLoss::LogLoss`]
+/// by the Newton steps.
+/// [`RegressionTree`](crate::weak_learner::RegressionTree)
+/// built with `.loss(GBMLoss::LogLoss)` does it.
+///
+/// `LogitBoost` terminates
+/// if the decrease of the training logistic loss
+/// is less than the tolerance parameter,
+/// or after `max_iter` iterations.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = LogitBoost::init(&sample)
+///     .tolerance(1e-4)
+///     .max_iter(200)
+///     .shrinkage(0.1);
+///
+/// // The weak learner must be trained on the logistic loss.
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .loss(GBMLoss::LogLoss)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// let probabilities = f.probability_all(&sample);
+/// ```
+pub struct LogitBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Shrinkage parameter
+    shrinkage: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration of LogitBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+    // The current scores `F(x)` on the training examples.
+    scores: Vec<f64>,
+
+    // The current training logistic loss.
+    loss: f64,
+}
+
+
+impl<'a, F> LogitBoost<'a, F> {
+    /// Constructs a new instance of `LogitBoost`.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        let n_sample = sample.shape().0;
+
+        Self {
+            sample,
+
+            tolerance: 1e-6,
+            shrinkage: 1f64,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            scores: vec![0f64; n_sample],
+            loss: 2f64.ln(),
+        }
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `LogitBoost` terminates if the decrease of
+    /// the training logistic loss is less than `tolerance`.
+    /// Default is `1e-6`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance >= 0f64, "The tolerance must be non-negative");
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Set the shrinkage parameter (a.k.a. learning rate)
+    /// `η ∈ (0, 1]`.
+    /// Each hypothesis is added to the model with weight `η`.
+    /// Default is `1`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn shrinkage(mut self, eta: f64) -> Self {
+        assert!(
+            0f64 < eta && eta <= 1f64,
+            "The shrinkage parameter must be in (0, 1]"
+        );
+        self.shrinkage = eta;
+        self
+    }
+
+
+    /// Returns the current training logistic loss.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn logistic_loss(&self) -> f64 {
+        self.loss
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+}
+
+
+impl<F> Booster<F> for LogitBoost<'_, F>
+    where F: Regressor + Clone,
+{
+    type Output = LogitClassifier<F>;
+
+
+    fn name(&self) -> &str {
+        "LogitBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Shrinkage", format!("{}", self.shrinkage)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+        self.weights = Vec::with_capacity(self.max_iter);
+        self.hypotheses = Vec::with_capacity(self.max_iter);
+
+        self.terminated = self.max_iter;
+        self.scores = vec![0f64; n_sample];
+        self.loss = GBMLoss::LogLoss.eval(&self.scores, self.sample.target());
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // The weak learner fits the Newton step
+        // on the logistic loss at the current scores.
+        let h = weak_learner.produce(self.sample, &self.scores[..]);
+        let predictions = h.predict_all(self.sample);
+
+
+        // If `h` predicts zero for all the examples,
+        // `h` does not change the model.
+        if predictions.iter().all(|p| *p == 0f64) {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let eta = self.shrinkage;
+        self.scores.par_iter_mut()
+            .zip(predictions)
+            .for_each(|(s, p)| { *s += eta * p; });
+
+        self.weights.push(eta);
+        self.hypotheses.push(h);
+
+
+        let loss = GBMLoss::LogLoss.eval(&self.scores, self.sample.target());
+        let decrease = self.loss - loss;
+        self.loss = loss;
+        if decrease < self.tolerance {
+            self.terminated = iteration;
+            return ControlFlow::Break(iteration);
+        }
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<F> Resumable<F> for LogitBoost<'_, F>
+    where F: Regressor + Clone,
+{
+    fn n_rounds(&self) -> usize {
+        self.hypotheses.len()
+    }
+
+
+    fn resume(&mut self, n_rounds: usize) {
+        self.max_iter = self.n_rounds() + n_rounds;
+        self.terminated = self.max_iter;
+    }
+}
+
+
+impl<H> Research for LogitBoost<'_, H>
+    where H: Regressor + Clone,
+{
+    type Output = LogitClassifier<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        LogitClassifier::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -7,4 +7,7 @@ mod lp_model;
 #[cfg(feature="gurobi")]
 mod gurobi_lp_model;
 
+mod refit;
+
 pub use lpboost_algorithm::LPBoost;
+pub(crate) use refit::{soft_margin_weights, soft_margin_weights_on};
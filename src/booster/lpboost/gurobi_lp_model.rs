@@ -3,28 +3,50 @@ use grb::prelude::*;
 
 use crate::Sample;
 use crate::hypothesis::Classifier;
+use crate::common::gurobi_config::GurobiConfig;
 
 /// A linear programming model for edge minimization. 
+/// If the constraint aging is enabled (see [`LPModel::set_max_age`]),
+/// the constraints whose dual values have been zero
+/// for `k` consecutive solves are removed from the model,
+/// and re-inserted when they are violated.
 pub(super) struct LPModel {
     pub(self) model: Model,
     pub(self) gamma: Var,
     pub(self) dist: Vec<Var>,
-    pub(self) constrs: Vec<Constr>,
+    pub(self) constrs: Vec<Option<Constr>>,
+    pub(self) margins: Vec<Vec<f64>>,
+    pub(self) ages: Vec<usize>,
+    pub(self) max_age: Option<usize>,
 }
 
 
+/// The dual values below this value are regarded as zero.
+pub(super) const ZERO_WEIGHT: f64 = 1e-7;
+/// The tolerance of the constraint violation of the removed constraints.
+const VIOLATION_TOLERANCE: f64 = 1e-6;
+
+
 impl LPModel {
     /// Initialize the LP model.
     /// arguments.
     /// - `size`: Number of variables (Number of examples).
-    /// - `upper_bound`: Capping parameter. `[1, size]`.
+    /// - `upper_bound`: Upper bound of the distribution over examples,
+    ///   `1/ν` for the capping parameter `ν` in `[1, size]`.
     pub(super) fn init(size: usize, upper_bound: f64) -> Self {
-        let mut env = Env::empty()
-            .expect("Failed to construct a new `Env` for LPBoost");
-        env.set(param::OutputFlag, 0)
-            .expect("Failed to set `param::OutputFlag` to `0`");
-        let env = env.start()
-            .expect("Failed to construct a new `Env` for LPBoost");
+        Self::init_with_config(size, upper_bound, &GurobiConfig::default())
+    }
+
+
+    /// Initialize the LP model
+    /// with the Gurobi parameters `config`.
+    pub(super) fn init_with_config(
+        size: usize,
+        upper_bound: f64,
+        config: &GurobiConfig,
+    ) -> Self
+    {
+        let env = config.env(None);
 
         let mut model = Model::with_env("LPBoost", env)
             .expect("Failed to construct a new model for `MLPBoost`");
@@ -61,7 +83,40 @@ impl LPModel {
             gamma,
             dist,
             constrs: Vec::new(),
+            margins: Vec::new(),
+            ages: Vec::new(),
+            max_age: None,
+        }
+    }
+
+
+    /// Scale the upper bound on `d[i]` by `m weights[i]`,
+    /// where `weights` is a distribution over the examples.
+    pub(super) fn set_example_weights(&mut self, weights: &[f64]) {
+        assert_eq!(weights.len(), self.dist.len());
+        let n_examples = self.dist.len() as f64;
+        for (d, w) in self.dist.iter().zip(weights) {
+            let ub = self.model.get_obj_attr(attr::UB, d)
+                .expect("Failed to get the upper bound of `d[i]`");
+            self.model.set_obj_attr(attr::UB, d, ub * n_examples * w)
+                .expect("Failed to set the upper bound of `d[i]`");
         }
+        self.model.update()
+            .expect("Failed to update the model after setting the bounds");
+    }
+
+
+    /// Remove the constraints
+    /// whose dual values have been zero for `max_age` consecutive solves.
+    pub(super) fn set_max_age(&mut self, max_age: usize) {
+        assert!(max_age > 0, "The max age of the constraints must be positive");
+        self.max_age = Some(max_age);
+    }
+
+
+    /// Returns the number of the constraints in the model.
+    pub(super) fn n_active(&self) -> usize {
+        self.constrs.iter().filter(|c| c.is_some()).count()
     }
 
 
@@ -78,24 +133,136 @@ impl LPModel {
         // If we got a new hypothesis,
         // 1. append a constraint, and
         // 2. optimize the model.
-        let edge = sample.target()
+        self.push_constraint(sample, clf);
+        self.solve()
+    }
+
+
+    /// Append all the hypotheses in `clfs` to the model,
+    /// solve the edge minimization problem only once,
+    /// and outputs the optimal value.
+    pub(super) fn update_many<F>(
+        &mut self,
+        sample: &Sample,
+        clfs: &[F]
+    ) -> f64
+        where F: Classifier
+    {
+        assert!(!clfs.is_empty(), "No hypothesis is given");
+        clfs.iter()
+            .for_each(|clf| { self.push_constraint(sample, clf); });
+        self.solve()
+    }
+
+
+    /// Append the constraints of the given margin vectors,
+    /// solve the edge minimization problem only once,
+    /// and outputs the optimal value.
+    /// `margins[j][i]` is the margin `y_i h_j (x_i)`.
+    pub(super) fn update_with_margins(&mut self, margins: Vec<Vec<f64>>)
+        -> f64
+    {
+        assert!(!margins.is_empty(), "No hypothesis is given");
+        margins.into_iter()
+            .for_each(|margin| { self.push_margins(margin); });
+        self.solve()
+    }
+
+
+    /// Append the constraint `edge of clf <= gamma`.
+    fn push_constraint<F>(&mut self, sample: &Sample, clf: &F)
+        where F: Classifier
+    {
+        let margins = sample.target()
             .iter()
             .enumerate()
             .map(|(i, y)| y * clf.confidence(sample, i))
+            .collect::<Vec<_>>();
+        self.push_margins(margins);
+    }
+
+
+    /// Append the constraint `Σ_i d_i margins[i] <= gamma`.
+    fn push_margins(&mut self, margins: Vec<f64>) {
+        self.margins.push(margins);
+        self.ages.push(0);
+        self.constrs.push(None);
+        self.add_constraint(self.constrs.len() - 1);
+    }
+
+
+    /// Add the constraint of the `t`-th hypothesis to the model.
+    fn add_constraint(&mut self, t: usize) {
+        let edge = self.margins[t].iter()
+            .copied()
             .zip(self.dist.iter().copied())
             .map(|(yh, d)| d * yh)
             .grb_sum();
 
 
-        let name = format!("{t}-th hypothesis", t = self.constrs.len());
+        let name = format!("{t}-th hypothesis");
 
 
-        self.constrs.push(
+        self.constrs[t] = Some(
             self.model.add_constr(&name, c!(edge <= self.gamma))
                 .expect("Failed to add a new constraint `edge <= gamma`")
         );
+        self.ages[t] = 0;
+    }
 
 
+    /// Optimize the model and outputs the optimal value.
+    /// The removed constraints violated by the solution are re-inserted
+    /// and the model is optimized again.
+    fn solve(&mut self) -> f64 {
+        let mut optimal_value = self.optimize();
+        while self.max_age.is_some() {
+            let dist = self.distribution();
+            let violated = (0..self.constrs.len())
+                .filter(|&t| self.constrs[t].is_none())
+                .filter(|&t| {
+                    let edge = self.margins[t].iter()
+                        .zip(&dist[..])
+                        .map(|(yh, d)| d * yh)
+                        .sum::<f64>();
+                    edge > optimal_value + VIOLATION_TOLERANCE
+                })
+                .collect::<Vec<_>>();
+            if violated.is_empty() {
+                break;
+            }
+            for t in violated {
+                self.add_constraint(t);
+            }
+            optimal_value = self.optimize();
+        }
+
+
+        // Remove the constraints with zero dual values for `max_age` solves.
+        // Since the dual values are zero,
+        // the current solution is still optimal without them.
+        if let Some(max_age) = self.max_age {
+            let weights = self.weight().collect::<Vec<_>>();
+            for (t, w) in weights.into_iter().enumerate() {
+                let Some(constr) = self.constrs[t] else { continue; };
+                if w > ZERO_WEIGHT {
+                    self.ages[t] = 0;
+                    continue;
+                }
+                self.ages[t] += 1;
+                if self.ages[t] >= max_age {
+                    self.model.remove(constr)
+                        .expect("Failed to remove the constraint");
+                    self.constrs[t] = None;
+                }
+            }
+        }
+        optimal_value
+    }
+
+
+    /// Optimize the model and outputs the optimal value.
+    fn optimize(&mut self) -> f64 {
         self.model.update()
             .expect("Failed to update the model after adding a new constraint");
 
@@ -106,7 +273,7 @@ impl LPModel {
 
         let status = self.model.status()
             .expect("Failed to get the model status");
-        if status != Status::Optimal {
+        if status != Status::Optimal && !self.has_solution_at_limit(status) {
             panic!("Status is {status:?}. Something wrong.");
         }
 
@@ -115,6 +282,16 @@ impl LPModel {
             .expect("Failed to get the dual solution `gamma`")
     }
 
+
+    /// Returns `true` if the solver reached the time limit
+    /// with a feasible solution.
+    fn has_solution_at_limit(&self, status: Status) -> bool {
+        status == Status::TimeLimit
+            && self.model.get_attr(attr::SolCount)
+                .expect("Failed to get the number of solutions") > 0
+    }
+
+
     /// Returns the distribution over examples.
     pub(super) fn distribution(&self)
         -> Vec<f64>
@@ -127,10 +304,16 @@ impl LPModel {
 
 
     /// Returns the weights over the hypotheses.
+    /// The weights on the removed constraints are zero.
     pub(super) fn weight(&self) -> impl Iterator<Item=f64> + '_
     {
         self.constrs[0..].iter()
-            .map(|c| self.model.get_obj_attr(attr::Pi, c).map(f64::abs).unwrap())
+            .map(|c| match c {
+                Some(c) => self.model.get_obj_attr(attr::Pi, c)
+                    .map(f64::abs)
+                    .unwrap(),
+                None => 0f64,
+            })
     }
 }
 
@@ -56,6 +56,14 @@ use crate::hypothesis::Classifier;
 ///
 /// Since the `clarabel` crate solves the minimization problems,
 /// we need to negate the objective function.
+///
+/// If the column aging is enabled (see [`LPModel::set_max_age`]),
+/// the columns of the hypotheses whose weights have been zero
+/// for `k` consecutive solves are dropped from the matrix.
+/// A dropped hypothesis `h_j` is re-inserted
+/// if the distribution `d` violates its constraint
+/// `Σ_i d_i y_i h_j (x_i) ≤ γ` in the dual problem,
+/// so that the solution is optimal over all the hypotheses.
 pub(super) struct LPModel {
     // -----
     // clarabel settings
@@ -67,22 +75,33 @@ pub(super) struct LPModel {
     // -----
     pub(self) n_examples: usize,        // number of columns
     pub(self) n_hypotheses: usize,      // number of rows
+    pub(self) margins: Vec<Vec<f64>>,   // margins of each hypothesis
+    pub(self) active: Vec<bool>,        // `true` if the column is in the LP
+    pub(self) ages: Vec<usize>,         // # of consecutive zero weights
+    pub(self) max_age: Option<usize>,   // age at which a column is dropped
     pub(self) weights: Vec<f64>,        // weight on hypothesis
     pub(self) dist: Vec<f64>,           // distribution over examples
 }
 
 
+/// The weights below this value are regarded as zero.
+pub(super) const ZERO_WEIGHT: f64 = 1e-7;
+/// The tolerance of the constraint violation of the dropped columns.
+const VIOLATION_TOLERANCE: f64 = 1e-6;
+
+
 impl LPModel {
     /// Initialize the LP model.
     /// arguments.
     /// - `size`: Number of variables (Number of examples).
-    /// - `upper_bound`: Capping parameter. `[1, size]`.
+    /// - `upper_bound`: Upper bound of the distribution over examples,
+    ///   `1/ν` for the capping parameter `ν` in `[1, size]`.
     pub(super) fn init(size: usize, upper_bound: f64) -> Self {
         let n_examples = size;
         // Set the linear part of the objective function 
         // as the minimization form
         // - ρ + (1/ν) Σ_i ξ_i
-        let mut lin_obj = vec![1f64/upper_bound; n_examples+1];
+        let mut lin_obj = vec![upper_bound; n_examples+1];
         lin_obj[0] = -1f64;
 
         let mut col_ptr = vec![0usize];
@@ -106,12 +125,42 @@ impl LPModel {
             row_val,
             n_examples,
             n_hypotheses: 0usize,
+            margins:      Vec::new(),
+            active:       Vec::new(),
+            ages:         Vec::new(),
+            max_age:      None,
             weights:      Vec::with_capacity(0usize),
             dist:         Vec::with_capacity(0usize),
         }
     }
 
 
+    /// Scale the penalty on the slack variable `ξ_i`
+    /// by `m weights[i]`,
+    /// where `weights` is a distribution over the examples.
+    pub(super) fn set_example_weights(&mut self, weights: &[f64]) {
+        assert_eq!(weights.len(), self.n_examples);
+        let n_examples = self.n_examples as f64;
+        self.lin_obj[1..].iter_mut()
+            .zip(weights)
+            .for_each(|(c, w)| { *c *= n_examples * w; });
+    }
+
+
+    /// Drop the columns of the hypotheses
+    /// whose weights have been zero for `max_age` consecutive solves.
+    pub(super) fn set_max_age(&mut self, max_age: usize) {
+        assert!(max_age > 0, "The max age of the columns must be positive");
+        self.max_age = Some(max_age);
+    }
+
+
+    /// Returns the number of the columns in the LP.
+    pub(super) fn n_active(&self) -> usize {
+        self.active.iter().filter(|a| **a).count()
+    }
+
+
     /// Solve the edge minimization problem 
     /// over the hypotheses `h1, ..., ht` 
     /// and outputs the optimal value.
@@ -122,27 +171,147 @@ impl LPModel {
     ) -> f64
         where F: Classifier
     {
+        self.push_column(sample, clf);
+        self.solve()
+    }
+
+
+    /// Append all the hypotheses in `clfs` to the model,
+    /// solve the edge minimization problem only once,
+    /// and outputs the optimal value.
+    pub(super) fn update_many<F>(
+        &mut self,
+        sample: &Sample,
+        clfs: &[F]
+    ) -> f64
+        where F: Classifier
+    {
+        assert!(!clfs.is_empty(), "No hypothesis is given");
+        clfs.iter()
+            .for_each(|clf| { self.push_column(sample, clf); });
+        self.solve()
+    }
+
+
+    /// Append the columns of the given margin vectors,
+    /// solve the edge minimization problem only once,
+    /// and outputs the optimal value.
+    /// `margins[j][i]` is the margin `y_i h_j (x_i)`.
+    pub(super) fn update_with_margins(&mut self, margins: Vec<Vec<f64>>)
+        -> f64
+    {
+        assert!(!margins.is_empty(), "No hypothesis is given");
+        margins.into_iter()
+            .for_each(|margin| { self.push_margins(margin); });
+        self.solve()
+    }
+
+
+    /// Append the column corresponding to `clf`
+    /// to the constraint matrix.
+    fn push_column<F>(&mut self, sample: &Sample, clf: &F)
+        where F: Classifier
+    {
+        self.push_margins(utils::margins_of_hypothesis(sample, clf));
+    }
+
+
+    /// Append the column of the margin vector `margins`
+    /// to the constraint matrix.
+    fn push_margins(&mut self, margins: Vec<f64>) {
+        assert_eq!(margins.len(), self.n_examples);
         self.n_hypotheses += 1;
-        let margins = utils::margins_of_hypothesis(sample, clf);
-        self.col_ptr.push(self.row_val.len());
-        for (i, yh) in margins.into_iter().enumerate() {
-            self.row_val.push(i);
-            self.nonzero.push(-yh);
+        self.margins.push(margins);
+        self.active.push(true);
+        self.ages.push(0);
+    }
+
+
+    /// Solve the edge minimization problem
+    /// over the columns appended so far
+    /// and outputs the optimal value.
+    /// The dropped columns violated by the solution are re-inserted
+    /// and the problem is solved again.
+    fn solve(&mut self) -> f64 {
+        let mut optimal_value = self.solve_active();
+        while self.max_age.is_some() {
+            let violated = (0..self.n_hypotheses)
+                .filter(|&j| !self.active[j])
+                .filter(|&j| {
+                    let edge = utils::inner_product(
+                        &self.margins[j][..], &self.dist[..]
+                    );
+                    edge > optimal_value + VIOLATION_TOLERANCE
+                })
+                .collect::<Vec<_>>();
+            if violated.is_empty() {
+                break;
+            }
+            for j in violated {
+                self.active[j] = true;
+                self.ages[j] = 0;
+            }
+            optimal_value = self.solve_active();
         }
-        // append 1 for equality constraint.
-        self.row_val.push(self.n_examples);
-        self.nonzero.push(1f64);
-        // append 1 for non-negative constraint of weight on `clf.`
-        self.row_val.push(2*self.n_examples + self.n_hypotheses);
-        self.nonzero.push(-1f64);
 
-        // In the CSC format, the following is equired:
-        let n_rows = 2 * self.n_examples + self.n_hypotheses + 1;
-        let n_cols = self.n_examples + self.n_hypotheses + 1;
+
+        // Drop the columns with zero weights for `max_age` solves.
+        // Since the weights on them are zero,
+        // the current solution is still optimal without them.
+        if let Some(max_age) = self.max_age {
+            for j in 0..self.n_hypotheses {
+                if !self.active[j] {
+                    continue;
+                }
+                if self.weights[j] > ZERO_WEIGHT {
+                    self.ages[j] = 0;
+                    continue;
+                }
+                self.ages[j] += 1;
+                if self.ages[j] >= max_age {
+                    self.active[j] = false;
+                }
+            }
+        }
+        optimal_value
+    }
+
+
+    /// Solve the edge minimization problem
+    /// over the columns in the LP
+    /// and outputs the optimal value.
+    fn solve_active(&mut self) -> f64 {
+        let active = (0..self.n_hypotheses)
+            .filter(|&j| self.active[j])
+            .collect::<Vec<_>>();
+        let n_active = active.len();
+
+
+        // Append the columns of the active hypotheses.
         let mut col_ptr = self.col_ptr.clone();
-        col_ptr.push(self.row_val.len());
-        let row_val = self.row_val.clone();
-        let nonzero = self.nonzero.clone();
+        let mut row_val = self.row_val.clone();
+        let mut nonzero = self.nonzero.clone();
+        let mut lin_obj = self.lin_obj.clone();
+        for (k, &j) in active.iter().enumerate() {
+            col_ptr.push(row_val.len());
+            for (i, yh) in self.margins[j].iter().enumerate() {
+                row_val.push(i);
+                nonzero.push(-yh);
+            }
+            // append 1 for equality constraint.
+            row_val.push(self.n_examples);
+            nonzero.push(1f64);
+            // append 1 for non-negative constraint of weight on `clf.`
+            row_val.push(2*self.n_examples + 1 + k);
+            nonzero.push(-1f64);
+            lin_obj.push(0f64);
+        }
+
+
+        // In the CSC format, the following is equired:
+        let n_rows = 2 * self.n_examples + n_active + 1;
+        let n_cols = self.n_examples + n_active + 1;
+        col_ptr.push(row_val.len());
         let constraint_matrix = CscMatrix::new(
             n_rows,  // # of rows
             n_cols,  // # of cols
@@ -151,13 +320,13 @@ impl LPModel {
             nonzero, // non-zero values
         );
 
-        let mut rhs = vec![0f64; 2*self.n_examples + self.n_hypotheses + 1];
+        let mut rhs = vec![0f64; 2*self.n_examples + n_active + 1];
         rhs[self.n_examples] = 1f64;
         let cones = [
             NonnegativeConeT(self.n_examples),
             ZeroConeT(1),
             NonnegativeConeT(self.n_examples),
-            NonnegativeConeT(self.n_hypotheses),
+            NonnegativeConeT(n_active),
         ];
 
         let settings = DefaultSettingsBuilder::default()
@@ -166,12 +335,11 @@ impl LPModel {
             .build()
             .unwrap();
 
-        let n_variables = 1 + self.n_examples + self.n_hypotheses;
+        let n_variables = 1 + self.n_examples + n_active;
         let zero_mat = CscMatrix::<f64>::zeros((n_variables, n_variables));
-        self.lin_obj.push(0f64);
         let mut solver = DefaultSolver::new(
             &zero_mat,
-            &self.lin_obj,
+            &lin_obj,
             &constraint_matrix,
             &rhs[..],
             &cones,
@@ -183,8 +351,12 @@ impl LPModel {
         //         here
         //          ↓
         // [ ρ, ξ, w[0], w[1], ..., w[T] ]
+        // The weights on the dropped hypotheses are zero.
         let size = 1 + self.n_examples;
-        self.weights = solver.solution.x[size..].to_vec();
+        self.weights = vec![0f64; self.n_hypotheses];
+        for (k, &j) in active.iter().enumerate() {
+            self.weights[j] = solver.solution.x[size + k];
+        }
         self.dist = solver.solution.z[..self.n_examples].to_vec();
 
         let wsum = self.weights.iter().sum::<f64>();
@@ -220,5 +392,3 @@ impl LPModel {
         self.weights.iter().copied()
     }
 }
-
-
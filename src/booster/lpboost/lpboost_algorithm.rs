@@ -8,9 +8,14 @@ use super::lp_model::LPModel;
 #[cfg(feature="gurobi")]
 use super::gurobi_lp_model::LPModel;
 
+#[cfg(feature="gurobi")]
+use crate::common::gurobi_config::GurobiConfig;
+
 use crate::{
     Sample,
     Booster,
+    Resumable,
+    Certificate,
     WeakLearner,
 
     Classifier,
@@ -54,6 +59,15 @@ use std::ops::ControlFlow;
 /// There exists a training set of size `m > 0` such that
 /// `LPBoost` takes `Ω( m )` iterations for the worst case.
 ///
+/// # Instance weights
+/// If the training sample has instance weights
+/// (see [`Sample::with_weights`]),
+/// `LPBoost` normalizes them to `w_{1}, w_{2}, ..., w_{m}`
+/// with `Σ_{i} w_{i} = 1`
+/// and penalizes the slack `ξ_{i}` by `m w_{i} / ν` instead of `1 / ν`.
+/// Equivalently, the cap on the `i`-th example
+/// in the edge minimization problem becomes `m w_{i} / ν`.
+///
 ///
 /// # Related information
 /// - Currently (2023), `LPBoost` has no convergence guarantee.
@@ -125,6 +139,9 @@ pub struct LPBoost<'a, F> {
     // min-max edge of the new hypothesis
     gamma_hat: f64,
 
+    // Optimal value of the LP over the hypotheses obtained so far
+    gamma_star: f64,
+
     // Tolerance parameter
     tolerance: f64,
 
@@ -137,10 +154,33 @@ pub struct LPBoost<'a, F> {
     nu: f64,
 
 
+    // Annealing schedule of the capping parameter,
+    // the initial value and the number of rounds.
+    anneal: Option<(f64, usize)>,
+
+
+    // Capping parameter of the current LP.
+    current_nu: f64,
+
+
+    // The hypotheses whose weights have been zero
+    // for this number of consecutive rounds are dropped from the LP.
+    max_age: Option<usize>,
+
+
+    // Number of hypotheses added to the LP per round.
+    columns_per_round: usize,
+
+
     // GRBModel.
     lp_model: Option<RefCell<LPModel>>,
 
 
+    // Parameters of the Gurobi environment.
+    #[cfg(feature="gurobi")]
+    gurobi: GurobiConfig,
+
+
     hypotheses: Vec<F>,
     weights: Vec<f64>,
 
@@ -165,11 +205,19 @@ impl<'a, F> LPBoost<'a, F>
 
             dist: Vec::new(),
             gamma_hat: 1.0,
+            gamma_star: -1.0,
             tolerance: uni,
             n_sample,
             nu: 1.0,
+            anneal: None,
+            current_nu: 1.0,
+            max_age: None,
+            columns_per_round: 1,
             lp_model: None,
 
+            #[cfg(feature="gurobi")]
+            gurobi: GurobiConfig::default(),
+
             hypotheses: Vec::new(),
             weights: Vec::new(),
 
@@ -191,14 +239,79 @@ impl<'a, F> LPBoost<'a, F>
     }
 
 
+    /// Anneal the capping parameter
+    /// from `initial_nu` to the one set by [`LPBoost::nu`]
+    /// geometrically over the first `n_rounds` rounds.
+    /// Since a large capping parameter makes
+    /// the distribution over examples close to the uniform one,
+    /// early rounds become stable on noisy data.
+    /// Each time the capping parameter changes,
+    /// the LP is re-solved over the hypotheses obtained so far,
+    /// so the final solution is the one for the target capping parameter.
+    /// `LPBoost` does not terminate before the annealing finishes.
+    /// If `initial_nu` is smaller than the target,
+    /// the annealing is disabled.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn anneal_nu(mut self, initial_nu: f64, n_rounds: usize) -> Self {
+        checker::check_nu(initial_nu, self.n_sample);
+        self.anneal = Some((initial_nu, n_rounds));
+        self
+    }
+
+
+    /// Set the parameters of the Gurobi environment
+    /// used to solve the LPs.
+    /// By default, `LPBoost` silences the solver output
+    /// and uses the defaults of Gurobi for the others.
+    ///
+    /// Time complexity: `O(1)`.
+    #[cfg(feature="gurobi")]
+    pub fn gurobi_config(mut self, config: GurobiConfig) -> Self {
+        self.gurobi = config;
+        self
+    }
+
+
+    /// Returns the capping parameter used at `iteration`.
+    fn nu_at(&self, iteration: usize) -> f64 {
+        match self.anneal {
+            Some((initial_nu, n_rounds)) if initial_nu > self.nu => {
+                utils::annealed_nu(initial_nu, self.nu, n_rounds, iteration)
+            },
+            _ => self.nu,
+        }
+    }
+
+
     /// Initializes the LP solver.
     fn init_solver(&mut self) {
-        let n_sample = self.sample.shape().0 as f64;
-        assert!((1.0..=n_sample).contains(&self.nu));
+        self.current_nu = self.nu_at(1);
+        self.init_solver_with(self.current_nu);
+    }
 
-        let upper_bound = 1.0 / self.nu;
 
-        let lp_model = RefCell::new(LPModel::init(self.n_sample, upper_bound));
+    /// Initializes the LP solver with the capping parameter `nu`.
+    fn init_solver_with(&mut self, nu: f64) {
+        let n_sample = self.sample.shape().0 as f64;
+        assert!((1.0..=n_sample).contains(&nu));
+
+        let upper_bound = 1.0 / nu;
+
+        #[cfg(not(feature="gurobi"))]
+        let mut lp_model = LPModel::init(self.n_sample, upper_bound);
+        #[cfg(feature="gurobi")]
+        let mut lp_model = LPModel::init_with_config(
+            self.n_sample, upper_bound, &self.gurobi
+        );
+        if let Some(max_age) = self.max_age {
+            lp_model.set_max_age(max_age);
+        }
+        if self.sample.weights().is_some() {
+            let weights = self.sample.weight_distribution();
+            lp_model.set_example_weights(&weights[..]);
+        }
+        let lp_model = RefCell::new(lp_model);
 
         self.lp_model = Some(lp_model);
     }
@@ -217,6 +330,54 @@ impl<'a, F> LPBoost<'a, F>
     }
 
 
+    /// Drop the hypotheses from the LP
+    /// if their weights have been zero for `k` consecutive rounds.
+    /// Since the LP grows by one column per round,
+    /// dropping the inactive columns shrinks the solve time on long runs.
+    /// A dropped hypothesis is re-inserted into the LP
+    /// if the distribution over examples violates its constraint,
+    /// i.e., its edge exceeds the optimal value of the LP.
+    /// Thus, the solution is the same as the one without dropping.
+    /// By default, no hypothesis is dropped.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn column_aging(mut self, k: usize) -> Self {
+        assert!(k > 0, "The number of rounds must be positive");
+        self.max_age = Some(k);
+        self
+    }
+
+
+    /// Add at most `k` hypotheses to the LP per round.
+    /// The hypotheses are obtained by
+    /// [`WeakLearner::produce_many`],
+    /// and the LP is solved only once per round.
+    /// Since the LP gets more columns per solve,
+    /// `LPBoost` often terminates in fewer rounds.
+    /// Note that the weak learners that do not override
+    /// [`WeakLearner::produce_many`] return a single hypothesis.
+    /// By default, one hypothesis is added per round.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn columns_per_round(mut self, k: usize) -> Self {
+        assert!(k > 0, "The number of columns must be positive");
+        self.columns_per_round = k;
+        self
+    }
+
+
+    /// Returns the number of the hypotheses in the current LP.
+    /// If [`LPBoost::column_aging`] is not set,
+    /// this is the number of the hypotheses obtained so far.
+    /// 
+    /// Time complexity: `O(T)`,
+    /// where `T` is the number of the hypotheses obtained so far.
+    pub fn n_active_hypotheses(&self) -> usize {
+        self.lp_model.as_ref()
+            .map_or(0, |lp_model| lp_model.borrow().n_active())
+    }
+
+
     /// Returns the terminated iteration.
     /// This method returns `usize::MAX` before the boosting step.
     /// 
@@ -227,18 +388,212 @@ impl<'a, F> LPBoost<'a, F>
     }
 
 
+    /// Returns the current pair of `gamma_hat` and `gamma_star`.
+    /// `LPBoost` terminates once
+    /// the gap `gamma_hat - gamma_star` is at most `tolerance`.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn certificate(&self) -> Certificate {
+        Certificate {
+            gamma_hat: self.gamma_hat,
+            gamma_star: self.gamma_star,
+        }
+    }
+
+
+    /// Returns the current optimality gap `gamma_hat - gamma_star`.
+    /// 
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn gap(&self) -> f64 {
+        self.certificate().gap()
+    }
+
+
     /// This method updates `self.dist` and `self.gamma_hat`
     /// by solving a linear program
     /// over the hypotheses obtained in past rounds.
     /// 
     /// Time complexity depends on the LP solver.
     #[inline(always)]
-    fn update_distribution_mut(&self, h: &F) -> f64
+    fn update_distribution_mut(&self, hypotheses: &[F]) -> f64
     {
-        self.lp_model.as_ref()
+        let mut lp_model = self.lp_model.as_ref()
             .expect("Failed to call `.as_ref()` to `self.lp_model`")
-            .borrow_mut()
-            .update(self.sample, h)
+            .borrow_mut();
+        match hypotheses {
+            [h] => lp_model.update(self.sample, h),
+            _ => lp_model.update_many(self.sample, hypotheses),
+        }
+    }
+
+
+    /// Obtains the hypotheses added to the LP in this round.
+    fn produce_columns<W>(&self, weak_learner: &W) -> Vec<F>
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let k = self.columns_per_round;
+        if k == 1 {
+            return vec![weak_learner.produce(self.sample, &self.dist)];
+        }
+        let hypotheses = weak_learner.produce_many(self.sample, &self.dist, k);
+        assert!(
+            !hypotheses.is_empty(),
+            "The weak learner returned no hypothesis"
+        );
+        hypotheses
+    }
+}
+
+
+impl<F> LPBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    /// Choose the capping parameter `nu` from `grid`
+    /// by the performance on `validation`.
+    /// `LPBoost` is trained for each `nu` in `grid` in ascending order.
+    /// The training for each `nu` is warm-started
+    /// with the hypotheses obtained for the previous `nu`,
+    /// so the weak learner is called far less than
+    /// training from scratch for each `nu`.
+    ///
+    /// This method returns the chosen `nu` and
+    /// the combined hypothesis trained with it.
+    /// The model with the best validation accuracy is chosen;
+    /// ties are broken by the average margin on `validation`.
+    /// After the call, `self` holds the chosen `nu`.
+    ///
+    /// ```no_run
+    /// use miniboosts::prelude::*;
+    ///
+    /// # let train = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+    /// # let valid = SampleReader::new().file("valid.csv").has_header(true).target_feature("class").read().unwrap();
+    /// let tree = DecisionTreeBuilder::new(&train).max_depth(2).build();
+    /// let n_sample = train.shape().0 as f64;
+    /// let grid = [0.01, 0.05, 0.1, 0.2].map(|ratio| (ratio * n_sample).max(1.0));
+    ///
+    /// let mut booster = LPBoost::init(&train)
+    ///     .tolerance(0.01);
+    /// let (nu, f) = booster.auto_nu(&tree, &valid, &grid);
+    /// ```
+    pub fn auto_nu<W>(
+        &mut self,
+        weak_learner: &W,
+        validation: &Sample,
+        grid: &[f64],
+    ) -> (f64, WeightedMajority<F>)
+        where W: WeakLearner<Hypothesis = F>
+    {
+        assert!(!grid.is_empty(), "The grid of `nu` is empty");
+        let mut grid = grid.to_vec();
+        grid.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        grid.iter().for_each(|&nu| checker::check_nu(nu, self.n_sample));
+
+        let mut pool = Vec::new();
+        let mut best: Option<((f64, f64), f64, WeightedMajority<F>)> = None;
+        for nu in grid {
+            let f = self.run_warm_started(weak_learner, nu, pool);
+            pool = self.hypotheses.clone();
+
+            let score = utils::validation_score(validation, &f);
+            let is_better = best.as_ref()
+                .is_none_or(|(best_score, _, _)| score > *best_score);
+            if is_better {
+                best = Some((score, nu, f));
+            }
+        }
+
+        let (_, nu, f) = best.unwrap();
+        self.nu = nu;
+        (nu, f)
+    }
+
+
+    /// Train `LPBoost` for each capping parameter `nu` in `grid`
+    /// in descending order
+    /// and returns the pairs of `nu` and the combined hypothesis
+    /// trained with it.
+    /// The LP for each `nu` is warm-started
+    /// with the hypotheses obtained for the previous (larger) `nu`,
+    /// so the weak learner is called far less than
+    /// training from scratch for each `nu`.
+    /// Since the LP is re-solved over these hypotheses,
+    /// each combined hypothesis is
+    /// a `tolerance`-approximate solution for its own `nu`.
+    ///
+    /// This is useful for the model selection over `nu`.
+    /// After the call, `self` holds the smallest `nu` in `grid`.
+    ///
+    /// ```no_run
+    /// use miniboosts::prelude::*;
+    ///
+    /// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+    /// let tree = DecisionTreeBuilder::new(&sample).max_depth(2).build();
+    /// let n_sample = sample.shape().0 as f64;
+    /// let grid = [0.2, 0.1, 0.05, 0.01].map(|ratio| (ratio * n_sample).max(1.0));
+    ///
+    /// let mut booster = LPBoost::init(&sample)
+    ///     .tolerance(0.01);
+    /// for (nu, f) in booster.nu_path(&tree, &grid) {
+    ///     println!("nu = {nu}: {} hypotheses", f.hypotheses.len());
+    /// }
+    /// ```
+    pub fn nu_path<W>(
+        &mut self,
+        weak_learner: &W,
+        grid: &[f64],
+    ) -> Vec<(f64, WeightedMajority<F>)>
+        where W: WeakLearner<Hypothesis = F>
+    {
+        assert!(!grid.is_empty(), "The grid of `nu` is empty");
+        let mut grid = grid.to_vec();
+        grid.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        grid.iter().for_each(|&nu| checker::check_nu(nu, self.n_sample));
+
+        let mut pool = Vec::new();
+        let mut path = Vec::with_capacity(grid.len());
+        for nu in grid {
+            let f = self.run_warm_started(weak_learner, nu, pool);
+            pool = self.hypotheses.clone();
+            path.push((nu, f));
+        }
+        path
+    }
+
+
+    /// Run `LPBoost` with capping parameter `nu`
+    /// starting from the LP over `pool`.
+    fn run_warm_started<W>(
+        &mut self,
+        weak_learner: &W,
+        nu: f64,
+        pool: Vec<F>,
+    ) -> WeightedMajority<F>
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.nu = nu;
+        self.preprocess(weak_learner);
+        if !pool.is_empty() {
+            self.warm_start(pool);
+        }
+
+        let _ = (1..).try_for_each(|iter| {
+            self.boost(weak_learner, iter)
+        });
+        self.postprocess(weak_learner)
+    }
+
+
+    /// Add `hypotheses` to the LP
+    /// and update the distribution over the training examples.
+    fn warm_start(&mut self, hypotheses: Vec<F>) {
+        let lp_model = self.lp_model.as_ref()
+            .expect("Failed to call `.as_ref()` to `self.lp_model`");
+        self.gamma_star = lp_model.borrow_mut()
+            .update_many(self.sample, &hypotheses[..]);
+        self.dist = lp_model.borrow().distribution();
+        self.hypotheses = hypotheses;
     }
 }
 
@@ -258,12 +613,29 @@ impl<F> Booster<F> for LPBoost<'_, F>
         let (n_sample, n_feature) = self.sample.shape();
         let ratio = self.nu * 100f64 / n_sample as f64;
         let nu = utils::format_unit(self.nu);
+        let anneal = match self.anneal {
+            Some((initial_nu, n_rounds)) => {
+                let initial_nu = utils::format_unit(initial_nu);
+                format!("{initial_nu} -> {nu} ({n_rounds} rounds)")
+            },
+            None => "-".to_string(),
+        };
+        let aging = match self.max_age {
+            Some(k) => format!("After {k} rounds"),
+            None => "-".to_string(),
+        };
         let info = Vec::from([
             ("# of examples", format!("{n_sample}")),
             ("# of features", format!("{n_feature}")),
             ("Tolerance", format!("{}", self.tolerance)),
             ("Max iteration", format!("-")),
-            ("Capping (outliers)", format!("{nu} ({ratio: >7.3} %)"))
+            ("Capping (outliers)", format!("{nu} ({ratio: >7.3} %)")),
+            ("Capping schedule", anneal),
+            ("Column aging", aging),
+            ("Columns per round", format!("{}", self.columns_per_round)),
+            ("Optimality gap", format!("{}", self.certificate())),
+            #[cfg(feature="gurobi")]
+            ("Gurobi", format!("{}", self.gurobi)),
         ]);
         Some(info)
     }
@@ -277,13 +649,13 @@ impl<F> Booster<F> for LPBoost<'_, F>
     {
         self.sample.is_valid_binary_instance();
         let n_sample = self.sample.shape().0;
-        let uni = 1.0_f64 / self.n_sample as f64;
 
         self.init_solver();
 
         self.n_sample = n_sample;
-        self.dist = vec![uni; n_sample];
+        self.dist = self.sample.weight_distribution();
         self.gamma_hat = 1.0;
+        self.gamma_star = -1.0;
         self.hypotheses = Vec::new();
         self.terminated = usize::MAX;
     }
@@ -296,31 +668,47 @@ impl<F> Booster<F> for LPBoost<'_, F>
     ) -> ControlFlow<usize>
         where W: WeakLearner<Hypothesis = F>,
     {
-        let h = weak_learner.produce(self.sample, &self.dist);
+        let hypotheses = self.produce_columns(weak_learner);
 
-        // Each element in `margins` is the product of
-        // the predicted vector and the correct vector
-        let ghat = utils::edge_of_hypothesis(self.sample, &self.dist[..], &h);
+        // The best edge among the new hypotheses
+        // is an upper bound of the optimal value.
+        let ghat = hypotheses.iter()
+            .map(|h| utils::edge_of_hypothesis(self.sample, &self.dist[..], h))
+            .fold(f64::MIN, f64::max);
 
         self.gamma_hat = ghat.min(self.gamma_hat);
 
-        let gamma_star = self.update_distribution_mut(&h);
-
-
-        if gamma_star >= self.gamma_hat - self.tolerance {
-            self.hypotheses.push(h);
-            self.terminated = self.hypotheses.len();
-            return ControlFlow::Break(iteration);
+        // If the capping parameter is annealed,
+        // re-solve the LP over all the hypotheses.
+        let nu = self.nu_at(iteration);
+        if nu != self.current_nu {
+            self.current_nu = nu;
+            self.init_solver_with(nu);
+            self.hypotheses.extend(hypotheses);
+            self.gamma_star = self.lp_model.as_ref()
+                .expect("Failed to call `.as_ref()` to `self.lp_model`")
+                .borrow_mut()
+                .update_many(self.sample, &self.hypotheses[..]);
+        } else {
+            self.gamma_star = self.update_distribution_mut(&hypotheses);
+            self.hypotheses.extend(hypotheses);
         }
 
-        self.hypotheses.push(h);
-
         // Update the distribution over the training examples.
+        // The distribution is updated even if the stopping criterion
+        // is satisfied so that the boosting can be resumed.
         self.dist = self.lp_model.as_ref()
             .expect("Failed to call `.as_ref()` to `self.lp_model`")
             .borrow()
             .distribution();
 
+
+        let annealed = self.current_nu == self.nu;
+        if annealed && self.certificate().is_within(self.tolerance) {
+            self.terminated = self.hypotheses.len();
+            return ControlFlow::Break(iteration);
+        }
+
         ControlFlow::Continue(())
     }
 
@@ -342,6 +730,24 @@ impl<F> Booster<F> for LPBoost<'_, F>
 }
 
 
+impl<F> Resumable<F> for LPBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    fn n_rounds(&self) -> usize {
+        self.hypotheses.len()
+    }
+
+
+    fn resume(&mut self, _n_rounds: usize) {
+        assert!(
+            self.lp_model.is_some(),
+            "`LPBoost::resume` is called before `LPBoost::run`"
+        );
+        self.terminated = usize::MAX;
+    }
+}
+
+
 impl<H> Research for LPBoost<'_, H>
     where H: Classifier + Clone,
 {
@@ -0,0 +1,125 @@
+//! Re-fitting the weights of a trained `WeightedMajority`
+//! by the soft margin optimization over its hypotheses.
+#[cfg(not(feature="gurobi"))]
+use super::lp_model::{LPModel, ZERO_WEIGHT};
+
+#[cfg(feature="gurobi")]
+use super::gurobi_lp_model::{LPModel, ZERO_WEIGHT};
+
+use crate::{
+    Sample,
+    Classifier,
+    WeightedMajority,
+    common::checker,
+    common::utils,
+};
+
+
+impl<H> WeightedMajority<H>
+    where H: Classifier + Clone,
+{
+    /// Keep the hypotheses of `self` fixed and
+    /// re-optimize the weights on them over `sample`.
+    /// The new weights are the solution of
+    /// the soft margin optimization (the one [`LPBoost`] solves)
+    /// with capping parameter `nu`:
+    ///
+    /// ```txt
+    /// max ρ - (1/ν) Σ_i ξ_i
+    /// s.t. y_i Σ_j w_j h_j (x_i) ≥ ρ - ξ_i,   ∀i = 1, 2, ..., m
+    ///      Σ_j w_j = 1,
+    ///      w_1, w_2, ..., w_T ≥ 0,
+    ///      ξ_1, ξ_2, ..., ξ_m ≥ 0.
+    /// ```
+    /// Hypotheses with zero weight are removed from the result.
+    ///
+    /// This is useful when the distribution of the data drifts;
+    /// you can adapt a trained model to new data
+    /// without calling the weak learner again.
+    ///
+    /// ```no_run
+    /// use miniboosts::prelude::*;
+    ///
+    /// # let old_sample = SampleReader::new().file("old.csv").has_header(true).target_feature("class").read().unwrap();
+    /// # let new_sample = SampleReader::new().file("new.csv").has_header(true).target_feature("class").read().unwrap();
+    /// let tree = DecisionTreeBuilder::new(&old_sample).build();
+    /// let mut booster = AdaBoost::init(&old_sample);
+    /// let f = booster.run(&tree);
+    ///
+    /// let n_sample = new_sample.shape().0 as f64;
+    /// let g = f.refit_weights(&new_sample, 0.1 * n_sample);
+    /// ```
+    ///
+    /// [`LPBoost`]: crate::booster::LPBoost
+    pub fn refit_weights(&self, sample: &Sample, nu: f64) -> Self {
+        assert!(
+            !self.hypotheses.is_empty(),
+            "No hypothesis to re-fit the weights"
+        );
+        let n_sample = sample.shape().0;
+        checker::check_nu(nu, n_sample);
+
+        let mut lp_model = LPModel::init(n_sample, 1f64 / nu);
+        lp_model.update_many(sample, &self.hypotheses[..]);
+
+        let weights = lp_model.weight().collect::<Vec<_>>();
+        Self::from_slices(&weights[..], &self.hypotheses[..])
+    }
+}
+
+
+/// Returns the solution `w` of the soft margin optimization
+/// with capping parameter `nu`
+/// over the hypotheses whose margins are given.
+/// `margins[j][i]` is the margin `y_i h_j (x_i)`
+/// of the `j`-th hypothesis on the `i`-th example.
+/// The weights below the solver precision are set to zero
+/// and the others are normalized.
+pub(crate) fn soft_margin_weights(margins: Vec<Vec<f64>>, nu: f64)
+    -> Vec<f64>
+{
+    assert!(!margins.is_empty(), "No hypothesis to re-fit the weights");
+    let n_sample = margins[0].len();
+    checker::check_nu(nu, n_sample);
+
+    let mut lp_model = LPModel::init(n_sample, 1f64 / nu);
+    lp_model.update_with_margins(margins);
+    let mut weights = lp_model.weight()
+        .map(|w| if w > ZERO_WEIGHT { w } else { 0f64 })
+        .collect::<Vec<_>>();
+    utils::normalize(&mut weights[..]);
+    weights
+}
+
+
+/// Returns the solution `w` of the soft margin optimization
+/// with capping parameter `nu`
+/// over `hypotheses` on `sample`.
+/// If `sample` has the instance weights,
+/// the slack variables are penalized in proportion to them,
+/// the same as [`LPBoost`](crate::booster::LPBoost).
+/// The weights below the solver precision are set to zero
+/// and the others are normalized.
+pub(crate) fn soft_margin_weights_on<H>(
+    sample: &Sample,
+    hypotheses: &[H],
+    nu: f64,
+) -> Vec<f64>
+    where H: Classifier,
+{
+    assert!(!hypotheses.is_empty(), "No hypothesis to re-fit the weights");
+    let n_sample = sample.shape().0;
+    checker::check_nu(nu, n_sample);
+
+    let mut lp_model = LPModel::init(n_sample, 1f64 / nu);
+    if sample.weights().is_some() {
+        let weights = sample.weight_distribution();
+        lp_model.set_example_weights(&weights[..]);
+    }
+    lp_model.update_many(sample, hypotheses);
+    let mut weights = lp_model.weight()
+        .map(|w| if w > ZERO_WEIGHT { w } else { 0f64 })
+        .collect::<Vec<_>>();
+    utils::normalize(&mut weights[..]);
+    weights
+}
@@ -10,9 +10,12 @@ use crate::{
     Sample,
 
     common::utils,
+    common::selection::{self, Selection},
     research::Research,
 };
 
+use rand::prelude::*;
+
 use std::ops::ControlFlow;
 
 
@@ -26,8 +29,10 @@ use std::ops::ControlFlow;
 /// ](https://www.learningtheory.org/colt2000/papers/DomingoWatanabe.pdf)
 /// by Carlos Domingo and Osamu Watanabe.
 /// 
-/// MadaBoost is a boosting algorithm for binary classification 
-/// that minimizes exponential loss over a set of training examples.
+/// MadaBoost is a boosting algorithm for binary classification.
+/// MadaBoost updates the weights on the training examples as AdaBoost does,
+/// but caps each weight at its initial value.
+/// Thus, the noisy examples cannot dominate the distribution.
 ///
 /// This struct provides the `MB:1/2` algorithm.
 ///
@@ -90,7 +95,9 @@ pub struct MadaBoost<'a, F> {
     // Weights for each instances in `sample.`
     // At the end of round `t,`
     // the `i`th element of `betas` holds
-    // `ln Bt[i] = sum_{k=1}^{T} ( y[i] hk(x[i]) ln beta[k] ).`
+    // `ln Bt[i] = sum_{k=1}^{t} ( y[i] hk(x[i]) ln beta[k] ).`
+    // The weight on the `i`th instance is `min(1, Bt[i])`,
+    // so that it never exceeds the initial weight.
     betas: Vec<f64>,
 
     // Tolerance parameter
@@ -116,6 +123,16 @@ pub struct MadaBoost<'a, F> {
     // MadaBoost terminates in eary step 
     // if the training set is linearly separable.
     terminated: usize,
+
+    // The rule to pick a hypothesis
+    // from the candidates of the weak learner.
+    selection: Selection,
+
+    // The seed of the random number generator.
+    seed: u64,
+
+    // Random number generator for `selection`.
+    rng: StdRng,
 }
 
 
@@ -139,6 +156,10 @@ impl<'a, F> MadaBoost<'a, F> {
             max_iter: usize::MAX,
             force_quit_at: None,
             terminated: usize::MAX,
+
+            selection: Selection::Best,
+            seed: selection::SEED,
+            rng: StdRng::seed_from_u64(selection::SEED),
         }
     }
 
@@ -169,6 +190,30 @@ impl<'a, F> MadaBoost<'a, F> {
     }
 
 
+    /// Set the rule to pick a hypothesis
+    /// from the candidates of the weak learner.
+    /// By default, `MadaBoost` always takes the best hypothesis,
+    /// i.e., `Selection::Best`.
+    /// See [`Selection`] for other rules.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn selection(mut self, selection: Selection) -> Self {
+        selection.check();
+        self.selection = selection;
+        self
+    }
+
+
+    /// Set the seed of the randomness for the selection rule.
+    /// Default value is `1234`.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
     /// Set the tolerance parameter.
     /// `MadaBoostV` terminates immediately
     /// after reaching the specified `tolerance`.
@@ -209,22 +254,26 @@ impl<'a, F> MadaBoost<'a, F> {
 
 
         // To prevent overflow, take the logarithm.
+        let ln_beta = beta.ln();
         self.betas.par_iter_mut()
             .zip(margins)
-            .for_each(|(b, yh)| { *b += yh * beta; });
+            .for_each(|(b, yh)| { *b += yh * ln_beta; });
 
 
         alpha
     }
 
 
+    /// Returns the distribution `d[i] ∝ min(1, Bt[i])`.
+    /// Since `self.betas` holds `ln Bt[i]`,
+    /// the capped weights are `min(0, ln Bt[i])` in the logarithmic scale.
     fn beta2distribution(&self) -> Vec<f64> {
         let n_sample = self.sample.shape().0;
 
         let weights = {
             let mut weights = self.betas.iter()
                 .copied()
-                .map(|b| b.min(1f64))
+                .map(|b| b.min(0f64))
                 .collect::<Vec<_>>();
             weights.shrink_to_fit();
             weights
@@ -282,6 +331,7 @@ impl<F> Booster<F> for MadaBoost<'_, F>
             ("Tolerance", format!("{}", self.tolerance)),
             ("Max iteration", format!("{}", self.max_loop())),
             ("Force quit", quit),
+            ("Selection", format!("{}", self.selection)),
         ]);
         Some(info)
     }
@@ -296,8 +346,8 @@ impl<F> Booster<F> for MadaBoost<'_, F>
         self.sample.is_valid_binary_instance();
         // Initialize parameters
         let n_sample = self.sample.shape().0;
-        let uni = 1.0 / n_sample as f64;
-        self.betas = vec![uni; n_sample];
+        // All the instances have the initial weight `B0[i] = 1`.
+        self.betas = vec![0f64; n_sample];
 
         self.alphas = Vec::new();
         self.hypotheses = Vec::new();
@@ -308,6 +358,9 @@ impl<F> Booster<F> for MadaBoost<'_, F>
         if let Some(it) = self.force_quit_at {
             self.max_iter = it;
         }
+        self.terminated = self.max_iter;
+
+        self.rng = StdRng::seed_from_u64(self.seed);
     }
 
 
@@ -325,7 +378,9 @@ impl<F> Booster<F> for MadaBoost<'_, F>
 
         let dist = self.beta2distribution();
         // Get a new hypothesis
-        let h = weak_learner.produce(self.sample, &dist[..]);
+        let h = self.selection.produce(
+            weak_learner, self.sample, &dist[..], &mut self.rng
+        );
 
 
         // Each element in `margins` is the product of
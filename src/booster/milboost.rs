@@ -0,0 +1,12 @@
+//! The MILBoost algorithm proposed
+//! by Paul Viola, John C. Platt, and Cha Zhang.
+//! This algorithm is based on the paper: 
+//! [
+//! Multiple Instance Boosting for Object Detection
+//! ](https://papers.nips.cc/paper/2926-multiple-instance-boosting-for-object-detection)
+//! 
+//! MILBoost is a boosting algorithm for multi-instance problems
+//! that maximizes the noisy-OR likelihood of the bag labels.
+pub mod milboost_algorithm;
+
+pub use milboost_algorithm::MILBoost;
@@ -0,0 +1,424 @@
+//! Provides [`MILBoost`] by Viola, Platt, & Zhang, 2005.
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    Sample,
+    BagClassifier,
+
+    hypothesis::bag_classifier::softplus,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+use std::collections::{HashMap, HashSet};
+
+
+// The search interval `[0, MAX_STEP_SIZE]` of the line search.
+const MAX_STEP_SIZE: f64 = 16.0;
+// The number of iterations of the golden-section search.
+const LINE_SEARCH_ITER: usize = 64;
+// The lower bound of the bag probabilities to avoid `ln(0)`.
+const MIN_PROBABILITY: f64 = 1e-300;
+
+
+/// The MILBoost algorithm
+/// proposed by Paul Viola, John C. Platt, and Cha Zhang.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Multiple Instance Boosting for Object Detection
+/// ](https://papers.nips.cc/paper/2926-multiple-instance-boosting-for-object-detection)
+/// by Paul Viola, John C. Platt, and Cha Zhang.
+///
+/// MILBoost is a boosting algorithm for multi-instance problems.
+/// The rows of the training sample are grouped into **bags**
+/// by the bag ids (see [`Sample::set_bags`]),
+/// and only the label of each bag is known.
+/// A bag is positive if at least one of its instances is positive.
+/// Every row of a bag must have the label of the bag as its target.
+///
+/// `MILBoost` models the probability of each bag by the noisy-OR model
+/// `p_i = 1 - Π_j (1 - p_ij)`,
+/// where `p_ij = 1 / (1 + exp(-F(x_ij)))`,
+/// and maximizes the bag-level log-likelihood
+/// by functional gradient ascent.
+/// At each round, the weak learner receives the distribution
+/// proportional to the absolute values of the instance gradients
+/// `w_ij = (t_i - p_i) p_ij / p_i`, where `t_i ∈ {0, 1}`.
+/// The sign of `w_ij` equals the bag label,
+/// so the weak learner that maximizes the edge
+/// maximizes the correlation with the gradient.
+/// The weight on the new hypothesis is determined by line search.
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // The column `bag` holds the bag ids.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap()
+///     .set_bags("bag");
+///
+/// let mut booster = MILBoost::init(&sample)
+///     .force_quit_at(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_bags(&sample);
+/// ```
+pub struct MILBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // `bag_of[j]` is the index of the bag that contains the `j`-th row.
+    bag_of: Vec<usize>,
+
+    // `true` if the bag is positive.
+    bag_labels: Vec<bool>,
+
+    // Distribution over the instances.
+    dist: Vec<f64>,
+
+    // Current scores `F(x_ij)` of the instances.
+    scores: Vec<f64>,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration of MILBoost.
+    max_iter: usize,
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> MILBoost<'a, F> {
+    /// Constructs a new instance of `MILBoost`.
+    /// This method panics if the bag ids of `sample` are not set.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        assert!(
+            sample.bags().is_some(),
+            "The bag ids are not set. Call `Sample::set_bags`"
+        );
+
+        Self {
+            sample,
+
+            bag_of: Vec::new(),
+            bag_labels: Vec::new(),
+            dist: Vec::new(),
+            scores: Vec::new(),
+
+            tolerance: 1e-6,
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            force_quit_at: None,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `MILBoost` terminates if the normalized correlation
+    /// between the new hypothesis and the gradient
+    /// is at most `tolerance`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the number of bags.
+    pub fn n_bags(&self) -> usize {
+        self.bag_labels.len()
+    }
+
+
+    /// Returns the bag-level log-likelihood
+    /// of the current combined hypothesis.
+    pub fn log_likelihood(&self) -> f64 {
+        self.log_likelihood_of(&self.scores[..])
+    }
+
+
+    /// Returns `ln(1 - p_i)` for each bag `i`
+    /// under the instance scores `scores`.
+    fn log_negative(&self, scores: &[f64]) -> Vec<f64> {
+        let mut log_negative = vec![0f64; self.n_bags()];
+        self.bag_of.iter()
+            .zip(scores)
+            .for_each(|(&i, &s)| { log_negative[i] -= softplus(s); });
+        log_negative
+    }
+
+
+    /// Returns the bag-level log-likelihood under `scores`.
+    fn log_likelihood_of(&self, scores: &[f64]) -> f64 {
+        self.log_negative(scores)
+            .into_iter()
+            .zip(&self.bag_labels)
+            .map(|(log_neg, &positive)| {
+                if positive {
+                    (-log_neg.exp_m1()).max(MIN_PROBABILITY).ln()
+                } else {
+                    log_neg
+                }
+            })
+            .sum::<f64>()
+    }
+
+
+    /// Returns the gradient `w_ij` of the log-likelihood
+    /// with respect to the score of each instance.
+    fn gradient(&self) -> Vec<f64> {
+        let probabilities = self.log_negative(&self.scores[..])
+            .into_iter()
+            .map(|log_neg| (-log_neg.exp_m1()).max(MIN_PROBABILITY))
+            .collect::<Vec<_>>();
+        self.bag_of.iter()
+            .zip(&self.scores)
+            .map(|(&i, &s)| {
+                let p_ij = 1f64 / (1f64 + (-s).exp());
+                if self.bag_labels[i] {
+                    (1f64 - probabilities[i]) * p_ij / probabilities[i]
+                } else {
+                    -p_ij
+                }
+            })
+            .collect()
+    }
+
+
+    /// Find the step size `α ∈ [0, MAX_STEP_SIZE]`
+    /// that maximizes the log-likelihood of `scores + α h`
+    /// by golden-section search.
+    fn line_search(&self, confidences: &[f64]) -> f64 {
+        let shifted = |alpha: f64| {
+            let scores = self.scores.iter()
+                .zip(confidences)
+                .map(|(s, h)| s + alpha * h)
+                .collect::<Vec<_>>();
+            self.log_likelihood_of(&scores[..])
+        };
+
+        let ratio = (5f64.sqrt() - 1f64) / 2f64;
+        let (mut lo, mut hi) = (0f64, MAX_STEP_SIZE);
+        let mut x1 = hi - ratio * (hi - lo);
+        let mut x2 = lo + ratio * (hi - lo);
+        let mut f1 = shifted(x1);
+        let mut f2 = shifted(x2);
+        for _ in 0..LINE_SEARCH_ITER {
+            if f1 < f2 {
+                lo = x1;
+                x1 = x2;
+                f1 = f2;
+                x2 = lo + ratio * (hi - lo);
+                f2 = shifted(x2);
+            } else {
+                hi = x2;
+                x2 = x1;
+                f2 = f1;
+                x1 = hi - ratio * (hi - lo);
+                f1 = shifted(x1);
+            }
+        }
+        (lo + hi) / 2f64
+    }
+}
+
+
+impl<F> Booster<F> for MILBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = BagClassifier<F>;
+
+
+    fn name(&self) -> &str {
+        "MILBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let n_bags = self.sample.bags()
+            .map(|bags| {
+                bags.iter()
+                    .collect::<HashSet<_>>()
+                    .len()
+            })
+            .unwrap_or(0);
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("# of bags", format!("{n_bags}")),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_iter)),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let bags = self.sample.bags()
+            .expect("The bag ids are not set. Call `Sample::set_bags`");
+        let target = self.sample.target();
+
+        let mut index = HashMap::new();
+        self.bag_of = Vec::with_capacity(bags.len());
+        self.bag_labels = Vec::new();
+        for (&bag, &y) in bags.iter().zip(target) {
+            let positive = y > 0f64;
+            let i = *index.entry(bag)
+                .or_insert_with(|| {
+                    self.bag_labels.push(positive);
+                    self.bag_labels.len() - 1
+                });
+            assert_eq!(
+                self.bag_labels[i], positive,
+                "The rows of bag {bag} have different labels"
+            );
+            self.bag_of.push(i);
+        }
+
+        let n_sample = self.sample.shape().0;
+        self.scores = vec![0f64; n_sample];
+        self.dist = vec![1f64 / n_sample as f64; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        if let Some(it) = self.force_quit_at {
+            self.max_iter = it;
+        }
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+        let gradient = self.gradient();
+        let norm = gradient.iter()
+            .map(|w| w.abs())
+            .sum::<f64>();
+        if norm <= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+        self.dist = gradient.iter()
+            .map(|w| w.abs() / norm)
+            .collect();
+
+
+        let h = weak_learner.produce(self.sample, &self.dist);
+        let confidences = h.confidence_all(self.sample);
+
+        let correlation = gradient.iter()
+            .zip(&confidences)
+            .map(|(w, h)| w * h)
+            .sum::<f64>()
+            / norm;
+        if correlation <= self.tolerance {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let weight = self.line_search(&confidences[..]);
+        self.scores.iter_mut()
+            .zip(&confidences)
+            .for_each(|(s, h)| { *s += weight * h; });
+
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for MILBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = BagClassifier<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        BagClassifier::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -56,46 +56,42 @@ impl LPModel {
     }
 
 
-    /// Solve the edge minimization problem over the hypotheses
-    /// `h1, ..., ht`.
-    /// The argument `h` is the new hypothesis `ht`.
-    pub(super) fn update<C>(
-        &mut self,
-        sample: &Sample,
-        opt_h: Option<&C>
-    ) -> Vec<f64>
+    /// Append the constraint `edge <= gamma` of a new hypothesis.
+    /// The LP is not solved until `self.solve()` is called.
+    pub(super) fn push<C>(&mut self, sample: &Sample, h: &C)
         where C: Classifier
     {
-        // If we got a new hypothesis,
-        // 1. append the corresponding constraint, and
-        // 2. optimize the model.
-        if let Some(h) = opt_h {
-            let edge = utils::margins_of_hypothesis(sample, h)
-                .into_iter()
-                .zip(self.dist.iter().copied())
-                .map(|(yh, d)| d * yh)
-                .grb_sum();
-
-
-            let name = format!("{t}-th hypothesis", t = self.constrs.len());
-            self.constrs.push(
-                self.model.add_constr(&name, c!(edge <= self.gamma))
-                    .expect("Failed to add a new constraint `edge <= gamma`")
+        let edge = utils::margins_of_hypothesis(sample, h)
+            .into_iter()
+            .zip(self.dist.iter().copied())
+            .map(|(yh, d)| d * yh)
+            .grb_sum();
+
+
+        let name = format!("{t}-th hypothesis", t = self.constrs.len());
+        self.constrs.push(
+            self.model.add_constr(&name, c!(edge <= self.gamma))
+                .expect("Failed to add a new constraint `edge <= gamma`")
+        );
+        self.model.update()
+            .expect(
+                "Failed to update the model after adding a new constraint"
             );
-            self.model.update()
-                .expect(
-                    "Failed to update the model after adding a new constraint"
-                );
-
-            self.model.optimize()
-                .expect("Failed to optimize the problem");
-
-            let status = self.model.status()
-                .expect("Failed to get the model status");
-            if status != Status::Optimal {
-                panic!("Status is {status:?}. Something wrong.");
-            }
+    }
+
+
+    /// Solve the edge minimization problem over the hypotheses
+    /// `h1, ..., ht` and outputs the optimal weights on them.
+    pub(super) fn solve(&mut self) -> Vec<f64> {
+        self.model.optimize()
+            .expect("Failed to optimize the problem");
+
+        let status = self.model.status()
+            .expect("Failed to get the model status");
+        if status != Status::Optimal {
+            panic!("Status is {status:?}. Something wrong.");
         }
+
         self.constrs.iter()
             .map(|c| self.model.get_obj_attr(attr::Pi, c).map(f64::abs))
             .collect::<Result<Vec<_>, _>>()
@@ -20,7 +20,7 @@ use crate::{
     common::{
         utils,
         checker,
-        frank_wolfe::{FrankWolfe, FWType},
+        frank_wolfe::{FrankWolfe, FWType, SecondaryType},
     },
     research::Research,
 };
@@ -80,6 +80,12 @@ use std::ops::ControlFlow;
 ///   the fastest.
 /// - One can choose different Frank-Wolfe algorithm.
 ///   See [`MLPBoost::frank_wolfe`].
+/// - One can also choose when `MLPBoost` solves the linear program.
+///   [`SecondaryType::Never`] yields the pure Frank-Wolfe algorithm,
+///   [`SecondaryType::Every`] solves the LP every `k` rounds,
+///   and [`SecondaryType::Adaptive`] solves the LP only if
+///   the Frank-Wolfe step makes little progress.
+///   See [`MLPBoost::secondary`].
 ///
 ///
 /// # Example
@@ -167,6 +173,9 @@ pub struct MLPBoost<'a, F> {
     // Secondary (LPBoost) update
     secondary: Option<RefCell<LPModel>>,
 
+    // The rule that decides when the secondary update is called.
+    secondary_type: SecondaryType,
+
 
     // Weights on hypotheses
     weights: Vec<f64>,
@@ -208,6 +217,7 @@ impl<'a, F> MLPBoost<'a, F> {
 
             primary,
             secondary: None,
+            secondary_type: SecondaryType::Every(1),
 
             weights: Vec::new(),
             hypotheses: Vec::new(),
@@ -243,6 +253,21 @@ impl<'a, F> MLPBoost<'a, F> {
     }
 
 
+    /// Set the rule for the secondary (LPBoost) update.
+    /// See [`SecondaryType`].
+    /// Default is `SecondaryType::Every(1)`,
+    /// i.e., `MLPBoost` solves the LP every round.
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn secondary(mut self, secondary_type: SecondaryType) -> Self {
+        if let SecondaryType::Every(k) = secondary_type {
+            assert!(k > 0, "The LP interval `k` must be positive");
+        }
+        self.secondary_type = secondary_type;
+        self
+    }
+
+
     /// Set the tolerance parameter.
     /// 
     /// Time complexity: `O(1)`.
@@ -265,9 +290,16 @@ impl<'a, F> MLPBoost<'a, F> {
 
 
     /// Initialize the LP solver.
+    /// If the secondary update is never called,
+    /// `MLPBoost` does not construct the LP model.
     /// 
     /// Time complexity: `O( # of training examples )`.
     fn init_solver(&mut self) {
+        if let SecondaryType::Never = self.secondary_type {
+            self.secondary = None;
+            return;
+        }
+
         // `ub` is the upper-bound of distribution for each example.
         let ub = 1.0 / self.nu;
 
@@ -297,7 +329,11 @@ impl<'a, F> MLPBoost<'a, F> {
     /// Time complexity: `O(1)`.
     pub fn max_loop(&self) -> usize {
         let ln_m = (self.n_sample as f64 / self.nu).ln();
-        (8.0_f64 * ln_m / self.half_tolerance.powi(2)).ceil() as usize
+        let max_iter = 8.0_f64 * ln_m / self.half_tolerance.powi(2);
+
+        // `ln_m` vanishes for `nu == n_sample`,
+        // so the bound of ERLPBoost keeps at least one round.
+        max_iter.max(4.0 / self.half_tolerance).ceil() as usize
     }
 
 
@@ -313,11 +349,19 @@ impl<'a, F> MLPBoost<'a, F> {
 impl<F> MLPBoost<'_, F>
     where F: Classifier,
 {
-    fn secondary_update(&self, opt_h: Option<&F>) -> Vec<f64> {
+    /// Append the constraint of a new hypothesis to the LP model.
+    fn secondary_push(&self, h: &F) {
+        if let Some(lp_model) = self.secondary.as_ref() {
+            lp_model.borrow_mut().push(self.sample, h);
+        }
+    }
+
+
+    fn secondary_update(&self) -> Vec<f64> {
         self.secondary.as_ref()
             .expect("Failed to call `.as_ref()` to `self.secondary`")
             .borrow_mut()
-            .update(self.sample, opt_h)
+            .solve()
     }
 
 
@@ -388,7 +432,7 @@ impl<F> Booster<F> for MLPBoost<'_, F>
             ("Max iteration", format!("{}", self.max_iter)),
             ("Capping (outliers)", format!("{nu} ({ratio: >7.3} %)")),
             ("Primary", format!("{}", self.primary.current_type())),
-            ("Secondary", format!("LPBoost"))
+            ("Secondary", format!("{}", self.secondary_type)),
         ]);
         Some(info)
     }
@@ -458,7 +502,7 @@ impl<F> Booster<F> for MLPBoost<'_, F>
             self.weights.push(1.0_f64);
 
             // **DO NOT FORGET** to update the LP model.
-            let _ = self.secondary_update(self.hypotheses.last());
+            self.secondary_push(&self.hypotheses[0]);
 
             return ControlFlow::Continue(())
         }
@@ -480,7 +524,6 @@ impl<F> Booster<F> for MLPBoost<'_, F>
         // Now, we move to the update of `weights`.
         // We first check whether `h` is obtained in past iterations
         // or not.
-        let mut opt_h = None;
         let pos = self.hypotheses.iter()
             .position(|f| *f == h)
             .unwrap_or(self.hypotheses.len());
@@ -491,7 +534,7 @@ impl<F> Booster<F> for MLPBoost<'_, F>
         if pos == self.hypotheses.len() {
             self.hypotheses.push(h);
             self.weights.push(0.0);
-            opt_h = self.hypotheses.last();
+            self.secondary_push(&self.hypotheses[pos]);
         }
 
 
@@ -503,10 +546,24 @@ impl<F> Booster<F> for MLPBoost<'_, F>
         );
 
         // Secondary update
-        let seco = self.secondary_update(opt_h);
-
-        // Choose the better one
-        self.better_weight(prim, seco);
+        let call_secondary = match self.secondary_type {
+            SecondaryType::Never => false,
+            SecondaryType::Every(k) => iteration.is_multiple_of(k),
+            SecondaryType::Adaptive => {
+                let gap = self.gamma - objval;
+                let next_gap = self.gamma - self.objval(&prim[..]);
+                next_gap > 0.5 * gap
+            },
+        };
+
+        if call_secondary {
+            let seco = self.secondary_update();
+
+            // Choose the better one
+            self.better_weight(prim, seco);
+        } else {
+            self.weights = prim;
+        }
 
         // DEBUG
         checker::check_capped_simplex_condition(&self.weights[..], 1.0);
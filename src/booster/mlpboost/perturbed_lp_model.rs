@@ -94,22 +94,26 @@ impl LPModel {
     }
 
 
-    /// Solve the edge minimization problem 
-    /// over the hypotheses `h1, ..., ht` 
-    /// and outputs the optimal value.
-    pub(super) fn update<F>(
-        &mut self,
-        sample: &Sample,
-        opt_h: Option<&F>
-    ) -> Vec<f64>
+    /// Append the margin constraint of a new hypothesis.
+    /// The LP is not solved until `self.solve()` is called.
+    pub(super) fn push<F>(&mut self, sample: &Sample, clf: &F)
         where F: Classifier
     {
-        if let Some(clf) = opt_h {
-            self.n_hypotheses += 1;
-            let margins = utils::margins_of_hypothesis(sample, clf);
-            self.margins.iter_mut()
-                .zip(margins)
-                .for_each(|(mvec, yh)| { mvec.push(yh); });
+        self.n_hypotheses += 1;
+        let margins = utils::margins_of_hypothesis(sample, clf);
+        self.margins.iter_mut()
+            .zip(margins)
+            .for_each(|(mvec, yh)| { mvec.push(yh); });
+    }
+
+
+    /// Solve the edge minimization problem 
+    /// over the hypotheses `h1, ..., ht` 
+    /// and outputs the optimal weights on them.
+    /// If no hypothesis is appended after the last call,
+    /// this method returns the previous solution.
+    pub(super) fn solve(&mut self) -> Vec<f64> {
+        if self.weights.len() < self.n_hypotheses {
             let constraint_matrix = self.build_constraint_matrix();
             let sense = self.build_sense();
             let rhs = self.build_rhs();
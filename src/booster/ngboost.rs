@@ -0,0 +1,13 @@
+//! The NGBoost algorithm proposed
+//! by Tony Duan, Anand Avati, Daisy Yi Ding, Khanh K. Thai,
+//! Sanjay Basu, Andrew Y. Ng, and Alejandro Schuler.
+//! This algorithm is based on the paper: 
+//! [
+//! NGBoost: Natural Gradient Boosting for Probabilistic Prediction
+//! ](https://proceedings.mlr.press/v119/duan20a.html)
+//! 
+//! NGBoost fits the parameters of a predictive distribution
+//! by the natural gradient steps on the negative log-likelihood.
+pub mod ngboost_algorithm;
+
+pub use ngboost_algorithm::NGBoost;
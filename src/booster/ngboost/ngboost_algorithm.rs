@@ -0,0 +1,378 @@
+//! Provides [`NGBoost`] by Duan et al., 2020.
+use rayon::prelude::*;
+
+use crate::{
+    Sample,
+    Booster,
+    WeakLearner,
+    Regressor,
+    NormalRegressor,
+
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The maximum number of halvings in the line search.
+const MAX_HALVING: usize = 50;
+/// The smallest log-scale of the initial distribution.
+const MIN_LOG_SCALE: f64 = -20f64;
+
+
+/// The NGBoost algorithm
+/// proposed by Tony Duan, Anand Avati, Daisy Yi Ding, Khanh K. Thai,
+/// Sanjay Basu, Andrew Y. Ng, and Alejandro Schuler.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// NGBoost: Natural Gradient Boosting for Probabilistic Prediction
+/// ](https://proceedings.mlr.press/v119/duan20a.html)
+/// by Tony Duan et al.
+///
+/// `NGBoost` predicts a normal distribution `N(μ(x), σ(x)²)`
+/// for each example `x`, instead of a point estimate.
+/// `NGBoost` maintains two additive models,
+/// one for the mean `μ` and the other for the log-scale `s = ln σ`,
+/// starting from the marginal distribution of the targets.
+/// At each round, `NGBoost` computes the *natural gradient*
+/// of the negative log-likelihood
+/// `s + (y - μ)² / (2 σ²)`
+/// on each example, i.e.,
+/// the gradient scaled by the inverse of the Fisher information
+/// `diag(1 / σ², 2)`:
+/// ```txt
+/// g_μ = -(y - μ),
+/// g_s = (1 - (y - μ)² / σ²) / 2,
+/// ```
+/// and fits one weak hypothesis to `- g_μ`
+/// and another to `- g_s`.
+/// Then, `NGBoost` finds the step size `ρ` by halving from `1`
+/// until the negative log-likelihood decreases,
+/// and adds the new hypotheses to the models with weight `η ρ`,
+/// where `η` is the learning rate.
+/// `NGBoost` terminates if no step size decreases
+/// the negative log-likelihood,
+/// or after `max_iter` iterations.
+///
+/// To fit a hypothesis to `r`,
+/// `NGBoost` passes the *predictions* `y - r` to the weak learner,
+/// so that the weak learner must minimize [`GBMLoss::L2`]
+/// by the Newton steps.
+/// [`RegressionTree`](crate::weak_learner::RegressionTree)
+/// built with `.loss(GBMLoss::L2)` does it.
+///
+/// [`GBMLoss::L2`]: crate::common::loss_functions::GBMLoss::L2
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `target` as the target value.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("target")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = NGBoost::init(&sample)
+///     .learning_rate(0.1)
+///     .max_iter(500);
+///
+/// // The weak learner must be trained on the L2 loss.
+/// let weak_learner = RegressionTreeBuilder::new(&sample)
+///     .max_depth(3)
+///     .loss(GBMLoss::L2)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+///
+/// // `(mean, variance)` of the predictive distribution.
+/// let distributions = f.predict_dist_all(&sample);
+/// ```
+pub struct NGBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Learning rate
+    learning_rate: f64,
+
+    // The initial mean and log-scale.
+    base: (f64, f64),
+
+    // Weights on hypotheses in `mean_hypotheses`
+    mean_weights: Vec<f64>,
+
+    // Hypotheses for the mean.
+    mean_hypotheses: Vec<F>,
+
+    // Weights on hypotheses in `scale_hypotheses`
+    scale_weights: Vec<f64>,
+
+    // Hypotheses for the log-scale.
+    scale_hypotheses: Vec<F>,
+
+    // Max iteration of NGBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+    // The current means `μ(x)` on the training examples.
+    means: Vec<f64>,
+
+    // The current log-scales `s(x)` on the training examples.
+    log_scales: Vec<f64>,
+
+    // The current negative log-likelihood.
+    nll: f64,
+}
+
+
+impl<'a, F> NGBoost<'a, F> {
+    /// Constructs a new instance of `NGBoost`.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            learning_rate: 0.1,
+            base: (0f64, 0f64),
+
+            mean_weights: Vec::new(),
+            mean_hypotheses: Vec::new(),
+            scale_weights: Vec::new(),
+            scale_hypotheses: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            means: Vec::new(),
+            log_scales: Vec::new(),
+            nll: f64::MAX,
+        }
+    }
+
+
+    /// Set the learning rate `η ∈ (0, 1]`.
+    /// Default is `0.1`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn learning_rate(mut self, eta: f64) -> Self {
+        assert!(
+            0f64 < eta && eta <= 1f64,
+            "The learning rate must be in (0, 1]"
+        );
+        self.learning_rate = eta;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the current average negative log-likelihood
+    /// (without the constant `ln √(2π)`)
+    /// on the training examples.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn negative_log_likelihood(&self) -> f64 {
+        self.nll
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the average negative log-likelihood
+    /// `s + (y - μ)² / (2 exp(2s))`.
+    fn eval(&self, means: &[f64], log_scales: &[f64]) -> f64 {
+        let target = self.sample.target();
+        let n_sample = target.len() as f64;
+        target.par_iter()
+            .zip(means)
+            .zip(log_scales)
+            .map(|((y, m), s)| s + 0.5 * ((y - m) / s.exp()).powi(2))
+            .sum::<f64>()
+            / n_sample
+    }
+}
+
+
+impl<F> Booster<F> for NGBoost<'_, F>
+    where F: Regressor + Clone,
+{
+    type Output = NormalRegressor<F>;
+
+
+    fn name(&self) -> &str {
+        "NGBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Distribution", "Normal".to_string()),
+            ("Learning rate", format!("{}", self.learning_rate)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let target = self.sample.target();
+        let n_sample = target.len();
+
+
+        // Start from the marginal distribution of the targets.
+        let mean = target.iter().sum::<f64>() / n_sample as f64;
+        let variance = target.iter()
+            .map(|y| (y - mean).powi(2))
+            .sum::<f64>()
+            / n_sample as f64;
+        let log_scale = (0.5 * variance.ln()).max(MIN_LOG_SCALE);
+        self.base = (mean, log_scale);
+
+
+        self.mean_weights = Vec::with_capacity(self.max_iter);
+        self.mean_hypotheses = Vec::with_capacity(self.max_iter);
+        self.scale_weights = Vec::with_capacity(self.max_iter);
+        self.scale_hypotheses = Vec::with_capacity(self.max_iter);
+
+        self.terminated = self.max_iter;
+        self.means = vec![mean; n_sample];
+        self.log_scales = vec![log_scale; n_sample];
+        self.nll = self.eval(&self.means[..], &self.log_scales[..]);
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // The weak learner fits `y - p` for the given predictions `p`.
+        // For the mean, `- g_μ = y - μ` so that `p = μ`.
+        let target = self.sample.target();
+        let scale_predictions = target.par_iter()
+            .zip(&self.means[..])
+            .zip(&self.log_scales[..])
+            .map(|((y, m), s)| {
+                let z = (y - m) / s.exp();
+                y - 0.5 * (z * z - 1f64)
+            })
+            .collect::<Vec<_>>();
+        let f = weak_learner.produce(self.sample, &self.means[..]);
+        let g = weak_learner.produce(self.sample, &scale_predictions[..]);
+
+        let f_predictions = f.predict_all(self.sample);
+        let g_predictions = g.predict_all(self.sample);
+
+
+        // Find the step size by halving.
+        let mut rho = 1f64;
+        let mut step = None;
+        for _ in 0..MAX_HALVING {
+            let means = self.means.iter()
+                .zip(&f_predictions[..])
+                .map(|(m, p)| m + rho * p)
+                .collect::<Vec<_>>();
+            let log_scales = self.log_scales.iter()
+                .zip(&g_predictions[..])
+                .map(|(s, p)| s + rho * p)
+                .collect::<Vec<_>>();
+            if self.eval(&means[..], &log_scales[..]) < self.nll {
+                step = Some(rho);
+                break;
+            }
+            rho *= 0.5;
+        }
+
+
+        // If no step size decreases the negative log-likelihood,
+        // NGBoost cannot make progress.
+        let Some(rho) = step else {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        };
+
+
+        let eta = self.learning_rate * rho;
+        self.means.par_iter_mut()
+            .zip(f_predictions)
+            .for_each(|(m, p)| { *m += eta * p; });
+        self.log_scales.par_iter_mut()
+            .zip(g_predictions)
+            .for_each(|(s, p)| { *s += eta * p; });
+        self.nll = self.eval(&self.means[..], &self.log_scales[..]);
+
+        self.mean_weights.push(eta);
+        self.mean_hypotheses.push(f);
+        self.scale_weights.push(eta);
+        self.scale_hypotheses.push(g);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for NGBoost<'_, H>
+    where H: Regressor + Clone,
+{
+    type Output = NormalRegressor<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        NormalRegressor {
+            base_mean: self.base.0,
+            base_log_scale: self.base.1,
+            mean_weights: self.mean_weights.clone(),
+            mean_hypotheses: self.mean_hypotheses.clone(),
+            scale_weights: self.scale_weights.clone(),
+            scale_hypotheses: self.scale_hypotheses.clone(),
+        }
+    }
+}
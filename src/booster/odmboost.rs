@@ -0,0 +1,10 @@
+//! ODMBoost module.
+//! ODMBoost optimizes the margin distribution,
+//! the mean and the variance of the margins,
+//! rather than the minimum margin.
+
+pub mod odmboost_algorithm;
+
+mod qp_model;
+
+pub use odmboost_algorithm::ODMBoost;
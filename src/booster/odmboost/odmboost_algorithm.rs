@@ -0,0 +1,387 @@
+//! This file defines `ODMBoost`,
+//! a boosting algorithm that optimizes the margin distribution.
+use super::qp_model::QPModel;
+
+use crate::{
+    Sample,
+    Booster,
+    WeakLearner,
+
+    Classifier,
+    WeightedMajority,
+    common::utils,
+    research::Research,
+};
+
+
+use std::ops::ControlFlow;
+
+
+/// The ODMBoost, a boosting algorithm
+/// that optimizes the **margin distribution**.
+/// This struct is based on the papers:
+///
+/// [
+/// Optimal Margin Distribution Machine
+/// ](https://arxiv.org/abs/1604.03348)
+/// by Teng Zhang and Zhi-Hua Zhou, and
+///
+/// [
+/// Boosting through Optimization of Margin Distributions
+/// ](https://arxiv.org/abs/0904.2037)
+/// by Chunhua Shen and Hanxi Li.
+///
+/// [`LPBoost`](crate::booster::LPBoost) and
+/// [`ERLPBoost`](crate::booster::ERLPBoost) maximize
+/// the minimum (soft) margin over the training examples.
+/// Zhang and Zhou showed that
+/// the margin mean and the margin variance
+/// characterize the generalization performance better
+/// than the minimum margin.
+/// Given a set of training examples `(x_1, y_1), ..., (x_m, y_m)`,
+/// `ODMBoost` solves the following problem
+/// over the hypotheses `h_1, h_2, ..., h_T`
+/// obtained so far:
+///
+/// ```txt
+/// max μ(w) - (λ/2) σ²(w)
+///  w
+/// s.t. Σ_j w_j = 1,
+///      w_1, w_2, ..., w_T ≥ 0,
+/// ```
+/// where `ρ_i = y_i Σ_j w_j h_j (x_i)` is the margin on the `i`-th example,
+/// `μ(w) = (1/m) Σ_i ρ_i` is the margin mean,
+/// `σ²(w) = (1/m) Σ_i (ρ_i - μ(w))²` is the margin variance,
+/// and `λ ≥ 0` is the weight on the variance
+/// (see [`ODMBoost::variance_weight`]).
+///
+/// At each round, `ODMBoost` passes the gradient
+/// `g_i = (1/m) (1 - λ (ρ_i - μ(w)))`
+/// of the objective with respect to the margins
+/// to the weak learner.
+/// Since `g_i` is negative for the examples with large margins,
+/// the weak learner receives the distribution `d_i ∝ |g_i|`
+/// over the training examples whose labels are flipped if `g_i < 0`.
+/// `ODMBoost` terminates if the new hypothesis `h` does not improve
+/// the objective more than the tolerance parameter `ε`,
+/// i.e., `Σ_i g_i y_i h(x_i) - Σ_i g_i ρ_i ≤ ε`.
+/// Otherwise, `ODMBoost` re-optimizes the weights on all the hypotheses.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = ODMBoost::init(&sample)
+///     .variance_weight(1.0)
+///     .tolerance(0.01);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct ODMBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // The training sample with the labels flipped
+    // on the examples with negative gradients.
+    flipped_sample: Sample,
+
+    // Distribution over examples
+    dist: Vec<f64>,
+
+    // The gradient of the objective with respect to the margins.
+    gradient: Vec<f64>,
+
+    // The current margins `ρ_i`.
+    margins: Vec<f64>,
+
+    // The weight `λ` on the margin variance.
+    variance_weight: f64,
+
+    // Tolerance parameter
+    tolerance: f64,
+
+    // The improvement of the objective by the last hypothesis.
+    gap: f64,
+
+    // QP model.
+    qp_model: Option<QPModel>,
+
+    hypotheses: Vec<F>,
+    weights: Vec<f64>,
+
+    // Max iteration of ODMBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> ODMBoost<'a, F> {
+    /// Constructs a new instance of `ODMBoost`.
+    ///
+    /// Time complexity: `O(mn)`,
+    /// where `m` is the number of training examples
+    /// and `n` is the number of features.
+    pub fn init(sample: &'a Sample) -> Self {
+        let n_sample = sample.shape().0;
+
+        Self {
+            sample,
+            flipped_sample: sample.clone(),
+
+            dist: Vec::new(),
+            gradient: Vec::new(),
+            margins: Vec::new(),
+
+            variance_weight: 1.0,
+            tolerance: 1.0 / n_sample as f64,
+            gap: f64::MAX,
+
+            qp_model: None,
+
+            hypotheses: Vec::new(),
+            weights: Vec::new(),
+
+            max_iter: 1_000,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the weight `λ ≥ 0` on the margin variance.
+    /// If `λ = 0`, `ODMBoost` maximizes the margin mean.
+    /// Default is `1.0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn variance_weight(mut self, lambda: f64) -> Self {
+        assert!(
+            lambda >= 0f64,
+            "The weight on the margin variance must be non-negative"
+        );
+        self.variance_weight = lambda;
+        self
+    }
+
+
+    /// Set the tolerance parameter.
+    /// `ODMBoost` terminates if the new hypothesis improves
+    /// the objective by at most `tolerance`.
+    /// Default is `1/m`,
+    /// where `m` is the number of training examples.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `1000`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the improvement of the objective
+    /// by the last hypothesis,
+    /// `Σ_i g_i y_i h(x_i) - Σ_i g_i ρ_i`.
+    /// This is an upper bound of the sub-optimality of the current weights
+    /// if the weak learner returns the best hypothesis.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn gap(&self) -> f64 {
+        self.gap
+    }
+
+
+    /// Returns the current objective value `μ(w) - (λ/2) σ²(w)`.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    pub fn objective(&self) -> f64 {
+        let (mean, variance) = self.mean_and_variance();
+        mean - 0.5 * self.variance_weight * variance
+    }
+
+
+    /// Returns the mean and the variance of the current margins.
+    fn mean_and_variance(&self) -> (f64, f64) {
+        let n_sample = self.margins.len() as f64;
+        let mean = self.margins.iter().sum::<f64>() / n_sample;
+        let variance = self.margins.iter()
+            .map(|rho| (rho - mean).powi(2))
+            .sum::<f64>()
+            / n_sample;
+        (mean, variance)
+    }
+
+
+    /// Updates the gradient, the flipped labels, and the distribution
+    /// from the current margins.
+    fn update_distribution(&mut self) {
+        let n_sample = self.margins.len() as f64;
+        let (mean, _) = self.mean_and_variance();
+        self.gradient = self.margins.iter()
+            .map(|rho| (1f64 - self.variance_weight * (rho - mean)) / n_sample)
+            .collect();
+
+
+        let target = self.sample.target();
+        self.flipped_sample.target_mut()
+            .iter_mut()
+            .zip(target)
+            .zip(&self.gradient[..])
+            .for_each(|((flipped, y), g)| {
+                *flipped = if *g < 0f64 { - y } else { *y };
+            });
+
+        self.dist = self.gradient.iter()
+            .map(|g| g.abs())
+            .collect();
+        utils::normalize(&mut self.dist[..]);
+    }
+}
+
+
+impl<F> Booster<F> for ODMBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "ODMBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Variance weight", format!("{}", self.variance_weight)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+        self.qp_model = Some(QPModel::init(n_sample, self.variance_weight));
+
+        self.margins = vec![0f64; n_sample];
+        self.update_distribution();
+
+        self.gap = f64::MAX;
+        self.hypotheses = Vec::new();
+        self.weights = Vec::new();
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        let h = weak_learner.produce(&self.flipped_sample, &self.dist);
+
+
+        // The improvement of the objective along `h`.
+        let new_margins = utils::margins_of_hypothesis(self.sample, &h);
+        let edge = utils::inner_product(&self.gradient[..], &new_margins[..]);
+        let current = utils::inner_product(&self.gradient[..], &self.margins[..]);
+        self.gap = edge - current;
+        if !self.hypotheses.is_empty() && self.gap <= self.tolerance {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        // Re-optimize the weights on all the hypotheses.
+        let qp_model = self.qp_model.as_mut()
+            .expect("Failed to call `.as_mut()` to `self.qp_model`");
+        qp_model.update(self.sample, &h);
+        self.weights = qp_model.weight().collect();
+        self.hypotheses.push(h);
+
+
+        // Update the margins.
+        let f = self.current_hypothesis();
+        let target = self.sample.target();
+        self.margins = f.confidence_all(self.sample)
+            .into_iter()
+            .zip(target)
+            .map(|(fx, y)| y * fx)
+            .collect();
+        self.update_distribution();
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for ODMBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
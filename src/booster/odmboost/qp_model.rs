@@ -0,0 +1,167 @@
+use clarabel::{
+    algebra::*,
+    solver::*,
+};
+
+use crate::{
+    Sample,
+    common::utils,
+};
+
+use crate::hypothesis::Classifier;
+
+
+/// A quadratic programming model for the margin distribution.
+/// `QPModel` solves the following problem
+/// over the hypotheses `h_1, h_2, ..., h_T`:
+///
+/// ```txt
+/// max μ(w) - (λ/2) σ²(w)
+///  w
+/// s.t. Σ_j w_j = 1,
+///      w_1, w_2, ..., w_T ≥ 0,
+/// ```
+/// where `ρ_i = y_i Σ_j w_j h_j (x_i)` is the margin on the `i`-th example,
+/// `μ(w) = (1/m) Σ_i ρ_i` is the margin mean, and
+/// `σ²(w) = (1/m) Σ_i (ρ_i - μ(w))²` is the margin variance.
+///
+/// Let `M` be the `m x T` matrix with `M_ij = y_i h_j (x_i)`
+/// and `C = I - (1/m) 1 1^T` be the centering matrix.
+/// Since the `clarabel` crate solves the minimization problems,
+/// we solve
+/// ```txt
+/// min (1/2) w^T P w + q^T w
+///  w
+/// s.t. Σ_j w_j = 1,
+///      w_1, w_2, ..., w_T ≥ 0,
+/// ```
+/// where `P = (λ/m) M^T C M` and `q = - (1/m) M^T 1`.
+pub(super) struct QPModel {
+    pub(self) n_examples: usize,        // number of examples
+    pub(self) variance_weight: f64,     // the weight `λ` on the variance
+    pub(self) margins: Vec<Vec<f64>>,   // margin vector of each hypothesis
+    pub(self) weights: Vec<f64>,        // weight on hypothesis
+}
+
+
+impl QPModel {
+    /// Initialize the QP model.
+    /// arguments.
+    /// - `size`: Number of examples.
+    /// - `variance_weight`: The weight `λ ≥ 0` on the margin variance.
+    pub(super) fn init(size: usize, variance_weight: f64) -> Self {
+        Self {
+            n_examples: size,
+            variance_weight,
+            margins:    Vec::new(),
+            weights:    Vec::new(),
+        }
+    }
+
+
+    /// Append the margins of `clf` to the model
+    /// and solve the problem over the hypotheses obtained so far.
+    pub(super) fn update<F>(&mut self, sample: &Sample, clf: &F)
+        where F: Classifier
+    {
+        let margins = utils::margins_of_hypothesis(sample, clf);
+        self.margins.push(margins);
+        self.solve();
+    }
+
+
+    /// Solve the problem over the hypotheses appended so far.
+    fn solve(&mut self) {
+        let n_hypotheses = self.margins.len();
+        let m = self.n_examples as f64;
+
+
+        // Center each column of `M`.
+        let centered = self.margins.iter()
+            .map(|col| {
+                let mean = col.iter().sum::<f64>() / m;
+                col.iter().map(|yh| yh - mean).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+
+        // The upper triangular part of `P` in the CSC form.
+        // NOTE:
+        // we do not need to multiply 0.5f64 
+        // since clarabel add it automatically.
+        let mut col_ptr = Vec::with_capacity(n_hypotheses + 1);
+        let mut row_val = Vec::new();
+        let mut nonzero = Vec::new();
+        for k in 0..n_hypotheses {
+            col_ptr.push(row_val.len());
+            for j in 0..=k {
+                let p = centered[j].iter()
+                    .zip(&centered[k][..])
+                    .map(|(a, b)| a * b)
+                    .sum::<f64>();
+                row_val.push(j);
+                nonzero.push(self.variance_weight * p / m);
+            }
+        }
+        col_ptr.push(row_val.len());
+        let quad = CscMatrix::new(
+            n_hypotheses, n_hypotheses, col_ptr, row_val, nonzero,
+        );
+
+
+        let linear = self.margins.iter()
+            .map(|col| - col.iter().sum::<f64>() / m)
+            .collect::<Vec<_>>();
+
+
+        // The constraint matrix.
+        // The first row is the simplex constraint `Σ_j w_j = 1`
+        // and the others are the non-negative constraints `- w_j ≤ 0`.
+        let mut col_ptr = Vec::with_capacity(n_hypotheses + 1);
+        let mut row_val = Vec::with_capacity(2 * n_hypotheses);
+        let mut nonzero = Vec::with_capacity(2 * n_hypotheses);
+        for j in 0..n_hypotheses {
+            col_ptr.push(row_val.len());
+            row_val.push(0);
+            nonzero.push(1f64);
+            row_val.push(1 + j);
+            nonzero.push(-1f64);
+        }
+        col_ptr.push(row_val.len());
+        let constraint_matrix = CscMatrix::new(
+            1 + n_hypotheses, n_hypotheses, col_ptr, row_val, nonzero,
+        );
+
+        let mut rhs = vec![0f64; 1 + n_hypotheses];
+        rhs[0] = 1f64;
+        let cones = [ZeroConeT(1), NonnegativeConeT(n_hypotheses)];
+
+
+        let settings = DefaultSettingsBuilder::default()
+            .equilibrate_enable(true)
+            .verbose(false)
+            .build()
+            .unwrap();
+        let mut solver = DefaultSolver::new(
+            &quad,
+            &linear,
+            &constraint_matrix,
+            &rhs[..],
+            &cones,
+            settings
+        );
+        solver.solve();
+
+        // The solution may have tiny negative values.
+        self.weights = solver.solution.x.iter()
+            .map(|w| w.max(0f64))
+            .collect();
+    }
+
+
+    /// Returns the weights over the hypotheses.
+    pub(super) fn weight(&self) -> impl Iterator<Item=f64> + '_
+    {
+        self.weights.iter().copied()
+    }
+}
@@ -0,0 +1,7 @@
+//! One-vs-all module.
+//! This module reduces a multiclass problem
+//! to the binary problems, one per class,
+//! and trains any binary booster on each of them.
+pub mod one_vs_all_algorithm;
+
+pub use one_vs_all_algorithm::OneVsAll;
@@ -0,0 +1,121 @@
+//! This file defines `OneVsAll`,
+//! a meta-booster that trains one binary booster per class.
+use rayon::prelude::*;
+
+use crate::{
+    Sample,
+    Classifier,
+    OneVsAllClassifier,
+};
+
+
+/// `OneVsAll` reduces a multiclass problem to the binary ones.
+/// For each class `k`, `OneVsAll` derives the binary sample
+/// whose label is `+1` if the class is `k` and `-1` otherwise,
+/// and trains a binary classifier on it.
+/// The derived samples share the features with the training sample,
+/// so that `OneVsAll` does not copy the feature matrix.
+/// The binary classifiers are trained in parallel.
+///
+/// The returned [`OneVsAllClassifier`] predicts the class
+/// whose classifier has the largest confidence,
+/// e.g., the largest margin for [`WeightedMajority`].
+///
+/// [`WeightedMajority`]: crate::hypothesis::WeightedMajority
+///
+/// # Example
+/// The following code trains [`LPBoost`](crate::booster::LPBoost)
+/// for each class.
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // The column `class` has the class labels `0, 1, 2, ...`.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let f = OneVsAll::new(&sample)
+///     .run(|binary| {
+///         let n_sample = binary.shape().0 as f64;
+///         let weak_learner = DecisionTreeBuilder::new(binary)
+///             .max_depth(2)
+///             .criterion(Criterion::Entropy)
+///             .build();
+///         LPBoost::init(binary)
+///             .tolerance(0.01)
+///             .nu(0.1 * n_sample)
+///             .run(&weak_learner)
+///     });
+/// let predictions = f.predict_class_all(&sample);
+/// ```
+pub struct OneVsAll<'a> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Class labels in ascending order.
+    classes: Vec<i64>,
+}
+
+
+impl<'a> OneVsAll<'a> {
+    /// Constructs a new instance of `OneVsAll`.
+    /// The target values of `sample` are the class labels.
+    ///
+    /// Time complexity: `O(m log m)`,
+    /// where `m` is the number of training examples.
+    pub fn new(sample: &'a Sample) -> Self {
+        let classes = sample.unique_target()
+            .into_iter()
+            .map(|y| y as i64)
+            .collect::<Vec<_>>();
+        assert!(
+            classes.len() >= 2,
+            "The training sample must have at least two classes"
+        );
+        Self { sample, classes }
+    }
+
+
+    /// Returns the class labels in ascending order.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn classes(&self) -> &[i64] {
+        &self.classes[..]
+    }
+
+
+    /// Returns the binary sample for `class`,
+    /// whose label is `+1` if the class is `class` and `-1` otherwise.
+    /// The returned sample shares the features with the training sample.
+    ///
+    /// Time complexity: `O(m)`,
+    /// where `m` is the number of training examples.
+    pub fn binary_sample(&self, class: i64) -> Sample {
+        let target = self.sample.target()
+            .iter()
+            .map(|&y| if y as i64 == class { 1f64 } else { -1f64 })
+            .collect::<Vec<_>>();
+        self.sample.with_target(target)
+    }
+
+
+    /// Trains a binary classifier `train(binary)`
+    /// on the binary sample of each class in parallel
+    /// and returns the combined multiclass classifier.
+    /// Typically, `train` runs a booster on `binary`.
+    pub fn run<H, G>(&self, train: G) -> OneVsAllClassifier<H>
+        where G: Fn(&Sample) -> H + Sync,
+              H: Classifier + Send,
+    {
+        let hypotheses = self.classes.par_iter()
+            .map(|&class| {
+                let binary = self.binary_sample(class);
+                train(&binary)
+            })
+            .collect::<Vec<_>>();
+        OneVsAllClassifier::new(self.classes.clone(), hypotheses)
+    }
+}
@@ -0,0 +1,12 @@
+//! The RankBoost algorithm proposed by
+//! Yoav Freund, Raj Iyer, Robert E. Schapire, and Yoram Singer.
+//! This algorithm is based on the paper: 
+//! [
+//! An Efficient Boosting Algorithm for Combining Preferences
+//! ](https://www.jmlr.org/papers/v4/freund03a.html)
+//! 
+//! RankBoost minimizes the ranking loss
+//! over the pairs of positive and negative examples.
+pub mod rankboost_algorithm;
+
+pub use rankboost_algorithm::RankBoost;
@@ -0,0 +1,354 @@
+//! Provides [`RankBoost`] by Freund et al., 2003.
+use rayon::prelude::*;
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The RankBoost algorithm
+/// proposed by Yoav Freund, Raj Iyer, Robert E. Schapire, and Yoram Singer.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// An Efficient Boosting Algorithm for Combining Preferences
+/// ](https://www.jmlr.org/papers/v4/freund03a.html)
+/// by Yoav Freund, Raj Iyer, Robert E. Schapire, and Yoram Singer.
+///
+/// RankBoost learns a scoring function `F(x) = Σ_t α_t h_t (x)`
+/// that ranks the positive examples above the negative ones.
+/// For a binary sample, the distribution over
+/// the (positive, negative) pairs `(x⁺, x⁻)` is maintained
+/// in the factorized form `D(x⁺, x⁻) = v(x⁺) v(x⁻)`,
+/// where `v` is a distribution over the positive examples
+/// and one over the negative examples (the bipartite case).
+/// Thus, `RankBoost` runs in `O(m)` time per round
+/// instead of `O(m⁺ m⁻)`,
+/// where `m⁺` and `m⁻` are
+/// the number of positive and negative examples.
+///
+/// At each round, the weak learner receives
+/// the distribution `d_i = v(x_i) / 2`,
+/// so that the edge `r = Σ_i y_i v(x_i) h(x_i)` of a hypothesis `h`
+/// equals to `Σ D(x⁺, x⁻) (h(x⁺) - h(x⁻))`.
+/// The weight on `h` is `α = 0.5 ln( (1 + r) / (1 - r) )`,
+/// and `v` is updated by
+/// `v(x_i) ∝ v(x_i) exp(- α y_i h(x_i))` for each class.
+///
+/// `RankBoost` returns a [`WeightedMajority`],
+/// whose [`Classifier::confidence`] is the real-valued score.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = RankBoost::init(&sample)
+///     .max_iter(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+///
+/// // The examples are ranked by the scores.
+/// let scores = f.confidence_all(&sample);
+/// println!("Ranking loss is: {}", booster.ranking_loss());
+/// ```
+pub struct RankBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Distribution over the positive examples and
+    // the one over the negative examples.
+    // Both of them are stored in the same vector.
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // The current scores `F(x)` on the training examples.
+    scores: Vec<f64>,
+
+    // Max iteration of RankBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> RankBoost<'a, F> {
+    /// Constructs a new instance of `RankBoost`.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+            scores: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the training ranking loss of the current scores,
+    /// i.e., the fraction of the (positive, negative) pairs
+    /// that are misordered.
+    /// The tied pairs are counted as half.
+    ///
+    /// Time complexity: `O(m log m)`,
+    /// where `m` is the number of training examples.
+    pub fn ranking_loss(&self) -> f64 {
+        let target = self.sample.target();
+        let mut items = target.iter()
+            .zip(&self.scores[..])
+            .map(|(&y, &s)| (s, y))
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+
+        // Sweep the examples in ascending order of the scores.
+        // `n_pos` is the number of positive examples
+        // whose scores are less than the current one.
+        let mut n_pos = 0f64;
+        let mut n_neg = 0f64;
+        let mut misordered = 0f64;
+        let mut i = 0;
+        while i < items.len() {
+            let mut j = i;
+            let (mut tie_pos, mut tie_neg) = (0f64, 0f64);
+            while j < items.len() && items[j].0 == items[i].0 {
+                if items[j].1 > 0f64 {
+                    tie_pos += 1f64;
+                } else {
+                    tie_neg += 1f64;
+                }
+                j += 1;
+            }
+            misordered += tie_neg * n_pos + 0.5 * tie_neg * tie_pos;
+            n_pos += tie_pos;
+            n_neg += tie_neg;
+            i = j;
+        }
+
+        misordered / (n_pos * n_neg)
+    }
+}
+
+
+impl<F> Booster<F> for RankBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "RankBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let n_pos = self.sample.target()
+            .iter()
+            .filter(|y| **y > 0f64)
+            .count();
+        let n_neg = n_sample - n_pos;
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("# of pairs", format!("{n_pos} x {n_neg}")),
+            ("Max iteration", format!("{}", self.max_iter)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+        let target = self.sample.target();
+
+        let n_pos = target.iter()
+            .filter(|y| **y > 0f64)
+            .count() as f64;
+        let n_neg = n_sample as f64 - n_pos;
+        assert!(
+            n_pos > 0f64 && n_neg > 0f64,
+            "RankBoost requires both positive and negative examples"
+        );
+
+        self.dist = target.iter()
+            .map(|y| if *y > 0f64 { 1f64 / n_pos } else { 1f64 / n_neg })
+            .collect();
+        self.scores = vec![0f64; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Each of the positive and the negative parts of `self.dist`
+        // sums to `1`, so that `d = v / 2` is a distribution.
+        let dist = self.dist.iter()
+            .map(|v| v / 2f64)
+            .collect::<Vec<_>>();
+        let h = weak_learner.produce(self.sample, &dist[..]);
+
+
+        // `r = Σ_i y_i v(x_i) h(x_i)`
+        // is the weighted difference over the pairs.
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+        let r = 2f64 * utils::inner_product(&margins[..], &dist[..]);
+
+
+        // If `h` ranks all the pairs correctly,
+        // use it as the scoring function.
+        if r >= 1f64 {
+            self.terminated = iteration;
+            self.scores = h.confidence_all(self.sample);
+            self.weights = vec![1f64];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+        // If `h` has no advantage,
+        // RankBoost cannot make progress.
+        if r <= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let alpha = 0.5 * ((1f64 + r) / (1f64 - r)).ln();
+
+
+        // Update `v` for each class in the logarithmic scale.
+        let target = self.sample.target();
+        let mut log_dist = self.dist.iter()
+            .zip(&margins[..])
+            .map(|(v, yh)| v.ln() - alpha * yh)
+            .collect::<Vec<_>>();
+        for label in [1f64, -1f64] {
+            let max = log_dist.iter()
+                .zip(target)
+                .filter(|(_, y)| **y == label)
+                .map(|(l, _)| *l)
+                .fold(f64::MIN, f64::max);
+            let z = log_dist.iter()
+                .zip(target)
+                .filter(|(_, y)| **y == label)
+                .map(|(l, _)| (l - max).exp())
+                .sum::<f64>();
+            let log_z = max + z.ln();
+            log_dist.iter_mut()
+                .zip(target)
+                .filter(|(_, y)| **y == label)
+                .for_each(|(l, _)| { *l -= log_z; });
+        }
+        self.dist = log_dist.into_iter()
+            .map(f64::exp)
+            .collect();
+
+
+        let confidences = h.confidence_all(self.sample);
+        self.scores.par_iter_mut()
+            .zip(confidences)
+            .for_each(|(s, c)| { *s += alpha * c; });
+
+        self.weights.push(alpha);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for RankBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,19 @@
+//! A variant of AdaBoost for imbalanced data,
+//! which resamples the training examples before each weak-learner call.
+//! See also the papers: 
+//! [
+//! RUSBoost: A Hybrid Approach to Alleviating Class Imbalance
+//! ](https://doi.org/10.1109/TSMCA.2009.2029559)
+//! by Chris Seiffert, Taghi M. Khoshgoftaar, Jason Van Hulse,
+//! and Amri Napolitano, and
+//! [
+//! SMOTEBoost: Improving Prediction of the Minority Class in Boosting
+//! ](https://doi.org/10.1007/978-3-540-39804-2_12)
+//! by Nitesh V. Chawla, Aleksandar Lazarevic, Lawrence O. Hall,
+//! and Kevin W. Bowyer.
+//! 
+//! RUSBoost is a boosting algorithm for binary classification
+//! where one class is much rarer than the other.
+pub mod rusboost_algorithm;
+
+pub use rusboost_algorithm::{RUSBoost, Resampling};
@@ -0,0 +1,522 @@
+//! Provides [`RUSBoost`], a variant of AdaBoost for imbalanced data.
+use rayon::prelude::*;
+use rand::prelude::*;
+use polars::prelude::*;
+
+use crate::{
+    Booster,
+    WeakLearner,
+    Classifier,
+    WeightedMajority,
+    Sample,
+
+    common::utils,
+    common::selection,
+    research::Research,
+};
+
+use std::fmt;
+use std::ops::ControlFlow;
+
+
+/// Resampling strategies for [`RUSBoost`].
+#[derive(Clone, Copy)]
+pub enum Resampling {
+    /// Random under-sampling (RUSBoost).
+    /// Drop the majority examples uniformly at random
+    /// until the ratio of the minority examples to the majority ones
+    /// reaches the target ratio.
+    Under,
+
+    /// Synthetic minority over-sampling (SMOTEBoost).
+    /// Add synthetic minority examples
+    /// until the ratio of the minority examples to the majority ones
+    /// reaches the target ratio.
+    /// Each synthetic example is a random point on the segment
+    /// between a minority example and
+    /// one of its `k` nearest minority examples.
+    Smote(usize),
+}
+
+
+impl fmt::Display for Resampling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Under => write!(f, "Random under-sampling"),
+            Self::Smote(k) => write!(f, "SMOTE ({k} neighbors)"),
+        }
+    }
+}
+
+
+/// A variant of AdaBoost for imbalanced binary classification
+/// that resamples the training examples
+/// before each weak-learner call.
+///
+/// See also the papers:
+///
+/// [
+/// RUSBoost: A Hybrid Approach to Alleviating Class Imbalance
+/// ](https://doi.org/10.1109/TSMCA.2009.2029559)
+/// by Chris Seiffert, Taghi M. Khoshgoftaar, Jason Van Hulse,
+/// and Amri Napolitano.
+///
+/// [
+/// SMOTEBoost: Improving Prediction of the Minority Class in Boosting
+/// ](https://doi.org/10.1007/978-3-540-39804-2_12)
+/// by Nitesh V. Chawla, Aleksandar Lazarevic, Lawrence O. Hall,
+/// and Kevin W. Bowyer.
+///
+/// `RUSBoost` maintains a distribution `d` over the training examples
+/// and updates it in the same way as
+/// [`AdaBoost`](crate::booster::AdaBoost).
+/// At each round, `RUSBoost` resamples the training examples
+/// so that the ratio of the minority examples to the majority ones
+/// is the target ratio (see [`RUSBoost::ratio`]),
+/// and passes the resampled examples to the weak learner
+/// with the weights `d` restricted to them.
+/// The resampling strategy is given by [`RUSBoost::resampling`]:
+///
+/// - [`Resampling::Under`] drops the majority examples at random.
+///   The weak learner receives the training sample
+///   with zero weights on the dropped examples.
+/// - [`Resampling::Smote`] adds synthetic minority examples.
+///   The weak learner receives the training sample
+///   followed by the synthetic examples,
+///   each of which has the weight of the minority example
+///   it is generated from.
+///
+/// The edge and the weight of the obtained hypothesis
+/// are computed on the original training examples,
+/// so that `RUSBoost` composes with any [`WeakLearner`].
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // Read the training sample from the CSV file.
+/// // We use the column named `class` as the label.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// // Balance the classes by the random under-sampling.
+/// let mut booster = RUSBoost::init(&sample)
+///     .resampling(Resampling::Under)
+///     .ratio(1.0)
+///     .seed(1234)
+///     .max_iter(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_all(&sample);
+/// ```
+pub struct RUSBoost<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Distribution on sample.
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // The resampling strategy.
+    resampling: Resampling,
+
+    // The target ratio of the minority examples to the majority ones.
+    ratio: f64,
+
+    // The label of the minority class.
+    minority: f64,
+
+    // The indices of the minority examples.
+    minority_rows: Vec<usize>,
+
+    // The indices of the majority examples.
+    majority_rows: Vec<usize>,
+
+    // The nearest minority examples of each minority example.
+    // `neighbors[a]` holds the positions in `minority_rows`.
+    neighbors: Vec<Vec<usize>>,
+
+    // Max iteration of RUSBoost.
+    max_iter: usize,
+
+    // Terminated iteration.
+    terminated: usize,
+
+    // The seed of the random number generator.
+    seed: u64,
+
+    // Random number generator for the resampling.
+    rng: StdRng,
+}
+
+
+impl<'a, F> RUSBoost<'a, F> {
+    /// Constructs a new instance of `RUSBoost`.
+    /// By default, `RUSBoost` balances the classes
+    /// by the random under-sampling.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            resampling: Resampling::Under,
+            ratio: 1f64,
+
+            minority: 1f64,
+            minority_rows: Vec::new(),
+            majority_rows: Vec::new(),
+            neighbors: Vec::new(),
+
+            max_iter: 100,
+            terminated: usize::MAX,
+
+            seed: selection::SEED,
+            rng: StdRng::seed_from_u64(selection::SEED),
+        }
+    }
+
+
+    /// Set the resampling strategy.
+    /// Default is [`Resampling::Under`].
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn resampling(mut self, resampling: Resampling) -> Self {
+        if let Resampling::Smote(k) = resampling {
+            assert!(k > 0, "The number of neighbors must be positive");
+        }
+        self.resampling = resampling;
+        self
+    }
+
+
+    /// Set the target ratio `r ∈ (0, 1]`
+    /// of the minority examples to the majority ones
+    /// after the resampling.
+    /// If the ratio is already `r` or larger,
+    /// `RUSBoost` does not resample the examples.
+    /// Default is `1.0`, i.e., the balanced classes.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        assert!(
+            0f64 < ratio && ratio <= 1f64,
+            "The target ratio must be in (0, 1]"
+        );
+        self.ratio = ratio;
+        self
+    }
+
+
+    /// Set the seed of the randomness for the resampling.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Set the maximum number of iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn max_iter(mut self, it: usize) -> Self {
+        self.max_iter = it;
+        self
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+
+
+    /// Returns the `k` nearest minority examples
+    /// of each minority example in the Euclidean distance.
+    ///
+    /// Time complexity: `O(m² n)`,
+    /// where `m` is the number of minority examples
+    /// and `n` is the number of features.
+    fn nearest_neighbors(&self, k: usize) -> Vec<Vec<usize>> {
+        let points = self.minority_rows.iter()
+            .map(|&i| self.sample.at(i).0)
+            .collect::<Vec<_>>();
+        let k = k.min(points.len().saturating_sub(1));
+
+        points.par_iter()
+            .enumerate()
+            .map(|(a, p)| {
+                let mut items = points.iter()
+                    .enumerate()
+                    .filter(|&(b, _)| b != a)
+                    .map(|(b, q)| {
+                        let dist = p.iter()
+                            .zip(q)
+                            .map(|(x, y)| (x - y).powi(2))
+                            .sum::<f64>();
+                        (dist, b)
+                    })
+                    .collect::<Vec<_>>();
+                items.sort_by(|x, y| x.partial_cmp(y).unwrap());
+                items.into_iter()
+                    .take(k)
+                    .map(|(_, b)| b)
+                    .collect()
+            })
+            .collect()
+    }
+
+
+    /// Returns the distribution for the weak learner
+    /// after the random under-sampling.
+    /// The dropped majority examples have zero weights.
+    fn under_sample(&mut self) -> Vec<f64> {
+        let n_minority = self.minority_rows.len();
+        let n_majority = self.majority_rows.len();
+        let n_keep = ((n_minority as f64 / self.ratio).ceil() as usize)
+            .min(n_majority);
+
+        let mut dist = vec![0f64; self.dist.len()];
+        for &i in self.minority_rows.iter() {
+            dist[i] = self.dist[i];
+        }
+        let kept = self.majority_rows
+            .choose_multiple(&mut self.rng, n_keep);
+        for &i in kept {
+            dist[i] = self.dist[i];
+        }
+        utils::normalize(&mut dist[..]);
+        dist
+    }
+
+
+    /// Returns the training sample followed by
+    /// the synthetic minority examples
+    /// and the distribution for the weak learner.
+    /// This method returns `None`
+    /// if no synthetic example is needed.
+    fn over_sample(&mut self) -> Option<(Sample, Vec<f64>)> {
+        let n_minority = self.minority_rows.len();
+        let n_majority = self.majority_rows.len();
+        let n_synthetic = ((self.ratio * n_majority as f64).ceil() as usize)
+            .saturating_sub(n_minority);
+
+        // The interpolation needs at least two minority examples.
+        if n_synthetic == 0 || n_minority < 2 {
+            return None;
+        }
+
+
+        let n_feature = self.sample.shape().1;
+        let mut columns = vec![Vec::with_capacity(n_synthetic); n_feature];
+        let mut dist = self.dist.clone();
+        dist.reserve(n_synthetic);
+        for _ in 0..n_synthetic {
+            let a = self.rng.gen_range(0..n_minority);
+            let b = *self.neighbors[a].choose(&mut self.rng).unwrap();
+            let lambda = self.rng.gen::<f64>();
+
+            let (p, _) = self.sample.at(self.minority_rows[a]);
+            let (q, _) = self.sample.at(self.minority_rows[b]);
+            columns.iter_mut()
+                .zip(p.into_iter().zip(q))
+                .for_each(|(col, (x, y))| {
+                    col.push(x + lambda * (y - x));
+                });
+            dist.push(self.dist[self.minority_rows[a]]);
+        }
+        utils::normalize(&mut dist[..]);
+
+
+        let data = self.sample.features()
+            .iter()
+            .zip(columns)
+            .map(|(feat, col)| Series::new(feat.name(), col))
+            .collect::<Vec<_>>();
+        let data = DataFrame::new(data)
+            .expect("Failed to construct the synthetic examples");
+        let target = Series::new("target", vec![self.minority; n_synthetic]);
+
+        let mut sample = self.sample.clone();
+        sample.push_rows(data, target)
+            .expect("Failed to append the synthetic examples");
+        Some((sample, dist))
+    }
+}
+
+
+impl<F> Booster<F> for RUSBoost<'_, F>
+    where F: Classifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "RUSBoost"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Resampling", format!("{}", self.resampling)),
+            ("Target ratio", format!("{}", self.ratio)),
+            ("Max iteration", format!("{}", self.max_iter)),
+            ("Seed", format!("{}", self.seed)),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.sample.is_valid_binary_instance();
+        let n_sample = self.sample.shape().0;
+
+
+        // The class with fewer examples is the minority.
+        let target = self.sample.target();
+        let n_positive = target.iter().filter(|y| **y > 0f64).count();
+        self.minority = if 2 * n_positive <= n_sample { 1f64 } else { -1f64 };
+        (self.minority_rows, self.majority_rows) = (0..n_sample)
+            .partition(|&i| target[i] == self.minority);
+
+        self.neighbors = match self.resampling {
+            Resampling::Under => Vec::new(),
+            Resampling::Smote(k) => self.nearest_neighbors(k),
+        };
+
+
+        let uni = 1f64 / n_sample as f64;
+        self.dist = vec![uni; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        self.terminated = self.max_iter;
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+
+        // Train the weak learner on the resampled examples.
+        let h = match self.resampling {
+            Resampling::Under => {
+                let dist = self.under_sample();
+                weak_learner.produce(self.sample, &dist[..])
+            },
+            Resampling::Smote(_) => match self.over_sample() {
+                Some((sample, dist)) => weak_learner.produce(&sample, &dist[..]),
+                None => weak_learner.produce(self.sample, &self.dist[..]),
+            },
+        };
+
+
+        // The edge is computed on the original examples.
+        let margins = utils::margins_of_hypothesis(self.sample, &h);
+        let edge = utils::inner_product(&margins[..], &self.dist[..]);
+
+
+        // If `h` has no advantage on the current distribution,
+        // `RUSBoost` cannot make progress.
+        if edge <= 0f64 {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        // If the edge reaches `1`,
+        // `h` classifies all the examples with non-zero weights correctly.
+        if edge >= 1f64 {
+            self.terminated = iteration;
+            self.weights = vec![1f64];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        let alpha = 0.5 * ((1f64 + edge) / (1f64 - edge)).ln();
+
+
+        // To prevent overflow, take the logarithm.
+        self.dist.par_iter_mut()
+            .zip(&margins[..])
+            .for_each(|(d, m)| { *d = d.ln() - alpha * m; });
+        let max = self.dist.iter()
+            .copied()
+            .fold(f64::MIN, f64::max);
+        self.dist.par_iter_mut()
+            .for_each(|d| { *d = (*d - max).exp(); });
+        utils::normalize(&mut self.dist[..]);
+
+
+        self.weights.push(alpha);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for RUSBoost<'_, H>
+    where H: Classifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
@@ -0,0 +1,15 @@
+//! The SAMME and SAMME.R algorithms proposed
+//! by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+//! These algorithms are based on the paper: 
+//! [
+//! Multi-class AdaBoost
+//! ](https://doi.org/10.4310/SII.2009.v2.n3.a8)
+//! 
+//! SAMME is a multiclass extension of AdaBoost
+//! that combines the class labels predicted by the hypotheses.
+//! SAMME.R combines the class probabilities instead.
+pub mod samme_algorithm;
+pub mod sammer_algorithm;
+
+pub use samme_algorithm::SAMME;
+pub use sammer_algorithm::SAMMER;
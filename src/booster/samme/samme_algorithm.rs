@@ -0,0 +1,299 @@
+//! Provides [`SAMME`] by Zhu et al., 2009.
+use crate::{
+    Sample,
+    Booster,
+    WeakLearner,
+    MulticlassClassifier,
+    WeightedMajority,
+
+    research::Research,
+};
+
+use std::ops::ControlFlow;
+
+
+/// The SAMME algorithm
+/// proposed by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Multi-class AdaBoost
+/// ](https://doi.org/10.4310/SII.2009.v2.n3.a8)
+/// by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+///
+/// SAMME (Stagewise Additive Modeling using a Multi-class Exponential loss)
+/// is a multiclass extension of AdaBoost.
+/// The target values of the training sample are the class labels,
+/// and the weak learner produces a [`MulticlassClassifier`].
+/// At each round, `SAMME` weights the new hypothesis `h` by
+/// `α = ln((1 - ε) / ε) + ln(K - 1)`,
+/// where `ε` is the weighted error of `h`
+/// and `K` is the number of classes.
+/// Thus, `h` only needs to be better than random guessing,
+/// `ε < 1 - 1/K`.
+///
+/// The resulting [`WeightedMajority`] predicts the class
+/// by [`MulticlassClassifier::predict_class`].
+/// See also [`SAMMER`](crate::booster::SAMMER) for the real-valued variant.
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // The column `class` has the class labels `0, 1, 2, ...`.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = SAMME::init(&sample)
+///     .force_quit_at(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_class_all(&sample);
+/// ```
+pub struct SAMME<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Class labels in ascending order.
+    classes: Vec<i64>,
+
+    // Distribution on examples
+    dist: Vec<f64>,
+
+    // Weights on hypotheses in `hypotheses`
+    weights: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<F>,
+
+    // Max iteration of SAMME.
+    max_iter: usize,
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> SAMME<'a, F> {
+    /// Constructs a new instance of `SAMME`.
+    ///
+    /// Time complexity: `O(m log m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        let classes = class_labels(sample);
+
+        Self {
+            sample,
+            classes,
+
+            dist: Vec::new(),
+
+            weights: Vec::new(),
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            force_quit_at: None,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Returns the class labels in ascending order.
+    pub fn classes(&self) -> &[i64] {
+        &self.classes[..]
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+}
+
+
+impl<F> Booster<F> for SAMME<'_, F>
+    where F: MulticlassClassifier + Clone,
+{
+    type Output = WeightedMajority<F>;
+
+
+    fn name(&self) -> &str {
+        "SAMME"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("# of classes", format!("{}", self.classes.len())),
+            ("Max iteration", format!("{}", self.max_iter)),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let n_sample = self.sample.shape().0;
+        self.dist = vec![1f64 / n_sample as f64; n_sample];
+
+        self.weights = Vec::new();
+        self.hypotheses = Vec::new();
+
+        if let Some(it) = self.force_quit_at {
+            self.max_iter = it;
+        }
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+        let h = weak_learner.produce(self.sample, &self.dist);
+        let mistakes = self.sample.target()
+            .iter()
+            .zip(h.predict_class_all(self.sample))
+            .map(|(&y, p)| y as i64 != p)
+            .collect::<Vec<_>>();
+        let error = self.dist.iter()
+            .zip(&mistakes)
+            .filter_map(|(d, &miss)| if miss { Some(d) } else { None })
+            .sum::<f64>();
+
+
+        // If `h` predicts all the examples correctly,
+        // use it as the combined hypothesis.
+        if error <= 0f64 {
+            self.terminated = iteration;
+            self.weights = vec![1f64];
+            self.hypotheses = vec![h];
+            return ControlFlow::Break(iteration);
+        }
+
+
+        // If `h` is not better than random guessing,
+        // SAMME cannot make progress.
+        let n_classes = self.classes.len() as f64;
+        if error >= 1f64 - 1f64 / n_classes {
+            self.terminated = iteration - 1;
+            return ControlFlow::Break(self.terminated);
+        }
+
+
+        let weight = ((1f64 - error) / error).ln() + (n_classes - 1f64).ln();
+
+        // Update the distribution by `d ∝ d exp(α 1[h(x) != y])`
+        // in the logarithmic scale.
+        self.dist.iter_mut()
+            .zip(&mistakes)
+            .for_each(|(d, &miss)| {
+                *d = d.ln() + if miss { weight } else { 0f64 };
+            });
+        normalize_log_dist(&mut self.dist[..]);
+
+        self.weights.push(weight);
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for SAMME<'_, H>
+    where H: MulticlassClassifier + Clone,
+{
+    type Output = WeightedMajority<H>;
+    fn current_hypothesis(&self) -> Self::Output {
+        WeightedMajority::from_slices(&self.weights[..], &self.hypotheses[..])
+    }
+}
+
+
+/// Returns the class labels of `sample` in ascending order.
+/// This function panics if `sample` has less than two classes.
+pub(super) fn class_labels(sample: &Sample) -> Vec<i64> {
+    let classes = sample.unique_target()
+        .into_iter()
+        .map(|y| y as i64)
+        .collect::<Vec<_>>();
+    assert!(
+        classes.len() >= 2,
+        "The training sample must have at least two classes"
+    );
+    classes
+}
+
+
+/// Convert the logarithm of an unnormalized distribution
+/// into the normalized distribution by log-sum-exp.
+pub(super) fn normalize_log_dist(dist: &mut [f64]) {
+    let max = dist.iter()
+        .copied()
+        .fold(f64::MIN, f64::max);
+    let normalizer = dist.iter()
+        .map(|&d| (d - max).exp())
+        .sum::<f64>()
+        .ln()
+        + max;
+    dist.iter_mut()
+        .for_each(|d| { *d = (*d - normalizer).exp(); });
+}
@@ -0,0 +1,244 @@
+//! Provides [`SAMMER`] (SAMME.R) by Zhu et al., 2009.
+use crate::{
+    Sample,
+    Booster,
+    WeakLearner,
+    MulticlassClassifier,
+    WeightedMajority,
+    LogProbabilityVote,
+
+    research::Research,
+};
+use super::samme_algorithm::{class_labels, normalize_log_dist};
+
+use std::ops::ControlFlow;
+
+
+/// The SAMME.R algorithm
+/// proposed by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+///
+/// This struct is based on the paper:
+///
+/// [
+/// Multi-class AdaBoost
+/// ](https://doi.org/10.4310/SII.2009.v2.n3.a8)
+/// by Ji Zhu, Hui Zou, Saharon Rosset, and Trevor Hastie.
+///
+/// SAMME.R is the real-valued variant of [`SAMME`](crate::booster::SAMME).
+/// Instead of the predicted class,
+/// `SAMMER` uses the class probabilities `p_k(x)`
+/// ([`MulticlassClassifier::class_probabilities`])
+/// of the hypotheses.
+/// Each hypothesis votes
+/// `(K - 1) (ln p_k(x) - (1 / K) Σ_j ln p_j(x))` for the class `k`
+/// (see [`LogProbabilityVote`]),
+/// and the distribution is updated by
+/// `d_i ∝ d_i exp(- (K - 1) / K Σ_k y_ik ln p_k(x_i))`,
+/// where `y_ik = 1` if `k` is the class of `x_i`
+/// and `y_ik = -1 / (K - 1)` otherwise.
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // The column `class` has the class labels `0, 1, 2, ...`.
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let mut booster = SAMMER::init(&sample)
+///     .force_quit_at(100);
+///
+/// let weak_learner = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .criterion(Criterion::Entropy)
+///     .build();
+///
+/// let f = booster.run(&weak_learner);
+/// let predictions = f.predict_class_all(&sample);
+/// ```
+pub struct SAMMER<'a, F> {
+    // Training sample
+    sample: &'a Sample,
+
+    // Class labels in ascending order.
+    classes: Vec<i64>,
+
+    // Distribution on examples
+    dist: Vec<f64>,
+
+    // Hypohteses obtained by the weak-learner.
+    hypotheses: Vec<LogProbabilityVote<F>>,
+
+    // Max iteration of SAMME.R.
+    max_iter: usize,
+
+    // Optional. If this value is `Some(it)`,
+    // the algorithm terminates after `it` iterations.
+    force_quit_at: Option<usize>,
+
+    // Terminated iteration.
+    terminated: usize,
+}
+
+
+impl<'a, F> SAMMER<'a, F> {
+    /// Constructs a new instance of `SAMMER`.
+    ///
+    /// Time complexity: `O(m log m)`,
+    /// where `m` is the number of training examples.
+    #[inline]
+    pub fn init(sample: &'a Sample) -> Self {
+        let classes = class_labels(sample);
+
+        Self {
+            sample,
+            classes,
+
+            dist: Vec::new(),
+
+            hypotheses: Vec::new(),
+
+            max_iter: 100,
+            force_quit_at: None,
+            terminated: usize::MAX,
+        }
+    }
+
+
+    /// Force quits after at most `it` iterations.
+    /// Default is `100`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn force_quit_at(mut self, it: usize) -> Self {
+        self.force_quit_at = Some(it);
+        self
+    }
+
+
+    /// Returns the class labels in ascending order.
+    pub fn classes(&self) -> &[i64] {
+        &self.classes[..]
+    }
+
+
+    /// Returns the terminated iteration.
+    /// This method returns `usize::MAX` before the boosting step.
+    ///
+    /// Time complexity: `O(1)`.
+    #[inline(always)]
+    pub fn terminated(&self) -> usize {
+        self.terminated
+    }
+}
+
+
+impl<F> Booster<F> for SAMMER<'_, F>
+    where F: MulticlassClassifier + Clone,
+{
+    type Output = WeightedMajority<LogProbabilityVote<F>>;
+
+
+    fn name(&self) -> &str {
+        "SAMME.R"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let quit = if let Some(it) = self.force_quit_at {
+            format!("At round {it}")
+        } else {
+            "-".to_string()
+        };
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("# of classes", format!("{}", self.classes.len())),
+            ("Max iteration", format!("{}", self.max_iter)),
+            ("Force quit", quit),
+        ]);
+        Some(info)
+    }
+
+
+    fn preprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    )
+        where W: WeakLearner<Hypothesis = F>
+    {
+        let n_sample = self.sample.shape().0;
+        self.dist = vec![1f64 / n_sample as f64; n_sample];
+
+        self.hypotheses = Vec::new();
+
+        if let Some(it) = self.force_quit_at {
+            self.max_iter = it;
+        }
+        self.terminated = self.max_iter;
+    }
+
+
+    fn boost<W>(
+        &mut self,
+        weak_learner: &W,
+        iteration: usize,
+    ) -> ControlFlow<usize>
+        where W: WeakLearner<Hypothesis = F>,
+    {
+        if self.max_iter < iteration {
+            return ControlFlow::Break(self.max_iter);
+        }
+
+        let h = weak_learner.produce(self.sample, &self.dist);
+        let h = LogProbabilityVote::new(h, self.classes.clone());
+
+
+        // Update the distribution by
+        // `d ∝ d exp(- (K - 1) / K Σ_k y_k ln p_k)`
+        // in the logarithmic scale.
+        let n_classes = self.classes.len() as f64;
+        let scale = (n_classes - 1f64) / n_classes;
+        let target = self.sample.target();
+        self.dist.iter_mut()
+            .zip(target)
+            .enumerate()
+            .for_each(|(i, (d, &y))| {
+                let log_p = h.log_probabilities(self.sample, i);
+                let correct = self.classes.binary_search(&(y as i64))
+                    .expect("Unknown class label");
+                let others = log_p.iter().sum::<f64>() - log_p[correct];
+                let margin = log_p[correct] - others / (n_classes - 1f64);
+                *d = d.ln() - scale * margin;
+            });
+        normalize_log_dist(&mut self.dist[..]);
+
+        self.hypotheses.push(h);
+
+        ControlFlow::Continue(())
+    }
+
+
+    fn postprocess<W>(
+        &mut self,
+        _weak_learner: &W,
+    ) -> Self::Output
+        where W: WeakLearner<Hypothesis = F>
+    {
+        self.current_hypothesis()
+    }
+}
+
+
+impl<H> Research for SAMMER<'_, H>
+    where H: MulticlassClassifier + Clone,
+{
+    type Output = WeightedMajority<LogProbabilityVote<H>>;
+    fn current_hypothesis(&self) -> Self::Output {
+        let weights = vec![1f64; self.hypotheses.len()];
+        WeightedMajority::from_slices(&weights[..], &self.hypotheses[..])
+    }
+}
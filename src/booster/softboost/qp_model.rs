@@ -140,6 +140,9 @@ impl QPModel {
             );
 
             solver.solve();
+
+            // breaks if the edge constraints are infeasible.
+            if solver.solution.status != SolverStatus::Solved { return None; }
             let solution = &solver.solution.x[..];
 
             // breaks if there is a zero-valued coordinate in the solution.
@@ -236,7 +239,7 @@ impl QPModel {
 
             // capping constraint: `d_i ≤ 1/ν`
             row_val.push(self.n_examples + j + 1);
-            nonzero.push(self.cap_inv);
+            nonzero.push(1f64);
 
             // margin constraints of `i`-th column
             for (i, &yh) in (0..).zip(margins) {
@@ -221,7 +221,9 @@ impl<'a, F> SoftBoost<'a, F>
         let temp = (n_sample / self.nu).ln();
         let max_iter = 2.0 * temp / self.tolerance.powi(2);
 
-        max_iter.ceil() as usize
+        // `temp` vanishes for `nu == n_sample`,
+        // where the first hypothesis is already optimal.
+        max_iter.ceil().max(1.0) as usize
     }
 }
 
@@ -249,10 +251,13 @@ impl<F> SoftBoost<'_, F>
     /// Returns `None` if the stopping criterion satisfied.
     fn update_params_mut(&mut self) -> Option<()> {
         let h = self.hypotheses.last().unwrap();
+        // The edges of the past hypotheses are at most `γ̂ - ε`,
+        // which is infeasible once `γ̂` is `ε`-optimal.
+        let ghat = self.gamma_hat - self.tolerance;
         self.qp_model.as_ref()
             .expect("Failed to call `.as_ref()` to `self.qp_model`")
             .borrow_mut()
-            .update(self.sample, &mut self.dist[..], self.gamma_hat, h)
+            .update(self.sample, &mut self.dist[..], ghat, h)
     }
 }
 
@@ -33,6 +33,28 @@ use std::ops::ControlFlow;
 ///      w ∈ Δ_{H}
 /// ```
 /// 
+/// `TotalBoost` is **totally corrective**.
+/// At each round `t`,
+/// it projects the distribution over the training examples
+/// onto the set of distributions
+/// on which all the hypotheses obtained so far
+/// have edge at most `γ̂_t - ε`:
+///
+/// ```txt
+/// min RE(d, d⁰)
+///  d
+/// s.t. Σ_i d_i y_i h_q (x_i) ≤ γ̂_t - ε,   ∀q = 1, 2, ..., t
+///      d ∈ Δ_{m, ν},
+/// ```
+/// where `γ̂_t` is the minimum edge of the hypotheses so far,
+/// `ε` is the tolerance,
+/// `d⁰` is the uniform distribution,
+/// and `Δ_{m, ν}` is the capped probability simplex
+/// (see [`TotalBoost::nu`]).
+/// `TotalBoost` stops once the projection is infeasible.
+/// The projection is solved by the QP model of [`SoftBoost`]
+/// with the sequential quadratic programming.
+/// 
 /// # Convergence rate
 /// Assume that there exists a convex combination of hypotheses
 /// that perfectly classifies the training examples:
@@ -51,9 +73,12 @@ use std::ops::ControlFlow;
 /// 
 /// # Related information
 /// - [`TotalBoost`] is a special case of [`SoftBoost`].
-///   That is, `TotalBoost` restricts [`SoftBoost::nu`] as `1.0`.  
+///   That is, `TotalBoost` sets [`SoftBoost::nu`] as `1.0` by default.  
 ///   For this reason, [`TotalBoost`] is 
-///   just a wrapper of [`SoftBoost`].
+///   just a wrapper of [`SoftBoost`] and reuses its QP model.
+///   [`TotalBoost::nu`] sets [`SoftBoost::nu`],
+///   so that `TotalBoost` with `ν > 1` maximizes the soft margin
+///   as [`SoftBoost`] does.
 ///
 /// 
 /// # Example
@@ -132,6 +157,20 @@ impl<'a, F> TotalBoost<'a, F>
         self.softboost = self.softboost.tolerance(tol);
         self
     }
+
+
+    /// Set the capping parameter `ν ∈ [1, m]`,
+    /// where `m` is the number of training examples.
+    /// Each coordinate of the distribution is at most `1/ν`,
+    /// so that `TotalBoost` tolerates at most `ν` outliers.
+    /// Default is `1.0`, i.e., no capping.
+    /// This method sets [`SoftBoost::nu`] of the underlying [`SoftBoost`].
+    /// 
+    /// Time complexity: `O(1)`.
+    pub fn nu(mut self, nu: f64) -> Self {
+        self.softboost = self.softboost.nu(nu);
+        self
+    }
 }
 
 
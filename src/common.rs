@@ -9,6 +9,16 @@ pub mod utils;
 /// Defines the Frank-Wolfe algorithms.
 pub mod frank_wolfe;
 
+/// Defines the rules to pick a hypothesis among candidates.
+pub mod selection;
+
+/// Defines the weighted sampling of the examples.
+pub mod weighted_sampler;
+
+/// Defines the parameters of the Gurobi environment.
+#[cfg(feature="gurobi")]
+pub mod gurobi_config;
+
 /// Defines some checker functions.
 pub(crate) mod checker;
 
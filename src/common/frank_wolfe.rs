@@ -46,6 +46,37 @@ impl fmt::Display for FWType {
 }
 
 
+/// Secondary updates of MLPBoost.
+/// These options decide when `MLPBoost` solves
+/// the soft margin LP over the hypotheses obtained so far.
+#[derive(Clone, Copy)]
+pub enum SecondaryType {
+    /// No secondary update.
+    /// `MLPBoost` runs the pure Frank-Wolfe algorithm.
+    Never,
+
+    /// Solve the LP every `k` rounds.
+    /// `Every(1)` solves the LP every round.
+    Every(usize),
+
+    /// Solve the LP only if the Frank-Wolfe step
+    /// fails to halve the optimality gap.
+    Adaptive,
+}
+
+
+impl fmt::Display for SecondaryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Never => write!(f, "-"),
+            Self::Every(1) => write!(f, "LPBoost"),
+            Self::Every(k) => write!(f, "LPBoost (every {k} rounds)"),
+            Self::Adaptive => write!(f, "LPBoost (adaptive)"),
+        }
+    }
+}
+
+
 pub(crate) struct FrankWolfe {
     eta: f64, // Strongly-smooth parameter
     nu: f64,
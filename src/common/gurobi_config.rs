@@ -0,0 +1,187 @@
+//! This file defines the parameters of the Gurobi environment
+//! used by the LP/QP-based boosting algorithms.
+
+use grb::prelude::*;
+
+use std::fmt;
+
+
+/// The algorithms that Gurobi uses to solve the LP/QP.
+/// See the `Method` parameter of Gurobi for details.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GurobiMethod {
+    /// Let Gurobi choose the algorithm.
+    Auto,
+    /// Primal simplex.
+    PrimalSimplex,
+    /// Dual simplex.
+    DualSimplex,
+    /// Barrier.
+    Barrier,
+    /// Concurrent.
+    Concurrent,
+    /// Deterministic concurrent.
+    DeterministicConcurrent,
+}
+
+
+impl GurobiMethod {
+    fn value(&self) -> i32 {
+        match self {
+            Self::Auto => -1,
+            Self::PrimalSimplex => 0,
+            Self::DualSimplex => 1,
+            Self::Barrier => 2,
+            Self::Concurrent => 3,
+            Self::DeterministicConcurrent => 4,
+        }
+    }
+}
+
+
+/// The parameters of the Gurobi environment.
+/// The parameters that are not set keep
+/// the defaults of each booster.
+/// By default, the solver output is silenced.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = Sample::dummy(10);
+/// let config = GurobiConfig::new()
+///     .threads(2)
+///     .method(GurobiMethod::DualSimplex)
+///     .time_limit(60.0);
+/// let booster = LPBoost::init(&sample)
+///     .nu(2.0)
+///     .gurobi_config(config);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GurobiConfig {
+    threads: Option<i32>,
+    method: Option<GurobiMethod>,
+    numeric_focus: Option<i32>,
+    output_flag: bool,
+    time_limit: Option<f64>,
+}
+
+
+impl Default for GurobiConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl GurobiConfig {
+    /// Construct a new `GurobiConfig` with the default parameters.
+    pub fn new() -> Self {
+        Self {
+            threads: None,
+            method: None,
+            numeric_focus: None,
+            output_flag: false,
+            time_limit: None,
+        }
+    }
+
+
+    /// Set the maximum number of threads Gurobi uses.
+    /// `0` lets Gurobi decide.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads as i32);
+        self
+    }
+
+
+    /// Set the algorithm Gurobi uses.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn method(mut self, method: GurobiMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+
+    /// Set the numeric focus, an integer in `[0, 3]`.
+    /// Larger values make Gurobi more careful about numerical issues.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn numeric_focus(mut self, focus: u8) -> Self {
+        assert!(focus <= 3, "The numeric focus must be in [0, 3]");
+        self.numeric_focus = Some(focus as i32);
+        self
+    }
+
+
+    /// Enable/disable the solver output.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn output_flag(mut self, flag: bool) -> Self {
+        self.output_flag = flag;
+        self
+    }
+
+
+    /// Set the time limit in seconds for each call of the solver.
+    /// If the solver reaches the limit,
+    /// the booster uses the best solution found so far.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn time_limit(mut self, seconds: f64) -> Self {
+        assert!(seconds > 0f64, "The time limit must be positive");
+        self.time_limit = Some(seconds);
+        self
+    }
+
+
+    /// Construct a new Gurobi environment with these parameters.
+    /// `default_focus` is the numeric focus used
+    /// if it is not set by [`GurobiConfig::numeric_focus`].
+    pub(crate) fn env(&self, default_focus: Option<i32>) -> Env {
+        let mut env = Env::empty()
+            .expect("Failed to construct a new `Env`");
+        env.set(param::OutputFlag, self.output_flag as i32)
+            .expect("Failed to set `param::OutputFlag`");
+        if let Some(threads) = self.threads {
+            env.set(param::Threads, threads)
+                .expect("Failed to set `param::Threads`");
+        }
+        if let Some(method) = self.method {
+            env.set(param::Method, method.value())
+                .expect("Failed to set `param::Method`");
+        }
+        if let Some(focus) = self.numeric_focus.or(default_focus) {
+            env.set(param::NumericFocus, focus)
+                .expect("Failed to set `param::NumericFocus`");
+        }
+        if let Some(seconds) = self.time_limit {
+            env.set(param::TimeLimit, seconds)
+                .expect("Failed to set `param::TimeLimit`");
+        }
+        env.start()
+            .expect("Failed to start the `Env`")
+    }
+}
+
+
+impl fmt::Display for GurobiConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let threads = self.threads
+            .map(|t| t.to_string())
+            .unwrap_or("-".to_string());
+        let method = self.method
+            .map(|m| format!("{m:?}"))
+            .unwrap_or("-".to_string());
+        let time_limit = self.time_limit
+            .map(|t| format!("{t}s"))
+            .unwrap_or("-".to_string());
+        write!(
+            f,
+            "threads: {threads}, method: {method}, time limit: {time_limit}"
+        )
+    }
+}
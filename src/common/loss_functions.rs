@@ -36,11 +36,53 @@ pub trait LossFunction {
         residuals: &[f64],
         predictions: &[f64],
     ) -> f64;
+
+
+    /// Best coffecient for the newly-attained hypothesis
+    /// at the current scores `scores`.
+    /// By default, this method returns [`LossFunction::best_coefficient`]
+    /// for the residuals `target - scores`.
+    fn best_coefficient_at(
+        &self,
+        scores: &[f64],
+        target: &[f64],
+        predictions: &[f64],
+    ) -> f64
+    {
+        let residuals = target.iter()
+            .zip(scores)
+            .map(|(y, s)| y - s)
+            .collect::<Vec<_>>();
+        self.best_coefficient(&residuals[..], predictions)
+    }
+
+
+    /// Best coffecient for the newly-attained hypothesis
+    /// at the current scores `scores`,
+    /// where the `i`-th example has the importance weight `weights[i]`.
+    /// By default, this method ignores `weights` and returns
+    /// [`LossFunction::best_coefficient_at`].
+    fn best_coefficient_with_weights(
+        &self,
+        scores: &[f64],
+        target: &[f64],
+        predictions: &[f64],
+        _weights: &[f64],
+    ) -> f64
+    {
+        self.best_coefficient_at(scores, target, predictions)
+    }
+
+
+    /// Checks whether the target values are valid for this loss.
+    /// This method panics if some target value is invalid.
+    /// By default, this method accepts any target values.
+    fn validate_target(&self, _target: &[f64]) {}
 }
 
 
 /// Some well-known loss functions.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum GBMLoss {
     /// `L1`-loss.
     /// This loss function is also known as
@@ -53,15 +95,85 @@ pub enum GBMLoss {
     L2,
 
 
-    // /// Huber loss with parameter `delta`.
-    // /// Huber loss maps the given scalar `z` to
-    // /// `0.5 * z.powi(2)` if `z.abs() < delta`,
-    // /// `delta * (z.abs() - 0.5 * delta)`, otherwise.
-    // Huber(f64),
-
-
-    // /// Quantile loss
-    // Quantile(f64),
+    /// Logistic loss for the binary labels `y ∈ {-1, +1}`.
+    /// The prediction `F` is regarded as the logit of `P(y = +1)`,
+    /// and the loss is `ln(1 + exp(-y F))`.
+    LogLoss,
+
+
+    /// Exponential loss for the binary labels `y ∈ {-1, +1}`.
+    /// The loss is `exp(-y F)`,
+    /// which is the loss that AdaBoost minimizes.
+    /// The prediction `F` is regarded as the half of
+    /// the logit of `P(y = +1)`.
+    Exponential,
+
+
+    /// Huber loss with parameter `delta > 0`.
+    /// Huber loss maps the residual `z = F - y` to
+    /// `0.5 * z.powi(2)` if `z.abs() <= delta`,
+    /// `delta * (z.abs() - 0.5 * delta)`, otherwise.
+    /// This loss is robust to the outliers in the target values.
+    Huber(f64),
+
+
+    /// Quantile loss (a.k.a. pinball loss)
+    /// with parameter `alpha ∈ (0, 1)`.
+    /// Quantile loss maps the residual `r = y - F` to
+    /// `alpha * r` if `r >= 0`,
+    /// `(alpha - 1) * r`, otherwise.
+    /// The minimizer of this loss is the `alpha`-quantile of `y`,
+    /// so that two models trained on `Quantile(0.05)` and `Quantile(0.95)`
+    /// give a `90%` prediction interval.
+    Quantile(f64),
+
+
+    /// Poisson loss for the nonnegative targets such as counts.
+    /// The prediction `F` is regarded as the logarithm of the mean,
+    /// and the loss is the negative log-likelihood
+    /// `exp(F) - y F` up to a constant.
+    Poisson,
+
+
+    /// Tweedie loss with the power parameter `p ∈ (1, 2)`
+    /// for the nonnegative targets
+    /// such as insurance claims, which have a point mass at zero.
+    /// The prediction `F` is regarded as the logarithm of the mean,
+    /// and the loss is the negative log-likelihood
+    /// `- y exp((1 - p) F) / (1 - p) + exp((2 - p) F) / (2 - p)`
+    /// up to a constant.
+    Tweedie(f64),
+
+
+    /// Savage loss for the binary labels `y ∈ {-1, +1}`.
+    /// The loss is `1 / (1 + exp(2 y F))²`,
+    /// which is bounded by `1`,
+    /// so that the mislabeled examples with large negative margins
+    /// have little influence on the model.
+    /// As [`GBMLoss::Exponential`],
+    /// the prediction `F` is regarded as the half of
+    /// the logit of `P(y = +1)`.
+    ///
+    /// Since this loss is not convex,
+    /// [`LossFunction::hessian`] returns
+    /// the absolute value of the second derivative,
+    /// so that the Newton step is always a descent direction.
+    Savage,
+
+
+    /// Truncated logistic loss with truncation level `s > 0`
+    /// for the binary labels `y ∈ {-1, +1}`.
+    /// The loss is the difference of two logistic losses
+    /// `ln(1 + exp(-y F)) - ln(1 + exp(-y F - s))`,
+    /// which behaves as the logistic loss for the large margins
+    /// and is bounded by `s` for the negative margins.
+    /// Thus, this loss is robust to the label noise.
+    ///
+    /// Since this loss is not convex,
+    /// [`LossFunction::hessian`] returns
+    /// the absolute value of the second derivative,
+    /// so that the Newton step is always a descent direction.
+    TruncatedLogLoss(f64),
 }
 
 
@@ -70,7 +182,14 @@ impl LossFunction for GBMLoss {
         match self {
             Self::L1 => "L1 loss",
             Self::L2 => "L2 loss",
-            // Self::Huber(_) => "Huber loss",
+            Self::LogLoss => "Logistic loss",
+            Self::Exponential => "Exponential loss",
+            Self::Huber(_) => "Huber loss",
+            Self::Quantile(_) => "Quantile loss",
+            Self::Poisson => "Poisson loss",
+            Self::Tweedie(_) => "Tweedie loss",
+            Self::Savage => "Savage loss",
+            Self::TruncatedLogLoss(_) => "Truncated logistic loss",
         }
     }
 
@@ -79,14 +198,40 @@ impl LossFunction for GBMLoss {
         match self {
             Self::L1 => (prediction - true_value).abs(),
             Self::L2 => (prediction - true_value).powi(2),
-            // Self::Huber(delta) => {
-            //     let diff = (prediction - true_value).abs();
-            //     if diff < *delta {
-            //         0.5 * diff.powi(2)
-            //     } else {
-            //         delta * (diff - 0.5 * delta)
-            //     }
-            // },
+            Self::LogLoss => softplus(-true_value * prediction),
+            Self::Exponential => (-true_value * prediction).exp(),
+            Self::Huber(delta) => {
+                let diff = (prediction - true_value).abs();
+                if diff <= *delta {
+                    0.5 * diff.powi(2)
+                } else {
+                    delta * (diff - 0.5 * delta)
+                }
+            },
+            Self::Quantile(alpha) => {
+                let diff = true_value - prediction;
+                if diff >= 0f64 {
+                    alpha * diff
+                } else {
+                    (alpha - 1f64) * diff
+                }
+            },
+            Self::Poisson => prediction.exp() - true_value * prediction,
+            Self::Tweedie(power) => {
+                assert_tweedie_power(*power);
+                let a = 1f64 - power;
+                let b = 2f64 - power;
+                - true_value * (a * prediction).exp() / a
+                    + (b * prediction).exp() / b
+            },
+            Self::Savage => {
+                sigmoid(-2f64 * true_value * prediction).powi(2)
+            },
+            Self::TruncatedLogLoss(level) => {
+                assert_truncation_level(*level);
+                let margin = true_value * prediction;
+                softplus(-margin) - softplus(-margin - level)
+            },
         }
     }
 
@@ -110,19 +255,51 @@ impl LossFunction for GBMLoss {
                     .map(|(y, p)| p - y)
                     .collect()
             },
-            // Self::Huber(delta) => {
-            //     target.iter()
-            //         .zip(predictions)
-            //         .map(|(y, p)| {
-            //             let diff = y - p;
-            //             if diff.abs() < *delta {
-            //                 -diff
-            //             } else {
-            //                 delta * diff.signum()
-            //             }
-            //         })
-            //         .collect::<Vec<_>>()
-            // },
+            Self::LogLoss => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| sigmoid(*p) - (y + 1f64) / 2f64)
+                    .collect()
+            },
+            Self::Exponential => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| -y * (-y * p).exp())
+                    .collect()
+            },
+            Self::Huber(delta) => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| huber_derivative(p - y, *delta))
+                    .collect()
+            },
+            Self::Quantile(alpha) => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| -quantile_derivative(y - p, *alpha))
+                    .collect()
+            },
+            Self::Poisson => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| p.exp() - y)
+                    .collect()
+            },
+            Self::Tweedie(power) => {
+                assert_tweedie_power(*power);
+                let a = 1f64 - power;
+                let b = 2f64 - power;
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| - y * (a * p).exp() + (b * p).exp())
+                    .collect()
+            },
+            Self::Savage | Self::TruncatedLogLoss(_) => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| y * self.margin_derivatives(y * p).0)
+                    .collect()
+            },
         }
     }
 
@@ -133,7 +310,7 @@ impl LossFunction for GBMLoss {
         assert_eq!(n_sample as usize, target.len());
 
         match self {
-            Self::L1 => {
+            Self::L1 | Self::Quantile(_) => {
                 std::iter::repeat(0f64)
                     .take(n_sample)
                     .collect()
@@ -143,15 +320,49 @@ impl LossFunction for GBMLoss {
                     .take(n_sample)
                     .collect()
             },
-            // Self::Huber(delta) => {
-            //     target.iter()
-            //         .zip(predictions)
-            //         .map(|(y, p)| {
-            //             let diff = (y - p).abs();
-            //             if diff < *delta { 1f64 } else { 0f64 }
-            //         })
-            //         .collect::<Vec<_>>()
-            // },
+            Self::LogLoss => {
+                predictions.iter()
+                    .map(|p| {
+                        let prob = sigmoid(*p);
+                        prob * (1f64 - prob)
+                    })
+                    .collect()
+            },
+            Self::Exponential => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| (-y * p).exp())
+                    .collect()
+            },
+            Self::Huber(delta) => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| {
+                        let diff = (y - p).abs();
+                        if diff <= *delta { 1f64 } else { 0f64 }
+                    })
+                    .collect()
+            },
+            Self::Poisson => {
+                predictions.iter()
+                    .map(|p| p.exp())
+                    .collect()
+            },
+            Self::Tweedie(power) => {
+                assert_tweedie_power(*power);
+                let a = 1f64 - power;
+                let b = 2f64 - power;
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| - y * a * (a * p).exp() + b * (b * p).exp())
+                    .collect()
+            },
+            Self::Savage | Self::TruncatedLogLoss(_) => {
+                target.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| self.margin_derivatives(y * p).1.abs())
+                    .collect()
+            },
         }
     }
 
@@ -181,8 +392,373 @@ impl LossFunction for GBMLoss {
 
                 y_sum / p_sum
             },
+            Self::LogLoss => {
+                // The Newton step from the zero predictions.
+                let numer = targets.iter()
+                    .zip(predictions)
+                    .map(|(y, p)| y * p)
+                    .sum::<f64>();
+                let denom = predictions.iter()
+                    .map(|p| p * p)
+                    .sum::<f64>();
+
+                assert_ne!(denom, 0.0);
+
+                2f64 * numer / denom
+            },
+            Self::Exponential | Self::Savage | Self::TruncatedLogLoss(_) => {
+                // The Newton step from the zero predictions.
+                let zeros = vec![0f64; predictions.len()];
+                newton_step(self, &zeros[..], targets, predictions)
+            },
+            Self::Huber(delta) => {
+                // The derivative of `c ↦ Σ_i ψ(r_i - c f_i)`.
+                let derivative = |c: f64| {
+                    targets.iter()
+                        .zip(predictions)
+                        .map(|(r, f)| -f * huber_derivative(r - c * f, *delta))
+                        .sum::<f64>()
+                };
+                convex_line_search(derivative)
+            },
+            Self::Quantile(alpha) => {
+                // The subderivative of `c ↦ Σ_i ρ(r_i - c f_i)`.
+                let derivative = |c: f64| {
+                    targets.iter()
+                        .zip(predictions)
+                        .map(|(r, f)| -f * quantile_derivative(r - c * f, *alpha))
+                        .sum::<f64>()
+                };
+                convex_line_search(derivative)
+            },
+            Self::Poisson | Self::Tweedie(_) => {
+                // The exact line search from the zero predictions.
+                let zeros = vec![0f64; predictions.len()];
+                log_link_line_search(self, &zeros[..], targets, predictions)
+            },
+        }
+    }
+
+
+    /// For `GBMLoss::Poisson` and `GBMLoss::Tweedie`,
+    /// this method returns the exact line search in the log scale.
+    /// Except for `GBMLoss::L1`, `GBMLoss::Huber`, and `GBMLoss::Quantile`,
+    /// this method returns the Newton step at `scores`
+    /// along the new hypothesis,
+    /// which is the exact line search for `GBMLoss::L2`.
+    fn best_coefficient_at(
+        &self,
+        scores: &[f64],
+        target: &[f64],
+        predictions: &[f64],
+    ) -> f64
+    {
+        match self {
+            Self::L1 | Self::Huber(_) | Self::Quantile(_) => {
+                let residuals = target.iter()
+                    .zip(scores)
+                    .map(|(y, s)| y - s)
+                    .collect::<Vec<_>>();
+                self.best_coefficient(&residuals[..], predictions)
+            },
+            Self::L2 | Self::LogLoss | Self::Exponential
+                | Self::Savage | Self::TruncatedLogLoss(_) =>
+            {
+                newton_step(self, scores, target, predictions)
+            },
+            Self::Poisson | Self::Tweedie(_) => {
+                log_link_line_search(self, scores, target, predictions)
+            },
+        }
+    }
+
+
+    /// For the losses whose coefficient is the Newton step
+    /// (see [`GBMLoss::best_coefficient_at`]),
+    /// this method returns the Newton step
+    /// for the loss weighted by `weights`.
+    /// Otherwise, this method ignores `weights`.
+    fn best_coefficient_with_weights(
+        &self,
+        scores: &[f64],
+        target: &[f64],
+        predictions: &[f64],
+        weights: &[f64],
+    ) -> f64
+    {
+        match self {
+            Self::L2 | Self::LogLoss | Self::Exponential
+                | Self::Savage | Self::TruncatedLogLoss(_) =>
+            {
+                weighted_newton_step(
+                    self, scores, target, predictions, weights
+                )
+            },
+            _ => self.best_coefficient_at(scores, target, predictions),
         }
     }
+
+
+    /// `GBMLoss::Poisson` and `GBMLoss::Tweedie` require
+    /// the nonnegative target values.
+    fn validate_target(&self, target: &[f64]) {
+        match self {
+            Self::Poisson | Self::Tweedie(_) => {
+                assert!(
+                    target.iter().all(|y| *y >= 0f64),
+                    "{} requires the nonnegative target values",
+                    self.name()
+                );
+            },
+            _ => {},
+        }
+    }
+}
+
+
+impl GBMLoss {
+    /// Returns the probability that the example is positive
+    /// from the score `F` of a model trained on `self`.
+    /// This method is defined only for
+    /// `GBMLoss::LogLoss` (`p = 1 / (1 + exp(-F))`) and
+    /// `GBMLoss::Exponential` and `GBMLoss::Savage`
+    /// (`p = 1 / (1 + exp(-2F))`).
+    pub fn probability(&self, score: f64) -> f64 {
+        match self {
+            Self::LogLoss => sigmoid(score),
+            Self::Exponential | Self::Savage => sigmoid(2f64 * score),
+            _ => panic!(
+                "The probability is not defined for {}",
+                self.name()
+            ),
+        }
+    }
+
+
+    /// Returns the mean `μ = exp(F)` of the target value
+    /// from the score `F` of a model trained on `self`.
+    /// This method is defined only for
+    /// `GBMLoss::Poisson` and `GBMLoss::Tweedie`.
+    pub fn mean(&self, score: f64) -> f64 {
+        match self {
+            Self::Poisson | Self::Tweedie(_) => score.exp(),
+            _ => panic!(
+                "The log link is not defined for {}",
+                self.name()
+            ),
+        }
+    }
+
+
+    /// Returns the first and the second derivatives
+    /// of the margin losses `GBMLoss::Savage` and
+    /// `GBMLoss::TruncatedLogLoss`
+    /// at the margin `z = y F`.
+    fn margin_derivatives(&self, margin: f64) -> (f64, f64) {
+        match self {
+            Self::Savage => {
+                // `φ(z) = s²`, where `s = 1 / (1 + exp(2z))`.
+                let s = sigmoid(-2f64 * margin);
+                let first = -4f64 * s * s * (1f64 - s);
+                let second = 8f64 * s * s * (1f64 - s) * (2f64 - 3f64 * s);
+                (first, second)
+            },
+            Self::TruncatedLogLoss(level) => {
+                assert_truncation_level(*level);
+                let p = sigmoid(-margin);
+                let q = sigmoid(-margin - level);
+                let first = - p + q;
+                let second = p * (1f64 - p) - q * (1f64 - q);
+                (first, second)
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+
+/// Returns the minimizer of `c ↦ Σ_i loss(s_i + c f_i, y_i)`
+/// for the losses with the log link,
+/// where `s` is `scores` and `f` is the predictions of a new hypothesis.
+fn log_link_line_search<L>(
+    loss: &L,
+    scores: &[f64],
+    target: &[f64],
+    predictions: &[f64],
+) -> f64
+    where L: LossFunction,
+{
+    let derivative = |c: f64| {
+        let shifted = scores.iter()
+            .zip(predictions)
+            .map(|(s, f)| s + c * f)
+            .collect::<Vec<_>>();
+        loss.gradient(&shifted[..], target)
+            .into_iter()
+            .zip(predictions)
+            .map(|(g, f)| g * f)
+            .sum::<f64>()
+    };
+    convex_line_search(derivative)
+}
+
+
+/// Returns the Newton step `- Σ g_i f_i / Σ h_i f_i²`
+/// at `scores` along the predictions `f` of a new hypothesis,
+/// where `g` and `h` are the gradient and the Hessian of `loss`.
+fn newton_step<L>(
+    loss: &L,
+    scores: &[f64],
+    target: &[f64],
+    predictions: &[f64],
+) -> f64
+    where L: LossFunction,
+{
+    let gradient = loss.gradient(scores, target);
+    let hessian = loss.hessian(scores, target);
+
+    let numer = gradient.into_iter()
+        .zip(predictions)
+        .map(|(g, f)| g * f)
+        .sum::<f64>();
+    let denom = hessian.into_iter()
+        .zip(predictions)
+        .map(|(h, f)| h * f * f)
+        .sum::<f64>();
+
+    if denom == 0f64 {
+        return 0f64;
+    }
+    - numer / denom
+}
+
+
+/// Returns the Newton step of the loss weighted by `weights`.
+fn weighted_newton_step<L>(
+    loss: &L,
+    scores: &[f64],
+    target: &[f64],
+    predictions: &[f64],
+    weights: &[f64],
+) -> f64
+    where L: LossFunction,
+{
+    let gradient = loss.gradient(scores, target);
+    let hessian = loss.hessian(scores, target);
+
+    let numer = gradient.into_iter()
+        .zip(predictions)
+        .zip(weights)
+        .map(|((g, f), w)| w * g * f)
+        .sum::<f64>();
+    let denom = hessian.into_iter()
+        .zip(predictions)
+        .zip(weights)
+        .map(|((h, f), w)| w * h * f * f)
+        .sum::<f64>();
+
+    if denom == 0f64 {
+        return 0f64;
+    }
+    - numer / denom
+}
+
+
+/// Returns the derivative of Huber loss `z ↦ ψ(z)`
+/// with parameter `delta`.
+fn huber_derivative(z: f64, delta: f64) -> f64 {
+    assert!(delta > 0f64, "The parameter of Huber loss must be positive");
+    if z.abs() <= delta {
+        z
+    } else {
+        delta * z.signum()
+    }
+}
+
+
+/// Returns a subderivative of Quantile loss `r ↦ ρ(r)`
+/// with parameter `alpha`.
+fn quantile_derivative(r: f64, alpha: f64) -> f64 {
+    assert!(
+        0f64 < alpha && alpha < 1f64,
+        "The parameter of Quantile loss must be in (0, 1)"
+    );
+    if r > 0f64 {
+        alpha
+    } else if r < 0f64 {
+        alpha - 1f64
+    } else {
+        0f64
+    }
+}
+
+
+/// Panics if the power parameter of Tweedie loss is not in `(1, 2)`.
+fn assert_tweedie_power(power: f64) {
+    assert!(
+        1f64 < power && power < 2f64,
+        "The power parameter of Tweedie loss must be in (1, 2)"
+    );
+}
+
+
+/// Panics if the truncation level of the truncated logistic loss
+/// is not positive.
+fn assert_truncation_level(level: f64) {
+    assert!(
+        level > 0f64,
+        "The truncation level of the truncated logistic loss must be positive"
+    );
+}
+
+
+/// Returns a minimizer of a one-dimensional convex function
+/// from its non-decreasing (sub)derivative
+/// by the bisection method.
+/// This function returns `0` if the minimizer is not bracketed
+/// in `[-2^63, 2^63]`.
+fn convex_line_search<D>(derivative: D) -> f64
+    where D: Fn(f64) -> f64,
+{
+    const MAX_LOOP: usize = 64;
+    let mut lb = -1f64;
+    let mut ub = 1f64;
+
+    let mut n_loop = 0;
+    while derivative(lb) > 0f64 || derivative(ub) < 0f64 {
+        if n_loop >= MAX_LOOP {
+            return 0f64;
+        }
+        lb *= 2f64;
+        ub *= 2f64;
+        n_loop += 1;
+    }
+
+    for _ in 0..MAX_LOOP {
+        let mid = 0.5 * (lb + ub);
+        if derivative(mid) < 0f64 {
+            lb = mid;
+        } else {
+            ub = mid;
+        }
+    }
+    0.5 * (lb + ub)
+}
+
+
+/// Returns `1 / (1 + exp(-x))`.
+pub(crate) fn sigmoid(x: f64) -> f64 {
+    1f64 / (1f64 + (-x).exp())
+}
+
+
+/// Returns `ln(1 + exp(x))` without overflow.
+pub(crate) fn softplus(x: f64) -> f64 {
+    if x > 0f64 {
+        x + (-x).exp().ln_1p()
+    } else {
+        x.exp().ln_1p()
+    }
 }
 
 
@@ -0,0 +1,125 @@
+//! This file defines the rules to pick a hypothesis
+//! among the candidates returned by a weak learner.
+
+use rand::prelude::*;
+use rand::distributions::WeightedIndex;
+
+use crate::{
+    Sample,
+    Classifier,
+    WeakLearner,
+    common::utils,
+};
+
+use std::fmt;
+
+
+/// The seed of the random number generator used for the selection.
+pub(crate) const SEED: u64 = 1234;
+
+
+/// Hypothesis selection rules.
+/// These options specify how a booster picks a hypothesis
+/// from the candidates returned by
+/// [`WeakLearner::produce_many`](crate::WeakLearner::produce_many).
+/// Picking a hypothesis other than the best one
+/// regularizes the boosters
+/// and avoids adding the same hypothesis repeatedly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Selection {
+    /// Always takes the best hypothesis,
+    /// i.e., the output of
+    /// [`WeakLearner::produce`](crate::WeakLearner::produce).
+    Best,
+
+    /// Picks one of the top-`k` candidates uniformly at random.
+    Uniform(usize),
+
+    /// Picks one of the top-`k` candidates at random
+    /// with probability proportional to `exp(|edge| / temperature)`.
+    Softmax {
+        /// The number of candidates.
+        k: usize,
+        /// The temperature parameter.
+        /// The smaller the temperature, the greedier the selection.
+        temperature: f64,
+    },
+}
+
+
+impl Selection {
+    /// Checks the parameters of `self`.
+    pub(crate) fn check(&self) {
+        match self {
+            Self::Best => {},
+            Self::Uniform(k) => {
+                assert!(*k > 0, "The number of candidates must be positive");
+            },
+            Self::Softmax { k, temperature } => {
+                assert!(*k > 0, "The number of candidates must be positive");
+                assert!(
+                    *temperature > 0f64,
+                    "The temperature must be positive"
+                );
+            },
+        }
+    }
+
+
+    /// Obtains a hypothesis from `weak_learner`
+    /// based on the selection rule.
+    pub(crate) fn produce<W, H, R>(
+        &self,
+        weak_learner: &W,
+        sample: &Sample,
+        dist: &[f64],
+        rng: &mut R,
+    ) -> H
+        where W: WeakLearner<Hypothesis = H>,
+              H: Classifier,
+              R: Rng,
+    {
+        let k = match self {
+            Self::Best => { return weak_learner.produce(sample, dist); },
+            Self::Uniform(k) | Self::Softmax { k, .. } => *k,
+        };
+
+        let mut candidates = weak_learner.produce_many(sample, dist, k);
+        assert!(
+            !candidates.is_empty(),
+            "The weak learner returned no candidate"
+        );
+
+        let pos = match self {
+            Self::Softmax { temperature, .. } => {
+                let edges = candidates.iter()
+                    .map(|h| utils::edge_of_hypothesis(sample, dist, h).abs())
+                    .collect::<Vec<_>>();
+                // Shift the edges to avoid the overflow.
+                let max = edges.iter()
+                    .copied()
+                    .fold(f64::MIN, f64::max);
+                let weights = edges.into_iter()
+                    .map(|edge| ((edge - max) / temperature).exp());
+                WeightedIndex::new(weights)
+                    .expect("Failed to construct the softmax distribution")
+                    .sample(rng)
+            },
+            _ => rng.gen_range(0..candidates.len()),
+        };
+        candidates.swap_remove(pos)
+    }
+}
+
+
+impl fmt::Display for Selection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Best => write!(f, "Best"),
+            Self::Uniform(k) => write!(f, "Uniform over top-{k}"),
+            Self::Softmax { k, temperature } => {
+                write!(f, "Softmax over top-{k} (temperature: {temperature})")
+            },
+        }
+    }
+}
@@ -9,6 +9,10 @@ use crate::common::checker;
 
 /// Returns the edge of a single hypothesis for the given distribution.
 /// Here `edge` is the weighted training loss.
+/// The examples on which `h` abstains, i.e., `h(x_i) = 0`,
+/// do not contribute to the edge.
+/// Thus, for the hypotheses with predictions in `{-1, 0, +1}`,
+/// the edge is `W+ - W-` (see [`abstaining_weights`]).
 /// 
 /// Time complexity: `O(m)`, where `m` is the number of training examples.
 #[inline(always)]
@@ -27,6 +31,33 @@ pub fn edge_of_hypothesis<H>(
 }
 
 
+/// Returns the triplet `(W+, W-, W0)` of the weights
+/// on which the hypothesis is correct, wrong, and abstains,
+/// given its margins `y_i h(x_i)`.
+/// Returns `None` if some margin is not in `{-1, 0, +1}`,
+/// i.e., the hypothesis is not an abstaining one.
+/// 
+/// Time complexity: `O(m)`, where `m` is the number of training examples.
+#[inline(always)]
+pub fn abstaining_weights(margins: &[f64], dist: &[f64])
+    -> Option<(f64, f64, f64)>
+{
+    let mut weights = (0f64, 0f64, 0f64);
+    for (&yh, &d) in margins.iter().zip(dist) {
+        if yh == 1f64 {
+            weights.0 += d;
+        } else if yh == -1f64 {
+            weights.1 += d;
+        } else if yh == 0f64 {
+            weights.2 += d;
+        } else {
+            return None;
+        }
+    }
+    Some(weights)
+}
+
+
 /// Returns the margin vector of a single hypothesis
 /// for the given distribution.
 /// 
@@ -261,6 +292,22 @@ pub fn entropy_from_uni_distribution<T: AsRef<[f64]>>(dist: T) -> f64 {
 }
 
 
+/// Compute the relative entropy of `dist` from `prior`.
+#[inline(always)]
+pub fn relative_entropy<T, U>(dist: T, prior: U) -> f64
+    where T: AsRef<[f64]>,
+          U: AsRef<[f64]>,
+{
+    let dist = dist.as_ref();
+    let prior = prior.as_ref();
+    assert_eq!(dist.len(), prior.len());
+    dist.iter()
+        .zip(prior)
+        .map(|(&d, &p)| if d == 0.0 { 0.0 } else { d * (d / p).ln() })
+        .sum::<f64>()
+}
+
+
 /// Compute the entropy of the given distribution.
 #[inline(always)]
 pub fn entropy<T: AsRef<[f64]>>(dist: T) -> f64 {
@@ -344,3 +391,72 @@ pub(crate) fn format_unit(value: f64) -> String {
     let g = m / 1_000f64;
     format!("{g:>.1}G")
 }
+
+
+/// Returns the pair of the accuracy and the average margin
+/// of `f` over `sample`.
+/// This pair is used to compare the models trained
+/// with different parameters on a validation sample.
+pub(crate) fn validation_score<H>(sample: &Sample, f: &H) -> (f64, f64)
+    where H: Classifier,
+{
+    let n_sample = sample.shape().0 as f64;
+    let margins = margins_of_hypothesis(sample, f);
+    let accuracy = margins.iter()
+        .filter(|&&yh| yh > 0f64)
+        .count() as f64
+        / n_sample;
+    let margin = margins.iter().sum::<f64>() / n_sample;
+    (accuracy, margin)
+}
+
+
+/// Returns the capping parameter at `iteration`
+/// for the schedule that anneals `initial_nu` to `nu`
+/// geometrically over the first `n_rounds` rounds.
+/// The first round uses `initial_nu` and
+/// the rounds after `n_rounds` use `nu`.
+pub(crate) fn annealed_nu(
+    initial_nu: f64,
+    nu: f64,
+    n_rounds: usize,
+    iteration: usize,
+) -> f64
+{
+    let t = iteration.saturating_sub(1);
+    if n_rounds == 0 || t >= n_rounds { return nu; }
+    let ratio = t as f64 / n_rounds as f64;
+    initial_nu * (nu / initial_nu).powf(ratio)
+}
+
+
+/// Solves the linear system `A x = b`
+/// by the Gaussian elimination with partial pivoting.
+pub(crate) fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Vec<f64> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| {
+                a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap()
+            })
+            .unwrap();
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col].clone();
+        for row in col+1..n {
+            let ratio = a[row][col] / pivot_row[col];
+            a[row][col..].iter_mut()
+                .zip(&pivot_row[col..])
+                .for_each(|(v, p)| { *v -= ratio * p; });
+            b[row] -= ratio * b[col];
+        }
+    }
+
+    let mut x = vec![0f64; n];
+    for row in (0..n).rev() {
+        let sum = (row+1..n).map(|k| a[row][k] * x[k]).sum::<f64>();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    x
+}
@@ -0,0 +1,119 @@
+//! This file defines the weighted sampling of the examples.
+use rand::prelude::*;
+
+
+/// A sampler that draws an index `i`
+/// with probability proportional to `weights[i]`.
+/// The sampler is built by the alias method of Vose,
+/// so that each draw takes `O(1)` time
+/// after the `O(m)` time construction,
+/// where `m` is the number of weights.
+///
+/// Weak learners can train on a weighted bootstrap
+/// drawn from the boosting distribution
+/// (see [`WeightedSampler::bootstrap`])
+/// instead of the full sample.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use rand::prelude::*;
+///
+/// let dist = vec![0.5, 0.25, 0.25, 0.0];
+/// let sampler = WeightedSampler::new(&dist);
+///
+/// let mut rng = StdRng::seed_from_u64(1234);
+/// let index = sampler.sample(&mut rng);
+/// assert_ne!(index, 3);
+///
+/// // The empirical distribution of 100 draws.
+/// let subsample = sampler.bootstrap(100, &mut rng);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeightedSampler {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+
+impl WeightedSampler {
+    /// Construct a new instance of [`WeightedSampler`].
+    /// The weights must be non-negative and finite,
+    /// and at least one of them must be positive.
+    ///
+    /// Time complexity: `O(m)`, where `m` is the number of weights.
+    pub fn new(weights: &[f64]) -> Self {
+        assert!(
+            weights.iter().all(|w| w.is_finite() && *w >= 0f64),
+            "The weights must be non-negative and finite"
+        );
+        let total = weights.iter().sum::<f64>();
+        assert!(total > 0f64, "The total weight must be positive");
+
+        let n_items = weights.len();
+        let mut prob = weights.iter()
+            .map(|w| w * n_items as f64 / total)
+            .collect::<Vec<_>>();
+        let mut alias = (0..n_items).collect::<Vec<_>>();
+
+        let (mut small, mut large): (Vec<_>, Vec<_>) = (0..n_items)
+            .partition(|&i| prob[i] < 1f64);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            alias[s] = l;
+            prob[l] -= 1f64 - prob[s];
+            if prob[l] < 1f64 {
+                large.pop();
+                small.push(l);
+            }
+        }
+
+        // The remaining items have probability `1`
+        // up to the rounding errors.
+        // The items of zero weight are never drawn.
+        let positive = weights.iter()
+            .position(|w| *w > 0f64)
+            .unwrap();
+        for i in small.into_iter().chain(large) {
+            if weights[i] > 0f64 {
+                prob[i] = 1f64;
+            } else {
+                prob[i] = 0f64;
+                alias[i] = positive;
+            }
+        }
+
+        Self { prob, alias }
+    }
+
+
+    /// Returns the empirical distribution of `size` indices
+    /// drawn independently from `self`.
+    /// The mass of index `i` is the number of draws of `i`
+    /// divided by `size`,
+    /// so that the indices never drawn have zero mass.
+    ///
+    /// Time complexity: `O(m + size)`.
+    pub fn bootstrap<R>(&self, size: usize, rng: &mut R) -> Vec<f64>
+        where R: Rng + ?Sized,
+    {
+        assert!(size > 0, "The subsample size must be positive");
+        let mut dist = vec![0f64; self.prob.len()];
+        let mass = 1f64 / size as f64;
+        for _ in 0..size {
+            let i = self.sample(rng);
+            dist[i] += mass;
+        }
+        dist
+    }
+}
+
+
+impl Distribution<usize> for WeightedSampler {
+    /// Draws an index in `O(1)` time.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let i = rng.gen_range(0..self.prob.len());
+        if rng.gen::<f64>() < self.prob[i] { i } else { self.alias[i] }
+    }
+}
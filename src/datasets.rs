@@ -0,0 +1,34 @@
+//! This directory provides the generators of synthetic samples,
+//! which make the examples and the benchmarks runnable
+//! without downloading datasets.
+//! Each generator is deterministic for a fixed seed.
+//! The `j`-th feature of a generated sample is named `Feat. [j]`,
+//! and the binary labels are `-1` and `+1`.
+//!
+//! # Example
+//! ```no_run
+//! use miniboosts::prelude::*;
+//! use miniboosts::datasets::make_two_spirals;
+//!
+//! let sample = make_two_spirals(1_000, 0.05, 1234);
+//! let tree = DecisionTreeBuilder::new(&sample)
+//!     .max_depth(3)
+//!     .build();
+//! let f = AdaBoost::init(&sample)
+//!     .force_quit_at(100)
+//!     .run(&tree);
+//! ```
+
+// Defines the isotropic Gaussian blobs.
+mod gaussian_blobs;
+// Defines the two interleaving spirals.
+mod two_spirals;
+// Defines the XOR pattern.
+mod xor;
+// Defines the sample with flipped labels and a known soft margin.
+mod noisy_margin;
+
+pub use gaussian_blobs::make_gaussian_blobs;
+pub use two_spirals::make_two_spirals;
+pub use xor::make_xor;
+pub use noisy_margin::{make_noisy_margin, noisy_margin_optimum};
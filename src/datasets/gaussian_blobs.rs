@@ -0,0 +1,52 @@
+use rand::prelude::*;
+use rand_distr::{Normal, Uniform};
+
+use crate::Sample;
+
+
+/// Returns a sample of `n_sample` examples
+/// drawn from `n_class` isotropic Gaussian distributions
+/// over `n_feature` dimensions, one for each class.
+/// The centers are drawn uniformly from `[-10, 10]^n_feature`,
+/// and the standard deviation of each coordinate is `std`.
+/// The classes are assigned to the examples in turn.
+///
+/// The labels are `-1` and `+1` if `n_class == 2`,
+/// and `0, 1, ..., n_class - 1` otherwise,
+/// the same as the ones read by
+/// [`SampleReader`](crate::SampleReader).
+pub fn make_gaussian_blobs(
+    n_sample: usize,
+    n_feature: usize,
+    n_class: usize,
+    std: f64,
+    seed: u64,
+) -> Sample
+{
+    assert!(n_class >= 2, "The number of classes must be at least 2");
+    assert!(n_feature >= 1, "The number of features must be at least 1");
+    assert!(std >= 0f64, "The standard deviation must be non-negative");
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let uniform = Uniform::new_inclusive(-10f64, 10f64);
+    let centers = (0..n_class)
+        .map(|_| {
+            (0..n_feature)
+                .map(|_| uniform.sample(&mut rng))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let normal = Normal::new(0f64, std).unwrap();
+    let mut data = Vec::with_capacity(n_sample * n_feature);
+    let mut target = Vec::with_capacity(n_sample);
+    for i in 0..n_sample {
+        let k = i % n_class;
+        centers[k].iter()
+            .for_each(|c| { data.push(c + normal.sample(&mut rng)); });
+        let y = if n_class == 2 { 2f64 * k as f64 - 1f64 } else { k as f64 };
+        target.push(y);
+    }
+    Sample::from_raw_parts(&data[..], n_sample, n_feature, &target[..])
+        .expect("The generated sample has the consistent shape")
+}
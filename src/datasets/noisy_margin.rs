@@ -0,0 +1,81 @@
+use rand::prelude::*;
+
+use crate::Sample;
+
+
+/// Returns a sample of `n_sample` examples
+/// whose optimal soft margin is known,
+/// which is [`noisy_margin_optimum`].
+/// The sample has one feature, the true class `+1` or `-1`,
+/// and the half of the examples are in each class.
+/// The labels of `n_flipped / 2` examples of each class are flipped,
+/// and the examples are shuffled with `seed`.
+///
+/// Since the examples of the same feature value are not distinguishable,
+/// any hypothesis `h` has the same value `h(+1)` or `h(-1)`
+/// on the clean and the flipped examples.
+/// Thus, the margin of a combined hypothesis is `s` on the clean examples
+/// and `-s` on the flipped ones for some `s` in `[-1, 1]`
+/// up to the symmetry of the classes,
+/// and the optimal combination is the decision stump of the threshold `0`.
+///
+/// `n_sample` and `n_flipped` must be even,
+/// and `n_flipped` must be at most `n_sample / 2`.
+pub fn make_noisy_margin(n_sample: usize, n_flipped: usize, seed: u64)
+    -> Sample
+{
+    check_noisy_margin(n_sample, n_flipped);
+
+    let half = n_sample / 2;
+    let mut rows = (0..n_sample)
+        .map(|i| {
+            let z = if i < half { 1f64 } else { -1f64 };
+            let flipped = i % half < n_flipped / 2;
+            let y = if flipped { -z } else { z };
+            (z, y)
+        })
+        .collect::<Vec<_>>();
+    let mut rng = StdRng::seed_from_u64(seed);
+    rows.shuffle(&mut rng);
+
+    let (data, target): (Vec<_>, Vec<_>) = rows.into_iter().unzip();
+    Sample::from_raw_parts(&data[..], n_sample, 1, &target[..])
+        .expect("The generated sample has the consistent shape")
+}
+
+
+/// Returns the optimal soft margin of the sample
+/// generated by [`make_noisy_margin`]
+/// with respect to the capping parameter `nu`,
+/// i.e., the value of
+/// [`SoftMarginObjective`](crate::SoftMarginObjective)
+/// of an optimal combined hypothesis,
+/// `max(0, 1 - 2 * n_flipped / nu)`.
+///
+/// The `nu` smallest margins are the `n_flipped` margins `-s`
+/// and the `nu - n_flipped` margins `s` if `nu > n_flipped`,
+/// so that the soft margin is `s * (1 - 2 * n_flipped / nu)`.
+/// Otherwise, the soft margin is at most `0`.
+pub fn noisy_margin_optimum(n_sample: usize, n_flipped: usize, nu: f64)
+    -> f64
+{
+    check_noisy_margin(n_sample, n_flipped);
+    assert!(
+        (1f64..=n_sample as f64).contains(&nu),
+        "The capping parameter must be in `[1, # of examples]`"
+    );
+    (1f64 - 2f64 * n_flipped as f64 / nu).max(0f64)
+}
+
+
+fn check_noisy_margin(n_sample: usize, n_flipped: usize) {
+    assert!(
+        n_sample >= 2 && n_sample.is_multiple_of(2),
+        "The number of examples must be a positive even number"
+    );
+    assert!(
+        n_flipped.is_multiple_of(2) && 2 * n_flipped <= n_sample,
+        "The number of flipped labels must be even \
+         and at most the half of the examples"
+    );
+}
@@ -0,0 +1,46 @@
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use std::f64::consts::PI;
+
+use crate::Sample;
+
+
+/// The number of turns of each spiral.
+const N_TURNS: f64 = 1.5;
+
+
+/// Returns a sample of `n_sample` examples on two interleaving spirals
+/// over two dimensions, one for each class.
+/// The example of the label `+1` at the angle `t` is
+/// `(t cos t, t sin t) / T`,
+/// where `t` is drawn from `[0, T]` with the density proportional to `t`
+/// and `T` is the angle of one and a half turns.
+/// The example of the label `-1` is the point symmetric to it
+/// with respect to the origin.
+/// Finally, the Gaussian noise of the standard deviation `noise`
+/// is added to each coordinate.
+///
+/// Since the boundary of the classes is not axis-aligned,
+/// this sample needs many decision stumps or deep trees.
+pub fn make_two_spirals(n_sample: usize, noise: f64, seed: u64) -> Sample {
+    assert!(noise >= 0f64, "The noise level must be non-negative");
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let normal = Normal::new(0f64, noise).unwrap();
+    let max_angle = 2f64 * PI * N_TURNS;
+
+    let mut data = Vec::with_capacity(2 * n_sample);
+    let mut target = Vec::with_capacity(n_sample);
+    for i in 0..n_sample {
+        let y = if i % 2 == 0 { 1f64 } else { -1f64 };
+        let t = max_angle * rng.gen::<f64>().sqrt();
+        let (sin, cos) = t.sin_cos();
+        let r = y / max_angle;
+        data.push(r * t * cos + normal.sample(&mut rng));
+        data.push(r * t * sin + normal.sample(&mut rng));
+        target.push(y);
+    }
+    Sample::from_raw_parts(&data[..], n_sample, 2, &target[..])
+        .expect("The generated sample has the consistent shape")
+}
@@ -0,0 +1,35 @@
+use rand::prelude::*;
+use rand_distr::{Normal, Uniform};
+
+use crate::Sample;
+
+
+/// Returns a sample of `n_sample` examples of the XOR pattern
+/// over two dimensions.
+/// Each example `(x, y)` is drawn uniformly from `[-1, 1]^2`,
+/// and its label is `+1` if `x * y > 0` and `-1` otherwise.
+/// Finally, the Gaussian noise of the standard deviation `noise`
+/// is added to each coordinate.
+///
+/// No single feature is correlated with the labels,
+/// so that the decision stumps fail on this sample
+/// while the trees of depth `2` fit it.
+pub fn make_xor(n_sample: usize, noise: f64, seed: u64) -> Sample {
+    assert!(noise >= 0f64, "The noise level must be non-negative");
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let uniform = Uniform::new_inclusive(-1f64, 1f64);
+    let normal = Normal::new(0f64, noise).unwrap();
+
+    let mut data = Vec::with_capacity(2 * n_sample);
+    let mut target = Vec::with_capacity(n_sample);
+    for _ in 0..n_sample {
+        let x = uniform.sample(&mut rng);
+        let y = uniform.sample(&mut rng);
+        data.push(x + normal.sample(&mut rng));
+        data.push(y + normal.sample(&mut rng));
+        target.push(if x * y > 0f64 { 1f64 } else { -1f64 });
+    }
+    Sample::from_raw_parts(&data[..], n_sample, 2, &target[..])
+        .expect("The generated sample has the consistent shape")
+}
@@ -3,14 +3,39 @@
 pub(crate) mod hypothesis_traits;
 pub(crate) mod weighted_majority;
 pub(crate) mod naive_aggregation;
+pub(crate) mod label_ranker;
+pub(crate) mod bag_classifier;
+pub(crate) mod compression;
+pub(crate) mod log_probability_vote;
+pub(crate) mod logit_classifier;
+pub(crate) mod normal_regressor;
+pub(crate) mod threshold_classifier;
+pub(crate) mod stacked_classifier;
+pub(crate) mod one_vs_all_classifier;
+pub(crate) mod ecoc_classifier;
+pub(crate) mod labeled_classifier;
+pub(crate) mod scaled_hypothesis;
 
 
 pub use hypothesis_traits::{
     Classifier,
     Regressor,
+    MulticlassClassifier,
 };
 
 pub use weighted_majority::WeightedMajority;
 pub use naive_aggregation::NaiveAggregation;
+pub use label_ranker::LabelRanker;
+pub use bag_classifier::BagClassifier;
+pub use compression::{Compression, CompressionReport};
+pub use log_probability_vote::LogProbabilityVote;
+pub use logit_classifier::LogitClassifier;
+pub use normal_regressor::NormalRegressor;
+pub use threshold_classifier::ThresholdClassifier;
+pub use stacked_classifier::StackedClassifier;
+pub use one_vs_all_classifier::OneVsAllClassifier;
+pub use ecoc_classifier::{ECOCClassifier, Decoding};
+pub use labeled_classifier::LabeledClassifier;
+pub use scaled_hypothesis::ScaledHypothesis;
 
 
@@ -0,0 +1,113 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    Sample,
+};
+
+use std::collections::BTreeMap;
+
+
+/// A combined hypothesis for multi-instance problems.
+/// `BagClassifier` scores each instance by the (unnormalized) sum
+/// `F(x) = Σ_t α_t h_t (x)`
+/// and combines the instance probabilities
+/// `p(x) = 1 / (1 + exp(-F(x)))`
+/// of a bag by the **noisy-OR** model:
+/// `p(bag) = 1 - Π_{x ∈ bag} (1 - p(x))`.
+/// The multi-instance boosting algorithms return this struct.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BagClassifier<H> {
+    /// Weights on each hypothesis in `self.hypotheses`.
+    pub weights: Vec<f64>,
+    /// Set of hypotheses.
+    pub hypotheses: Vec<H>,
+}
+
+
+impl<H> BagClassifier<H>
+    where H: Classifier,
+{
+    /// Construct a new `BagClassifier` from given slices.
+    pub fn from_slices(weights: &[f64], hypotheses: &[H]) -> Self
+        where H: Clone
+    {
+        Self {
+            weights: weights.to_vec(),
+            hypotheses: hypotheses.to_vec(),
+        }
+    }
+
+
+    /// Returns the score `F(x)` of the instance at `row`.
+    pub fn score(&self, sample: &Sample, row: usize) -> f64 {
+        self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .map(|(w, h)| *w * h.confidence(sample, row))
+            .sum::<f64>()
+    }
+
+
+    /// Returns the probability that
+    /// the instance at `row` is positive.
+    pub fn instance_probability(&self, sample: &Sample, row: usize) -> f64 {
+        sigmoid(self.score(sample, row))
+    }
+
+
+    /// Returns the noisy-OR probability that each bag is positive.
+    /// The bags are sorted in ascending order of the bag ids.
+    ///
+    /// This method panics if the bag ids of `sample` are not set.
+    pub fn bag_probabilities(&self, sample: &Sample) -> Vec<(usize, f64)> {
+        let bags = sample.bags()
+            .expect("The bag ids are not set. Call `Sample::set_bags`");
+        let mut log_negative = BTreeMap::new();
+        bags.iter()
+            .enumerate()
+            .for_each(|(row, &bag)| {
+                let score = self.score(sample, row);
+                *log_negative.entry(bag).or_insert(0f64) -= softplus(score);
+            });
+        log_negative.into_iter()
+            .map(|(bag, s)| (bag, -s.exp_m1()))
+            .collect()
+    }
+
+
+    /// Returns the predicted label of each bag.
+    /// A bag is predicted as positive
+    /// if its noisy-OR probability is at least `0.5`.
+    /// The bags are sorted in ascending order of the bag ids.
+    pub fn predict_bags(&self, sample: &Sample) -> Vec<(usize, i64)> {
+        self.bag_probabilities(sample)
+            .into_iter()
+            .map(|(bag, p)| (bag, if p >= 0.5 { 1 } else { -1 }))
+            .collect()
+    }
+}
+
+
+impl<H> Classifier for BagClassifier<H>
+    where H: Classifier,
+{
+    /// Returns `2 p(x) - 1 = tanh(F(x) / 2)`
+    /// for the instance `x` at `row`.
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        (self.score(sample, row) / 2f64).tanh()
+    }
+}
+
+
+fn sigmoid(x: f64) -> f64 {
+    1f64 / (1f64 + (-x).exp())
+}
+
+
+/// Returns `ln(1 + exp(x)) = -ln(1 - sigmoid(x))`.
+pub(crate) fn softplus(x: f64) -> f64 {
+    if x > 0f64 {
+        x + (-x).exp().ln_1p()
+    } else {
+        x.exp().ln_1p()
+    }
+}
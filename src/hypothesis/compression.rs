@@ -0,0 +1,165 @@
+//! Provides the configuration and the report of
+//! the compression of tree ensembles.
+use colored::Colorize;
+
+use std::fmt;
+
+
+/// The parameters of the compression of tree ensembles.
+/// See `WeightedMajority::compress`
+/// for [`DecisionTreeClassifier`] and [`RegressionTreeRegressor`].
+///
+/// The compression consists of the following steps:
+/// 1. Round the leaf values and the weights
+///    to the multiples of `step`.
+/// 2. Replace the branch nodes whose children are identical
+///    by one of the children.
+/// 3. Merge the identical trees into one by adding up their weights.
+/// 4. Remove the trees whose weights are less than
+///    `prune_threshold` times the sum of the absolute weights.
+///
+/// [`DecisionTreeClassifier`]: crate::weak_learner::DecisionTreeClassifier
+/// [`RegressionTreeRegressor`]: crate::weak_learner::RegressionTreeRegressor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Compression {
+    pub(crate) step: f64,
+    pub(crate) prune_threshold: f64,
+}
+
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+impl Compression {
+    /// Construct a new `Compression` with the default parameters.
+    /// The default quantization step is `1e-3` and
+    /// the default pruning threshold is `1e-6`.
+    pub fn new() -> Self {
+        Self {
+            step: 1e-3,
+            prune_threshold: 1e-6,
+        }
+    }
+
+
+    /// Set the quantization step.
+    /// `0` disables the quantization.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn step(mut self, step: f64) -> Self {
+        assert!(step >= 0f64, "The quantization step must be non-negative");
+        self.step = step;
+        self
+    }
+
+
+    /// Set the pruning threshold.
+    /// The trees whose weights are less than
+    /// `threshold` times the sum of the absolute weights are removed.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn prune_threshold(mut self, threshold: f64) -> Self {
+        assert!(
+            (0f64..1f64).contains(&threshold),
+            "The pruning threshold must be in [0, 1)"
+        );
+        self.prune_threshold = threshold;
+        self
+    }
+
+
+    /// Round `value` to the nearest multiple of the quantization step.
+    pub(crate) fn quantize(&self, value: f64) -> f64 {
+        if self.step == 0f64 { return value; }
+        (value / self.step).round() * self.step
+    }
+
+
+    /// Quantize the weights, merge the identical members,
+    /// and remove the members with small weights.
+    pub(crate) fn compress_members<H>(&self, weights: &[f64], hypotheses: &[H])
+        -> (Vec<f64>, Vec<H>)
+        where H: PartialEq + Clone,
+    {
+        let mut new_weights: Vec<f64> = Vec::with_capacity(weights.len());
+        let mut new_hypotheses: Vec<H> = Vec::with_capacity(hypotheses.len());
+        for (&w, h) in weights.iter().zip(hypotheses) {
+            match new_hypotheses.iter().position(|g| g == h) {
+                Some(k) => { new_weights[k] += w; },
+                None => {
+                    new_weights.push(w);
+                    new_hypotheses.push(h.clone());
+                },
+            }
+        }
+        new_weights.iter_mut()
+            .for_each(|w| { *w = self.quantize(*w); });
+
+
+        let total = new_weights.iter()
+            .map(|w| w.abs())
+            .sum::<f64>();
+        let threshold = self.prune_threshold * total;
+        new_weights.into_iter()
+            .zip(new_hypotheses)
+            .filter(|(w, _)| w.abs() > threshold)
+            .unzip()
+    }
+}
+
+
+/// The report of the compression of tree ensembles.
+/// `metric` is the name of the score on the given sample,
+/// the accuracy for the classifiers
+/// and the mean squared error for the regressors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompressionReport {
+    /// The name of the score.
+    pub metric: &'static str,
+    /// The number of trees before the compression.
+    pub trees_before: usize,
+    /// The number of trees after the compression.
+    pub trees_after: usize,
+    /// The number of nodes before the compression.
+    pub nodes_before: usize,
+    /// The number of nodes after the compression.
+    pub nodes_after: usize,
+    /// The score before the compression.
+    pub score_before: f64,
+    /// The score after the compression.
+    pub score_after: f64,
+}
+
+
+impl CompressionReport {
+    /// Returns `score_after - score_before`.
+    pub fn delta(&self) -> f64 {
+        self.score_after - self.score_before
+    }
+}
+
+
+impl fmt::Display for CompressionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", "COMPRESSION".bold())?;
+        writeln!(
+            f,
+            "  # of trees: {} -> {}",
+            self.trees_before, self.trees_after,
+        )?;
+        writeln!(
+            f,
+            "  # of nodes: {} -> {}",
+            self.nodes_before, self.nodes_after,
+        )?;
+        write!(
+            f,
+            "  {}: {:.6} -> {:.6} ({:+.6})",
+            self.metric, self.score_before, self.score_after, self.delta(),
+        )
+    }
+}
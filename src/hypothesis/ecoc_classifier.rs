@@ -0,0 +1,140 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    MulticlassClassifier,
+    Sample,
+};
+
+use std::fmt;
+
+
+/// Decoding strategies of [`ECOCClassifier`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Decoding {
+    /// The Hamming distance
+    /// `Σ_l (1 - sign(M_{k,l} f_l(x))) / 2`
+    /// between the code word of class `k`
+    /// and the signs of the binary classifiers.
+    /// The zero entries of the code contribute `1/2`.
+    Hamming,
+
+    /// The exponential loss `Σ_l exp(- M_{k,l} f_l(x))`
+    /// of the confidences of the binary classifiers
+    /// with respect to the code word of class `k`.
+    Loss,
+}
+
+
+impl fmt::Display for Decoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hamming => write!(f, "Hamming decoding"),
+            Self::Loss => write!(f, "Loss-based decoding"),
+        }
+    }
+}
+
+
+/// A multiclass classifier decoding an error-correcting output code.
+/// The `l`-th hypothesis separates the classes `k` with `code[k][l] = +1`
+/// from the ones with `code[k][l] = -1`,
+/// and `ECOCClassifier` predicts the class
+/// whose code word is the nearest to the outputs of the hypotheses
+/// (see [`Decoding`]).
+/// [`ECOC`](crate::booster::ECOC) returns this struct.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ECOCClassifier<H> {
+    /// The class labels in ascending order.
+    pub classes: Vec<i64>,
+    /// The coding matrix with entries `-1`, `0`, and `+1`.
+    /// `code[k]` is the code word of `classes[k]`.
+    pub code: Vec<Vec<i8>>,
+    /// The binary hypotheses. `hypotheses[l]` is the one of the `l`-th column.
+    pub hypotheses: Vec<H>,
+    /// The decoding strategy.
+    pub decoding: Decoding,
+}
+
+
+impl<H> ECOCClassifier<H>
+    where H: Classifier,
+{
+    /// Construct a new `ECOCClassifier`.
+    pub fn new(
+        classes: Vec<i64>,
+        code: Vec<Vec<i8>>,
+        hypotheses: Vec<H>,
+        decoding: Decoding,
+    ) -> Self
+    {
+        assert_eq!(
+            classes.len(), code.len(),
+            "The number of code words must be the same as the one of classes"
+        );
+        assert!(
+            code.iter().all(|word| word.len() == hypotheses.len()),
+            "The number of hypotheses must be the same as the one of columns"
+        );
+        Self { classes, code, hypotheses, decoding }
+    }
+
+
+    /// Returns the distance between the code word of each class
+    /// and the outputs of the hypotheses for the `row`-th example.
+    /// The `k`-th element is the one of `self.classes[k]`.
+    pub fn distances(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        let confidences = self.hypotheses.iter()
+            .map(|h| h.confidence(sample, row))
+            .collect::<Vec<_>>();
+        self.code.iter()
+            .map(|word| {
+                word.iter()
+                    .zip(&confidences[..])
+                    .map(|(&m, &f)| {
+                        let z = m as f64 * f;
+                        match self.decoding {
+                            Decoding::Hamming => (1f64 - sign(z)) / 2f64,
+                            Decoding::Loss => (-z).exp(),
+                        }
+                    })
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+}
+
+
+impl<H> MulticlassClassifier for ECOCClassifier<H>
+    where H: Classifier,
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64 {
+        let distances = self.distances(sample, row);
+        let best = distances.iter()
+            .enumerate()
+            .fold(0, |best, (k, &d)| {
+                if d < distances[best] { k } else { best }
+            });
+        self.classes[best]
+    }
+
+
+    fn class_votes(&self, sample: &Sample, row: usize) -> Vec<(i64, f64)> {
+        self.classes.iter()
+            .copied()
+            .zip(self.distances(sample, row))
+            .map(|(class, d)| (class, -d))
+            .collect()
+    }
+}
+
+
+/// Returns the sign of `z`, where the sign of `0` is `0`.
+fn sign(z: f64) -> f64 {
+    if z > 0f64 {
+        1f64
+    } else if z < 0f64 {
+        -1f64
+    } else {
+        0f64
+    }
+}
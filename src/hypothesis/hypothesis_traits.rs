@@ -8,6 +8,8 @@ pub trait Classifier {
     /// This code assumes that
     /// `Classifier::confidence` returns a value in `[-1.0, 1.0]`.
     /// Those hypotheses are called as **confidence-rated hypotheses**.
+    /// The confidence `0` means that the hypothesis abstains,
+    /// i.e., makes no prediction on the row.
     fn confidence(&self, sample: &Sample, row: usize) -> f64;
 
 
@@ -36,6 +38,18 @@ pub trait Classifier {
 }
 
 
+/// A boxed classifier is a classifier.
+/// This lets one combine classifiers of different types
+/// as `Box<dyn Classifier>`.
+impl<H> Classifier for Box<H>
+    where H: Classifier + ?Sized,
+{
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        (**self).confidence(sample, row)
+    }
+}
+
+
 /// A trait that defines the behavor of regressor.
 /// You only need to implement `predict` method.
 pub trait Regressor {
@@ -54,3 +68,41 @@ pub trait Regressor {
 
 
 
+
+
+/// A trait that defines the behavor of multiclass classifier.
+/// The class labels are the integers in `Sample::target`.
+/// You only need to implement `predict_class` method.
+pub trait MulticlassClassifier {
+    /// Predicts the class label of the i'th row of the `df`.
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64;
+
+
+    /// Returns the pairs of a class label and its probability
+    /// for the i'th row of the `df`.
+    /// The classes that are not listed have probability `0`.
+    /// By default, this method returns the predicted class
+    /// with probability `1`.
+    fn class_probabilities(&self, sample: &Sample, row: usize)
+        -> Vec<(i64, f64)>
+    {
+        vec![(self.predict_class(sample, row), 1f64)]
+    }
+
+
+    /// Returns the pairs of a class label and the vote
+    /// this hypothesis casts in a weighted majority vote
+    /// for the i'th row of the `df`.
+    /// By default, this method votes `1` for the predicted class.
+    fn class_votes(&self, sample: &Sample, row: usize) -> Vec<(i64, f64)> {
+        vec![(self.predict_class(sample, row), 1f64)]
+    }
+
+
+    /// Predicts the class labels of `df`.
+    fn predict_class_all(&self, sample: &Sample) -> Vec<i64> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_class(sample, row))
+            .collect::<Vec<_>>()
+    }
+}
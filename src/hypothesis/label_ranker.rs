@@ -0,0 +1,154 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    Sample,
+    MultiLabelSample,
+    WeightedMajority,
+};
+
+
+/// A combined hypothesis for multi-label problems.
+/// `LabelRanker` scores each pair of an example and a label
+/// by a [`WeightedMajority`] over the expanded sample
+/// (see [`MultiLabelSample`])
+/// and ranks the labels by the scores.
+/// The multi-label boosting algorithms return this struct.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LabelRanker<H> {
+    /// The combined hypothesis over the expanded sample.
+    pub combined: WeightedMajority<H>,
+    /// The number of labels.
+    pub n_labels: usize,
+}
+
+
+impl<H> LabelRanker<H>
+    where H: Classifier,
+{
+    /// Construct a new `LabelRanker`.
+    pub fn new(combined: WeightedMajority<H>, n_labels: usize) -> Self {
+        Self { combined, n_labels }
+    }
+
+
+    /// Returns the scores of the labels for the `i`-th example.
+    pub fn scores(&self, sample: &MultiLabelSample, i: usize) -> Vec<f64> {
+        assert_eq!(
+            self.n_labels, sample.n_labels(),
+            "The number of labels is different from the training sample"
+        );
+        let expanded = sample.sample();
+        (0..self.n_labels)
+            .map(|l| self.combined.confidence(expanded, sample.row(i, l)))
+            .collect()
+    }
+
+
+    /// Returns the labels for the `i`-th example
+    /// in descending order of the scores.
+    pub fn rank(&self, sample: &MultiLabelSample, i: usize) -> Vec<usize> {
+        let scores = self.scores(sample, i);
+        let mut labels = (0..self.n_labels).collect::<Vec<_>>();
+        labels.sort_by(|&a, &b| scores[b].partial_cmp(&scores[a]).unwrap());
+        labels
+    }
+
+
+    /// Returns [`LabelRanker::rank`] for all the examples.
+    pub fn rank_all(&self, sample: &MultiLabelSample) -> Vec<Vec<usize>> {
+        (0..sample.n_examples())
+            .map(|i| self.rank(sample, i))
+            .collect()
+    }
+
+
+    /// Returns the labels with positive scores for the `i`-th example.
+    pub fn predict_labels(&self, sample: &MultiLabelSample, i: usize)
+        -> Vec<usize>
+    {
+        self.scores(sample, i)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(l, s)| if s > 0f64 { Some(l) } else { None })
+            .collect()
+    }
+
+
+    /// Returns the label with the highest score for the `i`-th example.
+    /// For a multiclass problem
+    /// (see [`MultiLabelSample::from_multiclass`]),
+    /// this is the index of the predicted class in
+    /// [`MultiLabelSample::labels`].
+    pub fn predict_class(&self, sample: &MultiLabelSample, i: usize)
+        -> usize
+    {
+        self.rank(sample, i)[0]
+    }
+
+
+    /// Returns [`LabelRanker::predict_class`] for all the examples.
+    pub fn predict_classes(&self, sample: &MultiLabelSample) -> Vec<usize> {
+        (0..sample.n_examples())
+            .map(|i| self.predict_class(sample, i))
+            .collect()
+    }
+
+
+    /// Returns the ranking loss over `sample`,
+    /// the average fraction of the pairs of
+    /// an irrelevant label `l0` and a relevant label `l1`
+    /// such that `l0` is not ranked below `l1`.
+    /// The examples whose labels are all relevant or all irrelevant
+    /// are ignored.
+    pub fn ranking_loss(&self, sample: &MultiLabelSample) -> f64 {
+        let mut loss = 0f64;
+        let mut n_examples = 0usize;
+        for i in 0..sample.n_examples() {
+            let relevant = sample.relevant_labels(i);
+            let irrelevant = sample.irrelevant_labels(i);
+            if relevant.is_empty() || irrelevant.is_empty() { continue; }
+
+            let scores = self.scores(sample, i);
+            let n_misordered = irrelevant.iter()
+                .flat_map(|&l0| relevant.iter().map(move |&l1| (l0, l1)))
+                .filter(|&(l0, l1)| scores[l0] >= scores[l1])
+                .count();
+            let n_pairs = relevant.len() * irrelevant.len();
+            loss += n_misordered as f64 / n_pairs as f64;
+            n_examples += 1;
+        }
+
+        if n_examples == 0 { return 0f64; }
+        loss / n_examples as f64
+    }
+
+
+    /// Returns the Hamming loss over `sample`,
+    /// the fraction of the pairs of an example and a label
+    /// whose relevance is mispredicted by
+    /// [`LabelRanker::predict_labels`].
+    pub fn hamming_loss(&self, sample: &MultiLabelSample) -> f64 {
+        let n_pairs = sample.n_examples() * sample.n_labels();
+        let n_errors = (0..sample.n_examples())
+            .flat_map(|i| {
+                let scores = self.scores(sample, i);
+                (0..sample.n_labels())
+                    .map(move |l| (i, l, scores[l]))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|&(i, l, s)| (s > 0f64) != sample.is_relevant(i, l))
+            .count();
+        n_errors as f64 / n_pairs as f64
+    }
+}
+
+
+impl<H> Classifier for LabelRanker<H>
+    where H: Classifier,
+{
+    /// Returns the score of the pair of an example and a label
+    /// at `row` of the expanded sample.
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        self.combined.confidence(sample, row)
+    }
+}
@@ -0,0 +1,133 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    MulticlassClassifier,
+    Label,
+    LabelMap,
+    Sample,
+};
+
+
+/// A trained hypothesis with the mapping of the class labels
+/// of its training sample,
+/// so that it predicts the original labels such as `{0, 1}` or
+/// `{spam, ham}` instead of the target values.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// // The column `class` takes `spam` or `ham`.
+/// let sample = SampleReader::new()
+///     .file("/path/to/data.csv")
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+/// println!("{}", sample.label_map().unwrap());
+///
+/// let tree = DecisionTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .build();
+/// let f = AdaBoost::init(&sample).run(&tree);
+/// let f = LabeledClassifier::new(f, sample.label_map().unwrap().clone());
+/// let labels = f.predict_label_all(&sample);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LabeledClassifier<H> {
+    /// The underlying hypothesis.
+    pub hypothesis: H,
+    /// The mapping of the class labels.
+    pub labels: LabelMap,
+}
+
+
+impl<H> LabeledClassifier<H> {
+    /// Construct a new instance of `LabeledClassifier`.
+    pub fn new(hypothesis: H, labels: LabelMap) -> Self {
+        Self { hypothesis, labels }
+    }
+
+
+    /// Returns the original label of the target value `y`.
+    /// If no label is mapped to `y`, this method returns `y` itself.
+    fn decode(&self, y: i64) -> Label {
+        let y = y as f64;
+        self.labels.label(y)
+            .cloned()
+            .unwrap_or(Label::Number(y))
+    }
+}
+
+
+impl<H> LabeledClassifier<H>
+    where H: Classifier,
+{
+    /// Predicts the original label of the i'th row of `sample`.
+    pub fn predict_label(&self, sample: &Sample, row: usize) -> Label {
+        self.decode(self.hypothesis.predict(sample, row))
+    }
+
+
+    /// Predicts the original labels of `sample`.
+    pub fn predict_label_all(&self, sample: &Sample) -> Vec<Label> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_label(sample, row))
+            .collect::<Vec<_>>()
+    }
+}
+
+
+impl<H> LabeledClassifier<H>
+    where H: MulticlassClassifier,
+{
+    /// Predicts the original class label of the i'th row of `sample`.
+    pub fn predict_class_label(&self, sample: &Sample, row: usize)
+        -> Label
+    {
+        self.decode(self.hypothesis.predict_class(sample, row))
+    }
+
+
+    /// Predicts the original class labels of `sample`.
+    pub fn predict_class_label_all(&self, sample: &Sample) -> Vec<Label> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_class_label(sample, row))
+            .collect::<Vec<_>>()
+    }
+}
+
+
+impl<H> Classifier for LabeledClassifier<H>
+    where H: Classifier,
+{
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        self.hypothesis.confidence(sample, row)
+    }
+
+
+    fn predict(&self, sample: &Sample, row: usize) -> i64 {
+        self.hypothesis.predict(sample, row)
+    }
+}
+
+
+impl<H> MulticlassClassifier for LabeledClassifier<H>
+    where H: MulticlassClassifier,
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64 {
+        self.hypothesis.predict_class(sample, row)
+    }
+
+
+    fn class_probabilities(&self, sample: &Sample, row: usize)
+        -> Vec<(i64, f64)>
+    {
+        self.hypothesis.class_probabilities(sample, row)
+    }
+
+
+    fn class_votes(&self, sample: &Sample, row: usize) -> Vec<(i64, f64)> {
+        self.hypothesis.class_votes(sample, row)
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    MulticlassClassifier,
+    Sample,
+};
+
+
+/// The lower bound of the class probabilities to avoid `ln(0)`.
+const MIN_PROBABILITY: f64 = 1e-12;
+
+
+/// A wrapper of a multiclass classifier that votes
+/// `(K - 1) (ln p_k(x) - (1 / K) Σ_j ln p_j(x))`
+/// for each class `k`,
+/// where `p_k(x)` is [`MulticlassClassifier::class_probabilities`]
+/// of the wrapped hypothesis and `K` is the number of classes.
+/// [`SAMMER`](crate::booster::SAMMER) returns
+/// the weighted majority vote of this struct.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LogProbabilityVote<H> {
+    /// The wrapped hypothesis.
+    pub hypothesis: H,
+    /// The class labels in ascending order.
+    pub classes: Vec<i64>,
+}
+
+
+impl<H> LogProbabilityVote<H>
+    where H: MulticlassClassifier,
+{
+    /// Construct a new `LogProbabilityVote`.
+    pub fn new(hypothesis: H, classes: Vec<i64>) -> Self {
+        Self { hypothesis, classes }
+    }
+
+
+    /// Returns `ln p_k(x)` for each class `k` in `self.classes`.
+    pub(crate) fn log_probabilities(&self, sample: &Sample, row: usize)
+        -> Vec<f64>
+    {
+        let probabilities = self.hypothesis.class_probabilities(sample, row);
+        self.classes.iter()
+            .map(|class| {
+                probabilities.iter()
+                    .find(|(label, _)| label == class)
+                    .map(|&(_, p)| p)
+                    .unwrap_or(0f64)
+                    .max(MIN_PROBABILITY)
+                    .ln()
+            })
+            .collect()
+    }
+}
+
+
+impl<H> MulticlassClassifier for LogProbabilityVote<H>
+    where H: MulticlassClassifier,
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64 {
+        self.hypothesis.predict_class(sample, row)
+    }
+
+
+    fn class_probabilities(&self, sample: &Sample, row: usize)
+        -> Vec<(i64, f64)>
+    {
+        self.hypothesis.class_probabilities(sample, row)
+    }
+
+
+    fn class_votes(&self, sample: &Sample, row: usize) -> Vec<(i64, f64)> {
+        let n_classes = self.classes.len() as f64;
+        let log_p = self.log_probabilities(sample, row);
+        let mean = log_p.iter().sum::<f64>() / n_classes;
+        self.classes.iter()
+            .copied()
+            .zip(log_p)
+            .map(|(class, lp)| (class, (n_classes - 1f64) * (lp - mean)))
+            .collect()
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    Regressor,
+    Sample,
+    common::loss_functions::sigmoid,
+};
+
+
+/// An additive logistic model for binary classification.
+/// `LogitClassifier` scores each example by the (unnormalized) sum
+/// `F(x) = Σ_t α_t f_t (x)`
+/// of real-valued hypotheses `f_t`,
+/// and regards `F(x)` as the logit of `P(y = +1 | x)`, i.e.,
+/// `p(x) = 1 / (1 + exp(-F(x)))`.
+/// [`LogitBoost`](crate::booster::LogitBoost) returns this struct.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LogitClassifier<H> {
+    /// Weights on each hypothesis in `self.hypotheses`.
+    pub weights: Vec<f64>,
+    /// Set of hypotheses.
+    pub hypotheses: Vec<H>,
+}
+
+
+impl<H> LogitClassifier<H>
+    where H: Regressor,
+{
+    /// Construct a new `LogitClassifier` from given slices.
+    /// Unlike [`WeightedMajority`](crate::WeightedMajority),
+    /// the weights are not normalized.
+    pub fn from_slices(weights: &[f64], hypotheses: &[H]) -> Self
+        where H: Clone
+    {
+        Self {
+            weights: weights.to_vec(),
+            hypotheses: hypotheses.to_vec(),
+        }
+    }
+
+
+    /// Returns the score `F(x)` of the example at `row`.
+    pub fn score(&self, sample: &Sample, row: usize) -> f64 {
+        self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .map(|(w, h)| *w * h.predict(sample, row))
+            .sum::<f64>()
+    }
+
+
+    /// Returns the probability that
+    /// the example at `row` is positive.
+    pub fn probability(&self, sample: &Sample, row: usize) -> f64 {
+        sigmoid(self.score(sample, row))
+    }
+
+
+    /// Returns the probabilities that
+    /// the examples in `sample` are positive.
+    pub fn probability_all(&self, sample: &Sample) -> Vec<f64> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.probability(sample, row))
+            .collect()
+    }
+}
+
+
+impl<H> Classifier for LogitClassifier<H>
+    where H: Regressor,
+{
+    /// Returns `2 p(x) - 1 = tanh(F(x) / 2)`
+    /// for the example `x` at `row`.
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        (self.score(sample, row) / 2f64).tanh()
+    }
+}
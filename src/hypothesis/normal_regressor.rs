@@ -0,0 +1,80 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Regressor,
+    Sample,
+};
+
+
+/// A probabilistic regressor that predicts
+/// a normal distribution `N(μ(x), σ(x)²)` for each example.
+/// `NormalRegressor` holds two additive models
+/// `μ(x) = μ_0 + Σ_t α_t f_t (x)` and
+/// `ln σ(x) = s_0 + Σ_t β_t g_t (x)`
+/// of real-valued hypotheses `f_t` and `g_t`.
+/// [`NGBoost`](crate::booster::NGBoost) returns this struct.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NormalRegressor<H> {
+    /// The initial mean `μ_0`.
+    pub base_mean: f64,
+    /// The initial log-scale `s_0`.
+    pub base_log_scale: f64,
+    /// Weights on each hypothesis in `self.mean_hypotheses`.
+    pub mean_weights: Vec<f64>,
+    /// Set of hypotheses for the mean.
+    pub mean_hypotheses: Vec<H>,
+    /// Weights on each hypothesis in `self.scale_hypotheses`.
+    pub scale_weights: Vec<f64>,
+    /// Set of hypotheses for the log-scale.
+    pub scale_hypotheses: Vec<H>,
+}
+
+
+impl<H> NormalRegressor<H>
+    where H: Regressor,
+{
+    /// Returns the mean `μ(x)` of the example at `row`.
+    pub fn mean(&self, sample: &Sample, row: usize) -> f64 {
+        self.base_mean + self.mean_weights.iter()
+            .zip(&self.mean_hypotheses[..])
+            .map(|(w, h)| *w * h.predict(sample, row))
+            .sum::<f64>()
+    }
+
+
+    /// Returns the log-scale `ln σ(x)` of the example at `row`.
+    pub fn log_scale(&self, sample: &Sample, row: usize) -> f64 {
+        self.base_log_scale + self.scale_weights.iter()
+            .zip(&self.scale_hypotheses[..])
+            .map(|(w, h)| *w * h.predict(sample, row))
+            .sum::<f64>()
+    }
+
+
+    /// Returns the mean and the variance `(μ(x), σ(x)²)`
+    /// of the predictive distribution of the example at `row`.
+    pub fn predict_dist(&self, sample: &Sample, row: usize) -> (f64, f64) {
+        let mean = self.mean(sample, row);
+        let variance = (2f64 * self.log_scale(sample, row)).exp();
+        (mean, variance)
+    }
+
+
+    /// Returns the means and the variances
+    /// of the predictive distributions of the examples in `sample`.
+    /// See [`NormalRegressor::predict_dist`].
+    pub fn predict_dist_all(&self, sample: &Sample) -> Vec<(f64, f64)> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_dist(sample, row))
+            .collect()
+    }
+}
+
+
+impl<H> Regressor for NormalRegressor<H>
+    where H: Regressor,
+{
+    /// Returns the mean `μ(x)` of the example `x` at `row`.
+    fn predict(&self, sample: &Sample, row: usize) -> f64 {
+        self.mean(sample, row)
+    }
+}
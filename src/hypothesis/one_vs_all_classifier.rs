@@ -0,0 +1,68 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    MulticlassClassifier,
+    Sample,
+};
+
+
+/// A multiclass classifier that combines
+/// one binary classifier per class.
+/// The `k`-th hypothesis separates the class `classes[k]`
+/// from the others,
+/// and `OneVsAllClassifier` predicts the class
+/// whose hypothesis has the largest confidence.
+/// [`OneVsAll`](crate::booster::OneVsAll) returns this struct.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OneVsAllClassifier<H> {
+    /// The class labels in ascending order.
+    pub classes: Vec<i64>,
+    /// The binary hypotheses. `hypotheses[k]` is the one of `classes[k]`.
+    pub hypotheses: Vec<H>,
+}
+
+
+impl<H> OneVsAllClassifier<H>
+    where H: Classifier,
+{
+    /// Construct a new `OneVsAllClassifier`.
+    pub fn new(classes: Vec<i64>, hypotheses: Vec<H>) -> Self {
+        assert!(!classes.is_empty(), "No class is given");
+        assert_eq!(
+            classes.len(), hypotheses.len(),
+            "The number of hypotheses must be the same as the one of classes"
+        );
+        Self { classes, hypotheses }
+    }
+
+
+    /// Returns the confidences of the hypotheses
+    /// for the `row`-th example.
+    /// The `k`-th element is the one of `self.classes[k]`.
+    pub fn margins(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        self.hypotheses.iter()
+            .map(|h| h.confidence(sample, row))
+            .collect()
+    }
+}
+
+
+impl<H> MulticlassClassifier for OneVsAllClassifier<H>
+    where H: Classifier,
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64 {
+        let margins = self.margins(sample, row);
+        let best = margins.iter()
+            .enumerate()
+            .fold(0, |best, (k, &m)| if m > margins[best] { k } else { best });
+        self.classes[best]
+    }
+
+
+    fn class_votes(&self, sample: &Sample, row: usize) -> Vec<(i64, f64)> {
+        self.classes.iter()
+            .copied()
+            .zip(self.margins(sample, row))
+            .collect()
+    }
+}
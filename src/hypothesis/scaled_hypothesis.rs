@@ -0,0 +1,116 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    Regressor,
+    MulticlassClassifier,
+    Sample,
+};
+use crate::preprocessing::Scaler;
+
+
+/// A hypothesis trained on a scaled sample
+/// with its [`Scaler`],
+/// which scales the features of a sample before predicting.
+/// Since both the scaler and the hypothesis are serializable,
+/// one can save them together and
+/// predict the raw (unscaled) samples after loading.
+///
+/// The `*_all` methods scale the whole sample once,
+/// while the methods for a single row scale the row only.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScaledHypothesis<S, H> {
+    /// The scaler fitted on the training sample.
+    pub scaler: S,
+    /// The hypothesis trained on the scaled training sample.
+    pub hypothesis: H,
+}
+
+
+impl<S, H> ScaledHypothesis<S, H>
+    where S: Scaler,
+{
+    /// Construct a new instance of `ScaledHypothesis`.
+    pub fn new(scaler: S, hypothesis: H) -> Self {
+        Self { scaler, hypothesis }
+    }
+
+
+    /// Returns the scaled sample of the `row`-th example.
+    fn scaled_row(&self, sample: &Sample, row: usize) -> Sample {
+        self.scaler.transform(&sample.row_sample(row))
+    }
+}
+
+
+impl<S, H> Classifier for ScaledHypothesis<S, H>
+    where S: Scaler,
+          H: Classifier,
+{
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        self.hypothesis.confidence(&self.scaled_row(sample, row), 0)
+    }
+
+
+    fn predict(&self, sample: &Sample, row: usize) -> i64 {
+        self.hypothesis.predict(&self.scaled_row(sample, row), 0)
+    }
+
+
+    fn confidence_all(&self, sample: &Sample) -> Vec<f64> {
+        self.hypothesis.confidence_all(&self.scaler.transform(sample))
+    }
+
+
+    fn predict_all(&self, sample: &Sample) -> Vec<i64> {
+        Classifier::predict_all(
+            &self.hypothesis,
+            &self.scaler.transform(sample),
+        )
+    }
+}
+
+
+impl<S, H> Regressor for ScaledHypothesis<S, H>
+    where S: Scaler,
+          H: Regressor,
+{
+    fn predict(&self, sample: &Sample, row: usize) -> f64 {
+        self.hypothesis.predict(&self.scaled_row(sample, row), 0)
+    }
+
+
+    fn predict_all(&self, sample: &Sample) -> Vec<f64> {
+        Regressor::predict_all(
+            &self.hypothesis,
+            &self.scaler.transform(sample),
+        )
+    }
+}
+
+
+impl<S, H> MulticlassClassifier for ScaledHypothesis<S, H>
+    where S: Scaler,
+          H: MulticlassClassifier,
+{
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64 {
+        self.hypothesis.predict_class(&self.scaled_row(sample, row), 0)
+    }
+
+
+    fn class_probabilities(&self, sample: &Sample, row: usize)
+        -> Vec<(i64, f64)>
+    {
+        let sample = self.scaled_row(sample, row);
+        self.hypothesis.class_probabilities(&sample, 0)
+    }
+
+
+    fn class_votes(&self, sample: &Sample, row: usize) -> Vec<(i64, f64)> {
+        self.hypothesis.class_votes(&self.scaled_row(sample, row), 0)
+    }
+
+
+    fn predict_class_all(&self, sample: &Sample) -> Vec<i64> {
+        self.hypothesis.predict_class_all(&self.scaler.transform(sample))
+    }
+}
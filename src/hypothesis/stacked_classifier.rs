@@ -0,0 +1,67 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    Sample,
+    common::loss_functions::sigmoid,
+};
+
+
+/// A two-level ensemble of classifiers.
+/// `StackedClassifier` combines the confidences `h_j (x)`
+/// of the base classifiers by a logistic regression model,
+/// i.e., it scores each example by
+/// `F(x) = b + Σ_j w_j h_j (x)`
+/// and regards `F(x)` as the logit of `P(y = +1 | x)`.
+/// [`Stacking`](crate::research::Stacking) returns this struct.
+///
+/// The base classifiers can be of different types
+/// by boxing them as `Box<dyn Classifier>`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StackedClassifier<H> {
+    /// Weights on each classifier in `self.hypotheses`.
+    pub weights: Vec<f64>,
+    /// The intercept of the logistic regression model.
+    pub bias: f64,
+    /// Set of base classifiers.
+    pub hypotheses: Vec<H>,
+}
+
+
+impl<H> StackedClassifier<H>
+    where H: Classifier,
+{
+    /// Returns the score `F(x)` of the example at `row`.
+    pub fn score(&self, sample: &Sample, row: usize) -> f64 {
+        self.bias + self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .map(|(w, h)| *w * h.confidence(sample, row))
+            .sum::<f64>()
+    }
+
+
+    /// Returns the probability that
+    /// the example at `row` is positive.
+    pub fn probability(&self, sample: &Sample, row: usize) -> f64 {
+        sigmoid(self.score(sample, row))
+    }
+
+
+    /// Returns the probabilities that
+    /// the examples in `sample` are positive.
+    pub fn probability_all(&self, sample: &Sample) -> Vec<f64> {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.probability(sample, row))
+            .collect()
+    }
+}
+
+
+impl<H> Classifier for StackedClassifier<H>
+    where H: Classifier,
+{
+    /// Returns `2 p(x) - 1 = tanh(F(x) / 2)`
+    /// for the example `x` at `row`.
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        (self.score(sample, row) / 2f64).tanh()
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Serialize, Deserialize};
+use crate::{
+    Classifier,
+    Sample,
+    WeightedMajority,
+};
+
+
+/// A weighted majority vote with a shifted decision threshold.
+/// `ThresholdClassifier` predicts `+1` for the example `x` if
+/// `f(x) >= threshold` and `-1` otherwise,
+/// where `f(x) ∈ [-1, 1]` is the confidence of the majority vote.
+/// [`AsymmetricAdaBoost`](crate::booster::AsymmetricAdaBoost)
+/// returns this struct.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ThresholdClassifier<H> {
+    /// The underlying weighted majority vote.
+    pub majority: WeightedMajority<H>,
+    /// The decision threshold.
+    pub threshold: f64,
+}
+
+
+impl<H> Classifier for ThresholdClassifier<H>
+    where H: Classifier,
+{
+    /// Returns `f(x) - threshold` clipped to `[-1, 1]`
+    /// for the example `x` at `row`.
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let confidence = self.majority.confidence(sample, row);
+        (confidence - self.threshold).clamp(-1f64, 1f64)
+    }
+}
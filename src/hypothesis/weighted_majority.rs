@@ -1,11 +1,15 @@
 use serde::{Serialize, Deserialize};
 use crate::{
     common::utils,
+    common::loss_functions::GBMLoss,
     Classifier,
     Regressor,
+    MulticlassClassifier,
     Sample,
 };
 
+use std::collections::BTreeMap;
+
 
 /// A struct that the boosting algorithms in this library return.
 /// You can read/write this struct by `Serde` trait.
@@ -89,3 +93,87 @@ impl<F> Regressor for WeightedMajority<F>
     }
 }
 
+
+impl<F> WeightedMajority<F>
+    where F: Regressor,
+{
+    /// Returns the probability that the example at `row` is positive.
+    /// This method regards `self` as the additive model
+    /// trained on the classification loss `loss`
+    /// (see [`GBMLoss::probability`]).
+    pub fn predict_proba(&self, sample: &Sample, row: usize, loss: GBMLoss)
+        -> f64
+    {
+        loss.probability(self.predict(sample, row))
+    }
+
+
+    /// Returns the probabilities that the examples in `sample` are positive.
+    /// See [`WeightedMajority::predict_proba`].
+    pub fn predict_proba_all(&self, sample: &Sample, loss: GBMLoss)
+        -> Vec<f64>
+    {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_proba(sample, row, loss))
+            .collect()
+    }
+
+
+    /// Returns the mean of the target value of the example at `row`.
+    /// This method regards `self` as the additive model
+    /// trained on the loss `loss` with the log link
+    /// (see [`GBMLoss::mean`]).
+    pub fn predict_mean(&self, sample: &Sample, row: usize, loss: GBMLoss)
+        -> f64
+    {
+        loss.mean(self.predict(sample, row))
+    }
+
+
+    /// Returns the means of the target values of the examples in `sample`.
+    /// See [`WeightedMajority::predict_mean`].
+    pub fn predict_mean_all(&self, sample: &Sample, loss: GBMLoss)
+        -> Vec<f64>
+    {
+        let n_sample = sample.shape().0;
+        (0..n_sample).map(|row| self.predict_mean(sample, row, loss))
+            .collect()
+    }
+}
+
+
+impl<F> MulticlassClassifier for WeightedMajority<F>
+    where F: MulticlassClassifier,
+{
+    /// Returns the class with the largest weighted votes.
+    /// The ties are broken by the smallest label.
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64 {
+        self.class_votes(sample, row)
+            .into_iter()
+            .fold(None, |best: Option<(i64, f64)>, (label, v)| {
+                match best {
+                    Some((_, u)) if u >= v => best,
+                    _ => Some((label, v)),
+                }
+            })
+            .map(|(label, _)| label)
+            .expect("The combined hypothesis has no member")
+    }
+
+
+    /// Returns the weighted sum of the votes of the hypotheses
+    /// in ascending order of the labels.
+    fn class_votes(&self, sample: &Sample, row: usize) -> Vec<(i64, f64)> {
+        let mut votes = BTreeMap::new();
+        self.weights.iter()
+            .zip(&self.hypotheses[..])
+            .for_each(|(w, h)| {
+                h.class_votes(sample, row)
+                    .into_iter()
+                    .for_each(|(label, v)| {
+                        *votes.entry(label).or_insert(0f64) += w * v;
+                    });
+            });
+        votes.into_iter().collect()
+    }
+}
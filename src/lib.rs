@@ -24,7 +24,12 @@
 //!     - [`SmoothBoost`],
 //!     - [`ERLPBoost`](crate::booster::ERLPBoost),
 //!     - [`CERLPBoost`],
-//!     - [`MLPBoost`](crate::booster::MLPBoost).
+//!     - [`MLPBoost`](crate::booster::MLPBoost),
+//!     - [`GameBoost`](crate::booster::GameBoost).
+//! 
+//! 
+//! * Margin distribution optimizing boosting
+//!     - [`ODMBoost`](crate::booster::ODMBoost).
 //! 
 //!
 //! This crate also includes some Weak Learners.
@@ -32,9 +37,21 @@
 //!     - [`DecisionTree`],
 //!     - [`NeuralNetwork`],
 //!     - [`GaussianNB`],
-//!     - [`BadBaseLearner`] (The bad base learner for LPBoost).
+//!     - [`BernoulliNB`],
+//!     - [`BadBaseLearner`] (The bad base learner for LPBoost),
+//!     - [`HypothesisPool`] (A fixed set of hypotheses),
+//!     - [`DecisionStump`] (A threshold function on a single feature),
+//!     - [`ObliqueTree`] (A decision tree on linear combinations of features),
+//!     - [`LinearLearner`] (The regularized logistic regression),
+//!     - [`RffLearner`] (A linear SVM on the random Fourier features),
+//!     - [`MlpLearner`] (A neural network of one hidden layer),
+//!     - [`RuleLearner`] (A conjunctive rule of threshold conditions),
+//!     - [`KnnLearner`] (The k-nearest neighbor with weighted votes),
+//!     - [`RadialLearner`] (A ball centered at a training example).
 //! * Regression
 //!     - [`RegressionTree`]. Note that the current implement is not efficient.
+//!     - [`LinearLearner`] (The ridge regression on the negative gradient),
+//!     - [`MlpLearner`] (A neural network on the negative gradient).
 //! 
 //! # Example
 //! The following code shows a small example for running [`LPBoost`].  
@@ -51,7 +68,17 @@
 //! [`NeuralNetwork`]: crate::weak_learner::NeuralNetwork
 //! [`WeightedMajority<F>`]: crate::hypothesis::WeightedMajority
 //! [`GaussianNB`]: crate::weak_learner::GaussianNB
+//! [`BernoulliNB`]: crate::weak_learner::BernoulliNB
 //! [`BadBaseLearner`]: crate::weak_learner::BadBaseLearner
+//! [`HypothesisPool`]: crate::weak_learner::HypothesisPool
+//! [`DecisionStump`]: crate::weak_learner::DecisionStump
+//! [`ObliqueTree`]: crate::weak_learner::ObliqueTree
+//! [`LinearLearner`]: crate::weak_learner::LinearLearner
+//! [`RffLearner`]: crate::weak_learner::RffLearner
+//! [`MlpLearner`]: crate::weak_learner::MlpLearner
+//! [`RuleLearner`]: crate::weak_learner::RuleLearner
+//! [`KnnLearner`]: crate::weak_learner::KnnLearner
+//! [`RadialLearner`]: crate::weak_learner::RadialLearner
 //! 
 //! ```no_run
 //! use miniboosts::prelude::*;
@@ -113,14 +140,23 @@ mod weak_learner;
 
 pub mod prelude;
 pub mod research;
+pub mod preprocessing;
+pub mod tuning;
+pub mod datasets;
 // pub mod pywriter;
 
 
 // Export the struct that represents batch sample
 pub use sample::{
     SampleReader,
+    SampleChunks,
     Sample,
     Feature,
+    MultiLabelSample,
+    Label,
+    LabelMap,
+    MissingPolicy,
+    DEFAULT_SPARSE_THRESHOLD,
 };
 
 
@@ -128,8 +164,23 @@ pub use sample::{
 pub use hypothesis::{
     Classifier,
     Regressor,
+    MulticlassClassifier,
     WeightedMajority,
     NaiveAggregation,
+    LabelRanker,
+    BagClassifier,
+    Compression,
+    CompressionReport,
+    LogProbabilityVote,
+    LogitClassifier,
+    NormalRegressor,
+    ThresholdClassifier,
+    StackedClassifier,
+    OneVsAllClassifier,
+    ECOCClassifier,
+    Decoding,
+    LabeledClassifier,
+    ScaledHypothesis,
 };
 
 
@@ -137,15 +188,63 @@ pub use hypothesis::{
 
 // Export the `Booster` trait.
 pub use booster::Booster;
+pub use booster::Resumable;
+pub use booster::Certificate;
+pub use booster::{EarlyStopping, ValidationLoss};
 
 // Export the boosting algorithms that minimizes the empirical loss.
 pub use booster::{
     AdaBoost,
     MadaBoost,
+    LogitBoost,
+    BrownBoost,
+    FilterBoost,
+    AnyBoost,
+    StepSize,
+    EpsilonBoost,
+    DeepBoost,
     // AdaBoostL,
 };
 
 
+// Export the bootstrap aggregating.
+pub use booster::Bagging;
+
+
+// Export the boosting algorithms for cost-sensitive learning.
+pub use booster::AsymmetricAdaBoost;
+
+
+// Export the boosting algorithms for imbalanced data.
+pub use booster::{
+    RUSBoost,
+    Resampling,
+};
+
+
+// Export the boosting algorithms for semi-supervised learning.
+pub use booster::ASSEMBLE;
+
+
+// Export the boosting algorithms for multiclass/multi-label problems.
+pub use booster::{
+    AdaBoostMH,
+    AdaBoostMR,
+    SAMME,
+    SAMMER,
+    OneVsAll,
+    ECOC,
+};
+
+
+// Export the boosting algorithms for ranking problems.
+pub use booster::RankBoost;
+
+
+// Export the boosting algorithms for multi-instance problems.
+pub use booster::MILBoost;
+
+
 // Export the boosting algorithms that maximizes the hard margin.
 pub use booster::{
     AdaBoostV,
@@ -163,13 +262,22 @@ pub use booster::{
     MLPBoost,
     ERLPBoost,
     SoftBoost,
+    GameBoost,
 };
 
 
+// Export the boosting algorithm that optimizes the margin distribution.
+pub use booster::ODMBoost;
+
+
 
 // Export the boosting algorithms for regression
 pub use booster::{
     GBM,
+    DART,
+    OrderedBoost,
+    RGF,
+    NGBoost,
 };
 
 
@@ -186,16 +294,43 @@ pub use weak_learner::{
     DecisionTree,
     DecisionTreeBuilder,
     Criterion,
+    ClassWeight,
+    Monotone,
+    Device,
 
     // WLUnion,
 
     GaussianNB,
+    BernoulliNB,
     NeuralNetwork,
     Activation,
     NNLoss,
 
     BadBaseLearner,
     BadBaseLearnerBuilder,
+
+    HypothesisPool,
+
+    DecisionStump,
+
+    ObliqueTree,
+    ObliqueTreeBuilder,
+
+    LinearLearner,
+    LinearLearnerBuilder,
+    Logistic,
+
+    RffLearner,
+    RffLearnerBuilder,
+
+    MlpLearner,
+    MlpLearnerBuilder,
+
+    RuleLearner,
+
+    KnnLearner,
+
+    RadialLearner,
 };
 
 
@@ -210,19 +345,33 @@ pub use weak_learner::{
 
     BadClassifier,
     NBayesClassifier,
+    StumpClassifier,
+    ObliqueTreeClassifier,
+    LinearClassifier,
+    RffClassifier,
+    MlpClassifier,
+    RuleClassifier,
+    Condition,
+    KnnClassifier,
+    RadialClassifier,
 };
 
 pub use weak_learner::{
     RegressionTree,
     RegressionTreeBuilder,
     RegressionTreeRegressor,
+    LinearRegressor,
+    MlpRegressor,
 };
 
 /// Some useful functions / traits
 pub use common::{
     frank_wolfe::{
         FWType,
+        SecondaryType,
     },
+    selection::Selection,
+    weighted_sampler::WeightedSampler,
     loss_functions::{
         GBMLoss,
         LossFunction,
@@ -230,13 +379,37 @@ pub use common::{
 };
 
 
+/// Parameters of the Gurobi environment.
+#[cfg(feature="gurobi")]
+pub use common::gurobi_config::{
+    GurobiConfig,
+    GurobiMethod,
+};
+
+
+pub use preprocessing::{
+    FeatureHasher,
+    Scaler,
+    StandardScaler,
+    MinMaxScaler,
+    QuantileScaler,
+    OneHotEncoder,
+    TargetEncoder,
+};
+
+
 pub use research::{
     Logger,
     LoggerBuilder,
     CrossValidation,
+    CrossValidationReport,
+    cross_validate,
+    Stacking,
+    ConfusionMatrix,
     objective_functions::{
         SoftMarginObjective,
         HardMarginObjective,
+        MarginDistributionObjective,
         ExponentialLoss,
     },
 };
@@ -3,12 +3,56 @@
 pub use crate::booster::{
     // Booster trait
     Booster,
+    Resumable,
+    Certificate,
+    EarlyStopping,
 
 
     // Classification ---------------------------
     // ERM boostings
     AdaBoost,
     MadaBoost,
+    LogitBoost,
+    BrownBoost,
+    FilterBoost,
+    AnyBoost,
+    StepSize,
+    EpsilonBoost,
+    DeepBoost,
+
+
+    // Bagging
+    Bagging,
+
+
+    // Cost-sensitive learning
+    AsymmetricAdaBoost,
+
+
+    // Imbalanced data
+    RUSBoost,
+    Resampling,
+
+
+    // Semi-supervised learning
+    ASSEMBLE,
+
+
+    // Multiclass/Multi-label
+    AdaBoostMH,
+    AdaBoostMR,
+    SAMME,
+    SAMMER,
+    OneVsAll,
+    ECOC,
+
+
+    // Ranking
+    RankBoost,
+
+
+    // Multi-instance learning
+    MILBoost,
 
 
     // Hard margin maximizing boostings
@@ -22,10 +66,18 @@ pub use crate::booster::{
     MLPBoost,
     ERLPBoost,
     SoftBoost,
+    GameBoost,
+
+    // Margin distribution optimization
+    ODMBoost,
 
 
     // Regression
     GBM,
+    DART,
+    OrderedBoost,
+    RGF,
+    NGBoost,
 
 
     // Others
@@ -43,9 +95,13 @@ pub use crate::weak_learner::{
     DecisionTreeBuilder,
     DecisionTreeClassifier,
     Criterion,
+    ClassWeight,
+    Monotone,
+    Device,
 
 
     GaussianNB,
+    BernoulliNB,
     NBayesClassifier,
 
 
@@ -60,27 +116,96 @@ pub use crate::weak_learner::{
     BadBaseLearnerBuilder,
 
 
+    HypothesisPool,
+
+
+    DecisionStump,
+    StumpClassifier,
+
+
+    ObliqueTree,
+    ObliqueTreeBuilder,
+    ObliqueTreeClassifier,
+
+
+    LinearLearner,
+    LinearLearnerBuilder,
+    Logistic,
+    LinearClassifier,
+
+
+    RffLearner,
+    RffLearnerBuilder,
+    RffClassifier,
+
+
+    MlpLearner,
+    MlpLearnerBuilder,
+    MlpClassifier,
+
+
+    RuleLearner,
+    RuleClassifier,
+    Condition,
+
+
+    KnnLearner,
+    KnnClassifier,
+
+
+    RadialLearner,
+    RadialClassifier,
+
+
     // Regression -------------------------------
     RegressionTree,
     RegressionTreeBuilder,
     RegressionTreeRegressor,
+    LinearRegressor,
+    MlpRegressor,
 };
 
 
 pub use crate::hypothesis::{
     Classifier,
     Regressor,
+    MulticlassClassifier,
     WeightedMajority,
+    LabelRanker,
+    BagClassifier,
+    Compression,
+    CompressionReport,
+    LogProbabilityVote,
+    LogitClassifier,
+    NormalRegressor,
+    ThresholdClassifier,
+    StackedClassifier,
+    OneVsAllClassifier,
+    ECOCClassifier,
+    Decoding,
+    LabeledClassifier,
+    ScaledHypothesis,
 };
 
 pub use crate::{
     SampleReader,
     Sample,
+    MultiLabelSample,
+    MissingPolicy,
 };
 
 pub use crate::common::{
     loss_functions::GBMLoss,
     loss_functions::LossFunction,
     frank_wolfe::FWType,
+    frank_wolfe::SecondaryType,
+    selection::Selection,
+    weighted_sampler::WeightedSampler,
+};
+
+#[cfg(feature="gurobi")]
+pub use crate::common::gurobi_config::{
+    GurobiConfig,
+    GurobiMethod,
 };
 
@@ -0,0 +1,28 @@
+//! This directory provides transformers
+//! that convert raw data into numerical features
+//! before constructing a [`Sample`](crate::Sample),
+//! and the scalers and the encoders
+//! that map the features of a [`Sample`](crate::Sample).
+
+// Defines the feature hashing.
+mod feature_hasher;
+// Defines the trait for the scalers.
+mod scaler;
+// Defines the standardization.
+mod standard_scaler;
+// Defines the min-max scaling.
+mod min_max_scaler;
+// Defines the quantile transformation.
+mod quantile_scaler;
+// Defines the one-hot encoding of the categorical features.
+mod one_hot_encoder;
+// Defines the target encoding of the categorical features.
+mod target_encoder;
+
+pub use feature_hasher::FeatureHasher;
+pub use scaler::Scaler;
+pub use standard_scaler::StandardScaler;
+pub use min_max_scaler::MinMaxScaler;
+pub use quantile_scaler::{QuantileScaler, DEFAULT_N_QUANTILES};
+pub use one_hot_encoder::{OneHotEncoder, DEFAULT_MAX_CARDINALITY};
+pub use target_encoder::{TargetEncoder, DEFAULT_SMOOTHING};
@@ -0,0 +1,178 @@
+//! Provides [`FeatureHasher`],
+//! a transformer that maps categorical columns
+//! into a fixed number of numerical columns.
+use polars::prelude::*;
+
+use std::io;
+
+
+/// The number of hashed columns set as default.
+pub const DEFAULT_N_FEATURES: usize = 1 << 10;
+
+
+/// Feature hashing (a.k.a. the hashing trick).
+/// `FeatureHasher` maps the values of categorical (or string) columns
+/// into a fixed number of numerical columns.
+/// Each value `v` of a column `c` is hashed to a column index
+/// and adds `±1` to that column,
+/// so the number of columns does not depend on the cardinality
+/// of the categorical columns.
+/// This is a memory-bounded alternative to one-hot encoding.
+///
+/// If `signed` is `true` (default),
+/// the sign of the added value is also determined by the hash.
+/// This makes the colliding values cancel out in expectation.
+/// See the paper:
+/// [Feature Hashing for Large Scale Multitask Learning](https://dl.acm.org/doi/10.1145/1553374.1553516)
+/// by Kilian Weinberger, Anirban Dasgupta, John Langford,
+/// Alex Smola, and Josh Attenberg.
+///
+/// The hash function is deterministic,
+/// so the same `FeatureHasher` maps training and test data consistently.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use miniboosts::FeatureHasher;
+/// use polars::prelude::*;
+///
+/// let df = CsvReadOptions::default()
+///     .with_has_header(true)
+///     .try_into_reader_with_file_path(Some("/path/to/file.csv".into()))
+///     .unwrap()
+///     .finish()
+///     .unwrap();
+/// let target = df.column("class").unwrap().clone();
+/// let df = df.drop("class").unwrap();
+///
+/// // Replace the columns `city` and `zip` by 64 hashed columns.
+/// let hasher = FeatureHasher::new(64)
+///     .columns(["city", "zip"]);
+/// let df = hasher.transform(&df).unwrap();
+///
+/// let sample = Sample::from_dataframe(df, target).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FeatureHasher {
+    n_features: usize,
+    columns: Vec<String>,
+    signed: bool,
+}
+
+
+impl FeatureHasher {
+    /// Construct a new instance of [`FeatureHasher`]
+    /// that outputs `n_features` columns.
+    /// By default, [`FeatureHasher`] hashes no column
+    /// and uses signed hashing.
+    pub fn new(n_features: usize) -> Self {
+        assert!(n_features > 0, "The number of columns must be positive");
+        Self {
+            n_features,
+            columns: Vec::new(),
+            signed: true,
+        }
+    }
+
+
+    /// Set the columns to be hashed.
+    /// The columns are cast to strings before hashing,
+    /// so numerical columns are treated as categorical ones.
+    pub fn columns<I, T>(mut self, columns: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        self.columns = columns.into_iter()
+            .map(|column| column.as_ref().to_string())
+            .collect();
+        self
+    }
+
+
+    /// Use signed hashing or not.
+    /// Default value is `true`.
+    pub fn signed(mut self, flag: bool) -> Self {
+        self.signed = flag;
+        self
+    }
+
+
+    /// Returns the names of the hashed columns.
+    pub fn feature_names(&self) -> Vec<String> {
+        (1..=self.n_features).map(|k| format!("Hash [{k}]"))
+            .collect()
+    }
+
+
+    /// Replace the columns specified by
+    /// [`FeatureHasher::columns`] in `data`
+    /// by the hashed columns.
+    /// Null values are ignored.
+    pub fn transform(&self, data: &DataFrame) -> io::Result<DataFrame> {
+        let n_sample = data.height();
+        let mut hashed = vec![vec![0f64; n_sample]; self.n_features];
+
+        for name in self.columns.iter() {
+            let values = data.column(name)
+                .and_then(|series| series.cast(&DataType::String))
+                .map_err(io::Error::other)?;
+            let values = values.str()
+                .map_err(io::Error::other)?;
+
+            for (i, value) in values.into_iter().enumerate() {
+                let Some(value) = value else { continue; };
+                let (k, sign) = self.index_and_sign(name, value);
+                hashed[k][i] += sign;
+            }
+        }
+
+
+        let columns = self.feature_names()
+            .into_iter()
+            .zip(hashed)
+            .map(|(name, values)| Series::new(&name, values))
+            .collect::<Vec<_>>();
+
+        data.drop_many(&self.columns)
+            .hstack(&columns)
+            .map_err(io::Error::other)
+    }
+
+
+    /// Returns the column index and the sign for `value` in `column`.
+    fn index_and_sign(&self, column: &str, value: &str) -> (usize, f64) {
+        let hash = hash(column, value);
+        let k = (hash % self.n_features as u64) as usize;
+        let sign = if !self.signed || hash >> 63 == 0 { 1f64 } else { -1f64 };
+        (k, sign)
+    }
+}
+
+
+impl Default for FeatureHasher {
+    fn default() -> Self {
+        Self::new(DEFAULT_N_FEATURES)
+    }
+}
+
+
+/// 64-bit FNV-1a hash of `column` and `value`,
+/// followed by the finalizer of SplitMix64
+/// to spread the bits.
+fn hash(column: &str, value: &str) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let bytes = column.bytes()
+        .chain(std::iter::once(0u8))
+        .chain(value.bytes());
+    let mut h = OFFSET;
+    for byte in bytes {
+        h ^= byte as u64;
+        h = h.wrapping_mul(PRIME);
+    }
+
+    h = (h ^ (h >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    h ^ (h >> 31)
+}
@@ -0,0 +1,52 @@
+//! Provides [`MinMaxScaler`].
+use serde::{Serialize, Deserialize};
+
+use crate::Sample;
+use super::scaler::{Scaler, fit_features, transform_features};
+
+
+/// A scaler that maps each feature to `[0, 1]`,
+/// i.e., `x ↦ (x - min) / (max - min)`,
+/// where `min` and `max` are the ones of the training sample.
+/// The values out of `[min, max]` at predict time
+/// are mapped out of `[0, 1]`.
+/// A constant feature is mapped to `0`.
+/// The categorical features are not scaled.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MinMaxScaler {
+    names: Vec<String>,
+    mins: Vec<f64>,
+    ranges: Vec<f64>,
+}
+
+
+impl MinMaxScaler {
+    /// Computes the minimum and the maximum values
+    /// of the features of `sample`.
+    pub fn fit(sample: &Sample) -> Self {
+        let stats = fit_features(sample, |values| {
+            let min = values.iter().copied().fold(f64::MAX, f64::min);
+            let max = values.iter().copied().fold(f64::MIN, f64::max);
+            let range = if max > min { max - min } else { 1f64 };
+            Some((min, range))
+        });
+        let mut names = Vec::with_capacity(stats.len());
+        let mut mins = Vec::with_capacity(stats.len());
+        let mut ranges = Vec::with_capacity(stats.len());
+        for (name, (min, range)) in stats {
+            names.push(name);
+            mins.push(min);
+            ranges.push(range);
+        }
+        Self { names, mins, ranges }
+    }
+}
+
+
+impl Scaler for MinMaxScaler {
+    fn transform(&self, sample: &Sample) -> Sample {
+        transform_features(sample, &self.names, |k, x| {
+            (x - self.mins[k]) / self.ranges[k]
+        })
+    }
+}
@@ -0,0 +1,94 @@
+//! Provides [`OneHotEncoder`].
+use rayon::prelude::*;
+use serde::{Serialize, Deserialize};
+
+use crate::Sample;
+use super::scaler::Scaler;
+
+
+/// The maximum number of the categories
+/// of an encoded feature set as default.
+pub const DEFAULT_MAX_CARDINALITY: usize = 32;
+
+
+/// An encoder that replaces each categorical feature `c`
+/// by the binary features `c=k`, one for each category `k`
+/// of the training sample.
+/// The feature `c=k` is `1` if the value of `c` is `k`
+/// and `0` otherwise.
+/// An unseen category at predict time is mapped to all zeros,
+/// and a missing value is mapped to all `f64::NAN`.
+///
+/// A categorical feature with too many categories is not encoded
+/// and remains categorical.
+/// [`TargetEncoder`](crate::TargetEncoder) is suitable
+/// for such a feature.
+/// The non-categorical features are not changed.
+/// See [`Sample::set_categorical`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OneHotEncoder {
+    names: Vec<String>,
+    categories: Vec<Vec<i64>>,
+}
+
+
+impl OneHotEncoder {
+    /// Collects the categories of the categorical features of `sample`
+    /// with at most [`DEFAULT_MAX_CARDINALITY`] categories.
+    pub fn fit(sample: &Sample) -> Self {
+        Self::fit_with(sample, DEFAULT_MAX_CARDINALITY)
+    }
+
+
+    /// Collects the categories of the categorical features of `sample`
+    /// with at most `max_cardinality` categories.
+    pub fn fit_with(sample: &Sample, max_cardinality: usize) -> Self {
+        assert!(
+            max_cardinality >= 1,
+            "The maximum number of categories must be at least 1"
+        );
+        let (names, categories) = sample.features()
+            .par_iter()
+            .filter(|feature| sample.is_categorical(feature.name()))
+            .filter_map(|feature| {
+                let categories = sample.categories(feature.name());
+                if categories.len() > max_cardinality { return None; }
+                Some((feature.name().to_string(), categories))
+            })
+            .unzip();
+        Self { names, categories }
+    }
+
+
+    /// Returns the categories of the feature named `name`
+    /// in the ascending order.
+    /// Returns `None` if the feature is not encoded.
+    pub fn categories<S: AsRef<str>>(&self, name: S) -> Option<&[i64]> {
+        let k = self.names.iter().position(|n| n == name.as_ref())?;
+        Some(&self.categories[k][..])
+    }
+}
+
+
+impl Scaler for OneHotEncoder {
+    fn transform(&self, sample: &Sample) -> Sample {
+        let n_sample = sample.shape().0;
+        sample.flat_map_features(|feature| {
+            let name = feature.name();
+            let k = self.names.iter().position(|n| n == name)?;
+            let columns = self.categories[k].iter()
+                .map(|&category| {
+                    let values = (0..n_sample)
+                        .map(|i| {
+                            let x = feature[i];
+                            if x.is_nan() { return x; }
+                            if x == category as f64 { 1f64 } else { 0f64 }
+                        })
+                        .collect();
+                    (format!("{name}={category}"), values)
+                })
+                .collect();
+            Some(columns)
+        })
+    }
+}
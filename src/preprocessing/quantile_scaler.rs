@@ -0,0 +1,100 @@
+//! Provides [`QuantileScaler`].
+use serde::{Serialize, Deserialize};
+
+use crate::Sample;
+use super::scaler::{Scaler, fit_features, transform_features};
+
+
+/// The number of the quantiles set as default.
+pub const DEFAULT_N_QUANTILES: usize = 1000;
+
+
+/// A scaler that maps each feature to `[0, 1]`
+/// by its empirical cumulative distribution function,
+/// i.e., the value `x` is mapped to the fraction of
+/// the training values below `x`.
+/// The function is approximated by the linear interpolation
+/// of `n_quantiles` quantiles.
+/// Unlike [`StandardScaler`](crate::StandardScaler) and
+/// [`MinMaxScaler`](crate::MinMaxScaler),
+/// this scaler is robust to outliers.
+/// The categorical features are not scaled.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct QuantileScaler {
+    names: Vec<String>,
+    quantiles: Vec<Vec<f64>>,
+}
+
+
+impl QuantileScaler {
+    /// Computes the quantiles of the features of `sample`
+    /// with [`DEFAULT_N_QUANTILES`] quantiles.
+    pub fn fit(sample: &Sample) -> Self {
+        Self::fit_with(sample, DEFAULT_N_QUANTILES)
+    }
+
+
+    /// Computes `n_quantiles` quantiles of the features of `sample`.
+    /// The number of the quantiles of a feature is at most
+    /// the number of its non-missing values.
+    pub fn fit_with(sample: &Sample, n_quantiles: usize) -> Self {
+        assert!(
+            n_quantiles >= 2,
+            "The number of quantiles must be at least 2"
+        );
+        let stats = fit_features(sample, |mut values| {
+            values.sort_by(f64::total_cmp);
+            Some(quantiles(&values, n_quantiles.min(values.len())))
+        });
+        let (names, quantiles) = stats.into_iter().unzip();
+        Self { names, quantiles }
+    }
+}
+
+
+impl Scaler for QuantileScaler {
+    fn transform(&self, sample: &Sample) -> Sample {
+        transform_features(sample, &self.names, |k, x| {
+            cdf(&self.quantiles[k], x)
+        })
+    }
+}
+
+
+/// Returns `n` quantiles of the sorted `values`
+/// at the probabilities `0, 1/(n-1), ..., 1`.
+fn quantiles(values: &[f64], n: usize) -> Vec<f64> {
+    if n < 2 { return values.to_vec(); }
+    let last = (values.len() - 1) as f64;
+    (0..n).map(|k| {
+            let position = last * k as f64 / (n - 1) as f64;
+            let lo = position.floor() as usize;
+            let hi = position.ceil() as usize;
+            let frac = position - lo as f64;
+            values[lo] + frac * (values[hi] - values[lo])
+        })
+        .collect()
+}
+
+
+/// Returns the interpolated probability of `x`
+/// given the sorted `quantiles`.
+/// The value equal to some quantiles is mapped to
+/// the middle of their probabilities.
+fn cdf(quantiles: &[f64], x: f64) -> f64 {
+    let n = quantiles.len();
+    if n < 2 { return 0.5; }
+    let lower = quantiles.partition_point(|&q| q < x);
+    let upper = quantiles.partition_point(|&q| q <= x);
+    let position = if lower < upper {
+        (lower + upper - 1) as f64 / 2f64
+    } else if upper == 0 {
+        0f64
+    } else if upper == n {
+        (n - 1) as f64
+    } else {
+        let (lo, hi) = (quantiles[upper - 1], quantiles[upper]);
+        (upper - 1) as f64 + (x - lo) / (hi - lo)
+    };
+    position / (n - 1) as f64
+}
@@ -0,0 +1,80 @@
+//! Provides the [`Scaler`] trait
+//! and the common routines of the scalers.
+use rayon::prelude::*;
+
+use crate::{Sample, Feature};
+
+
+/// A trait that defines the behavior of a feature scaler.
+/// A scaler is fitted on a training sample
+/// by its `fit` method and maps the features of any sample
+/// with the same feature names.
+/// The features unknown to the scaler are not changed.
+/// The encoders of the categorical features,
+/// [`OneHotEncoder`](crate::OneHotEncoder) and
+/// [`TargetEncoder`](crate::TargetEncoder),
+/// are also scalers.
+///
+/// Wrap a trained hypothesis with
+/// [`ScaledHypothesis`](crate::ScaledHypothesis)
+/// to apply the scaler at predict time.
+pub trait Scaler {
+    /// Returns the sample whose features are scaled.
+    /// The missing values, `f64::NAN`, are kept.
+    fn transform(&self, sample: &Sample) -> Sample;
+}
+
+
+/// Returns the non-missing values of `feature` of `sample`.
+/// Returns `None` if the feature is categorical or
+/// all the values are missing.
+pub(super) fn fitted_values(sample: &Sample, feature: &Feature)
+    -> Option<Vec<f64>>
+{
+    if sample.is_categorical(feature.name()) { return None; }
+    let n_sample = sample.shape().0;
+    let values = (0..n_sample)
+        .map(|i| feature[i])
+        .filter(|x| !x.is_nan())
+        .collect::<Vec<_>>();
+    if values.is_empty() { None } else { Some(values) }
+}
+
+
+/// Fits the parameters of each feature of `sample` by `fit`,
+/// which returns `None` for a feature that is not scaled.
+pub(super) fn fit_features<T, F>(sample: &Sample, fit: F) -> Vec<(String, T)>
+    where T: Send,
+          F: Fn(Vec<f64>) -> Option<T> + Sync,
+{
+    sample.features()
+        .par_iter()
+        .filter_map(|feature| {
+            let values = fitted_values(sample, feature)?;
+            Some((feature.name().to_string(), fit(values)?))
+        })
+        .collect()
+}
+
+
+/// Returns `sample` whose feature named `names[k]` is mapped
+/// by `scale(k, x)` for each value `x` but the missing values.
+pub(super) fn transform_features<F>(
+    sample: &Sample,
+    names: &[String],
+    scale: F,
+) -> Sample
+    where F: Fn(usize, f64) -> f64 + Sync,
+{
+    let n_sample = sample.shape().0;
+    sample.map_features(|feature| {
+        let k = names.iter().position(|name| name == feature.name())?;
+        let values = (0..n_sample)
+            .map(|i| {
+                let x = feature[i];
+                if x.is_nan() { x } else { scale(k, x) }
+            })
+            .collect();
+        Some(values)
+    })
+}
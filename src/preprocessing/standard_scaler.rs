@@ -0,0 +1,87 @@
+//! Provides [`StandardScaler`].
+use serde::{Serialize, Deserialize};
+
+use crate::Sample;
+use super::scaler::{Scaler, fit_features, transform_features};
+
+
+/// A scaler that maps each feature to zero mean and unit variance,
+/// i.e., `x ↦ (x - mean) / std`.
+/// The mean and the standard deviation ignore the missing values.
+/// A feature of zero variance is only centered.
+/// The categorical features are not scaled.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use miniboosts::{Scaler, StandardScaler, ScaledHypothesis};
+///
+/// # let train = Sample::dummy(10);
+/// # let test = Sample::dummy(10);
+/// let scaler = StandardScaler::fit(&train);
+/// let scaled = scaler.transform(&train);
+/// let learner = LinearLearnerBuilder::new(&scaled).build();
+/// let f = AdaBoost::init(&scaled).run(&learner);
+///
+/// // `f` scales the test sample before predicting.
+/// let f = ScaledHypothesis::new(scaler, f);
+/// let predictions = f.predict_all(&test);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StandardScaler {
+    names: Vec<String>,
+    means: Vec<f64>,
+    stds: Vec<f64>,
+}
+
+
+impl StandardScaler {
+    /// Computes the means and the standard deviations
+    /// of the features of `sample`.
+    pub fn fit(sample: &Sample) -> Self {
+        let stats = fit_features(sample, |values| {
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            let var = values.iter()
+                .map(|x| (x - mean).powi(2))
+                .sum::<f64>()
+                / n;
+            let std = if var > 0f64 { var.sqrt() } else { 1f64 };
+            Some((mean, std))
+        });
+        let mut names = Vec::with_capacity(stats.len());
+        let mut means = Vec::with_capacity(stats.len());
+        let mut stds = Vec::with_capacity(stats.len());
+        for (name, (mean, std)) in stats {
+            names.push(name);
+            means.push(mean);
+            stds.push(std);
+        }
+        Self { names, means, stds }
+    }
+
+
+    /// Returns the mean of the feature `name`,
+    /// or `None` if the feature is not scaled.
+    pub fn mean(&self, name: &str) -> Option<f64> {
+        let k = self.names.iter().position(|n| n == name)?;
+        Some(self.means[k])
+    }
+
+
+    /// Returns the standard deviation of the feature `name`,
+    /// or `None` if the feature is not scaled.
+    pub fn std(&self, name: &str) -> Option<f64> {
+        let k = self.names.iter().position(|n| n == name)?;
+        Some(self.stds[k])
+    }
+}
+
+
+impl Scaler for StandardScaler {
+    fn transform(&self, sample: &Sample) -> Sample {
+        transform_features(sample, &self.names, |k, x| {
+            (x - self.means[k]) / self.stds[k]
+        })
+    }
+}
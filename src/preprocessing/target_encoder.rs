@@ -0,0 +1,204 @@
+//! Provides [`TargetEncoder`].
+use rand::prelude::*;
+use rayon::prelude::*;
+use serde::{Serialize, Deserialize};
+
+use crate::Sample;
+use super::scaler::Scaler;
+
+
+/// The smoothing parameter set as default.
+pub const DEFAULT_SMOOTHING: f64 = 1.0;
+
+
+/// An encoder that replaces each category of a categorical feature
+/// by the smoothed mean of the target values of the category,
+/// i.e., `k ↦ (s_k + m * p) / (n_k + m)`,
+/// where `s_k` is the sum of the target values of the category `k`,
+/// `n_k` is the number of its examples,
+/// `p` is the mean of all the target values,
+/// and `m` is the smoothing parameter.
+/// An unseen category at predict time is mapped to `p`,
+/// and a missing value is kept.
+/// The unlabeled examples are not used to fit the encoder.
+///
+/// The encoded feature of a training example depends on
+/// its own target value.
+/// To avoid this leakage,
+/// encode the training sample by [`TargetEncoder::out_of_fold`]
+/// and the other samples by [`Scaler::transform`].
+///
+/// The mean of the target values makes sense
+/// for the binary labels and the real-valued targets.
+/// The encoded features are no longer categorical,
+/// and the non-categorical features are not changed.
+/// See [`Sample::set_categorical`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TargetEncoder {
+    smoothing: f64,
+    prior: f64,
+    names: Vec<String>,
+    encodings: Vec<Vec<(i64, f64)>>,
+}
+
+
+impl TargetEncoder {
+    /// Computes the encodings of the categorical features of `sample`
+    /// with the smoothing parameter [`DEFAULT_SMOOTHING`].
+    pub fn fit(sample: &Sample) -> Self {
+        Self::fit_with(sample, DEFAULT_SMOOTHING)
+    }
+
+
+    /// Computes the encodings of the categorical features of `sample`
+    /// with the smoothing parameter `smoothing`.
+    pub fn fit_with(sample: &Sample, smoothing: f64) -> Self {
+        assert!(
+            smoothing >= 0f64,
+            "The smoothing parameter must be non-negative"
+        );
+        let n_sample = sample.shape().0;
+        let rows = (0..n_sample).collect::<Vec<_>>();
+        Self::fit_rows(sample, &rows[..], smoothing)
+    }
+
+
+    /// Computes the encodings from the labeled examples in `rows`.
+    fn fit_rows(sample: &Sample, rows: &[usize], smoothing: f64) -> Self {
+        let target = sample.target();
+        let rows = rows.iter()
+            .copied()
+            .filter(|&i| !sample.is_unlabeled(i))
+            .collect::<Vec<_>>();
+        assert!(
+            !rows.is_empty(),
+            "The target encoder needs at least one labeled example"
+        );
+        let prior = rows.iter().map(|&i| target[i]).sum::<f64>()
+            / rows.len() as f64;
+
+        let (names, encodings) = sample.features()
+            .par_iter()
+            .filter(|feature| sample.is_categorical(feature.name()))
+            .map(|feature| {
+                // Sums and counts of the target values of each category.
+                let mut stats = rows.iter()
+                    .filter(|&&i| !feature[i].is_nan())
+                    .map(|&i| (feature[i] as i64, target[i]))
+                    .collect::<Vec<_>>();
+                stats.sort_by_key(|&(category, _)| category);
+                let mut encoding: Vec<(i64, f64, f64)> = Vec::new();
+                for (category, y) in stats {
+                    match encoding.last_mut() {
+                        Some((c, sum, n)) if *c == category => {
+                            *sum += y;
+                            *n += 1f64;
+                        },
+                        _ => { encoding.push((category, y, 1f64)); },
+                    }
+                }
+                let encoding = encoding.into_iter()
+                    .map(|(category, sum, n)| {
+                        let value = (sum + smoothing * prior)
+                            / (n + smoothing);
+                        (category, value)
+                    })
+                    .collect::<Vec<_>>();
+                (feature.name().to_string(), encoding)
+            })
+            .unzip();
+        Self { smoothing, prior, names, encodings }
+    }
+
+
+    /// Returns the mean of the target values of the training sample,
+    /// the encoding of an unseen category.
+    pub fn prior(&self) -> f64 {
+        self.prior
+    }
+
+
+    /// Returns the encoding of `category` of the feature named `name`.
+    /// Returns `None` if the feature is not encoded.
+    pub fn encoding<S: AsRef<str>>(&self, name: S, category: i64)
+        -> Option<f64>
+    {
+        let k = self.names.iter().position(|n| n == name.as_ref())?;
+        Some(self.encode(k, category as f64))
+    }
+
+
+    /// Returns the encoding of the value `x`
+    /// of the `k`-th encoded feature.
+    fn encode(&self, k: usize, x: f64) -> f64 {
+        if x.is_nan() { return x; }
+        let encoding = &self.encodings[k];
+        match encoding.binary_search_by_key(&(x as i64), |&(c, _)| c) {
+            Ok(pos) => encoding[pos].1,
+            Err(_) => self.prior,
+        }
+    }
+
+
+    /// Encodes the training sample `sample` out-of-fold.
+    /// The examples are shuffled with the seed `1234`
+    /// and split into `n_folds` folds.
+    /// Each example is encoded by the encoder
+    /// fitted on the other folds with the same smoothing parameter,
+    /// so that its encoded features do not depend on its target value.
+    /// The features that `self` does not encode are not changed.
+    pub fn out_of_fold(&self, sample: &Sample, n_folds: usize) -> Sample {
+        let n_sample = sample.shape().0;
+        assert!(
+            (2..=n_sample).contains(&n_folds),
+            "The number of folds must be in `[2, # of examples]`"
+        );
+
+        let mut ix = (0..n_sample).collect::<Vec<_>>();
+        let mut rng = StdRng::seed_from_u64(1234);
+        ix.shuffle(&mut rng);
+
+        let mut fold_of = vec![0usize; n_sample];
+        let encoders = (0..n_folds).map(|k| {
+                let start = k * n_sample / n_folds;
+                let end = (k + 1) * n_sample / n_folds;
+                ix[start..end].iter().for_each(|&i| { fold_of[i] = k; });
+                let rows = ix[..start].iter()
+                    .chain(&ix[end..])
+                    .copied()
+                    .collect::<Vec<_>>();
+                Self::fit_rows(sample, &rows[..], self.smoothing)
+            })
+            .collect::<Vec<_>>();
+
+        sample.flat_map_features(|feature| {
+            let name = feature.name();
+            self.names.iter().position(|n| n == name)?;
+            let encoded = encoders.iter()
+                .map(|encoder| encoder.names.iter().position(|n| n == name))
+                .collect::<Option<Vec<_>>>()?;
+            let values = (0..n_sample)
+                .map(|i| {
+                    let k = fold_of[i];
+                    encoders[k].encode(encoded[k], feature[i])
+                })
+                .collect();
+            Some(vec![(name.to_string(), values)])
+        })
+    }
+}
+
+
+impl Scaler for TargetEncoder {
+    fn transform(&self, sample: &Sample) -> Sample {
+        let n_sample = sample.shape().0;
+        sample.flat_map_features(|feature| {
+            let name = feature.name();
+            let k = self.names.iter().position(|n| n == name)?;
+            let values = (0..n_sample)
+                .map(|i| self.encode(k, feature[i]))
+                .collect();
+            Some(vec![(name.to_string(), values)])
+        })
+    }
+}
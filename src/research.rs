@@ -13,12 +13,25 @@ mod logger_builder;
 
 mod cross_validation;
 
+// Defines the two-level ensemble.
+mod stacking;
+
 pub use logger::{
     Logger,
     Research,
 };
 
-pub use cross_validation::CrossValidation;
+pub use cross_validation::{
+    CrossValidation,
+    CrossValidationReport,
+    Metric,
+    cross_validate,
+};
+pub use stacking::Stacking;
+
+/// Defines evaluation metrics for classifiers.
+pub mod metrics;
+pub use metrics::ConfusionMatrix;
 
 pub use logger_builder::LoggerBuilder;
 
@@ -1,7 +1,9 @@
 use rand::prelude::*;
+use rayon::prelude::*;
 use colored::Colorize;
 use crate::Sample;
 
+use std::fmt;
 use std::iter::Iterator;
 
 const WIDTH: usize = 9;
@@ -177,3 +179,157 @@ impl<'a> Iterator for CrossValidation<'a> {
 
 
 
+
+
+/// A metric on a test fold for [`cross_validate`],
+/// such as the zero-one loss of a hypothesis.
+pub type Metric<'a, H> = &'a (dyn Fn(&Sample, &H) -> f64 + Sync);
+
+
+/// The values of the metrics on each test fold,
+/// returned by [`cross_validate`].
+/// `Display` prints the mean and the standard deviation
+/// of each metric.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossValidationReport {
+    /// `folds[k][j]` is the value of the `j`-th metric
+    /// on the `k`-th test fold.
+    pub folds: Vec<Vec<f64>>,
+}
+
+
+impl CrossValidationReport {
+    /// Returns the mean of each metric over the folds.
+    pub fn mean(&self) -> Vec<f64> {
+        let n_folds = self.folds.len() as f64;
+        self.columns()
+            .map(|values| values.iter().sum::<f64>() / n_folds)
+            .collect()
+    }
+
+
+    /// Returns the standard deviation of each metric over the folds,
+    /// where the sum of the squared deviations is divided by `k`.
+    pub fn std(&self) -> Vec<f64> {
+        let n_folds = self.folds.len() as f64;
+        self.columns()
+            .zip(self.mean())
+            .map(|(values, mean)| {
+                let var = values.iter()
+                    .map(|v| (v - mean).powi(2))
+                    .sum::<f64>()
+                    / n_folds;
+                var.sqrt()
+            })
+            .collect()
+    }
+
+
+    /// Returns the values of each metric over the folds.
+    fn columns(&self) -> impl Iterator<Item = Vec<f64>> + '_ {
+        let n_metrics = self.folds.first().map_or(0, |fold| fold.len());
+        (0..n_metrics).map(|j| {
+            self.folds.iter()
+                .map(|fold| fold[j])
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+
+impl fmt::Display for CrossValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}",
+            format!("  [{: >3} folds]", self.folds.len()).bold().red()
+        )?;
+        for (j, (mean, std)) in self.mean().into_iter()
+            .zip(self.std())
+            .enumerate()
+        {
+            writeln!(f, "    [metric {j}] {mean:.6} ± {std:.6}")?;
+        }
+        Ok(())
+    }
+}
+
+
+/// Runs `k`-fold cross validation.
+/// The examples of `sample` are shuffled with the seed `1234`
+/// and split into `n_folds` folds.
+/// For each fold, `learner` trains a hypothesis
+/// (e.g., runs a booster with a weak learner)
+/// on the other folds,
+/// and each of `metrics` evaluates the hypothesis on the fold.
+/// The folds are processed in parallel.
+///
+/// The `learner` builds both the booster and the weak learner
+/// from the training folds,
+/// since they borrow the sample they are built on.
+/// Use [`CrossValidation`] to iterate over the folds by hand.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use miniboosts::research::cross_validate;
+///
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let zero_one_loss = |test: &Sample, f: &WeightedMajority<_>| {
+///     let n_sample = test.shape().0 as f64;
+///     f.predict_all(test)
+///         .into_iter()
+///         .zip(test.target())
+///         .filter(|&(p, &y)| p != y as i64)
+///         .count() as f64
+///         / n_sample
+/// };
+/// let report = cross_validate(
+///     |train: &Sample| {
+///         let tree = DecisionTreeBuilder::new(train).max_depth(2).build();
+///         AdaBoost::init(train).force_quit_at(100).run(&tree)
+///     },
+///     &sample,
+///     5,
+///     &[&zero_one_loss],
+/// );
+/// println!("{report}");
+/// println!("Test loss: {:?} ± {:?}", report.mean(), report.std());
+/// ```
+pub fn cross_validate<L, H>(
+    learner: L,
+    sample: &Sample,
+    n_folds: usize,
+    metrics: &[Metric<'_, H>],
+) -> CrossValidationReport
+    where L: Fn(&Sample) -> H + Sync,
+{
+    let n_sample = sample.shape().0;
+    assert!(
+        (2..=n_sample).contains(&n_folds),
+        "The number of folds must be in `[2, # of examples]`"
+    );
+
+    let mut ix = (0..n_sample).collect::<Vec<_>>();
+    let mut rng = StdRng::seed_from_u64(1234);
+    ix.shuffle(&mut rng);
+
+    let folds = (0..n_folds).into_par_iter()
+        .map(|k| {
+            let start = k * n_sample / n_folds;
+            let end = (k + 1) * n_sample / n_folds;
+            let (train, test) = sample.split(&ix[..], start, end);
+            let h = learner(&train);
+            metrics.iter()
+                .map(|metric| metric(&test, &h))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    CrossValidationReport { folds }
+}
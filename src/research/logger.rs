@@ -7,6 +7,7 @@ use crate::{
     Classifier,
 };
 use super::ObjectiveFunction;
+use super::ConfusionMatrix;
 
 use std::fs::File;
 use std::io::prelude::*;
@@ -35,6 +36,7 @@ pub struct Logger<'a, B, W, F, G> {
     pub(super) test: &'a Sample,
     pub(super) time_limit: u128,
     pub(super) round: usize,
+    pub(super) per_class: bool,
 }
 
 
@@ -58,6 +60,7 @@ impl<'a, B, W, F, G> Logger<'a, B, W, F, G> {
             test,
             time_limit: DEFAULT_TIMELIMIT_MILLIS,
             round: DEFAULT_ROUND,
+            per_class: false,
         }
     }
 }
@@ -208,6 +211,32 @@ impl<'a, H, B, W, F, G, O> Logger<'a, B, W, F, G>
 
 
 
+    /// Print the per-class precision, recall, and F1 score
+    /// with their macro/micro averages
+    /// for the training and test samples after boosting.
+    /// See [`ConfusionMatrix`].
+    /// By default, the report is not printed.
+    #[inline(always)]
+    pub fn per_class_report(mut self, flag: bool) -> Self {
+        self.per_class = flag;
+        self
+    }
+
+
+    #[inline(always)]
+    fn print_per_class_report(&self, f: &O) {
+        let samples = [("TRAIN", self.train), ("TEST", self.test)];
+        for (name, sample) in samples {
+            let matrix = ConfusionMatrix::from_classifier(sample, f);
+            let header = format!(
+                "{:=>FULL_WIDTH$}\n{:^FULL_WIDTH$}\n{:->FULL_WIDTH$}",
+                "", name.bold(), "",
+            );
+            println!("{header}\n{matrix}\n{:=>FULL_WIDTH$}\n", "");
+        }
+    }
+
+
     /// Run the given boosting algorithm with logging.
     /// Note that this method is almost the same as `Booster::run`.
     /// This method measures running time per iteration.
@@ -299,6 +328,7 @@ impl<'a, H, B, W, F, G, O> Logger<'a, B, W, F, G>
 
 
         let f = self.booster.postprocess(&self.weak_learner);
+        if self.per_class { self.print_per_class_report(&f); }
         Ok(f)
     }
 }
@@ -334,6 +364,18 @@ pub trait Research {
 
     /// Returns the combined hypothesis at current state.
     fn current_hypothesis(&self) -> Self::Output;
+
+
+    /// Returns the loss of the combined hypothesis at current state
+    /// on the out-of-bag examples,
+    /// which are not used at the last round.
+    /// Boosting algorithms that use a subsample of
+    /// the training examples at each round
+    /// override this method.
+    /// By default, this method returns `None`.
+    fn out_of_bag_loss(&self) -> Option<f64> {
+        None
+    }
 }
 
 
@@ -82,6 +82,7 @@ pub struct LoggerBuilder<'a, B, W, F, G> {
     test: Option<&'a Sample>,
     time_limit: u128,
     round: usize,
+    per_class: bool,
 }
 
 
@@ -97,6 +98,7 @@ impl<'a, B, W, F, G> LoggerBuilder<'a, B, W, F, G> {
             test: None,
             time_limit: DEFAULT_TIMELIMIT_MILLIS,
             round: DEFAULT_ROUND,
+            per_class: false,
         }
     }
 
@@ -188,6 +190,16 @@ impl<'a, B, W, F, G> LoggerBuilder<'a, B, W, F, G> {
     }
 
 
+    /// Print the per-class precision, recall, and F1 score
+    /// for the training and test samples after boosting.
+    /// By default, the report is not printed.
+    #[inline(always)]
+    pub fn per_class_report(mut self, flag: bool) -> Self {
+        self.per_class = flag;
+        self
+    }
+
+
     /// Build [Logger] from the given components.
     pub fn build(self) -> Logger<'a, B, W, F, G> {
         let booster = self.booster
@@ -204,6 +216,7 @@ impl<'a, B, W, F, G> LoggerBuilder<'a, B, W, F, G> {
             .expect("Test sample is not specified");
         let time_limit = self.time_limit;
         let round = self.round;
+        let per_class = self.per_class;
 
         Logger {
             booster,
@@ -214,6 +227,7 @@ impl<'a, B, W, F, G> LoggerBuilder<'a, B, W, F, G> {
             test,
             time_limit,
             round,
+            per_class,
         }
     }
 }
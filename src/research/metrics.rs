@@ -0,0 +1,294 @@
+//! Provides evaluation metrics for classifiers.
+//! [`ConfusionMatrix`] counts the pairs of true/predicted labels
+//! and reports the precision, recall, and F1 score
+//! for each class and their macro/micro averages.
+//! These metrics work for binary and multi-class classifiers.
+use colored::Colorize;
+
+use crate::{
+    Sample,
+    Classifier,
+};
+
+use std::fmt;
+use std::collections::BTreeSet;
+
+
+const LABEL_WIDTH: usize = 10;
+const VALUE_WIDTH: usize = 10;
+
+
+/// A confusion matrix over the classes
+/// that appear in the true or predicted labels.
+/// The entry `(i, j)` is the number of examples
+/// whose true label is `classes()[i]`
+/// and the predicted label is `classes()[j]`.
+///
+/// [`fmt::Display`] prints the per-class breakdown
+/// of precision, recall, F1, and support,
+/// followed by the macro/micro averages.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use miniboosts::research::ConfusionMatrix;
+///
+/// # let sample = SampleReader::new().file("test.csv").has_header(true).target_feature("class").read().unwrap();
+/// # let tree = DecisionTreeBuilder::new(&sample).build();
+/// # let f = AdaBoost::init(&sample).run(&tree);
+/// let matrix = ConfusionMatrix::from_classifier(&sample, &f);
+/// println!("{matrix}");
+/// println!("Macro F1: {}", matrix.macro_f1());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfusionMatrix {
+    classes: Vec<i64>,
+    counts: Vec<Vec<usize>>,
+}
+
+
+impl ConfusionMatrix {
+    /// Construct a confusion matrix from the true labels `target`
+    /// and the predicted labels `predictions`.
+    pub fn new(target: &[f64], predictions: &[i64]) -> Self {
+        assert_eq!(
+            target.len(), predictions.len(),
+            "The number of labels and predictions must be the same"
+        );
+        let classes = target.iter()
+            .map(|&y| y as i64)
+            .chain(predictions.iter().copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let n_classes = classes.len();
+        let mut counts = vec![vec![0usize; n_classes]; n_classes];
+        let index = |label: i64| classes.binary_search(&label).unwrap();
+        target.iter()
+            .zip(predictions)
+            .for_each(|(&y, &p)| {
+                counts[index(y as i64)][index(p)] += 1;
+            });
+
+        Self { classes, counts }
+    }
+
+
+    /// Construct a confusion matrix of `f` over `sample`.
+    pub fn from_classifier<H>(sample: &Sample, f: &H) -> Self
+        where H: Classifier
+    {
+        let predictions = f.predict_all(sample);
+        Self::new(sample.target(), &predictions[..])
+    }
+
+
+    /// Returns the classes in ascending order.
+    pub fn classes(&self) -> &[i64] {
+        &self.classes[..]
+    }
+
+
+    /// Returns the number of examples
+    /// whose true label is `actual` and the predicted label is `predicted`.
+    pub fn count(&self, actual: i64, predicted: i64) -> usize {
+        match (self.position(actual), self.position(predicted)) {
+            (Some(i), Some(j)) => self.counts[i][j],
+            _ => 0,
+        }
+    }
+
+
+    /// Returns the number of examples.
+    pub fn total(&self) -> usize {
+        self.counts.iter()
+            .map(|row| row.iter().sum::<usize>())
+            .sum::<usize>()
+    }
+
+
+    /// Returns the accuracy.
+    pub fn accuracy(&self) -> f64 {
+        let correct = (0..self.classes.len())
+            .map(|k| self.counts[k][k])
+            .sum::<usize>();
+        ratio(correct, self.total())
+    }
+
+
+    /// Returns the precision for `class`.
+    /// Returns `0` if no example is predicted as `class`.
+    pub fn precision(&self, class: i64) -> f64 {
+        let Some(k) = self.position(class) else { return 0f64; };
+        ratio(self.true_positive(k), self.predicted(k))
+    }
+
+
+    /// Returns the recall for `class`.
+    /// Returns `0` if no example belongs to `class`.
+    pub fn recall(&self, class: i64) -> f64 {
+        let Some(k) = self.position(class) else { return 0f64; };
+        ratio(self.true_positive(k), self.support_at(k))
+    }
+
+
+    /// Returns the F1 score for `class`.
+    pub fn f1(&self, class: i64) -> f64 {
+        f1(self.precision(class), self.recall(class))
+    }
+
+
+    /// Returns the number of examples that belong to `class`.
+    pub fn support(&self, class: i64) -> usize {
+        self.position(class)
+            .map(|k| self.support_at(k))
+            .unwrap_or(0)
+    }
+
+
+    /// Returns the unweighted mean of the precisions over the classes.
+    pub fn macro_precision(&self) -> f64 {
+        self.macro_average(|class| self.precision(class))
+    }
+
+
+    /// Returns the unweighted mean of the recalls over the classes.
+    pub fn macro_recall(&self) -> f64 {
+        self.macro_average(|class| self.recall(class))
+    }
+
+
+    /// Returns the unweighted mean of the F1 scores over the classes.
+    pub fn macro_f1(&self) -> f64 {
+        self.macro_average(|class| self.f1(class))
+    }
+
+
+    /// Returns the precision computed from
+    /// the true/false positives summed over the classes.
+    pub fn micro_precision(&self) -> f64 {
+        let tp = (0..self.classes.len())
+            .map(|k| self.true_positive(k))
+            .sum::<usize>();
+        let predicted = (0..self.classes.len())
+            .map(|k| self.predicted(k))
+            .sum::<usize>();
+        ratio(tp, predicted)
+    }
+
+
+    /// Returns the recall computed from
+    /// the true positives/false negatives summed over the classes.
+    pub fn micro_recall(&self) -> f64 {
+        let tp = (0..self.classes.len())
+            .map(|k| self.true_positive(k))
+            .sum::<usize>();
+        let support = (0..self.classes.len())
+            .map(|k| self.support_at(k))
+            .sum::<usize>();
+        ratio(tp, support)
+    }
+
+
+    /// Returns the F1 score of
+    /// [`ConfusionMatrix::micro_precision`] and
+    /// [`ConfusionMatrix::micro_recall`].
+    pub fn micro_f1(&self) -> f64 {
+        f1(self.micro_precision(), self.micro_recall())
+    }
+
+
+    fn position(&self, class: i64) -> Option<usize> {
+        self.classes.binary_search(&class).ok()
+    }
+
+
+    fn true_positive(&self, k: usize) -> usize {
+        self.counts[k][k]
+    }
+
+
+    fn predicted(&self, k: usize) -> usize {
+        self.counts.iter()
+            .map(|row| row[k])
+            .sum::<usize>()
+    }
+
+
+    fn support_at(&self, k: usize) -> usize {
+        self.counts[k].iter().sum::<usize>()
+    }
+
+
+    fn macro_average<M>(&self, metric: M) -> f64
+        where M: Fn(i64) -> f64
+    {
+        if self.classes.is_empty() { return 0f64; }
+        self.classes.iter()
+            .copied()
+            .map(metric)
+            .sum::<f64>()
+            / self.classes.len() as f64
+    }
+}
+
+
+impl fmt::Display for ConfusionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>LABEL_WIDTH$} {:>VALUE_WIDTH$} {:>VALUE_WIDTH$} \
+            {:>VALUE_WIDTH$} {:>VALUE_WIDTH$}",
+            "CLASS".bold(),
+            "PRECISION".bold(),
+            "RECALL".bold(),
+            "F1".bold(),
+            "SUPPORT".bold(),
+        )?;
+        for &class in self.classes.iter() {
+            writeln!(
+                f,
+                "{:>LABEL_WIDTH$} {:>VALUE_WIDTH$.4} {:>VALUE_WIDTH$.4} \
+                {:>VALUE_WIDTH$.4} {:>VALUE_WIDTH$}",
+                class,
+                self.precision(class),
+                self.recall(class),
+                self.f1(class),
+                self.support(class),
+            )?;
+        }
+        writeln!(
+            f,
+            "{:>LABEL_WIDTH$} {:>VALUE_WIDTH$.4} {:>VALUE_WIDTH$.4} \
+            {:>VALUE_WIDTH$.4} {:>VALUE_WIDTH$}",
+            "macro",
+            self.macro_precision(),
+            self.macro_recall(),
+            self.macro_f1(),
+            self.total(),
+        )?;
+        write!(
+            f,
+            "{:>LABEL_WIDTH$} {:>VALUE_WIDTH$.4} {:>VALUE_WIDTH$.4} \
+            {:>VALUE_WIDTH$.4} {:>VALUE_WIDTH$}",
+            "micro",
+            self.micro_precision(),
+            self.micro_recall(),
+            self.micro_f1(),
+            self.total(),
+        )
+    }
+}
+
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 { return 0f64; }
+    numerator as f64 / denominator as f64
+}
+
+
+fn f1(precision: f64, recall: f64) -> f64 {
+    if precision + recall == 0f64 { return 0f64; }
+    2f64 * precision * recall / (precision + recall)
+}
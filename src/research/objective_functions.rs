@@ -131,6 +131,67 @@ impl<H> ObjectiveFunction<H> for HardMarginObjective
 }
 
 
+/// Margin distribution objective is the objective function
+/// for [`ODMBoost`].
+/// Given a set of training instances `(x1, y1), (x2, y2), ..., (xm, ym)`
+/// and a hypothesis `h`,
+/// the objective is computed as
+/// ```txt
+/// μ - (λ/2) σ²,
+/// ```
+/// where `μ` and `σ²` are the mean and the variance
+/// of the margins `yk h(xk)`.
+///
+/// [`ODMBoost`]: crate::booster::ODMBoost
+pub struct MarginDistributionObjective(f64);
+
+impl MarginDistributionObjective {
+    /// Construct a new instance of `MarginDistributionObjective`
+    /// with the weight `λ ≥ 0` on the margin variance.
+    pub fn new(lambda: f64) -> Self {
+        assert!(
+            lambda >= 0f64,
+            "The weight on the margin variance must be non-negative"
+        );
+        Self(lambda)
+    }
+}
+
+impl<H> ObjectiveFunction<H> for MarginDistributionObjective
+    where H: Classifier,
+{
+    fn name(&self) -> &str {
+        "Margin-distribution objective"
+    }
+
+
+    fn eval(
+        &self,
+        sample: &Sample,
+        hypothesis: &H,
+    ) -> f64
+    {
+        checker::check_sample(sample);
+        let n_sample = sample.shape().0 as f64;
+        let target = sample.target();
+
+        let margins = hypothesis.confidence_all(sample)
+            .into_iter()
+            .zip(target.iter())
+            .map(|(hx, y)| y * hx)
+            .collect::<Vec<f64>>();
+
+        let mean = margins.iter().sum::<f64>() / n_sample;
+        let variance = margins.iter()
+            .map(|yh| (yh - mean).powi(2))
+            .sum::<f64>()
+            / n_sample;
+
+        mean - 0.5 * self.0 * variance
+    }
+}
+
+
 /// The exponential loss objective.
 /// Given a set of training instances `(x1, y1), (x2, y2), ..., (xm, ym)`
 /// and a hypothesis `h`,
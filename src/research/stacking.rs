@@ -0,0 +1,267 @@
+use rand::prelude::*;
+
+use crate::{
+    Sample,
+    Classifier,
+    StackedClassifier,
+    common::loss_functions::sigmoid,
+    common::utils::solve,
+};
+
+
+/// The maximum number of Newton iterations for the meta-learner.
+const MAX_NEWTON_ITER: usize = 100;
+/// The Newton iteration stops if every coordinate moves less than this.
+const NEWTON_TOLERANCE: f64 = 1e-10;
+
+
+/// A trainer of a base classifier for [`Stacking`].
+type Learner<'a, H> = Box<dyn Fn(&Sample) -> H + 'a>;
+
+
+/// A two-level ensemble trainer.
+/// `Stacking` takes some learners,
+/// each of which trains a classifier (e.g., a booster)
+/// from a given sample,
+/// and combines the classifiers by a logistic regression model
+/// (the meta-learner).
+///
+/// To avoid over-fitting of the meta-learner,
+/// the meta-learner is trained over the **out-of-fold** confidences.
+/// That is, the training sample is split into `k` folds,
+/// each learner is trained on `k - 1` folds,
+/// and the confidences on the remaining fold are
+/// the features of the meta-learner.
+/// After that, each learner is trained on the whole sample
+/// and the output [`StackedClassifier`] combines them
+/// with the weights of the meta-learner.
+///
+/// The meta-learner minimizes the L2-regularized logistic loss
+/// ```txt
+/// Σ_i ln( 1 + exp( - y_i (b + Σ_j w_j z_ij) ) ) + (λ/2) Σ_j w_j²,
+/// ```
+/// where `z_ij` is the out-of-fold confidence
+/// of the `j`-th learner on the `i`-th example.
+///
+/// If the learners return classifiers of different types,
+/// box them as `Box<dyn Classifier>`.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use miniboosts::Stacking;
+///
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let f = Stacking::new(&sample)
+///     .n_folds(5)
+///     .learner(|train: &Sample| {
+///         let tree = DecisionTreeBuilder::new(train).max_depth(2).build();
+///         AdaBoost::init(train).force_quit_at(100).run(&tree)
+///     })
+///     .learner(|train: &Sample| {
+///         let tree = DecisionTreeBuilder::new(train).max_depth(2).build();
+///         let nu = 0.1 * train.shape().0 as f64;
+///         LPBoost::init(train).nu(nu).run(&tree)
+///     })
+///     .fit();
+///
+/// println!("Weights on the boosters: {:?}", f.weights);
+/// ```
+pub struct Stacking<'a, H> {
+    sample: &'a Sample,
+    n_folds: usize,
+    seed: u64,
+    l2_regularization: f64,
+    learners: Vec<Learner<'a, H>>,
+}
+
+
+impl<'a, H> Stacking<'a, H>
+    where H: Classifier,
+{
+    /// Construct a new instance of `Stacking` without learners.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn new(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            n_folds: 5,
+            seed: 1234,
+            l2_regularization: 1f64,
+            learners: Vec::new(),
+        }
+    }
+
+
+    /// Set the number of folds
+    /// for the out-of-fold confidences.
+    /// Default value is `5`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn n_folds(mut self, n_folds: usize) -> Self {
+        let n_sample = self.sample.shape().0;
+        assert!(
+            (2..=n_sample).contains(&n_folds),
+            "The number of folds must be in `[2, # of examples]`"
+        );
+        self.n_folds = n_folds;
+        self
+    }
+
+
+    /// Set the seed of the randomness for shuffling the folds.
+    /// Default value is `1234`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Set the L2-regularization parameter `λ ≥ 0`
+    /// on the weights of the meta-learner.
+    /// Default value is `1.0`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn l2_regularization(mut self, lambda: f64) -> Self {
+        assert!(
+            lambda >= 0f64,
+            "The regularization parameter must be non-negative"
+        );
+        self.l2_regularization = lambda;
+        self
+    }
+
+
+    /// Add a learner that trains a classifier from a given sample.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn learner<L>(mut self, learner: L) -> Self
+        where L: Fn(&Sample) -> H + 'a,
+    {
+        self.learners.push(Box::new(learner));
+        self
+    }
+
+
+    /// Returns the out-of-fold confidences.
+    /// The `i`-th element is the vector of the confidences
+    /// of the learners on the `i`-th example,
+    /// trained without the fold containing the example.
+    ///
+    /// Time complexity: `O(k)` calls of each learner.
+    pub fn out_of_fold_confidences(&self) -> Vec<Vec<f64>> {
+        assert!(!self.learners.is_empty(), "No learner is given");
+        let n_sample = self.sample.shape().0;
+        let n_learners = self.learners.len();
+
+        let mut ix = (0..n_sample).collect::<Vec<_>>();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        ix.shuffle(&mut rng);
+
+
+        let mut confidences = vec![vec![0f64; n_learners]; n_sample];
+        let fold_size = n_sample.div_ceil(self.n_folds);
+        for k in 0..self.n_folds {
+            let start = (k * fold_size).min(n_sample);
+            let end = ((k + 1) * fold_size).min(n_sample);
+            if start == end { break; }
+
+            let (train, test) = self.sample.split(&ix[..], start, end);
+            for (j, learner) in self.learners.iter().enumerate() {
+                let h = learner(&train);
+                for (row, &i) in ix[start..end].iter().enumerate() {
+                    confidences[i][j] = h.confidence(&test, row);
+                }
+            }
+        }
+        confidences
+    }
+
+
+    /// Trains the meta-learner over the out-of-fold confidences
+    /// and the learners over the whole sample,
+    /// and returns the stacked classifier.
+    ///
+    /// Time complexity: `O(k + 1)` calls of each learner.
+    pub fn fit(&self) -> StackedClassifier<H> {
+        self.sample.is_valid_binary_instance();
+        let confidences = self.out_of_fold_confidences();
+        let (weights, bias) = fit_logistic_regression(
+            &confidences[..], self.sample.target(), self.l2_regularization
+        );
+
+        let hypotheses = self.learners.iter()
+            .map(|learner| learner(self.sample))
+            .collect();
+        StackedClassifier { weights, bias, hypotheses }
+    }
+}
+
+
+/// Fits the L2-regularized logistic regression model
+/// `P(y = +1 | z) = σ(b + w・z)` by Newton's method
+/// and returns the pair `(w, b)`.
+/// The intercept `b` is not regularized.
+fn fit_logistic_regression(
+    features: &[Vec<f64>],
+    target: &[f64],
+    lambda: f64,
+) -> (Vec<f64>, f64)
+{
+    let n_dim = features[0].len();
+    // The last coordinate of `theta` is the intercept.
+    let mut theta = vec![0f64; n_dim + 1];
+
+    for _ in 0..MAX_NEWTON_ITER {
+        let mut gradient = vec![0f64; n_dim + 1];
+        let mut hessian = vec![vec![0f64; n_dim + 1]; n_dim + 1];
+        for (z, y) in features.iter().zip(target) {
+            let z = z.iter()
+                .copied()
+                .chain(std::iter::once(1f64))
+                .collect::<Vec<_>>();
+            let score = z.iter()
+                .zip(&theta[..])
+                .map(|(zj, t)| zj * t)
+                .sum::<f64>();
+            // The label in `{0, 1}`.
+            let label = if *y > 0f64 { 1f64 } else { 0f64 };
+            let p = sigmoid(score);
+            for j in 0..=n_dim {
+                gradient[j] += (p - label) * z[j];
+                for k in 0..=n_dim {
+                    hessian[j][k] += p * (1f64 - p) * z[j] * z[k];
+                }
+            }
+        }
+        for j in 0..n_dim {
+            gradient[j] += lambda * theta[j];
+            hessian[j][j] += lambda;
+        }
+        // Keep the Hessian positive definite
+        // even if the examples are separable.
+        hessian[n_dim][n_dim] += f64::EPSILON;
+
+
+        let step = solve(hessian, gradient);
+        let mut max_step = 0f64;
+        theta.iter_mut()
+            .zip(step)
+            .for_each(|(t, s)| {
+                *t -= s;
+                max_step = max_step.max(s.abs());
+            });
+        if max_step < NEWTON_TOLERANCE { break; }
+    }
+
+    let bias = theta.pop().unwrap();
+    (theta, bias)
+}
@@ -4,12 +4,29 @@
 pub(crate) mod feature_struct;
 // Provides sample struct.
 pub(crate) mod sample_struct;
+// Provides the rows of the sparse features.
+pub(crate) mod sparse_rows;
+// Provides the mapping of the class labels.
+pub(crate) mod label_map;
+// Provides the policies for the missing values.
+pub(crate) mod missing_policy;
+
+// Provides the target format for multi-label problems.
+pub(crate) mod multi_label;
 
 // Provides a struct that reads a file.
 pub(crate) mod sample_reader;
+// Provides an iterator over the blocks of the rows of a file.
+pub(crate) mod sample_chunks;
+// Provides the on-disk column store of a sample.
+pub(crate) mod column_store;
 
 
 pub use sample_reader::SampleReader;
-pub use sample_struct::Sample;
+pub use sample_chunks::SampleChunks;
+pub use sample_struct::{Sample, DEFAULT_SPARSE_THRESHOLD};
 pub use feature_struct::Feature;
+pub use label_map::{Label, LabelMap};
+pub use missing_policy::MissingPolicy;
+pub use multi_label::MultiLabelSample;
 
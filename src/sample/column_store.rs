@@ -0,0 +1,311 @@
+//! This file defines the on-disk column store of a sample,
+//! a binary file of the following layout
+//! (all the integers and the floats are little-endian):
+//!
+//! 1. The magic bytes `MBSTORE1`.
+//! 2. The number of the rows and the one of the features as `u64`.
+//! 3. The name of each feature as the length (`u64`) and the UTF-8 bytes.
+//! 4. The zero padding up to a multiple of 8 bytes.
+//! 5. The target values as `f64`.
+//! 6. The values of each feature as `f64`, column by column.
+//!
+//! Since each column is contiguous,
+//! a column store is written block by block of rows
+//! without holding the whole sample in memory,
+//! and the features of a sample are backed by
+//! a memory-mapped column store.
+use std::path::Path;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::sync::Arc;
+
+use memmap2::Mmap;
+
+use super::feature_struct::{Feature, MappedFeature};
+use super::sample_struct::{Sample, invalid_data, invalid_input};
+
+
+const MAGIC: &[u8; 8] = b"MBSTORE1";
+const VALUE_SIZE: u64 = std::mem::size_of::<f64>() as u64;
+
+
+/// The header of a column store.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct StoreHeader {
+    pub(crate) n_sample: usize,
+    pub(crate) names: Vec<String>,
+}
+
+
+impl StoreHeader {
+    /// Returns the offset of the target values in bytes,
+    /// which is a multiple of 8.
+    pub(crate) fn data_offset(&self) -> u64 {
+        let names = self.names.iter()
+            .map(|name| VALUE_SIZE + name.len() as u64)
+            .sum::<u64>();
+        (MAGIC.len() as u64 + 2 * VALUE_SIZE + names)
+            .next_multiple_of(VALUE_SIZE)
+    }
+
+
+    /// Returns the offset of the values of the `j`-th feature in bytes.
+    pub(crate) fn column_offset(&self, j: usize) -> u64 {
+        self.data_offset() + (j as u64 + 1) * self.column_bytes()
+    }
+
+
+    /// Returns the size of the file in bytes.
+    pub(crate) fn file_size(&self) -> u64 {
+        self.column_offset(self.names.len())
+    }
+
+
+    fn column_bytes(&self) -> u64 {
+        self.n_sample as u64 * VALUE_SIZE
+    }
+
+
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(self.n_sample as u64).to_le_bytes())?;
+        writer.write_all(&(self.names.len() as u64).to_le_bytes())?;
+        let mut size = MAGIC.len() as u64 + 2 * VALUE_SIZE;
+        for name in &self.names {
+            writer.write_all(&(name.len() as u64).to_le_bytes())?;
+            writer.write_all(name.as_bytes())?;
+            size += VALUE_SIZE + name.len() as u64;
+        }
+        let padding = self.data_offset() - size;
+        writer.write_all(&vec![0u8; padding as usize])
+    }
+
+
+    /// Reads the header from the beginning of a column store.
+    pub(crate) fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("The file is not a column store"));
+        }
+        let n_sample = read_u64(reader)? as usize;
+        let n_feature = read_u64(reader)? as usize;
+        let names = (0..n_feature)
+            .map(|_| {
+                let len = read_u64(reader)? as usize;
+                let mut name = vec![0u8; len];
+                reader.read_exact(&mut name)?;
+                String::from_utf8(name).map_err(invalid_data)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self { n_sample, names })
+    }
+}
+
+
+/// Writes a column store block by block of rows.
+pub(crate) struct StoreWriter {
+    file: File,
+    header: StoreHeader,
+    // The number of the rows written so far.
+    n_written: usize,
+}
+
+
+impl StoreWriter {
+    /// Creates a column store of `header.n_sample` rows at `path`.
+    pub(crate) fn create<P: AsRef<Path>>(path: P, header: StoreHeader)
+        -> io::Result<Self>
+    {
+        let mut file = BufWriter::new(File::create(path)?);
+        header.write(&mut file)?;
+        let file = file.into_inner().map_err(|e| e.into_error())?;
+        file.set_len(header.file_size())?;
+        Ok(Self { file, header, n_written: 0 })
+    }
+
+
+    /// Appends the rows of `sample`,
+    /// whose features must be in the same order as the header.
+    pub(crate) fn write_rows(&mut self, sample: &Sample) -> io::Result<()> {
+        let names = sample.features()
+            .iter()
+            .map(|feature| feature.name());
+        if !names.eq(self.header.names.iter().map(|name| name.as_str())) {
+            return Err(invalid_input(
+                "The features are different from the ones of the store"
+            ));
+        }
+        let n_row = sample.shape().0;
+        if self.n_written + n_row > self.header.n_sample {
+            return Err(invalid_input(format!(
+                "The store has only {} rows", self.header.n_sample
+            )));
+        }
+
+        let row_offset = self.n_written as u64 * VALUE_SIZE;
+        let offset = self.header.data_offset() + row_offset;
+        self.write_at(offset, sample.target().iter().copied())?;
+        for (j, feature) in sample.features().iter().enumerate() {
+            let offset = self.header.column_offset(j) + row_offset;
+            self.write_at(offset, (0..n_row).map(|i| feature[i]))?;
+        }
+        self.n_written += n_row;
+        Ok(())
+    }
+
+
+    /// Finishes writing.
+    /// This method returns `Err` if the number of the written rows
+    /// is not the one in the header.
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        if self.n_written != self.header.n_sample {
+            return Err(invalid_data(format!(
+                "Expected {} rows, got {}",
+                self.header.n_sample, self.n_written
+            )));
+        }
+        self.file.flush()
+    }
+
+
+    fn write_at<I>(&mut self, offset: u64, values: I) -> io::Result<()>
+        where I: Iterator<Item = f64>,
+    {
+        self.file.seek(SeekFrom::Start(offset))?;
+        let mut writer = BufWriter::new(&mut self.file);
+        for value in values {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        writer.flush()
+    }
+}
+
+
+impl Sample {
+    /// Reads a column store made by
+    /// [`SampleReader::build_store`](crate::SampleReader::build_store)
+    /// or [`Sample::to_mmap`] into memory.
+    /// See also [`Sample::open_mmap`].
+    pub fn load_store<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let header = StoreHeader::read(&mut reader)?;
+        reader.seek(SeekFrom::Start(header.data_offset()))?;
+
+        let n_sample = header.n_sample;
+        let target = read_f64s(&mut reader, n_sample)?;
+        let columns = (0..header.names.len())
+            .map(|_| read_f64s(&mut reader, n_sample))
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self::from_columns(header.names, columns, target))
+    }
+
+
+    /// Writes `self` to the column store at `path`,
+    /// which [`Sample::open_mmap`] and [`Sample::load_store`] read.
+    /// The bag ids, the instance weights, the group ids,
+    /// and the categorical marks are not written.
+    pub fn to_mmap<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let names = self.features()
+            .iter()
+            .map(|feature| feature.name().to_string())
+            .collect();
+        let n_sample = self.shape().0;
+        if self.target().len() != n_sample {
+            return Err(invalid_input(
+                "The target class is not specified"
+            ));
+        }
+        let header = StoreHeader { n_sample, names };
+        let mut writer = StoreWriter::create(path, header)?;
+        writer.write_rows(self)?;
+        writer.finish()
+    }
+
+
+    /// Opens the column store at `path`
+    /// made by [`Sample::to_mmap`] or
+    /// [`SampleReader::build_store`](crate::SampleReader::build_store).
+    /// The features are not read into memory;
+    /// their values are read from the memory-mapped file on demand,
+    /// so that a sample larger than the memory can be used, and
+    /// the processes opening the same file share one copy of it
+    /// in the page cache.
+    /// The target values are copied into memory.
+    ///
+    /// The file must not be modified while it is open.
+    /// Methods that modify the features,
+    /// such as [`Sample::push_rows`] and
+    /// [`Sample::with_sparse_threshold`],
+    /// copy the modified features into memory.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use miniboosts::prelude::*;
+    ///
+    /// let sample = SampleReader::new()
+    ///     .file("/path/to/data.csv")
+    ///     .has_header(true)
+    ///     .target_feature("class")
+    ///     .read()
+    ///     .unwrap();
+    /// sample.to_mmap("/path/to/data.bin").unwrap();
+    ///
+    /// // In each experiment process:
+    /// let sample = Sample::open_mmap("/path/to/data.bin").unwrap();
+    /// ```
+    pub fn open_mmap<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        if cfg!(target_endian = "big") {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Column stores are mapped on little-endian machines only"
+            ));
+        }
+        let file = File::open(path)?;
+        // SAFETY: the map is read-only,
+        // and the file must not be modified while it is open,
+        // as documented above.
+        let map = Arc::new(unsafe { Mmap::map(&file)? });
+        let header = StoreHeader::read(&mut &map[..])?;
+        if (map.len() as u64) < header.file_size() {
+            return Err(invalid_data("The column store is truncated"));
+        }
+
+        let n_sample = header.n_sample;
+        let offset = header.data_offset() as usize;
+        let target = read_f64s(
+            &mut &map[offset..header.column_offset(0) as usize],
+            n_sample,
+        )?;
+        let features = header.names
+            .iter()
+            .enumerate()
+            .map(|(j, name)| {
+                let name = name.clone();
+                let offset = header.column_offset(j) as usize;
+                MappedFeature::new(name, Arc::clone(&map), offset, n_sample)
+                    .map(Feature::Mapped)
+                    .ok_or_else(|| invalid_data("Misaligned store"))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self::from_features(features, target))
+    }
+}
+
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+
+fn read_f64s<R: Read>(reader: &mut R, n: usize) -> io::Result<Vec<f64>> {
+    let mut bytes = [0u8; 8];
+    (0..n)
+        .map(|_| {
+            reader.read_exact(&mut bytes)?;
+            Ok(f64::from_le_bytes(bytes))
+        })
+        .collect()
+}
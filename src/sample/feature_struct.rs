@@ -1,6 +1,8 @@
 use polars::prelude::*;
+use memmap2::Mmap;
 use std::ops::Index;
 use std::slice::Iter;
+use std::sync::Arc;
 
 use crate::common::{utils, checker};
 
@@ -30,6 +32,20 @@ pub struct SparseFeature {
 }
 
 
+/// Dense representation of a feature
+/// whose values reside in a memory-mapped file.
+/// See [`Sample::open_mmap`](crate::Sample::open_mmap).
+#[derive(Debug,Clone)]
+pub struct MappedFeature {
+    /// Feature name
+    pub name: String,
+    map: Arc<Mmap>,
+    // The position of the first value in bytes.
+    offset: usize,
+    n_sample: usize,
+}
+
+
 /// An enumeration of sparse/dense feature.
 #[derive(Debug,Clone)]
 pub enum Feature {
@@ -37,6 +53,8 @@ pub enum Feature {
     Dense(DenseFeature),
     /// Sparse representation of a feature
     Sparse(SparseFeature),
+    /// Dense representation of a feature in a memory-mapped file
+    Mapped(MappedFeature),
 }
 
 
@@ -55,7 +73,7 @@ impl Feature {
 
     pub(crate) fn is_sparse(&self) -> bool {
         match self {
-            Self::Dense(_) => false,
+            Self::Dense(_) | Self::Mapped(_) => false,
             Self::Sparse(_) => true,
         }
     }
@@ -66,9 +84,48 @@ impl Feature {
     // }
 
 
+    /// Returns the fraction of the examples
+    /// whose values are non-zero.
+    /// Missing values are non-zero.
+    pub fn density(&self) -> f64 {
+        let (n_nonzero, n_sample) = match self {
+            Self::Dense(feat) => {
+                let n_nonzero = feat.iter().filter(|x| **x != 0f64).count();
+                (n_nonzero, feat.len())
+            },
+            Self::Sparse(feat) => (feat.len(), feat.n_sample),
+            Self::Mapped(feat) => {
+                let n_nonzero = feat.iter().filter(|x| **x != 0f64).count();
+                (n_nonzero, feat.len())
+            },
+        };
+        if n_sample == 0 { return 1f64; }
+        n_nonzero as f64 / n_sample as f64
+    }
+
+
+    /// Converts `self` into the sparse representation.
+    pub(crate) fn into_sparse(self) -> Self {
+        match self.into_dense() {
+            Self::Dense(feat) => Self::Sparse(SparseFeature::from_dense(feat)),
+            feature => feature,
+        }
+    }
+
+
+    /// Converts `self` into the dense representation in the memory.
+    pub(crate) fn into_dense(self) -> Self {
+        match self {
+            Self::Dense(_) => self,
+            Self::Sparse(feat) => Self::Dense(DenseFeature::from_sparse(feat)),
+            Self::Mapped(feat) => Self::Dense(feat.into_dense()),
+        }
+    }
+
+
     pub(crate) fn set_n_sample(&mut self, n_sample: usize) {
         match self {
-            Self::Dense(_) => {},
+            Self::Dense(_) | Self::Mapped(_) => {},
             Self::Sparse(feat) => {
                 feat.n_sample = n_sample;
             }
@@ -76,10 +133,17 @@ impl Feature {
     }
 
 
+    /// Appends the value `f` of the `i`-th example.
+    /// A feature in a memory-mapped file is copied into the memory
+    /// since the file is read-only.
     pub(crate) fn append(&mut self, i: usize, f: f64) {
+        if let Self::Mapped(feat) = self {
+            *self = Self::Dense(feat.clone().into_dense());
+        }
         match self {
             Self::Dense(feat) => { feat.append(f); },
-            Self::Sparse(feat) => { feat.append((i, f)); }
+            Self::Sparse(feat) => { feat.append((i, f)); },
+            Self::Mapped(_) => unreachable!(),
         }
     }
 
@@ -89,6 +153,7 @@ impl Feature {
         match self {
             Self::Dense(feat) => feat.name(),
             Self::Sparse(feat) => feat.name(),
+            Self::Mapped(feat) => feat.name(),
         }
     }
 
@@ -99,6 +164,7 @@ impl Feature {
         match self {
             Self::Dense(feat) => feat.replace_name(name),
             Self::Sparse(feat) => feat.replace_name(name),
+            Self::Mapped(feat) => feat.replace_name(name),
         }
     }
 
@@ -107,6 +173,7 @@ impl Feature {
         match self {
             Self::Dense(feat) => feat.into_target(),
             Self::Sparse(feat) => feat.into_target(),
+            Self::Mapped(feat) => feat.values().to_vec(),
         }
     }
 
@@ -116,6 +183,7 @@ impl Feature {
         match self {
             Self::Dense(feat) => feat.len(),
             Self::Sparse(feat) => feat.len(),
+            Self::Mapped(feat) => feat.len(),
         }
     }
 
@@ -125,6 +193,7 @@ impl Feature {
         match self {
             Self::Dense(feat) => feat.is_empty(),
             Self::Sparse(feat) => feat.is_empty(),
+            Self::Mapped(feat) => feat.is_empty(),
         }
     }
 
@@ -133,6 +202,8 @@ impl Feature {
         match self {
             Self::Dense(feat) => feat.distinct_value_count(),
             Self::Sparse(feat) => feat.distinct_value_count(),
+            Self::Mapped(feat)
+                => inner_distinct_value_count(feat.values().to_vec()),
         }
     }
 
@@ -148,6 +219,7 @@ impl Feature {
         match self {
             Self::Dense(feat) => feat.weighted_mean(weight),
             Self::Sparse(feat) => feat.weighted_mean(weight),
+            Self::Mapped(feat) => dense_weighted_mean(feat.values(), weight),
         }
     }
 
@@ -163,6 +235,8 @@ impl Feature {
         let variance = match self {
             Self::Dense(feat) => feat.weighted_variance(mean, weight),
             Self::Sparse(feat) => feat.weighted_variance(mean, weight),
+            Self::Mapped(feat)
+                => dense_weighted_variance(feat.values(), mean, weight),
         };
         (mean, variance)
     }
@@ -184,6 +258,9 @@ impl Feature {
                 => feat.weighted_mean_for_label(y, target, weight),
             Self::Sparse(feat)
                 => feat.weighted_mean_for_label(y, target, weight),
+            Self::Mapped(feat) => dense_weighted_mean_for_label(
+                feat.values(), y, target, weight
+            ),
         }
     }
 
@@ -206,6 +283,9 @@ impl Feature {
                 => feat.weighted_variance_for_label(mean, y, target, weight),
             Self::Sparse(feat)
                 => feat.weighted_variance_for_label(mean, y, target, weight),
+            Self::Mapped(feat) => dense_weighted_variance_for_label(
+                feat.values(), mean, y, target, weight
+            ),
         };
         (mean, var)
     }
@@ -242,19 +322,28 @@ impl DenseFeature {
 
 
     /// Convert `polars::Series` into `DenseFeature`.
+    /// The null values are missing values, `f64::NAN`.
     pub fn from_series(series: &Series) -> Self {
         let name = series.name().to_string();
 
         let sample = series.f64()
             .expect("The series is not a dtype f64")
             .into_iter()
-            .collect::<Option<Vec<_>>>()
-            .unwrap();
+            .map(|x| x.unwrap_or(f64::NAN))
+            .collect::<Vec<_>>();
 
         Self { name, sample, }
     }
 
 
+    /// Convert `SparseFeature` into `DenseFeature`.
+    pub fn from_sparse(feature: SparseFeature) -> Self {
+        let name = feature.name.clone();
+        let sample = feature.into_target();
+        Self { name, sample, }
+    }
+
+
     fn into_target(self) -> Vec<f64> {
         self.sample
     }
@@ -279,26 +368,19 @@ impl DenseFeature {
 
 
     fn distinct_value_count(&self) -> usize {
-        let values = self.sample[..].to_vec();
-        inner_distinct_value_count(values)
+        inner_distinct_value_count(self.sample[..].to_vec())
     }
 
 
     pub(self) fn weighted_mean(&self, weight: &[f64]) -> f64 {
-        self.sample.iter()
-            .zip(weight)
-            .map(|(f, w)| f * w)
-            .sum::<f64>()
+        dense_weighted_mean(&self.sample, weight)
     }
 
 
     pub(self) fn weighted_variance(&self, mean: f64, weight: &[f64])
         -> f64
     {
-        self.sample.iter()
-            .zip(weight)
-            .map(|(f, w)| w * (f - mean).powi(2))
-            .sum::<f64>()
+        dense_weighted_variance(&self.sample, mean, weight)
     }
 
 
@@ -311,14 +393,7 @@ impl DenseFeature {
         weight: &[f64],
     ) -> f64
     {
-        let mut total_weight = utils::total_weight_for_label(y, target, weight);
-        if total_weight == 0.0 { total_weight = MINIMAL_WEIGHT_SUM; }
-        self.sample.iter()
-            .zip(target)
-            .zip(weight)
-            .map(|((f, t), w)| if *t != y { 0.0 } else { f * w })
-            .sum::<f64>()
-            / total_weight
+        dense_weighted_mean_for_label(&self.sample, y, target, weight)
     }
 
 
@@ -332,16 +407,75 @@ impl DenseFeature {
         weight: &[f64],
     ) -> f64
     {
-        let mut total_weight = utils::total_weight_for_label(y, target, weight);
-        if total_weight == 0.0 { total_weight = MINIMAL_WEIGHT_SUM; }
-        self.sample.iter()
-            .zip(target)
-            .zip(weight)
-            .map(|((f, t), w)| {
-                if *t != y { 0.0 } else { w * (f - mean).powi(2) }
-            })
-            .sum::<f64>()
-            / total_weight
+        dense_weighted_variance_for_label(
+            &self.sample, mean, y, target, weight
+        )
+    }
+}
+
+
+impl MappedFeature {
+    /// Construct a feature of `n_sample` values
+    /// stored at `offset` bytes of `map`.
+    /// Returns `None` if the values are out of `map`
+    /// or `offset` is not aligned to `f64`.
+    pub(crate) fn new(
+        name: String,
+        map: Arc<Mmap>,
+        offset: usize,
+        n_sample: usize,
+    ) -> Option<Self>
+    {
+        let end = n_sample.checked_mul(std::mem::size_of::<f64>())?
+            .checked_add(offset)?;
+        let bytes = map.get(offset..end)?;
+        bytemuck::try_cast_slice::<u8, f64>(bytes).ok()?;
+        Some(Self { name, map, offset, n_sample })
+    }
+
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+
+    pub(self) fn replace_name<S>(&mut self, name: S) -> String
+        where S: ToString,
+    {
+        let name = name.to_string();
+        std::mem::replace(&mut self.name, name)
+    }
+
+
+    /// Returns the feature values in the mapped file.
+    pub fn values(&self) -> &[f64] {
+        let end = self.offset + self.n_sample * std::mem::size_of::<f64>();
+        bytemuck::cast_slice(&self.map[self.offset..end])
+    }
+
+
+    /// Returns an iterator over feature values.
+    pub fn iter(&self) -> Iter<'_, f64> {
+        self.values().iter()
+    }
+
+
+    /// Returns the number of items in this feature.
+    pub fn len(&self) -> usize {
+        self.n_sample
+    }
+
+
+    /// Returns `true` if `self.len()` is equals to `0`.
+    pub fn is_empty(&self) -> bool {
+        self.n_sample == 0
+    }
+
+
+    /// Copies the values into the memory.
+    fn into_dense(self) -> DenseFeature {
+        let sample = self.values().to_vec();
+        DenseFeature { name: self.name, sample, }
     }
 }
 
@@ -357,6 +491,19 @@ impl SparseFeature {
     }
 
 
+    /// Convert `DenseFeature` into `SparseFeature`.
+    /// The missing values, `f64::NAN`, are kept as non-zero values.
+    pub fn from_dense(feature: DenseFeature) -> Self {
+        let n_sample = feature.len();
+        let sample = feature.sample
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, x)| x != 0f64)
+            .collect::<Vec<_>>();
+        Self { name: feature.name, sample, n_sample, }
+    }
+
+
     /// Append an example to this feature.
     pub fn append(&mut self, (i, x): (usize, f64)) {
         self.sample.push((i, x));
@@ -450,10 +597,8 @@ impl SparseFeature {
         let mut total_weight = utils::total_weight_for_label(y, target, weight);
         if total_weight == 0.0 { total_weight = MINIMAL_WEIGHT_SUM; }
         self.sample.iter()
-            .zip(target)
-            .map(|((i, f), &t)|
-                if t == y { 0.0 } else { weight[*i] * f }
-            )
+            .filter(|(i, _)| target[*i] == y)
+            .map(|&(i, f)| weight[i] * f)
             .sum::<f64>()
             / total_weight
     }
@@ -493,18 +638,24 @@ impl SparseFeature {
         let mut total_weight = utils::total_weight_for_label(y, target, weight);
         if total_weight == 0.0 { total_weight = MINIMAL_WEIGHT_SUM; }
 
+        // The total weight of the zero values whose label is `y`.
+        let zero_weight_for_label = |range: std::ops::Range<usize>| {
+            range.filter(|&k| target[k] == y)
+                .map(|k| weight[k])
+                .sum::<f64>()
+        };
         let mut variance = 0.0;
         let mut zero_weight = 0.0;
         let mut prev = 0;
         for &(i, f) in self.sample.iter() {
-            zero_weight += weight[prev..i].iter().sum::<f64>();
+            zero_weight += zero_weight_for_label(prev..i);
             prev = i + 1;
             if target[i] == y {
                 variance += weight[i] * (f - mean).powi(2);
             }
         }
 
-        zero_weight += weight[prev..].iter().sum::<f64>();
+        zero_weight += zero_weight_for_label(prev..self.n_sample);
 
         variance += zero_weight * mean.powi(2);
         variance / total_weight
@@ -518,6 +669,7 @@ impl Index<usize> for Feature {
         match self {
             Self::Dense(feat)  => &feat[idx],
             Self::Sparse(feat) => &feat[idx],
+            Self::Mapped(feat) => &feat[idx],
         }
     }
 }
@@ -526,6 +678,8 @@ impl Index<usize> for Feature {
 /// Count the number of items in `src` that has the same value.
 /// The given vector `src` is assumed to be sorted in ascending order.
 fn inner_distinct_value_count(mut src: Vec<f64>) -> usize {
+    // Missing values are not counted.
+    src.retain(|v| !v.is_nan());
     src.sort_by(|a, b| a.partial_cmp(&b).unwrap());
     let mut iter = src.into_iter();
     let mut value = match iter.next() {
@@ -545,6 +699,61 @@ fn inner_distinct_value_count(mut src: Vec<f64>) -> usize {
 }
 
 
+fn dense_weighted_mean(values: &[f64], weight: &[f64]) -> f64 {
+    values.iter()
+        .zip(weight)
+        .map(|(f, w)| f * w)
+        .sum::<f64>()
+}
+
+
+fn dense_weighted_variance(values: &[f64], mean: f64, weight: &[f64]) -> f64 {
+    values.iter()
+        .zip(weight)
+        .map(|(f, w)| w * (f - mean).powi(2))
+        .sum::<f64>()
+}
+
+
+fn dense_weighted_mean_for_label(
+    values: &[f64],
+    y: f64,
+    target: &[f64],
+    weight: &[f64],
+) -> f64
+{
+    let mut total_weight = utils::total_weight_for_label(y, target, weight);
+    if total_weight == 0.0 { total_weight = MINIMAL_WEIGHT_SUM; }
+    values.iter()
+        .zip(target)
+        .zip(weight)
+        .map(|((f, t), w)| if *t != y { 0.0 } else { f * w })
+        .sum::<f64>()
+        / total_weight
+}
+
+
+fn dense_weighted_variance_for_label(
+    values: &[f64],
+    mean: f64,
+    y: f64,
+    target: &[f64],
+    weight: &[f64],
+) -> f64
+{
+    let mut total_weight = utils::total_weight_for_label(y, target, weight);
+    if total_weight == 0.0 { total_weight = MINIMAL_WEIGHT_SUM; }
+    values.iter()
+        .zip(target)
+        .zip(weight)
+        .map(|((f, t), w)| {
+            if *t != y { 0.0 } else { w * (f - mean).powi(2) }
+        })
+        .sum::<f64>()
+        / total_weight
+}
+
+
 impl Index<usize> for DenseFeature {
     type Output = f64;
     fn index(&self, idx: usize) -> &Self::Output {
@@ -553,6 +762,14 @@ impl Index<usize> for DenseFeature {
 }
 
 
+impl Index<usize> for MappedFeature {
+    type Output = f64;
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.values()[idx]
+    }
+}
+
+
 impl Index<usize> for SparseFeature {
     type Output = f64;
     fn index(&self, idx: usize) -> &Self::Output {
@@ -0,0 +1,184 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+
+/// A class label as written in the data,
+/// which is either a number or a string.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Label {
+    /// A numerical label such as `0`, `1`, or `-1`.
+    Number(f64),
+    /// A non-numerical label such as `spam`.
+    Text(String),
+}
+
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(y) => write!(f, "{y}"),
+            Self::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+
+/// The mapping from the original class labels of a sample
+/// to the target values the boosters train on.
+///
+/// - If the labels take exactly 2 kinds,
+///   the smaller one is mapped to `-1` and the larger one to `+1`.
+///   For example, `{0, 1}` is mapped to `{-1, +1}` and
+///   `{no, yes}` is mapped to `{-1, +1}`.
+/// - Otherwise, the string labels are mapped to `0, 1, ..., k-1`
+///   in the ascending order,
+///   and the integer labels are kept as they are.
+///
+/// [`SampleReader`](crate::SampleReader) builds this mapping,
+/// and [`Sample::label_map`](crate::Sample::label_map) returns it.
+/// The `Display` implementation prints the mapping as a report.
+/// Wrap a trained hypothesis with
+/// [`LabeledClassifier`](crate::LabeledClassifier)
+/// to predict the original labels.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LabelMap {
+    // The original labels in the ascending order.
+    labels: Vec<Label>,
+    // The target value of each label.
+    values: Vec<f64>,
+}
+
+
+impl LabelMap {
+    /// Builds the mapping of the distinct labels sorted in
+    /// the ascending order.
+    fn new(labels: Vec<Label>) -> Self {
+        let values = if labels.len() == 2 {
+            vec![-1f64, 1f64]
+        } else {
+            labels.iter()
+                .enumerate()
+                .map(|(k, label)| match label {
+                    Label::Number(y) => *y,
+                    Label::Text(_) => k as f64,
+                })
+                .collect()
+        };
+        Self { labels, values }
+    }
+
+
+    /// Returns the mapping of the numerical labels `target`,
+    /// or `None` if `target` is not a class label,
+    /// i.e., it takes more than 2 kinds of values
+    /// and some of them are not integers.
+    /// Missing values, `f64::NAN`, are ignored.
+    pub(super) fn from_numbers(target: &[f64]) -> Option<Self> {
+        let mut labels = target.iter()
+            .copied()
+            .filter(|y| !y.is_nan())
+            .collect::<Vec<_>>();
+        labels.sort_by(f64::total_cmp);
+        labels.dedup();
+        if labels.len() > 2 && labels.iter().any(|y| y.fract() != 0f64) {
+            return None;
+        }
+        let labels = labels.into_iter()
+            .map(Label::Number)
+            .collect();
+        Some(Self::new(labels))
+    }
+
+
+    /// Converts the cells of a target column into the target values.
+    /// If all the non-empty cells are numbers,
+    /// this function returns them as they are without a mapping.
+    /// Otherwise, all the cells are string labels,
+    /// which are mapped by the returned mapping.
+    /// An empty cell is an unlabeled row, `f64::NAN`.
+    pub(super) fn encode_cells(cells: &[String]) -> (Vec<f64>, Option<Self>) {
+        let numbers = cells.iter()
+            .map(|cell| {
+                let cell = cell.trim();
+                if cell.is_empty() { return Some(f64::NAN); }
+                cell.parse().ok()
+            })
+            .collect::<Option<Vec<f64>>>();
+        if let Some(numbers) = numbers {
+            return (numbers, None);
+        }
+
+        let mut labels = cells.iter()
+            .map(|cell| cell.trim())
+            .filter(|cell| !cell.is_empty())
+            .collect::<Vec<_>>();
+        labels.sort();
+        labels.dedup();
+        let labels = labels.into_iter()
+            .map(|label| Label::Text(label.to_string()))
+            .collect();
+        let map = Self::new(labels);
+
+        let target = cells.iter()
+            .map(|cell| {
+                let cell = cell.trim();
+                if cell.is_empty() { return f64::NAN; }
+                map.value(&Label::Text(cell.to_string()))
+                    .expect("Every label is in the mapping")
+            })
+            .collect();
+        (target, Some(map))
+    }
+
+
+    /// Maps the numerical labels `target` in place.
+    pub(super) fn apply(&self, target: &mut [f64]) {
+        target.iter_mut()
+            .filter(|y| !y.is_nan())
+            .for_each(|y| {
+                *y = self.value(&Label::Number(*y))
+                    .expect("Every label is in the mapping");
+            });
+    }
+
+
+    /// Returns the original labels in the ascending order.
+    pub fn labels(&self) -> &[Label] {
+        &self.labels[..]
+    }
+
+
+    /// Returns the pairs of an original label and its target value.
+    pub fn iter(&self) -> impl Iterator<Item = (&Label, f64)> + '_ {
+        self.labels.iter().zip(self.values.iter().copied())
+    }
+
+
+    /// Returns the target value of the original label `label`,
+    /// or `None` if `label` is unknown.
+    pub fn value(&self, label: &Label) -> Option<f64> {
+        self.labels.iter()
+            .position(|l| l == label)
+            .map(|k| self.values[k])
+    }
+
+
+    /// Returns the original label of the target value `value`,
+    /// or `None` if no label is mapped to `value`.
+    pub fn label(&self, value: f64) -> Option<&Label> {
+        self.values.iter()
+            .position(|&v| v == value)
+            .map(|k| &self.labels[k])
+    }
+}
+
+
+impl fmt::Display for LabelMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Label mapping ({} classes):", self.labels.len())?;
+        for (label, value) in self.iter() {
+            writeln!(f, "    {label} -> {value:+}")?;
+        }
+        Ok(())
+    }
+}
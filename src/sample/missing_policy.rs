@@ -0,0 +1,142 @@
+use std::io;
+
+use super::feature_struct::Feature;
+use super::sample_struct::{Sample, invalid_data};
+
+
+/// The tokens read as missing values by default,
+/// in addition to the empty cells.
+pub(super) const DEFAULT_MISSING_TOKENS: [&str; 2] = ["NA", "N/A"];
+
+
+/// A policy for the missing values of the features,
+/// passed to [`SampleReader::missing_policy`]
+/// and [`Sample::impute_missing`].
+///
+/// [`SampleReader::missing_policy`]: crate::SampleReader::missing_policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingPolicy {
+    /// Keeps the missing values as `f64::NAN`.
+    /// The tree weak learners handle them
+    /// by learning the default direction of each split.
+    #[default]
+    KeepNaN,
+    /// Returns `Err` if a feature has a missing value.
+    Error,
+    /// Replaces the missing values of each feature
+    /// with the mean of its non-missing values.
+    ImputeMean,
+    /// Replaces the missing values of each feature
+    /// with the median of its non-missing values.
+    ImputeMedian,
+}
+
+
+impl Sample {
+    /// Handles the missing values of the features
+    /// according to `policy`.
+    /// The missing values of the target are the unlabeled rows
+    /// (see [`Sample::is_unlabeled`]), which are not changed.
+    ///
+    /// The imputation does not change the categorical features
+    /// (see [`Sample::set_categorical`]) and
+    /// the features whose values are all missing.
+    ///
+    /// This method returns `Err` if `policy` is
+    /// [`MissingPolicy::Error`] and a feature has a missing value.
+    pub fn impute_missing(mut self, policy: MissingPolicy)
+        -> io::Result<Self>
+    {
+        let n_sample = self.shape().0;
+        let missing = |feature: &Feature| {
+            (0..n_sample).any(|i| feature[i].is_nan())
+        };
+        match policy {
+            MissingPolicy::KeepNaN => return Ok(self),
+            MissingPolicy::Error => {
+                let feature = self.features()
+                    .iter()
+                    .find(|feature| missing(feature));
+                if let Some(feature) = feature {
+                    return Err(invalid_data(format!(
+                        "The feature `{}` has missing values",
+                        feature.name()
+                    )));
+                }
+                return Ok(self);
+            },
+            _ => {},
+        }
+
+        let fills = self.features()
+            .iter()
+            .map(|feature| {
+                if self.is_categorical(feature.name()) || !missing(feature) {
+                    return None;
+                }
+                let mut values = (0..n_sample)
+                    .map(|i| feature[i])
+                    .filter(|x| !x.is_nan())
+                    .collect::<Vec<_>>();
+                if values.is_empty() { return None; }
+                let fill = match policy {
+                    MissingPolicy::ImputeMean => mean(&values),
+                    _ => median(&mut values),
+                };
+                Some(fill)
+            })
+            .collect::<Vec<_>>();
+        if fills.iter().all(Option::is_none) { return Ok(self); }
+
+        let features = self.features_mut();
+        *features = std::mem::take(features).into_iter()
+            .zip(fills)
+            .map(|(feature, fill)| match fill {
+                Some(fill) => fill_missing(feature, fill),
+                None => feature,
+            })
+            .collect();
+        Ok(self)
+    }
+}
+
+
+/// Replaces the missing values of `feature` with `fill`.
+fn fill_missing(feature: Feature, fill: f64) -> Feature {
+    match feature {
+        Feature::Dense(mut feature) => {
+            feature.sample.iter_mut()
+                .filter(|x| x.is_nan())
+                .for_each(|x| { *x = fill; });
+            Feature::Dense(feature)
+        },
+        Feature::Sparse(mut feature) => {
+            // A sparse feature does not store zeros.
+            if fill == 0f64 {
+                feature.sample.retain(|(_, x)| !x.is_nan());
+            } else {
+                feature.sample.iter_mut()
+                    .filter(|(_, x)| x.is_nan())
+                    .for_each(|(_, x)| { *x = fill; });
+            }
+            Feature::Sparse(feature)
+        },
+        Feature::Mapped(_) => fill_missing(feature.into_dense(), fill),
+    }
+}
+
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let n = values.len();
+    if n % 2 == 1 {
+        values[n / 2]
+    } else {
+        (values[n / 2 - 1] + values[n / 2]) / 2f64
+    }
+}
@@ -0,0 +1,225 @@
+//! Provides [`MultiLabelSample`],
+//! the target format for multi-label boosting algorithms.
+use polars::prelude::*;
+
+use std::io;
+
+use super::sample_struct::Sample;
+
+
+/// A sample whose examples have a set of relevant labels.
+///
+/// `MultiLabelSample` holds the examples in the **expanded** form:
+/// each pair `(x_i, l)` of an example `x_i` and a label `l`
+/// becomes a row of a binary [`Sample`].
+/// The row has the features of `x_i`,
+/// the one-hot indicator columns `Label [name]` of `l`,
+/// and the target `+1` if `l` is relevant to `x_i`, `-1` otherwise.
+/// The row of `(x_i, l)` is `i * n_labels + l`.
+///
+/// Thus, any weak learner for the binary [`Sample`]
+/// can be used for the multi-label boosting algorithms
+/// by building it from [`MultiLabelSample::sample`].
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use polars::prelude::*;
+///
+/// let data = df!(
+///     "x" => [1.0, 2.0, 3.0],
+///     "y" => [0.5, 0.1, 0.9],
+/// ).unwrap();
+/// // Each column is a label. Positive values are relevant.
+/// let targets = df!(
+///     "sports"   => [1.0, 0.0, 1.0],
+///     "politics" => [0.0, 1.0, 1.0],
+/// ).unwrap();
+/// let sample = MultiLabelSample::from_dataframe(data, targets).unwrap();
+/// assert_eq!(sample.sample().shape(), (6, 4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiLabelSample {
+    sample: Sample,
+    labels: Vec<String>,
+    n_examples: usize,
+}
+
+
+impl MultiLabelSample {
+    /// Construct a new `MultiLabelSample`
+    /// from the features `data` and the labels `targets`.
+    /// Each column of `targets` corresponds to a label,
+    /// and a positive value means that the label is relevant.
+    pub fn from_dataframe(data: DataFrame, targets: DataFrame)
+        -> io::Result<Self>
+    {
+        let n_examples = data.height();
+        if targets.height() != n_examples {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The features and the targets have different # of rows",
+            ));
+        }
+        if targets.width() == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "No label is given",
+            ));
+        }
+        let labels = targets.get_column_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let n_labels = labels.len();
+
+
+        let mut columns = Vec::with_capacity(data.width() + n_labels);
+        for series in data.get_columns() {
+            let values = column_to_vec(series)?;
+            let expanded = values.into_iter()
+                .flat_map(|v| std::iter::repeat_n(v, n_labels))
+                .collect::<Vec<_>>();
+            columns.push(Series::new(series.name(), expanded));
+        }
+        for (l, label) in labels.iter().enumerate() {
+            let indicator = (0..n_examples * n_labels)
+                .map(|row| if row % n_labels == l { 1f64 } else { 0f64 })
+                .collect::<Vec<_>>();
+            columns.push(Series::new(&format!("Label [{label}]"), indicator));
+        }
+        let data = DataFrame::new(columns)
+            .map_err(io::Error::other)?;
+
+
+        let targets = targets.get_columns()
+            .iter()
+            .map(column_to_vec)
+            .collect::<io::Result<Vec<_>>>()?;
+        let target = (0..n_examples)
+            .flat_map(|i| {
+                targets.iter()
+                    .map(move |t| if t[i] > 0f64 { 1f64 } else { -1f64 })
+            })
+            .collect::<Vec<_>>();
+        let target = Series::new("label", target);
+
+        let sample = Sample::from_dataframe(data, target)?;
+
+        Ok(Self { sample, labels, n_examples })
+    }
+
+
+    /// Construct a new `MultiLabelSample` from a multiclass `sample`.
+    /// Each class of `sample.target()` becomes a label
+    /// named after the class value,
+    /// and the class of each example is its only relevant label.
+    /// The labels are sorted in ascending order of the class values.
+    pub fn from_multiclass(sample: &Sample) -> io::Result<Self> {
+        let classes = sample.unique_target();
+        if classes.len() < 2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The sample has less than 2 classes",
+            ));
+        }
+        let n_sample = sample.shape().0;
+
+        let columns = sample.features()
+            .iter()
+            .map(|feat| {
+                let values = (0..n_sample)
+                    .map(|i| feat[i])
+                    .collect::<Vec<_>>();
+                Series::new(feat.name(), values)
+            })
+            .collect::<Vec<_>>();
+        let data = DataFrame::new(columns)
+            .map_err(io::Error::other)?;
+
+        let target = sample.target();
+        let columns = classes.iter()
+            .map(|&class| {
+                let indicator = target.iter()
+                    .map(|&y| if y == class { 1f64 } else { 0f64 })
+                    .collect::<Vec<_>>();
+                Series::new(&format!("{class}"), indicator)
+            })
+            .collect::<Vec<_>>();
+        let targets = DataFrame::new(columns)
+            .map_err(io::Error::other)?;
+
+        Self::from_dataframe(data, targets)
+    }
+
+
+    /// Returns the expanded binary sample.
+    /// Build the weak learner from this sample.
+    pub fn sample(&self) -> &Sample {
+        &self.sample
+    }
+
+
+    /// Returns the names of the labels.
+    pub fn labels(&self) -> &[String] {
+        &self.labels[..]
+    }
+
+
+    /// Returns the number of examples (before the expansion).
+    pub fn n_examples(&self) -> usize {
+        self.n_examples
+    }
+
+
+    /// Returns the number of labels.
+    pub fn n_labels(&self) -> usize {
+        self.labels.len()
+    }
+
+
+    /// Returns the row of the expanded sample
+    /// that corresponds to the pair `(i, label)`.
+    pub fn row(&self, i: usize, label: usize) -> usize {
+        i * self.n_labels() + label
+    }
+
+
+    /// Returns `true` if `label` is relevant to the `i`-th example.
+    pub fn is_relevant(&self, i: usize, label: usize) -> bool {
+        self.sample.target()[self.row(i, label)] > 0f64
+    }
+
+
+    /// Returns the relevant labels of the `i`-th example.
+    pub fn relevant_labels(&self, i: usize) -> Vec<usize> {
+        (0..self.n_labels())
+            .filter(|&l| self.is_relevant(i, l))
+            .collect()
+    }
+
+
+    /// Returns the irrelevant labels of the `i`-th example.
+    pub fn irrelevant_labels(&self, i: usize) -> Vec<usize> {
+        (0..self.n_labels())
+            .filter(|&l| !self.is_relevant(i, l))
+            .collect()
+    }
+}
+
+
+fn column_to_vec(series: &Series) -> io::Result<Vec<f64>> {
+    let name = series.name();
+    series.cast(&DataType::Float64)
+        .map_err(io::Error::other)?
+        .f64()
+        .map_err(io::Error::other)?
+        .into_iter()
+        .map(|v| v.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The column `{name}` has a null value"),
+            )
+        }))
+        .collect()
+}
@@ -0,0 +1,190 @@
+use std::path::Path;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Lines};
+
+use super::sample_struct::{
+    Sample,
+    default_names,
+    invalid_data,
+    invalid_input,
+    is_missing,
+    parse_cell,
+};
+
+
+/// An iterator over the blocks of the rows of a CSV file.
+/// Each block is a [`Sample`] of at most `chunk_size` rows
+/// with the same features,
+/// so that one can process a file larger than the memory
+/// block by block.
+/// This struct is created by [`SampleReader::read_chunks`].
+///
+/// [`SampleReader::read_chunks`]: crate::SampleReader::read_chunks
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// let chunks = SampleReader::new()
+///     .file("/path/to/large/file.csv")
+///     .has_header(true)
+///     .target_feature("class")
+///     .chunk_size(10_000)
+///     .read_chunks()
+///     .unwrap();
+/// for chunk in chunks {
+///     let chunk = chunk.unwrap();
+///     println!("Read {} rows", chunk.shape().0);
+/// }
+/// ```
+pub struct SampleChunks {
+    lines: Lines<BufReader<File>>,
+    // The first row of a file without the header row.
+    first: Option<String>,
+    names: Vec<String>,
+    target: usize,
+    chunk_size: usize,
+    // The tokens of the missing values.
+    missing: Vec<String>,
+    // The number of the lines read so far.
+    n_line: usize,
+}
+
+
+impl SampleChunks {
+    /// Opens the CSV file `file` and reads its header row
+    /// if `has_header` is `true`.
+    /// The empty cells and the cells in `missing` are missing values,
+    /// which are not allowed in the target column.
+    /// Otherwise, the columns are named
+    /// `Feat. [1]`, `Feat. [2]`, ..., as in [`SampleReader::read`].
+    ///
+    /// [`SampleReader::read`]: crate::SampleReader::read
+    pub(super) fn new<P: AsRef<Path>>(
+        file: P,
+        has_header: bool,
+        target: &str,
+        chunk_size: usize,
+        missing: Vec<String>,
+    ) -> io::Result<Self>
+    {
+        assert!(chunk_size > 0, "The chunk size must be positive");
+        let file = File::open(file)?;
+        let mut lines = BufReader::new(file).lines();
+
+        let mut first = None;
+        let mut n_line = 0;
+        let names = if has_header {
+            n_line += 1;
+            match lines.next() {
+                Some(line) => line?.split(',').map(String::from).collect(),
+                None => Vec::new(),
+            }
+        } else {
+            let line = next_row(&mut lines, &mut n_line).transpose()?;
+            let n_column = line.as_ref()
+                .map_or(0, |line| line.split(',').count());
+            first = line;
+            default_names(n_column)
+        };
+        let target = names.iter()
+            .position(|name| name == target)
+            .ok_or_else(|| {
+                invalid_input(format!("Unknown target column `{target}`"))
+            })?;
+
+        Ok(Self { lines, first, names, target, chunk_size, missing, n_line })
+    }
+
+
+    /// Returns the names of the features, i.e.,
+    /// the columns except the target column.
+    pub fn feature_names(&self) -> Vec<&str> {
+        self.names.iter()
+            .enumerate()
+            .filter(|&(j, _)| j != self.target)
+            .map(|(_, name)| name.as_str())
+            .collect()
+    }
+
+
+    /// Reads the next block of at most `chunk_size` rows.
+    fn read_chunk(&mut self) -> io::Result<Option<Sample>> {
+        let n_column = self.names.len();
+        let mut columns = vec![Vec::new(); n_column];
+        let mut n_row = 0;
+        while n_row < self.chunk_size {
+            let line = match self.first.take() {
+                Some(line) => line,
+                None => match next_row(&mut self.lines, &mut self.n_line) {
+                    Some(line) => line?,
+                    None => break,
+                },
+            };
+
+            if line.split(',').count() != n_column {
+                return Err(invalid_data(format!(
+                    "Expected {n_column} cells in Line {}", self.n_line
+                )));
+            }
+            let cells = columns.iter_mut().zip(line.split(','));
+            for (j, (column, cell)) in cells.enumerate() {
+                if j == self.target && is_missing(cell, &self.missing) {
+                    return Err(invalid_data(format!(
+                        "The target value is missing in Line {}", self.n_line
+                    )));
+                }
+                column.push(parse_cell(cell, self.n_line, &self.missing)?);
+            }
+            n_row += 1;
+        }
+        if n_row == 0 { return Ok(None); }
+
+        let target = columns.remove(self.target);
+        let names = self.feature_names()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        Ok(Some(Sample::from_columns(names, columns, target)))
+    }
+}
+
+
+impl Iterator for SampleChunks {
+    type Item = io::Result<Sample>;
+
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_chunk().transpose()
+    }
+}
+
+
+/// Returns the next non-empty line of `lines`.
+fn next_row(lines: &mut Lines<BufReader<File>>, n_line: &mut usize)
+    -> Option<io::Result<String>>
+{
+    for line in lines.by_ref() {
+        *n_line += 1;
+        match line {
+            Ok(line) if line.trim().is_empty() => continue,
+            line => return Some(line),
+        }
+    }
+    None
+}
+
+
+/// Returns the number of the non-empty lines of `file`.
+pub(super) fn count_rows<P: AsRef<Path>>(file: P, has_header: bool)
+    -> io::Result<usize>
+{
+    let file = File::open(file)?;
+    let mut lines = BufReader::new(file).lines();
+    if has_header { lines.next().transpose()?; }
+    let mut n_row = 0;
+    for line in lines {
+        if !line?.trim().is_empty() { n_row += 1; }
+    }
+    Ok(n_row)
+}
@@ -2,6 +2,14 @@ use std::path::Path;
 use std::io;
 
 use super::sample_struct::Sample;
+use super::missing_policy::{MissingPolicy, DEFAULT_MISSING_TOKENS};
+use super::sample_chunks::{SampleChunks, count_rows};
+use super::column_store::{StoreHeader, StoreWriter};
+
+
+/// The default number of the rows of a block
+/// read by [`SampleReader::read_chunks`].
+const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
 
 
 /// A struct that returns [`Sample`].
@@ -21,6 +29,12 @@ pub struct SampleReader<P, S> {
     file: Option<P>,
     has_header: bool,
     target: Option<S>,
+    weight: Option<S>,
+    group: Option<S>,
+    chunk_size: usize,
+    normalize_labels: bool,
+    missing: Vec<String>,
+    missing_policy: MissingPolicy,
 }
 
 
@@ -31,6 +45,12 @@ impl<P, S> SampleReader<P, S> {
             file: None,
             has_header: false,
             target: None,
+            weight: None,
+            group: None,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            normalize_labels: true,
+            missing: DEFAULT_MISSING_TOKENS.map(String::from).to_vec(),
+            missing_policy: MissingPolicy::KeepNaN,
         }
     }
 
@@ -41,6 +61,59 @@ impl<P, S> SampleReader<P, S> {
         self.has_header = flag;
         self
     }
+
+
+    /// Set the number of the rows of a block
+    /// read by [`SampleReader::read_chunks`]
+    /// and [`SampleReader::build_store`].
+    /// Default is `65536`.
+    pub fn chunk_size(mut self, size: usize) -> Self {
+        assert!(size > 0, "The chunk size must be positive");
+        self.chunk_size = size;
+        self
+    }
+
+
+    /// Set the flag whether to map the class labels
+    /// to the target values boosters train on.
+    /// If `true`, the labels of 2 kinds, such as `{0, 1}`,
+    /// are mapped to `{-1, +1}`
+    /// (see [`Sample::normalize_labels`]).
+    /// String labels in a CSV file are always mapped.
+    /// The mapping is returned by [`Sample::label_map`].
+    /// Default is `true`.
+    ///
+    /// Set `false` to read the target values of regression
+    /// that take only 2 kinds of values.
+    pub fn normalize_labels(mut self, flag: bool) -> Self {
+        self.normalize_labels = flag;
+        self
+    }
+
+
+    /// Add the tokens read as missing values in a CSV file,
+    /// such as `?` or `-999`.
+    /// The empty cells, `NA`, and `N/A` are always missing values.
+    /// A missing feature value is `f64::NAN`,
+    /// and a missing target value is an error.
+    pub fn missing_tokens<I, T>(mut self, tokens: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: ToString,
+    {
+        self.missing.extend(tokens.into_iter().map(|t| t.to_string()));
+        self
+    }
+
+
+    /// Set the policy for the missing values of the features.
+    /// See [`MissingPolicy`] and [`Sample::impute_missing`].
+    /// [`SampleReader::read_chunks`] and [`SampleReader::build_store`]
+    /// keep the missing values regardless of the policy.
+    /// Default is [`MissingPolicy::KeepNaN`].
+    pub fn missing_policy(mut self, policy: MissingPolicy) -> Self {
+        self.missing_policy = policy;
+        self
+    }
 }
 
 
@@ -59,11 +132,36 @@ impl<P, S> SampleReader<P, S>
     where S: AsRef<str>
 {
     /// Set the column name that is used for target label.
-    /// The each item of the column takes value in `{-1, +1}.`
+    /// The column may contain numerical labels such as `{0, 1}`
+    /// or string labels such as `{spam, ham}`
+    /// (see [`SampleReader::normalize_labels`]).
     pub fn target_feature(mut self, column: S) -> Self {
         self.target = Some(column);
         self
     }
+
+
+    /// Set the column name that is used for the instance weights.
+    /// The column is removed from the features
+    /// (see [`Sample::set_weights`]).
+    /// By default, the instance weights are not set.
+    pub fn weight_feature(mut self, column: S) -> Self {
+        self.weight = Some(column);
+        self
+    }
+
+
+    /// Set the column name that is used for the group ids,
+    /// such as the query ids for learning to rank.
+    /// The column is removed from the features
+    /// (see [`Sample::set_groups`]).
+    /// The group ids of a SVMLight file are read from
+    /// the `qid:id` words without this setter.
+    /// By default, the group ids are not set.
+    pub fn group_feature(mut self, column: S) -> Self {
+        self.group = Some(column);
+        self
+    }
 }
 
 
@@ -91,15 +189,95 @@ impl<P, S> SampleReader<P, S>
                 );
             }
             let target = self.target.unwrap();
-            Sample::from_csv(file, self.has_header)?
-                .set_target(target.as_ref())
+            Sample::from_csv(
+                file,
+                self.has_header,
+                target.as_ref(),
+                &self.missing,
+            )?
         } else {
             Sample::from_svmlight(file)?
         };
+        let sample = match self.weight {
+            Some(weight) => sample.set_weights(weight.as_ref()),
+            None => sample,
+        };
+        let sample = match self.group {
+            Some(group) => sample.set_groups(group.as_ref()),
+            None => sample,
+        };
+        let sample = sample.impute_missing(self.missing_policy)?;
+        if self.normalize_labels {
+            return Ok(sample.normalize_labels());
+        }
         Ok(sample)
     }
-}
 
 
+    /// Reads the CSV file block by block of rows.
+    /// Each block is a [`Sample`] of at most
+    /// [`chunk_size`](SampleReader::chunk_size) rows,
+    /// so that the file need not fit in memory.
+    /// See [`SampleChunks`].
+    ///
+    /// This method returns `Err` if the file is not a CSV file
+    /// or does not have the target column.
+    pub fn read_chunks(self) -> io::Result<SampleChunks> {
+        let (file, target) = self.csv_file_and_target()?;
+        SampleChunks::new(
+            file,
+            self.has_header,
+            target.as_ref(),
+            self.chunk_size,
+            self.missing.clone(),
+        )
+    }
+
+
+    /// Reads the CSV file block by block of rows
+    /// and writes the sample to the column store at `store`,
+    /// which [`Sample::open_mmap`] maps
+    /// and [`Sample::load_store`] reads.
+    /// At most [`chunk_size`](SampleReader::chunk_size) rows
+    /// are held in memory at a time.
+    ///
+    /// This method returns `Err` if the file is not a CSV file
+    /// or does not have the target column.
+    pub fn build_store<Q: AsRef<Path>>(self, store: Q) -> io::Result<()> {
+        let (file, _) = self.csv_file_and_target()?;
+        let n_sample = count_rows(file.as_ref(), self.has_header)?;
 
+        let chunks = self.read_chunks()?;
+        let names = chunks.feature_names()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let header = StoreHeader { n_sample, names };
+        let mut writer = StoreWriter::create(store, header)?;
+        for chunk in chunks {
+            writer.write_rows(&chunk?)?;
+        }
+        writer.finish()
+    }
 
+
+    fn csv_file_and_target(&self) -> io::Result<(&P, &S)> {
+        let file = self.file.as_ref()
+            .expect("The file name for csv/svmlight is not set");
+        let is_csv = file.as_ref()
+            .extension()
+            .is_some_and(|ext| ext == "csv");
+        if !is_csv {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Only CSV files are read block by block"
+            ));
+        }
+        let target = self.target.as_ref()
+            .expect(
+                "Target (class) column is not specified. \
+                Use `SampleReader::target`."
+            );
+        Ok((file, target))
+    }
+}
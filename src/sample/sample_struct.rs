@@ -4,13 +4,31 @@ use std::io::{self, BufRead, BufReader};
 use std::collections::{HashMap, HashSet};
 use std::ops::Index;
 use std::mem;
+use std::sync::{Arc, OnceLock};
 
 use polars::prelude::*;
 use rayon::prelude::*;
 use super::feature_struct::*;
+use super::sparse_rows::SparseRows;
+use super::label_map::LabelMap;
+
+
+/// The default density threshold of the features.
+/// A feature read from a CSV file or a `DataFrame`
+/// is stored in the sparse format
+/// if the fraction of its non-zero values is below this threshold.
+/// See [`Sample::with_sparse_threshold`].
+pub const DEFAULT_SPARSE_THRESHOLD: f64 = 0.1;
 
 
 /// Struct `Sample` holds a batch sample with dense/sparse format.
+/// Each feature is stored either in the dense format
+/// or in the sparse format,
+/// i.e., the compressed sparse column (CSC) format.
+/// The features with few non-zero values,
+/// such as one-hot encoded features or bag-of-words features,
+/// are stored in the sparse format automatically
+/// (see [`Sample::with_sparse_threshold`]).
 /// # Example
 /// The following code reads a CSV file and sets the column of name `"class"`
 /// as the target values.
@@ -24,10 +42,19 @@ use super::feature_struct::*;
 #[derive(Debug,Clone)]
 pub struct Sample {
     pub(super) name_to_index: HashMap<String, usize>,
-    pub(super) features: Vec<Feature>,
+    pub(super) features: Arc<Vec<Feature>>,
     pub(super) target: Vec<f64>,
     pub(super) n_sample: usize,
     pub(super) n_feature: usize,
+    pub(super) bags: Option<Vec<usize>>,
+    pub(super) weights: Option<Vec<f64>>,
+    pub(super) groups: Option<Vec<u32>>,
+    pub(super) categorical: HashSet<String>,
+    // The rows of the sparse features,
+    // which are built on the first access.
+    pub(super) rows: Arc<OnceLock<SparseRows>>,
+    // The mapping from the original class labels to the target values.
+    pub(super) label_map: Option<LabelMap>,
 }
 
 
@@ -43,25 +70,40 @@ impl Sample {
         features.push(Feature::new_sparse("dummy"));
         Self {
             name_to_index: HashMap::from([("dummy".to_string(), 0)]),
-            features,
+            features: Arc::new(features),
             target,
             n_sample,
             n_feature: 1usize,
+            bags: None,
+            weights: None,
+            groups: None,
+            categorical: HashSet::new(),
+            rows: Arc::default(),
+            label_map: None,
         }
     }
 
 
-    /// Read a CSV format file to [`Sample`] type.
-    /// This method returns `Err` if the file does not exist.
+    /// Read a CSV format file to [`Sample`] type
+    /// with the column of name `target` as the target values.
+    /// This method returns `Err` if the file does not exist,
+    /// does not have the target column,
+    /// or has a missing target value.
     /// 
     /// If the CSV file does not header row,
     /// this method assigns a default name for each column:
-    /// `Feat. [0]`, `Feat. [1]`, ..., `Feat. [n]`.
+    /// `Feat. [1]`, `Feat. [2]`, ..., `Feat. [n]`.
     /// 
-    /// **Do not forget** to call [`Sample::set_target`] to
-    /// assign the class label.
-    pub(super) fn from_csv<P>(file: P, mut has_header: bool)
-        -> io::Result<Self>
+    /// The target column may contain string labels,
+    /// which are mapped to the target values by a [`LabelMap`].
+    /// Numerical labels are kept as they are
+    /// (see [`Sample::normalize_labels`]).
+    pub(super) fn from_csv<P>(
+        file: P,
+        mut has_header: bool,
+        target: &str,
+        missing: &[String],
+    ) -> io::Result<Self>
         where P: AsRef<Path>,
     {
         // Open the given `file`.
@@ -75,6 +117,9 @@ impl Sample {
                 .map(DenseFeature::new)
                 .collect::<Vec<_>>();
         }
+        let mut pos = features.iter()
+            .position(|feat| feat.name == target);
+        let mut cells = Vec::new();
         let mut n_sample = 0_usize;
 
         // For each line of the file
@@ -85,50 +130,43 @@ impl Sample {
             // if the headeer does not exists,
             // construct a dummy header.
             if !has_header {
-                let xs = line.split(',')
-                    .map(|x| {
-                        x.trim().parse::<f64>()
-                            .unwrap_or_else(|_| {
-                                panic!(
-                                    "The file contains non-numerical value. \
-                                    Got {x} in Line {i}"
-                                )
-                            })
-                    })
-                    .collect::<Vec<_>>();
-
-                let n_feature = xs.len();
-                features = (1..=n_feature).map(|i| {
-                        let name = format!("Feat. [{i}]");
-                        DenseFeature::new(name)
-                    })
+                let n_feature = line.split(',').count();
+                features = default_names(n_feature).into_iter()
+                    .map(DenseFeature::new)
                     .collect::<Vec<_>>();
-
-                for (feat, x) in features.iter_mut().zip(xs) {
-                    feat.append(x);
-                }
-
+                pos = features.iter()
+                    .position(|feat| feat.name == target);
                 has_header = true;
-                n_sample += 1;
-                continue;
             }
 
-            line.split(',')
-                .map(|x| x.trim().parse::<f64>().unwrap())
-                .enumerate()
-                .for_each(|(i, x)| {
-                    features[i].append(x);
-                });
+            let pos = pos.ok_or_else(|| {
+                invalid_input(format!("Unknown target column `{target}`"))
+            })?;
+            for (j, x) in line.split(',').enumerate() {
+                if j != pos {
+                    features[j].append(parse_cell(x, i, missing)?);
+                } else if is_missing(x, missing) {
+                    return Err(invalid_data(format!(
+                        "The target value is missing in Line {i}"
+                    )));
+                } else {
+                    cells.push(x.to_string());
+                }
+            }
 
             n_sample += 1;
         }
+        let pos = pos.ok_or_else(|| {
+            invalid_input(format!("Unknown target column `{target}`"))
+        })?;
+        features.remove(pos);
 
         let features = features.into_par_iter()
-            .map(Feature::Dense)
+            .map(|feat| select_storage(Feature::Dense(feat)))
             .collect::<Vec<_>>();
 
         let n_feature = features.len();
-        let target = Vec::with_capacity(0);
+        let (target, label_map) = LabelMap::encode_cells(&cells);
 
         let name_to_index = features.iter()
             .enumerate()
@@ -136,7 +174,17 @@ impl Sample {
             .collect::<HashMap<_, _>>();
 
         let sample = Self {
-            name_to_index, features, target, n_sample, n_feature,
+            name_to_index,
+            features: Arc::new(features),
+            target,
+            n_sample,
+            n_feature,
+            bags: None,
+            weights: None,
+            groups: None,
+            categorical: HashSet::new(),
+            rows: Arc::default(),
+            label_map,
         };
 
         Ok(sample)
@@ -146,6 +194,8 @@ impl Sample {
     /// Convert [`DataFrame`] and [`Series`] to `Sample`.
     /// This method takes the ownership of the given pair of 
     /// `data` and `target`.
+    /// The rows with null target values are unlabeled
+    /// (see [`Sample::is_unlabeled`]).
     pub fn from_dataframe(data: DataFrame, target: Series)
         -> io::Result<Self>
     {
@@ -153,14 +203,15 @@ impl Sample {
         let target = target.f64()
             .expect("The target is not a dtype f64")
             .into_iter()
-            .collect::<Option<Vec<_>>>()
-            .unwrap();
+            .map(|y| y.unwrap_or(f64::NAN))
+            .collect::<Vec<_>>();
 
         let features = data.get_columns()
             .into_par_iter()
-            .map(|series| 
-                Feature::Dense(DenseFeature::from_series(series))
-            )
+            .map(|series| {
+                let feature = DenseFeature::from_series(series);
+                select_storage(Feature::Dense(feature))
+            })
             .collect::<Vec<_>>();
 
         let name_to_index = features.iter()
@@ -169,12 +220,170 @@ impl Sample {
             .collect::<HashMap<_, _>>();
 
         let sample = Self {
-            name_to_index, features, target, n_sample, n_feature,
+            name_to_index,
+            features: Arc::new(features),
+            target,
+            n_sample,
+            n_feature,
+            bags: None,
+            weights: None,
+            groups: None,
+            categorical: HashSet::new(),
+            rows: Arc::default(),
+            label_map: None,
         };
         Ok(sample)
     }
 
 
+    /// Convert `self` into the pair of a [`DataFrame`] of the features
+    /// and a [`Series`] of the target values named `"target"`,
+    /// which [`Sample::from_dataframe`] converts back to `self`.
+    /// The missing values and the unlabeled target values
+    /// become the null values.
+    ///
+    /// The bag ids, the instance weights, the group ids,
+    /// and the categorical marks are not converted.
+    pub fn to_dataframe(&self) -> (DataFrame, Series) {
+        let columns = self.features.par_iter()
+            .map(|feature| {
+                let values = (0..self.n_sample)
+                    .map(|i| Some(feature[i]).filter(|x| !x.is_nan()))
+                    .collect::<Vec<_>>();
+                Series::new(feature.name(), values)
+            })
+            .collect::<Vec<_>>();
+        let data = DataFrame::new(columns)
+            .expect("The features have distinct names and the same length");
+        let target = self.target.iter()
+            .map(|&y| Some(y).filter(|y| !y.is_nan()))
+            .collect::<Vec<_>>();
+        let target = Series::new("target", target);
+        (data, target)
+    }
+
+
+    /// Construct a `Sample` from the row-major matrix `data`
+    /// of `n_rows` rows and `n_cols` columns
+    /// and the target values `target`.
+    /// The `j`-th column is the feature named `Feat. [j + 1]`
+    /// (see [`Sample::replace_names`]),
+    /// and `f64::NAN` is a missing value.
+    /// `target[i]` is the target value of the `i`-th row.
+    ///
+    /// This method returns `Err` if
+    /// `data.len() != n_rows * n_cols` or `target.len() != n_rows`.
+    pub fn from_raw_parts(
+        data: &[f64],
+        n_rows: usize,
+        n_cols: usize,
+        target: &[f64],
+    ) -> io::Result<Self>
+    {
+        if n_rows.checked_mul(n_cols) != Some(data.len()) {
+            return Err(invalid_input(format!(
+                "Expected {n_rows} x {n_cols} values, got {}",
+                data.len()
+            )));
+        }
+        if target.len() != n_rows {
+            return Err(invalid_input(format!(
+                "Expected {n_rows} target values, got {}",
+                target.len()
+            )));
+        }
+
+        let columns = (0..n_cols)
+            .map(|j| {
+                (0..n_rows)
+                    .map(|i| data[i * n_cols + j])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let names = default_names(columns.len());
+        Ok(Self::from_columns(names, columns, target.to_vec()))
+    }
+
+
+    /// Construct a `Sample` from the matrix `data`,
+    /// whose rows are the examples,
+    /// and the target values `target`.
+    /// The `j`-th column is the feature named `Feat. [j + 1]`
+    /// (see [`Sample::replace_names`]),
+    /// and `f64::NAN` is a missing value.
+    /// `data` need not be in the standard layout.
+    ///
+    /// This method requires the `ndarray` feature and
+    /// returns `Err` if `data` and `target` have
+    /// different numbers of rows.
+    #[cfg(feature="ndarray")]
+    pub fn from_ndarray(
+        data: ndarray::ArrayView2<'_, f64>,
+        target: ndarray::ArrayView1<'_, f64>,
+    ) -> io::Result<Self>
+    {
+        let n_rows = data.nrows();
+        if target.len() != n_rows {
+            return Err(invalid_input(format!(
+                "Expected {n_rows} target values, got {}",
+                target.len()
+            )));
+        }
+
+        let columns = data.columns()
+            .into_iter()
+            .map(|column| column.to_vec())
+            .collect::<Vec<_>>();
+        let names = default_names(columns.len());
+        Ok(Self::from_columns(names, columns, target.to_vec()))
+    }
+
+
+    /// Construct a `Sample` from the feature columns
+    /// named `names` and the target values.
+    pub(super) fn from_columns(
+        names: Vec<String>,
+        columns: Vec<Vec<f64>>,
+        target: Vec<f64>,
+    ) -> Self
+    {
+        let features = names.into_par_iter()
+            .zip(columns)
+            .map(|(name, sample)| {
+                select_storage(Feature::Dense(DenseFeature { name, sample }))
+            })
+            .collect::<Vec<_>>();
+        Self::from_features(features, target)
+    }
+
+
+    /// Construct a `Sample` from the features and the target values.
+    pub(super) fn from_features(features: Vec<Feature>, target: Vec<f64>)
+        -> Self
+    {
+        let n_sample = target.len();
+        let n_feature = features.len();
+        let name_to_index = features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+
+        Self {
+            name_to_index,
+            features: Arc::new(features),
+            target,
+            n_sample,
+            n_feature,
+            bags: None,
+            weights: None,
+            groups: None,
+            categorical: HashSet::new(),
+            rows: Arc::default(),
+            label_map: None,
+        }
+    }
+
+
     /// Returns the slice of target values.
     pub fn target(&self) -> &[f64] {
         &self.target[..]
@@ -191,12 +400,105 @@ impl Sample {
     }
 
 
+    /// Returns the mapping from the original class labels
+    /// to the target values,
+    /// or `None` if the labels are not mapped.
+    /// See [`Sample::normalize_labels`].
+    pub fn label_map(&self) -> Option<&LabelMap> {
+        self.label_map.as_ref()
+    }
+
+
+    /// Maps the class labels to the target values boosters train on.
+    /// The labels of 2 kinds, such as `{0, 1}`,
+    /// are mapped to `{-1, +1}`,
+    /// so that binary boosters accept them.
+    /// The integer labels of more than 2 kinds are kept as they are,
+    /// and so are the real-valued targets for regression.
+    /// See [`LabelMap`] for the details.
+    ///
+    /// [`SampleReader`](crate::SampleReader) calls this method
+    /// by default.
+    /// This method does nothing if the labels are already mapped
+    /// or the target values are not set.
+    pub fn normalize_labels(mut self) -> Self {
+        if self.label_map.is_some() || self.target.is_empty() {
+            return self;
+        }
+        self.label_map = LabelMap::from_numbers(&self.target);
+        if let Some(map) = &self.label_map {
+            map.apply(&mut self.target);
+        }
+        self
+    }
+
+
     /// Returns a slice of the features.
     pub fn features(&self) -> &[Feature] {
         &self.features[..]
     }
 
 
+    /// Returns the mutable features.
+    /// Since the features may change,
+    /// this method drops the rows of the sparse features.
+    pub(super) fn features_mut(&mut self) -> &mut Vec<Feature> {
+        self.rows = Arc::default();
+        Arc::make_mut(&mut self.features)
+    }
+
+
+    /// Returns the rows of the sparse features.
+    fn sparse_rows(&self) -> &SparseRows {
+        self.rows.get_or_init(|| {
+            SparseRows::new(&self.features[..], self.n_sample)
+        })
+    }
+
+
+    /// Returns the fraction of the non-zero values of the features.
+    /// Missing values are non-zero.
+    pub fn density(&self) -> f64 {
+        if self.n_sample == 0 || self.n_feature == 0 { return 1f64; }
+        self.features.iter()
+            .map(|feat| feat.density())
+            .sum::<f64>()
+            / self.n_feature as f64
+    }
+
+
+    /// Selects the format of each feature by its density.
+    /// A feature whose fraction of the non-zero values
+    /// is below `threshold` is stored in the sparse format,
+    /// and the other features are stored in the dense format.
+    /// Passing `0.0` stores all the features in the dense format,
+    /// and passing a value greater than `1.0` stores
+    /// all the features in the sparse format.
+    ///
+    /// [`Sample::from_dataframe`] and [`SampleReader`](crate::SampleReader)
+    /// select the format with [`DEFAULT_SPARSE_THRESHOLD`],
+    /// except for SVMLight files, whose features are always sparse.
+    /// The format does not change the trained hypotheses.
+    pub fn with_sparse_threshold(mut self, threshold: f64) -> Self {
+        assert!(
+            threshold >= 0f64,
+            "The sparse threshold must be non-negative. Got {threshold}"
+        );
+        let features = mem::take(self.features_mut());
+        let features = features.into_par_iter()
+            .map(|feat| {
+                if feat.density() < threshold {
+                    feat.into_sparse()
+                } else {
+                    feat.into_dense()
+                }
+            })
+            .collect::<Vec<_>>();
+        self.features = Arc::new(features);
+        self
+    }
+
+
     /// Set the feature of name `target` to `self.target`.
     /// The old value assigned to `self.target` will be dropped.
     pub fn set_target<S: AsRef<str>>(mut self, target: S) -> Self {
@@ -206,20 +508,324 @@ impl Sample {
             .expect("The target class does not exist");
 
 
-        let target = self.features.remove(pos).into_target();
+        let target = self.features_mut().remove(pos).into_target();
         self.target = target;
+        self.label_map = None;
+        self.n_feature -= 1;
+
+
+        self.name_to_index = self.features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+
+        self
+    }
+
+
+    /// Set the feature of name `bag` as the bag ids
+    /// for multi-instance learning.
+    /// The rows with the same id belong to the same bag.
+    /// The feature is removed from `self.features`.
+    /// Each value of the feature must be a non-negative integer.
+    pub fn set_bags<S: AsRef<str>>(mut self, bag: S) -> Self {
+        let bag = bag.as_ref();
+        let pos = self.features.iter()
+            .position(|feat| feat.name() == bag)
+            .expect("The bag column does not exist");
+
+
+        let bags = self.features_mut().remove(pos).into_target();
         self.n_feature -= 1;
 
+        self.name_to_index = self.features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+
+        let bags = bags.into_iter()
+            .map(|b| {
+                assert!(
+                    b >= 0f64 && b.trunc() == b,
+                    "Bag ids must be non-negative integers. Got {b}"
+                );
+                b as usize
+            })
+            .collect();
+        self.with_bags(bags)
+    }
+
+
+    /// Set the bag ids for multi-instance learning.
+    /// `bags[i]` is the bag id of the `i`-th row.
+    pub fn with_bags(mut self, bags: Vec<usize>) -> Self {
+        assert_eq!(
+            bags.len(), self.n_sample,
+            "The number of bag ids must be the same as the one of rows"
+        );
+        self.bags = Some(bags);
+        self
+    }
+
+
+    /// Returns the bag ids if they are set.
+    pub fn bags(&self) -> Option<&[usize]> {
+        self.bags.as_deref()
+    }
+
+
+    /// Set the feature of name `weight` as the instance weights.
+    /// The feature is removed from `self.features`.
+    /// See [`Sample::with_weights`].
+    pub fn set_weights<S: AsRef<str>>(mut self, weight: S) -> Self {
+        let weight = weight.as_ref();
+        let pos = self.features.iter()
+            .position(|feat| feat.name() == weight)
+            .expect("The weight column does not exist");
+
+
+        let weights = self.features_mut().remove(pos).into_target();
+        self.n_feature -= 1;
 
         self.name_to_index = self.features.iter()
             .enumerate()
             .map(|(i, f)| (f.name().to_string(), i))
             .collect::<HashMap<_, _>>();
 
+        self.with_weights(weights)
+    }
+
+
+    /// Set the instance weights.
+    /// `weights[i]` is the importance of the `i`-th row.
+    /// Each weight must be a positive finite number.
+    /// The weights need not sum to `1`;
+    /// boosters normalize them
+    /// to initialize their distributions over the examples.
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Self {
+        assert_eq!(
+            weights.len(), self.n_sample,
+            "The number of weights must be the same as the one of rows"
+        );
+        weights.iter()
+            .for_each(|&w| {
+                assert!(
+                    w > 0f64 && w.is_finite(),
+                    "Instance weights must be positive. Got {w}"
+                );
+            });
+        self.weights = Some(weights);
         self
     }
 
 
+    /// Returns the instance weights if they are set.
+    pub fn weights(&self) -> Option<&[f64]> {
+        self.weights.as_deref()
+    }
+
+
+    /// Set the feature of name `group` as the group ids,
+    /// such as the query ids for learning to rank.
+    /// The rows with the same id belong to the same group.
+    /// The feature is removed from `self.features`.
+    /// Each value of the feature must be an integer in `[0, 2^32)`.
+    pub fn set_groups<S: AsRef<str>>(mut self, group: S) -> Self {
+        let group = group.as_ref();
+        let pos = self.features.iter()
+            .position(|feat| feat.name() == group)
+            .expect("The group column does not exist");
+
+
+        let groups = self.features_mut().remove(pos).into_target();
+        self.n_feature -= 1;
+
+        self.name_to_index = self.features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+
+        let groups = groups.into_iter()
+            .map(|g| {
+                assert!(
+                    (0f64..=u32::MAX as f64).contains(&g) && g.trunc() == g,
+                    "Group ids must be integers in `[0, 2^32)`. Got {g}"
+                );
+                g as u32
+            })
+            .collect();
+        self.with_groups(groups)
+    }
+
+
+    /// Set the group ids.
+    /// `groups[i]` is the group id of the `i`-th row.
+    pub fn with_groups(mut self, groups: Vec<u32>) -> Self {
+        assert_eq!(
+            groups.len(), self.n_sample,
+            "The number of group ids must be the same as the one of rows"
+        );
+        self.groups = Some(groups);
+        self
+    }
+
+
+    /// Returns the group ids if they are set.
+    pub fn groups(&self) -> Option<&[u32]> {
+        self.groups.as_deref()
+    }
+
+
+    /// Mark the features in `names` as categorical features.
+    /// Each value of a categorical feature must be
+    /// a non-negative integer, the code of a category,
+    /// or a missing value `f64::NAN`.
+    /// The codes need not be consecutive.
+    /// Weak learners such as [`DecisionTree`](crate::DecisionTree)
+    /// split a categorical feature into subsets of the categories
+    /// instead of a threshold.
+    pub fn set_categorical<I, T>(mut self, names: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        for name in names {
+            let name = name.as_ref();
+            let &k = self.name_to_index.get(name)
+                .unwrap_or_else(|| {
+                    panic!("The feature named `{name}` does not exist")
+                });
+            let feature = &self.features[k];
+            (0..self.n_sample).for_each(|row| {
+                let x = feature[row];
+                assert!(
+                    x.is_nan() || (x >= 0f64 && x.trunc() == x),
+                    "Categories must be non-negative integers. Got {x}"
+                );
+            });
+            self.categorical.insert(name.to_string());
+        }
+        self
+    }
+
+
+    /// Returns `true` if the feature named `name` is categorical.
+    /// See [`Sample::set_categorical`].
+    pub fn is_categorical<S: AsRef<str>>(&self, name: S) -> bool {
+        self.categorical.contains(name.as_ref())
+    }
+
+
+    /// Returns the distinct categories of the feature named `name`
+    /// in the ascending order.
+    pub(crate) fn categories<S: AsRef<str>>(&self, name: S) -> Vec<i64> {
+        let feature = &self[name];
+        let mut categories = (0..self.n_sample)
+            .map(|row| feature[row])
+            .filter(|x| !x.is_nan())
+            .map(|x| x as i64)
+            .collect::<Vec<_>>();
+        categories.sort_unstable();
+        categories.dedup();
+        categories
+    }
+
+
+    /// Returns the distribution over the examples
+    /// proportional to the instance weights.
+    /// If the weights are not set,
+    /// this method returns the uniform distribution.
+    pub(crate) fn weight_distribution(&self) -> Vec<f64> {
+        match self.weights.as_ref() {
+            Some(weights) => {
+                let total = weights.iter().sum::<f64>();
+                weights.iter().map(|w| w / total).collect()
+            },
+            None => {
+                let uni = 1f64 / self.n_sample as f64;
+                vec![uni; self.n_sample]
+            },
+        }
+    }
+
+
+    /// Mark the given rows as unlabeled.
+    /// The target values of the unlabeled rows are `f64::NAN`.
+    pub fn with_unlabeled<T>(mut self, rows: T) -> Self
+        where T: AsRef<[usize]>
+    {
+        for &row in rows.as_ref() {
+            assert!(
+                row < self.n_sample,
+                "The row {row} is out of range. # of rows is {}",
+                self.n_sample
+            );
+            self.target[row] = f64::NAN;
+        }
+        self
+    }
+
+
+    /// Returns `true` if the `row`-th example is unlabeled,
+    /// i.e., its target value is `f64::NAN`.
+    pub fn is_unlabeled(&self, row: usize) -> bool {
+        self.target[row].is_nan()
+    }
+
+
+    /// Returns the indices of the labeled rows.
+    pub fn labeled_rows(&self) -> Vec<usize> {
+        (0..self.target.len())
+            .filter(|&row| !self.is_unlabeled(row))
+            .collect()
+    }
+
+
+    /// Returns the indices of the unlabeled rows.
+    pub fn unlabeled_rows(&self) -> Vec<usize> {
+        (0..self.target.len())
+            .filter(|&row| self.is_unlabeled(row))
+            .collect()
+    }
+
+
+    /// Returns the mutable slice of target values.
+    pub(crate) fn target_mut(&mut self) -> &mut [f64] {
+        &mut self.target[..]
+    }
+
+
+    /// Returns `true` if `self` shares the features with `other`,
+    /// e.g., one of them is made by `Sample::with_target`.
+    pub(crate) fn shares_features_with(&self, other: &Sample) -> bool {
+        Arc::ptr_eq(&self.features, &other.features)
+    }
+
+
+    /// Returns a sample with the same features as `self`
+    /// and the target values `target`.
+    /// The returned sample shares the features with `self`,
+    /// so that this method does not copy them.
+    pub(crate) fn with_target(&self, target: Vec<f64>) -> Self {
+        assert_eq!(
+            target.len(), self.n_sample,
+            "The number of target values must be the same as the one of rows"
+        );
+        Self {
+            name_to_index: self.name_to_index.clone(),
+            features: Arc::clone(&self.features),
+            target,
+            n_sample: self.n_sample,
+            n_feature: self.n_feature,
+            bags: self.bags.clone(),
+            weights: self.weights.clone(),
+            groups: self.groups.clone(),
+            categorical: self.categorical.clone(),
+            rows: Arc::clone(&self.rows),
+            label_map: None,
+        }
+    }
+
+
     /// Read a SVMLight format file to `Sample`.
     /// 
     /// Each line of SVMLight format file has the following form:
@@ -229,6 +835,8 @@ impl Sample {
     /// where `y` is the target label of type `f64`,
     /// `index` is the feature index, and `value` is the value
     /// at the feature.
+    /// The optional word `qid:id` after `y` is the query id,
+    /// which is read as the group id (see [`Sample::groups`]).
     /// 
     /// **Note**
     /// The SVMLight format file is basically 1-indexed,
@@ -239,6 +847,7 @@ impl Sample {
     {
         let mut features = Vec::new();
         let mut target = Vec::new();
+        let mut groups = Vec::new();
         let mut n_sample = 0_usize;
 
         // Open the given `file`.
@@ -259,6 +868,13 @@ impl Sample {
             target.push(y);
 
             for word in words {
+                if let Some(qid) = word.strip_prefix("qid:") {
+                    let qid = qid.trim()
+                        .parse::<u32>()
+                        .expect("Failed to parse a query id.");
+                    groups.push(qid);
+                    continue;
+                }
                 let (i, x) = index_and_feature(word);
 
                 while features.len() <= i {
@@ -271,6 +887,11 @@ impl Sample {
             }
             n_sample += 1;
         }
+        assert!(
+            groups.is_empty() || groups.len() == n_sample,
+            "Either all or none of the lines must have a query id"
+        );
+        let groups = if groups.is_empty() { None } else { Some(groups) };
 
         let n_feature = features.len();
 
@@ -288,7 +909,17 @@ impl Sample {
             .collect::<HashMap<_, _>>();
 
         let mut sample = Self {
-            name_to_index, features, target, n_sample, n_feature,
+            name_to_index,
+            features: Arc::new(features),
+            target,
+            n_sample,
+            n_feature,
+            bags: None,
+            weights: None,
+            groups,
+            categorical: HashSet::new(),
+            rows: Arc::default(),
+            label_map: None,
         };
 
         sample.remove_allzero_features();
@@ -299,7 +930,7 @@ impl Sample {
 
     /// Removes the empty features in `self.features`.
     fn remove_allzero_features(&mut self) {
-        let features = mem::take(&mut self.features);
+        let features = mem::take(self.features_mut());
         self.name_to_index = features.iter()
             .filter_map(|feat| {
                 if feat.is_empty() {
@@ -311,9 +942,10 @@ impl Sample {
             .enumerate()
             .map(|(i, name)| (name, i))
             .collect();
-        self.features = features.into_iter()
+        let features = features.into_iter()
             .filter(|feat| !feat.is_empty())
             .collect();
+        self.features = Arc::new(features);
         self.n_feature = self.features.len();
     }
 
@@ -342,11 +974,17 @@ impl Sample {
             not equal to the one of `self.features.`"
         );
 
-        let old_names = names.iter()
-            .zip(&mut self.features[..])
+        let old_names: Vec<String> = names.iter()
+            .zip(self.features_mut().iter_mut())
             .map(|(name, feature)| feature.replace_name(name))
             .collect();
 
+        self.categorical = old_names.iter()
+            .zip(names)
+            .filter(|(old, _)| self.categorical.contains(*old))
+            .map(|(_, new)| new.to_string())
+            .collect();
+
         self.name_to_index = self.features.iter()
             .map(|feature| feature.name().to_string())
             .enumerate()
@@ -356,11 +994,215 @@ impl Sample {
     }
 
 
+    /// Returns the names of the features in the order of
+    /// [`Sample::features`].
+    pub fn feature_names(&self) -> Vec<&str> {
+        self.features.iter()
+            .map(|feature| feature.name())
+            .collect()
+    }
+
+
+    /// Returns the position of the feature named `name`
+    /// in [`Sample::features`],
+    /// or `None` if the feature does not exist.
+    pub fn feature_index<S: AsRef<str>>(&self, name: S) -> Option<usize> {
+        self.name_to_index.get(name.as_ref()).copied()
+    }
+
+
+    /// Returns the sample with the features in `names` only,
+    /// in the order of `names`.
+    /// The target values, the bag ids, the instance weights,
+    /// the group ids, and the categorical marks are kept.
+    ///
+    /// The values of a selected feature are copied
+    /// unless it is mapped from a column store
+    /// (see [`Sample::open_mmap`]),
+    /// in which case the selection shares the file with `self`.
+    ///
+    /// This method panics if a name does not exist or
+    /// appears twice.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use miniboosts::prelude::*;
+    ///
+    /// let sample = SampleReader::new()
+    ///     .file("/path/to/data.csv")
+    ///     .has_header(true)
+    ///     .target_feature("class")
+    ///     .read()
+    ///     .unwrap();
+    /// let selected = sample.select_features(["f1", "f7"]);
+    /// assert_eq!(selected.feature_names(), ["f1", "f7"]);
+    /// ```
+    pub fn select_features<I, T>(&self, names: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        let mut selected = HashSet::new();
+        let features = names.into_iter()
+            .map(|name| {
+                let name = name.as_ref();
+                let &k = self.name_to_index.get(name)
+                    .unwrap_or_else(|| {
+                        panic!("The feature named `{name}` does not exist")
+                    });
+                assert!(
+                    selected.insert(k),
+                    "The feature named `{name}` is selected twice"
+                );
+                self.features[k].clone()
+            })
+            .collect::<Vec<_>>();
+        self.with_features(features)
+    }
+
+
+    /// Returns the sample without the features in `names`.
+    /// The other features keep their order.
+    /// See [`Sample::select_features`] for the details.
+    ///
+    /// This method panics if a name does not exist.
+    pub fn drop_features<I, T>(&self, names: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        let dropped = names.into_iter()
+            .map(|name| {
+                let name = name.as_ref();
+                *self.name_to_index.get(name)
+                    .unwrap_or_else(|| {
+                        panic!("The feature named `{name}` does not exist")
+                    })
+            })
+            .collect::<HashSet<_>>();
+        let features = self.features.iter()
+            .enumerate()
+            .filter(|(k, _)| !dropped.contains(k))
+            .map(|(_, feature)| feature.clone())
+            .collect::<Vec<_>>();
+        self.with_features(features)
+    }
+
+
+    /// Returns a sample with the same rows as `self`,
+    /// where each feature `f` is replaced by the dense feature of
+    /// the values `map(f)` if it is `Some`.
+    pub(crate) fn map_features<F>(&self, map: F) -> Self
+        where F: Fn(&Feature) -> Option<Vec<f64>> + Sync,
+    {
+        let features = self.features.par_iter()
+            .map(|feature| {
+                match map(feature) {
+                    Some(values) => {
+                        let mut dense = DenseFeature::new(feature.name());
+                        dense.sample = values;
+                        Feature::Dense(dense)
+                    },
+                    None => feature.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        self.with_features(features)
+    }
+
+
+    /// Returns a sample with the same rows as `self`,
+    /// where each feature `f` is replaced by the dense features
+    /// `(name, values)` in `map(f)` if it is `Some`.
+    /// The replaced features are no longer categorical.
+    pub(crate) fn flat_map_features<F>(&self, map: F) -> Self
+        where F: Fn(&Feature) -> Option<Vec<(String, Vec<f64>)>> + Sync,
+    {
+        let replaced = self.features.par_iter()
+            .map(|feature| (feature, map(feature)))
+            .collect::<Vec<_>>();
+        let mut features = Vec::with_capacity(replaced.len());
+        let mut unmarked = Vec::new();
+        for (feature, columns) in replaced {
+            let Some(columns) = columns else {
+                features.push(feature.clone());
+                continue;
+            };
+            unmarked.push(feature.name().to_string());
+            for (name, values) in columns {
+                let mut dense = DenseFeature::new(name);
+                dense.sample = values;
+                features.push(Feature::Dense(dense));
+            }
+        }
+        let mut sample = self.with_features(features);
+        assert_eq!(
+            sample.name_to_index.len(), sample.n_feature,
+            "The feature names must be distinct"
+        );
+        unmarked.iter()
+            .for_each(|name| { sample.categorical.remove(name); });
+        sample
+    }
+
+
+    /// Returns the sample of the `row`-th example only.
+    pub(crate) fn row_sample(&self, row: usize) -> Self {
+        let features = self.features.iter()
+            .map(|feature| {
+                let mut dense = DenseFeature::new(feature.name());
+                dense.append(feature[row]);
+                Feature::Dense(dense)
+            })
+            .collect::<Vec<_>>();
+        let y = self.target.get(row).copied().unwrap_or(f64::NAN);
+        let mut sample = Self::from_features(features, vec![y]);
+        sample.categorical = self.categorical.clone();
+        sample
+    }
+
+
+    /// Returns a sample with the same rows as `self`
+    /// and the features `features`,
+    /// each of which is a feature of `self`.
+    fn with_features(&self, features: Vec<Feature>) -> Self {
+        let name_to_index = features.iter()
+            .enumerate()
+            .map(|(i, f)| (f.name().to_string(), i))
+            .collect::<HashMap<_, _>>();
+        let categorical = self.categorical.iter()
+            .filter(|name| name_to_index.contains_key(*name))
+            .cloned()
+            .collect();
+        Self {
+            name_to_index,
+            n_feature: features.len(),
+            features: Arc::new(features),
+            target: self.target.clone(),
+            n_sample: self.n_sample,
+            bags: self.bags.clone(),
+            weights: self.weights.clone(),
+            groups: self.groups.clone(),
+            categorical,
+            rows: Arc::default(),
+            label_map: self.label_map.clone(),
+        }
+    }
+
+
     /// Returns the `idx`-th instance `(x, y)`.
     pub fn at(&self, idx: usize) -> (Vec<f64>, f64) {
-        let x = self.features.iter()
-            .map(|feat| feat[idx])
+        let mut has_sparse = false;
+        let mut x = self.features.iter()
+            .map(|feat| match feat {
+                Feature::Dense(feat) => feat[idx],
+                Feature::Mapped(feat) => feat[idx],
+                Feature::Sparse(_) => { has_sparse = true; 0f64 },
+            })
             .collect::<Vec<f64>>();
+        if has_sparse {
+            self.sparse_rows()
+                .row(idx)
+                .for_each(|(j, v)| { x[j] = v; });
+        }
         let y = self.target[idx];
 
         (x, y)
@@ -430,7 +1272,8 @@ impl Sample {
                 .join(", ");
             println!(
                 "Warning: the target values take values not in [-1.0, 1.0].\n\
-                 Currently, the labels are: [{line}]."
+                 Currently, the labels are: [{line}].\n\
+                 Use `Sample::normalize_labels` to map them to [-1.0, 1.0]."
             );
         }
 
@@ -517,9 +1360,163 @@ impl Sample {
     }
 
 
+    /// Append the rows of `data` and `target` to `self`.
+    /// `data` must have the same column names as the features of `self`
+    /// (in any order), and each column must be a numerical dtype
+    /// without null values.
+    /// `target` must be a dtype `f64` without null values.
+    ///
+    /// This method returns `Err` and leaves `self` unchanged
+    /// if the given rows do not match `self`.
+    /// Since the rows have no bag ids,
+    /// this method also returns `Err` if the bag ids of `self` are set.
+    /// The same applies to the instance weights and the group ids.
+    /// Use [`Sample::extend_from`] in that case.
+    pub fn push_rows(&mut self, data: DataFrame, target: Series)
+        -> io::Result<()>
+    {
+        if self.bags.is_some() {
+            return Err(invalid_input(
+                "Cannot push rows without bag ids to a sample with bag ids"
+            ));
+        }
+        if self.weights.is_some() {
+            return Err(invalid_input(
+                "Cannot push rows without weights to a sample with weights"
+            ));
+        }
+        if self.groups.is_some() {
+            return Err(invalid_input(
+                "Cannot push rows without group ids to a sample with groups"
+            ));
+        }
+        let n_rows = data.height();
+        if target.len() != n_rows {
+            return Err(invalid_input(
+                "The features and the target have different # of rows"
+            ));
+        }
+        if data.width() != self.n_feature {
+            return Err(invalid_input(format!(
+                "Expected {} columns, got {}", self.n_feature, data.width()
+            )));
+        }
+
+
+        let mut columns = vec![Vec::new(); self.n_feature];
+        for series in data.get_columns() {
+            let name = series.name();
+            let &i = self.name_to_index.get(name)
+                .ok_or_else(|| {
+                    invalid_input(format!("Unknown column `{name}`"))
+                })?;
+            if !series.dtype().is_numeric() {
+                return Err(invalid_data(format!(
+                    "The column `{name}` has non-numerical dtype `{}`",
+                    series.dtype()
+                )));
+            }
+            columns[i] = series_to_features(series)?;
+        }
+
+        if target.dtype() != &DataType::Float64 {
+            return Err(invalid_data(format!(
+                "The target must be a dtype f64, got `{}`",
+                target.dtype()
+            )));
+        }
+        let target = series_to_vec(&target)?;
+
+        self.append_columns(columns, target);
+        Ok(())
+    }
+
+
+    /// Append the rows of `other` to `self`.
+    /// `other` must have the same feature names as `self`.
+    /// The bag ids, the instance weights, and the group ids
+    /// are concatenated if both samples have them.
+    ///
+    /// This method returns `Err` and leaves `self` unchanged
+    /// if `other` does not match `self`.
+    pub fn extend_from(&mut self, other: &Sample) -> io::Result<()> {
+        if other.n_feature != self.n_feature {
+            return Err(invalid_input(format!(
+                "Expected {} features, got {}",
+                self.n_feature, other.n_feature
+            )));
+        }
+        if self.bags.is_some() != other.bags.is_some() {
+            return Err(invalid_input(
+                "Either both or none of the samples must have bag ids"
+            ));
+        }
+        if self.weights.is_some() != other.weights.is_some() {
+            return Err(invalid_input(
+                "Either both or none of the samples must have weights"
+            ));
+        }
+        if self.groups.is_some() != other.groups.is_some() {
+            return Err(invalid_input(
+                "Either both or none of the samples must have group ids"
+            ));
+        }
+
+
+        let mut columns = vec![Vec::new(); self.n_feature];
+        for feat in other.features.iter() {
+            let name = feat.name();
+            let &i = self.name_to_index.get(name)
+                .ok_or_else(|| {
+                    invalid_input(format!("Unknown feature `{name}`"))
+                })?;
+            columns[i] = (0..other.n_sample)
+                .map(|row| feat[row])
+                .collect();
+        }
+
+        if let (Some(bags), Some(others)) = (self.bags.as_mut(), &other.bags) {
+            bags.extend_from_slice(&others[..]);
+        }
+        if let (Some(weights), Some(others))
+            = (self.weights.as_mut(), &other.weights)
+        {
+            weights.extend_from_slice(&others[..]);
+        }
+        if let (Some(groups), Some(others))
+            = (self.groups.as_mut(), &other.groups)
+        {
+            groups.extend_from_slice(&others[..]);
+        }
+        self.append_columns(columns, other.target.clone());
+        Ok(())
+    }
+
+
+    /// Append the rows given as the columns to `self`.
+    /// `columns[i]` must be the values of `self.features[i]`.
+    fn append_columns(&mut self, columns: Vec<Vec<f64>>, target: Vec<f64>) {
+        let offset = self.n_sample;
+        let n_sample = offset + target.len();
+        self.features_mut().par_iter_mut()
+            .zip(columns)
+            .for_each(|(feat, column)| {
+                let is_sparse = feat.is_sparse();
+                column.into_iter()
+                    .enumerate()
+                    .filter(|&(_, x)| !is_sparse || x != 0f64)
+                    .for_each(|(k, x)| { feat.append(offset + k, x); });
+                feat.set_n_sample(n_sample);
+            });
+        self.target.extend(target);
+        self.n_sample = n_sample;
+    }
+
+
     fn append(&mut self, row: usize, feat: Vec<f64>, y: f64) {
-        self.features.par_iter_mut()
+        self.features_mut().par_iter_mut()
             .zip(feat)
+            .filter(|(col, f)| !col.is_sparse() || *f != 0f64)
             .for_each(|(col, f)| {
                 col.append(row, f);
             });
@@ -538,53 +1535,96 @@ impl Sample {
         let train_size = self.n_sample - test_size;
         let ix = ix.as_ref();
 
+        let mut train_features = vec![Feature::new_sparse("dummy"); n_feature];
+        let mut test_features = vec![Feature::new_sparse("dummy"); n_feature];
+        for (name, &i) in self.name_to_index.iter() {
+            if self.features[i].is_sparse() {
+                train_features[i] = Feature::new_sparse(name.to_string());
+                test_features[i] = Feature::new_sparse(name.to_string());
+                train_features[i].set_n_sample(train_size);
+                test_features[i].set_n_sample(test_size);
+            } else {
+                train_features[i] = Feature::new_dense(name.to_string());
+                test_features[i] = Feature::new_dense(name.to_string());
+            }
+        }
+
         let name_to_ix = self.name_to_index.clone();
         let mut train = Self {
             n_sample: train_size,
             n_feature: n_feature,
             name_to_index: name_to_ix.clone(),
-            features: vec![Feature::new_sparse("dummy"); n_feature],
+            features: Arc::new(train_features),
             target: Vec::with_capacity(train_size),
+            bags: None,
+            weights: None,
+            groups: None,
+            categorical: self.categorical.clone(),
+            rows: Arc::default(),
+            label_map: self.label_map.clone(),
         };
 
         let mut test = Self {
             n_sample: test_size,
             n_feature: n_feature,
             name_to_index: name_to_ix,
-            features: vec![Feature::new_sparse("dummy"); n_feature],
+            features: Arc::new(test_features),
             target: Vec::with_capacity(test_size),
+            bags: None,
+            weights: None,
+            groups: None,
+            categorical: self.categorical.clone(),
+            rows: Arc::default(),
+            label_map: self.label_map.clone(),
         };
 
-        for (name, &i) in self.name_to_index.iter() {
-            if self.features[i].is_sparse() {
-                train.features[i] = Feature::new_sparse(name.to_string());
-                test.features[i] = Feature::new_sparse(name.to_string());
-                train.features[i].set_n_sample(train_size);
-                test.features[i].set_n_sample(test_size);
-            } else {
-                train.features[i] = Feature::new_dense(name.to_string());
-                test.features[i] = Feature::new_dense(name.to_string());
-            }
-        }
-
-        for i in 0..start {
-            let ii = ix[i];
+        let train_ix = ix[..start].iter().chain(&ix[end..self.n_sample]);
+        for (i, &ii) in train_ix.enumerate() {
             let (x, y) = self.at(ii);
             train.append(i, x, y);
         }
 
 
-        for i in start..end {
-            let ii = ix[i];
+        for (i, &ii) in ix[start..end].iter().enumerate() {
             let (x, y) = self.at(ii);
             test.append(i, x, y);
         }
 
 
-        for i in end..self.n_sample {
-            let ii = ix[i];
-            let (x, y) = self.at(ii);
-            train.append(i, x, y);
+        if let Some(bags) = self.bags.as_ref() {
+            let train_bags = ix[..start].iter()
+                .chain(&ix[end..self.n_sample])
+                .map(|&i| bags[i])
+                .collect();
+            let test_bags = ix[start..end].iter()
+                .map(|&i| bags[i])
+                .collect();
+            train.bags = Some(train_bags);
+            test.bags = Some(test_bags);
+        }
+
+        if let Some(weights) = self.weights.as_ref() {
+            let train_weights = ix[..start].iter()
+                .chain(&ix[end..self.n_sample])
+                .map(|&i| weights[i])
+                .collect();
+            let test_weights = ix[start..end].iter()
+                .map(|&i| weights[i])
+                .collect();
+            train.weights = Some(train_weights);
+            test.weights = Some(test_weights);
+        }
+
+        if let Some(groups) = self.groups.as_ref() {
+            let train_groups = ix[..start].iter()
+                .chain(&ix[end..self.n_sample])
+                .map(|&i| groups[i])
+                .collect();
+            let test_groups = ix[start..end].iter()
+                .map(|&i| groups[i])
+                .collect();
+            train.groups = Some(train_groups);
+            test.groups = Some(test_groups);
         }
 
         (train, test)
@@ -624,3 +1664,136 @@ impl<S> Index<S> for Sample
         &self.features[k]
     }
 }
+
+
+/// Returns the default feature names,
+/// `Feat. [1]`, `Feat. [2]`, ..., `Feat. [n_feature]`.
+pub(super) fn default_names(n_feature: usize) -> Vec<String> {
+    (1..=n_feature).map(|j| format!("Feat. [{j}]")).collect()
+}
+
+
+/// Stores `feature` in the sparse format
+/// if its density is below [`DEFAULT_SPARSE_THRESHOLD`].
+fn select_storage(feature: Feature) -> Feature {
+    if feature.density() < DEFAULT_SPARSE_THRESHOLD {
+        feature.into_sparse()
+    } else {
+        feature
+    }
+}
+
+
+/// Converts a [`DataFrame`] and the name of its target column
+/// into a [`Sample`].
+/// The other columns are the features,
+/// which must be numerical dtypes.
+/// The null values are missing values,
+/// and the rows with null target values are unlabeled
+/// (see [`Sample::from_dataframe`]).
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use polars::prelude::*;
+///
+/// let data = df!(
+///     "x" => [0.1, 0.4, 0.8],
+///     "class" => [-1.0, -1.0, 1.0]
+/// ).unwrap();
+/// let sample = Sample::try_from((&data, "class")).unwrap();
+/// ```
+impl TryFrom<(&DataFrame, &str)> for Sample {
+    type Error = io::Error;
+
+
+    fn try_from((data, target): (&DataFrame, &str)) -> io::Result<Self> {
+        let column = data.column(target)
+            .map_err(|_| {
+                let message = format!("Unknown target column `{target}`");
+                invalid_input(message)
+            })?;
+        let target = column.cast(&DataType::Float64)
+            .map_err(invalid_data)?;
+
+        let columns = data.get_columns()
+            .iter()
+            .filter(|series| series.name() != target.name())
+            .map(|series| {
+                if !series.dtype().is_numeric() {
+                    return Err(invalid_data(format!(
+                        "The column `{}` has non-numerical dtype `{}`",
+                        series.name(),
+                        series.dtype()
+                    )));
+                }
+                series.cast(&DataType::Float64).map_err(invalid_data)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        let data = DataFrame::new(columns).map_err(invalid_data)?;
+        Self::from_dataframe(data, target)
+    }
+}
+
+
+pub(super) fn invalid_input<E: ToString>(message: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.to_string())
+}
+
+
+pub(super) fn invalid_data<E: ToString>(message: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+
+/// Returns `true` if `cell` is a missing value,
+/// i.e., an empty cell or one of the tokens in `missing`.
+pub(super) fn is_missing(cell: &str, missing: &[String]) -> bool {
+    let cell = cell.trim();
+    cell.is_empty() || missing.iter().any(|token| token == cell)
+}
+
+
+/// Parses a cell of a CSV file in the `line`-th line.
+/// A missing value (see [`is_missing`]) is `f64::NAN`.
+pub(super) fn parse_cell(cell: &str, line: usize, missing: &[String])
+    -> io::Result<f64>
+{
+    if is_missing(cell, missing) { return Ok(f64::NAN); }
+    let cell = cell.trim();
+    cell.parse::<f64>()
+        .map_err(|_| {
+            invalid_data(format!(
+                "The file contains non-numerical value. \
+                Got {cell} in Line {line}"
+            ))
+        })
+}
+
+
+/// Converts a column of features into `Vec<f64>`.
+/// The null values are missing values, `f64::NAN`.
+fn series_to_features(series: &Series) -> io::Result<Vec<f64>> {
+    let values = series.cast(&DataType::Float64)
+        .map_err(io::Error::other)?
+        .f64()
+        .map_err(io::Error::other)?
+        .into_iter()
+        .map(|v| v.unwrap_or(f64::NAN))
+        .collect();
+    Ok(values)
+}
+
+
+fn series_to_vec(series: &Series) -> io::Result<Vec<f64>> {
+    let name = series.name();
+    series.cast(&DataType::Float64)
+        .map_err(io::Error::other)?
+        .f64()
+        .map_err(io::Error::other)?
+        .into_iter()
+        .map(|v| v.ok_or_else(|| {
+            invalid_data(format!("The column `{name}` has a null value"))
+        }))
+        .collect()
+}
@@ -0,0 +1,64 @@
+use super::feature_struct::Feature;
+
+
+/// The non-zero values of the sparse features of a sample
+/// in the compressed sparse row (CSR) format.
+/// The sparse features themselves hold the values
+/// in the compressed sparse column (CSC) format,
+/// which the weak learners scan to find the splits.
+/// This struct gives the access to the rows of a sample
+/// without the binary search on each sparse feature.
+#[derive(Debug, Clone)]
+pub(crate) struct SparseRows {
+    /// The non-zero values of the `i`-th row are
+    /// `columns[offsets[i]..offsets[i + 1]]` and
+    /// `values[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<f64>,
+}
+
+
+impl SparseRows {
+    /// Gathers the non-zero values of the sparse features
+    /// in `features` row by row.
+    /// The dense features are skipped.
+    pub(crate) fn new(features: &[Feature], n_sample: usize) -> Self {
+        let mut offsets = vec![0usize; n_sample + 1];
+        for feature in features {
+            let Feature::Sparse(feat) = feature else { continue; };
+            feat.iter().for_each(|(i, _)| { offsets[i + 1] += 1; });
+        }
+        for i in 0..n_sample {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let nnz = offsets[n_sample];
+        let mut columns = vec![0usize; nnz];
+        let mut values = vec![0f64; nnz];
+        let mut next = offsets.clone();
+        for (j, feature) in features.iter().enumerate() {
+            let Feature::Sparse(feat) = feature else { continue; };
+            for &(i, x) in feat.iter() {
+                let pos = next[i];
+                columns[pos] = j;
+                values[pos] = x;
+                next[i] += 1;
+            }
+        }
+
+        Self { offsets, columns, values }
+    }
+
+
+    /// Returns the pairs of the feature index and the non-zero value
+    /// of the sparse features on the `i`-th row.
+    pub(crate) fn row(&self, i: usize)
+        -> impl Iterator<Item = (usize, f64)> + '_
+    {
+        let range = self.offsets[i]..self.offsets[i + 1];
+        self.columns[range.clone()].iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+}
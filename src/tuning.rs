@@ -0,0 +1,20 @@
+//! This directory provides the hyperparameter searches
+//! over the parameters of boosters and weak learners,
+//! such as `nu`, `tolerance`, `max_depth`, and `bins`.
+//! Each candidate is evaluated by
+//! [`cross_validate`](crate::research::cross_validate),
+//! and the candidates are evaluated in parallel.
+
+// Defines the values of the hyperparameters of a trial.
+mod params;
+// Defines the results of the trials.
+mod tuning_report;
+// Defines the exhaustive search over a grid.
+mod grid_search;
+// Defines the search over random candidates.
+mod random_search;
+
+pub use params::Params;
+pub use tuning_report::{Trial, TuningReport};
+pub use grid_search::GridSearch;
+pub use random_search::RandomSearch;
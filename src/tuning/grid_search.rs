@@ -0,0 +1,131 @@
+use crate::Sample;
+use crate::research::Metric;
+use super::params::Params;
+use super::tuning_report::{TuningReport, evaluate};
+
+
+/// The exhaustive search over a grid of hyperparameters.
+/// `GridSearch` evaluates every combination of the given values
+/// by `k`-fold cross validation
+/// and reports the trials sorted by the mean metric.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use miniboosts::tuning::{GridSearch, Params};
+///
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let zero_one_loss = |test: &Sample, f: &WeightedMajority<_>| {
+///     let n_sample = test.shape().0 as f64;
+///     f.predict_all(test)
+///         .into_iter()
+///         .zip(test.target())
+///         .filter(|&(p, &y)| p != y as i64)
+///         .count() as f64
+///         / n_sample
+/// };
+/// let report = GridSearch::new(&sample)
+///     .n_folds(5)
+///     .param("nu", &[0.01, 0.1])
+///     .param("max_depth", &[1.0, 2.0, 3.0])
+///     .run(
+///         |train: &Sample, params: &Params| {
+///             let tree = DecisionTreeBuilder::new(train)
+///                 .max_depth(params["max_depth"] as usize)
+///                 .build();
+///             let nu = params["nu"] * train.shape().0 as f64;
+///             LPBoost::init(train).nu(nu).run(&tree)
+///         },
+///         &zero_one_loss,
+///     );
+/// println!("{report}");
+/// println!("Best: {}", report.best().params);
+/// ```
+pub struct GridSearch<'a> {
+    sample: &'a Sample,
+    n_folds: usize,
+    grid: Vec<(String, Vec<f64>)>,
+}
+
+
+impl<'a> GridSearch<'a> {
+    /// Construct a new instance of `GridSearch` without parameters.
+    pub fn new(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            n_folds: 5,
+            grid: Vec::new(),
+        }
+    }
+
+
+    /// Set the number of folds of the cross validation.
+    /// Default value is `5`.
+    pub fn n_folds(mut self, n_folds: usize) -> Self {
+        let n_sample = self.sample.shape().0;
+        assert!(
+            (2..=n_sample).contains(&n_folds),
+            "The number of folds must be in `[2, # of examples]`"
+        );
+        self.n_folds = n_folds;
+        self
+    }
+
+
+    /// Add the parameter `name` taking the values `values`.
+    pub fn param<S: ToString>(mut self, name: S, values: &[f64]) -> Self {
+        let name = name.to_string();
+        assert!(
+            !values.is_empty(),
+            "The parameter `{name}` has no value"
+        );
+        assert!(
+            self.grid.iter().all(|(n, _)| *n != name),
+            "The parameter `{name}` is already given"
+        );
+        self.grid.push((name, values.to_vec()));
+        self
+    }
+
+
+    /// Returns all the combinations of the parameters.
+    pub fn candidates(&self) -> Vec<Params> {
+        let mut candidates = vec![Vec::new()];
+        for (name, values) in self.grid.iter() {
+            candidates = candidates.into_iter()
+                .flat_map(|params: Vec<(String, f64)>| {
+                    values.iter().map(move |&value| {
+                        let mut params = params.clone();
+                        params.push((name.clone(), value));
+                        params
+                    })
+                })
+                .collect();
+        }
+        candidates.into_iter()
+            .map(Params::new)
+            .collect()
+    }
+
+
+    /// Evaluates every combination of the parameters
+    /// by the cross validation in parallel.
+    /// For each combination and each fold,
+    /// `learner` trains a hypothesis on the training folds
+    /// with the parameters,
+    /// and `metric` evaluates it on the test fold.
+    /// The smaller metric is the better.
+    pub fn run<L, H>(&self, learner: L, metric: Metric<'_, H>)
+        -> TuningReport
+        where L: Fn(&Sample, &Params) -> H + Sync,
+    {
+        let candidates = self.candidates();
+        evaluate(self.sample, self.n_folds, candidates, &learner, metric)
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::ops::Index;
+
+
+/// The values of the hyperparameters of a trial,
+/// passed to the learner of [`GridSearch`](crate::tuning::GridSearch)
+/// and [`RandomSearch`](crate::tuning::RandomSearch).
+/// Integer parameters such as `max_depth` are also stored as `f64`;
+/// convert them with `as usize`.
+///
+/// # Example
+/// ```no_run
+/// # use miniboosts::tuning::Params;
+/// # fn f(params: &Params) {
+/// let nu = params["nu"];
+/// let max_depth = params["max_depth"] as usize;
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    values: Vec<(String, f64)>,
+}
+
+
+impl Params {
+    /// Construct the parameters from the pairs of a name and a value.
+    pub(crate) fn new(values: Vec<(String, f64)>) -> Self {
+        Self { values }
+    }
+
+
+    /// Returns the value of the parameter `name`,
+    /// or `None` if `name` is unknown.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.values.iter()
+            .find(|(n, _)| n == name)
+            .map(|&(_, value)| value)
+    }
+
+
+    /// Returns the pairs of a name and a value.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> + '_ {
+        self.values.iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+}
+
+
+impl Index<&str> for Params {
+    type Output = f64;
+
+
+    /// Returns the value of the parameter `name`.
+    /// Panics if `name` is unknown.
+    fn index(&self, name: &str) -> &f64 {
+        self.values.iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value)
+            .unwrap_or_else(|| panic!("Unknown parameter `{name}`"))
+    }
+}
+
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = self.values.iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{line}")
+    }
+}
@@ -0,0 +1,205 @@
+use rand::prelude::*;
+
+use crate::Sample;
+use crate::research::Metric;
+use super::params::Params;
+use super::tuning_report::{TuningReport, evaluate};
+
+
+/// The distribution of a parameter for [`RandomSearch`].
+#[derive(Debug, Clone)]
+enum Distribution {
+    /// The uniform distribution over `[low, high]`.
+    Uniform(f64, f64),
+    /// The distribution whose logarithm is uniform over
+    /// `[ln low, ln high]`.
+    LogUniform(f64, f64),
+    /// The uniform distribution over the values.
+    Choice(Vec<f64>),
+}
+
+
+impl Distribution {
+    fn sample<R: Rng>(&self, rng: &mut R) -> f64 {
+        match self {
+            Self::Uniform(low, high) => rng.gen_range(*low..=*high),
+            Self::LogUniform(low, high) => {
+                rng.gen_range(low.ln()..=high.ln()).exp()
+            },
+            Self::Choice(values) => *values.choose(rng).unwrap(),
+        }
+    }
+}
+
+
+/// The search over random combinations of hyperparameters.
+/// `RandomSearch` draws `n_trials` combinations
+/// from the given distributions,
+/// evaluates them by `k`-fold cross validation,
+/// and reports the trials sorted by the mean metric.
+/// Random search often finds good parameters
+/// with fewer trials than [`GridSearch`](crate::tuning::GridSearch)
+/// when only a few parameters matter.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+/// use miniboosts::tuning::{RandomSearch, Params};
+///
+/// let sample = SampleReader::new()
+///     .file(path_to_file)
+///     .has_header(true)
+///     .target_feature("class")
+///     .read()
+///     .unwrap();
+///
+/// let zero_one_loss = |test: &Sample, f: &WeightedMajority<_>| {
+///     let n_sample = test.shape().0 as f64;
+///     f.predict_all(test)
+///         .into_iter()
+///         .zip(test.target())
+///         .filter(|&(p, &y)| p != y as i64)
+///         .count() as f64
+///         / n_sample
+/// };
+/// let report = RandomSearch::new(&sample)
+///     .n_trials(20)
+///     .log_uniform("tolerance", 1e-4, 1e-1)
+///     .choice("bins", &[16.0, 64.0, 256.0])
+///     .run(
+///         |train: &Sample, params: &Params| {
+///             let tree = DecisionTreeBuilder::new(train)
+///                 .max_depth(2)
+///                 .bins(params["bins"] as usize)
+///                 .build();
+///             AdaBoost::init(train)
+///                 .tolerance(params["tolerance"])
+///                 .run(&tree)
+///         },
+///         &zero_one_loss,
+///     );
+/// println!("{report}");
+/// ```
+pub struct RandomSearch<'a> {
+    sample: &'a Sample,
+    n_folds: usize,
+    n_trials: usize,
+    seed: u64,
+    distributions: Vec<(String, Distribution)>,
+}
+
+
+impl<'a> RandomSearch<'a> {
+    /// Construct a new instance of `RandomSearch` without parameters.
+    pub fn new(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            n_folds: 5,
+            n_trials: 10,
+            seed: 1234,
+            distributions: Vec::new(),
+        }
+    }
+
+
+    /// Set the number of folds of the cross validation.
+    /// Default value is `5`.
+    pub fn n_folds(mut self, n_folds: usize) -> Self {
+        let n_sample = self.sample.shape().0;
+        assert!(
+            (2..=n_sample).contains(&n_folds),
+            "The number of folds must be in `[2, # of examples]`"
+        );
+        self.n_folds = n_folds;
+        self
+    }
+
+
+    /// Set the number of the combinations to evaluate.
+    /// Default value is `10`.
+    pub fn n_trials(mut self, n_trials: usize) -> Self {
+        assert!(n_trials > 0, "The number of trials must be positive");
+        self.n_trials = n_trials;
+        self
+    }
+
+
+    /// Set the seed of the randomness for drawing the parameters.
+    /// Default value is `1234`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Add the parameter `name` drawn uniformly from `[low, high]`.
+    pub fn uniform<S: ToString>(self, name: S, low: f64, high: f64) -> Self {
+        assert!(
+            low <= high,
+            "The lower bound must be at most the upper bound"
+        );
+        self.push(name, Distribution::Uniform(low, high))
+    }
+
+
+    /// Add the parameter `name` drawn from `[low, high]`
+    /// uniformly in the log scale,
+    /// which suits scale parameters such as `tolerance`.
+    pub fn log_uniform<S: ToString>(self, name: S, low: f64, high: f64)
+        -> Self
+    {
+        assert!(
+            0f64 < low && low <= high,
+            "The bounds must satisfy `0 < low <= high`"
+        );
+        self.push(name, Distribution::LogUniform(low, high))
+    }
+
+
+    /// Add the parameter `name` drawn uniformly from `values`,
+    /// which suits integer parameters such as `max_depth`.
+    pub fn choice<S: ToString>(self, name: S, values: &[f64]) -> Self {
+        assert!(!values.is_empty(), "No value to choose");
+        self.push(name, Distribution::Choice(values.to_vec()))
+    }
+
+
+    fn push<S: ToString>(mut self, name: S, dist: Distribution) -> Self {
+        let name = name.to_string();
+        assert!(
+            self.distributions.iter().all(|(n, _)| *n != name),
+            "The parameter `{name}` is already given"
+        );
+        self.distributions.push((name, dist));
+        self
+    }
+
+
+    /// Returns the combinations of the parameters to evaluate.
+    pub fn candidates(&self) -> Vec<Params> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        (0..self.n_trials).map(|_| {
+                let values = self.distributions.iter()
+                    .map(|(name, dist)| (name.clone(), dist.sample(&mut rng)))
+                    .collect();
+                Params::new(values)
+            })
+            .collect()
+    }
+
+
+    /// Evaluates the random combinations of the parameters
+    /// by the cross validation in parallel.
+    /// For each combination and each fold,
+    /// `learner` trains a hypothesis on the training folds
+    /// with the parameters,
+    /// and `metric` evaluates it on the test fold.
+    /// The smaller metric is the better.
+    pub fn run<L, H>(&self, learner: L, metric: Metric<'_, H>)
+        -> TuningReport
+        where L: Fn(&Sample, &Params) -> H + Sync,
+    {
+        let candidates = self.candidates();
+        evaluate(self.sample, self.n_folds, candidates, &learner, metric)
+    }
+}
@@ -0,0 +1,106 @@
+use colored::Colorize;
+use rayon::prelude::*;
+
+use crate::Sample;
+use crate::research::{cross_validate, Metric};
+use super::params::Params;
+
+use std::fmt;
+
+
+const RANK_WIDTH: usize = 6;
+const VALUE_WIDTH: usize = 10;
+
+
+/// The result of a trial of a hyperparameter search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trial {
+    /// The values of the hyperparameters.
+    pub params: Params,
+    /// The value of the metric on each test fold.
+    pub folds: Vec<f64>,
+    /// The mean of the metric over the folds.
+    pub mean: f64,
+    /// The standard deviation of the metric over the folds.
+    pub std: f64,
+}
+
+
+/// The results of a hyperparameter search,
+/// returned by [`GridSearch::run`](crate::tuning::GridSearch::run)
+/// and [`RandomSearch::run`](crate::tuning::RandomSearch::run).
+/// The trials are sorted in the ascending order of the mean metric,
+/// so that the first one is the best for a loss.
+/// `Display` prints the summary table of the trials.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuningReport {
+    /// The trials sorted by the mean metric.
+    pub trials: Vec<Trial>,
+}
+
+
+impl TuningReport {
+    /// Returns the trial of the smallest mean metric.
+    pub fn best(&self) -> &Trial {
+        self.trials.first()
+            .expect("No trial is evaluated")
+    }
+}
+
+
+impl fmt::Display for TuningReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>RANK_WIDTH$} {:>VALUE_WIDTH$} {:>VALUE_WIDTH$}  {}",
+            "RANK".bold(),
+            "MEAN".bold(),
+            "STD".bold(),
+            "PARAMS".bold(),
+        )?;
+        for (rank, trial) in self.trials.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>RANK_WIDTH$} {:>VALUE_WIDTH$.4} {:>VALUE_WIDTH$.4}  {}",
+                rank + 1,
+                trial.mean,
+                trial.std,
+                trial.params,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+
+/// Evaluates each of `candidates` by `n_folds`-fold cross validation
+/// in parallel.
+pub(super) fn evaluate<L, H>(
+    sample: &Sample,
+    n_folds: usize,
+    candidates: Vec<Params>,
+    learner: &L,
+    metric: Metric<'_, H>,
+) -> TuningReport
+    where L: Fn(&Sample, &Params) -> H + Sync,
+{
+    assert!(!candidates.is_empty(), "No candidate to evaluate");
+    let mut trials = candidates.into_par_iter()
+        .map(|params| {
+            let report = cross_validate(
+                |train: &Sample| learner(train, &params),
+                sample,
+                n_folds,
+                &[metric],
+            );
+            let folds = report.folds.iter()
+                .map(|fold| fold[0])
+                .collect();
+            let mean = report.mean()[0];
+            let std = report.std()[0];
+            Trial { params, folds, mean, std }
+        })
+        .collect::<Vec<_>>();
+    trials.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+    TuningReport { trials }
+}
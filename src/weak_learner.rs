@@ -27,10 +27,47 @@ mod bad_learner;
 // Defines Naive Bayes.
 mod naive_bayes;
 
+
+// Defines a weak learner over a fixed set of hypotheses.
+mod hypothesis_pool;
+
+
+// Defines Decision Stump.
+mod decision_stump;
+
+
+// Defines Oblique Decision Tree.
+mod oblique_tree;
+
+
+// Defines the linear weak learner.
+mod linear_learner;
+
+
+// Defines the weak learner on the random Fourier features.
+mod rff_learner;
+
+
+// Defines the one-hidden-layer neural network weak learner.
+mod mlp;
+
+
+// Defines the conjunctive rule weak learner.
+mod rule_learner;
+
+
+// Defines the k-nearest neighbor weak learner.
+mod knn;
+
+
+// Defines the radial threshold weak learner.
+mod radial;
+
 pub use self::core::WeakLearner;
 
 pub use self::decision_tree::{
     Criterion,
+    ClassWeight,
     DecisionTree,
     DecisionTreeBuilder,
     DecisionTreeClassifier,
@@ -38,6 +75,7 @@ pub use self::decision_tree::{
 
 pub use self::naive_bayes::{
     GaussianNB,
+    BernoulliNB,
     NBayesClassifier,
 };
 
@@ -66,6 +104,67 @@ pub use self::bad_learner::{
 };
 
 
+pub use self::hypothesis_pool::HypothesisPool;
+
+
+pub use self::decision_stump::{
+    DecisionStump,
+    StumpClassifier,
+};
+
+
+pub use self::oblique_tree::{
+    ObliqueTree,
+    ObliqueTreeBuilder,
+    ObliqueTreeClassifier,
+};
+
+
+pub use self::linear_learner::{
+    LinearLearner,
+    LinearLearnerBuilder,
+    Logistic,
+    LinearClassifier,
+    LinearRegressor,
+};
+
+
+pub use self::rff_learner::{
+    RffLearner,
+    RffLearnerBuilder,
+    RffClassifier,
+};
+
+
+pub use self::mlp::{
+    MlpLearner,
+    MlpLearnerBuilder,
+    MlpClassifier,
+    MlpRegressor,
+};
+
+
+pub use self::rule_learner::{
+    RuleLearner,
+    RuleClassifier,
+    Condition,
+};
+
+
+pub use self::knn::{
+    KnnLearner,
+    KnnClassifier,
+};
+
+
+pub use self::radial::{
+    RadialLearner,
+    RadialClassifier,
+};
+
+
 // pub use self::union::WLUnion;
 
 pub(crate) use common::type_and_struct;
+pub use common::monotone::Monotone;
+pub use common::device::Device;
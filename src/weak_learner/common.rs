@@ -1,2 +1,11 @@
 pub(crate) mod type_and_struct;
 pub(crate) mod split_rule;
+pub(crate) mod feature_constraint;
+pub(crate) mod monotone;
+pub(crate) mod standardizer;
+pub(crate) mod bin_cache;
+pub(crate) mod row_subsample;
+pub(crate) mod parallel_growth;
+pub(crate) mod device;
+#[cfg(feature="gpu")]
+pub(crate) mod gpu_histogram;
@@ -0,0 +1,263 @@
+//! This file defines the devices
+//! on which the tree-based weak learners build the histograms.
+use std::fmt;
+
+use crate::Sample;
+use super::bin_cache::BinMap;
+
+#[cfg(feature="gpu")]
+use std::sync::Arc;
+
+#[cfg(feature="gpu")]
+use super::gpu_histogram::GpuHistogram;
+
+
+/// The minimal number of the examples of a node
+/// whose histograms are built on a GPU.
+/// The histograms of the smaller nodes are built on the CPU
+/// since the transfer to the GPU costs more than building them.
+#[cfg(feature="gpu")]
+const GPU_MIN_EXAMPLES: usize = 1 << 14;
+
+
+/// The device on which the tree-based weak learners
+/// build the histograms of the bins
+/// (see [`DecisionTreeBuilder::bins`](crate::DecisionTreeBuilder::bins)
+/// and [`RegressionTreeBuilder::bins`](crate::RegressionTreeBuilder::bins)).
+///
+/// `Device::Gpu` requires the `gpu` feature.
+/// On a GPU, the histograms of the large nodes
+/// and the sums of the leaf values are accumulated
+/// in single precision,
+/// so the resulting trees may differ slightly
+/// from the ones grown on the CPU.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let tree = RegressionTreeBuilder::new(&sample)
+///     .loss(GBMLoss::L2)
+///     .bins(255)
+///     .device(Device::Gpu)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Device {
+    /// Build the histograms on the CPU. This is the default.
+    #[default]
+    Cpu,
+    /// Build the histograms on the first GPU adapter
+    /// found by `wgpu`.
+    #[cfg(feature="gpu")]
+    Gpu,
+}
+
+
+impl Device {
+    /// Returns `true` if `self` is available on this machine.
+    pub fn is_available(&self) -> bool {
+        match self {
+            Self::Cpu => true,
+            #[cfg(feature="gpu")]
+            Self::Gpu => GpuHistogram::adapter().is_some(),
+        }
+    }
+}
+
+
+impl fmt::Display for Device {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cpu => write!(f, "CPU"),
+            #[cfg(feature="gpu")]
+            Self::Gpu => write!(f, "GPU"),
+        }
+    }
+}
+
+
+/// The sums of the values of the examples in a slot,
+/// i.e., a pair of a bin and a label.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct SlotSum {
+    pub(crate) count: usize,
+    pub(crate) first: f64,
+    pub(crate) second: f64,
+}
+
+
+/// The sums of the values of the examples of a node
+/// computed on a device.
+pub(crate) struct DeviceSums {
+    sums: Vec<SlotSum>,
+    // The first slot of each feature and the end of the last one.
+    offsets: Vec<usize>,
+}
+
+
+impl DeviceSums {
+    /// Returns the sums on the bins of the `j`-th feature
+    /// of the sample.
+    /// The sums of the labels of a bin are consecutive,
+    /// and the last bin is for the missing values.
+    #[inline]
+    pub(crate) fn feature(&self, j: usize) -> &[SlotSum] {
+        &self.sums[self.offsets[j]..self.offsets[j + 1]]
+    }
+
+
+    /// Returns the totals of each label.
+    #[inline]
+    pub(crate) fn totals(&self) -> &[SlotSum] {
+        &self.sums[*self.offsets.last().unwrap()..]
+    }
+}
+
+
+/// The state of the device of a tree-based weak learner.
+/// With `Device::Gpu`,
+/// the positions of the training examples on the bins
+/// are uploaded to the GPU when the weak learner is built.
+#[derive(Clone, Default)]
+pub(crate) struct Accelerator {
+    #[cfg(feature="gpu")]
+    gpu: Option<Arc<GpuHistogram>>,
+}
+
+
+#[cfg(feature="gpu")]
+impl Accelerator {
+    /// Prepares `device` for the histograms of the features of `sample`,
+    /// where `n_bins(bins)` is the number of the bins.
+    pub(crate) fn new<B>(
+        device: Device,
+        sample: &Sample,
+        bins: &BinMap<'_, B>,
+        n_bins: fn(&B) -> usize,
+    ) -> Self
+    {
+        if device == Device::Cpu { return Self::default(); }
+
+        let n_sample = sample.shape().0;
+        let (columns, n_bins): (Vec<_>, Vec<_>) = sample.features()
+            .iter()
+            .map(|feature| {
+                let name = feature.name();
+                let missing = n_bins(bins.get(name).unwrap());
+                let positions = bins.positions(sample, name).unwrap();
+                let column = (0..n_sample)
+                    .map(|i| positions.get(i).unwrap_or(missing) as u32)
+                    .collect::<Vec<_>>();
+                (column, missing + 1)
+            })
+            .unzip();
+        let gpu = GpuHistogram::new(columns, n_bins)
+            .expect("No GPU adapter is available");
+        Self { gpu: Some(Arc::new(gpu)) }
+    }
+
+
+    /// Returns `true` if the histograms are built on a GPU.
+    #[inline]
+    pub(crate) fn is_gpu(&self) -> bool {
+        self.gpu.is_some()
+    }
+
+
+    /// Returns the sums of the values of the examples in `indices`
+    /// for each label on each bin of each feature
+    /// if `histograms` is `true`,
+    /// and the totals for each label,
+    /// where `example(i)` is the label in `0..n_labels`
+    /// and the values of the `i`-th example.
+    /// Returns `None` if the sums should be computed on the CPU,
+    /// i.e., the device is the CPU,
+    /// `sample` does not share the features with the training sample,
+    /// or the node is small.
+    pub(crate) fn sums<B, F>(
+        &self,
+        sample: &Sample,
+        bins: &BinMap<'_, B>,
+        indices: &[usize],
+        n_labels: usize,
+        example: F,
+        histograms: bool,
+    ) -> Option<DeviceSums>
+        where F: Fn(usize) -> (u32, [f64; 2]),
+    {
+        let gpu = self.gpu.as_ref()?;
+        let name = sample.features().first()?.name();
+        let cached = bins.positions(sample, name).is_some();
+        if !cached || indices.len() < GPU_MIN_EXAMPLES { return None; }
+
+        let (labels, values): (Vec<_>, Vec<_>) = indices.iter()
+            .map(|&i| {
+                let (label, [first, second]) = example(i);
+                (label, [first as f32, second as f32])
+            })
+            .unzip();
+        let sums = gpu.sums(indices, &labels, n_labels, &values, histograms);
+
+        let mut offsets = vec![0];
+        if histograms {
+            for n in gpu.n_bins() {
+                offsets.push(offsets.last().unwrap() + n * n_labels);
+            }
+        }
+        Some(DeviceSums { sums, offsets })
+    }
+
+
+    /// Returns the information on the device.
+    pub(crate) fn info(&self) -> Option<(&str, String)> {
+        self.gpu.as_ref()
+            .map(|gpu| ("Device", format!("GPU ({})", gpu.adapter_name())))
+    }
+}
+
+
+#[cfg(not(feature="gpu"))]
+impl Accelerator {
+    /// Prepares `device` for the histograms.
+    /// Without the `gpu` feature, the device is always the CPU.
+    pub(crate) fn new<B>(
+        _device: Device,
+        _sample: &Sample,
+        _bins: &BinMap<'_, B>,
+        _n_bins: fn(&B) -> usize,
+    ) -> Self
+    {
+        Self::default()
+    }
+
+
+    /// Returns `true` if the histograms are built on a GPU.
+    #[inline]
+    pub(crate) fn is_gpu(&self) -> bool {
+        false
+    }
+
+
+    /// Returns `None` since the sums are always computed on the CPU.
+    pub(crate) fn sums<B, F>(
+        &self,
+        _sample: &Sample,
+        _bins: &BinMap<'_, B>,
+        _indices: &[usize],
+        _n_labels: usize,
+        _example: F,
+        _histograms: bool,
+    ) -> Option<DeviceSums>
+        where F: Fn(usize) -> (u32, [f64; 2]),
+    {
+        None
+    }
+
+
+    /// Returns the information on the device.
+    pub(crate) fn info(&self) -> Option<(&str, String)> {
+        None
+    }
+}
@@ -0,0 +1,352 @@
+//! This file defines the constraints on the features
+//! that tree-based weak learners can use.
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::Sample;
+use crate::common::selection;
+use super::monotone::Monotone;
+
+use std::sync::Mutex;
+use std::collections::{HashMap, HashSet};
+
+
+/// The features available to the tree under construction.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TreeFeatures {
+    /// The features used in the tree so far.
+    pub(crate) used: HashSet<String>,
+    /// The features sampled for the tree.
+    /// `None` implies that all the features are sampled.
+    sampled: Option<HashSet<String>>,
+    /// The features from which the ones of each node are sampled.
+    candidates: Vec<String>,
+    /// The features used in the splitting rules
+    /// on the path from the root to the node under construction.
+    pub(crate) path: Vec<String>,
+}
+
+
+/// Restricts the features used in splitting rules.
+/// - Features in `forbidden` are never used.
+/// - If `budget` is `Some(b)`, the weak learner uses at most `b`
+///   distinct features over all the hypotheses it produces.
+///   Once `b` features are used,
+///   the subsequent splits only use the features used so far.
+/// - If `tree_fraction` is `Some(p)`, each tree only uses
+///   a random subset of the features of size `ceil(p * # of features)`.
+/// - If `node_fraction` is `Some(p)`, each node only uses
+///   a random subset of the features sampled for the tree
+///   of size `ceil(p * # of features sampled for the tree)`.
+/// - The predictions are monotone in the features in `monotone`.
+/// - If `interactions` is not empty, the features on a path
+///   from the root must belong to a common group in `interactions`.
+///   A feature in no group forms a group by itself.
+#[derive(Debug)]
+pub(crate) struct FeatureConstraint {
+    forbidden: HashSet<String>,
+    budget: Option<usize>,
+    used: Mutex<HashSet<String>>,
+    tree_fraction: Option<f64>,
+    node_fraction: Option<f64>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+    monotone: HashMap<String, Monotone>,
+    interactions: Vec<HashSet<String>>,
+}
+
+
+impl Default for FeatureConstraint {
+    fn default() -> Self {
+        Self {
+            forbidden: HashSet::new(),
+            budget: None,
+            used: Mutex::new(HashSet::new()),
+            tree_fraction: None,
+            node_fraction: None,
+            seed: selection::SEED,
+            rng: Mutex::new(StdRng::seed_from_u64(selection::SEED)),
+            monotone: HashMap::new(),
+            interactions: Vec::new(),
+        }
+    }
+}
+
+
+impl FeatureConstraint {
+    /// Forbids the feature named `name`.
+    pub(crate) fn forbid<S: ToString>(&mut self, name: S) {
+        self.forbidden.insert(name.to_string());
+    }
+
+
+    /// Set the maximal number of distinct features.
+    pub(crate) fn set_budget(&mut self, budget: usize) {
+        assert!(budget > 0, "The feature budget must be positive");
+        self.budget = Some(budget);
+    }
+
+
+    /// Set the fraction of the features sampled for each tree.
+    pub(crate) fn set_tree_fraction(&mut self, fraction: f64) {
+        assert!(
+            0f64 < fraction && fraction <= 1f64,
+            "The fraction of the features must be in (0, 1]"
+        );
+        self.tree_fraction = Some(fraction);
+    }
+
+
+    /// Set the fraction of the features sampled for each node.
+    pub(crate) fn set_node_fraction(&mut self, fraction: f64) {
+        assert!(
+            0f64 < fraction && fraction <= 1f64,
+            "The fraction of the features must be in (0, 1]"
+        );
+        self.node_fraction = Some(fraction);
+    }
+
+
+    /// Set the seed of the randomness for the feature subsampling.
+    pub(crate) fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+    }
+
+
+    /// Constrains the predictions to be monotone
+    /// in the feature named `name`.
+    pub(crate) fn set_monotone<S: ToString>(
+        &mut self,
+        name: S,
+        direction: Monotone,
+    )
+    {
+        self.monotone.insert(name.to_string(), direction);
+    }
+
+
+    /// Returns the direction of the monotonicity constraint
+    /// on the feature named `name`, if any.
+    #[inline]
+    pub(crate) fn monotone(&self, name: &str) -> Option<Monotone> {
+        self.monotone.get(name).copied()
+    }
+
+
+    /// Returns `true` if some feature has a monotonicity constraint.
+    #[inline]
+    pub(crate) fn has_monotone(&self) -> bool {
+        !self.monotone.is_empty()
+    }
+
+
+    /// Adds a group of the features that can interact with each other.
+    pub(crate) fn add_interaction_group<I, S>(&mut self, group: I)
+        where I: IntoIterator<Item = S>,
+              S: ToString,
+    {
+        let group = group.into_iter()
+            .map(|name| name.to_string())
+            .collect::<HashSet<_>>();
+        assert!(!group.is_empty(), "An interaction group must be non-empty");
+        self.interactions.push(group);
+    }
+
+
+    /// Returns `true` if the feature named `name` can be used
+    /// on a node below the splits on the features in `path`.
+    fn interacts(&self, path: &[String], name: &str) -> bool {
+        if self.interactions.is_empty() || path.is_empty() {
+            return true;
+        }
+        let mut groups = self.interactions.iter()
+            .filter(|group| group.contains(name))
+            .peekable();
+        if groups.peek().is_none() {
+            return path.iter().all(|feature| feature == name);
+        }
+        groups.any(|group| path.iter().all(|feature| group.contains(feature)))
+    }
+
+
+    /// Returns the features available to a new tree on `sample`.
+    /// If the feature subsampling is set,
+    /// this method samples the features for the tree.
+    pub(crate) fn tree_features(&self, sample: &Sample) -> TreeFeatures {
+        if self.tree_fraction.is_none() && self.node_fraction.is_none() {
+            return TreeFeatures::default();
+        }
+        let mut candidates = sample.features()
+            .iter()
+            .map(|feature| feature.name())
+            .filter(|name| !self.forbidden.contains(*name))
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+
+        let sampled = self.tree_fraction
+            .map(|fraction| {
+                candidates = self.choose(&candidates[..], fraction);
+                candidates.iter().cloned().collect()
+            });
+        TreeFeatures {
+            used: HashSet::new(), sampled, candidates, path: Vec::new(),
+        }
+    }
+
+
+    /// Returns a subset of `candidates` of size
+    /// `ceil(fraction * candidates.len())`,
+    /// keeping the order of `candidates`.
+    fn choose(&self, candidates: &[String], fraction: f64) -> Vec<String> {
+        let n_candidates = candidates.len();
+        let size = (fraction * n_candidates as f64).ceil() as usize;
+        let size = size.min(n_candidates);
+
+        let mut rng = self.rng.lock().unwrap();
+        let mut indices = rand::seq::index::sample(
+            &mut *rng, n_candidates, size
+        ).into_vec();
+        indices.sort();
+        indices.into_iter()
+            .map(|i| candidates[i].clone())
+            .collect()
+    }
+
+
+    /// Returns a predicate that is `true`
+    /// if a split on the given feature is allowed at a node.
+    /// If the feature subsampling per node is set,
+    /// this method samples the features for the node.
+    /// `tree` is the features of the tree under construction
+    /// and `tree.path` is the ones on the path to the node.
+    pub(crate) fn node_predicate<'b>(&'b self, tree: &'b TreeFeatures)
+        -> impl Fn(&str) -> bool + Sync + 'b
+    {
+        let node = self.node_fraction
+            .map(|fraction| {
+                self.choose(&tree.candidates[..], fraction)
+                    .into_iter()
+                    .collect::<HashSet<_>>()
+            });
+        let allowed = self.predicate(tree);
+        move |name: &str| {
+            allowed(name)
+                && node.as_ref().is_none_or(|node| node.contains(name))
+                && self.interacts(&tree.path[..], name)
+        }
+    }
+
+
+    /// Returns a predicate that is `true`
+    /// if a split on the given feature is allowed in the tree.
+    /// `tree` is the features of the tree under construction.
+    pub(crate) fn predicate<'b>(&'b self, tree: &'b TreeFeatures)
+        -> impl Fn(&str) -> bool + Sync + 'b
+    {
+        let used = &tree.used;
+        let sampled = tree.sampled.as_ref();
+        let recorded = self.used.lock().unwrap();
+        let n_used = recorded.len()
+            + used.iter().filter(|name| !recorded.contains(*name)).count();
+        let exhausted = self.budget
+            .is_some_and(|budget| n_used >= budget);
+
+        move |name: &str| {
+            !self.forbidden.contains(name)
+                && sampled.is_none_or(|sampled| sampled.contains(name))
+                && (
+                    !exhausted
+                    || used.contains(name)
+                    || recorded.contains(name)
+                )
+        }
+    }
+
+
+    /// Records the features used in a tree.
+    pub(crate) fn record(&self, used: HashSet<String>) {
+        if self.budget.is_some() {
+            self.used.lock().unwrap().extend(used);
+        }
+    }
+
+
+    /// Returns `true` if the features available to a node
+    /// depend on the nodes grown before it.
+    #[inline]
+    pub(crate) fn is_sequential(&self) -> bool {
+        self.budget.is_some() || self.node_fraction.is_some()
+    }
+
+
+    /// Forgets the features used so far
+    /// and restarts the feature subsampling from the seed.
+    pub(crate) fn reset(&self) {
+        self.used.lock().unwrap().clear();
+        *self.rng.lock().unwrap() = StdRng::seed_from_u64(self.seed);
+    }
+
+
+    /// Returns the information on the constraints.
+    pub(crate) fn info(&self) -> Vec<(&str, String)> {
+        let mut info = Vec::new();
+        if !self.forbidden.is_empty() {
+            let mut names = self.forbidden.iter()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>();
+            names.sort();
+            info.push(("Forbidden features", names.join(", ")));
+        }
+        if let Some(budget) = self.budget {
+            let n_used = self.used.lock().unwrap().len();
+            info.push(("Feature budget", format!("{n_used} / {budget}")));
+        }
+        if let Some(fraction) = self.tree_fraction {
+            info.push(("Feature subsample (tree)", format!("{fraction}")));
+        }
+        if let Some(fraction) = self.node_fraction {
+            info.push(("Feature subsample (node)", format!("{fraction}")));
+        }
+        if self.tree_fraction.is_some() || self.node_fraction.is_some() {
+            info.push(("Seed", format!("{}", self.seed)));
+        }
+        if !self.monotone.is_empty() {
+            let mut names = self.monotone.iter()
+                .map(|(name, direction)| format!("{name} ({direction:?})"))
+                .collect::<Vec<_>>();
+            names.sort();
+            info.push(("Monotone features", names.join(", ")));
+        }
+        if !self.interactions.is_empty() {
+            let groups = self.interactions.iter()
+                .map(|group| {
+                    let mut names = group.iter()
+                        .map(|name| name.as_str())
+                        .collect::<Vec<_>>();
+                    names.sort();
+                    format!("{{{}}}", names.join(", "))
+                })
+                .collect::<Vec<_>>();
+            info.push(("Interaction groups", groups.join(", ")));
+        }
+        info
+    }
+}
+
+
+impl Clone for FeatureConstraint {
+    fn clone(&self) -> Self {
+        Self {
+            forbidden: self.forbidden.clone(),
+            budget: self.budget,
+            used: Mutex::new(self.used.lock().unwrap().clone()),
+            tree_fraction: self.tree_fraction,
+            node_fraction: self.node_fraction,
+            seed: self.seed,
+            rng: Mutex::new(self.rng.lock().unwrap().clone()),
+            monotone: self.monotone.clone(),
+            interactions: self.interactions.clone(),
+        }
+    }
+}
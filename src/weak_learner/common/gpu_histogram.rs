@@ -0,0 +1,373 @@
+//! This file defines the histogram construction on a GPU
+//! for the tree-based weak learners.
+//! This file is compiled only with the `gpu` feature.
+use wgpu::util::DeviceExt;
+
+use super::device::SlotSum;
+
+
+/// The compute shader of the histograms.
+const SHADER: &str = include_str!("gpu_histogram.wgsl");
+
+
+/// The number of the invocations of a workgroup,
+/// which must be the same as the `workgroup_size` in the shader.
+const WORKGROUP_SIZE: u32 = 64;
+
+
+/// The minimal number of the examples in a chunk.
+const MIN_CHUNK_SIZE: usize = 1_024;
+
+
+/// The maximal number of the chunks of the examples.
+const MAX_CHUNKS: usize = 256;
+
+
+/// The bytes of the partial sums of all the chunks
+/// allowed for a single dispatch.
+const MAX_PARTIAL_BYTES: usize = 1 << 27;
+
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Params {
+    n_sample: u32,
+    n_examples: u32,
+    n_labels: u32,
+    n_features: u32,
+    n_slots: u32,
+    chunk_size: u32,
+    n_chunks: u32,
+    padding: u32,
+}
+
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct Sum {
+    count: u32,
+    first: f32,
+    second: f32,
+}
+
+
+/// The GPU context and the positions of the training examples
+/// on the bins of the features, which reside in the GPU memory.
+pub(crate) struct GpuHistogram {
+    adapter: String,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    layout: wgpu::BindGroupLayout,
+    accumulate: wgpu::ComputePipeline,
+    reduce: wgpu::ComputePipeline,
+    positions: wgpu::Buffer,
+    features: wgpu::Buffer,
+    n_sample: usize,
+    // The number of the bins plus one for the missing values
+    // of each feature.
+    n_bins: Vec<usize>,
+}
+
+
+impl GpuHistogram {
+    /// Returns the name of the first GPU adapter,
+    /// or `None` if no adapter is available.
+    pub(crate) fn adapter() -> Option<String> {
+        let instance = instance();
+        let options = wgpu::RequestAdapterOptions::default();
+        let adapter = pollster::block_on(instance.request_adapter(&options))
+            .ok()?;
+        Some(adapter.get_info().name)
+    }
+
+
+    /// Uploads the positions of the examples on the bins.
+    /// `columns[j][i]` is the position of the `i`-th example
+    /// on the `j`-th feature,
+    /// which is in `0..=n_bins[j]`,
+    /// where `n_bins[j]` is the position of the missing values.
+    /// Returns `None` if no adapter is available.
+    pub(crate) fn new(columns: Vec<Vec<u32>>, n_bins: Vec<usize>)
+        -> Option<Self>
+    {
+        let instance = instance();
+        let options = wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        };
+        let adapter = pollster::block_on(instance.request_adapter(&options))
+            .ok()?;
+        let descriptor = wgpu::DeviceDescriptor {
+            label: Some("miniboosts histogram"),
+            required_limits: adapter.limits(),
+            ..Default::default()
+        };
+        let (device, queue) = pollster::block_on(
+            adapter.request_device(&descriptor)
+        ).ok()?;
+
+        let module = device.create_shader_module(
+            wgpu::ShaderModuleDescriptor {
+                label: Some("histogram"),
+                source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+            }
+        );
+        let entries = (0..8)
+            .map(|binding| {
+                let ty = match binding {
+                    0..=4 => storage(true),
+                    5 | 6 => storage(false),
+                    _ => wgpu::BufferBindingType::Uniform,
+                };
+                wgpu::BindGroupLayoutEntry {
+                    binding,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("histogram"),
+                entries: &entries,
+            }
+        );
+        let pipeline_layout = device.create_pipeline_layout(
+            &wgpu::PipelineLayoutDescriptor {
+                label: Some("histogram"),
+                bind_group_layouts: &[Some(&layout)],
+                ..Default::default()
+            }
+        );
+        let pipeline = |entry_point| {
+            device.create_compute_pipeline(
+                &wgpu::ComputePipelineDescriptor {
+                    label: Some(entry_point),
+                    layout: Some(&pipeline_layout),
+                    module: &module,
+                    entry_point: Some(entry_point),
+                    compilation_options: Default::default(),
+                    cache: None,
+                }
+            )
+        };
+        let accumulate = pipeline("accumulate");
+        let reduce = pipeline("reduce");
+
+        let n_sample = columns.first().map_or(0, |column| column.len());
+        let positions = columns.concat();
+        let positions = upload(&device, "positions", &positions);
+        let mut offset = 0;
+        let features = n_bins.iter()
+            .map(|&n| {
+                let feature = [offset as u32, n as u32];
+                offset += n;
+                feature
+            })
+            .collect::<Vec<_>>();
+        let features = upload(&device, "features", &features);
+
+        let adapter = adapter.get_info().name;
+        Some(Self {
+            adapter,
+            device,
+            queue,
+            layout,
+            accumulate,
+            reduce,
+            positions,
+            features,
+            n_sample,
+            n_bins,
+        })
+    }
+
+
+    /// Returns the name of the adapter.
+    pub(crate) fn adapter_name(&self) -> &str {
+        &self.adapter
+    }
+
+
+    /// Returns the number of the bins plus one of each feature.
+    pub(crate) fn n_bins(&self) -> &[usize] {
+        &self.n_bins
+    }
+
+
+    /// Returns the sums of `values` of the examples in `indices`
+    /// for each label on each bin of each feature
+    /// if `histograms` is `true`,
+    /// followed by the totals for each label.
+    /// `labels[j]` and `values[j]` are the label in `0..n_labels`
+    /// and the values of the example `indices[j]`.
+    pub(crate) fn sums(
+        &self,
+        indices: &[usize],
+        labels: &[u32],
+        n_labels: usize,
+        values: &[[f32; 2]],
+        histograms: bool,
+    ) -> Vec<SlotSum>
+    {
+        let n_examples = indices.len();
+        let n_features = if histograms { self.n_bins.len() } else { 0 };
+        let n_feature_slots = if histograms {
+            self.n_bins.iter().sum::<usize>() * n_labels
+        } else {
+            0
+        };
+        let n_slots = n_feature_slots + n_labels;
+
+        let slot_bytes = std::mem::size_of::<Sum>() * n_slots;
+        let max_chunks = (MAX_PARTIAL_BYTES / slot_bytes).clamp(1, MAX_CHUNKS);
+        let chunk_size = n_examples.div_ceil(max_chunks).max(MIN_CHUNK_SIZE);
+        let n_chunks = n_examples.div_ceil(chunk_size).max(1);
+
+        let params = Params {
+            n_sample: self.n_sample as u32,
+            n_examples: n_examples as u32,
+            n_labels: n_labels as u32,
+            n_features: n_features as u32,
+            n_slots: n_slots as u32,
+            chunk_size: chunk_size as u32,
+            n_chunks: n_chunks as u32,
+            padding: 0,
+        };
+        let indices = indices.iter()
+            .map(|&i| i as u32)
+            .collect::<Vec<_>>();
+
+        let device = &self.device;
+        let indices = upload(device, "indices", &indices);
+        let labels = upload(device, "labels", labels);
+        let values = upload(device, "values", values);
+        let partial = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("partial"),
+            size: (slot_bytes * n_chunks) as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        let sums = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("sums"),
+            size: slot_bytes as u64,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: slot_bytes as u64,
+            usage: wgpu::BufferUsages::MAP_READ
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let params = device.create_buffer_init(
+            &wgpu::util::BufferInitDescriptor {
+                label: Some("params"),
+                contents: bytemuck::bytes_of(&params),
+                usage: wgpu::BufferUsages::UNIFORM,
+            }
+        );
+
+        let buffers = [
+            &self.positions, &self.features, &indices, &labels, &values,
+            &partial, &sums, &params,
+        ];
+        let entries = buffers.iter()
+            .enumerate()
+            .map(|(binding, buffer)| {
+                wgpu::BindGroupEntry {
+                    binding: binding as u32,
+                    resource: buffer.as_entire_binding(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("histogram"),
+            layout: &self.layout,
+            entries: &entries,
+        });
+
+        let mut encoder = device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor { label: Some("histogram") }
+        );
+        {
+            let mut pass = encoder.begin_compute_pass(
+                &wgpu::ComputePassDescriptor::default()
+            );
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.set_pipeline(&self.accumulate);
+            pass.dispatch_workgroups(
+                (n_chunks as u32).div_ceil(WORKGROUP_SIZE),
+                n_features as u32 + 1,
+                1,
+            );
+            pass.set_pipeline(&self.reduce);
+            pass.dispatch_workgroups(
+                (n_slots as u32).div_ceil(WORKGROUP_SIZE), 1, 1,
+            );
+        }
+        encoder.copy_buffer_to_buffer(
+            &sums, 0, &readback, 0, slot_bytes as u64,
+        );
+        self.queue.submit([encoder.finish()]);
+
+        let slice = readback.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |result| {
+            result.expect("Failed to read the histograms from the GPU");
+        });
+        device.poll(wgpu::PollType::wait_indefinitely())
+            .expect("Failed to compute the histograms on the GPU");
+        let view = slice.get_mapped_range()
+            .expect("Failed to read the histograms from the GPU");
+        let sums = bytemuck::cast_slice::<u8, Sum>(&view)
+            .iter()
+            .map(|sum| {
+                SlotSum {
+                    count: sum.count as usize,
+                    first: sum.first as f64,
+                    second: sum.second as f64,
+                }
+            })
+            .collect::<Vec<_>>();
+        drop(view);
+        readback.unmap();
+        sums
+    }
+}
+
+
+fn instance() -> wgpu::Instance {
+    wgpu::Instance::new(
+        wgpu::InstanceDescriptor::new_without_display_handle_from_env()
+    )
+}
+
+
+fn storage(read_only: bool) -> wgpu::BufferBindingType {
+    wgpu::BufferBindingType::Storage { read_only }
+}
+
+
+/// Uploads `contents` to a new storage buffer.
+/// The empty contents are padded
+/// since the buffers must not be empty.
+fn upload<T>(device: &wgpu::Device, label: &str, contents: &[T])
+    -> wgpu::Buffer
+    where T: bytemuck::Pod,
+{
+    let bytes = bytemuck::cast_slice::<T, u8>(contents);
+    let padding = [0u8; 16];
+    let contents = if bytes.is_empty() { &padding[..] } else { bytes };
+    device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some(label),
+        contents,
+        usage: wgpu::BufferUsages::STORAGE,
+    })
+}
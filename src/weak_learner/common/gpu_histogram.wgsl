@@ -0,0 +1,96 @@
+// The histograms of the examples of a node on the bins of each feature.
+//
+// The slots of feature `f` start at `features[f].x * n_labels`
+// and hold `features[f].y * n_labels` sums,
+// where `features[f].y` is the number of the bins plus one
+// for the missing values.
+// The `n_labels` slots of the totals of the node follow the features.
+//
+// `accumulate` sums the values of each chunk of the examples
+// into its own row of `partial`,
+// and `reduce` sums the rows in the order of the chunks,
+// so that the sums do not depend on the scheduling.
+
+struct Params {
+    n_sample: u32,
+    n_examples: u32,
+    n_labels: u32,
+    n_features: u32,
+    n_slots: u32,
+    chunk_size: u32,
+    n_chunks: u32,
+    padding: u32,
+}
+
+
+struct Sum {
+    count: u32,
+    first: f32,
+    second: f32,
+}
+
+
+@group(0) @binding(0) var<storage, read> positions: array<u32>;
+@group(0) @binding(1) var<storage, read> features: array<vec2<u32>>;
+@group(0) @binding(2) var<storage, read> indices: array<u32>;
+@group(0) @binding(3) var<storage, read> labels: array<u32>;
+@group(0) @binding(4) var<storage, read> values: array<vec2<f32>>;
+@group(0) @binding(5) var<storage, read_write> partial: array<Sum>;
+@group(0) @binding(6) var<storage, read_write> sums: array<Sum>;
+@group(0) @binding(7) var<uniform> params: Params;
+
+
+// One invocation per pair of a chunk and a feature.
+// The feature `n_features` stands for the totals of the node.
+@compute @workgroup_size(64)
+fn accumulate(@builtin(global_invocation_id) id: vec3<u32>) {
+    let chunk = id.x;
+    let feature = id.y;
+    if (chunk >= params.n_chunks || feature > params.n_features) {
+        return;
+    }
+
+    var first = params.n_slots - params.n_labels;
+    var n_slots = params.n_labels;
+    if (feature < params.n_features) {
+        first = features[feature].x * params.n_labels;
+        n_slots = features[feature].y * params.n_labels;
+    }
+
+    let row = chunk * params.n_slots + first;
+    for (var s = 0u; s < n_slots; s++) {
+        partial[row + s] = Sum(0u, 0.0, 0.0);
+    }
+
+    let start = chunk * params.chunk_size;
+    let end = min(start + params.chunk_size, params.n_examples);
+    for (var j = start; j < end; j++) {
+        var position = 0u;
+        if (feature < params.n_features) {
+            position = positions[feature * params.n_sample + indices[j]];
+        }
+        let slot = row + position * params.n_labels + labels[j];
+        partial[slot].count += 1u;
+        partial[slot].first += values[j].x;
+        partial[slot].second += values[j].y;
+    }
+}
+
+
+// One invocation per slot.
+@compute @workgroup_size(64)
+fn reduce(@builtin(global_invocation_id) id: vec3<u32>) {
+    let slot = id.x;
+    if (slot >= params.n_slots) {
+        return;
+    }
+
+    var sum = Sum(0u, 0.0, 0.0);
+    for (var c = 0u; c < params.n_chunks; c++) {
+        let p = partial[c * params.n_slots + slot];
+        sum.count += p.count;
+        sum.first += p.first;
+        sum.second += p.second;
+    }
+    sums[slot] = sum;
+}
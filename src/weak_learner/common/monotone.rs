@@ -0,0 +1,100 @@
+//! This file defines the monotonicity constraints
+//! on the predictions of tree-based weak learners.
+
+
+/// The direction of a monotonicity constraint on a feature.
+/// The prediction of a constrained tree is
+/// a non-decreasing (`Increasing`) or
+/// a non-increasing (`Decreasing`) function of the feature
+/// when the other features are fixed.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let tree = RegressionTreeBuilder::new(&sample)
+///     .loss(GBMLoss::L2)
+///     .monotone(&[("age", Monotone::Increasing)])
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotone {
+    /// The prediction is non-decreasing in the feature.
+    Increasing,
+    /// The prediction is non-increasing in the feature.
+    Decreasing,
+}
+
+
+/// The range of the values that a node can predict.
+/// The children of a split on a constrained feature
+/// divide the range of their parent at the midpoint of their values,
+/// and the children of the other splits inherit it.
+/// Then, the values of the leaves are monotone
+/// in the constrained features.
+/// This is the scheme used in XGBoost and LightGBM.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Bounds {
+    lower: f64,
+    upper: f64,
+}
+
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Self { lower: f64::NEG_INFINITY, upper: f64::INFINITY }
+    }
+}
+
+
+impl Bounds {
+    /// Returns the nearest value to `value` in the range.
+    #[inline]
+    pub(crate) fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.lower, self.upper)
+    }
+
+
+    /// Returns `true` if the values `left` and `right`
+    /// of the children respect `direction`.
+    #[inline]
+    pub(crate) fn admits(
+        direction: Option<Monotone>,
+        left: f64,
+        right: f64,
+    ) -> bool
+    {
+        match direction {
+            None => true,
+            Some(Monotone::Increasing) => left <= right,
+            Some(Monotone::Decreasing) => left >= right,
+        }
+    }
+
+
+    /// Returns the ranges of the children
+    /// whose values are `left` and `right`.
+    pub(crate) fn children(
+        &self,
+        direction: Option<Monotone>,
+        left: f64,
+        right: f64,
+    ) -> (Self, Self)
+    {
+        let mid = left / 2f64 + right / 2f64;
+        let (mut lbounds, mut rbounds) = (*self, *self);
+        match direction {
+            None => {},
+            Some(Monotone::Increasing) => {
+                lbounds.upper = mid.min(self.upper);
+                rbounds.lower = mid.max(self.lower);
+            },
+            Some(Monotone::Decreasing) => {
+                lbounds.lower = mid.max(self.lower);
+                rbounds.upper = mid.min(self.upper);
+            },
+        }
+        (lbounds, rbounds)
+    }
+}
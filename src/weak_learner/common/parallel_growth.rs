@@ -0,0 +1,38 @@
+//! This file defines when tree-based weak learners
+//! grow the subtrees in parallel.
+use super::feature_constraint::FeatureConstraint;
+
+
+/// The minimal `max_depth` of the trees grown in parallel.
+/// Shallow trees are cheap enough to grow on a single thread
+/// after the parallel split search at each node.
+pub(crate) const PARALLEL_MIN_DEPTH: usize = 6;
+
+
+/// The minimal number of examples on each child
+/// to grow the subtrees in parallel.
+/// The subtrees of fewer examples are grown on the current thread
+/// since spawning a task costs more than growing them.
+pub(crate) const PARALLEL_MIN_EXAMPLES: usize = 64;
+
+
+/// Returns `true` if the two subtrees of a node
+/// should be grown in parallel tasks.
+/// The subtrees are grown one by one
+/// if the features of a node depend on the nodes grown before it,
+/// i.e., the feature budget or
+/// the feature subsampling per node is set,
+/// so that the resulting tree does not depend on the scheduling.
+#[inline]
+pub(crate) fn forks<D>(
+    max_depth: D,
+    n_left: usize,
+    n_right: usize,
+    constraint: &FeatureConstraint,
+) -> bool
+    where D: PartialOrd<usize>,
+{
+    max_depth >= PARALLEL_MIN_DEPTH
+        && n_left.min(n_right) >= PARALLEL_MIN_EXAMPLES
+        && !constraint.is_sequential()
+}
@@ -0,0 +1,93 @@
+//! This file defines the row subsampling
+//! of tree-based weak learners.
+use rand::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::common::{
+    selection,
+    weighted_sampler::WeightedSampler,
+};
+
+use std::sync::Mutex;
+
+
+/// The weighted bootstrap of the examples.
+/// If `size` is `Some(n)`,
+/// a weak learner trains on `n` examples drawn with replacement
+/// proportionally to the given weights,
+/// each of which has the mass of the number of its draws.
+#[derive(Debug)]
+pub(crate) struct RowSubsample {
+    size: Option<usize>,
+    seed: u64,
+    rng: Mutex<StdRng>,
+}
+
+
+impl Default for RowSubsample {
+    fn default() -> Self {
+        Self {
+            size: None,
+            seed: selection::SEED,
+            rng: Mutex::new(StdRng::seed_from_u64(selection::SEED)),
+        }
+    }
+}
+
+
+impl Clone for RowSubsample {
+    fn clone(&self) -> Self {
+        Self {
+            size: self.size,
+            seed: self.seed,
+            rng: Mutex::new(self.rng.lock().unwrap().clone()),
+        }
+    }
+}
+
+
+impl RowSubsample {
+    /// Set the number of the examples drawn for each weak hypothesis.
+    pub(crate) fn set_size(&mut self, size: usize) {
+        assert!(size > 0, "The subsample size must be positive");
+        self.size = Some(size);
+    }
+
+
+    /// Set the seed of the randomness for the subsampling.
+    pub(crate) fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+    }
+
+
+    /// Returns the weights of the subsample drawn from `weights`,
+    /// scaled to the total of `weights`,
+    /// or `None` if the subsampling is not set.
+    /// The examples not drawn have zero weight.
+    pub(crate) fn resample(&self, weights: &[f64]) -> Option<Vec<f64>> {
+        let size = self.size?;
+        let total = weights.iter().sum::<f64>();
+        let sampler = WeightedSampler::new(weights);
+        let mut rng = self.rng.lock().unwrap();
+        let mut subsample = sampler.bootstrap(size, &mut *rng);
+        subsample.iter_mut()
+            .for_each(|w| { *w *= total; });
+        Some(subsample)
+    }
+
+
+    /// Restarts the subsampling from the seed.
+    pub(crate) fn reset(&self) {
+        *self.rng.lock().unwrap() = StdRng::seed_from_u64(self.seed);
+    }
+
+
+    /// Returns the information on the subsampling.
+    pub(crate) fn info(&self) -> Option<(&str, String)> {
+        self.size
+            .map(|size| {
+                ("Rows per tree (weighted bootstrap)", format!("{size}"))
+            })
+    }
+}
@@ -1,5 +1,6 @@
 //! This file defines split rules for decision tree.
 use serde::*;
+use rayon::prelude::*;
 
 use crate::weak_learner::type_and_struct::*;
 use crate::Sample;
@@ -17,6 +18,15 @@ pub enum LR {
 pub(crate) struct Splitter {
     pub(crate) feature: String,
     pub(crate) threshold: Threshold,
+    /// The categories sent to the left, sorted in the ascending order.
+    /// If `Some`, the feature is categorical
+    /// and `threshold` is not used.
+    #[serde(default)]
+    pub(crate) categories: Option<Vec<i64>>,
+    /// The examples with missing values (`f64::NAN`)
+    /// go to the left if `true`, and to the right otherwise.
+    #[serde(default)]
+    pub(crate) missing_left: bool,
 }
 
 
@@ -26,7 +36,34 @@ impl Splitter {
         let feature = name.to_string();
         Self {
             feature,
-            threshold
+            threshold,
+            categories: None,
+            missing_left: false,
+        }
+    }
+
+
+    /// Send the examples with missing values to the left
+    /// if `missing_left` is `true`.
+    #[inline]
+    pub(crate) fn missing_left(mut self, missing_left: bool) -> Self {
+        self.missing_left = missing_left;
+        self
+    }
+
+
+    /// Construct a splitting rule on a categorical feature
+    /// that sends the examples in `categories` to the left.
+    /// Unseen categories go to the right.
+    #[inline]
+    pub(crate) fn categorical(name: &str, mut categories: Vec<i64>) -> Self {
+        categories.sort_unstable();
+        let feature = name.to_string();
+        Self {
+            feature,
+            threshold: Threshold::from(0f64),
+            categories: Some(categories),
+            missing_left: false,
         }
     }
 
@@ -38,6 +75,46 @@ impl Splitter {
 
         let value = sample[name][row];
 
+        if value.is_nan() {
+            return if self.missing_left { LR::Left } else { LR::Right };
+        }
+
+        if let Some(categories) = &self.categories {
+            let category = value as i64;
+            return if categories.binary_search(&category).is_ok() {
+                LR::Left
+            } else {
+                LR::Right
+            };
+        }
+
         if value < self.threshold.0 { LR::Left } else { LR::Right }
     }
 }
+
+
+/// Returns the rule of the best score in `candidates`.
+/// The best score is the smallest one if `minimize` is `true`,
+/// and the largest one otherwise.
+///
+/// The candidates are evaluated in parallel,
+/// but the result does not depend on the thread scheduling
+/// since ties are broken by the order of `candidates`.
+/// Scores that are not comparable, e.g., `f64::NAN`, are never the best.
+pub(crate) fn best_rule<S, I>(candidates: I, minimize: bool)
+    -> Option<Splitter>
+    where S: PartialOrd + Send,
+          I: ParallelIterator<Item = (S, Splitter)>,
+{
+    candidates.collect::<Vec<_>>()
+        .into_iter()
+        .reduce(|best, candidate| {
+            let better = if minimize {
+                candidate.0 < best.0
+            } else {
+                candidate.0 > best.0
+            };
+            if better { candidate } else { best }
+        })
+        .map(|(_, rule)| rule)
+}
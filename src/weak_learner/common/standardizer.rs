@@ -0,0 +1,70 @@
+//! This file defines the standardization of the features
+//! for the weak learners on the feature vectors.
+use rayon::prelude::*;
+
+use crate::Sample;
+
+
+/// The means and the standard deviations of the features.
+pub(crate) struct Standardizer {
+    pub(crate) names: Vec<String>,
+    pub(crate) means: Vec<f64>,
+    pub(crate) stds: Vec<f64>,
+}
+
+
+impl Standardizer {
+    /// Computes the means and the standard deviations
+    /// of the features of `sample`, ignoring the missing values.
+    /// The features that take a single value are dropped.
+    pub(crate) fn new(sample: &Sample) -> Self {
+        let stats = sample.features()
+            .par_iter()
+            .filter_map(|feature| {
+                let values = (0..feature.len())
+                    .map(|i| feature[i])
+                    .filter(|v| !v.is_nan())
+                    .collect::<Vec<_>>();
+                if values.is_empty() { return None; }
+                let n = values.len() as f64;
+                let mean = values.iter().sum::<f64>() / n;
+                let var = values.iter()
+                    .map(|v| (v - mean).powi(2))
+                    .sum::<f64>()
+                    / n;
+                if var <= 0f64 { return None; }
+                Some((feature.name().to_string(), mean, var.sqrt()))
+            })
+            .collect::<Vec<_>>();
+
+        let mut names = Vec::with_capacity(stats.len());
+        let mut means = Vec::with_capacity(stats.len());
+        let mut stds = Vec::with_capacity(stats.len());
+        for (name, mean, std) in stats {
+            names.push(name);
+            means.push(mean);
+            stds.push(std);
+        }
+        Self { names, means, stds }
+    }
+
+
+    /// Returns the standardized values of the features of `sample`.
+    /// The missing values are mapped to `0`.
+    pub(crate) fn columns(&self, sample: &Sample) -> Vec<Vec<f64>> {
+        let n_sample = sample.shape().0;
+        self.names.par_iter()
+            .zip(&self.means)
+            .zip(&self.stds)
+            .map(|((name, mean), std)| {
+                let feature = &sample[name];
+                (0..n_sample)
+                    .map(|i| {
+                        let x = feature[i];
+                        if x.is_nan() { 0f64 } else { (x - mean) / std }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -1,11 +1,13 @@
 use serde::{Serialize, Deserialize};
 use std::{fmt, cmp, ops};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 
 /// This is an alias from label of type `i32` 
 /// to non-negative weight of type `f64`.
-pub type LabelToWeight = HashMap<i32, f64>;
+/// The labels are iterated in the ascending order,
+/// so that the sums over the labels do not change across runs.
+pub type LabelToWeight = BTreeMap<i32, f64>;
 
 
 #[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
@@ -27,5 +27,55 @@ pub trait WeakLearner {
     /// on the given distribution `dist`.
     fn produce(&self, sample: &Sample, dist: &[f64])
         -> Self::Hypothesis;
+
+
+    /// Returns at most `k` candidate hypotheses
+    /// for the given distribution `dist`,
+    /// sorted from the most promising one.
+    /// Boosters use this method to pick a hypothesis
+    /// other than the best one
+    /// (see [`Selection`](crate::Selection))
+    /// or to add several hypotheses per round
+    /// (see [`LPBoost::columns_per_round`](crate::LPBoost::columns_per_round)).
+    /// By default, this method returns the output of
+    /// [`WeakLearner::produce`] only.
+    fn produce_many(&self, sample: &Sample, dist: &[f64], k: usize)
+        -> Vec<Self::Hypothesis>
+    {
+        assert!(k > 0, "The number of candidates must be positive");
+        vec![self.produce(sample, dist)]
+    }
+
+
+    /// Returns a hypothesis for the given `dist`
+    /// where the `i`-th example has the importance weight `weights[i]`.
+    /// Boosters that reweight a subsample of the training examples
+    /// (see [`GBM::goss`](crate::booster::GBM::goss))
+    /// use this method.
+    /// By default, this method ignores `weights` and returns
+    /// the output of [`WeakLearner::produce`].
+    fn produce_with_weights(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        _weights: &[f64],
+    ) -> Self::Hypothesis
+    {
+        self.produce(sample, dist)
+    }
+
+
+    /// Returns the complexity (e.g., the Rademacher complexity)
+    /// of the hypothesis class that `hypothesis` belongs to.
+    /// Boosters that penalize complex hypotheses
+    /// (see [`DeepBoost`](crate::booster::DeepBoost))
+    /// use this method.
+    /// By default, this method returns `0`,
+    /// i.e., no hypothesis is penalized.
+    fn complexity(&self, _sample: &Sample, _hypothesis: &Self::Hypothesis)
+        -> f64
+    {
+        0f64
+    }
 }
 
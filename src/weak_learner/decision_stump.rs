@@ -0,0 +1,7 @@
+/// Defines `DecisionStump`.
+mod stump_algorithm;
+/// Defines `StumpClassifier` returned by `DecisionStump`.
+mod stump_classifier;
+
+pub use stump_algorithm::DecisionStump;
+pub use stump_classifier::StumpClassifier;
@@ -0,0 +1,198 @@
+use rayon::prelude::*;
+
+use crate::{Sample, WeakLearner};
+use super::stump_classifier::StumpClassifier;
+
+
+/// A weak learner that returns the decision stump,
+/// a threshold function on a single feature,
+/// with the maximum edge `Σ_i d_i y_i h(x_i)`.
+///
+/// [`DecisionStump`] sorts the examples by each feature
+/// only once in [`DecisionStump::init`].
+/// Thus, each call of [`WeakLearner::produce`]
+/// takes `O(nd)` time for `n` examples and `d` features,
+/// a linear scan per feature.
+/// The features are scanned in parallel.
+/// Since the set of decision stumps is closed under negation,
+/// the best edge is always non-negative.
+///
+/// The presorted indices are reused
+/// for the sample given to [`DecisionStump::init`]
+/// and the samples that share the features with it,
+/// such as the binary samples made by
+/// [`OneVsAll`](crate::booster::OneVsAll).
+/// For other samples, `produce` sorts the examples on each call.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let stump = DecisionStump::init(&sample);
+///
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&stump);
+/// ```
+pub struct DecisionStump<'a> {
+    sample: &'a Sample,
+    // `sorted[j]` is the pairs `(row, value)` of the `j`-th feature
+    // sorted in the ascending order of the values.
+    sorted: Vec<Vec<(usize, f64)>>,
+}
+
+
+impl<'a> DecisionStump<'a> {
+    /// Construct a new instance of `DecisionStump`
+    /// and sort the examples by each feature.
+    ///
+    /// Time complexity: `O(n d log n)`.
+    pub fn init(sample: &'a Sample) -> Self {
+        let sorted = sort_features(sample);
+        Self { sample, sorted }
+    }
+
+
+    /// Returns the best stump on each feature
+    /// as the triplet `(edge, threshold, sign)`.
+    fn stumps(&self, sample: &Sample, dist: &[f64]) -> Vec<(f64, f64, f64)> {
+        let n_sample = sample.shape().0;
+        assert_eq!(
+            dist.len(), n_sample,
+            "The length of the distribution must be the number of examples"
+        );
+
+        let resorted;
+        let sorted = if sample.shares_features_with(self.sample) {
+            &self.sorted
+        } else {
+            resorted = sort_features(sample);
+            &resorted
+        };
+
+
+        let target = sample.target();
+        sorted.par_iter()
+            .map(|pairs| best_stump_on(pairs, target, dist))
+            .collect()
+    }
+}
+
+
+/// Returns the pairs `(row, value)` of each feature
+/// sorted in the ascending order of the values.
+fn sort_features(sample: &Sample) -> Vec<Vec<(usize, f64)>> {
+    let n_sample = sample.shape().0;
+    sample.features()
+        .par_iter()
+        .map(|feature| {
+            let mut pairs = (0..n_sample)
+                .map(|row| (row, feature[row]))
+                .collect::<Vec<_>>();
+            pairs.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+            pairs
+        })
+        .collect()
+}
+
+
+/// Returns the best stump on a single feature
+/// as the triplet `(edge, threshold, sign)`.
+/// The threshold `f64::MIN` corresponds to the constant stump.
+fn best_stump_on(sorted: &[(usize, f64)], target: &[f64], dist: &[f64])
+    -> (f64, f64, f64)
+{
+    let total = sorted.iter()
+        .map(|&(i, _)| dist[i] * target[i])
+        .sum::<f64>();
+
+    let mut best_edge = total;
+    let mut best_threshold = f64::MIN;
+
+    // `left` is the sum of `d_i y_i` over the examples
+    // below the current threshold.
+    let mut left = 0f64;
+    for (k, &(i, value)) in sorted.iter().enumerate() {
+        left += dist[i] * target[i];
+
+        let Some(&(_, next)) = sorted.get(k + 1) else { break; };
+        if value >= next { continue; }
+
+        let edge = total - 2f64 * left;
+        if edge.abs() > best_edge.abs() {
+            best_edge = edge;
+            best_threshold = value / 2f64 + next / 2f64;
+        }
+    }
+
+    let sign = if best_edge >= 0f64 { 1f64 } else { -1f64 };
+    (best_edge.abs(), best_threshold, sign)
+}
+
+
+impl WeakLearner for DecisionStump<'_> {
+    type Hypothesis = StumpClassifier;
+
+
+    fn name(&self) -> &str {
+        "Decision Stump"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+        ]);
+        Some(info)
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let stumps = self.stumps(sample, dist);
+
+        // Ties are broken by the position of the feature.
+        let mut best = 0;
+        for (j, stump) in stumps.iter().enumerate().skip(1) {
+            if stump.0 > stumps[best].0 { best = j; }
+        }
+
+        let (_, threshold, sign) = stumps[best];
+        let name = sample.features()[best].name();
+        StumpClassifier::new(name, threshold, sign)
+    }
+
+    /// Returns the best stumps on the `k` features
+    /// of the largest edges,
+    /// sorted from the largest edge.
+    /// Ties are broken by the position of the feature.
+    /// Since the constant stumps on different features are the same,
+    /// at most one constant stump is returned.
+    fn produce_many(&self, sample: &Sample, dist: &[f64], k: usize)
+        -> Vec<Self::Hypothesis>
+    {
+        assert!(k > 0, "The number of candidates must be positive");
+        let stumps = self.stumps(sample, dist);
+
+        let mut order = (0..stumps.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| stumps[b].0.total_cmp(&stumps[a].0));
+
+        let mut has_constant = false;
+        let mut hypotheses = Vec::with_capacity(k);
+        for j in order {
+            if hypotheses.len() >= k { break; }
+            let (_, threshold, sign) = stumps[j];
+            if threshold == f64::MIN {
+                if has_constant { continue; }
+                has_constant = true;
+            }
+            let name = sample.features()[j].name();
+            hypotheses.push(StumpClassifier::new(name, threshold, sign));
+        }
+        hypotheses
+    }
+}
@@ -0,0 +1,62 @@
+//! Defines the decision stump classifier.
+use serde::{Serialize, Deserialize};
+
+use crate::{Classifier, Sample};
+
+
+/// A decision stump, a threshold function on a single feature.
+/// `StumpClassifier` predicts `sign` for the examples
+/// whose value of `feature` is greater than `threshold`,
+/// and `-sign` otherwise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StumpClassifier {
+    feature: String,
+    threshold: f64,
+    sign: f64,
+}
+
+
+impl StumpClassifier {
+    /// Construct a new decision stump.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn new<T: ToString>(feature: T, threshold: f64, sign: f64) -> Self {
+        assert!(
+            sign == 1.0 || sign == -1.0,
+            "The sign of a decision stump must be `1.0` or `-1.0`"
+        );
+        Self { feature: feature.to_string(), threshold, sign, }
+    }
+
+
+    /// Returns the name of the feature that the stump splits on.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn feature(&self) -> &str {
+        &self.feature
+    }
+
+
+    /// Returns the threshold of the stump.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+
+    /// Returns the prediction for the examples above the threshold.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn sign(&self) -> f64 {
+        self.sign
+    }
+}
+
+
+impl Classifier for StumpClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let value = sample[&self.feature][row];
+        if value > self.threshold { self.sign } else { -self.sign }
+    }
+}
@@ -7,14 +7,19 @@ mod decision_tree_classifier;
 mod builder;
 
 pub(crate) mod bin;
+// Defines the exclusive feature bundling.
+mod bundle;
 
 // Defines the inner representations of `DecisionTreeClassifier`.
 mod node;
 mod criterion;
 mod train_node;
+// Defines the class weights of the examples.
+mod class_weight;
 
 
 pub use decision_tree_classifier::DecisionTreeClassifier;
 pub use decision_tree_algorithm::DecisionTree;
 pub use criterion::Criterion;
+pub use class_weight::ClassWeight;
 pub use builder::DecisionTreeBuilder;
@@ -2,15 +2,17 @@ use rayon::prelude::*;
 use std::fmt;
 use std::ops::Range;
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 
 use crate::weak_learner::common::{
     type_and_struct::*,
+    bin_cache::Positions,
+    device::SlotSum,
 };
 
 use crate::sample::{
     Feature,
     feature_struct::{
-        DenseFeature,
         SparseFeature,
     },
 };
@@ -23,7 +25,7 @@ const NUM_TOLERANCE: f64 = 1e-9;
 
 
 /// Binning: A feature processing.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Bin(pub Range<f64>);
 
 impl Bin {
@@ -43,7 +45,9 @@ impl Bin {
 
 
 /// A wrapper of `Vec<Bin>`.
-pub struct Bins(Vec<Bin>);
+/// The second field holds the categories of a categorical feature,
+/// the `k`-th of which is the only category in the `k`-th bin.
+pub struct Bins(Vec<Bin>, Option<Vec<i64>>);
 
 impl Bins {
     /// Returns the number of bins.
@@ -57,8 +61,12 @@ impl Bins {
     #[inline(always)]
     pub fn cut(feature: &Feature, n_bin: usize) -> Self
     {
+        // A feature with no value other than the missing ones
+        // has a single bin.
+        let n_bin = n_bin.max(1);
         let mut bins = match feature {
-            Feature::Dense(feat) => Self::cut_dense(feat, n_bin),
+            Feature::Dense(feat) => Self::cut_dense(&feat.sample, n_bin),
+            Feature::Mapped(feat) => Self::cut_dense(feat.values(), n_bin),
             Feature::Sparse(feat) => Self::cut_sparse(feat, n_bin),
         };
 
@@ -71,37 +79,79 @@ impl Bins {
     }
 
 
-    // /// Cut the given `Feature` into `n_bins` bins.
-    // /// This method returns a vector of `Bin`,
-    // /// where each `Bin` has almost the same elements.
-    // pub fn qcut(feature: &Feature, n_bin: usize)
-    //     -> Vec<Self>
-    // {
-    //     let items = value_counts(feature);
+    /// Cut the given `Feature` into at most `n_bin` bins
+    /// so that each bin has almost the same number of examples.
+    /// Since the examples with the same value fall into the same bin,
+    /// the number of bins may be smaller than `n_bin`.
+    /// The missing values are ignored.
+    pub fn qcut(feature: &Feature, n_bin: usize) -> Self {
+        assert!(n_bin > 0, "The number of bins must be positive");
+        let mut values = match feature {
+            Feature::Dense(feat) => feat.sample[..].to_vec(),
+            Feature::Mapped(feat) => feat.values().to_vec(),
+            Feature::Sparse(feat) => {
+                let mut values = feat.sample[..].iter()
+                    .map(|(_, v)| *v)
+                    .collect::<Vec<_>>();
+                values.resize(values.len() + feat.zero_counts(), 0f64);
+                values
+            },
+        };
+        values.retain(|v| !v.is_nan());
+        values.sort_by(|a, b| a.total_cmp(b));
 
-    //     if items.is_empty() {
-    //         return Vec::with_capacity(0);
-    //     }
 
-    //     let n_feature = feature.len();
-    //     let item_per_bin = n_feature / n_bin;
+        let n_sample = values.len();
+        let mut bins = Vec::with_capacity(n_bin);
+        let mut left = f64::MIN;
+        for k in 1..n_bin {
+            // Move the cut point to the next distinct value.
+            let mut pos = k * n_sample / n_bin;
+            if pos == 0 { continue; }
+            while pos < n_sample && values[pos - 1] == values[pos] {
+                pos += 1;
+            }
+            if pos >= n_sample { break; }
+
+            let right = values[pos - 1] / 2f64 + values[pos] / 2f64;
+            if right <= left { continue; }
+            bins.push(Bin::new(left..right));
+            left = right;
+        }
+        bins.push(Bin::new(left..f64::MAX));
 
-    //     let mut iter = items.into_iter()
-    //         .peekable();
+        Self(bins, None)
+    }
 
-    //     let mut bins: Vec<Self> = Vec::with_capacity(n_bin);
 
+    /// Returns the bins of a categorical feature
+    /// such that each bin contains exactly one of `categories`.
+    /// `categories` must be sorted in the ascending order.
+    pub fn categorical(categories: &[i64]) -> Self {
+        assert!(!categories.is_empty(), "No category is given");
+        let mut bins = Vec::with_capacity(categories.len());
+        let mut left = f64::MIN;
+        for pair in categories.windows(2) {
+            let right = (pair[0] as f64 + pair[1] as f64) / 2f64;
+            bins.push(Bin::new(left..right));
+            left = right;
+        }
+        bins.push(Bin::new(left..f64::MAX));
+        Self(bins, Some(categories.to_vec()))
+    }
 
-    //     todo!()
-    // }
+
+    /// Returns the categories if the bins are of a categorical feature.
+    pub(crate) fn categories(&self) -> Option<&[i64]> {
+        self.1.as_deref()
+    }
 
 
-    fn cut_dense(feature: &DenseFeature, n_bin: usize) -> Self
+    fn cut_dense(values: &[f64], n_bin: usize) -> Self
     {
         let mut min = f64::MAX;
         let mut max = f64::MIN;
-        feature.sample[..]
-            .iter()
+        values.iter()
             .copied()
             .for_each(|val| {
                 min = min.min(val);
@@ -109,6 +159,13 @@ impl Bins {
             });
 
 
+        // All the values are missing.
+        if min > max {
+            min = 0.0;
+            max = 0.0;
+        }
+
+
         // If the minimum value equals to the maximum one,
         // slightly perturb them.
         if min == max {
@@ -136,7 +193,7 @@ impl Bins {
 
         assert_eq!(bins.len(), n_bin);
 
-        Self(bins)
+        Self(bins, None)
     }
 
 
@@ -163,6 +220,13 @@ impl Bins {
         }
 
 
+        // All the values are missing.
+        if min > max {
+            min = 0.0;
+            max = 0.0;
+        }
+
+
         // If the minimum value equals to the maximum one,
         // slightly perturb them.
         if min == max {
@@ -190,42 +254,99 @@ impl Bins {
 
         assert_eq!(bins.len(), n_bin);
 
-        Self(bins)
+        Self(bins, None)
+    }
+
+
+    /// Returns the index of the bin that contains `x`.
+    #[inline(always)]
+    pub(crate) fn position(&self, x: f64) -> usize {
+        self.0.binary_search_by(|range| {
+                if range.contains(&x) {
+                    return Ordering::Equal;
+                }
+                range.0.start.partial_cmp(&x).unwrap()
+            })
+            .unwrap()
     }
 
 
-    pub(crate) fn pack(
+    /// Returns the index of the bin that contains the `i`-th example,
+    /// or `None` if the value is missing.
+    /// The cached `positions` of the examples are used if given.
+    #[inline(always)]
+    fn locate(&self, feat: &Feature, positions: Option<Positions<'_>>, i: usize)
+        -> Option<usize>
+    {
+        match positions {
+            Some(positions) => positions.get(i),
+            None => {
+                let xi = feat[i];
+                if xi.is_nan() { None } else { Some(self.position(xi)) }
+            },
+        }
+    }
+
+
+    /// Returns the weights of the labels on each bin
+    /// for the examples in `indices`
+    /// and the ones of the examples with missing values.
+    /// The empty bins are kept and the weights are not normalized.
+    /// See `Bins::remove_zero_weight_pack_and_normalize`.
+    pub(crate) fn raw_pack(
         &self,
         indices: &[usize],
         feat: &Feature,
+        positions: Option<Positions<'_>>,
         y: &[f64],
         dist: &[f64]
-    ) -> Vec<(Bin, LabelToWeight)>
+    ) -> (Vec<LabelToWeight>, LabelToWeight)
     {
         let n_bins = self.0.len();
         let mut packed = vec![LabelToWeight::new(); n_bins];
+        let mut missing = LabelToWeight::new();
 
         for &i in indices {
-            let xi = feat[i];
             let yi = y[i] as i32;
             let di = dist[i];
 
 
-            let pos = self.0.binary_search_by(|range| {
-                    if range.contains(&xi) {
-                        return Ordering::Equal;
-                    }
-                    range.0.start.partial_cmp(&xi).unwrap()
-                })
-                .unwrap();
-            let weight = packed[pos].entry(yi).or_insert(0.0);
+            let weight = match self.locate(feat, positions, i) {
+                Some(pos) => packed[pos].entry(yi).or_insert(0.0),
+                None => missing.entry(yi).or_insert(0.0),
+            };
             *weight += di;
         }
-        self.remove_zero_weight_pack_and_normalize(packed)
+        (packed, missing)
     }
 
 
 
+    /// Returns the histogram of the weights
+    /// over the bins for the examples in `indices`.
+    pub(crate) fn histogram(
+        &self,
+        indices: &[usize],
+        feat: &Feature,
+        positions: Option<Positions<'_>>,
+        y: &[f64],
+        dist: &[f64]
+    ) -> Histogram
+    {
+        // The last entry is for the examples with missing values.
+        let n_bins = self.0.len();
+        let mut hist = vec![BTreeMap::new(); n_bins + 1];
+
+        for &i in indices {
+            let pos = self.locate(feat, positions, i).unwrap_or(n_bins);
+            let entry = hist[pos].entry(y[i] as i32).or_insert((0, 0f64));
+            entry.0 += 1;
+            entry.1 += dist[i];
+        }
+        Histogram(hist)
+    }
+
+
     /// This method removes bins with zero weights.
     /// # Example
     /// Assume that we have bins and its weight.
@@ -244,12 +365,12 @@ impl Bins {
     /// That is, this method
     /// - Change the bin bounds,
     /// - 
-    fn remove_zero_weight_pack_and_normalize(
+    pub(crate) fn remove_zero_weight_pack_and_normalize(
         &self,
         pack: Vec<LabelToWeight>
     ) -> Vec<(Bin, LabelToWeight)>
     {
-        let total_weight = pack.par_iter()
+        let total_weight = pack.iter()
             .map(|mp| mp.values().sum::<f64>())
             .sum::<f64>();
         assert!(total_weight > 0.0);
@@ -414,6 +535,83 @@ impl Bins {
 // }
 
 
+/// A histogram over the bins of a feature.
+/// Each bin maps a label to the number of examples and their total weight.
+/// The last bin is for the examples with missing values.
+/// The counts detect the empty bins exactly
+/// after [`Histogram::subtract`].
+#[derive(Clone)]
+pub(crate) struct Histogram(Vec<BTreeMap<i32, (usize, f64)>>);
+
+
+impl Histogram {
+    /// Construct the histogram from the sums on the bins
+    /// computed on a device,
+    /// where the sums of `labels` on each bin are consecutive.
+    pub(crate) fn from_sums(sums: &[SlotSum], labels: &[i32]) -> Self {
+        let hist = sums.chunks(labels.len())
+            .map(|bin| {
+                labels.iter()
+                    .zip(bin)
+                    .filter(|(_, sum)| sum.count > 0)
+                    .map(|(&y, sum)| (y, (sum.count, sum.first)))
+                    .collect()
+            })
+            .collect();
+        Self(hist)
+    }
+
+
+    /// Returns the total weight of each label over the bins.
+    pub(crate) fn totals(&self) -> BTreeMap<i64, f64> {
+        let mut totals = BTreeMap::new();
+        for (&y, &(_, w)) in self.0.iter().flatten() {
+            *totals.entry(y as i64).or_insert(0f64) += w;
+        }
+        totals
+    }
+
+
+    /// Returns the weights of the labels on each bin
+    /// in the same format as `Bins::raw_pack`.
+    pub(crate) fn raw_pack(&self) -> (Vec<LabelToWeight>, LabelToWeight) {
+        let mut packed = self.0.iter()
+            .map(|map| {
+                map.iter()
+                    .filter(|(_, (count, _))| *count > 0)
+                    .map(|(&y, &(_, w))| (y, w.max(0f64)))
+                    .collect::<LabelToWeight>()
+            })
+            .collect::<Vec<_>>();
+        let missing = packed.pop().unwrap();
+        (packed, missing)
+    }
+
+
+    /// Returns the histogram of the examples in `self`
+    /// but not in `other`.
+    /// If `self` is the histogram of a node
+    /// and `other` is the one of a child,
+    /// the result is the histogram of the sibling.
+    pub(crate) fn subtract(&self, other: &Self) -> Self {
+        let hist = self.0.iter()
+            .zip(&other.0)
+            .map(|(parent, child)| {
+                let mut map = parent.clone();
+                for (y, (count, weight)) in child {
+                    let entry = map.get_mut(y).unwrap();
+                    entry.0 -= count;
+                    entry.1 -= weight;
+                    if entry.0 == 0 { map.remove(y); }
+                }
+                map
+            })
+            .collect();
+        Self(hist)
+    }
+}
+
+
 const PRINT_BIN_SIZE: usize = 3;
 
 impl fmt::Display for Bins {
@@ -1,7 +1,17 @@
 use crate::{Sample, DecisionTree};
-use crate::weak_learner::common::type_and_struct::*;
+use crate::weak_learner::common::{
+    type_and_struct::*,
+    feature_constraint::FeatureConstraint,
+    monotone::Monotone,
+    bin_cache::BinMap,
+    row_subsample::RowSubsample,
+    device::{Device, Accelerator},
+};
 use super::bin::*;
 use super::criterion::*;
+use super::class_weight::ClassWeight;
+use super::bundle::FeatureBundle;
+use super::decision_tree_algorithm::GrowthLimits;
 use std::collections::HashMap;
 
 
@@ -9,6 +19,9 @@ use std::collections::HashMap;
 pub const DEFAULT_NBIN: usize = 255;
 /// The maxmial depth set as default.
 pub const DEFAULT_MAX_DEPTH: usize = 2;
+/// The maximal number of categories of a categorical feature
+/// set as default.
+pub const DEFAULT_MAX_CATEGORIES: usize = 256;
 
 
 /// A struct that builds `DecisionTree`.
@@ -32,6 +45,23 @@ pub struct DecisionTreeBuilder<'a> {
 
     max_depth: Depth,
     criterion: Criterion,
+    /// Bundle mutually exclusive sparse features or not.
+    bundle_features: bool,
+    /// Restrictions on the features used in splitting rules.
+    constraint: FeatureConstraint,
+    /// Cut the features by quantiles
+    /// and search splits on the histograms.
+    histogram: bool,
+    /// The maximal number of categories of a categorical feature.
+    max_categories: usize,
+    /// Restrictions on the growth of the trees.
+    limits: GrowthLimits,
+    /// The weights of the classes.
+    class_weight: Option<ClassWeight>,
+    /// The weighted bootstrap of the examples for each tree.
+    subsample: RowSubsample,
+    /// The device that builds the histograms.
+    device: Device,
 }
 
 
@@ -42,6 +72,15 @@ impl<'a> DecisionTreeBuilder<'a> {
     /// n_bins: DEFAULT_NBIN == 255,
     /// max_depth: DEFAULT_MAX_DEPTH == 2,
     /// criterion: Criterion::Entropy,
+    /// bundle_exclusive_features: false,
+    /// histogram: false,
+    /// max_categories: DEFAULT_MAX_CATEGORIES == 256,
+    /// min_samples_leaf: 1,
+    /// min_split_gain: 0.0,
+    /// max_leaves: None,
+    /// class_weight: None,
+    /// train_on_subsample: None,
+    /// device: Device::Cpu,
     /// ```
     pub fn new(sample: &'a Sample) -> Self {
         let n_bins = sample.features()
@@ -54,12 +93,40 @@ impl<'a> DecisionTreeBuilder<'a> {
             .collect();
         let max_depth = Depth::from(DEFAULT_MAX_DEPTH);
         let criterion = Criterion::Entropy;
+        let bundle_features = false;
+        let constraint = FeatureConstraint::default();
+        let histogram = false;
+        let max_categories = DEFAULT_MAX_CATEGORIES;
+        let limits = GrowthLimits::default();
+        let class_weight = None;
+        let subsample = RowSubsample::default();
+        let device = Device::default();
 
-        Self { sample, n_bins, max_depth, criterion, }
+        Self {
+            sample,
+            n_bins,
+            max_depth,
+            criterion,
+            bundle_features,
+            constraint,
+            histogram,
+            max_categories,
+            limits,
+            class_weight,
+            subsample,
+            device,
+        }
     }
 
 
     /// Specify the maximal depth of the tree.
+    /// If `depth >= 6`, the two subtrees of a node are grown
+    /// in parallel tasks,
+    /// unless the feature budget
+    /// or the feature subsampling per node is set.
+    /// The resulting trees are the same as the ones grown one by one.
+    /// The subtrees of the trees grown in the best-first order
+    /// (see [`DecisionTreeBuilder::max_leaves`]) are not grown in parallel.
     /// Default maximal depth is `2`.
     pub fn max_depth(mut self, depth: usize) -> Self {
         assert!(depth > 0, "Tree must have positive depth");
@@ -69,6 +136,71 @@ impl<'a> DecisionTreeBuilder<'a> {
     }
 
 
+    /// Set the minimal number of examples on a leaf.
+    /// A split that leaves fewer than `n_samples` examples
+    /// on one of the children is not made.
+    /// The examples of zero weight are not counted.
+    /// Default value is `1`.
+    pub fn min_samples_leaf(mut self, n_samples: usize) -> Self {
+        assert!(n_samples > 0, "The minimal leaf size must be positive");
+        self.limits.min_samples_leaf = n_samples;
+        self
+    }
+
+
+    /// Set the minimal decrease of the training loss by a split.
+    /// The training loss of a node is the total weight
+    /// of the examples misclassified by the node as a leaf.
+    /// A split that decreases the training loss
+    /// by less than `min_gain` is not made.
+    /// Default value is `0.0`.
+    pub fn min_split_gain(mut self, min_gain: f64) -> Self {
+        assert!(min_gain >= 0f64, "The minimal gain must be non-negative");
+        self.limits.min_split_gain = min_gain;
+        self
+    }
+
+
+    /// Set the maximal number of leaves.
+    /// If this method is called,
+    /// the trees are grown in the best-first order;
+    /// the leaf whose split decreases the training loss the most
+    /// is split first,
+    /// until the tree has `max_leaves` leaves
+    /// or no leaf can be split.
+    /// The maximal depth is still respected.
+    /// By default, there is no limit and
+    /// the trees are grown in the depth-first order.
+    pub fn max_leaves(mut self, max_leaves: usize) -> Self {
+        assert!(
+            max_leaves > 0,
+            "The maximal number of leaves must be positive"
+        );
+        self.limits.max_leaves = Some(max_leaves);
+        self
+    }
+
+
+    /// Weight the classes by `class_weight`.
+    /// The mass of each example on the given distribution
+    /// is multiplied by the weight of its class
+    /// before computing the impurities and the confidences of the leaves.
+    /// See [`ClassWeight`] for the options.
+    /// By default, the classes are not weighted.
+    pub fn class_weight(mut self, class_weight: ClassWeight) -> Self {
+        if let ClassWeight::Explicit(pairs) = &class_weight {
+            for (label, weight) in pairs {
+                assert!(
+                    *weight > 0f64,
+                    "The weight of class `{label}` must be positive"
+                );
+            }
+        }
+        self.class_weight = Some(class_weight);
+        self
+    }
+
+
     /// Set the node splitting rule.
     /// Default value is `Criterion::Entropy`.
     /// See [`Criterion`] for other rules.
@@ -79,6 +211,206 @@ impl<'a> DecisionTreeBuilder<'a> {
     }
 
 
+    /// Bundle the mutually exclusive sparse features
+    /// (e.g., one-hot encoded categorical features)
+    /// before growing trees.
+    /// Bundled features are scanned at once while searching a split,
+    /// but the resulting splitting rules
+    /// refer to the original features.
+    /// Default value is `false`.
+    #[inline]
+    pub fn bundle_exclusive_features(mut self, flag: bool) -> Self {
+        self.bundle_features = flag;
+        self
+    }
+
+
+    /// Forbid the features in `names` from being used in splitting rules.
+    /// By default, all the features are allowed.
+    pub fn forbid_features<I, T>(mut self, names: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        for name in names {
+            let name = name.as_ref();
+            if !self.n_bins.contains_key(name) {
+                panic!("The feature named `{name}` does not exist");
+            }
+            self.constraint.forbid(name);
+        }
+        self
+    }
+
+
+    /// Set the maximal number of distinct features
+    /// used over all the trees produced by the resulting [`DecisionTree`].
+    /// Once `budget` features are used,
+    /// the subsequent trees only split on the features used so far.
+    /// By default, there is no budget.
+    pub fn feature_budget(mut self, budget: usize) -> Self {
+        self.constraint.set_budget(budget);
+        self
+    }
+
+
+    /// Let each tree only consider a random subset of the features
+    /// of size `ceil(fraction * # of features)`.
+    /// The subset is sampled for every tree
+    /// produced by the resulting [`DecisionTree`].
+    /// The forbidden features are excluded before the sampling.
+    /// By default, every tree considers all the features.
+    pub fn feature_subsample(mut self, fraction: f64) -> Self {
+        self.constraint.set_tree_fraction(fraction);
+        self
+    }
+
+
+    /// Let each node only consider a random subset of the features
+    /// sampled for the tree
+    /// of size `ceil(fraction * # of features sampled for the tree)`.
+    /// Combined with [`DecisionTreeBuilder::feature_subsample`],
+    /// the features of a node are sampled from the ones of the tree.
+    /// By default, every node considers all the features of the tree.
+    pub fn feature_subsample_per_node(mut self, fraction: f64) -> Self {
+        self.constraint.set_node_fraction(fraction);
+        self
+    }
+
+
+    /// Train each tree on a weighted bootstrap of `n_samples` examples
+    /// drawn with replacement
+    /// proportionally to the given distribution,
+    /// instead of the full sample.
+    /// Each drawn example has the mass
+    /// proportional to the number of its draws,
+    /// and the examples not drawn are not scanned.
+    /// By default, each tree is trained on the full sample.
+    pub fn train_on_subsample(mut self, n_samples: usize) -> Self {
+        self.subsample.set_size(n_samples);
+        self
+    }
+
+
+    /// Set the seed of the randomness
+    /// for the feature subsampling and the row subsampling.
+    /// Default value is `1234`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.constraint.set_seed(seed);
+        self.subsample.set_seed(seed);
+        self
+    }
+
+
+    /// Constrain the predictions of the trees
+    /// to be monotone in the given features.
+    /// `constraints` is the pairs of a feature name and its direction,
+    /// e.g., `&[("age", Monotone::Increasing)]`.
+    /// At each node, the best split among the ones whose children
+    /// respect the directions is chosen,
+    /// and the confidences of the descendants are bounded accordingly.
+    /// Categorical splits on the constrained features are not used.
+    /// By default, no feature is constrained.
+    pub fn monotone<T>(mut self, constraints: &[(T, Monotone)]) -> Self
+        where T: AsRef<str>,
+    {
+        for (name, direction) in constraints {
+            let name = name.as_ref();
+            if !self.n_bins.contains_key(name) {
+                panic!("The feature named `{name}` does not exist");
+            }
+            self.constraint.set_monotone(name, *direction);
+        }
+        self
+    }
+
+
+    /// Restrict the interactions of the features in each tree.
+    /// `groups` is the groups of the features that can interact,
+    /// e.g., `[vec!["x", "y"], vec!["y", "z"]]`.
+    /// The features on every path from the root of a tree
+    /// belong to a common group,
+    /// and a feature in no group is used alone on the paths.
+    /// In the example, `x` and `z` never appear on the same path.
+    /// By default, all the features can interact.
+    pub fn interaction_constraints<I, G, T>(mut self, groups: I) -> Self
+        where I: IntoIterator<Item = G>,
+              G: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        for group in groups {
+            let group = group.into_iter()
+                .map(|name| {
+                    let name = name.as_ref();
+                    if !self.n_bins.contains_key(name) {
+                        panic!("The feature named `{name}` does not exist");
+                    }
+                    name.to_string()
+                })
+                .collect::<Vec<_>>();
+            self.constraint.add_interaction_group(group);
+        }
+        self
+    }
+
+
+    /// Cut each feature into at most `n_bins` bins by quantiles
+    /// and search the splits on the histograms of the bins.
+    /// The histograms of a node are passed to its children,
+    /// so that only the histograms of the smaller child
+    /// are computed from the examples
+    /// and the ones of the larger child are obtained by subtraction.
+    /// This method overwrites the number of bins
+    /// set by [`DecisionTreeBuilder::set_nbins`] so far.
+    /// If `n_bins <= 255`,
+    /// the positions of the examples on the bins are stored in 8 bits.
+    /// The histograms are not used
+    /// with [`DecisionTreeBuilder::bundle_exclusive_features`].
+    ///
+    /// By default, each feature is cut into bins of the same width
+    /// and the examples are scanned at each node.
+    pub fn bins(mut self, n_bins: usize) -> Self {
+        assert!(n_bins > 0, "The number of bins must be positive");
+        self.n_bins.values_mut()
+            .for_each(|n| { *n = n_bins; });
+        self.histogram = true;
+        self
+    }
+
+
+    /// Build the histograms on `device`.
+    /// With `Device::Gpu`, which requires the `gpu` feature,
+    /// the positions of the examples on the bins are uploaded to the GPU
+    /// when the tree is built,
+    /// and the histograms and the leaf values of the large nodes
+    /// are accumulated on the GPU.
+    /// The device is used only with [`DecisionTreeBuilder::bins`].
+    /// [`DecisionTreeBuilder::build`] panics
+    /// if no GPU adapter is available
+    /// (see [`Device::is_available`]).
+    /// Default device is `Device::Cpu`.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = device;
+        self
+    }
+
+
+    /// Set the maximal number of categories of a categorical feature.
+    /// [`DecisionTreeBuilder::build`] panics
+    /// if a categorical feature has more categories than `max_categories`.
+    /// Categorical features are specified by
+    /// [`Sample::set_categorical`](crate::Sample::set_categorical)
+    /// and split into subsets of the categories.
+    /// Default value is `256`.
+    pub fn max_categories(mut self, max_categories: usize) -> Self {
+        assert!(
+            max_categories > 0,
+            "The maximal number of categories must be positive"
+        );
+        self.max_categories = max_categories;
+        self
+    }
+
+
     /// Set the number of bins to a feature named `name`.
     /// By default, each feature is binned in `255` bins.
     pub fn set_nbins<T>(&mut self, name: T, n_bins: usize)
@@ -103,13 +435,50 @@ impl<'a> DecisionTreeBuilder<'a> {
                 let name = feature.name();
                 let n_bins = *self.n_bins.get(name).unwrap();
 
-                (name, Bins::cut(feature, n_bins))
+                let bins = if self.sample.is_categorical(name) {
+                    let categories = self.sample.categories(name);
+                    let n_categories = categories.len();
+                    assert!(
+                        n_categories <= self.max_categories,
+                        "The categorical feature `{name}` has \
+                        {n_categories} categories, \
+                        more than `max_categories == {}`",
+                        self.max_categories,
+                    );
+                    Bins::categorical(&categories)
+                } else if self.histogram {
+                    Bins::qcut(feature, n_bins)
+                } else {
+                    Bins::cut(feature, n_bins)
+                };
+                (name, bins)
             })
             .collect::<HashMap<_, _>>();
+        let bins = BinMap::new(self.sample, bins, Bins::position);
+        let histogram = self.histogram && !self.bundle_features;
+        let device = if histogram { self.device } else { Device::Cpu };
+        let accelerator = Accelerator::new(
+            device, self.sample, &bins, Bins::len,
+        );
+
+        let bundles = if self.bundle_features {
+            FeatureBundle::bundle(self.sample)
+        } else {
+            Vec::new()
+        };
 
         let dtree = DecisionTree::from_components(
-            bins, self.criterion, self.max_depth
-        );
+            bins,
+            self.criterion,
+            self.max_depth,
+            bundles,
+            self.constraint,
+            self.histogram,
+            self.limits,
+        )
+        .with_class_weight(self.class_weight)
+        .with_subsample(self.subsample)
+        .with_accelerator(accelerator);
 
 
         dtree
@@ -0,0 +1,216 @@
+//! Exclusive Feature Bundling (EFB).
+//!
+//! Sparse features that never take non-zero values simultaneously
+//! (e.g., one-hot encoded categorical features)
+//! are grouped into a single *bundle*.
+//! The histograms of all the features in a bundle are built
+//! by a single scan over the examples,
+//! so the cost of finding the best split
+//! scales with the number of bundles
+//! instead of the number of features.
+//!
+//! The splitting rules are still expressed
+//! in terms of the original features,
+//! so the bundling is invisible to the trained classifier.
+//!
+//! See the paper:
+//! [LightGBM: A Highly Efficient Gradient Boosting Decision Tree](https://papers.nips.cc/paper_files/paper/2017/hash/6449f44a102fde848669bdd9eb6b76fa-Abstract.html)
+//! by Guolin Ke, Qi Meng, Thomas Finley, Taifeng Wang, Wei Chen,
+//! Weidong Ma, Qiwei Ye, and Tie-Yan Liu.
+use fixedbitset::FixedBitSet;
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use crate::Sample;
+use crate::sample::Feature;
+use crate::weak_learner::common::{
+    type_and_struct::*,
+    bin_cache::BinMap,
+};
+use super::bin::*;
+
+
+/// A group of features scanned together while searching a split.
+pub(crate) enum FeatureBundle {
+    /// A feature that is not bundled with the others.
+    /// The value is the index of the feature in `Sample::features()`.
+    Single(usize),
+    /// Mutually exclusive sparse features.
+    Exclusive {
+        /// The indices of the features in `Sample::features()`.
+        members: Vec<usize>,
+        /// `owner[i] = Some((k, x))` implies that
+        /// the `members[k]`-th feature takes the non-zero value `x`
+        /// on the `i`-th example.
+        /// All the other members take `0` on that example.
+        owner: Vec<Option<(usize, f64)>>,
+    },
+}
+
+
+impl FeatureBundle {
+    /// Greedily bundles the mutually exclusive sparse features
+    /// in `sample`.
+    /// Features with more non-zero values are assigned first,
+    /// and each feature goes to the first bundle
+    /// that has no conflicting example.
+    /// Dense features, categorical features,
+    /// and features with missing values are never bundled.
+    pub(crate) fn bundle(sample: &Sample) -> Vec<Self> {
+        let n_sample = sample.shape().0;
+        let features = sample.features();
+
+        let mut singles = Vec::new();
+        let mut sparse = Vec::new();
+        for (k, feature) in features.iter().enumerate() {
+            match feature {
+                Feature::Sparse(feat)
+                    if feat.has_zero()
+                        && !sample.is_categorical(feature.name())
+                        && !feat.iter().any(|(_, x)| x.is_nan())
+                => {
+                    sparse.push((k, feat));
+                },
+                _ => { singles.push(k); },
+            }
+        }
+        sparse.sort_by_key(|(_, feat)| Reverse(feat.len()));
+
+
+        let mut groups: Vec<(Vec<usize>, FixedBitSet)> = Vec::new();
+        for (k, feat) in sparse {
+            let group = groups.iter_mut()
+                .find(|(_, used)| feat.iter().all(|(i, _)| !used[*i]));
+
+            match group {
+                Some((members, used)) => {
+                    members.push(k);
+                    feat.iter().for_each(|(i, _)| { used.insert(*i); });
+                },
+                None => {
+                    let mut used = FixedBitSet::with_capacity(n_sample);
+                    feat.iter().for_each(|(i, _)| { used.insert(*i); });
+                    groups.push((vec![k], used));
+                },
+            }
+        }
+
+
+        let mut bundles = singles.into_iter()
+            .map(Self::Single)
+            .collect::<Vec<_>>();
+        for (members, _) in groups {
+            if members.len() == 1 {
+                bundles.push(Self::Single(members[0]));
+                continue;
+            }
+
+            let mut owner = vec![None; n_sample];
+            for (pos, &k) in members.iter().enumerate() {
+                let Feature::Sparse(feat) = &features[k] else {
+                    unreachable!("Only sparse features are bundled");
+                };
+                feat.iter().for_each(|&(i, x)| { owner[i] = Some((pos, x)); });
+            }
+            bundles.push(Self::Exclusive { members, owner });
+        }
+        bundles
+    }
+
+
+    /// Returns the number of features in this bundle.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Single(_) => 1,
+            Self::Exclusive { members, .. } => members.len(),
+        }
+    }
+
+
+    /// Build the histograms of the features in this bundle
+    /// over the examples `indices`
+    /// in the same format as `Bins::raw_pack`.
+    /// Each histogram is paired with the name of the original feature.
+    /// Only the unbundled features have missing values.
+    pub(crate) fn pack<'a>(
+        &self,
+        bins_map: &BinMap<'_, Bins>,
+        sample: &'a Sample,
+        indices: &[usize],
+        y: &[f64],
+        dist: &[f64],
+    ) -> Vec<(&'a str, Vec<LabelToWeight>, LabelToWeight)>
+    {
+        let features = sample.features();
+        let (members, owner) = match self {
+            Self::Single(k) => {
+                let feature = &features[*k];
+                let name = feature.name();
+                let bin = bins_map.get(name).unwrap();
+                let positions = bins_map.positions(sample, name);
+                let (pack, missing) = bin.raw_pack(
+                    indices, feature, positions, y, dist
+                );
+                return vec![(name, pack, missing)];
+            },
+            Self::Exclusive { members, owner } => (members, owner),
+        };
+        assert_eq!(
+            owner.len(), sample.shape().0,
+            "The feature bundles are built for another sample"
+        );
+
+
+        let bins = members.iter()
+            .map(|&k| bins_map.get(features[k].name()).unwrap())
+            .collect::<Vec<_>>();
+        let mut packed = bins.iter()
+            .map(|bin| vec![LabelToWeight::new(); bin.len()])
+            .collect::<Vec<_>>();
+
+        // `total` holds the weight and the number of examples per label,
+        // and `non_zero[k]` holds the ones that
+        // the `k`-th member takes a non-zero value.
+        let mut total: BTreeMap<i32, (f64, usize)> = BTreeMap::new();
+        let mut non_zero =
+            vec![BTreeMap::<i32, (f64, usize)>::new(); bins.len()];
+        for &i in indices {
+            let yi = y[i] as i32;
+            let di = dist[i];
+
+            let entry = total.entry(yi).or_insert((0f64, 0));
+            entry.0 += di;
+            entry.1 += 1;
+
+            if let Some((k, xi)) = owner[i] {
+                let pos = bins[k].position(xi);
+                *packed[k][pos].entry(yi).or_insert(0f64) += di;
+
+                let entry = non_zero[k].entry(yi).or_insert((0f64, 0));
+                entry.0 += di;
+                entry.1 += 1;
+            }
+        }
+
+
+        // The remaining mass of each label goes to the bin containing `0`.
+        members.iter()
+            .zip(bins)
+            .zip(packed.into_iter().zip(non_zero))
+            .map(|((&k, bin), (mut pack, non_zero))| {
+                let pos = bin.position(0f64);
+                for (y, &(w, n)) in total.iter() {
+                    let (nz_w, nz_n) = non_zero.get(y)
+                        .copied()
+                        .unwrap_or((0f64, 0));
+                    if n > nz_n {
+                        *pack[pos].entry(*y).or_insert(0f64)
+                            += (w - nz_w).max(0f64);
+                    }
+                }
+                (features[k].name(), pack, LabelToWeight::new())
+            })
+            .collect()
+    }
+}
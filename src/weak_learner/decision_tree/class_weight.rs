@@ -0,0 +1,85 @@
+//! This file defines the class weights
+//! of the examples for `DecisionTree`.
+use crate::Sample;
+
+use std::collections::HashMap;
+
+
+/// The weights of the classes for growing decision trees.
+/// The contribution of an example to the impurities
+/// and the confidences of the leaves
+/// is its mass on the given distribution
+/// multiplied by the weight of its class.
+/// * `ClassWeight::Balanced` weights the class `c` by
+///   `n / (k * n_c)`,
+///   where `n` is the number of examples,
+///   `k` is the number of classes,
+///   and `n_c` is the number of examples of class `c`
+///   in the sample given to the weak learner.
+/// * `ClassWeight::Explicit` weights the classes by the given pairs
+///   of a label and its weight, e.g., `vec![(-1, 1.0), (1, 5.0)]`.
+///   The classes not in the pairs are weighted by `1.0`.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let tree = DecisionTreeBuilder::new(&sample)
+///     .class_weight(ClassWeight::Balanced)
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassWeight {
+    /// Weights the classes inversely proportional to their frequencies.
+    Balanced,
+    /// Weights the classes by the pairs of a label and its weight.
+    Explicit(Vec<(i64, f64)>),
+}
+
+
+impl ClassWeight {
+    /// Returns the weight of each class in `sample`.
+    fn weights(&self, sample: &Sample) -> HashMap<i64, f64> {
+        match self {
+            Self::Balanced => {
+                let target = sample.target();
+                let mut counts: HashMap<i64, usize> = HashMap::new();
+                for &y in target {
+                    *counts.entry(y as i64).or_insert(0) += 1;
+                }
+                let n_sample = target.len() as f64;
+                let n_class = counts.len() as f64;
+                counts.into_iter()
+                    .map(|(label, n)| (label, n_sample / (n_class * n as f64)))
+                    .collect()
+            },
+            Self::Explicit(pairs) => pairs.iter().copied().collect(),
+        }
+    }
+
+
+    /// Returns the distribution `dist` reweighted by the class weights.
+    /// The result has the same total mass as `dist`
+    /// unless all the weighted masses are zero.
+    pub(super) fn reweight(&self, sample: &Sample, dist: &[f64]) -> Vec<f64> {
+        let weights = self.weights(sample);
+        let mut weighted = sample.target()
+            .iter()
+            .zip(dist)
+            .map(|(&y, &d)| {
+                let w = weights.get(&(y as i64)).copied().unwrap_or(1f64);
+                w * d
+            })
+            .collect::<Vec<_>>();
+
+        let total = dist.iter().sum::<f64>();
+        let weighted_total = weighted.iter().sum::<f64>();
+        if weighted_total > 0f64 {
+            let scale = total / weighted_total;
+            weighted.iter_mut()
+                .for_each(|d| { *d *= scale; });
+        }
+        weighted
+    }
+}
@@ -8,12 +8,15 @@ use serde::{Serialize, Deserialize};
 use std::fmt;
 use std::cmp::Ordering;
 use std::ops::{Mul, Add};
-use std::collections::{HashSet, HashMap};
+use std::collections::BTreeSet;
 
 use crate::Sample;
 use super::bin::*;
+use super::bundle::FeatureBundle;
 use crate::weak_learner::common::{
     type_and_struct::*,
+    split_rule::{Splitter, best_rule},
+    bin_cache::BinMap,
 };
 
 
@@ -68,9 +71,16 @@ impl Add for Score {
 
 
 /// Splitting criteria for growing decision tree.
+/// All the criteria are computed with the weights of the given distribution.
 /// * `Criterion::Edge` maximizes the edge (weighted training accuracy)
 ///     for given distribution.
 /// * `Criterion::Entropy` minimizes entropic impurity for given distribution.
+/// * `Criterion::Gini` minimizes Gini impurity for given distribution.
+/// * `Criterion::Twoing` maximizes the twoing rule for given distribution.
+/// * `Criterion::Misclassification` minimizes the weighted misclassification,
+///   the total weight of the examples
+///   whose labels differ from the majority label of their child,
+///   for given distribution.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Criterion {
     /// Binary entropy function.
@@ -82,6 +92,10 @@ pub enum Criterion {
     Gini,
     /// Twoing rule.
     Twoing,
+    /// Weighted misclassification.
+    /// Unlike `Criterion::Edge`,
+    /// this criterion also works for multiclass labels.
+    Misclassification,
 }
 
 
@@ -92,6 +106,7 @@ impl fmt::Display for Criterion {
             Self::Edge => "Edge (Weighted accuracy)",
             Self::Gini => "Gini index",
             Self::Twoing => "Twoing Rule",
+            Self::Misclassification => "Weighted misclassification",
         };
 
         write!(f, "{name}")
@@ -101,77 +116,329 @@ impl fmt::Display for Criterion {
 
 impl Criterion {
     /// Returns the best splitting rule based on the criterion.
-    pub(super) fn best_split<'a>(
+    /// Features with `allowed(name) == false` are not used.
+    /// Returns `None` if no feature is allowed.
+    pub(super) fn best_split(
         &self,
-        bins_map: &HashMap<&'a str, Bins>,
-        sample: &'a Sample,
+        bins_map: &BinMap<'_, Bins>,
+        sample: &Sample,
         dist: &[f64],
         idx: &[usize],
-    ) -> (&'a str, f64)
+        allowed: &(dyn Fn(&str) -> bool + Sync),
+    ) -> Option<Splitter>
     {
         let target = sample.target();
-        let target = &target[..];
+        let candidates = sample.features()
+            .par_iter()
+            .filter(|feature| allowed(feature.name()))
+            .map(|feature| {
+                let name = feature.name();
+                let bins = bins_map.get(name).unwrap();
+                let positions = bins_map.positions(sample, name);
+                let (packed, missing) = bins.raw_pack(
+                    idx, feature, positions, target, dist
+                );
+                self.split_on(name, bins, packed, missing)
+            });
+        self.best(candidates)
+    }
+
+
+    /// Returns the best splitting rule based on the criterion.
+    /// Unlike [`Criterion::best_split`],
+    /// this method scans the features bundle by bundle.
+    /// The returned feature is one of the original features.
+    pub(super) fn best_split_bundled(
+        &self,
+        bins_map: &BinMap<'_, Bins>,
+        bundles: &[FeatureBundle],
+        sample: &Sample,
+        dist: &[f64],
+        idx: &[usize],
+        allowed: &(dyn Fn(&str) -> bool + Sync),
+    ) -> Option<Splitter>
+    {
+        let target = sample.target();
+        let candidates = bundles.par_iter()
+            .flat_map_iter(|bundle| {
+                bundle.pack(bins_map, sample, idx, target, dist)
+            })
+            .filter(|(name, _, _)| allowed(name))
+            .map(|(name, packed, missing)| {
+                let bins = bins_map.get(name).unwrap();
+                self.split_on(name, bins, packed, missing)
+            });
+        self.best(candidates)
+    }
+
+
+    /// Returns the best splitting rule based on the criterion.
+    /// Unlike [`Criterion::best_split`],
+    /// this method reads the weights from the histograms `hists`,
+    /// where `hists[j]` is the histogram of the `j`-th feature.
+    pub(super) fn best_split_from_histograms(
+        &self,
+        bins_map: &BinMap<'_, Bins>,
+        sample: &Sample,
+        hists: &[Histogram],
+        allowed: &(dyn Fn(&str) -> bool + Sync),
+    ) -> Option<Splitter>
+    {
+        let candidates = sample.features()
+            .par_iter()
+            .zip(hists)
+            .filter(|(feature, _)| allowed(feature.name()))
+            .map(|(feature, hist)| {
+                let name = feature.name();
+                let bins = bins_map.get(name).unwrap();
+                let (packed, missing) = hist.raw_pack();
+                self.split_on(name, bins, packed, missing)
+            });
+        self.best(candidates)
+    }
+
+
+    /// Returns the best splitting rule for each feature,
+    /// sorted from the best one to the worst one.
+    pub(super) fn ranked_splits(
+        &self,
+        bins_map: &BinMap<'_, Bins>,
+        bundles: &[FeatureBundle],
+        sample: &Sample,
+        dist: &[f64],
+        idx: &[usize],
+        allowed: &(dyn Fn(&str) -> bool + Sync),
+    ) -> Vec<Splitter>
+    {
+        let target = sample.target();
+        let mut candidates = if bundles.is_empty() {
+            sample.features()
+                .par_iter()
+                .filter(|feature| allowed(feature.name()))
+                .map(|feature| {
+                    let name = feature.name();
+                    let bins = bins_map.get(name).unwrap();
+                    let positions = bins_map.positions(sample, name);
+                    let (packed, missing) = bins.raw_pack(
+                        idx, feature, positions, target, dist
+                    );
+                    self.split_on(name, bins, packed, missing)
+                })
+                .collect::<Vec<_>>()
+        } else {
+            bundles.par_iter()
+                .flat_map_iter(|bundle| {
+                    bundle.pack(bins_map, sample, idx, target, dist)
+                })
+                .filter(|(name, _, _)| allowed(name))
+                .map(|(name, packed, missing)| {
+                    let bins = bins_map.get(name).unwrap();
+                    self.split_on(name, bins, packed, missing)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        candidates.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+        if !self.minimizes() {
+            candidates.reverse();
+        }
+        candidates.into_iter()
+            .map(|(_, rule)| rule)
+            .collect()
+    }
+
+
+    /// Returns the best candidate in `candidates`.
+    /// Ties are broken by the order of the features.
+    fn best<I>(&self, candidates: I) -> Option<Splitter>
+        where I: ParallelIterator<Item = (Score, Splitter)>,
+    {
+        best_rule(candidates, self.minimizes())
+    }
+
+
+    /// Returns the best splitting rule on the feature `name`
+    /// and its score.
+    /// `packed[k]` is the weights of the labels
+    /// on the `k`-th bin of `bins`
+    /// and `missing` is the ones of the examples with missing values.
+    ///
+    /// The missing values are sent to the left and to the right,
+    /// and the better direction is learned as in XGBoost.
+    /// Ties are broken by sending them to the right.
+    fn split_on(
+        &self,
+        name: &str,
+        bins: &Bins,
+        packed: Vec<LabelToWeight>,
+        mut missing: LabelToWeight,
+    ) -> (Score, Splitter)
+    {
+        if let Some(categories) = bins.categories() {
+            return self.split_categories(name, categories, packed, missing);
+        }
+
+        let value_weight = packed.iter()
+            .map(|map| map.values().sum::<f64>())
+            .sum::<f64>();
+        let missing_weight = missing.values().sum::<f64>();
+        let total_weight = value_weight + missing_weight;
+        missing.values_mut().for_each(|w| { *w /= total_weight; });
+
+
+        // All the examples have missing values,
+        // so that no split is possible.
+        if value_weight <= 0f64 {
+            let pack = vec![(Bin::new(f64::MIN..f64::MAX), missing)];
+            let (_, score) = self.split(pack);
+            return (score, Splitter::new(name, Threshold::from(f64::MIN)));
+        }
+
+
+        let mut pack = bins.remove_zero_weight_pack_and_normalize(packed);
+        if missing.is_empty() {
+            let (threshold, score) = self.split(pack);
+            return (score, Splitter::new(name, Threshold::from(threshold)));
+        }
+        let ratio = value_weight / total_weight;
+        pack.iter_mut()
+            .for_each(|(_, map)| {
+                map.values_mut().for_each(|w| { *w *= ratio; });
+            });
+
+
+        // Scan the bins with the missing values on the right.
+        // The threshold `f64::INFINITY` sends all the examples to the left.
+        let mut right = pack.clone();
+        right.push((Bin::new(f64::MAX..f64::INFINITY), missing.clone()));
+        let (rthreshold, rscore) = self.split(right);
+
+
+        // Scan the bins with the missing values on the left.
+        // The threshold `f64::NEG_INFINITY` sends
+        // only the missing values to the left,
+        // and `f64::MIN` implies that no split improves the score.
+        let mut left = vec![(Bin::new(f64::MIN..f64::NEG_INFINITY), missing)];
+        left.extend(pack);
+        let (lthreshold, lscore) = self.split(left);
+
+
+        let missing_left = if self.minimizes() {
+            lscore < rscore
+        } else {
+            lscore > rscore
+        };
+        let rule = if !missing_left {
+            let missing_left = rthreshold == f64::INFINITY;
+            let threshold = rthreshold.min(f64::MAX);
+            Splitter::new(name, Threshold::from(threshold))
+                .missing_left(missing_left)
+        } else if lthreshold == f64::MIN {
+            Splitter::new(name, Threshold::from(f64::MIN))
+        } else {
+            let threshold = lthreshold.max(f64::MIN);
+            Splitter::new(name, Threshold::from(threshold))
+                .missing_left(true)
+        };
+        let score = if missing_left { lscore } else { rscore };
+        (score, rule)
+    }
+
+
+    /// Returns the best subset split on the categorical feature `name`
+    /// and its score.
+    /// The categories are sorted by the weighted mean of the labels,
+    /// where the ties are broken by the category codes.
+    /// Then, the best prefix of the sorted categories
+    /// is sent to the left.
+    /// For binary labels, the best prefix is the best subset
+    /// for the entropy and the Gini index,
+    /// so that this method avoids the search over `2^k` subsets.
+    /// The missing values always go to the right.
+    fn split_categories(
+        &self,
+        name: &str,
+        categories: &[i64],
+        packed: Vec<LabelToWeight>,
+        mut missing: LabelToWeight,
+    ) -> (Score, Splitter)
+    {
+        let total_weight = packed.iter()
+            .chain([&missing])
+            .map(|map| map.values().sum::<f64>())
+            .sum::<f64>();
+        assert!(total_weight > 0f64);
+
+        let mut items = categories.iter()
+            .copied()
+            .zip(packed)
+            .filter(|(_, map)| !map.is_empty())
+            .map(|(category, map)| {
+                let weight = map.values().sum::<f64>();
+                let mean = if weight > 0f64 {
+                    map.iter()
+                        .map(|(y, w)| *y as f64 * w)
+                        .sum::<f64>()
+                        / weight
+                } else {
+                    0f64
+                };
+                (mean, category, map)
+            })
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+
+        let sorted = items.iter()
+            .map(|(_, category, _)| *category)
+            .collect::<Vec<_>>();
+        let mut pack = items.into_iter()
+            .enumerate()
+            .map(|(k, (_, _, mut map))| {
+                map.values_mut().for_each(|w| { *w /= total_weight; });
+                (Bin::new(k as f64..(k + 1) as f64), map)
+            })
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            missing.values_mut().for_each(|w| { *w /= total_weight; });
+            let n_categories = sorted.len() as f64;
+            pack.push((Bin::new(n_categories..f64::INFINITY), missing));
+        }
+        let (threshold, score) = self.split(pack);
+
+
+        // `threshold == k` implies that
+        // the first `k` categories go to the left.
+        let n_left = if threshold < 0f64 {
+            0
+        } else {
+            (threshold.min(sorted.len() as f64)) as usize
+        };
+        let rule = Splitter::categorical(name, sorted[..n_left].to_vec());
+        (score, rule)
+    }
+
+
+    /// Returns the best threshold for the given histogram
+    /// and its score.
+    fn split(&self, pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
         match self {
-            Criterion::Entropy => {
-                sample.features()
-                    .par_iter()
-                    .map(|feature| {
-                        let name = feature.name();
-                        let bin = bins_map.get(name).unwrap();
-                        let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_entropy(pack);
-
-                        (score, name, threshold)
-                    })
-                    .min_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
-                    .map(|(_, name, threshold)| (name, threshold))
-                    .expect("No feature minimizes entropic impurity")
-            },
-            Criterion::Edge => {
-                sample.features()
-                    .par_iter()
-                    .map(|feature| {
-                        let name = feature.name();
-                        let bin = bins_map.get(name).unwrap();
-                        let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_edge(pack);
-
-                        (score, name, threshold)
-                    })
-                    .max_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
-                    .map(|(_, name, threshold)| (name, threshold))
-                    .expect("No feature maximizes edge")
-            },
-            Criterion::Gini => {
-                sample.features()
-                    .par_iter()
-                    .map(|feature| {
-                        let name = feature.name();
-                        let bin = bins_map.get(name).unwrap();
-                        let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_gini(pack);
-
-                        (score, name, threshold)
-                    })
-                    .min_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
-                    .map(|(_, name, threshold)| (name, threshold))
-                    .expect("No feature minimizes Gini impurity")
-            },
-            Criterion::Twoing => {
-                sample.features()
-                    .par_iter()
-                    .map(|feature| {
-                        let name = feature.name();
-                        let bin = bins_map.get(name).unwrap();
-                        let pack = bin.pack(idx, feature, target, dist);
-                        let (threshold, score) = split_by_twoing(pack);
-
-                        (score, name, threshold)
-                    })
-                    .max_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
-                    .map(|(_, name, threshold)| (name, threshold))
-                    .expect("No feature maximizes Twoing rule")
-            },
+            Criterion::Entropy => split_by_entropy(pack),
+            Criterion::Edge => split_by_edge(pack),
+            Criterion::Gini => split_by_gini(pack),
+            Criterion::Twoing => split_by_twoing(pack),
+            Criterion::Misclassification => split_by_misclassification(pack),
+        }
+    }
+
+
+    /// Returns `true` if the smaller score is the better.
+    fn minimizes(&self) -> bool {
+        match self {
+            Criterion::Entropy
+                | Criterion::Gini
+                | Criterion::Misclassification => true,
+            Criterion::Edge | Criterion::Twoing => false,
         }
     }
 }
@@ -315,7 +582,7 @@ fn split_by_twoing(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
     let mut left_weight = LabelToWeight::new();
     let mut right_weight = LabelToWeight::new();
 
-    let mut labels = HashSet::new();
+    let mut labels = BTreeSet::new();
     for (_, mp) in pack.iter() {
         for (y, w) in mp.iter() {
             let entry = right_weight.entry(*y).or_insert(0f64);
@@ -354,14 +621,55 @@ fn split_by_twoing(pack: Vec<(Bin, LabelToWeight)>) -> (f64, Score) {
 }
 
 
+fn split_by_misclassification(pack: Vec<(Bin, LabelToWeight)>)
+    -> (f64, Score)
+{
+    let mut left_weight = LabelToWeight::new();
+    let mut right_weight = LabelToWeight::new();
+
+    for (_, mp) in pack.iter() {
+        for (y, w) in mp.iter() {
+            let entry = right_weight.entry(*y).or_insert(0f64);
+            *entry += w;
+        }
+    }
+
+    let mut best_score = misclassification(&right_weight);
+    let mut best_threshold = f64::MIN;
+
+    for (bin, map) in pack {
+        // Move the weights in a `pack` from right to left.
+        for (y, w) in map {
+            let entry = left_weight.entry(y).or_insert(0f64);
+            *entry += w;
+            if let Some(entry) = right_weight.get_mut(&y) {
+                *entry -= w;
+                if *entry <= 0f64 { right_weight.remove(&y); }
+            }
+        }
+
+        let score = misclassification(&left_weight)
+            + misclassification(&right_weight);
+
+
+        if score < best_score {
+            best_score = score;
+            best_threshold = bin.0.end;
+        }
+    }
+    let best_score = Score::from(best_score);
+    (best_threshold, best_score)
+}
+
+
 /// Returns the entropic-impurity of the given map.
 #[inline(always)]
-pub(self) fn entropic_impurity(map: &HashMap<i32, f64>) -> f64 {
+pub(self) fn entropic_impurity(map: &LabelToWeight) -> f64 {
     let total = map.values().sum::<f64>();
     if total <= 0f64 || map.is_empty() { return 0f64.into(); }
 
-    map.par_iter()
-        .map(|(_, &p)| {
+    map.values()
+        .map(|&p| {
             let r = p / total;
             if r <= 0f64 { 0f64 } else { -r * r.ln() }
         })
@@ -371,24 +679,45 @@ pub(self) fn entropic_impurity(map: &HashMap<i32, f64>) -> f64 {
 
 /// Returns the gini-impurity of the given map.
 #[inline(always)]
-pub(self) fn gini_impurity(map: &HashMap<i32, f64>) -> f64 {
+pub(self) fn gini_impurity(map: &LabelToWeight) -> f64 {
     let total = map.values().sum::<f64>();
     if total <= 0f64 || map.is_empty() { return 0f64.into(); }
 
-    let correct = map.par_iter()
-        .map(|(_, &w)| (w / total).powi(2))
+    let correct = map.values()
+        .map(|&w| (w / total).powi(2))
         .sum::<f64>();
 
     (1f64 - correct).max(0f64)
 }
 
 
+/// Returns the total weight of the labels
+/// other than the majority one of the given map.
+/// The weights are summed up directly
+/// since `total - majority` suffers from the cancellation.
+#[inline(always)]
+fn misclassification(map: &LabelToWeight) -> f64 {
+    let mut majority = 0f64;
+    let mut others = 0f64;
+    for &w in map.values() {
+        if w > majority {
+            others += majority;
+            majority = w;
+        } else {
+            others += w;
+        }
+    }
+
+    others.max(0f64)
+}
+
+
 /// Returns the gini-impurity of the given map.
 #[inline(always)]
 pub(self) fn twoing_score(
-    labels: &HashSet<i32>,
-    left: &HashMap<i32, f64>,
-    right: &HashMap<i32, f64>,
+    labels: &BTreeSet<i32>,
+    left: &LabelToWeight,
+    right: &LabelToWeight,
 ) -> f64
 {
     let pl = left.values().sum::<f64>();
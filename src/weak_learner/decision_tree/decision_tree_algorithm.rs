@@ -8,18 +8,26 @@ use super::bin::*;
 use crate::weak_learner::common::{
     type_and_struct::*,
     split_rule::*,
+    feature_constraint::{FeatureConstraint, TreeFeatures},
+    monotone::Bounds,
+    bin_cache::BinMap,
+    row_subsample::RowSubsample,
+    parallel_growth,
+    device::Accelerator,
 };
 use super::{
     node::*,
     criterion::*,
+    bundle::FeatureBundle,
     train_node::*,
+    class_weight::ClassWeight,
     decision_tree_classifier::DecisionTreeClassifier,
 };
 
 
 use std::fmt;
-use std::rc::Rc;
-use std::collections::HashMap;
+use std::sync::Arc;
+use std::collections::{BTreeMap, BTreeSet};
 
 
 /// The Decision Tree algorithm.  
@@ -36,6 +44,8 @@ use std::collections::HashMap;
 ///
 /// [`DecisionTree`] is constructed 
 /// by [`DecisionTreeBuilder`](crate::weak_learner::DecisionTreeBuilder).
+/// The builder places the training examples on the bins once,
+/// and [`DecisionTree`] reuses the positions across the boosting rounds.
 /// 
 /// # Example
 /// ```no_run
@@ -73,9 +83,44 @@ use std::collections::HashMap;
 /// println!("loss (train) is: {loss}");
 /// ```
 pub struct DecisionTree<'a> {
-    bins: HashMap<&'a str, Bins>,
+    bins: BinMap<'a, Bins>,
     criterion: Criterion,
     max_depth: Depth,
+    /// Groups of mutually exclusive features.
+    /// If empty, the features are scanned one by one.
+    bundles: Vec<FeatureBundle>,
+    /// Restrictions on the features used in splitting rules.
+    constraint: FeatureConstraint,
+    /// Search splits on the histograms with sibling subtraction or not.
+    histogram: bool,
+    /// Restrictions on the growth of the trees.
+    limits: GrowthLimits,
+    /// The weights of the classes multiplied to the distribution.
+    class_weight: Option<ClassWeight>,
+    /// The weighted bootstrap of the examples for each tree.
+    subsample: RowSubsample,
+    /// The device that builds the histograms.
+    accelerator: Accelerator,
+}
+
+
+/// Restrictions on the growth of a tree.
+#[derive(Clone, Copy)]
+pub(super) struct GrowthLimits {
+    /// The minimal number of examples on a leaf.
+    pub(super) min_samples_leaf: usize,
+    /// The minimal decrease of the training loss by a split.
+    pub(super) min_split_gain: f64,
+    /// The maximal number of leaves.
+    /// If `Some(_)`, the trees are grown in the best-first order.
+    pub(super) max_leaves: Option<usize>,
+}
+
+
+impl Default for GrowthLimits {
+    fn default() -> Self {
+        Self { min_samples_leaf: 1, min_split_gain: 0f64, max_leaves: None }
+    }
 }
 
 
@@ -84,79 +129,587 @@ impl<'a> DecisionTree<'a> {
     /// This method is called only via `DecisionTreeBuilder::build`.
     #[inline]
     pub(super) fn from_components(
-        bins: HashMap<&'a str, Bins>,
+        bins: BinMap<'a, Bins>,
         criterion: Criterion,
         max_depth: Depth,
+        bundles: Vec<FeatureBundle>,
+        constraint: FeatureConstraint,
+        histogram: bool,
+        limits: GrowthLimits,
     ) -> Self
     {
-        Self { bins, criterion, max_depth, }
+        Self {
+            bins, criterion, max_depth, bundles, constraint, histogram, limits,
+            class_weight: None,
+            subsample: RowSubsample::default(),
+            accelerator: Accelerator::default(),
+        }
+    }
+
+
+    /// Set the weights of the classes.
+    /// This method is called only via `DecisionTreeBuilder::build`.
+    #[inline]
+    pub(super) fn with_class_weight(
+        mut self,
+        class_weight: Option<ClassWeight>,
+    ) -> Self
+    {
+        self.class_weight = class_weight;
+        self
+    }
+
+
+    /// Set the weighted bootstrap of the examples.
+    /// This method is called only via `DecisionTreeBuilder::build`.
+    #[inline]
+    pub(super) fn with_subsample(mut self, subsample: RowSubsample) -> Self {
+        self.subsample = subsample;
+        self
+    }
+
+
+    /// Set the device that builds the histograms.
+    /// This method is called only via `DecisionTreeBuilder::build`.
+    #[inline]
+    pub(super) fn with_accelerator(mut self, accelerator: Accelerator)
+        -> Self
+    {
+        self.accelerator = accelerator;
+        self
+    }
+
+
+    /// Returns the distribution reweighted by the class weights
+    /// and resampled by the weighted bootstrap,
+    /// or `None` if neither of them is set.
+    fn training_dist(&self, sample: &Sample, dist: &[f64])
+        -> Option<Vec<f64>>
+    {
+        let weighted = self.class_weight.as_ref()
+            .map(|class_weight| class_weight.reweight(sample, dist));
+        let dist = weighted.as_deref().unwrap_or(dist);
+        self.subsample.resample(dist).or(weighted)
+    }
+
+
+    /// Forgets the features used so far
+    /// and restarts the feature and row subsampling from the seed.
+    /// Call this method before reusing `self`
+    /// for another ensemble
+    /// if the feature budget is set by
+    /// [`DecisionTreeBuilder::feature_budget`](crate::DecisionTreeBuilder::feature_budget),
+    /// or if the same subsamples are expected
+    /// as the previous ensemble.
+    pub fn reset_feature_usage(&self) {
+        self.constraint.reset();
+        self.subsample.reset();
+    }
+
+
+    /// Returns the histograms of the examples in `indices`
+    /// for each feature.
+    fn histograms(&self, sample: &Sample, dist: &[f64], indices: &[usize])
+        -> Vec<Histogram>
+    {
+        let target = sample.target();
+        let labels = node_labels(target, indices);
+        let sums = self.accelerator.sums(
+            sample,
+            &self.bins,
+            indices,
+            labels.len(),
+            |i| (label_index(&labels, target[i]), [dist[i], 0f64]),
+            true,
+        );
+        if let Some(sums) = sums {
+            return (0..sample.shape().1)
+                .map(|j| Histogram::from_sums(sums.feature(j), &labels))
+                .collect();
+        }
+
+        sample.features()
+            .par_iter()
+            .map(|feature| {
+                let name = feature.name();
+                let bin = self.bins.get(name).unwrap();
+                let positions = self.bins.positions(sample, name);
+                bin.histogram(indices, feature, positions, target, dist)
+            })
+            .collect()
     }
 
 
     /// Construct a full binary tree of depth `depth`.
+    /// The features used in the tree are added to `features`.
     #[inline]
     fn full_tree(
         &self,
         sample: &'a Sample,
         dist: &[f64],
-        indices: Vec<usize>,
+        examples: NodeExamples,
         criterion: Criterion,
         depth: Depth,
+        features: &mut TreeFeatures,
     ) -> TrainNodePtr
     {
-        let total_weight = indices.par_iter()
-            .copied()
-            .map(|i| dist[i])
+        let NodeExamples { indices, hists, bounds } = examples;
+        let total_weight = indices.iter()
+            .map(|&i| dist[i])
             .sum::<f64>();
 
 
         // Compute the best confidence that minimizes the training error
         // on this node.
-        let (conf, loss) = confidence_and_loss(sample, dist, &indices[..]);
+        let weights = self.label_weights(
+            sample, dist, &indices[..], hists.as_deref()
+        );
+        let (conf, classes, loss) = confidence_from_weights(weights);
+        let conf = Confidence::from(bounds.clamp(conf.0));
 
 
         // If sum of `dist` over `train` is zero, construct a leaf node.
         if loss == 0f64 || depth < 1 {
-            return TrainNode::leaf(conf, total_weight, loss);
+            return TrainNode::leaf(conf, classes, total_weight, loss);
         }
 
 
         // Find the best pair of feature name and threshold
         // based on the `criterion`.
-        let (feature, threshold) = criterion.best_split(
-            &self.bins, sample, dist, &indices[..]
+        let mut examples = NodeExamples { indices, hists, bounds };
+        let split = self.find_split(
+            sample, dist, &mut examples, criterion, features
         );
 
 
-        // Construct the splitting rule
-        // from the best feature and threshold.
-        let rule = Splitter::new(feature, Threshold::from(threshold));
+        // If no feature is allowed, construct a leaf node.
+        let Some(rule) = split else {
+            return TrainNode::leaf(conf, classes, total_weight, loss);
+        };
 
 
-        // Split the train data for left/right childrens
-        let mut lindices = Vec::new();
-        let mut rindices = Vec::new();
-        for i in indices {
-            match rule.split(sample, i) {
-                LR::Left  => { lindices.push(i); },
-                LR::Right => { rindices.push(i); },
-            }
+        self.branch(sample, dist, examples, rule, depth, features)
+            .unwrap_or_else(|| {
+                TrainNode::leaf(conf, classes, total_weight, loss)
+            })
+    }
+
+
+    /// Returns the weights of the labels of the examples in `indices`.
+    /// On a GPU, the weights are read from the histograms
+    /// of the node if given.
+    fn label_weights(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        indices: &[usize],
+        hists: Option<&[Histogram]>,
+    ) -> BTreeMap<i64, f64>
+    {
+        if !self.accelerator.is_gpu() {
+            return label_weights(sample, dist, indices);
+        }
+        if let Some(hist) = hists.and_then(|hists| hists.first()) {
+            return hist.totals();
+        }
+
+        let target = sample.target();
+        let labels = node_labels(target, indices);
+        let sums = self.accelerator.sums(
+            sample,
+            &self.bins,
+            indices,
+            labels.len(),
+            |i| (label_index(&labels, target[i]), [dist[i], 0f64]),
+            false,
+        );
+        match sums {
+            Some(sums) => {
+                labels.iter()
+                    .zip(sums.totals())
+                    .filter(|(_, sum)| sum.count > 0)
+                    .map(|(&y, sum)| (y as i64, sum.first))
+                    .collect()
+            },
+            None => label_weights(sample, dist, indices),
         }
+    }
+
+
+    /// Returns the best splitting rule on `examples`
+    /// based on the `criterion`.
+    /// If the best one violates the monotonicity constraints,
+    /// this method returns the best one among the rules
+    /// that respect the constraints.
+    /// Returns `None` if no feature is allowed.
+    fn find_split(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        examples: &mut NodeExamples,
+        criterion: Criterion,
+        features: &TreeFeatures,
+    ) -> Option<Splitter>
+    {
+        let indices = &examples.indices[..];
+        let allowed = self.constraint.node_predicate(features);
+        let best = if !self.bundles.is_empty() {
+            criterion.best_split_bundled(
+                &self.bins, &self.bundles, sample, dist, indices, &allowed
+            )
+        } else if self.histogram {
+            let hists = examples.hists.get_or_insert_with(|| {
+                self.histograms(sample, dist, indices)
+            });
+            criterion.best_split_from_histograms(
+                &self.bins, sample, hists, &allowed
+            )
+        } else {
+            criterion.best_split(
+                &self.bins, sample, dist, indices, &allowed
+            )
+        };
+        if !self.constraint.has_monotone() {
+            return best;
+        }
+
 
+        let bounds = examples.bounds;
+        let admits = |rule: &Splitter| {
+            self.admits(sample, dist, rule, indices, bounds)
+        };
+        if best.as_ref().is_none_or(admits) {
+            return best;
+        }
+        criterion.ranked_splits(
+                &self.bins, &self.bundles, sample, dist, indices, &allowed
+            )
+            .into_iter()
+            .find(admits)
+    }
 
-        // If the split has no meaning, construct a leaf node.
+
+    /// Returns `true` if the split of `indices` by `rule`
+    /// respects the monotonicity constraints
+    /// on a node whose confidence is in `bounds`.
+    /// The splits that send all the examples to one side
+    /// are left to the callers.
+    fn admits(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        rule: &Splitter,
+        indices: &[usize],
+        bounds: Bounds,
+    ) -> bool
+    {
+        let (lindices, rindices) = partition(sample, rule, indices.to_vec());
         if lindices.is_empty() || rindices.is_empty() {
-            return TrainNode::leaf(conf, total_weight, loss);
+            return true;
+        }
+        let children = (&lindices[..], &rindices[..]);
+        self.child_bounds(sample, dist, rule, children, bounds).is_some()
+    }
+
+
+    /// Returns the ranges of the confidences of the children
+    /// of a node whose confidence is in `bounds`,
+    /// where `children` is the examples sent to the left and the right
+    /// by `rule`.
+    /// Returns `None` if the confidences of the children
+    /// violate the monotonicity constraint on the feature of `rule`.
+    fn child_bounds(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        rule: &Splitter,
+        children: (&[usize], &[usize]),
+        bounds: Bounds,
+    ) -> Option<(Bounds, Bounds)>
+    {
+        let direction = self.constraint.monotone(&rule.feature);
+        if direction.is_none() {
+            return Some((bounds, bounds));
+        }
+        if rule.categories.is_some() {
+            return None;
+        }
+
+        let (lindices, rindices) = children;
+        let (lconf, _, _) = confidence_and_loss(sample, dist, lindices);
+        let (rconf, _, _) = confidence_and_loss(sample, dist, rindices);
+        let lconf = bounds.clamp(lconf.0);
+        let rconf = bounds.clamp(rconf.0);
+        if !Bounds::admits(direction, lconf, rconf) {
+            return None;
+        }
+        Some(bounds.children(direction, lconf, rconf))
+    }
+
+
+    /// Returns the decrease of the training loss
+    /// by splitting the examples of loss `loss`
+    /// into `lindices` and `rindices`.
+    /// Returns `None` if one of the children has
+    /// fewer than `min_samples_leaf` examples
+    /// or the decrease is smaller than `min_split_gain`.
+    fn split_gain(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        lindices: &[usize],
+        rindices: &[usize],
+        loss: LossValue,
+    ) -> Option<f64>
+    {
+        let min_samples = self.limits.min_samples_leaf;
+        if lindices.len() < min_samples || rindices.len() < min_samples {
+            return None;
+        }
+
+        let (_, _, lloss) = confidence_and_loss(sample, dist, lindices);
+        let (_, _, rloss) = confidence_and_loss(sample, dist, rindices);
+        let gain = loss.0 - lloss.0 - rloss.0;
+
+        // Skip the comparison by default
+        // since `gain` may be slightly negative by the numerical error.
+        let min_gain = self.limits.min_split_gain;
+        if min_gain > 0f64 && gain < min_gain {
+            return None;
         }
+        Some(gain)
+    }
+
+
+    /// Grows a tree of at most `max_leaves` leaves
+    /// in the best-first order;
+    /// the leaf whose split decreases the training loss the most
+    /// is split first.
+    /// If `root` is given, the root is split by `root`
+    /// and this method returns `None`
+    /// if the split violates the growth limits.
+    fn best_first_tree(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        indices: Vec<usize>,
+        root: Option<Splitter>,
+        features: &mut TreeFeatures,
+    ) -> Option<TrainNodePtr>
+    {
+        let max_leaves = self.limits.max_leaves.unwrap();
+        let mut tree = BestFirst {
+            sample, dist, nodes: Vec::new(), candidates: Vec::new(),
+        };
+        let forced = root.is_some();
+        let bounds = Bounds::default();
+        let splittable = self.add_leaf(
+            &mut tree, indices, self.max_depth, root, features, bounds
+        );
+        if forced && !splittable { return None; }
+
+
+        let mut n_leaves = 1;
+        while n_leaves < max_leaves {
+            // Split the leaf with the largest gain.
+            // Ties are broken by the order of the leaves.
+            let best = tree.candidates.iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| {
+                    a.gain.partial_cmp(&b.gain).unwrap()
+                        .then(b.node.cmp(&a.node))
+                })
+                .map(|(k, _)| k);
+            let Some(best) = best else { break; };
+            let candidate = tree.candidates.swap_remove(best);
+
+            features.used.insert(candidate.rule.feature.clone());
+            features.path = candidate.path;
+            features.path.push(candidate.rule.feature.clone());
+            let depth = candidate.depth - 1;
+            let (lbounds, rbounds) = candidate.bounds;
+            let left = tree.nodes.len();
+            self.add_leaf(
+                &mut tree, candidate.lindices, depth, None, features, lbounds
+            );
+            let right = tree.nodes.len();
+            self.add_leaf(
+                &mut tree, candidate.rindices, depth, None, features, rbounds
+            );
+
+            let node = tree.nodes[candidate.node].as_mut().unwrap();
+            node.children = Some((candidate.rule, left, right));
+            n_leaves += 1;
+        }
+        features.path.clear();
+
+        Some(tree.take_train_node(0))
+    }
+
+
+    /// Adds a leaf of the examples in `indices` to `tree`,
+    /// whose confidence is in `bounds`.
+    /// If the leaf can be split by `rule`,
+    /// or by the best splitting rule if `rule` is `None`,
+    /// the split is added to the candidates and this method returns `true`.
+    fn add_leaf(
+        &self,
+        tree: &mut BestFirst<'_>,
+        indices: Vec<usize>,
+        depth: Depth,
+        rule: Option<Splitter>,
+        features: &TreeFeatures,
+        bounds: Bounds,
+    ) -> bool
+    {
+        let (sample, dist) = (tree.sample, tree.dist);
+        let total_weight = indices.iter()
+            .map(|&i| dist[i])
+            .sum::<f64>();
+        let (confidence, classes, loss) = confidence_and_loss(
+            sample, dist, &indices[..]
+        );
+        let confidence = Confidence::from(bounds.clamp(confidence.0));
+        let node = tree.nodes.len();
+        tree.nodes.push(Some(GrowingNode {
+            confidence, classes, total_weight, loss, children: None,
+        }));
+
+
+        let rule = match rule {
+            Some(rule) => rule,
+            None => {
+                if loss == 0f64 || depth < 1 { return false; }
+                let mut examples = NodeExamples {
+                    indices: indices.clone(), hists: None, bounds,
+                };
+                let split = self.find_split(
+                    sample, dist, &mut examples, self.criterion, features
+                );
+                let Some(rule) = split else { return false; };
+                rule
+            },
+        };
+
+
+        let (lindices, rindices) = partition(sample, &rule, indices);
+        let gain = self.split_gain(
+            sample, dist, &lindices[..], &rindices[..], loss
+        );
+        let Some(gain) = gain else { return false; };
+        let children = (&lindices[..], &rindices[..]);
+        let bounds = self.child_bounds(sample, dist, &rule, children, bounds);
+        let Some(bounds) = bounds else { return false; };
+        let path = features.path.clone();
+        tree.candidates.push(Candidate {
+            node, gain, rule, depth, lindices, rindices, bounds, path,
+        });
+        true
+    }
+
+
+    /// Split `indices` by `rule` and grow the children
+    /// of depth at most `depth - 1`.
+    /// Returns `None` if `rule` sends all the examples to one side.
+    /// If the histograms of `examples` are given,
+    /// only the histograms of the smaller child
+    /// are computed from the examples
+    /// and the ones of the other child are obtained by subtraction.
+    #[inline]
+    fn branch(
+        &self,
+        sample: &'a Sample,
+        dist: &[f64],
+        examples: NodeExamples,
+        rule: Splitter,
+        depth: Depth,
+        features: &mut TreeFeatures,
+    ) -> Option<TrainNodePtr>
+    {
+        let NodeExamples { indices, hists, bounds } = examples;
+        let criterion = self.criterion;
+        let total_weight = indices.iter()
+            .map(|&i| dist[i])
+            .sum::<f64>();
+        let (conf, classes, loss) = confidence_and_loss(
+            sample, dist, &indices[..]
+        );
+        let conf = Confidence::from(bounds.clamp(conf.0));
+
+
+        // Split the train data for left/right childrens
+        let (lindices, rindices) = partition(sample, &rule, indices);
+
+
+        // If the split has no meaning
+        // or violates the growth limits, construct a leaf node.
+        self.split_gain(sample, dist, &lindices[..], &rindices[..], loss)?;
+        let children = (&lindices[..], &rindices[..]);
+        let (lbounds, rbounds) = self.child_bounds(
+            sample, dist, &rule, children, bounds
+        )?;
 
         // At this point, `depth > 0` is guaranteed so that
         // one can grow the tree.
+        features.used.insert(rule.feature.clone());
         let depth = depth - 1;
-        let ltree = self.full_tree(sample, dist, lindices, criterion, depth);
-        let rtree = self.full_tree(sample, dist, rindices, criterion, depth);
+        let (lhists, rhists) = match hists {
+            Some(hists) if depth > 0 => {
+                let left_is_small = lindices.len() <= rindices.len();
+                let small = if left_is_small { &lindices } else { &rindices };
+                let small = self.histograms(sample, dist, &small[..]);
+                let large = hists.iter()
+                    .zip(&small)
+                    .map(|(parent, child)| parent.subtract(child))
+                    .collect::<Vec<_>>();
+                if left_is_small {
+                    (Some(small), Some(large))
+                } else {
+                    (Some(large), Some(small))
+                }
+            },
+            _ => (None, None),
+        };
+        let lexamples = NodeExamples {
+            indices: lindices, hists: lhists, bounds: lbounds,
+        };
+        let rexamples = NodeExamples {
+            indices: rindices, hists: rhists, bounds: rbounds,
+        };
+        let forks = depth > 0 && parallel_growth::forks(
+            self.max_depth,
+            lexamples.indices.len(),
+            rexamples.indices.len(),
+            &self.constraint,
+        );
+        features.path.push(rule.feature.clone());
+        let (ltree, rtree) = if forks {
+            let mut rfeatures = features.clone();
+            let (ltree, rtree) = rayon::join(
+                || self.full_tree(
+                    sample, dist, lexamples, criterion, depth, features
+                ),
+                || self.full_tree(
+                    sample, dist, rexamples, criterion, depth, &mut rfeatures
+                ),
+            );
+            features.used.extend(rfeatures.used);
+            (ltree, rtree)
+        } else {
+            let ltree = self.full_tree(
+                sample, dist, lexamples, criterion, depth, features
+            );
+            let rtree = self.full_tree(
+                sample, dist, rexamples, criterion, depth, features
+            );
+            (ltree, rtree)
+        };
+        features.path.pop();
 
 
-        TrainNode::branch(rule, ltree, rtree, conf, total_weight, loss)
+        Some(TrainNode::branch(
+            rule, ltree, rtree, conf, classes, total_weight, loss
+        ))
     }
 }
 
@@ -175,11 +728,50 @@ impl<'a> WeakLearner for DecisionTree<'a> {
             .map(|bin| bin.len())
             .reduce(usize::max)
             .unwrap_or(0);
-        let info = Vec::from([
+        let mut info = Vec::from([
             ("# of bins (max)", format!("{n_bins}")),
             ("Max depth", format!("{}", self.max_depth)),
             ("Split criterion", format!("{}", self.criterion)),
         ]);
+        if self.histogram {
+            info.push(("Binning", String::from("Quantile (histogram)")));
+        }
+        if self.limits.min_samples_leaf > 1 {
+            let min_samples = self.limits.min_samples_leaf;
+            info.push(("Min. samples per leaf", format!("{min_samples}")));
+        }
+        if self.limits.min_split_gain > 0f64 {
+            let min_gain = self.limits.min_split_gain;
+            info.push(("Min. split gain", format!("{min_gain}")));
+        }
+        if let Some(max_leaves) = self.limits.max_leaves {
+            info.push(("Max leaves (best-first)", format!("{max_leaves}")));
+        }
+        match &self.class_weight {
+            Some(ClassWeight::Balanced) => {
+                info.push(("Class weight", String::from("Balanced")));
+            },
+            Some(ClassWeight::Explicit(pairs)) => {
+                let pairs = pairs.iter()
+                    .map(|(label, w)| format!("{label}: {w}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                info.push(("Class weight", pairs));
+            },
+            None => {},
+        }
+        info.extend(self.constraint.info());
+        info.extend(self.subsample.info());
+        info.extend(self.accelerator.info());
+        if !self.bundles.is_empty() {
+            let n_bundles = self.bundles.len();
+            let n_features = self.bundles.iter()
+                .map(|bundle| bundle.len())
+                .sum::<usize>();
+            info.push(
+                ("# of bundles", format!("{n_bundles} ({n_features} features)"))
+            );
+        }
         Some(info)
     }
 
@@ -192,6 +784,8 @@ impl<'a> WeakLearner for DecisionTree<'a> {
     fn produce(&self, sample: &Sample, dist: &[f64])
         -> Self::Hypothesis
     {
+        let weighted = self.training_dist(sample, dist);
+        let dist = weighted.as_deref().unwrap_or(dist);
         let n_sample = sample.shape().0;
 
         let indices = (0..n_sample).filter(|&i| dist[i] > 0f64)
@@ -201,53 +795,296 @@ impl<'a> WeakLearner for DecisionTree<'a> {
         let criterion = self.criterion;
 
         // Construct a large binary tree
-        let tree = self.full_tree(
-            sample, dist, indices, criterion, self.max_depth
-        );
+        let mut features = self.constraint.tree_features(sample);
+        let tree = if self.limits.max_leaves.is_some() {
+            self.best_first_tree(sample, dist, indices, None, &mut features)
+                .unwrap()
+        } else {
+            self.full_tree(
+                sample,
+                dist,
+                indices.into(),
+                criterion,
+                self.max_depth,
+                &mut features,
+            )
+        };
+        self.constraint.record(features.used);
 
 
-        tree.borrow_mut().remove_redundant_nodes();
+        into_classifier(tree)
+    }
 
 
-        let root = Node::from(
-            Rc::try_unwrap(tree)
-                .expect("Root node has reference counter >= 1")
-                .into_inner()
+    /// Grows a tree from each of the `k` best splitting rules at the root.
+    /// The returned trees are sorted from the best root split
+    /// to the worst one.
+    /// Root splits that send all the examples to one side are skipped.
+    /// If the feature budget is set,
+    /// the features used in all the returned trees are recorded.
+    fn produce_many(&self, sample: &Sample, dist: &[f64], k: usize)
+        -> Vec<Self::Hypothesis>
+    {
+        assert!(k > 0, "The number of candidates must be positive");
+        let original = dist;
+        let weighted = self.training_dist(sample, dist);
+        let dist = weighted.as_deref().unwrap_or(dist);
+        let n_sample = sample.shape().0;
+
+        let indices = (0..n_sample).filter(|&i| dist[i] > 0f64)
+            .collect::<Vec<usize>>();
+        assert_ne!(indices.len(), 0);
+
+        let criterion = self.criterion;
+
+        // The trees share `features` so that
+        // the features of all the candidates are within the budget.
+        let mut features = self.constraint.tree_features(sample);
+        let allowed = self.constraint.node_predicate(&features);
+        let splits = criterion.ranked_splits(
+            &self.bins, &self.bundles, sample, dist, &indices[..], &allowed
         );
+        drop(allowed);
+
+        let mut trees = Vec::with_capacity(k);
+        for rule in splits {
+            if trees.len() >= k { break; }
+            if !self.constraint.predicate(&features)(&rule.feature) {
+                continue;
+            }
+
+            let tree = if self.limits.max_leaves.is_some() {
+                self.best_first_tree(
+                    sample, dist, indices.clone(), Some(rule), &mut features
+                )
+            } else {
+                self.branch(
+                    sample,
+                    dist,
+                    indices.clone().into(),
+                    rule,
+                    self.max_depth,
+                    &mut features,
+                )
+            };
+            if let Some(tree) = tree {
+                trees.push(into_classifier(tree));
+            }
+        }
+        self.constraint.record(features.used);
+
+        // Every root split is meaningless,
+        // so the best tree is a leaf.
+        if trees.is_empty() {
+            trees.push(self.produce(sample, original));
+        }
+        trees
+    }
 
 
-        DecisionTreeClassifier::from(root)
+    /// Returns the upper bound of the Rademacher complexity
+    /// of the decision trees with `n` leaves,
+    /// `sqrt( (4n + 2) log2(d + 2) ln(m + 1) / m )`,
+    /// where `n` is the number of leaves of `hypothesis`,
+    /// `d` is the number of features,
+    /// and `m` is the number of examples.
+    /// This bound is given in the DeepBoost paper
+    /// by Cortes, Mohri, and Syed.
+    fn complexity(&self, sample: &Sample, hypothesis: &Self::Hypothesis)
+        -> f64
+    {
+        let (n_sample, n_feature) = sample.shape();
+        let n_leaves = hypothesis.n_leaves() as f64;
+        let m = n_sample as f64;
+        let d = n_feature as f64;
+
+        ((4f64 * n_leaves + 2f64) * (d + 2f64).log2() * (m + 1f64).ln() / m)
+            .sqrt()
+    }
+}
+
+
+/// The examples on a node.
+/// `hists` are the histograms of the examples for each feature,
+/// which are computed only if the histograms are used.
+/// `bounds` is the range of the confidence of the node.
+struct NodeExamples {
+    indices: Vec<usize>,
+    hists: Option<Vec<Histogram>>,
+    bounds: Bounds,
+}
+
+
+impl From<Vec<usize>> for NodeExamples {
+    #[inline]
+    fn from(indices: Vec<usize>) -> Self {
+        Self { indices, hists: None, bounds: Bounds::default() }
+    }
+}
+
+
+/// A node of a tree grown in the best-first order.
+struct GrowingNode {
+    confidence: Confidence<f64>,
+    classes: Vec<(i64, f64)>,
+    total_weight: f64,
+    loss: LossValue,
+    /// The splitting rule and the positions of the children.
+    children: Option<(Splitter, usize, usize)>,
+}
+
+
+/// A split of a leaf grown in the best-first order.
+struct Candidate {
+    /// The position of the leaf.
+    node: usize,
+    gain: f64,
+    rule: Splitter,
+    depth: Depth,
+    lindices: Vec<usize>,
+    rindices: Vec<usize>,
+    /// The ranges of the confidences of the children.
+    bounds: (Bounds, Bounds),
+    /// The features on the path from the root to the leaf.
+    path: Vec<String>,
+}
+
+
+/// A tree grown in the best-first order.
+/// `candidates` are the splits of the current leaves.
+struct BestFirst<'s> {
+    sample: &'s Sample,
+    dist: &'s [f64],
+    nodes: Vec<Option<GrowingNode>>,
+    candidates: Vec<Candidate>,
+}
+
+
+impl BestFirst<'_> {
+    /// Takes the sub-tree rooted at `nodes[node]` as a `TrainNode`.
+    fn take_train_node(&mut self, node: usize) -> TrainNodePtr {
+        let GrowingNode {
+            confidence, classes, total_weight, loss, children,
+        } = self.nodes[node].take().unwrap();
+
+        match children {
+            None => TrainNode::leaf(confidence, classes, total_weight, loss),
+            Some((rule, left, right)) => {
+                let left = self.take_train_node(left);
+                let right = self.take_train_node(right);
+                TrainNode::branch(
+                    rule, left, right, confidence, classes, total_weight, loss
+                )
+            },
+        }
+    }
+}
+
+
+/// Splits `indices` into the left and the right ones by `rule`.
+fn partition(sample: &Sample, rule: &Splitter, indices: Vec<usize>)
+    -> (Vec<usize>, Vec<usize>)
+{
+    let mut lindices = Vec::new();
+    let mut rindices = Vec::new();
+    for i in indices {
+        match rule.split(sample, i) {
+            LR::Left  => { lindices.push(i); },
+            LR::Right => { rindices.push(i); },
+        }
     }
+    (lindices, rindices)
+}
+
+
+/// Pares the redundant nodes of `tree`
+/// and converts it into a [`DecisionTreeClassifier`].
+#[inline]
+fn into_classifier(tree: TrainNodePtr) -> DecisionTreeClassifier {
+    tree.lock().unwrap().remove_redundant_nodes();
+
+
+    let root = Node::from(
+        Arc::try_unwrap(tree)
+            .expect("Root node has reference counter >= 1")
+            .into_inner()
+            .unwrap()
+    );
+
+
+    DecisionTreeClassifier::from(root)
 }
 
 
-/// This function returns a tuple `(c, l)` where
+/// This function returns a tuple `(c, p, l)` where
 /// - `c` is the **confidence** for some label `y`
 /// that minimizes the training loss.
+/// - `p` is the weighted proportions of the classes,
+///   sorted by the labels.
 /// - `l` is the training loss when the confidence is `y`.
 /// 
-/// **Note that** this function assumes that the label is `+1` or `-1`.
+/// **Note that** the confidence assumes that the label is `+1` or `-1`.
 #[inline]
 fn confidence_and_loss(sample: &Sample, dist: &[f64], indices: &[usize])
-    -> (Confidence<f64>, LossValue)
+    -> (Confidence<f64>, Vec<(i64, f64)>, LossValue)
 {
-
     assert_ne!(indices.len(), 0);
+    confidence_from_weights(label_weights(sample, dist, indices))
+}
+
+
+/// Returns the weights of the labels of the examples in `indices`.
+fn label_weights(sample: &Sample, dist: &[f64], indices: &[usize])
+    -> BTreeMap<i64, f64>
+{
     let target = sample.target();
-    let mut counter: HashMap<i64, f64> = HashMap::new();
+    let mut counter: BTreeMap<i64, f64> = BTreeMap::new();
 
     for &i in indices {
         let l = target[i] as i64;
         let cnt = counter.entry(l).or_insert(0f64);
         *cnt += dist[i];
     }
+    counter
+}
+
+
+/// Returns the labels of the examples in `indices` in ascending order.
+fn node_labels(target: &[f64], indices: &[usize]) -> Vec<i32> {
+    indices.iter()
+        .map(|&i| target[i] as i32)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
 
 
+/// Returns the index of label `y` in `labels`.
+#[inline]
+fn label_index(labels: &[i32], y: f64) -> u32 {
+    labels.binary_search(&(y as i32)).unwrap() as u32
+}
+
+
+/// Same as `confidence_and_loss`,
+/// but takes the weights of the labels of a node.
+fn confidence_from_weights(counter: BTreeMap<i64, f64>)
+    -> (Confidence<f64>, Vec<(i64, f64)>, LossValue)
+{
     let total = counter.values().sum::<f64>();
 
-    // Compute the max (key, val) that has maximal p(j, t)
-    let (label, p) = counter.into_par_iter()
-        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+    let mut classes = counter.iter()
+        .map(|(&label, &w)| {
+            let p = if total > 0f64 { w / total } else { 0f64 };
+            (label, p)
+        })
+        .collect::<Vec<_>>();
+    classes.sort_by_key(|&(label, _)| label);
+
+    // Compute the max (key, val) that has maximal p(j, t).
+    // Ties are broken by the smaller label.
+    let (label, p) = counter.into_iter()
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(b.0.cmp(&a.0)))
         .unwrap();
 
 
@@ -264,7 +1101,7 @@ fn confidence_and_loss(sample: &Sample, dist: &[f64], indices: &[usize])
 
     let confidence = Confidence::from(confidence);
     let loss = LossValue::from(loss);
-    (confidence, loss)
+    (confidence, classes, loss)
 }
 
 
@@ -1,5 +1,12 @@
 //! Defines the decision tree classifier.
-use crate::{Classifier, Sample};
+use crate::{
+    Classifier,
+    MulticlassClassifier,
+    Sample,
+    WeightedMajority,
+    Compression,
+    CompressionReport,
+};
 
 
 use super::node::*;
@@ -8,6 +15,7 @@ use serde::{Serialize, Deserialize};
 use std::path::Path;
 use std::fs::File;
 use std::io::prelude::*;
+use std::collections::BTreeSet;
 
 
 /// Decision tree classifier.
@@ -33,7 +41,49 @@ impl Classifier for DecisionTreeClassifier {
 }
 
 
+/// A decision tree trained on a multiclass sample
+/// predicts the class with the largest weighted proportion
+/// on the leaf.
+/// Use [`Criterion::Entropy`](crate::Criterion::Entropy)
+/// or [`Criterion::Gini`](crate::Criterion::Gini)
+/// to grow the tree for multiclass problems.
+impl MulticlassClassifier for DecisionTreeClassifier {
+    fn predict_class(&self, sample: &Sample, row: usize) -> i64 {
+        self.root.leaf(sample, row).majority_class()
+    }
+
+
+    /// Returns the weighted proportions of the classes on the leaf.
+    fn class_probabilities(&self, sample: &Sample, row: usize)
+        -> Vec<(i64, f64)>
+    {
+        self.root.leaf(sample, row).classes.clone()
+    }
+}
+
+
 impl DecisionTreeClassifier {
+    /// Returns the number of nodes in this tree.
+    pub fn n_nodes(&self) -> usize {
+        self.root.n_nodes()
+    }
+
+
+    /// Returns the number of leaves in this tree.
+    pub fn n_leaves(&self) -> usize {
+        self.root.n_leaves()
+    }
+
+
+    /// Returns the names of the features used in this tree
+    /// in lexicographical order.
+    pub fn used_features(&self) -> Vec<&str> {
+        let mut names = BTreeSet::new();
+        self.root.used_features(&mut names);
+        names.into_iter().collect()
+    }
+
+
     /// Write the current decision tree to dot file.
     #[inline]
     pub fn to_dot_file<P>(&self, path: P) -> std::io::Result<()>
@@ -54,3 +104,68 @@ impl DecisionTreeClassifier {
         Ok(())
     }
 }
+
+
+impl WeightedMajority<DecisionTreeClassifier> {
+    /// Returns the names of the features used in this ensemble
+    /// in lexicographical order.
+    pub fn used_features(&self) -> Vec<&str> {
+        let mut names = BTreeSet::new();
+        self.hypotheses.iter()
+            .for_each(|h| { h.root.used_features(&mut names); });
+        names.into_iter().collect()
+    }
+
+
+    /// Compress this ensemble by `config` (see [`Compression`])
+    /// and report the number of trees/nodes and the accuracy on `sample`
+    /// before and after the compression.
+    pub fn compress(&self, sample: &Sample, config: Compression)
+        -> (Self, CompressionReport)
+    {
+        let trees = self.hypotheses.iter()
+            .map(|h| Self::compress_tree(h, &config))
+            .collect::<Vec<_>>();
+        let (weights, hypotheses) = config.compress_members(
+            &self.weights[..], &trees[..]
+        );
+        let compressed = Self { weights, hypotheses };
+
+        let report = CompressionReport {
+            metric: "Accuracy",
+            trees_before: self.hypotheses.len(),
+            trees_after: compressed.hypotheses.len(),
+            nodes_before: self.n_nodes(),
+            nodes_after: compressed.n_nodes(),
+            score_before: accuracy(self, sample),
+            score_after: accuracy(&compressed, sample),
+        };
+        (compressed, report)
+    }
+
+
+    fn compress_tree(tree: &DecisionTreeClassifier, config: &Compression)
+        -> DecisionTreeClassifier
+    {
+        let root = tree.root.clone().compress(config);
+        DecisionTreeClassifier::from(root)
+    }
+
+
+    fn n_nodes(&self) -> usize {
+        self.hypotheses.iter()
+            .map(|h| h.n_nodes())
+            .sum::<usize>()
+    }
+}
+
+
+fn accuracy<H: Classifier>(f: &H, sample: &Sample) -> f64 {
+    let n_sample = sample.shape().0;
+    let n_correct = f.predict_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(p, &y)| *p == y as i64)
+        .count();
+    n_correct as f64 / n_sample as f64
+}
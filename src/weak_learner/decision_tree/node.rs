@@ -1,6 +1,6 @@
 //! Defines the inner representation 
 //! of the Decision Tree class.
-use crate::{Classifier, Sample};
+use crate::{Classifier, Sample, Compression};
 
 
 use crate::weak_learner::common::{
@@ -12,7 +12,8 @@ use super::train_node::*;
 
 use serde::{Serialize, Deserialize};
 
-use std::rc::Rc;
+use std::sync::Arc;
+use std::collections::BTreeSet;
 
 
 /// Enumeration of `BranchNode` and `LeafNode`.
@@ -56,6 +57,8 @@ impl BranchNode {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LeafNode {
     pub(super) confidence: Confidence<f64>,
+    #[serde(default)]
+    pub(super) classes: Vec<(i64, f64)>,
 }
 
 
@@ -64,8 +67,29 @@ impl LeafNode {
     /// given to this function.
     /// Note that this function does not assign the impurity.
     #[inline]
-    pub(crate) fn from_raw(confidence: Confidence<f64>) -> Self {
-        Self { confidence }
+    pub(crate) fn from_raw(
+        confidence: Confidence<f64>,
+        classes: Vec<(i64, f64)>,
+    ) -> Self
+    {
+        Self { confidence, classes }
+    }
+
+
+    /// Returns the class with the largest proportion on this leaf.
+    /// The ties are broken by the smallest label.
+    pub(super) fn majority_class(&self) -> i64 {
+        self.classes.iter()
+            .fold(None, |best: Option<(i64, f64)>, &(label, p)| {
+                match best {
+                    Some((_, q)) if q >= p => best,
+                    _ => Some((label, p)),
+                }
+            })
+            .map(|(label, _)| label)
+            .unwrap_or_else(|| {
+                if self.confidence.0 >= 0f64 { 1 } else { -1 }
+            })
     }
 }
 
@@ -74,12 +98,12 @@ impl From<TrainBranchNode> for BranchNode {
     #[inline]
     fn from(branch: TrainBranchNode) -> Self {
 
-        let left = match Rc::try_unwrap(branch.left) {
-            Ok(l) => l.into_inner().into(),
+        let left = match Arc::try_unwrap(branch.left) {
+            Ok(l) => l.into_inner().unwrap().into(),
             Err(_) => panic!("Strong count is greater than 1")
         };
-        let right = match Rc::try_unwrap(branch.right) {
-            Ok(r) => r.into_inner().into(),
+        let right = match Arc::try_unwrap(branch.right) {
+            Ok(r) => r.into_inner().unwrap().into(),
             Err(_) => panic!("Strong count is greater than 1")
         };
 
@@ -95,7 +119,7 @@ impl From<TrainBranchNode> for BranchNode {
 impl From<TrainLeafNode> for LeafNode {
     #[inline]
     fn from(leaf: TrainLeafNode) -> Self {
-        Self::from_raw(leaf.confidence)
+        Self::from_raw(leaf.confidence, leaf.classes)
     }
 }
 
@@ -146,14 +170,85 @@ impl Classifier for Node {
 
 
 impl Node {
+    /// Returns the leaf that the `row`-th example reaches.
+    pub(super) fn leaf(&self, sample: &Sample, row: usize) -> &LeafNode {
+        match self {
+            Node::Branch(b) => match b.rule.split(sample, row) {
+                LR::Left => b.left.leaf(sample, row),
+                LR::Right => b.right.leaf(sample, row),
+            },
+            Node::Leaf(l) => l,
+        }
+    }
+
+
+    /// Returns the number of nodes in this subtree.
+    pub(super) fn n_nodes(&self) -> usize {
+        match self {
+            Node::Branch(b) => 1 + b.left.n_nodes() + b.right.n_nodes(),
+            Node::Leaf(_) => 1,
+        }
+    }
+
+
+    /// Returns the number of leaves in this subtree.
+    pub(super) fn n_leaves(&self) -> usize {
+        match self {
+            Node::Branch(b) => b.left.n_leaves() + b.right.n_leaves(),
+            Node::Leaf(_) => 1,
+        }
+    }
+
+
+    /// Rounds the leaf values by `config`
+    /// and replaces the branch nodes whose children are identical
+    /// by one of the children.
+    pub(super) fn compress(self, config: &Compression) -> Self {
+        match self {
+            Node::Branch(b) => {
+                let left = b.left.compress(config);
+                let right = b.right.compress(config);
+                if left == right { return left; }
+                Node::Branch(BranchNode::from_raw(
+                    b.rule, Box::new(left), Box::new(right),
+                ))
+            },
+            Node::Leaf(l) => {
+                let value = config.quantize(l.confidence.0);
+                let classes = l.classes.into_iter()
+                    .map(|(label, p)| (label, config.quantize(p)))
+                    .collect();
+                Node::Leaf(LeafNode::from_raw(Confidence(value), classes))
+            },
+        }
+    }
+
+
+    /// Inserts the names of the features used in this subtree
+    /// into `names`.
+    pub(super) fn used_features<'a>(&'a self, names: &mut BTreeSet<&'a str>) {
+        if let Node::Branch(b) = self {
+            names.insert(&b.rule.feature);
+            b.left.used_features(names);
+            b.right.used_features(names);
+        }
+    }
+
+
     pub(super) fn to_dot_info(&self, id: usize) -> (Vec<String>, usize) {
         match self {
             Node::Branch(b) => {
-                let b_info = format!(
-                    "\tnode_{id} [ label = \"{feat} < {thr:.2} ?\" ];\n",
-                    feat = b.rule.feature,
-                    thr = b.rule.threshold.0
-                );
+                let b_info = match &b.rule.categories {
+                    Some(categories) => format!(
+                        "\tnode_{id} [ label = \"{feat} in {categories:?} ?\" ];\n",
+                        feat = b.rule.feature,
+                    ),
+                    None => format!(
+                        "\tnode_{id} [ label = \"{feat} < {thr:.2} ?\" ];\n",
+                        feat = b.rule.feature,
+                        thr = b.rule.threshold.0
+                    ),
+                };
 
                 let (l_info, next_id) = b.left.to_dot_info(id + 1);
                 let (mut r_info, ret_id) = b.right.to_dot_info(next_id);
@@ -9,13 +9,12 @@ use crate::weak_learner::common::{
 };
 
 
-use std::rc::Rc;
-use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
 
 use std::fmt;
 
 
-pub(super) type TrainNodePtr = Rc<RefCell<TrainNode>>;
+pub(super) type TrainNodePtr = Arc<Mutex<TrainNode>>;
 
 
 /// Enumeration of `TrainBranchNode` and `TrainLeafNode`.
@@ -48,6 +47,10 @@ pub struct TrainBranchNode {
     pub(super) confidence: Confidence<f64>,
 
 
+    // Weighted proportions of the classes on this node.
+    pub(super) classes: Vec<(i64, f64)>,
+
+
     // Total mass on this node.
     pub(self) total_weight: f64,
 
@@ -75,6 +78,7 @@ impl TrainBranchNode {
 /// Represents the leaf nodes of decision tree.
 pub struct TrainLeafNode {
     pub(super) confidence: Confidence<f64>,
+    pub(super) classes: Vec<(i64, f64)>,
     pub(self) total_weight: f64,
     pub(self) loss_as_leaf: LossValue,
 }
@@ -97,6 +101,7 @@ impl From<TrainBranchNode> for TrainLeafNode {
     fn from(branch: TrainBranchNode) -> Self {
         Self {
             confidence: branch.confidence,
+            classes: branch.classes,
             total_weight: branch.total_weight,
             loss_as_leaf: branch.loss_as_leaf,
         }
@@ -109,18 +114,20 @@ impl TrainNode {
     #[inline]
     pub(super) fn leaf(
         confidence: Confidence<f64>,
+        classes: Vec<(i64, f64)>,
         total_weight: f64,
         loss_as_leaf: LossValue,
-    ) -> Rc<RefCell<Self>>
+    ) -> TrainNodePtr
     {
         let leaf = TrainLeafNode {
             confidence,
+            classes,
             total_weight,
             loss_as_leaf,
         };
 
 
-        Rc::new(RefCell::new(TrainNode::Leaf(leaf)))
+        Arc::new(Mutex::new(TrainNode::Leaf(leaf)))
     }
 
 
@@ -131,17 +138,20 @@ impl TrainNode {
         left: TrainNodePtr,
         right: TrainNodePtr,
         confidence: Confidence<f64>,
+        classes: Vec<(i64, f64)>,
         total_weight: f64,
         loss_as_leaf: LossValue,
-    ) -> Rc<RefCell<Self>>
+    ) -> TrainNodePtr
     {
-        let leaves = left.borrow().leaves() + right.borrow().leaves();
+        let leaves = left.lock().unwrap().leaves()
+            + right.lock().unwrap().leaves();
         let node = TrainBranchNode {
             rule,
             left,
             right,
 
             confidence,
+            classes,
             total_weight,
             loss_as_leaf,
 
@@ -149,7 +159,7 @@ impl TrainNode {
             leaves,
         };
 
-        Rc::new(RefCell::new(TrainNode::Branch(node)))
+        Arc::new(Mutex::new(TrainNode::Branch(node)))
     }
 
 
@@ -194,21 +204,21 @@ impl TrainNode {
 
             // If the left node is not a leaf,
             // move to the leaf node.
-            if !branch.left.borrow().is_leaf() {
-                branch.left.borrow_mut().remove_redundant_nodes();
+            if !branch.left.lock().unwrap().is_leaf() {
+                branch.left.lock().unwrap().remove_redundant_nodes();
                 return;
             }
             // If the right node is not a leaf,
             // move to the leaf node.
-            if !branch.right.borrow().is_leaf() {
-                branch.right.borrow_mut().remove_redundant_nodes();
+            if !branch.right.lock().unwrap().is_leaf() {
+                branch.right.lock().unwrap().remove_redundant_nodes();
                 return;
             }
 
 
             let t = branch.node_misclassification_cost();
-            let l = branch.left.borrow().node_misclassification_cost();
-            let r = branch.right.borrow().node_misclassification_cost();
+            let l = branch.left.lock().unwrap().node_misclassification_cost();
+            let r = branch.right.lock().unwrap().node_misclassification_cost();
 
 
             if t == l + r {
@@ -216,14 +226,15 @@ impl TrainNode {
                     *self = TrainNode::Leaf(
                         TrainLeafNode {
                             confidence: branch.confidence,
+                            classes: std::mem::take(&mut branch.classes),
                             total_weight: branch.total_weight,
                             loss_as_leaf: branch.loss_as_leaf,
                         }
                     );
                 }
             } else {
-                branch.left.borrow_mut().remove_redundant_nodes();
-                branch.right.borrow_mut().remove_redundant_nodes();
+                branch.left.lock().unwrap().remove_redundant_nodes();
+                branch.right.lock().unwrap().remove_redundant_nodes();
             }
         }
     }
@@ -242,8 +253,8 @@ impl Classifier for TrainBranchNode {
     #[inline]
     fn confidence(&self, sample: &Sample, row: usize) -> f64 {
         match self.rule.split(sample, row) {
-            LR::Left => self.left.borrow().confidence(sample, row),
-            LR::Right => self.right.borrow().confidence(sample, row)
+            LR::Left => self.left.lock().unwrap().confidence(sample, row),
+            LR::Right => self.right.lock().unwrap().confidence(sample, row)
         }
     }
 }
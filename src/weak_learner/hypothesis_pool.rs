@@ -0,0 +1,4 @@
+/// Defines `HypothesisPool`.
+mod pool;
+
+pub use pool::HypothesisPool;
@@ -0,0 +1,143 @@
+use rayon::prelude::*;
+
+use crate::{
+    Sample,
+    Classifier,
+    WeakLearner,
+    common::utils,
+};
+
+
+/// A weak learner over a fixed, finite set of hypotheses.
+/// For a given distribution over training examples,
+/// `HypothesisPool` returns the hypothesis in the pool
+/// with the maximum edge `Σ_i d_i y_i h(x_i)`.
+/// Ties are broken by the position in the pool.
+///
+/// With `HypothesisPool`, any booster
+/// (e.g., [`LPBoost`], [`ERLPBoost`], and [`AdaBoost`])
+/// runs over a pre-computed hypothesis class,
+/// such as the decision stumps on each feature,
+/// without calling a learning algorithm.
+/// Note that some boosters such as [`AdaBoost`] assume
+/// that the best edge is positive.
+/// Add the negations of the hypotheses to the pool
+/// if the pool is not closed under negation.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// # let hypotheses: Vec<DecisionTreeClassifier> = Vec::new();
+/// // `hypotheses` is the pre-computed hypothesis class.
+/// let pool = HypothesisPool::new(hypotheses);
+///
+/// let n_sample = sample.shape().0 as f64;
+/// let mut booster = LPBoost::init(&sample)
+///     .tolerance(0.01)
+///     .nu(0.1 * n_sample);
+/// let f = booster.run(&pool);
+/// ```
+///
+/// [`LPBoost`]: crate::booster::LPBoost
+/// [`ERLPBoost`]: crate::booster::ERLPBoost
+/// [`AdaBoost`]: crate::booster::AdaBoost
+pub struct HypothesisPool<H> {
+    hypotheses: Vec<H>,
+}
+
+
+impl<H> HypothesisPool<H> {
+    /// Construct a new instance of `HypothesisPool`
+    /// over the given hypotheses.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn new(hypotheses: Vec<H>) -> Self {
+        assert!(!hypotheses.is_empty(), "The hypothesis pool is empty");
+        Self { hypotheses }
+    }
+
+
+    /// Returns the number of hypotheses in the pool.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn len(&self) -> usize {
+        self.hypotheses.len()
+    }
+
+
+    /// Returns `true` if the pool has no hypothesis.
+    /// Since [`HypothesisPool::new`] rejects the empty pool,
+    /// this method always returns `false`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn is_empty(&self) -> bool {
+        self.hypotheses.is_empty()
+    }
+
+
+    /// Returns the hypotheses in the pool.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn hypotheses(&self) -> &[H] {
+        &self.hypotheses[..]
+    }
+}
+
+
+impl<H> HypothesisPool<H>
+    where H: Classifier + Sync,
+{
+    /// Returns the indices of the hypotheses
+    /// sorted in the descending order of the edge for `dist`.
+    fn sorted_by_edge(&self, sample: &Sample, dist: &[f64]) -> Vec<usize> {
+        let edges = self.hypotheses.par_iter()
+            .map(|h| utils::edge_of_hypothesis(sample, dist, h))
+            .collect::<Vec<_>>();
+
+        let mut ix = (0..edges.len()).collect::<Vec<_>>();
+        ix.sort_by(|&i, &j| edges[j].partial_cmp(&edges[i]).unwrap());
+        ix
+    }
+}
+
+
+impl<H> WeakLearner for HypothesisPool<H>
+    where H: Classifier + Clone + Sync,
+{
+    type Hypothesis = H;
+
+
+    fn name(&self) -> &str {
+        "Hypothesis Pool"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("# of hypotheses", format!("{}", self.hypotheses.len())),
+        ]);
+        Some(info)
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let best = self.sorted_by_edge(sample, dist)[0];
+        self.hypotheses[best].clone()
+    }
+
+
+    fn produce_many(&self, sample: &Sample, dist: &[f64], k: usize)
+        -> Vec<Self::Hypothesis>
+    {
+        assert!(k > 0, "The number of candidates must be positive");
+        self.sorted_by_edge(sample, dist)
+            .into_iter()
+            .take(k)
+            .map(|i| self.hypotheses[i].clone())
+            .collect()
+    }
+}
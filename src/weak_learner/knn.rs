@@ -0,0 +1,9 @@
+/// Defines `KnnLearner`.
+mod knn_algorithm;
+/// Defines the KD-tree for the neighbor search.
+mod kd_tree;
+/// Defines `KnnClassifier` returned by `KnnLearner`.
+mod knn_classifier;
+
+pub use knn_algorithm::KnnLearner;
+pub use knn_classifier::KnnClassifier;
@@ -0,0 +1,234 @@
+//! Defines the KD-tree over the standardized training examples.
+use serde::{Serialize, Deserialize};
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::Sample;
+use crate::weak_learner::common::standardizer::Standardizer;
+
+
+/// The maximal number of examples in a leaf.
+const LEAF_SIZE: usize = 8;
+
+
+/// A node of [`KdTree`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Node {
+    /// A leaf holding the indices of the examples.
+    Leaf(Vec<usize>),
+    /// A split of the examples by `point[axis] < value`.
+    Split {
+        axis: usize,
+        value: f64,
+        left: usize,
+        right: usize,
+    },
+}
+
+
+/// A neighbor candidate ordered by the distance and then by the index.
+#[derive(Debug, Clone, Copy)]
+struct Neighbor {
+    distance: f64,
+    index: usize,
+}
+
+
+impl PartialEq for Neighbor {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+
+impl Eq for Neighbor {}
+
+
+impl PartialOrd for Neighbor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+impl Ord for Neighbor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+
+/// A KD-tree over the training examples.
+/// The features are standardized by the mean and the standard deviation,
+/// and the missing values are replaced by the means.
+/// The features that take a single value are not used.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct KdTree {
+    features: Vec<String>,
+    means: Vec<f64>,
+    stds: Vec<f64>,
+    points: Vec<Vec<f64>>,
+    nodes: Vec<Node>,
+}
+
+
+impl KdTree {
+    /// Builds the KD-tree over the examples of `sample`.
+    /// Each node splits the examples at the median
+    /// of the coordinate of the largest spread.
+    ///
+    /// Time complexity: `O(n (d + log n) log n)`.
+    pub(super) fn new(sample: &Sample) -> Self {
+        let scaler = Standardizer::new(sample);
+        let n_sample = sample.shape().0;
+        let columns = scaler.columns(sample);
+        let points = (0..n_sample)
+            .map(|i| columns.iter().map(|column| column[i]).collect())
+            .collect::<Vec<Vec<f64>>>();
+
+        let Standardizer { names, means, stds } = scaler;
+        let mut tree = Self {
+            features: names,
+            means,
+            stds,
+            points,
+            nodes: Vec::new(),
+        };
+        let mut indices = (0..n_sample).collect::<Vec<_>>();
+        tree.build(&mut indices[..]);
+        tree
+    }
+
+
+    /// Returns the number of the examples.
+    #[inline]
+    pub(super) fn len(&self) -> usize {
+        self.points.len()
+    }
+
+
+    /// Builds the subtree over `indices`
+    /// and returns the position of its root.
+    fn build(&mut self, indices: &mut [usize]) -> usize {
+        let position = self.nodes.len();
+        self.nodes.push(Node::Leaf(Vec::new()));
+        if indices.len() <= LEAF_SIZE {
+            self.nodes[position] = Node::Leaf(indices.to_vec());
+            return position;
+        }
+
+        let n_features = self.features.len();
+        let (axis, spread) = (0..n_features)
+            .map(|j| {
+                let (min, max) = indices.iter()
+                    .fold((f64::MAX, f64::MIN), |(min, max), &i| {
+                        let x = self.points[i][j];
+                        (min.min(x), max.max(x))
+                    });
+                (j, max - min)
+            })
+            .fold((0, 0f64), |best, (j, s)| {
+                if s > best.1 { (j, s) } else { best }
+            });
+        if spread <= 0f64 {
+            self.nodes[position] = Node::Leaf(indices.to_vec());
+            return position;
+        }
+
+
+        let points = &self.points;
+        indices.sort_by(|&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+        // Move the median to the first position of its value
+        // so that both children are non-empty.
+        let mut mid = indices.len() / 2;
+        let value = self.points[indices[mid]][axis];
+        while mid > 0 && self.points[indices[mid - 1]][axis] >= value {
+            mid -= 1;
+        }
+        if mid == 0 {
+            mid = indices.iter()
+                .position(|&i| self.points[i][axis] > value)
+                .unwrap();
+        }
+        let value = self.points[indices[mid]][axis];
+
+
+        let (left, right) = indices.split_at_mut(mid);
+        let left = self.build(left);
+        let right = self.build(right);
+        self.nodes[position] = Node::Split { axis, value, left, right };
+        position
+    }
+
+
+    /// Returns the standardized point of the `row`-th example of `sample`.
+    fn point_of(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        self.features.iter()
+            .zip(&self.means)
+            .zip(&self.stds)
+            .map(|((name, mean), std)| {
+                let x = sample[name][row];
+                if x.is_nan() { 0f64 } else { (x - mean) / std }
+            })
+            .collect()
+    }
+
+
+    /// Returns the indices of the `k` nearest training examples
+    /// to the `row`-th example of `sample` in the Euclidean distance,
+    /// from the nearest one.
+    /// Ties are broken by the indices.
+    pub(super) fn nearest(&self, sample: &Sample, row: usize, k: usize)
+        -> Vec<usize>
+    {
+        let point = self.point_of(sample, row);
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        if !self.nodes.is_empty() {
+            self.search(0, &point[..], k, &mut heap);
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|neighbor| neighbor.index)
+            .collect()
+    }
+
+
+    /// Pushes the neighbors in the subtree rooted at `position` to `heap`,
+    /// keeping the `k` nearest ones.
+    fn search(
+        &self,
+        position: usize,
+        point: &[f64],
+        k: usize,
+        heap: &mut BinaryHeap<Neighbor>,
+    )
+    {
+        match &self.nodes[position] {
+            Node::Leaf(indices) => {
+                for &index in indices {
+                    let distance = self.points[index].iter()
+                        .zip(point)
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f64>();
+                    heap.push(Neighbor { distance, index });
+                    if heap.len() > k { heap.pop(); }
+                }
+            },
+            &Node::Split { axis, value, left, right } => {
+                let diff = point[axis] - value;
+                let (near, far) = if diff < 0f64 {
+                    (left, right)
+                } else {
+                    (right, left)
+                };
+                self.search(near, point, k, heap);
+                let worst = heap.peek().map(|n| n.distance);
+                let visit = heap.len() < k
+                    || worst.is_some_and(|w| diff * diff <= w);
+                if visit { self.search(far, point, k, heap); }
+            },
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use crate::{Sample, WeakLearner};
+use super::kd_tree::KdTree;
+use super::knn_classifier::KnnClassifier;
+
+
+/// The number of the neighbors set as default.
+pub const DEFAULT_K: usize = 5;
+
+
+/// The k-nearest neighbor weak learner.
+/// [`KnnLearner`] returns a [`KnnClassifier`]
+/// that takes the vote of the `k` nearest training examples
+/// weighted by the given distribution.
+/// Thus, the examples of zero weight do not vote.
+///
+/// The neighbors are searched in a KD-tree
+/// built only once in [`KnnLearner::init`]
+/// and shared by all the hypotheses.
+/// The distance is the Euclidean one on the standardized features,
+/// and the missing values are replaced by the means.
+/// The features that take a single value are not used.
+/// Since the search in a KD-tree degrades in high dimensions,
+/// [`KnnLearner`] is suited to low-dimensional problems.
+///
+/// Note that a training example is the nearest neighbor of itself.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let knn = KnnLearner::init(&sample)
+///     .k(10);
+///
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&knn);
+/// ```
+pub struct KnnLearner {
+    tree: Arc<KdTree>,
+    k: usize,
+}
+
+
+impl KnnLearner {
+    /// Construct a new instance of `KnnLearner`
+    /// and build the KD-tree over the examples of `sample`.
+    ///
+    /// Time complexity: `O(n (d + log n) log n)`.
+    pub fn init(sample: &Sample) -> Self {
+        let tree = Arc::new(KdTree::new(sample));
+        Self { tree, k: DEFAULT_K }
+    }
+
+
+    /// Set the number of the neighbors.
+    /// Default value is `5`.
+    pub fn k(mut self, k: usize) -> Self {
+        assert!(k > 0, "The number of the neighbors must be positive");
+        self.k = k;
+        self
+    }
+}
+
+
+impl WeakLearner for KnnLearner {
+    type Hypothesis = KnnClassifier;
+
+
+    fn name(&self) -> &str {
+        "k-Nearest Neighbor"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("# of examples", format!("{}", self.tree.len())),
+            ("# of neighbors", format!("{}", self.k)),
+        ]);
+        Some(info)
+    }
+
+
+    /// `sample` must have the examples of the sample
+    /// given to [`KnnLearner::init`] in the same order,
+    /// possibly with other labels.
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_sample = sample.shape().0;
+        assert_eq!(
+            n_sample, self.tree.len(),
+            "The sample must have the examples given to `KnnLearner::init`"
+        );
+        assert_eq!(
+            dist.len(), n_sample,
+            "The length of the distribution must be the number of examples"
+        );
+
+        let votes = sample.target()
+            .iter()
+            .zip(dist)
+            .map(|(y, d)| (d * y, *d))
+            .collect::<Vec<_>>();
+        KnnClassifier::from_components(Arc::clone(&self.tree), votes, self.k)
+    }
+}
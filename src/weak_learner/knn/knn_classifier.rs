@@ -0,0 +1,64 @@
+//! Defines the k-nearest neighbor classifier.
+use serde::{Serialize, Deserialize};
+
+use std::sync::Arc;
+
+use crate::{Classifier, Sample};
+use super::kd_tree::KdTree;
+
+
+/// Classifier returned by [`KnnLearner`](crate::KnnLearner).
+/// The confidence for an example `x` is
+/// ```text
+/// Σ_{i ∈ N(x)} d_i y_i / Σ_{i ∈ N(x)} d_i,
+/// ```
+/// where `N(x)` is the `k` nearest training examples to `x`
+/// and `d` is the distribution given to the weak learner.
+/// The confidence is `0` if `d_i = 0` for all `i ∈ N(x)`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KnnClassifier {
+    tree: Arc<KdTree>,
+    // `votes[i]` is the pair `(d_i y_i, d_i)`.
+    votes: Vec<(f64, f64)>,
+    k: usize,
+}
+
+
+impl KnnClassifier {
+    /// Construct a new instance of `KnnClassifier`
+    /// from the given components.
+    #[inline]
+    pub(super) fn from_components(
+        tree: Arc<KdTree>,
+        votes: Vec<(f64, f64)>,
+        k: usize,
+    ) -> Self
+    {
+        Self { tree, votes, k, }
+    }
+
+
+    /// Returns the number of the neighbors.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+
+    /// Returns the indices of the `k` nearest training examples
+    /// to the `row`-th example of `sample`, from the nearest one.
+    pub fn neighbors(&self, sample: &Sample, row: usize) -> Vec<usize> {
+        self.tree.nearest(sample, row, self.k)
+    }
+}
+
+
+impl Classifier for KnnClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let (vote, total) = self.neighbors(sample, row)
+            .into_iter()
+            .fold((0f64, 0f64), |(vote, total), i| {
+                (vote + self.votes[i].0, total + self.votes[i].1)
+            });
+        if total > 0f64 { vote / total } else { 0f64 }
+    }
+}
@@ -0,0 +1,11 @@
+/// Defines `LinearLearnerBuilder`.
+mod builder;
+/// Defines `LinearLearner`.
+mod linear_learner_algorithm;
+/// Defines `LinearClassifier` and `LinearRegressor`
+/// returned by `LinearLearner`.
+mod linear_hypothesis;
+
+pub use builder::LinearLearnerBuilder;
+pub use linear_learner_algorithm::{LinearLearner, Logistic};
+pub use linear_hypothesis::{LinearClassifier, LinearRegressor};
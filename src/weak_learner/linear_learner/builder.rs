@@ -0,0 +1,129 @@
+use crate::Sample;
+use crate::weak_learner::common::standardizer::Standardizer;
+use super::linear_learner_algorithm::{
+    LinearLearner,
+    Logistic,
+};
+
+
+/// The regularization parameter set as default.
+pub const DEFAULT_LAMBDA: f64 = 0.01;
+/// The maximal number of Newton iterations set as default.
+pub const DEFAULT_MAX_ITER: usize = 100;
+/// The tolerance of the Newton iterations set as default.
+pub const DEFAULT_TOLERANCE: f64 = 1e-6;
+
+
+/// A struct that builds [`LinearLearner`].
+/// `LinearLearnerBuilder` keeps parameters for constructing [`LinearLearner`].
+///
+/// By default, the resulting [`LinearLearner`] fits
+/// the logistic regression for classification.
+/// Specify a loss function by [`LinearLearnerBuilder::loss`]
+/// to get a [`LinearLearner`] for gradient boosting.
+///
+/// # Example
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let classification = LinearLearnerBuilder::new(&sample)
+///     .lambda(0.1)
+///     .build();
+///
+/// let regression = LinearLearnerBuilder::new(&sample)
+///     .lambda(0.1)
+///     .loss(GBMLoss::L2)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct LinearLearnerBuilder<'a, L> {
+    sample: &'a Sample,
+    lambda: f64,
+    max_iter: usize,
+    tolerance: f64,
+    loss: L,
+}
+
+
+impl<'a> LinearLearnerBuilder<'a, Logistic> {
+    /// Construct a new instance of [`LinearLearnerBuilder`].
+    /// By default, [`LinearLearnerBuilder`] sets the parameters as follows;
+    /// ```text
+    /// lambda: DEFAULT_LAMBDA == 0.01,
+    /// max_iter: DEFAULT_MAX_ITER == 100,
+    /// tolerance: DEFAULT_TOLERANCE == 1e-6,
+    /// loss: Logistic,
+    /// ```
+    pub fn new(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            lambda: DEFAULT_LAMBDA,
+            max_iter: DEFAULT_MAX_ITER,
+            tolerance: DEFAULT_TOLERANCE,
+            loss: Logistic,
+        }
+    }
+}
+
+
+impl<'a, L> LinearLearnerBuilder<'a, L> {
+    /// Set the regularization parameter of the coefficients
+    /// on the standardized features.
+    /// Default value is `0.01`.
+    pub fn lambda(mut self, lambda: f64) -> Self {
+        assert!(
+            lambda >= 0f64,
+            "The regularization parameter must be non-negative"
+        );
+        self.lambda = lambda;
+        self
+    }
+
+
+    /// Set the maximal number of Newton iterations
+    /// for the logistic regression.
+    /// Default value is `100`.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        assert!(max_iter > 0, "The number of iterations must be positive");
+        self.max_iter = max_iter;
+        self
+    }
+
+
+    /// Set the tolerance of the Newton iterations.
+    /// The iterations stop once the largest change
+    /// of the coefficients is below `tolerance`.
+    /// Default value is `1e-6`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0f64, "The tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Specify the loss function, e.g., `GBMLoss::L2`.
+    /// The resulting [`LinearLearner`] fits the negative gradient
+    /// of the loss by the ridge regression
+    /// and outputs a [`LinearRegressor`](crate::LinearRegressor).
+    pub fn loss<M>(self, loss: M) -> LinearLearnerBuilder<'a, M> {
+        LinearLearnerBuilder {
+            sample: self.sample,
+            lambda: self.lambda,
+            max_iter: self.max_iter,
+            tolerance: self.tolerance,
+            loss,
+        }
+    }
+
+
+    /// Build a [`LinearLearner`].
+    /// This method consumes `self`.
+    pub fn build(self) -> LinearLearner<L> {
+        let scaler = Standardizer::new(self.sample);
+        LinearLearner::from_components(
+            scaler, self.lambda, self.max_iter, self.tolerance, self.loss
+        )
+    }
+}
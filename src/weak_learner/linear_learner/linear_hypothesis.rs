@@ -0,0 +1,118 @@
+//! Defines the linear hypotheses.
+use serde::{Serialize, Deserialize};
+
+use crate::{Classifier, Regressor, Sample};
+
+
+/// A linear function `Σ_j weights[j] * x[features[j]] + intercept`.
+/// A missing value of `features[j]` is replaced by `fill[j]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct LinearFunction {
+    pub(super) features: Vec<String>,
+    pub(super) weights: Vec<f64>,
+    pub(super) fill: Vec<f64>,
+    pub(super) intercept: f64,
+}
+
+
+impl LinearFunction {
+    /// Returns the value of the function at the `row`-th example.
+    #[inline]
+    fn eval(&self, sample: &Sample, row: usize) -> f64 {
+        self.features.iter()
+            .zip(&self.weights)
+            .zip(&self.fill)
+            .map(|((name, w), fill)| {
+                let x = sample[name][row];
+                let x = if x.is_nan() { *fill } else { x };
+                w * x
+            })
+            .sum::<f64>()
+            + self.intercept
+    }
+
+
+    fn weights(&self) -> Vec<(&str, f64)> {
+        self.features.iter()
+            .map(|name| name.as_str())
+            .zip(self.weights.iter().copied())
+            .collect()
+    }
+}
+
+
+/// Linear classifier returned by
+/// [`LinearLearner`](crate::LinearLearner).
+/// The confidence for an example `x` is `tanh(f(x) / 2) = 2 p(x) - 1`,
+/// where `f(x)` is the linear function
+/// and `p(x) = 1 / (1 + exp(-f(x)))` is the probability of `+1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearClassifier(LinearFunction);
+
+
+impl From<LinearFunction> for LinearClassifier {
+    #[inline]
+    fn from(f: LinearFunction) -> Self {
+        Self(f)
+    }
+}
+
+
+impl LinearClassifier {
+    /// Returns the pairs of a feature name and its coefficient.
+    /// The features of a single value in the training sample
+    /// are not included.
+    pub fn weights(&self) -> Vec<(&str, f64)> {
+        self.0.weights()
+    }
+
+
+    /// Returns the intercept of the linear function.
+    pub fn intercept(&self) -> f64 {
+        self.0.intercept
+    }
+}
+
+
+impl Classifier for LinearClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        (0.5 * self.0.eval(sample, row)).tanh()
+    }
+}
+
+
+/// Linear regressor returned by
+/// [`LinearLearner`](crate::LinearLearner).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearRegressor(LinearFunction);
+
+
+impl From<LinearFunction> for LinearRegressor {
+    #[inline]
+    fn from(f: LinearFunction) -> Self {
+        Self(f)
+    }
+}
+
+
+impl LinearRegressor {
+    /// Returns the pairs of a feature name and its coefficient.
+    /// The features of a single value in the training sample
+    /// are not included.
+    pub fn weights(&self) -> Vec<(&str, f64)> {
+        self.0.weights()
+    }
+
+
+    /// Returns the intercept of the linear function.
+    pub fn intercept(&self) -> f64 {
+        self.0.intercept
+    }
+}
+
+
+impl Regressor for LinearRegressor {
+    fn predict(&self, sample: &Sample, row: usize) -> f64 {
+        self.0.eval(sample, row)
+    }
+}
@@ -0,0 +1,416 @@
+use rayon::prelude::*;
+
+use crate::{Sample, WeakLearner};
+use crate::common::loss_functions::LossFunction;
+use crate::common::utils::solve;
+use crate::weak_learner::common::standardizer::Standardizer;
+use super::linear_hypothesis::{
+    LinearFunction,
+    LinearClassifier,
+    LinearRegressor,
+};
+
+
+/// The regularization parameter of the intercept.
+/// This keeps the intercept of the logistic regression finite
+/// even if all the examples on the support of the distribution
+/// have the same label.
+const INTERCEPT_LAMBDA: f64 = 1e-8;
+
+
+/// The maximal number of halvings of a Newton step.
+const MAX_HALVINGS: usize = 30;
+
+
+/// The marker of the logistic loss.
+/// [`LinearLearner<Logistic>`](LinearLearner) and
+/// [`MlpLearner<Logistic>`](crate::MlpLearner) are the weak learners
+/// for classification.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Logistic;
+
+
+/// The linear weak learner.
+/// [`LinearLearner`] fits a linear function of the features
+/// with the L2 (ridge) regularization
+/// on the standardized features.
+///
+/// - `LinearLearner<Logistic>` minimizes the logistic loss
+///   weighted by the given distribution
+///   and outputs a [`LinearClassifier`].
+///   The loss is minimized by Newton's method
+///   with the backtracking line search.
+/// - `LinearLearner<L>` with a loss function `L`,
+///   e.g., [`GBMLoss`](crate::GBMLoss),
+///   fits the negative gradient of the loss
+///   at the given predictions by the ridge regression
+///   and outputs a [`LinearRegressor`].
+///   This is the weak learner for gradient boosting.
+///
+/// The features are standardized by the mean and the standard deviation
+/// over the sample given to [`LinearLearnerBuilder`],
+/// and the missing values are replaced by the means.
+/// The features that take a single value are not used.
+/// The intercept is not regularized.
+///
+/// [`LinearLearner`] is constructed
+/// by [`LinearLearnerBuilder`].
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// // Boosting linear classifiers.
+/// let linear = LinearLearnerBuilder::new(&sample)
+///     .lambda(0.01)
+///     .build();
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&linear);
+///
+/// // Gradient boosting with linear regressors.
+/// let linear = LinearLearnerBuilder::new(&sample)
+///     .loss(GBMLoss::L2)
+///     .build();
+/// let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+///     .max_iter(100);
+/// let f = booster.run(&linear);
+/// ```
+///
+/// [`LinearLearnerBuilder`]: crate::weak_learner::LinearLearnerBuilder
+pub struct LinearLearner<L> {
+    scaler: Standardizer,
+    lambda: f64,
+    max_iter: usize,
+    tolerance: f64,
+    loss: L,
+}
+
+
+/// Converts the coefficients `beta` on the standardized features,
+/// followed by the intercept,
+/// into a linear function of the original features.
+fn linear_function(scaler: &Standardizer, beta: Vec<f64>) -> LinearFunction {
+    let n_features = scaler.names.len();
+    let weights = beta[..n_features].iter()
+        .zip(&scaler.stds)
+        .map(|(b, std)| b / std)
+        .collect::<Vec<_>>();
+    let intercept = beta[n_features]
+        - weights.iter()
+            .zip(&scaler.means)
+            .map(|(w, mean)| w * mean)
+            .sum::<f64>();
+    LinearFunction {
+        features: scaler.names.clone(),
+        weights,
+        fill: scaler.means.clone(),
+        intercept,
+    }
+}
+
+
+impl<L> LinearLearner<L> {
+    /// Initialize [`LinearLearner`].
+    /// This method is called only via `LinearLearnerBuilder::build`.
+    #[inline]
+    pub(super) fn from_components(
+        scaler: Standardizer,
+        lambda: f64,
+        max_iter: usize,
+        tolerance: f64,
+        loss: L,
+    ) -> Self
+    {
+        Self { scaler, lambda, max_iter, tolerance, loss, }
+    }
+
+
+    /// Returns the regularization parameters of the coefficients,
+    /// followed by the one of the intercept.
+    fn penalties(&self) -> Vec<f64> {
+        let n_features = self.scaler.names.len();
+        let mut penalties = vec![self.lambda; n_features];
+        penalties.push(INTERCEPT_LAMBDA);
+        penalties
+    }
+
+
+    /// Returns the coefficients of the ridge regression
+    /// of `y` on `columns` weighted by `weights`,
+    /// followed by the intercept.
+    fn ridge(&self, columns: &[Vec<f64>], weights: &[f64], y: &[f64])
+        -> Vec<f64>
+    {
+        let mut a = gram(columns, weights);
+        a.iter_mut()
+            .zip(self.penalties())
+            .enumerate()
+            .for_each(|(j, (row, penalty))| { row[j] += penalty; });
+        let b = transpose_times(columns, weights, y);
+        solve(a, b)
+    }
+
+
+    fn info_with(&self, loss: String) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("Loss", loss),
+            ("# of features", format!("{}", self.scaler.names.len())),
+            ("Regularization param.", format!("{}", self.lambda)),
+            ("Max iterations", format!("{}", self.max_iter)),
+            ("Tolerance", format!("{}", self.tolerance)),
+        ]);
+        Some(info)
+    }
+}
+
+
+impl LinearLearner<Logistic> {
+    /// Returns the coefficients and the intercept
+    /// minimizing the logistic loss weighted by `dist`
+    /// plus the regularization term.
+    fn logistic_regression(
+        &self,
+        columns: &[Vec<f64>],
+        labels: &[f64],
+        dist: &[f64],
+    ) -> Vec<f64>
+    {
+        let penalties = self.penalties();
+        let objective = |beta: &[f64], scores: &[f64]| {
+            let loss = scores.iter()
+                .zip(labels)
+                .zip(dist)
+                .map(|((z, y), d)| d * logistic_loss(y * z))
+                .sum::<f64>();
+            let reg = beta.iter()
+                .zip(&penalties)
+                .map(|(b, p)| 0.5 * p * b * b)
+                .sum::<f64>();
+            loss + reg
+        };
+
+
+        let mut beta = vec![0f64; columns.len() + 1];
+        let mut scores = vec![0f64; labels.len()];
+        let mut value = objective(&beta[..], &scores[..]);
+        for _ in 0..self.max_iter {
+            // The first and the second derivatives of the loss
+            // with respect to the scores.
+            let (slopes, curvatures): (Vec<_>, Vec<_>) = scores.iter()
+                .zip(labels)
+                .zip(dist)
+                .map(|((z, y), d)| {
+                    let p = sigmoid(y * z);
+                    (-d * y * (1f64 - p), d * p * (1f64 - p))
+                })
+                .unzip();
+
+            let ones = vec![1f64; labels.len()];
+            let gradient = transpose_times(columns, &ones[..], &slopes[..])
+                .into_iter()
+                .zip(&beta)
+                .zip(&penalties)
+                .map(|((g, b), p)| -(g + p * b))
+                .collect::<Vec<_>>();
+            let mut hessian = gram(columns, &curvatures[..]);
+            hessian.iter_mut()
+                .zip(&penalties)
+                .enumerate()
+                .for_each(|(j, (row, p))| { row[j] += p; });
+            let step = solve(hessian, gradient);
+
+
+            // Halve the step until the objective decreases.
+            let mut size = 1f64;
+            let mut accepted = None;
+            for _ in 0..MAX_HALVINGS {
+                let next = beta.iter()
+                    .zip(&step)
+                    .map(|(b, s)| b + size * s)
+                    .collect::<Vec<_>>();
+                let next_scores = scores_of(columns, &next[..], labels.len());
+                let next_value = objective(&next[..], &next_scores[..]);
+                if next_value <= value {
+                    accepted = Some((next, next_scores, next_value));
+                    break;
+                }
+                size *= 0.5;
+            }
+            let Some((next, next_scores, next_value)) = accepted else {
+                break;
+            };
+            beta = next;
+            scores = next_scores;
+            value = next_value;
+
+
+            let max_step = step.iter()
+                .fold(0f64, |acc, s| acc.max(s.abs()));
+            if size * max_step < self.tolerance { break; }
+        }
+        beta
+    }
+}
+
+
+/// Returns the matrix `X^T W X`,
+/// where `X` is `columns` with the column of ones appended
+/// and `W` is the diagonal matrix of `weights`.
+fn gram(columns: &[Vec<f64>], weights: &[f64]) -> Vec<Vec<f64>> {
+    let ones = vec![1f64; weights.len()];
+    let columns = columns.iter()
+        .chain(std::iter::once(&ones))
+        .collect::<Vec<_>>();
+    columns.par_iter()
+        .map(|xj| {
+            columns.iter()
+                .map(|xk| {
+                    weights.iter()
+                        .zip(xj.iter().zip(xk.iter()))
+                        .map(|(w, (a, b))| w * a * b)
+                        .sum::<f64>()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+
+/// Returns the vector `X^T W y`,
+/// where `X` is `columns` with the column of ones appended
+/// and `W` is the diagonal matrix of `weights`.
+fn transpose_times(columns: &[Vec<f64>], weights: &[f64], y: &[f64])
+    -> Vec<f64>
+{
+    let mut product = columns.par_iter()
+        .map(|xj| {
+            weights.iter()
+                .zip(xj.iter().zip(y))
+                .map(|(w, (x, y))| w * x * y)
+                .sum::<f64>()
+        })
+        .collect::<Vec<_>>();
+    let sum = weights.iter()
+        .zip(y)
+        .map(|(w, y)| w * y)
+        .sum::<f64>();
+    product.push(sum);
+    product
+}
+
+
+/// Returns the scores `X beta` of the `n_sample` examples,
+/// where `X` is `columns` with the column of ones appended.
+fn scores_of(columns: &[Vec<f64>], beta: &[f64], n_sample: usize)
+    -> Vec<f64>
+{
+    let n_features = columns.len();
+    let mut scores = vec![beta[n_features]; n_sample];
+    columns.iter()
+        .zip(beta)
+        .for_each(|(xj, b)| {
+            scores.iter_mut()
+                .zip(xj)
+                .for_each(|(z, x)| { *z += b * x; });
+        });
+    scores
+}
+
+
+#[inline]
+fn sigmoid(x: f64) -> f64 {
+    1f64 / (1f64 + (-x).exp())
+}
+
+
+/// Returns `ln(1 + exp(-margin))` without the overflow.
+#[inline]
+fn logistic_loss(margin: f64) -> f64 {
+    if margin > 0f64 {
+        (-margin).exp().ln_1p()
+    } else {
+        -margin + margin.exp().ln_1p()
+    }
+}
+
+
+impl WeakLearner for LinearLearner<Logistic> {
+    type Hypothesis = LinearClassifier;
+
+
+    fn name(&self) -> &str {
+        "Linear Learner"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        self.info_with(String::from("Logistic"))
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let columns = self.scaler.columns(sample);
+        let labels = sample.target()
+            .iter()
+            .map(|&y| if y > 0f64 { 1f64 } else { -1f64 })
+            .collect::<Vec<_>>();
+        let beta = self.logistic_regression(&columns[..], &labels[..], dist);
+        LinearClassifier::from(linear_function(&self.scaler, beta))
+    }
+}
+
+
+impl<L> WeakLearner for LinearLearner<L>
+    where L: LossFunction,
+{
+    type Hypothesis = LinearRegressor;
+
+
+    fn name(&self) -> &str {
+        "Linear Learner"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        self.info_with(self.loss.name().to_string())
+    }
+
+
+    fn produce(&self, sample: &Sample, predictions: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_sample = sample.shape().0;
+        let weights = vec![1f64; n_sample];
+        self.produce_with_weights(sample, predictions, &weights[..])
+    }
+
+
+    /// Fits the negative gradient of the loss
+    /// by the ridge regression weighted by `weights`.
+    fn produce_with_weights(
+        &self,
+        sample: &Sample,
+        predictions: &[f64],
+        weights: &[f64],
+    ) -> Self::Hypothesis
+    {
+        assert_eq!(predictions.len(), weights.len());
+        let total = weights.iter().sum::<f64>();
+        assert!(total > 0f64, "The sum of the weights must be positive");
+        let weights = weights.iter()
+            .map(|w| w / total)
+            .collect::<Vec<_>>();
+
+        let columns = self.scaler.columns(sample);
+        let residuals = self.loss.gradient(predictions, sample.target())
+            .into_iter()
+            .map(|g| -g)
+            .collect::<Vec<_>>();
+        let beta = self.ridge(&columns[..], &weights[..], &residuals[..]);
+        LinearRegressor::from(linear_function(&self.scaler, beta))
+    }
+}
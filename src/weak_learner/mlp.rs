@@ -0,0 +1,11 @@
+/// Defines `MlpLearnerBuilder`.
+mod builder;
+/// Defines `MlpLearner`.
+mod mlp_algorithm;
+/// Defines `MlpClassifier` and `MlpRegressor`
+/// returned by `MlpLearner`.
+mod mlp_hypothesis;
+
+pub use builder::MlpLearnerBuilder;
+pub use mlp_algorithm::MlpLearner;
+pub use mlp_hypothesis::{MlpClassifier, MlpRegressor};
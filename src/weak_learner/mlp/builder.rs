@@ -0,0 +1,162 @@
+use crate::Sample;
+use crate::weak_learner::Logistic;
+use crate::weak_learner::common::standardizer::Standardizer;
+use super::mlp_algorithm::{MlpLearner, Schedule};
+
+
+/// The number of the hidden units set as default.
+pub const DEFAULT_HIDDEN_UNITS: usize = 16;
+/// The number of epochs set as default.
+pub const DEFAULT_EPOCHS: usize = 50;
+/// The learning rate set as default.
+pub const DEFAULT_LEARNING_RATE: f64 = 0.1;
+/// The mini-batch size set as default.
+pub const DEFAULT_BATCH_SIZE: usize = 32;
+/// The seed of the randomness set as default.
+pub const DEFAULT_SEED: u64 = 1234;
+
+
+/// A struct that builds [`MlpLearner`].
+/// `MlpLearnerBuilder` keeps parameters for constructing [`MlpLearner`].
+///
+/// By default, the resulting [`MlpLearner`] minimizes
+/// the logistic loss for classification.
+/// Specify a loss function by [`MlpLearnerBuilder::loss`]
+/// to get a [`MlpLearner`] for gradient boosting.
+///
+/// # Example
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let classification = MlpLearnerBuilder::new(&sample)
+///     .hidden_units(8)
+///     .epochs(20)
+///     .learning_rate(0.05)
+///     .build();
+///
+/// let regression = MlpLearnerBuilder::new(&sample)
+///     .hidden_units(8)
+///     .loss(GBMLoss::L2)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct MlpLearnerBuilder<'a, L> {
+    sample: &'a Sample,
+    hidden_units: usize,
+    epochs: usize,
+    learning_rate: f64,
+    batch_size: usize,
+    seed: u64,
+    loss: L,
+}
+
+
+impl<'a> MlpLearnerBuilder<'a, Logistic> {
+    /// Construct a new instance of [`MlpLearnerBuilder`].
+    /// By default, [`MlpLearnerBuilder`] sets the parameters as follows;
+    /// ```text
+    /// hidden_units: DEFAULT_HIDDEN_UNITS == 16,
+    /// epochs: DEFAULT_EPOCHS == 50,
+    /// learning_rate: DEFAULT_LEARNING_RATE == 0.1,
+    /// batch_size: DEFAULT_BATCH_SIZE == 32,
+    /// seed: DEFAULT_SEED == 1234,
+    /// loss: Logistic,
+    /// ```
+    pub fn new(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            hidden_units: DEFAULT_HIDDEN_UNITS,
+            epochs: DEFAULT_EPOCHS,
+            learning_rate: DEFAULT_LEARNING_RATE,
+            batch_size: DEFAULT_BATCH_SIZE,
+            seed: DEFAULT_SEED,
+            loss: Logistic,
+        }
+    }
+}
+
+
+impl<'a, L> MlpLearnerBuilder<'a, L> {
+    /// Set the number of the hidden units.
+    /// Default value is `16`.
+    pub fn hidden_units(mut self, hidden_units: usize) -> Self {
+        assert!(
+            hidden_units > 0,
+            "The number of the hidden units must be positive"
+        );
+        self.hidden_units = hidden_units;
+        self
+    }
+
+
+    /// Set the number of epochs,
+    /// i.e., the number of passes over the training examples.
+    /// Default value is `50`.
+    pub fn epochs(mut self, epochs: usize) -> Self {
+        assert!(epochs > 0, "The number of epochs must be positive");
+        self.epochs = epochs;
+        self
+    }
+
+
+    /// Set the learning rate of the stochastic gradient descent.
+    /// Default value is `0.1`.
+    pub fn learning_rate(mut self, learning_rate: f64) -> Self {
+        assert!(learning_rate > 0f64, "The learning rate must be positive");
+        self.learning_rate = learning_rate;
+        self
+    }
+
+
+    /// Set the mini-batch size.
+    /// Default value is `32`.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "The mini-batch size must be positive");
+        self.batch_size = batch_size;
+        self
+    }
+
+
+    /// Set the seed of the initial weights
+    /// and of the order of the examples.
+    /// Default value is `1234`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Specify the loss function, e.g., `GBMLoss::L2`.
+    /// The resulting [`MlpLearner`] fits the negative gradient
+    /// of the loss by the squared loss
+    /// and outputs a [`MlpRegressor`](crate::MlpRegressor).
+    pub fn loss<M>(self, loss: M) -> MlpLearnerBuilder<'a, M> {
+        MlpLearnerBuilder {
+            sample: self.sample,
+            hidden_units: self.hidden_units,
+            epochs: self.epochs,
+            learning_rate: self.learning_rate,
+            batch_size: self.batch_size,
+            seed: self.seed,
+            loss,
+        }
+    }
+
+
+    /// Build a [`MlpLearner`].
+    /// This method consumes `self`.
+    pub fn build(self) -> MlpLearner<L> {
+        let scaler = Standardizer::new(self.sample);
+        let schedule = Schedule {
+            epochs: self.epochs,
+            learning_rate: self.learning_rate,
+            batch_size: self.batch_size,
+            seed: self.seed,
+        };
+        MlpLearner::from_components(
+            scaler, self.hidden_units, schedule, self.loss
+        )
+    }
+}
@@ -0,0 +1,336 @@
+use rand::prelude::*;
+use rand_distr::Normal;
+
+use crate::{Sample, WeakLearner};
+use crate::common::loss_functions::LossFunction;
+use crate::weak_learner::Logistic;
+use crate::weak_learner::common::standardizer::Standardizer;
+use super::mlp_hypothesis::{
+    Network,
+    MlpClassifier,
+    MlpRegressor,
+};
+
+
+/// A tiny neural network weak learner.
+/// [`MlpLearner`] trains a network of one hidden layer
+/// of `tanh` units and a linear output unit
+/// by the mini-batch stochastic gradient descent.
+/// The loss of the `i`-th example is weighted by `n_sample * dist[i]`,
+/// so that the expected gradient of a mini-batch is
+/// the gradient of the loss weighted by the given distribution.
+///
+/// - `MlpLearner<Logistic>` minimizes the logistic loss
+///   and outputs a [`MlpClassifier`].
+/// - `MlpLearner<L>` with a loss function `L`,
+///   e.g., [`GBMLoss`](crate::GBMLoss),
+///   fits the negative gradient of the loss
+///   at the given predictions by the squared loss
+///   and outputs a [`MlpRegressor`].
+///   This is the weak learner for gradient boosting.
+///
+/// The initial weights, and the order of the examples in each epoch,
+/// are drawn from the seed given to [`MlpLearnerBuilder`],
+/// so that [`MlpLearner`] returns the same network
+/// for the same inputs.
+/// The features are standardized by the mean and the standard deviation
+/// over the sample given to [`MlpLearnerBuilder`],
+/// and the missing values are replaced by the means.
+/// The features that take a single value are not used.
+///
+/// See [`NeuralNetwork`](crate::NeuralNetwork)
+/// for the networks of arbitrary depth.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// // Boosting the networks.
+/// let mlp = MlpLearnerBuilder::new(&sample)
+///     .hidden_units(8)
+///     .epochs(20)
+///     .build();
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&mlp);
+///
+/// // Gradient boosting with the networks.
+/// let mlp = MlpLearnerBuilder::new(&sample)
+///     .loss(GBMLoss::L2)
+///     .build();
+/// let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+///     .max_iter(100);
+/// let f = booster.run(&mlp);
+/// ```
+///
+/// [`MlpLearnerBuilder`]: crate::weak_learner::MlpLearnerBuilder
+pub struct MlpLearner<L> {
+    scaler: Standardizer,
+    hidden_units: usize,
+    epochs: usize,
+    learning_rate: f64,
+    batch_size: usize,
+    seed: u64,
+    loss: L,
+}
+
+
+/// The hyperparameters of the stochastic gradient descent.
+pub(super) struct Schedule {
+    pub(super) epochs: usize,
+    pub(super) learning_rate: f64,
+    pub(super) batch_size: usize,
+    pub(super) seed: u64,
+}
+
+
+impl<L> MlpLearner<L> {
+    /// Initialize [`MlpLearner`].
+    /// This method is called only via `MlpLearnerBuilder::build`.
+    #[inline]
+    pub(super) fn from_components(
+        scaler: Standardizer,
+        hidden_units: usize,
+        schedule: Schedule,
+        loss: L,
+    ) -> Self
+    {
+        let Schedule { epochs, learning_rate, batch_size, seed } = schedule;
+        Self {
+            scaler,
+            hidden_units,
+            epochs,
+            learning_rate,
+            batch_size,
+            seed,
+            loss,
+        }
+    }
+
+
+    /// Returns the network trained by the stochastic gradient descent,
+    /// where `derivative(i, f)` is the derivative of the loss
+    /// of the `i`-th example at the output `f`.
+    fn train<D>(&self, sample: &Sample, weights: &[f64], derivative: D)
+        -> Network
+        where D: Fn(usize, f64) -> f64,
+    {
+        let n_sample = sample.shape().0;
+        let rows = rows_of(&self.scaler.columns(sample), n_sample);
+        let n_features = self.scaler.names.len();
+
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let std = 1f64 / (n_features.max(1) as f64).sqrt();
+        let normal = Normal::new(0f64, std).unwrap();
+        let mut hidden = (0..self.hidden_units)
+            .map(|_| {
+                (0..n_features)
+                    .map(|_| normal.sample(&mut rng))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut hidden_bias = vec![0f64; self.hidden_units];
+        let std = 1f64 / (self.hidden_units as f64).sqrt();
+        let normal = Normal::new(0f64, std).unwrap();
+        let mut output = (0..self.hidden_units)
+            .map(|_| normal.sample(&mut rng))
+            .collect::<Vec<_>>();
+        let mut output_bias = 0f64;
+
+
+        let mut order = (0..n_sample)
+            .filter(|&i| weights[i] > 0f64)
+            .collect::<Vec<_>>();
+        for _ in 0..self.epochs {
+            order.shuffle(&mut rng);
+            for batch in order.chunks(self.batch_size) {
+                let mut d_hidden = vec![vec![0f64; n_features]; hidden.len()];
+                let mut d_hidden_bias = vec![0f64; hidden.len()];
+                let mut d_output = vec![0f64; hidden.len()];
+                let mut d_output_bias = 0f64;
+                for &i in batch {
+                    let x = &rows[i];
+                    let h = hidden.iter()
+                        .zip(&hidden_bias)
+                        .map(|(w, b)| {
+                            let u = w.iter()
+                                .zip(x)
+                                .map(|(w, x)| w * x)
+                                .sum::<f64>();
+                            (u + b).tanh()
+                        })
+                        .collect::<Vec<_>>();
+                    let f = h.iter()
+                        .zip(&output)
+                        .map(|(h, v)| h * v)
+                        .sum::<f64>()
+                        + output_bias;
+
+                    // Back propagation
+                    let g = n_sample as f64 * weights[i] * derivative(i, f);
+                    d_output_bias += g;
+                    for k in 0..h.len() {
+                        d_output[k] += g * h[k];
+                        let dz = g * output[k] * (1f64 - h[k] * h[k]);
+                        d_hidden_bias[k] += dz;
+                        d_hidden[k].iter_mut()
+                            .zip(x)
+                            .for_each(|(d, x)| { *d += dz * x; });
+                    }
+                }
+
+                let rate = self.learning_rate / batch.len() as f64;
+                hidden.iter_mut()
+                    .zip(d_hidden)
+                    .for_each(|(w, d)| {
+                        w.iter_mut()
+                            .zip(d)
+                            .for_each(|(w, d)| { *w -= rate * d; });
+                    });
+                hidden_bias.iter_mut()
+                    .zip(d_hidden_bias)
+                    .for_each(|(b, d)| { *b -= rate * d; });
+                output.iter_mut()
+                    .zip(d_output)
+                    .for_each(|(v, d)| { *v -= rate * d; });
+                output_bias -= rate * d_output_bias;
+            }
+        }
+
+
+        // Rewrite the hidden layer on the standardized features
+        // as the one on the original features.
+        let hidden = hidden.into_iter()
+            .map(|w| {
+                w.into_iter()
+                    .zip(&self.scaler.stds)
+                    .map(|(w, std)| w / std)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let hidden_bias = hidden.iter()
+            .zip(hidden_bias)
+            .map(|(w, b)| {
+                b - w.iter()
+                    .zip(&self.scaler.means)
+                    .map(|(w, mean)| w * mean)
+                    .sum::<f64>()
+            })
+            .collect::<Vec<_>>();
+        Network {
+            features: self.scaler.names.clone(),
+            fill: self.scaler.means.clone(),
+            hidden,
+            hidden_bias,
+            output,
+            output_bias,
+        }
+    }
+
+
+    fn info_with(&self, loss: String) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("Loss", loss),
+            ("# of features", format!("{}", self.scaler.names.len())),
+            ("# of hidden units", format!("{}", self.hidden_units)),
+            ("# of epochs", format!("{}", self.epochs)),
+            ("Learning rate", format!("{}", self.learning_rate)),
+            ("Mini-batch size", format!("{}", self.batch_size)),
+            ("Seed", format!("{}", self.seed)),
+        ]);
+        Some(info)
+    }
+}
+
+
+impl WeakLearner for MlpLearner<Logistic> {
+    type Hypothesis = MlpClassifier;
+
+
+    fn name(&self) -> &str {
+        "MLP"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        self.info_with(String::from("Logistic"))
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let labels = sample.target()
+            .iter()
+            .map(|&y| if y > 0f64 { 1f64 } else { -1f64 })
+            .collect::<Vec<_>>();
+        // The derivative of `ln(1 + exp(-y f))` with respect to `f`.
+        let derivative = |i: usize, f: f64| {
+            let y = labels[i];
+            -y / (1f64 + (y * f).exp())
+        };
+        MlpClassifier::from(self.train(sample, dist, derivative))
+    }
+}
+
+
+impl<L> WeakLearner for MlpLearner<L>
+    where L: LossFunction,
+{
+    type Hypothesis = MlpRegressor;
+
+
+    fn name(&self) -> &str {
+        "MLP"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        self.info_with(self.loss.name().to_string())
+    }
+
+
+    fn produce(&self, sample: &Sample, predictions: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_sample = sample.shape().0;
+        let weights = vec![1f64; n_sample];
+        self.produce_with_weights(sample, predictions, &weights[..])
+    }
+
+
+    /// Fits the negative gradient of the loss
+    /// by the squared loss weighted by `weights`.
+    fn produce_with_weights(
+        &self,
+        sample: &Sample,
+        predictions: &[f64],
+        weights: &[f64],
+    ) -> Self::Hypothesis
+    {
+        assert_eq!(predictions.len(), weights.len());
+        let total = weights.iter().sum::<f64>();
+        assert!(total > 0f64, "The sum of the weights must be positive");
+        let weights = weights.iter()
+            .map(|w| w / total)
+            .collect::<Vec<_>>();
+
+        let residuals = self.loss.gradient(predictions, sample.target())
+            .into_iter()
+            .map(|g| -g)
+            .collect::<Vec<_>>();
+        // The derivative of `(f - r)^2 / 2` with respect to `f`.
+        let derivative = |i: usize, f: f64| f - residuals[i];
+        MlpRegressor::from(self.train(sample, &weights[..], derivative))
+    }
+}
+
+
+/// Returns the rows of the matrix whose columns are `columns`.
+fn rows_of(columns: &[Vec<f64>], n_sample: usize) -> Vec<Vec<f64>> {
+    (0..n_sample)
+        .map(|i| columns.iter().map(|column| column[i]).collect())
+        .collect()
+}
@@ -0,0 +1,106 @@
+//! Defines the hypotheses of the one-hidden-layer networks.
+use serde::{Serialize, Deserialize};
+
+use crate::{Classifier, Regressor, Sample};
+
+
+/// A network `f(x) = Σ_k output[k] * tanh(u_k(x)) + output_bias`
+/// of one hidden layer,
+/// where `u_k(x) = Σ_j hidden[k][j] * x[features[j]] + hidden_bias[k]`.
+/// A missing value of `features[j]` is replaced by `fill[j]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct Network {
+    pub(super) features: Vec<String>,
+    pub(super) fill: Vec<f64>,
+    pub(super) hidden: Vec<Vec<f64>>,
+    pub(super) hidden_bias: Vec<f64>,
+    pub(super) output: Vec<f64>,
+    pub(super) output_bias: f64,
+}
+
+
+impl Network {
+    /// Returns the output of the network at the `row`-th example.
+    #[inline]
+    fn eval(&self, sample: &Sample, row: usize) -> f64 {
+        let x = self.features.iter()
+            .zip(&self.fill)
+            .map(|(name, fill)| {
+                let x = sample[name][row];
+                if x.is_nan() { *fill } else { x }
+            })
+            .collect::<Vec<_>>();
+
+        self.hidden.iter()
+            .zip(&self.hidden_bias)
+            .zip(&self.output)
+            .map(|((w, b), v)| {
+                let u = w.iter()
+                    .zip(&x)
+                    .map(|(w, x)| w * x)
+                    .sum::<f64>();
+                v * (u + b).tanh()
+            })
+            .sum::<f64>()
+            + self.output_bias
+    }
+}
+
+
+/// Classifier returned by [`MlpLearner`](crate::MlpLearner).
+/// The confidence for an example `x` is `tanh(f(x) / 2) = 2 p(x) - 1`,
+/// where `f(x)` is the output of the network
+/// and `p(x) = 1 / (1 + exp(-f(x)))` is the probability of `+1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MlpClassifier(Network);
+
+
+impl From<Network> for MlpClassifier {
+    #[inline]
+    fn from(f: Network) -> Self {
+        Self(f)
+    }
+}
+
+
+impl MlpClassifier {
+    /// Returns the number of the hidden units.
+    pub fn hidden_units(&self) -> usize {
+        self.0.output.len()
+    }
+}
+
+
+impl Classifier for MlpClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        (0.5 * self.0.eval(sample, row)).tanh()
+    }
+}
+
+
+/// Regressor returned by [`MlpLearner`](crate::MlpLearner).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MlpRegressor(Network);
+
+
+impl From<Network> for MlpRegressor {
+    #[inline]
+    fn from(f: Network) -> Self {
+        Self(f)
+    }
+}
+
+
+impl MlpRegressor {
+    /// Returns the number of the hidden units.
+    pub fn hidden_units(&self) -> usize {
+        self.0.output.len()
+    }
+}
+
+
+impl Regressor for MlpRegressor {
+    fn predict(&self, sample: &Sample, row: usize) -> f64 {
+        self.0.eval(sample, row)
+    }
+}
@@ -6,5 +6,5 @@ mod nbayes_classifier;
 /// Defines probability density/mass functions.
 mod probability;
 
-pub use nbayes::GaussianNB;
+pub use nbayes::{GaussianNB, BernoulliNB};
 pub use nbayes_classifier::NBayesClassifier;
@@ -1,9 +1,12 @@
+use rayon::prelude::*;
+
 use crate::{Sample, WeakLearner};
 use crate::common::utils;
 
 
 use super::probability::{
     Gaussian,
+    Bernoulli,
 };
 
 
@@ -57,6 +60,134 @@ impl WeakLearner for GaussianNB {
         let density = Gaussian::new(means, vars);
 
 
+        NBayesClassifier::from_components(conditionals, density)
+    }
+}
+
+
+/// The smoothing parameter set as default.
+pub const DEFAULT_ALPHA: f64 = 1.0;
+
+
+/// A factory that produces a Bernoulli naive Bayes classifier
+/// for a given distribution over training examples.
+/// Each feature is binarized by the threshold `binarize`,
+/// i.e., `x > binarize` or not,
+/// and modeled by a Bernoulli distribution for each class.
+/// The struct name comes from scikit-learn.
+///
+/// The probabilities are estimated from the pseudo-counts
+/// `n_sample * dist[i]` of the examples
+/// with the additive (Laplace) smoothing `alpha`.
+/// The missing values are ignored.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let nbayes = BernoulliNB::init()
+///     .binarize(0.5)
+///     .alpha(1.0);
+///
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&nbayes);
+/// ```
+pub struct BernoulliNB {
+    binarize: f64,
+    alpha: f64,
+}
+
+
+impl BernoulliNB {
+    /// Initializes the BernoulliNB instance.
+    /// By default, the features are binarized by `0.0`
+    /// and the smoothing parameter is `1.0`.
+    pub fn init() -> Self {
+        Self { binarize: 0f64, alpha: DEFAULT_ALPHA }
+    }
+
+
+    /// Set the threshold that binarizes the features.
+    /// Default value is `0.0`.
+    pub fn binarize(mut self, threshold: f64) -> Self {
+        self.binarize = threshold;
+        self
+    }
+
+
+    /// Set the additive smoothing parameter.
+    /// Default value is `1.0`.
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        assert!(alpha > 0f64, "The smoothing parameter must be positive");
+        self.alpha = alpha;
+        self
+    }
+
+
+    /// Returns the Bernoulli distributions of the features
+    /// estimated from the examples with `selected(label) == true`.
+    fn estimate<F>(&self, sample: &Sample, dist: &[f64], selected: F)
+        -> Bernoulli
+        where F: Fn(f64) -> bool + Sync,
+    {
+        let n_sample = sample.shape().0 as f64;
+        let target = sample.target();
+        let probs = sample.features()
+            .par_iter()
+            .map(|feat| {
+                let mut ones = 0f64;
+                let mut total = 0f64;
+                for (i, (&y, &d)) in target.iter().zip(dist).enumerate() {
+                    let x = feat[i];
+                    if !selected(y) || x.is_nan() { continue; }
+                    total += d;
+                    if x > self.binarize { ones += d; }
+                }
+                (n_sample * ones + self.alpha)
+                    / (n_sample * total + 2f64 * self.alpha)
+            })
+            .collect::<Vec<_>>();
+        Bernoulli::new(probs, self.binarize)
+    }
+}
+
+
+impl WeakLearner for BernoulliNB {
+    type Hypothesis = NBayesClassifier<Bernoulli>;
+
+    fn name(&self) -> &str {
+        "Bernoulli Naive Bayes"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("Binarize threshold", format!("{}", self.binarize)),
+            ("Smoothing param.", format!("{}", self.alpha)),
+        ]);
+        Some(info)
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let uniq = sample.unique_target();
+        let target = sample.target();
+
+        let conditionals = uniq.into_iter()
+            .map(|y| {
+                let prior = utils::total_weight_for_label(y, target, dist)
+                    .clamp(0.0, 1.0);
+                let density = self.estimate(sample, dist, |label| label == y);
+                (y, prior, density)
+            })
+            .collect::<Vec<_>>();
+        let density = self.estimate(sample, dist, |_| true);
+
+
         NBayesClassifier::from_components(conditionals, density)
     }
 }
@@ -52,3 +52,41 @@ impl Probability for Gaussian {
         - 0.5 * (gauss_const + non_const)
     }
 }
+
+
+/// Bernoulli distributions over the binarized features.
+/// `probs[j]` is the probability that
+/// the `j`-th feature is greater than `threshold`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Bernoulli {
+    pub(super) probs: Vec<f64>,
+    pub(super) threshold: f64,
+}
+
+
+impl Bernoulli {
+    pub(super) fn new(probs: Vec<f64>, threshold: f64) -> Self {
+        Self { probs, threshold }
+    }
+}
+
+
+impl Probability for Bernoulli {
+    /// The missing values are ignored.
+    #[inline(always)]
+    fn log_probability(&self, sample: &Sample, row: usize) -> f64 {
+        self.probs.par_iter()
+            .zip(sample.features())
+            .map(|(&p, feat)| {
+                let x = feat[row];
+                if x.is_nan() {
+                    0f64
+                } else if x > self.threshold {
+                    p.ln()
+                } else {
+                    (1f64 - p).ln()
+                }
+            })
+            .sum::<f64>()
+    }
+}
@@ -1,4 +1,4 @@
-use rand::prelude::{Distribution, thread_rng};
+use rand::prelude::{Distribution, Rng};
 use rand_distr::Normal;
 use rayon::prelude::*;
 use crate::common::utils;
@@ -21,18 +21,21 @@ pub(crate) struct Layer {
 
 impl Layer {
     #[inline(always)]
-    pub(crate) fn new(nrow: usize, ncol: usize, activation: Activation)
-        -> Self
+    pub(crate) fn new<R: Rng>(
+        nrow: usize,
+        ncol: usize,
+        activation: Activation,
+        rng: &mut R,
+    ) -> Self
     {
-        let mut rng = thread_rng();
         let dist = Normal::<f64>::new(MEAN, DEVIATION).unwrap();
         let matrix = (0..nrow).map(|_|
-                dist.sample_iter(&mut rng)
+                dist.sample_iter(&mut *rng)
                     .take(ncol)
                     .collect::<Vec<_>>()
             )
             .collect::<Vec<_>>();
-        let bias = dist.sample_iter(&mut rng).take(nrow).collect();
+        let bias = dist.sample_iter(&mut *rng).take(nrow).collect();
 
         Self { nrow, ncol, matrix, bias, activation, }
     }
@@ -9,6 +9,7 @@ use super::{
     nn_loss::*,
     activation::*,
 };
+use rand::Rng;
 
 
 /// A wrapper for [`NNHypothesis`].
@@ -63,13 +64,15 @@ pub struct NNHypothesis {
 
 impl NNHypothesis {
     #[inline(always)]
-    pub(crate) fn new<S, T>(
+    pub(crate) fn new<S, T, R>(
         task: Task,
         dimensions: S,
         activations: T,
+        rng: &mut R,
     ) -> Self
         where S: AsRef<[usize]>,
               T: AsRef<[Activation]>,
+              R: Rng,
     {
         let dimensions = dimensions.as_ref();
         let activations = activations.as_ref();
@@ -81,7 +84,7 @@ impl NNHypothesis {
 
         let mut layers = Vec::with_capacity(n_layers);
         for (output_size, act) in iter.zip(activations) {
-            let layer = Layer::new(*output_size, *input_size, *act);
+            let layer = Layer::new(*output_size, *input_size, *act, rng);
             layers.push(layer);
             input_size = output_size;
         }
@@ -13,13 +13,14 @@ use super::{
     nn_hypothesis::*,
 };
 
-use rand;
+use rand::prelude::*;
 use rand::seq::index;
 
 const N_EPOCH: usize = 100;
 const N_ITER: usize = 200;
 const LEARNING_RATE: f64 = 1e-3;
 const MINIBATCH_SIZE: usize = 128;
+const SEED: u64 = 1234;
 
 
 type OutputDim = usize;
@@ -75,6 +76,7 @@ pub struct NeuralNetwork {
     loss_func: NNLoss,
     n_epoch: usize,
     n_iter_per_epoch: usize,
+    seed: u64,
 }
 
 
@@ -112,6 +114,7 @@ impl NeuralNetwork {
             n_epoch,
             n_iter_per_epoch,
             loss_func,
+            seed: SEED,
         }
     }
 
@@ -155,6 +158,16 @@ impl NeuralNetwork {
     }
 
 
+    /// Set the seed of the initial weights
+    /// and of the mini-batches.
+    /// Default value is `1234`.
+    #[inline(always)]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
     /// Set the task.
     /// Currently, Binary classification is available.
     #[inline(always)]
@@ -182,6 +195,7 @@ impl WeakLearner for NeuralNetwork {
             ("# of epochs", format!("{}", self.n_epoch)),
             ("# of layers", format!("{}", self.activations.len())),
             ("Loss", format!("{}", self.loss_func)),
+            ("Seed", format!("{}", self.seed)),
         ]);
         Some(info)
     }
@@ -193,13 +207,13 @@ impl WeakLearner for NeuralNetwork {
     {
         let rate = self.learning_rate / self.minibatch_size as f64;
         let n_samples = sample.shape().0;
+        let mut rng = StdRng::seed_from_u64(self.seed);
         let mut f = NNHypothesis::new(
-            self.task, &self.dimensions[..], &self.activations[..]
+            self.task, &self.dimensions[..], &self.activations[..], &mut rng
         );
         let weights = |i: usize| dist[i];
         for _ in 1..=self.n_epoch {
             // Randomly chosen indices over training sample
-            let mut rng = rand::thread_rng();
             let minibatch = index::sample_weighted(
                 &mut rng, n_samples, weights, self.minibatch_size,
             ).unwrap();
@@ -0,0 +1,10 @@
+/// Defines `ObliqueTreeBuilder`.
+mod builder;
+/// Defines `ObliqueTree`.
+mod oblique_tree_algorithm;
+/// Defines `ObliqueTreeClassifier` returned by `ObliqueTree`.
+mod oblique_tree_classifier;
+
+pub use builder::ObliqueTreeBuilder;
+pub use oblique_tree_algorithm::ObliqueTree;
+pub use oblique_tree_classifier::ObliqueTreeClassifier;
@@ -0,0 +1,97 @@
+use crate::{Sample, ObliqueTree};
+
+
+/// The maximal depth set as default.
+pub const DEFAULT_MAX_DEPTH: usize = 2;
+/// The maximal number of features in a splitting rule set as default.
+pub const DEFAULT_MAX_FEATURES: usize = 3;
+/// The ridge regularization parameter set as default.
+pub const DEFAULT_LAMBDA: f64 = 1.0;
+
+
+/// A struct that builds [`ObliqueTree`].
+/// `ObliqueTreeBuilder` keeps parameters for constructing [`ObliqueTree`].
+///
+/// # Example
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let weak_learner = ObliqueTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .max_features(3)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct ObliqueTreeBuilder<'a> {
+    sample: &'a Sample,
+    max_depth: usize,
+    max_features: usize,
+    lambda: f64,
+}
+
+
+impl<'a> ObliqueTreeBuilder<'a> {
+    /// Construct a new instance of [`ObliqueTreeBuilder`].
+    /// By default, [`ObliqueTreeBuilder`] sets the parameters as follows;
+    /// ```text
+    /// max_depth: DEFAULT_MAX_DEPTH == 2,
+    /// max_features: DEFAULT_MAX_FEATURES == 3,
+    /// lambda: DEFAULT_LAMBDA == 1.0,
+    /// ```
+    /// If the sample has fewer features than `DEFAULT_MAX_FEATURES`,
+    /// `max_features` is the number of features.
+    pub fn new(sample: &'a Sample) -> Self {
+        let n_features = sample.shape().1;
+        let max_features = DEFAULT_MAX_FEATURES.min(n_features).max(1);
+        Self {
+            sample,
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_features,
+            lambda: DEFAULT_LAMBDA,
+        }
+    }
+
+
+    /// Specify the maximal depth of the tree.
+    /// Default maximal depth is `2`.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        assert!(depth > 0, "Tree must have positive depth");
+        self.max_depth = depth;
+        self
+    }
+
+
+    /// Set the maximal number of features
+    /// in the linear combination of a splitting rule.
+    /// Default value is `3`.
+    pub fn max_features(mut self, max_features: usize) -> Self {
+        let n_features = self.sample.shape().1;
+        assert!(
+            0 < max_features && max_features <= n_features,
+            "The maximal number of features must be in [1, {n_features}]"
+        );
+        self.max_features = max_features;
+        self
+    }
+
+
+    /// Set the regularization parameter of the ridge regression
+    /// that fits the linear combination at each node.
+    /// Default value is `1.0`.
+    pub fn lambda(mut self, lambda: f64) -> Self {
+        assert!(lambda > 0f64, "The regularization parameter must be positive");
+        self.lambda = lambda;
+        self
+    }
+
+
+    /// Build an [`ObliqueTree`].
+    /// This method consumes `self`.
+    pub fn build(self) -> ObliqueTree {
+        ObliqueTree::from_components(
+            self.max_depth, self.max_features, self.lambda
+        )
+    }
+}
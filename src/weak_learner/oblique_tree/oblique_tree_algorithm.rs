@@ -0,0 +1,393 @@
+use rayon::prelude::*;
+
+use crate::{Sample, WeakLearner};
+use crate::common::utils::solve;
+use super::oblique_tree_classifier::{
+    ObliqueNode,
+    LinearSplit,
+    ObliqueTreeClassifier,
+};
+
+
+/// A split is made only if it decreases the impurity by more than this.
+const IMPURITY_TOLERANCE: f64 = 1e-12;
+
+
+/// The oblique decision tree algorithm.
+/// Given a set of training examples for binary classification
+/// and a distribution over the set,
+/// [`ObliqueTree`] outputs an [`ObliqueTreeClassifier`],
+/// a decision tree whose branch nodes split the examples
+/// by a threshold on a sparse linear combination of features.
+///
+/// At each node, [`ObliqueTree`] fits a weighted ridge regression
+/// of the labels on the standardized features,
+/// keeps the `max_features` largest coefficients,
+/// and refits the ridge regression on the kept features.
+/// The threshold on the resulting linear combination
+/// minimizes the weighted Gini impurity of the children.
+/// The axis-aligned splits on single features are also considered,
+/// and the split of the smaller impurity is chosen.
+/// Each node takes `O(n d^2 + d^3 + n d log n)` time
+/// for `n` examples on the node and `d` features.
+///
+/// On the datasets with correlated numerical features,
+/// a single oblique split can replace
+/// a staircase of many axis-aligned splits.
+///
+/// The features with missing values on a node
+/// are not used in the splitting rules of the node.
+///
+/// [`ObliqueTree`] is constructed
+/// by [`ObliqueTreeBuilder`](crate::weak_learner::ObliqueTreeBuilder).
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let tree = ObliqueTreeBuilder::new(&sample)
+///     .max_depth(2)
+///     .max_features(2)
+///     .build();
+///
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&tree);
+/// ```
+pub struct ObliqueTree {
+    max_depth: usize,
+    max_features: usize,
+    lambda: f64,
+}
+
+
+impl ObliqueTree {
+    /// Initialize [`ObliqueTree`].
+    /// This method is called only via `ObliqueTreeBuilder::build`.
+    #[inline]
+    pub(super) fn from_components(
+        max_depth: usize,
+        max_features: usize,
+        lambda: f64,
+    ) -> Self
+    {
+        Self { max_depth, max_features, lambda, }
+    }
+
+
+    /// Grows a tree of depth at most `depth`
+    /// on the examples in `indices`.
+    fn grow(
+        &self,
+        sample: &Sample,
+        dist: &[f64],
+        indices: Vec<usize>,
+        depth: usize,
+    ) -> ObliqueNode
+    {
+        let target = sample.target();
+        let total_weight = indices.iter()
+            .map(|&i| dist[i])
+            .sum::<f64>();
+        let positive_weight = indices.iter()
+            .filter(|&&i| target[i] > 0f64)
+            .map(|&i| dist[i])
+            .sum::<f64>();
+        let confidence = if total_weight > 0f64 {
+            2f64 * positive_weight / total_weight - 1f64
+        } else {
+            0f64
+        };
+        let leaf = ObliqueNode::Leaf { confidence };
+
+
+        let impurity = gini(total_weight, positive_weight);
+        if depth == 0 || indices.len() < 2 || impurity <= 0f64 {
+            return leaf;
+        }
+
+
+        let Some((split_impurity, rule)) = self.best_split(
+            sample, dist, &indices[..]
+        ) else {
+            return leaf;
+        };
+        if split_impurity >= impurity - IMPURITY_TOLERANCE {
+            return leaf;
+        }
+
+
+        let (lindices, rindices) = indices.into_iter()
+            .partition::<Vec<_>, _>(|&i| {
+                let value = rule.features.iter()
+                    .zip(&rule.weights)
+                    .map(|(name, w)| w * sample[name][i])
+                    .sum::<f64>();
+                value < rule.threshold
+            });
+        let left = self.grow(sample, dist, lindices, depth - 1);
+        let right = self.grow(sample, dist, rindices, depth - 1);
+        ObliqueNode::Branch {
+            rule,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+
+    /// Returns the splitting rule on the examples in `indices`
+    /// with the minimum weighted Gini impurity and the impurity.
+    /// Returns `None` if no feature separates the examples.
+    fn best_split(&self, sample: &Sample, dist: &[f64], indices: &[usize])
+        -> Option<(f64, LinearSplit)>
+    {
+        let target = sample.target();
+        let total_weight = indices.iter()
+            .map(|&i| dist[i])
+            .sum::<f64>();
+        if total_weight <= 0f64 { return None; }
+        let weights = indices.iter()
+            .map(|&i| dist[i] / total_weight)
+            .collect::<Vec<_>>();
+
+
+        // The standardized values of the features
+        // that take at least two values and no missing value.
+        let columns = sample.features()
+            .par_iter()
+            .filter_map(|feature| {
+                let values = indices.iter()
+                    .map(|&i| feature[i])
+                    .collect::<Vec<_>>();
+                if values.iter().any(|v| v.is_nan()) { return None; }
+                let mean = values.iter()
+                    .zip(&weights)
+                    .map(|(v, w)| v * w)
+                    .sum::<f64>();
+                let var = values.iter()
+                    .zip(&weights)
+                    .map(|(v, w)| w * (v - mean).powi(2))
+                    .sum::<f64>();
+                if var <= 0f64 { return None; }
+                let std = var.sqrt();
+                let z = values.into_iter()
+                    .map(|v| (v - mean) / std)
+                    .collect::<Vec<_>>();
+                Some(Column { name: feature.name(), std, z })
+            })
+            .collect::<Vec<_>>();
+        if columns.is_empty() { return None; }
+
+
+        // The axis-aligned splits.
+        let axis = columns.par_iter()
+            .filter_map(|column| {
+                let feature = &sample[column.name];
+                let values = indices.iter()
+                    .map(|&i| feature[i])
+                    .collect::<Vec<_>>();
+                let (impurity, threshold) = best_threshold(
+                    &values[..], indices, target, dist
+                )?;
+                let rule = LinearSplit {
+                    features: vec![column.name.to_string()],
+                    weights: vec![1f64],
+                    threshold,
+                };
+                Some((impurity, rule))
+            })
+            .min_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        if columns.len() < 2 || self.max_features < 2 {
+            return axis;
+        }
+
+
+        // The oblique split.
+        let mean = indices.iter()
+            .zip(&weights)
+            .map(|(&i, w)| w * target[i])
+            .sum::<f64>();
+        let y = indices.iter()
+            .map(|&i| target[i] - mean)
+            .collect::<Vec<_>>();
+        let all = (0..columns.len()).collect::<Vec<_>>();
+        let coef = ridge(&columns, &all[..], &weights[..], &y[..], self.lambda);
+
+        let mut kept = all;
+        kept.sort_by(|&j, &k| coef[k].abs().total_cmp(&coef[j].abs()));
+        kept.truncate(self.max_features);
+        kept.sort();
+        let coef = ridge(&columns, &kept[..], &weights[..], &y[..], self.lambda);
+
+
+        // Express the combination on the original scale.
+        let features = kept.iter()
+            .map(|&j| columns[j].name.to_string())
+            .collect::<Vec<_>>();
+        let combination = kept.iter()
+            .zip(&coef)
+            .map(|(&j, c)| c / columns[j].std)
+            .collect::<Vec<_>>();
+        let values = indices.iter()
+            .map(|&i| {
+                features.iter()
+                    .zip(&combination)
+                    .map(|(name, w)| w * sample[name][i])
+                    .sum::<f64>()
+            })
+            .collect::<Vec<_>>();
+        let oblique = best_threshold(&values[..], indices, target, dist)
+            .map(|(impurity, threshold)| {
+                let rule = LinearSplit {
+                    features, weights: combination, threshold,
+                };
+                (impurity, rule)
+            });
+
+
+        // Prefer the axis-aligned split on ties.
+        match (axis, oblique) {
+            (Some(axis), Some(oblique)) => {
+                if oblique.0 < axis.0 { Some(oblique) } else { Some(axis) }
+            },
+            (axis, oblique) => axis.or(oblique),
+        }
+    }
+}
+
+
+/// The standardized values of a feature on a node.
+struct Column<'a> {
+    name: &'a str,
+    std: f64,
+    z: Vec<f64>,
+}
+
+
+/// Returns the coefficients of the weighted ridge regression
+/// of `y` on the columns in `kept`.
+fn ridge(
+    columns: &[Column<'_>],
+    kept: &[usize],
+    weights: &[f64],
+    y: &[f64],
+    lambda: f64,
+) -> Vec<f64>
+{
+    let a = kept.iter()
+        .map(|&j| {
+            kept.iter()
+                .map(|&k| {
+                    let zj = &columns[j].z;
+                    let zk = &columns[k].z;
+                    let dot = weights.iter()
+                        .zip(zj.iter().zip(zk))
+                        .map(|(w, (a, b))| w * a * b)
+                        .sum::<f64>();
+                    if j == k { dot + lambda } else { dot }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let b = kept.iter()
+        .map(|&j| {
+            weights.iter()
+                .zip(columns[j].z.iter().zip(y))
+                .map(|(w, (z, y))| w * z * y)
+                .sum::<f64>()
+        })
+        .collect::<Vec<_>>();
+    solve(a, b)
+}
+
+
+/// Returns the weighted Gini impurity `2 p (w - p) / w`
+/// of a node of weight `w` whose positive examples have weight `p`.
+#[inline]
+fn gini(weight: f64, positive_weight: f64) -> f64 {
+    if weight <= 0f64 { return 0f64; }
+    2f64 * positive_weight * (weight - positive_weight) / weight
+}
+
+
+/// Returns the threshold on `values` with the minimum
+/// weighted Gini impurity of the children and the impurity,
+/// where `values[k]` is the value of the `indices[k]`-th example.
+/// The threshold is the midpoint of two consecutive values.
+/// Returns `None` if all the values are the same.
+fn best_threshold(
+    values: &[f64],
+    indices: &[usize],
+    target: &[f64],
+    dist: &[f64],
+) -> Option<(f64, f64)>
+{
+    let mut items = values.iter()
+        .zip(indices)
+        .map(|(&v, &i)| (v, target[i] > 0f64, dist[i]))
+        .collect::<Vec<_>>();
+    items.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let total_weight = items.iter().map(|item| item.2).sum::<f64>();
+    let positive_weight = items.iter()
+        .filter(|item| item.1)
+        .map(|item| item.2)
+        .sum::<f64>();
+
+
+    let mut best = None;
+    let mut left_weight = 0f64;
+    let mut left_positive = 0f64;
+    for (k, &(value, positive, weight)) in items.iter().enumerate() {
+        left_weight += weight;
+        if positive { left_positive += weight; }
+
+        let Some(&(next, _, _)) = items.get(k + 1) else { break; };
+        if value >= next { continue; }
+
+        let impurity = gini(left_weight, left_positive)
+            + gini(
+                total_weight - left_weight,
+                positive_weight - left_positive,
+            );
+        let threshold = value / 2f64 + next / 2f64;
+        if best.is_none_or(|(best_impurity, _)| impurity < best_impurity) {
+            best = Some((impurity, threshold));
+        }
+    }
+    best
+}
+
+
+impl WeakLearner for ObliqueTree {
+    type Hypothesis = ObliqueTreeClassifier;
+
+
+    fn name(&self) -> &str {
+        "Oblique Decision Tree"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("Max depth", format!("{}", self.max_depth)),
+            ("Max features per split", format!("{}", self.max_features)),
+            ("Ridge param.", format!("{}", self.lambda)),
+        ]);
+        Some(info)
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_sample = sample.shape().0;
+        let indices = (0..n_sample).filter(|&i| dist[i] > 0f64)
+            .collect::<Vec<usize>>();
+        assert_ne!(indices.len(), 0);
+
+        let root = self.grow(sample, dist, indices, self.max_depth);
+        ObliqueTreeClassifier::from(root)
+    }
+}
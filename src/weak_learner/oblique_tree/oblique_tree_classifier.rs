@@ -0,0 +1,125 @@
+//! Defines the oblique decision tree classifier.
+use serde::{Serialize, Deserialize};
+
+use crate::{Classifier, Sample};
+
+use std::collections::BTreeSet;
+
+
+/// A splitting rule on a linear combination of features.
+/// An example `x` goes to the left child
+/// if `Σ_j weights[j] * x[features[j]] < threshold`,
+/// and to the right child otherwise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct LinearSplit {
+    pub(super) features: Vec<String>,
+    pub(super) weights: Vec<f64>,
+    pub(super) threshold: f64,
+}
+
+
+impl LinearSplit {
+    /// Returns `true` if the `row`-th example of `sample`
+    /// goes to the left child.
+    #[inline]
+    fn goes_left(&self, sample: &Sample, row: usize) -> bool {
+        let value = self.features.iter()
+            .zip(&self.weights)
+            .map(|(name, w)| w * sample[name][row])
+            .sum::<f64>();
+        value < self.threshold
+    }
+}
+
+
+/// A node of [`ObliqueTreeClassifier`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) enum ObliqueNode {
+    /// A node that splits the examples by `rule`.
+    Branch {
+        rule: LinearSplit,
+        left: Box<ObliqueNode>,
+        right: Box<ObliqueNode>,
+    },
+    /// A node that predicts `confidence` in `[-1, 1]`.
+    Leaf {
+        confidence: f64,
+    },
+}
+
+
+impl ObliqueNode {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        match self {
+            Self::Branch { rule, left, right } => {
+                if rule.goes_left(sample, row) {
+                    left.confidence(sample, row)
+                } else {
+                    right.confidence(sample, row)
+                }
+            },
+            Self::Leaf { confidence } => *confidence,
+        }
+    }
+
+
+    fn n_leaves(&self) -> usize {
+        match self {
+            Self::Branch { left, right, .. } => {
+                left.n_leaves() + right.n_leaves()
+            },
+            Self::Leaf { .. } => 1,
+        }
+    }
+
+
+    fn used_features<'a>(&'a self, names: &mut BTreeSet<&'a str>) {
+        if let Self::Branch { rule, left, right } = self {
+            names.extend(rule.features.iter().map(|name| name.as_str()));
+            left.used_features(names);
+            right.used_features(names);
+        }
+    }
+}
+
+
+/// Oblique decision tree classifier.
+/// Each branch node splits the examples
+/// by a threshold on a sparse linear combination of features.
+/// This struct is the output of [`ObliqueTree`](crate::ObliqueTree).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObliqueTreeClassifier {
+    root: ObliqueNode,
+}
+
+
+impl From<ObliqueNode> for ObliqueTreeClassifier {
+    #[inline]
+    fn from(root: ObliqueNode) -> Self {
+        Self { root }
+    }
+}
+
+
+impl ObliqueTreeClassifier {
+    /// Returns the number of leaves of the tree.
+    pub fn n_leaves(&self) -> usize {
+        self.root.n_leaves()
+    }
+
+
+    /// Returns the names of the features used in the splitting rules,
+    /// sorted in the lexicographic order.
+    pub fn used_features(&self) -> Vec<&str> {
+        let mut names = BTreeSet::new();
+        self.root.used_features(&mut names);
+        names.into_iter().collect()
+    }
+}
+
+
+impl Classifier for ObliqueTreeClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        self.root.confidence(sample, row)
+    }
+}
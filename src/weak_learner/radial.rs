@@ -0,0 +1,7 @@
+/// Defines `RadialLearner`.
+mod radial_algorithm;
+/// Defines `RadialClassifier` returned by `RadialLearner`.
+mod radial_classifier;
+
+pub use radial_algorithm::RadialLearner;
+pub use radial_classifier::RadialClassifier;
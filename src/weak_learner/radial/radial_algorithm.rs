@@ -0,0 +1,190 @@
+use rayon::prelude::*;
+
+use crate::{Sample, WeakLearner};
+use crate::weak_learner::common::standardizer::Standardizer;
+use super::radial_classifier::RadialClassifier;
+
+
+/// A weak learner that returns the radial threshold function,
+/// a ball centered at a training example,
+/// with the maximum edge `Σ_i d_i y_i h(x_i)`.
+///
+/// For each candidate center,
+/// [`RadialLearner`] sorts the examples by the distance from it
+/// and scans the radii in the middle of the consecutive distances.
+/// Thus, each call of [`WeakLearner::produce`]
+/// takes `O(m n (d + log n))` time
+/// for `m` candidate centers, `n` examples, and `d` features.
+/// The candidate centers are scanned in parallel.
+/// By default, all the examples are candidates.
+/// Use [`RadialLearner::max_prototypes`]
+/// to restrict them to the examples of the largest weights.
+///
+/// The distance is the Euclidean one on the standardized features
+/// by the mean and the standard deviation over the sample
+/// given to [`RadialLearner::init`],
+/// and the missing values are replaced by the means.
+/// The features that take a single value are not used.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let radial = RadialLearner::init(&sample)
+///     .max_prototypes(100);
+///
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&radial);
+/// ```
+pub struct RadialLearner {
+    scaler: Standardizer,
+    max_prototypes: Option<usize>,
+}
+
+
+impl RadialLearner {
+    /// Construct a new instance of `RadialLearner`.
+    ///
+    /// Time complexity: `O(n d)`.
+    pub fn init(sample: &Sample) -> Self {
+        let scaler = Standardizer::new(sample);
+        Self { scaler, max_prototypes: None, }
+    }
+
+
+    /// Restrict the candidate centers
+    /// to the `max_prototypes` examples of the largest weights.
+    /// By default, all the examples are candidates.
+    pub fn max_prototypes(mut self, max_prototypes: usize) -> Self {
+        assert!(
+            max_prototypes > 0,
+            "The number of the prototypes must be positive"
+        );
+        self.max_prototypes = Some(max_prototypes);
+        self
+    }
+
+
+    /// Returns the candidate centers.
+    /// Ties of the weights are broken by the indices.
+    fn prototypes(&self, dist: &[f64]) -> Vec<usize> {
+        let mut indices = (0..dist.len()).collect::<Vec<_>>();
+        if let Some(m) = self.max_prototypes {
+            indices.sort_by(|&i, &j| dist[j].total_cmp(&dist[i]));
+            indices.truncate(m);
+        }
+        indices
+    }
+}
+
+
+/// Returns the best ball centered at `center`
+/// as the triplet `(edge, radius, sign)`.
+fn best_ball_at(
+    center: &[f64],
+    points: &[Vec<f64>],
+    target: &[f64],
+    dist: &[f64],
+) -> (f64, f64, f64)
+{
+    let mut distances = points.iter()
+        .enumerate()
+        .map(|(i, point)| {
+            let distance = point.iter()
+                .zip(center)
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            (i, distance)
+        })
+        .collect::<Vec<_>>();
+    distances.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    let total = target.iter()
+        .zip(dist)
+        .map(|(y, d)| d * y)
+        .sum::<f64>();
+
+    // The ball containing all the examples.
+    let mut best_edge = total;
+    let mut best_radius = distances.last().map_or(0f64, |&(_, r)| r);
+
+    // `inside` is the sum of `d_i y_i` over the examples in the ball.
+    let mut inside = 0f64;
+    for (k, &(i, distance)) in distances.iter().enumerate() {
+        inside += dist[i] * target[i];
+
+        let Some(&(_, next)) = distances.get(k + 1) else { break; };
+        if distance >= next { continue; }
+
+        let edge = 2f64 * inside - total;
+        if edge.abs() > best_edge.abs() {
+            best_edge = edge;
+            best_radius = distance / 2f64 + next / 2f64;
+        }
+    }
+
+    let sign = if best_edge >= 0f64 { 1f64 } else { -1f64 };
+    (best_edge.abs(), best_radius, sign)
+}
+
+
+impl WeakLearner for RadialLearner {
+    type Hypothesis = RadialClassifier;
+
+
+    fn name(&self) -> &str {
+        "Radial Threshold"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let prototypes = self.max_prototypes
+            .map_or(String::from("All"), |m| format!("{m}"));
+        let info = Vec::from([
+            ("# of features", format!("{}", self.scaler.names.len())),
+            ("Max prototypes", prototypes),
+        ]);
+        Some(info)
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_sample = sample.shape().0;
+        assert_eq!(
+            dist.len(), n_sample,
+            "The length of the distribution must be the number of examples"
+        );
+
+        let columns = self.scaler.columns(sample);
+        let points = (0..n_sample)
+            .map(|i| columns.iter().map(|column| column[i]).collect())
+            .collect::<Vec<Vec<f64>>>();
+        let target = sample.target();
+
+        let prototypes = self.prototypes(dist);
+        let balls = prototypes.par_iter()
+            .map(|&c| best_ball_at(&points[c], &points[..], target, dist))
+            .collect::<Vec<_>>();
+
+        // Ties are broken by the position of the candidate.
+        let mut best = 0;
+        for (k, ball) in balls.iter().enumerate().skip(1) {
+            if ball.0 > balls[best].0 { best = k; }
+        }
+
+        let (_, radius, sign) = balls[best];
+        RadialClassifier {
+            features: self.scaler.names.clone(),
+            means: self.scaler.means.clone(),
+            stds: self.scaler.stds.clone(),
+            center: points[prototypes[best]].clone(),
+            radius,
+            sign,
+        }
+    }
+}
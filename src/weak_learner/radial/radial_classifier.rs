@@ -0,0 +1,80 @@
+//! Defines the radial threshold classifier.
+use serde::{Serialize, Deserialize};
+
+use crate::{Classifier, Sample};
+
+
+/// A radial threshold function.
+/// `RadialClassifier` predicts `sign` for the examples
+/// within the distance `radius` from `center`,
+/// and `-sign` otherwise.
+/// The distance is the Euclidean one on the standardized features,
+/// and the missing values are replaced by the means.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RadialClassifier {
+    pub(super) features: Vec<String>,
+    pub(super) means: Vec<f64>,
+    pub(super) stds: Vec<f64>,
+    // The center on the standardized features.
+    pub(super) center: Vec<f64>,
+    pub(super) radius: f64,
+    pub(super) sign: f64,
+}
+
+
+impl RadialClassifier {
+    /// Returns the pairs of a feature name and
+    /// the value of the center on the original feature.
+    ///
+    /// Time complexity: `O(d)`.
+    pub fn center(&self) -> Vec<(&str, f64)> {
+        self.features.iter()
+            .zip(&self.center)
+            .zip(self.means.iter().zip(&self.stds))
+            .map(|((name, c), (mean, std))| (name.as_str(), c * std + mean))
+            .collect()
+    }
+
+
+    /// Returns the radius on the standardized features.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+
+    /// Returns the prediction for the examples inside the ball.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn sign(&self) -> f64 {
+        self.sign
+    }
+
+
+    /// Returns the distance from the center
+    /// to the `row`-th example of `sample`.
+    pub fn distance(&self, sample: &Sample, row: usize) -> f64 {
+        self.features.iter()
+            .zip(&self.center)
+            .zip(self.means.iter().zip(&self.stds))
+            .map(|((name, c), (mean, std))| {
+                let x = sample[name][row];
+                let x = if x.is_nan() { 0f64 } else { (x - mean) / std };
+                (x - c).powi(2)
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+
+impl Classifier for RadialClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        if self.distance(sample, row) <= self.radius {
+            self.sign
+        } else {
+            -self.sign
+        }
+    }
+}
@@ -3,10 +3,13 @@ use std::ops::Range;
 use std::cmp::Ordering;
 
 
+use crate::weak_learner::common::{
+    bin_cache::Positions,
+    device::SlotSum,
+};
 use crate::sample::{
     Feature,
     feature_struct::{
-        DenseFeature,
         SparseFeature,
     },
 };
@@ -20,10 +23,12 @@ const NUM_TOLERANCE: f64 = 1e-9;
 
 type Gradient = f64;
 type Hessian  = f64;
+/// The gradient and the Hessian over the bins.
+type Pack = Vec<(Bin, Gradient, Hessian)>;
 
 
 /// Binning: A feature processing.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Bin(pub Range<f64>);
 
 impl Bin {
@@ -57,8 +62,12 @@ impl Bins {
     #[inline(always)]
     pub fn cut(feature: &Feature, n_bin: usize) -> Self
     {
+        // A feature with no value other than the missing ones
+        // has a single bin.
+        let n_bin = n_bin.max(1);
         let mut bins = match feature {
-            Feature::Dense(feat) => Self::cut_dense(feat, n_bin),
+            Feature::Dense(feat) => Self::cut_dense(&feat.sample, n_bin),
+            Feature::Mapped(feat) => Self::cut_dense(feat.values(), n_bin),
             Feature::Sparse(feat) => Self::cut_sparse(feat, n_bin),
         };
 
@@ -71,37 +80,56 @@ impl Bins {
     }
 
 
-    // /// Cut the given `Feature` into `n_bins` bins.
-    // /// This method returns a vector of `Bin`,
-    // /// where each `Bin` has almost the same elements.
-    // pub fn qcut(feature: &Feature, n_bin: usize)
-    //     -> Vec<Self>
-    // {
-    //     let items = value_counts(feature);
-
-    //     if items.is_empty() {
-    //         return Vec::with_capacity(0);
-    //     }
-
-    //     let n_feature = feature.len();
-    //     let item_per_bin = n_feature / n_bin;
-
-    //     let mut iter = items.into_iter()
-    //         .peekable();
+    /// Cut the given `Feature` into at most `n_bin` bins
+    /// so that each bin has almost the same number of examples.
+    /// Since the examples with the same value fall into the same bin,
+    /// the number of bins may be smaller than `n_bin`.
+    /// The missing values are ignored.
+    pub fn qcut(feature: &Feature, n_bin: usize) -> Self {
+        assert!(n_bin > 0, "The number of bins must be positive");
+        let mut values = match feature {
+            Feature::Dense(feat) => feat.sample[..].to_vec(),
+            Feature::Mapped(feat) => feat.values().to_vec(),
+            Feature::Sparse(feat) => {
+                let mut values = feat.sample[..].iter()
+                    .map(|(_, v)| *v)
+                    .collect::<Vec<_>>();
+                values.resize(values.len() + feat.zero_counts(), 0f64);
+                values
+            },
+        };
+        values.retain(|v| !v.is_nan());
+        values.sort_by(|a, b| a.total_cmp(b));
 
-    //     let mut bins: Vec<Self> = Vec::with_capacity(n_bin);
 
+        let n_sample = values.len();
+        let mut bins = Vec::with_capacity(n_bin);
+        let mut left = f64::MIN;
+        for k in 1..n_bin {
+            // Move the cut point to the next distinct value.
+            let mut pos = k * n_sample / n_bin;
+            if pos == 0 { continue; }
+            while pos < n_sample && values[pos - 1] == values[pos] {
+                pos += 1;
+            }
+            if pos >= n_sample { break; }
+
+            let right = values[pos - 1] / 2f64 + values[pos] / 2f64;
+            if right <= left { continue; }
+            bins.push(Bin::new(left..right));
+            left = right;
+        }
+        bins.push(Bin::new(left..f64::MAX));
 
-    //     todo!()
-    // }
+        Self(bins)
+    }
 
 
-    fn cut_dense(feature: &DenseFeature, n_bin: usize) -> Self
+    fn cut_dense(values: &[f64], n_bin: usize) -> Self
     {
         let mut min = f64::MAX;
         let mut max = f64::MIN;
-        feature.sample[..]
-            .iter()
+        values.iter()
             .copied()
             .for_each(|val| {
                 min = min.min(val);
@@ -109,6 +137,13 @@ impl Bins {
             });
 
 
+        // All the values are missing.
+        if min > max {
+            min = 0.0;
+            max = 0.0;
+        }
+
+
         // If the minimum value equals to the maximum one,
         // slightly perturb them.
         if min == max {
@@ -163,6 +198,13 @@ impl Bins {
         }
 
 
+        // All the values are missing.
+        if min > max {
+            min = 0.0;
+            max = 0.0;
+        }
+
+
         // If the minimum value equals to the maximum one,
         // slightly perturb them.
         if min == max {
@@ -194,37 +236,112 @@ impl Bins {
     }
 
 
+    /// Returns the index of the bin that contains `x`.
+    #[inline(always)]
+    pub(crate) fn position(&self, x: f64) -> usize {
+        self.0.binary_search_by(|range| {
+                if range.contains(&x) {
+                    return Ordering::Equal;
+                }
+                range.0.start.partial_cmp(&x).unwrap()
+            })
+            .unwrap()
+    }
+
+
+    /// Returns the index of the bin that contains the `i`-th example,
+    /// or `None` if the value is missing.
+    /// The cached `positions` of the examples are used if given.
+    #[inline(always)]
+    fn locate(&self, feat: &Feature, positions: Option<Positions<'_>>, i: usize)
+        -> Option<usize>
+    {
+        match positions {
+            Some(positions) => positions.get(i),
+            None => {
+                let xi = feat[i];
+                if xi.is_nan() { None } else { Some(self.position(xi)) }
+            },
+        }
+    }
+
+
+    /// Returns the gradient and the Hessian over the bins
+    /// and the ones of the examples with missing values.
     pub(crate) fn pack(
         &self,
         indices: &[usize],
         feat: &Feature,
+        positions: Option<Positions<'_>>,
         gradient: &[Gradient],
         hessian: &[Hessian],
-    ) -> Vec<(Bin, Gradient, Hessian)>
+    ) -> (Pack, (Gradient, Hessian))
     {
         let n_bins = self.0.len();
         let mut grad_pack = vec![0f64; n_bins];
         let mut hess_pack = vec![0f64; n_bins];
+        let mut missing = (0f64, 0f64);
 
         for &i in indices {
-            let xi = feat[i];
-
-
-            let pos = self.0.binary_search_by(|range| {
-                    if range.contains(&xi) {
-                        return Ordering::Equal;
-                    }
-                    range.0.start.partial_cmp(&xi).unwrap()
-                })
-                .unwrap();
+            let Some(pos) = self.locate(feat, positions, i) else {
+                missing.0 += gradient[i];
+                missing.1 += hessian[i];
+                continue;
+            };
             grad_pack[pos] += gradient[i];
             hess_pack[pos] += hessian[i];
         }
-        self.remove_zero_weight_pack_and_normalize(grad_pack, hess_pack)
+        let pack = self.remove_zero_weight_pack_and_normalize(
+            grad_pack, hess_pack
+        );
+        (pack, missing)
     }
 
 
 
+    /// Returns the histogram of the gradient and the Hessian
+    /// over the bins for the examples in `indices`.
+    /// The last entry of the histogram is for the missing values.
+    pub(crate) fn histogram(
+        &self,
+        indices: &[usize],
+        feat: &Feature,
+        positions: Option<Positions<'_>>,
+        gradient: &[Gradient],
+        hessian: &[Hessian],
+    ) -> Histogram
+    {
+        let n_bins = self.0.len();
+        let mut hist = vec![(0usize, 0f64, 0f64); n_bins + 1];
+
+        for &i in indices {
+            let pos = self.locate(feat, positions, i).unwrap_or(n_bins);
+            hist[pos].0 += 1;
+            hist[pos].1 += gradient[i];
+            hist[pos].2 += hessian[i];
+        }
+        Histogram(hist)
+    }
+
+
+    /// Same as `Bins::pack`, but reads the gradient and the Hessian
+    /// from `hist` instead of the examples.
+    pub(crate) fn pack_histogram(&self, hist: &Histogram)
+        -> (Pack, (Gradient, Hessian))
+    {
+        let (mut grad_pack, mut hess_pack): (Vec<_>, Vec<_>) = hist.0.iter()
+            .map(|&(count, grad, hess)| {
+                if count == 0 { (0f64, 0f64) } else { (grad, hess) }
+            })
+            .unzip();
+        let missing = (grad_pack.pop().unwrap(), hess_pack.pop().unwrap());
+        let pack = self.remove_zero_weight_pack_and_normalize(
+            grad_pack, hess_pack
+        );
+        (pack, missing)
+    }
+
+
     /// This method removes bins with zero weights.
     /// # Example
     /// Assume that we have bins and its weight.
@@ -249,6 +366,10 @@ impl Bins {
         hess_pack: Vec<Hessian>,
     ) -> Vec<(Bin, Gradient, Hessian)>
     {
+        // All the examples have missing values.
+        if grad_pack.iter().chain(&hess_pack).all(|&v| v == 0.0) {
+            return vec![(Bin::new(f64::MIN..f64::MAX), 0.0, 0.0)];
+        }
         let mut iter = self.0.iter().zip(grad_pack.into_iter().zip(hess_pack));
 
         let (prev_bin, (mut prev_grad, mut prev_hess)) = iter.next().unwrap();
@@ -290,6 +411,50 @@ impl Bins {
 }
 
 
+/// A histogram over the bins of a feature.
+/// Each bin keeps the number of examples,
+/// the sum of the gradient, and the sum of the Hessian.
+/// The counts detect the empty bins exactly
+/// after [`Histogram::subtract`].
+#[derive(Clone)]
+pub(crate) struct Histogram(Vec<(usize, Gradient, Hessian)>);
+
+
+impl Histogram {
+    /// Construct the histogram from the sums on the bins
+    /// computed on a device.
+    pub(crate) fn from_sums(sums: &[SlotSum]) -> Self {
+        let hist = sums.iter()
+            .map(|sum| (sum.count, sum.first, sum.second))
+            .collect();
+        Self(hist)
+    }
+
+
+    /// Returns the sums of the gradient and the Hessian over the bins.
+    pub(crate) fn totals(&self) -> (Gradient, Hessian) {
+        self.0.iter()
+            .fold((0f64, 0f64), |(g, h), &(_, gi, hi)| (g + gi, h + hi))
+    }
+
+
+    /// Returns the histogram of the examples in `self`
+    /// but not in `other`.
+    /// If `self` is the histogram of a node
+    /// and `other` is the one of a child,
+    /// the result is the histogram of the sibling.
+    pub(crate) fn subtract(&self, other: &Self) -> Self {
+        let hist = self.0.iter()
+            .zip(&other.0)
+            .map(|(parent, child)| {
+                (parent.0 - child.0, parent.1 - child.1, parent.2 - child.2)
+            })
+            .collect();
+        Self(hist)
+    }
+}
+
+
 const PRINT_BIN_SIZE: usize = 3;
 
 impl fmt::Display for Bins {
@@ -1,7 +1,15 @@
 use crate::{Sample, RegressionTree};
 use super::bin::*;
+use super::regression_tree_algorithm::LeafPenalty;
 
 use crate::common::loss_functions::LossFunction;
+use crate::weak_learner::common::{
+    feature_constraint::FeatureConstraint,
+    monotone::Monotone,
+    bin_cache::BinMap,
+    row_subsample::RowSubsample,
+    device::{Device, Accelerator},
+};
 
 use std::collections::HashMap;
 
@@ -12,6 +20,8 @@ pub const DEFAULT_NBIN: usize = 255;
 pub const DEFAULT_MAX_DEPTH: usize = 2;
 /// Default L2-regularization parameter
 pub const DEFAULT_LAMBDA_L2: f64 = 0.01;
+/// Default L1-regularization parameter
+pub const DEFAULT_LAMBDA_L1: f64 = 0.0;
 
 
 /// A struct that builds `RegressionTree`.
@@ -41,8 +51,23 @@ pub struct RegressionTreeBuilder<'a, L> {
     /// L2 regularization for the leaf values.
     lambda_l2: f64,
 
+    /// L1 regularization for the leaf values.
+    lambda_l1: f64,
+
     /// Loss function
     loss: Option<L>,
+
+    /// Restrictions on the features used in splitting rules.
+    constraint: FeatureConstraint,
+
+    /// Cut the features by quantiles
+    /// and search splits on the histograms.
+    histogram: bool,
+
+    /// The weighted bootstrap of the examples for each tree.
+    subsample: RowSubsample,
+    /// The device that builds the histograms.
+    device: Device,
 }
 
 
@@ -53,6 +78,11 @@ impl<'a, L> RegressionTreeBuilder<'a, L> {
     /// ```text
     /// n_bins: DEFAULT_NBIN == 255,
     /// max_depth: DEFAULT_MAX_DEPTH == 2,
+    /// lambda_l2: DEFAULT_LAMBDA_L2 == 0.01,
+    /// lambda_l1: DEFAULT_LAMBDA_L1 == 0.0,
+    /// histogram: false,
+    /// train_on_subsample: None,
+    /// device: Device::Cpu,
     /// ```
     pub fn new(sample: &'a Sample) -> Self {
         let n_bins = sample.features()
@@ -66,10 +96,26 @@ impl<'a, L> RegressionTreeBuilder<'a, L> {
         let max_depth = DEFAULT_MAX_DEPTH;
 
         let lambda_l2 = DEFAULT_LAMBDA_L2;
+        let lambda_l1 = DEFAULT_LAMBDA_L1;
 
         let loss = None;
-
-        Self { sample, n_bins, max_depth, loss, lambda_l2, }
+        let constraint = FeatureConstraint::default();
+        let histogram = false;
+        let subsample = RowSubsample::default();
+        let device = Device::default();
+
+        Self {
+            sample,
+            n_bins,
+            max_depth,
+            loss,
+            lambda_l2,
+            lambda_l1,
+            constraint,
+            histogram,
+            subsample,
+            device,
+        }
     }
 
 
@@ -87,7 +133,27 @@ impl<'a, L> RegressionTreeBuilder<'a, L> {
     }
 
 
+    /// Set the L1-regularization parameter.
+    /// A leaf whose absolute gradient sum is at most `lambda_l1`
+    /// predicts `0`, and the other leaf values
+    /// are shrunk toward `0` by `lambda_l1`.
+    /// Default value is `0.0`.
+    pub fn lambda_l1(mut self, lambda_l1: f64) -> Self {
+        assert!(
+            lambda_l1 >= 0.0,
+            "The L1-regularization parameter must be non-negative"
+        );
+        self.lambda_l1 = lambda_l1;
+        self
+    }
+
+
     /// Specify the maximal depth of the tree.
+    /// If `depth >= 6`, the two subtrees of a node are grown
+    /// in parallel tasks,
+    /// unless the feature budget
+    /// or the feature subsampling per node is set.
+    /// The resulting trees are the same as the ones grown one by one.
     /// Default maximal depth is `2`.
     pub fn max_depth(mut self, depth: usize) -> Self {
         assert!(depth > 0);
@@ -97,6 +163,173 @@ impl<'a, L> RegressionTreeBuilder<'a, L> {
     }
 
 
+    /// Forbid the features in `names` from being used in splitting rules.
+    /// By default, all the features are allowed.
+    pub fn forbid_features<I, T>(mut self, names: I) -> Self
+        where I: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        for name in names {
+            let name = name.as_ref();
+            if !self.n_bins.contains_key(name) {
+                panic!("The feature named `{name}` does not exist");
+            }
+            self.constraint.forbid(name);
+        }
+        self
+    }
+
+
+    /// Set the maximal number of distinct features
+    /// used over all the trees produced by the resulting [`RegressionTree`].
+    /// Once `budget` features are used,
+    /// the subsequent trees only split on the features used so far.
+    /// By default, there is no budget.
+    pub fn feature_budget(mut self, budget: usize) -> Self {
+        self.constraint.set_budget(budget);
+        self
+    }
+
+
+    /// Let each tree only consider a random subset of the features
+    /// of size `ceil(fraction * # of features)`.
+    /// The subset is sampled for every tree
+    /// produced by the resulting [`RegressionTree`].
+    /// The forbidden features are excluded before the sampling.
+    /// By default, every tree considers all the features.
+    pub fn feature_subsample(mut self, fraction: f64) -> Self {
+        self.constraint.set_tree_fraction(fraction);
+        self
+    }
+
+
+    /// Let each node only consider a random subset of the features
+    /// sampled for the tree
+    /// of size `ceil(fraction * # of features sampled for the tree)`.
+    /// Combined with [`RegressionTreeBuilder::feature_subsample`],
+    /// the features of a node are sampled from the ones of the tree.
+    /// By default, every node considers all the features of the tree.
+    pub fn feature_subsample_per_node(mut self, fraction: f64) -> Self {
+        self.constraint.set_node_fraction(fraction);
+        self
+    }
+
+
+    /// Train each tree on a bootstrap of `n_samples` examples
+    /// drawn with replacement, instead of the full sample.
+    /// The examples are drawn uniformly,
+    /// or proportionally to the weights given to
+    /// [`WeakLearner::produce_with_weights`](crate::WeakLearner::produce_with_weights).
+    /// The gradient and the Hessian of each drawn example
+    /// are multiplied by the number of its draws,
+    /// and the examples not drawn are not scanned.
+    /// By default, each tree is trained on the full sample.
+    pub fn train_on_subsample(mut self, n_samples: usize) -> Self {
+        self.subsample.set_size(n_samples);
+        self
+    }
+
+
+    /// Set the seed of the randomness
+    /// for the feature subsampling and the row subsampling.
+    /// Default value is `1234`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.constraint.set_seed(seed);
+        self.subsample.set_seed(seed);
+        self
+    }
+
+
+    /// Constrain the predictions of the trees
+    /// to be monotone in the given features.
+    /// `constraints` is the pairs of a feature name and its direction,
+    /// e.g., `&[("age", Monotone::Increasing)]`.
+    /// Each split on a constrained feature is chosen
+    /// so that the values of its children respect the direction,
+    /// and the values of the descendants are bounded accordingly.
+    /// By default, no feature is constrained.
+    pub fn monotone<T>(mut self, constraints: &[(T, Monotone)]) -> Self
+        where T: AsRef<str>,
+    {
+        for (name, direction) in constraints {
+            let name = name.as_ref();
+            if !self.n_bins.contains_key(name) {
+                panic!("The feature named `{name}` does not exist");
+            }
+            self.constraint.set_monotone(name, *direction);
+        }
+        self
+    }
+
+
+    /// Restrict the interactions of the features in each tree.
+    /// `groups` is the groups of the features that can interact,
+    /// e.g., `[vec!["x", "y"], vec!["y", "z"]]`.
+    /// The features on every path from the root of a tree
+    /// belong to a common group,
+    /// and a feature in no group is used alone on the paths.
+    /// In the example, `x` and `z` never appear on the same path.
+    /// By default, all the features can interact.
+    pub fn interaction_constraints<I, G, T>(mut self, groups: I) -> Self
+        where I: IntoIterator<Item = G>,
+              G: IntoIterator<Item = T>,
+              T: AsRef<str>,
+    {
+        for group in groups {
+            let group = group.into_iter()
+                .map(|name| {
+                    let name = name.as_ref();
+                    if !self.n_bins.contains_key(name) {
+                        panic!("The feature named `{name}` does not exist");
+                    }
+                    name.to_string()
+                })
+                .collect::<Vec<_>>();
+            self.constraint.add_interaction_group(group);
+        }
+        self
+    }
+
+
+    /// Cut each feature into at most `n_bins` bins by quantiles
+    /// and search the splits on the histograms of the bins.
+    /// The histograms of a node are passed to its children,
+    /// so that only the histograms of the smaller child
+    /// are computed from the examples
+    /// and the ones of the larger child are obtained by subtraction.
+    /// This method overwrites the number of bins
+    /// set by [`RegressionTreeBuilder::set_nbins`] so far.
+    /// If `n_bins <= 255`,
+    /// the positions of the examples on the bins are stored in 8 bits.
+    ///
+    /// By default, each feature is cut into bins of the same width
+    /// and the examples are scanned at each node.
+    pub fn bins(mut self, n_bins: usize) -> Self {
+        assert!(n_bins > 0, "The number of bins must be positive");
+        self.n_bins.values_mut()
+            .for_each(|n| { *n = n_bins; });
+        self.histogram = true;
+        self
+    }
+
+
+    /// Build the histograms on `device`.
+    /// With `Device::Gpu`, which requires the `gpu` feature,
+    /// the positions of the examples on the bins are uploaded to the GPU
+    /// when the tree is built,
+    /// and the histograms and the leaf values of the large nodes
+    /// are accumulated on the GPU.
+    /// The device is used only with [`RegressionTreeBuilder::bins`].
+    /// [`RegressionTreeBuilder::build`] panics
+    /// if no GPU adapter is available
+    /// (see [`Device::is_available`]).
+    /// Default device is `Device::Cpu`.
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = device;
+        self
+    }
+
+
     /// Set the number of bins to a feature named `name`.
     pub fn set_nbins<T>(&mut self, name: T, n_bins: usize)
         where T: AsRef<str>
@@ -124,17 +357,35 @@ impl<'a, L> RegressionTreeBuilder<'a, L>
                 let name = feature.name();
                 let n_bins = *self.n_bins.get(name).unwrap();
 
-                (name, Bins::cut(feature, n_bins))
+                let bins = if self.histogram {
+                    Bins::qcut(feature, n_bins)
+                } else {
+                    Bins::cut(feature, n_bins)
+                };
+                (name, bins)
             })
             .collect::<HashMap<_, _>>();
+        let bins = BinMap::new(self.sample, bins, Bins::position);
+        let device = if self.histogram { self.device } else { Device::Cpu };
+        let accelerator = Accelerator::new(
+            device, self.sample, &bins, Bins::len,
+        );
 
         let loss = self.loss
             .expect("failed to get loss function. you need to specify a function that implements `LossFunction` trait");
 
-        let n_sample = self.sample.shape().0;
+        let penalty = LeafPenalty { l1: self.lambda_l1, l2: self.lambda_l2, };
+
         let regression_tree = RegressionTree::from_components(
-            bins, n_sample, self.max_depth, self.lambda_l2, loss,
-        );
+            bins,
+            self.max_depth,
+            penalty,
+            loss,
+            self.constraint,
+            self.histogram,
+            self.subsample,
+        )
+        .with_accelerator(accelerator);
 
 
         regression_tree
@@ -1,6 +1,6 @@
 //! Defines the inner representation 
 //! of the Decision Tree class.
-use crate::Regressor;
+use crate::{Regressor, Compression};
 
 
 use crate::weak_learner::common::{
@@ -15,7 +15,8 @@ use super::train_node::*;
 
 use serde::{Serialize, Deserialize};
 
-use std::rc::Rc;
+use std::sync::Arc;
+use std::collections::BTreeSet;
 
 
 /// Enumeration of `BranchNode` and `LeafNode`.
@@ -81,12 +82,12 @@ impl From<TrainBranchNode> for BranchNode {
     #[inline]
     fn from(branch: TrainBranchNode) -> Self {
 
-        let left = match Rc::try_unwrap(branch.left) {
-            Ok(l) => l.into_inner().into(),
+        let left = match Arc::try_unwrap(branch.left) {
+            Ok(l) => l.into_inner().unwrap().into(),
             Err(_) => panic!("Strong count is greater than 1")
         };
-        let right = match Rc::try_unwrap(branch.right) {
-            Ok(r) => r.into_inner().into(),
+        let right = match Arc::try_unwrap(branch.right) {
+            Ok(r) => r.into_inner().unwrap().into(),
             Err(_) => panic!("Strong count is greater than 1")
         };
 
@@ -153,6 +154,103 @@ impl Regressor for Node {
 
 
 impl Node {
+    /// Returns the number of nodes in this subtree.
+    pub(super) fn n_nodes(&self) -> usize {
+        match self {
+            Node::Branch(b) => 1 + b.left.n_nodes() + b.right.n_nodes(),
+            Node::Leaf(_) => 1,
+        }
+    }
+
+
+    /// Returns the number of leaves in this subtree.
+    pub(super) fn n_leaves(&self) -> usize {
+        match self {
+            Node::Branch(b) => b.left.n_leaves() + b.right.n_leaves(),
+            Node::Leaf(_) => 1,
+        }
+    }
+
+
+    /// Returns the index of the leaf that the example at `row` reaches.
+    /// The leaves are indexed from left to right.
+    pub(super) fn leaf_index(&self, sample: &Sample, row: usize) -> usize {
+        match self {
+            Node::Branch(b) => match b.rule.split(sample, row) {
+                LR::Left => b.left.leaf_index(sample, row),
+                LR::Right => {
+                    b.left.n_leaves() + b.right.leaf_index(sample, row)
+                },
+            },
+            Node::Leaf(_) => 0,
+        }
+    }
+
+
+    /// Appends the leaf values of this subtree to `values`
+    /// from left to right.
+    pub(super) fn leaf_values(&self, values: &mut Vec<f64>) {
+        match self {
+            Node::Branch(b) => {
+                b.left.leaf_values(values);
+                b.right.leaf_values(values);
+            },
+            Node::Leaf(l) => { values.push(l.prediction.0); },
+        }
+    }
+
+
+    /// Replaces the leaf values of this subtree
+    /// by the ones drawn from `values` from left to right.
+    pub(super) fn set_leaf_values<I>(&mut self, values: &mut I)
+        where I: Iterator<Item = f64>
+    {
+        match self {
+            Node::Branch(b) => {
+                b.left.set_leaf_values(values);
+                b.right.set_leaf_values(values);
+            },
+            Node::Leaf(l) => {
+                l.prediction = Prediction(
+                    values.next().expect("Too few leaf values")
+                );
+            },
+        }
+    }
+
+
+    /// Rounds the leaf values by `config`
+    /// and replaces the branch nodes whose children are identical
+    /// by one of the children.
+    pub(super) fn compress(self, config: &Compression) -> Self {
+        match self {
+            Node::Branch(b) => {
+                let left = b.left.compress(config);
+                let right = b.right.compress(config);
+                if left == right { return left; }
+                Node::Branch(BranchNode::from_raw(
+                    b.rule, Box::new(left), Box::new(right),
+                ))
+            },
+            Node::Leaf(l) => {
+                let value = config.quantize(l.prediction.0);
+                Node::Leaf(LeafNode::from_raw(Prediction(value)))
+            },
+        }
+    }
+
+
+    /// Inserts the names of the features used in this subtree
+    /// into `names`.
+    pub(super) fn used_features<'a>(&'a self, names: &mut BTreeSet<&'a str>) {
+        if let Node::Branch(b) = self {
+            names.insert(&b.rule.feature);
+            b.left.used_features(names);
+            b.right.used_features(names);
+        }
+    }
+
+
     pub(super) fn to_dot_info(&self, id: usize) -> (Vec<String>, usize) {
         match self {
             Node::Branch(b) => {
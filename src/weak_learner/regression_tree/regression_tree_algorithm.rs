@@ -7,6 +7,12 @@ use crate::common::loss_functions::LossFunction;
 use crate::weak_learner::common::{
     split_rule::*,
     type_and_struct::*,
+    feature_constraint::{FeatureConstraint, TreeFeatures},
+    monotone::{Bounds, Monotone},
+    bin_cache::BinMap,
+    row_subsample::RowSubsample,
+    parallel_growth,
+    device::Accelerator,
 };
 
 use rayon::prelude::*;
@@ -19,9 +25,7 @@ use super::{
 
 
 use std::fmt;
-use std::rc::Rc;
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::sync::Arc;
 
 
 type Gradient = f64;
@@ -30,6 +34,9 @@ type Hessian  = f64;
 
 /// `RegressionTree` is the factory that generates
 /// a `RegressionTreeClassifier` for a given distribution over examples.
+/// The training examples are placed on the bins once
+/// when `RegressionTree` is built,
+/// and the positions are reused across the boosting rounds.
 /// 
 /// # Example
 /// ```no_run
@@ -68,64 +75,253 @@ type Hessian  = f64;
 /// println!("loss (train) is: {loss}");
 /// ```
 pub struct RegressionTree<'a, L> {
-    bins: HashMap<&'a str, Bins>,
+    bins: BinMap<'a, Bins>,
     // The maximal depth of the output trees
     max_depth: usize,
 
-    // The number of training instances
-    n_sample: usize,
-
-    // Regularization parameter
-    lambda_l2: f64,
+    // Regularization parameters of the leaf values
+    penalty: LeafPenalty,
 
     // Loss function
     loss_func: L,
+
+    // Restrictions on the features used in splitting rules.
+    constraint: FeatureConstraint,
+
+    // Search splits on the histograms with sibling subtraction or not.
+    histogram: bool,
+
+    // The weighted bootstrap of the examples for each tree.
+    subsample: RowSubsample,
+
+    // The device that builds the histograms.
+    accelerator: Accelerator,
 }
 
 
 impl<'a, L> RegressionTree<'a, L> {
     #[inline]
     pub(super) fn from_components(
-        bins: HashMap<&'a str, Bins>,
-        n_sample: usize,
+        bins: BinMap<'a, Bins>,
         max_depth: usize,
-        lambda_l2: f64,
+        penalty: LeafPenalty,
         loss_func: L,
+        constraint: FeatureConstraint,
+        histogram: bool,
+        subsample: RowSubsample,
     ) -> Self
     {
-        Self { bins, n_sample, max_depth, lambda_l2, loss_func, }
+        Self {
+            bins,
+            max_depth,
+            penalty,
+            loss_func,
+            constraint,
+            histogram,
+            subsample,
+            accelerator: Accelerator::default(),
+        }
     }
 
 
+    /// Set the device that builds the histograms.
+    /// This method is called only via `RegressionTreeBuilder::build`.
     #[inline]
-    fn full_tree(
+    pub(super) fn with_accelerator(mut self, accelerator: Accelerator)
+        -> Self
+    {
+        self.accelerator = accelerator;
+        self
+    }
+
+
+    /// Forgets the features used so far
+    /// and restarts the feature and row subsampling from the seed.
+    /// Call this method before reusing `self`
+    /// for another ensemble
+    /// if the feature budget is set by
+    /// [`RegressionTreeBuilder::feature_budget`](crate::RegressionTreeBuilder::feature_budget),
+    /// or if the same subsamples are expected
+    /// as the previous ensemble.
+    pub fn reset_feature_usage(&self) {
+        self.constraint.reset();
+        self.subsample.reset();
+    }
+}
+
+
+impl<'a, L> RegressionTree<'a, L>
+    where L: Sync,
+{
+    /// Grows a tree from the gradient and the Hessian
+    /// of the loss on `sample`.
+    fn fit(
         &self,
         sample: &Sample,
         gradient: &[Gradient],
         hessian: &[Hessian],
         indices: Vec<usize>,
+    ) -> RegressionTreeRegressor
+    {
+
+        let mut features = self.constraint.tree_features(sample);
+        let tree = self.full_tree(
+            sample,
+            gradient,
+            hessian,
+            indices.into(),
+            self.max_depth,
+            &mut features,
+        );
+        self.constraint.record(features.used);
+
+        let root = Node::from(
+            Arc::try_unwrap(tree)
+                .expect("Root node has reference counter >= 1")
+                .into_inner()
+                .unwrap()
+        );
+
+        RegressionTreeRegressor::from(root)
+    }
+
+
+    /// Returns the histograms of the examples in `indices`
+    /// for each feature.
+    fn histograms(
+        &self,
+        sample: &Sample,
+        gradient: &[Gradient],
+        hessian: &[Hessian],
+        indices: &[usize],
+    ) -> Vec<Histogram>
+    {
+        let sums = self.accelerator.sums(
+            sample,
+            &self.bins,
+            indices,
+            1,
+            |i| (0, [gradient[i], hessian[i]]),
+            true,
+        );
+        if let Some(sums) = sums {
+            return (0..sample.shape().1)
+                .map(|j| Histogram::from_sums(sums.feature(j)))
+                .collect();
+        }
+
+        let bins = &self.bins;
+        sample.features()
+            .par_iter()
+            .map(|feature| {
+                let name = feature.name();
+                let bin = bins.get(name).unwrap();
+                let positions = bins.positions(sample, name);
+                bin.histogram(indices, feature, positions, gradient, hessian)
+            })
+            .collect()
+    }
+
+
+    /// Returns the sums of the gradient and the Hessian
+    /// over the examples in `indices`.
+    /// On a GPU, the sums are read from the histograms of the node
+    /// if given.
+    fn node_sums(
+        &self,
+        sample: &Sample,
+        gradient: &[Gradient],
+        hessian: &[Hessian],
+        indices: &[usize],
+        hists: Option<&[Histogram]>,
+    ) -> (Gradient, Hessian)
+    {
+        if self.accelerator.is_gpu() {
+            if let Some(hist) = hists.and_then(|hists| hists.first()) {
+                return hist.totals();
+            }
+            let sums = self.accelerator.sums(
+                sample,
+                &self.bins,
+                indices,
+                1,
+                |i| (0, [gradient[i], hessian[i]]),
+                false,
+            );
+            if let Some(sums) = sums {
+                let total = sums.totals()[0];
+                return (total.first, total.second);
+            }
+        }
+        sums(indices, gradient, hessian)
+    }
+
+
+    /// Grows a tree of depth at most `max_depth`.
+    /// If the histograms of `examples` are given,
+    /// only the histograms of the smaller child
+    /// are computed from the examples
+    /// and the ones of the other child are obtained by subtraction.
+    #[inline]
+    fn full_tree(
+        &self,
+        sample: &Sample,
+        gradient: &[Gradient],
+        hessian: &[Hessian],
+        examples: NodeExamples,
         max_depth: usize,
-    ) -> Rc<RefCell<TrainNode>>
+        features: &mut TreeFeatures,
+    ) -> TrainNodePtr
     {
+        let NodeExamples { indices, mut hists, bounds } = examples;
+        let objective = LeafObjective {
+            penalty: self.penalty, bounds, constraint: &self.constraint,
+        };
         // Compute the best prediction that minimizes the training error
         // on this node.
-        let (pred, loss) = prediction_and_loss(
-            &indices[..], gradient, hessian, self.lambda_l2,
+        let (grad_sum, hess_sum) = self.node_sums(
+            sample, gradient, hessian, &indices[..], hists.as_deref(),
         );
+        let (pred, loss) = objective.leaf(grad_sum, hess_sum);
+        let (pred, loss) = (Prediction::from(pred), LossValue::from(loss));
 
 
         // If sum of `dist` over `train` is zero, construct a leaf node.
-        if loss == 0.0 || max_depth <= 1 {
+        // With the L1 regularization, the loss of a node can be zero
+        // even if the losses of its children are not.
+        let no_l1 = self.penalty.l1 == 0.0;
+        if (no_l1 && loss == 0.0) || max_depth <= 1 {
             return TrainNode::leaf(pred, loss);
         }
 
 
         // Find the best splitting rule.
-        let (feature, threshold) = best_split(
-            &self.bins, sample, gradient, hessian, &indices[..], self.lambda_l2,
-        );
-
-        let rule = Splitter::new(feature, threshold);
+        let allowed = self.constraint.node_predicate(features);
+        let split = if self.histogram {
+            let hists = hists.get_or_insert_with(|| {
+                self.histograms(sample, gradient, hessian, &indices[..])
+            });
+            best_split_from_histograms(
+                &self.bins, sample, hists, objective, &allowed,
+            )
+        } else {
+            best_split(
+                &self.bins,
+                sample,
+                gradient,
+                hessian,
+                &indices[..],
+                objective,
+                &allowed,
+            )
+        };
+        drop(allowed);
+
+
+        // If no feature is allowed, construct a leaf node.
+        let Some(rule) = split else {
+            return TrainNode::leaf(pred, loss);
+        };
 
 
         // Split the train data for left/right childrens
@@ -144,11 +340,85 @@ impl<'a, L> RegressionTree<'a, L> {
             return TrainNode::leaf(pred, loss);
         }
 
+
+        // Divide the range of the values for the children.
+        let (lbounds, rbounds) = if self.constraint.has_monotone() {
+            let (lpred, _) = prediction_and_loss(
+                &lindices[..], gradient, hessian, objective,
+            );
+            let (rpred, _) = prediction_and_loss(
+                &rindices[..], gradient, hessian, objective,
+            );
+            let direction = objective.direction(&rule);
+            bounds.children(direction, lpred.0, rpred.0)
+        } else {
+            (bounds, bounds)
+        };
+
         // -----
         // At this point, `max_depth > 1` is guaranteed
         // so that one can grow the tree.
-        let ltree = self.full_tree(sample, gradient, hessian, lindices, max_depth-1);
-        let rtree = self.full_tree(sample, gradient, hessian, rindices, max_depth-1);
+        features.used.insert(rule.feature.clone());
+
+        let (lhists, rhists) = match hists {
+            Some(hists) if max_depth > 2 => {
+                let left_is_small = lindices.len() <= rindices.len();
+                let small = if left_is_small { &lindices } else { &rindices };
+                let small = self.histograms(
+                    sample, gradient, hessian, &small[..]
+                );
+                let large = hists.iter()
+                    .zip(&small)
+                    .map(|(parent, child)| parent.subtract(child))
+                    .collect::<Vec<_>>();
+                if left_is_small {
+                    (Some(small), Some(large))
+                } else {
+                    (Some(large), Some(small))
+                }
+            },
+            _ => (None, None),
+        };
+        let lexamples = NodeExamples {
+            indices: lindices, hists: lhists, bounds: lbounds,
+        };
+        let rexamples = NodeExamples {
+            indices: rindices, hists: rhists, bounds: rbounds,
+        };
+        let forks = max_depth > 2 && parallel_growth::forks(
+            self.max_depth,
+            lexamples.indices.len(),
+            rexamples.indices.len(),
+            &self.constraint,
+        );
+        features.path.push(rule.feature.clone());
+        let (ltree, rtree) = if forks {
+            let mut rfeatures = features.clone();
+            let (ltree, rtree) = rayon::join(
+                || self.full_tree(
+                    sample, gradient, hessian, lexamples, max_depth-1, features
+                ),
+                || self.full_tree(
+                    sample,
+                    gradient,
+                    hessian,
+                    rexamples,
+                    max_depth-1,
+                    &mut rfeatures,
+                ),
+            );
+            features.used.extend(rfeatures.used);
+            (ltree, rtree)
+        } else {
+            let ltree = self.full_tree(
+                sample, gradient, hessian, lexamples, max_depth-1, features
+            );
+            let rtree = self.full_tree(
+                sample, gradient, hessian, rexamples, max_depth-1, features
+            );
+            (ltree, rtree)
+        };
+        features.path.pop();
 
 
         TrainNode::branch(rule, ltree, rtree, pred, loss)
@@ -157,7 +427,7 @@ impl<'a, L> RegressionTree<'a, L> {
 
 
 impl<'a, L> WeakLearner for RegressionTree<'a, L>
-    where L: LossFunction,
+    where L: LossFunction + Sync,
 {
     type Hypothesis = RegressionTreeRegressor;
 
@@ -172,40 +442,60 @@ impl<'a, L> WeakLearner for RegressionTree<'a, L>
             .map(|bin| bin.len())
             .reduce(usize::max)
             .unwrap_or(0);
-        let info = Vec::from([
+        let mut info = Vec::from([
             ("# of bins (max)", format!("{n_bins}")),
             ("Max depth", format!("{}", self.max_depth)),
             ("Split criterion", format!("{}", self.loss_func.name())),
-            ("Regularization param.", format!("{}", self.lambda_l2)),
+            ("Regularization param.", format!("{}", self.penalty.l2)),
         ]);
+        if self.penalty.l1 > 0.0 {
+            info.push(("L1 regularization param.", format!("{}", self.penalty.l1)));
+        }
+        if self.histogram {
+            info.push(("Binning", String::from("Quantile (histogram)")));
+        }
+        info.extend(self.constraint.info());
+        info.extend(self.subsample.info());
+        info.extend(self.accelerator.info());
         Some(info)
     }
 
     fn produce(&self, sample: &Sample, predictions: &[f64])
         -> Self::Hypothesis
     {
-        let gradient = self.loss_func.gradient(predictions, sample.target());
-        let hessian = self.loss_func.hessian(predictions, sample.target());
-
-
-        let indices = (0..self.n_sample).collect::<Vec<_>>();
-
+        let mut gradient = self.loss_func.gradient(predictions, sample.target());
+        let mut hessian = self.loss_func.hessian(predictions, sample.target());
+
+        let n_sample = sample.shape().0;
+        let indices = match self.subsample.resample(&vec![1f64; n_sample]) {
+            Some(weights) => weigh(&mut gradient, &mut hessian, &weights),
+            None => (0..n_sample).collect(),
+        };
+        self.fit(sample, &gradient[..], &hessian[..], indices)
+    }
 
-        let tree = self.full_tree(
-            sample,
-            &gradient[..],
-            &hessian[..],
-            indices,
-            self.max_depth,
-        );
 
-        let root = Node::from(
-            Rc::try_unwrap(tree)
-                .expect("Root node has reference counter >= 1")
-                .into_inner()
-        );
-
-        RegressionTreeRegressor::from(root)
+    /// Grows a tree from the gradient and the Hessian
+    /// multiplied by `weights`.
+    fn produce_with_weights(
+        &self,
+        sample: &Sample,
+        predictions: &[f64],
+        weights: &[f64],
+    ) -> Self::Hypothesis
+    {
+        assert_eq!(predictions.len(), weights.len());
+        let mut gradient = self.loss_func.gradient(predictions, sample.target());
+        let mut hessian = self.loss_func.hessian(predictions, sample.target());
+
+        let indices = match self.subsample.resample(weights) {
+            Some(weights) => weigh(&mut gradient, &mut hessian, &weights),
+            None => {
+                weigh(&mut gradient, &mut hessian, weights);
+                (0..weights.len()).collect()
+            },
+        };
+        self.fit(sample, &gradient[..], &hessian[..], indices)
     }
 }
 
@@ -253,44 +543,230 @@ impl<L> fmt::Display for RegressionTree<'_, L>
 }
 
 
+/// The regularization parameters of the leaf values.
+/// The value of a leaf with the gradient sum `G`
+/// and the Hessian sum `H` is `- T(G) / (H + l2)`,
+/// where `T(G) = sign(G) * max(0, |G| - l1)` is the soft-thresholding.
+/// That is, `l1` shrinks the leaf values toward zero
+/// and the small ones become exactly zero.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LeafPenalty {
+    pub(super) l1: f64,
+    pub(super) l2: f64,
+}
+
+
+impl LeafPenalty {
+    /// Returns the soft-thresholded gradient sum `T(G)`.
+    #[inline]
+    fn threshold(&self, grad_sum: Gradient) -> Gradient {
+        grad_sum.signum() * (grad_sum.abs() - self.l1).max(0.0)
+    }
+
+
+    /// Returns the regularized leaf value `- T(G) / (H + l2)`.
+    #[inline]
+    fn leaf_value(&self, grad_sum: Gradient, hess_sum: Hessian) -> f64 {
+        - self.threshold(grad_sum) / (hess_sum + self.l2)
+    }
+
+
+    /// Returns the score `T(G)^2 / (H + l2)` of a leaf.
+    /// The loss of the leaf is `-0.5` times the score.
+    #[inline]
+    fn score(&self, grad_sum: Gradient, hess_sum: Hessian) -> f64 {
+        self.threshold(grad_sum).powi(2) / (hess_sum + self.l2)
+    }
+
+
+    /// Returns the loss `G w + 0.5 (H + l2) w^2 + l1 |w|`
+    /// of a leaf predicting `w`.
+    #[inline]
+    fn loss_at(&self, grad_sum: Gradient, hess_sum: Hessian, value: f64)
+        -> f64
+    {
+        grad_sum * value
+            + 0.5 * (hess_sum + self.l2) * value.powi(2)
+            + self.l1 * value.abs()
+    }
+}
+
+
+/// The objective of the leaf values on a node;
+/// the regularization, the range of the values,
+/// and the monotonicity constraints on the features.
+#[derive(Clone, Copy)]
+struct LeafObjective<'c> {
+    penalty: LeafPenalty,
+    bounds: Bounds,
+    constraint: &'c FeatureConstraint,
+}
+
+
+impl LeafObjective<'_> {
+    /// Returns the value of a leaf in the range and its loss.
+    /// Since the loss is convex in the value,
+    /// the best value in the range is
+    /// the unconstrained one clamped into the range.
+    #[inline]
+    fn leaf(&self, grad_sum: Gradient, hess_sum: Hessian) -> (f64, f64) {
+        let value = self.penalty.leaf_value(grad_sum, hess_sum);
+        let clamped = self.bounds.clamp(value);
+        if clamped == value {
+            (value, -0.5 * self.penalty.score(grad_sum, hess_sum))
+        } else {
+            (clamped, self.penalty.loss_at(grad_sum, hess_sum, clamped))
+        }
+    }
+
+
+    /// Returns the direction of the monotonicity constraint
+    /// on the feature of `rule`.
+    #[inline]
+    fn direction(&self, rule: &Splitter) -> Option<Monotone> {
+        self.constraint.monotone(&rule.feature)
+    }
+}
+
+
+/// The examples on a node.
+/// `hists` are the histograms of the examples for each feature,
+/// which are computed only if the histograms are used.
+/// `bounds` is the range of the values of the node.
+struct NodeExamples {
+    indices: Vec<usize>,
+    hists: Option<Vec<Histogram>>,
+    bounds: Bounds,
+}
+
+
+impl From<Vec<usize>> for NodeExamples {
+    #[inline]
+    fn from(indices: Vec<usize>) -> Self {
+        Self { indices, hists: None, bounds: Bounds::default() }
+    }
+}
+
+
 /// Returns the best splitting rule based on the loss function.
-fn best_split<'a>(
-    bins_map: &HashMap<&'_ str, Bins>,
-    sample: &'a Sample,
+/// Features with `allowed(name) == false` are not used.
+/// Returns `None` if no feature is allowed.
+/// Ties are broken by the order of the features.
+fn best_split(
+    bins_map: &BinMap<'_, Bins>,
+    sample: &Sample,
     gradient: &[Gradient],
     hessian: &[Hessian],
     idx: &[usize],
-    lambda_l2: f64,
-) -> (&'a str, Threshold)
+    objective: LeafObjective<'_>,
+    allowed: &(dyn Fn(&str) -> bool + Sync),
+) -> Option<Splitter>
 {
-
-    sample.features()
+    let candidates = sample.features()
         .par_iter()
+        .filter(|feature| allowed(feature.name()))
         .map(|feature| {
             let name = feature.name();
             let bin = bins_map.get(name).unwrap();
-            let pack = bin.pack(idx, feature, gradient, hessian);
-            let (score, threshold) = best_split_at(pack, lambda_l2);
-
-            (score, name, threshold)
-        })
-        .max_by(|x, y| x.0.partial_cmp(&y.0).unwrap())
-        .map(|(_, name, threshold)| (name, threshold))
-        .expect("No feature that maximizes the score.")
+            let positions = bins_map.positions(sample, name);
+            let (pack, missing) = bin.pack(
+                idx, feature, positions, gradient, hessian
+            );
+            best_split_at(name, pack, missing, objective)
+        });
+    best_rule(candidates, false)
 }
 
-/// this code is implemented based on Algorithm 3 of the following paper:
+/// Same as `best_split`, but reads the gradient and the Hessian
+/// from the histograms `hists`,
+/// where `hists[j]` is the histogram of the `j`-th feature.
+fn best_split_from_histograms(
+    bins_map: &BinMap<'_, Bins>,
+    sample: &Sample,
+    hists: &[Histogram],
+    objective: LeafObjective<'_>,
+    allowed: &(dyn Fn(&str) -> bool + Sync),
+) -> Option<Splitter>
+{
+    let candidates = sample.features()
+        .par_iter()
+        .zip(hists)
+        .filter(|(feature, _)| allowed(feature.name()))
+        .map(|(feature, hist)| {
+            let name = feature.name();
+            let bin = bins_map.get(name).unwrap();
+            let (pack, missing) = bin.pack_histogram(hist);
+            best_split_at(name, pack, missing, objective)
+        });
+    best_rule(candidates, false)
+}
+
+
+/// Returns the best splitting rule on the feature `name` and its score.
+/// The examples with missing values are sent to the left and to the right,
+/// and the better direction is learned
+/// as the sparsity-aware split finding (Algorithm 3) of the following paper:
 /// Tianqi Chen and Carlos Guestrin.
 /// XGBoost: A scalable tree boosting system [KDD '16]
 fn best_split_at(
+    name: &str,
     pack: Vec<(Bin, Gradient, Hessian)>,
-    lambda_l2: f64,
-) -> (LossValue, Threshold)
+    missing: (Gradient, Hessian),
+    objective: LeafObjective<'_>,
+) -> (LossValue, Splitter)
 {
-    let mut right_grad_sum = pack.par_iter()
+    let direction = objective.constraint.monotone(name);
+    let (missing_grad, missing_hess) = missing;
+    if missing_grad == 0.0 && missing_hess == 0.0 {
+        let (score, threshold) = best_threshold(pack, objective, direction);
+        return (score.into(), Splitter::new(name, threshold.into()));
+    }
+
+
+    // Scan the bins with the missing values on the right.
+    // The threshold `f64::INFINITY` sends all the examples to the left.
+    let mut right = pack.clone();
+    right.push((Bin::new(f64::MAX..f64::INFINITY), missing_grad, missing_hess));
+    let (rscore, rthreshold) = best_threshold(right, objective, direction);
+
+
+    // Scan the bins with the missing values on the left.
+    // The threshold `f64::NEG_INFINITY` sends
+    // only the missing values to the left.
+    let mut left = vec![
+        (Bin::new(f64::MIN..f64::NEG_INFINITY), missing_grad, missing_hess)
+    ];
+    left.extend(pack);
+    let (lscore, lthreshold) = best_threshold(left, objective, direction);
+
+
+    // Ties are broken by sending the missing values to the right.
+    let (score, threshold, missing_left) = if rscore >= lscore {
+        (rscore, rthreshold.min(f64::MAX), rthreshold == f64::INFINITY)
+    } else {
+        (lscore, lthreshold.max(f64::MIN), true)
+    };
+    let rule = Splitter::new(name, threshold.into())
+        .missing_left(missing_left);
+    (score.into(), rule)
+}
+
+
+/// this code is implemented based on Algorithm 3 of the following paper:
+/// Tianqi Chen and Carlos Guestrin.
+/// XGBoost: A scalable tree boosting system [KDD '16]
+///
+/// The thresholds whose children violate `direction` are skipped.
+fn best_threshold(
+    pack: Vec<(Bin, Gradient, Hessian)>,
+    objective: LeafObjective<'_>,
+    direction: Option<Monotone>,
+) -> (f64, f64)
+{
+    let mut right_grad_sum = pack.iter()
         .map(|(_, grad, _)| grad)
         .sum::<f64>();
-    let mut right_hess_sum = pack.par_iter()
+    let mut right_hess_sum = pack.iter()
         .map(|(_, _, hess)| hess)
         .sum::<f64>();
 
@@ -310,16 +786,18 @@ fn best_split_at(
         right_hess_sum -= hess;
 
 
-        let score = 
-            left_grad_sum.powi(2) / (left_hess_sum + lambda_l2)
-            + right_grad_sum.powi(2) / (right_hess_sum + lambda_l2);
+        let (lvalue, lloss) = objective.leaf(left_grad_sum, left_hess_sum);
+        let (rvalue, rloss) = objective.leaf(right_grad_sum, right_hess_sum);
+        if !Bounds::admits(direction, lvalue, rvalue) { continue; }
+
+        let score = -2.0 * (lloss + rloss);
         if best_score < score {
             best_score = score;
             best_threshold = bin.0.end;
         }
     }
 
-    (best_score.into(), best_threshold.into())
+    (best_score, best_threshold)
 }
 
 /// returns the prediction value and the loss value of a leaf.
@@ -330,20 +808,47 @@ fn prediction_and_loss(
     indices: &[usize],
     gradient: &[Gradient],
     hessian: &[Hessian],
-    lambda_l2: f64,
+    objective: LeafObjective<'_>,
 ) -> (Prediction<f64>, LossValue)
 {
-    let grad_sum = indices.par_iter()
+    let (grad_sum, hess_sum) = sums(indices, gradient, hessian);
+    let (prediction, loss_value) = objective.leaf(grad_sum, hess_sum);
+
+    (prediction.into(), loss_value.into())
+}
+
+
+
+/// Returns the sums of the gradient and the Hessian
+/// over the examples in `indices`.
+fn sums(indices: &[usize], gradient: &[Gradient], hessian: &[Hessian])
+    -> (Gradient, Hessian)
+{
+    let grad_sum = indices.iter()
         .map(|&i| gradient[i])
         .sum::<f64>();
 
-    let hess_sum = indices.par_iter()
+    let hess_sum = indices.iter()
         .map(|&i| hessian[i])
         .sum::<f64>();
 
-    let prediction = - grad_sum / (hess_sum + lambda_l2);
-    let loss_value = -0.5 * grad_sum.powi(2) / (hess_sum + lambda_l2);
-
-    (prediction.into(), loss_value.into())
+    (grad_sum, hess_sum)
 }
 
+
+/// Multiplies the gradient and the Hessian by `weights`
+/// and returns the indices of the examples of positive weight.
+fn weigh(gradient: &mut [Gradient], hessian: &mut [Hessian], weights: &[f64])
+    -> Vec<usize>
+{
+    gradient.iter_mut()
+        .zip(hessian.iter_mut())
+        .zip(weights)
+        .for_each(|((g, h), w)| {
+            *g *= w;
+            *h *= w;
+        });
+    (0..weights.len())
+        .filter(|&i| weights[i] > 0f64)
+        .collect()
+}
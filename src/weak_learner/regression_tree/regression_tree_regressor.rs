@@ -3,12 +3,19 @@ use serde::{
     Deserialize,
 };
 
-use crate::{Sample, Regressor};
+use crate::{
+    Sample,
+    Regressor,
+    WeightedMajority,
+    Compression,
+    CompressionReport,
+};
 use super::node::*;
 
 use std::path::Path;
 use std::fs::File;
 use std::io::prelude::*;
+use std::collections::BTreeSet;
 
 
 /// Regression Tree regressor.
@@ -36,6 +43,57 @@ impl Regressor for RegressionTreeRegressor {
 
 
 impl RegressionTreeRegressor {
+    /// Returns the number of nodes in this tree.
+    pub fn n_nodes(&self) -> usize {
+        self.root.n_nodes()
+    }
+
+
+    /// Returns the names of the features used in this tree
+    /// in lexicographical order.
+    pub fn used_features(&self) -> Vec<&str> {
+        let mut names = BTreeSet::new();
+        self.root.used_features(&mut names);
+        names.into_iter().collect()
+    }
+
+
+    /// Returns the number of leaves in this tree.
+    pub fn n_leaves(&self) -> usize {
+        self.root.n_leaves()
+    }
+
+
+    /// Returns the index of the leaf that the example at `row` reaches.
+    /// The leaves are indexed from left to right,
+    /// so that the index is less than [`Self::n_leaves`].
+    pub fn leaf_index(&self, sample: &Sample, row: usize) -> usize {
+        self.root.leaf_index(sample, row)
+    }
+
+
+    /// Returns the values of the leaves from left to right.
+    pub fn leaf_values(&self) -> Vec<f64> {
+        let mut values = Vec::with_capacity(self.n_leaves());
+        self.root.leaf_values(&mut values);
+        values
+    }
+
+
+    /// Replaces the values of the leaves by `values`
+    /// from left to right.
+    ///
+    /// # Panics
+    /// Panics if `values.len()` is not equal to [`Self::n_leaves`].
+    pub fn set_leaf_values(&mut self, values: &[f64]) {
+        assert_eq!(
+            values.len(), self.n_leaves(),
+            "The number of leaf values must be equal to the number of leaves"
+        );
+        self.root.set_leaf_values(&mut values.iter().copied());
+    }
+
+
     /// Write the current regression tree to dot file.
     #[inline]
     pub fn to_dot_file<P>(&self, path: P) -> std::io::Result<()>
@@ -57,3 +115,69 @@ impl RegressionTreeRegressor {
     }
 }
 
+
+
+impl WeightedMajority<RegressionTreeRegressor> {
+    /// Returns the names of the features used in this ensemble
+    /// in lexicographical order.
+    pub fn used_features(&self) -> Vec<&str> {
+        let mut names = BTreeSet::new();
+        self.hypotheses.iter()
+            .for_each(|h| { h.root.used_features(&mut names); });
+        names.into_iter().collect()
+    }
+
+
+    /// Compress this ensemble by `config` (see [`Compression`])
+    /// and report the number of trees/nodes and
+    /// the mean squared error on `sample`
+    /// before and after the compression.
+    pub fn compress(&self, sample: &Sample, config: Compression)
+        -> (Self, CompressionReport)
+    {
+        let trees = self.hypotheses.iter()
+            .map(|h| Self::compress_tree(h, &config))
+            .collect::<Vec<_>>();
+        let (weights, hypotheses) = config.compress_members(
+            &self.weights[..], &trees[..]
+        );
+        let compressed = Self { weights, hypotheses };
+
+        let report = CompressionReport {
+            metric: "L2 loss",
+            trees_before: self.hypotheses.len(),
+            trees_after: compressed.hypotheses.len(),
+            nodes_before: self.n_nodes(),
+            nodes_after: compressed.n_nodes(),
+            score_before: squared_error(self, sample),
+            score_after: squared_error(&compressed, sample),
+        };
+        (compressed, report)
+    }
+
+
+    fn compress_tree(tree: &RegressionTreeRegressor, config: &Compression)
+        -> RegressionTreeRegressor
+    {
+        let root = tree.root.clone().compress(config);
+        RegressionTreeRegressor::from(root)
+    }
+
+
+    fn n_nodes(&self) -> usize {
+        self.hypotheses.iter()
+            .map(|h| h.n_nodes())
+            .sum::<usize>()
+    }
+}
+
+
+fn squared_error<H: Regressor>(f: &H, sample: &Sample) -> f64 {
+    let n_sample = sample.shape().0;
+    f.predict_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .map(|(p, y)| (p - y).powi(2))
+        .sum::<f64>()
+        / n_sample as f64
+}
@@ -10,12 +10,14 @@ use crate::weak_learner::common::{
 use crate::Sample;
 
 
-use std::rc::Rc;
-use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
 
 use std::fmt;
 
 
+pub(super) type TrainNodePtr = Arc<Mutex<TrainNode>>;
+
+
 /// Enumeration of `TrainBranchNode` and `TrainLeafNode`.
 pub enum TrainNode {
     /// A node that have two childrens.
@@ -35,11 +37,11 @@ pub struct TrainBranchNode {
 
 
     // Left child
-    pub(super) left: Rc<RefCell<TrainNode>>,
+    pub(super) left: TrainNodePtr,
 
 
     // Right child
-    pub(super) right: Rc<RefCell<TrainNode>>,
+    pub(super) right: TrainNodePtr,
 
 
     // A label that have most weight on this node.
@@ -78,7 +80,7 @@ impl TrainNode {
     pub(super) fn leaf(
         prediction: Prediction<f64>,
         loss_as_leaf: LossValue,
-    ) -> Rc<RefCell<Self>>
+    ) -> TrainNodePtr
     {
         let leaf = TrainLeafNode {
             prediction,
@@ -86,7 +88,7 @@ impl TrainNode {
         };
 
 
-        Rc::new(RefCell::new(TrainNode::Leaf(leaf)))
+        Arc::new(Mutex::new(TrainNode::Leaf(leaf)))
     }
 
 
@@ -94,13 +96,14 @@ impl TrainNode {
     #[inline]
     pub(super) fn branch(
         rule: Splitter,
-        left: Rc<RefCell<TrainNode>>,
-        right: Rc<RefCell<TrainNode>>,
+        left: TrainNodePtr,
+        right: TrainNodePtr,
         prediction: Prediction<f64>,
         loss_as_leaf: LossValue,
-    ) -> Rc<RefCell<Self>>
+    ) -> TrainNodePtr
     {
-        let leaves = left.borrow().leaves() + right.borrow().leaves();
+        let leaves = left.lock().unwrap().leaves()
+            + right.lock().unwrap().leaves();
         let node = TrainBranchNode {
             rule,
             left,
@@ -113,7 +116,7 @@ impl TrainNode {
             leaves,
         };
 
-        Rc::new(RefCell::new(TrainNode::Branch(node)))
+        Arc::new(Mutex::new(TrainNode::Branch(node)))
     }
 
 
@@ -140,8 +143,8 @@ impl Regressor for TrainBranchNode {
     #[inline]
     fn predict(&self, sample: &Sample, row: usize) -> f64 {
         match self.rule.split(sample, row) {
-            LR::Left => self.left.borrow().predict(sample, row),
-            LR::Right => self.right.borrow().predict(sample, row)
+            LR::Left => self.left.lock().unwrap().predict(sample, row),
+            LR::Right => self.right.lock().unwrap().predict(sample, row)
         }
     }
 }
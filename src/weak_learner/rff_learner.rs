@@ -0,0 +1,10 @@
+/// Defines `RffLearnerBuilder`.
+mod builder;
+/// Defines `RffLearner`.
+mod rff_learner_algorithm;
+/// Defines `RffClassifier` returned by `RffLearner`.
+mod rff_classifier;
+
+pub use builder::RffLearnerBuilder;
+pub use rff_learner_algorithm::RffLearner;
+pub use rff_classifier::RffClassifier;
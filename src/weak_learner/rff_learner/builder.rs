@@ -0,0 +1,176 @@
+use rand::prelude::*;
+use rand_distr::{Normal, Uniform};
+
+use std::f64::consts::PI;
+
+use crate::Sample;
+use crate::weak_learner::common::standardizer::Standardizer;
+use super::rff_learner_algorithm::RffLearner;
+use super::rff_classifier::FourierFeatures;
+
+
+/// The number of the random features set as default.
+pub const DEFAULT_N_COMPONENTS: usize = 100;
+/// The bandwidth of the RBF kernel set as default.
+pub const DEFAULT_BANDWIDTH: f64 = 1.0;
+/// The regularization parameter set as default.
+pub const DEFAULT_LAMBDA: f64 = 0.01;
+/// The maximal number of epochs set as default.
+pub const DEFAULT_MAX_ITER: usize = 100;
+/// The tolerance of the dual coordinate descent set as default.
+pub const DEFAULT_TOLERANCE: f64 = 1e-3;
+/// The seed of the random features set as default.
+pub const DEFAULT_SEED: u64 = 1234;
+
+
+/// A struct that builds [`RffLearner`].
+/// `RffLearnerBuilder` keeps parameters for constructing [`RffLearner`].
+///
+/// # Example
+///
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let rff = RffLearnerBuilder::new(&sample)
+///     .n_components(200)
+///     .bandwidth(0.5)
+///     .lambda(0.001)
+///     .seed(777)
+///     .build();
+/// ```
+#[derive(Clone)]
+pub struct RffLearnerBuilder<'a> {
+    sample: &'a Sample,
+    n_components: usize,
+    bandwidth: f64,
+    lambda: f64,
+    max_iter: usize,
+    tolerance: f64,
+    seed: u64,
+}
+
+
+impl<'a> RffLearnerBuilder<'a> {
+    /// Construct a new instance of [`RffLearnerBuilder`].
+    /// By default, [`RffLearnerBuilder`] sets the parameters as follows;
+    /// ```text
+    /// n_components: DEFAULT_N_COMPONENTS == 100,
+    /// bandwidth: DEFAULT_BANDWIDTH == 1.0,
+    /// lambda: DEFAULT_LAMBDA == 0.01,
+    /// max_iter: DEFAULT_MAX_ITER == 100,
+    /// tolerance: DEFAULT_TOLERANCE == 1e-3,
+    /// seed: DEFAULT_SEED == 1234,
+    /// ```
+    pub fn new(sample: &'a Sample) -> Self {
+        Self {
+            sample,
+            n_components: DEFAULT_N_COMPONENTS,
+            bandwidth: DEFAULT_BANDWIDTH,
+            lambda: DEFAULT_LAMBDA,
+            max_iter: DEFAULT_MAX_ITER,
+            tolerance: DEFAULT_TOLERANCE,
+            seed: DEFAULT_SEED,
+        }
+    }
+
+
+    /// Set the number of the random Fourier features.
+    /// A larger value approximates the RBF kernel better.
+    /// Default value is `100`.
+    pub fn n_components(mut self, n_components: usize) -> Self {
+        assert!(
+            n_components > 0,
+            "The number of the random features must be positive"
+        );
+        self.n_components = n_components;
+        self
+    }
+
+
+    /// Set the bandwidth `σ` of the RBF kernel
+    /// `exp(-|x - x'|^2 / (2σ^2))` on the standardized features.
+    /// A smaller value gives a more complex decision boundary.
+    /// Default value is `1.0`.
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        assert!(bandwidth > 0f64, "The bandwidth must be positive");
+        self.bandwidth = bandwidth;
+        self
+    }
+
+
+    /// Set the regularization parameter of the linear SVM.
+    /// Default value is `0.01`.
+    pub fn lambda(mut self, lambda: f64) -> Self {
+        assert!(
+            lambda > 0f64,
+            "The regularization parameter must be positive"
+        );
+        self.lambda = lambda;
+        self
+    }
+
+
+    /// Set the maximal number of epochs
+    /// of the dual coordinate descent.
+    /// Default value is `100`.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        assert!(max_iter > 0, "The number of iterations must be positive");
+        self.max_iter = max_iter;
+        self
+    }
+
+
+    /// Set the tolerance of the dual coordinate descent.
+    /// The iterations stop once the violation of the optimality
+    /// is below `tolerance`.
+    /// Default value is `1e-3`.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0f64, "The tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+
+    /// Set the seed of the random features
+    /// and of the order of the coordinate descent.
+    /// Default value is `1234`.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+
+    /// Build a [`RffLearner`].
+    /// This method consumes `self`.
+    pub fn build(self) -> RffLearner {
+        let scaler = Standardizer::new(self.sample);
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let normal = Normal::new(0f64, 1f64 / self.bandwidth).unwrap();
+        let uniform = Uniform::new(0f64, 2f64 * PI);
+
+        // Draw the directions on the standardized features
+        // and rewrite them as the ones on the original features.
+        let mut directions = Vec::with_capacity(self.n_components);
+        let mut offsets = Vec::with_capacity(self.n_components);
+        for _ in 0..self.n_components {
+            let direction = scaler.stds.iter()
+                .map(|std| normal.sample(&mut rng) / std)
+                .collect::<Vec<_>>();
+            let shift = direction.iter()
+                .zip(&scaler.means)
+                .map(|(w, mean)| w * mean)
+                .sum::<f64>();
+            offsets.push(uniform.sample(&mut rng) - shift);
+            directions.push(direction);
+        }
+        let map = FourierFeatures {
+            features: scaler.names,
+            fill: scaler.means,
+            directions,
+            offsets,
+        };
+        let params = (self.lambda, self.max_iter, self.tolerance);
+        RffLearner::from_components(map, self.bandwidth, params, self.seed)
+    }
+}
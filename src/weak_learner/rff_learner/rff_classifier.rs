@@ -0,0 +1,111 @@
+//! Defines the random Fourier features and the classifier on them.
+use serde::{Serialize, Deserialize};
+
+use std::sync::Arc;
+
+use crate::{Classifier, Sample};
+
+
+/// The random Fourier features
+/// ```text
+/// z_k(x) = sqrt(2 / D) * cos(Σ_j directions[k][j] * x_j + offsets[k])
+/// ```
+/// for `k = 0, 1, ..., D - 1`, where `x_j` is the value of `features[j]`.
+/// A missing value of `features[j]` is replaced by `fill[j]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct FourierFeatures {
+    pub(super) features: Vec<String>,
+    pub(super) fill: Vec<f64>,
+    pub(super) directions: Vec<Vec<f64>>,
+    pub(super) offsets: Vec<f64>,
+}
+
+
+impl FourierFeatures {
+    /// Returns the number of the random features.
+    #[inline]
+    pub(super) fn dim(&self) -> usize {
+        self.offsets.len()
+    }
+
+
+    /// Returns the random features of the `row`-th example.
+    pub(super) fn transform(&self, sample: &Sample, row: usize) -> Vec<f64> {
+        let x = self.features.iter()
+            .zip(&self.fill)
+            .map(|(name, fill)| {
+                let x = sample[name][row];
+                if x.is_nan() { *fill } else { x }
+            })
+            .collect::<Vec<_>>();
+
+        let scale = (2f64 / self.dim() as f64).sqrt();
+        self.directions.iter()
+            .zip(&self.offsets)
+            .map(|(direction, offset)| {
+                let projection = direction.iter()
+                    .zip(&x)
+                    .map(|(w, x)| w * x)
+                    .sum::<f64>();
+                scale * (projection + offset).cos()
+            })
+            .collect()
+    }
+}
+
+
+/// Classifier returned by [`RffLearner`](crate::RffLearner).
+/// This is a linear function `f` of the random Fourier features
+/// and the confidence for an example `x` is `f(x)` clipped to `[-1, 1]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RffClassifier {
+    map: Arc<FourierFeatures>,
+    weights: Vec<f64>,
+    intercept: f64,
+}
+
+
+impl RffClassifier {
+    /// Construct a new instance of `RffClassifier`
+    /// from the given components.
+    #[inline]
+    pub(super) fn from_components(
+        map: Arc<FourierFeatures>,
+        weights: Vec<f64>,
+        intercept: f64,
+    ) -> Self
+    {
+        Self { map, weights, intercept, }
+    }
+
+
+    /// Returns the value of the linear function
+    /// at the `row`-th example before clipping.
+    pub fn score(&self, sample: &Sample, row: usize) -> f64 {
+        self.map.transform(sample, row)
+            .into_iter()
+            .zip(&self.weights)
+            .map(|(z, w)| w * z)
+            .sum::<f64>()
+            + self.intercept
+    }
+
+
+    /// Returns the coefficients of the random Fourier features.
+    pub fn weights(&self) -> &[f64] {
+        &self.weights[..]
+    }
+
+
+    /// Returns the intercept of the linear function.
+    pub fn intercept(&self) -> f64 {
+        self.intercept
+    }
+}
+
+
+impl Classifier for RffClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        self.score(sample, row).clamp(-1f64, 1f64)
+    }
+}
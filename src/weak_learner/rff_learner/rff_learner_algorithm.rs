@@ -0,0 +1,179 @@
+use rand::prelude::*;
+use rayon::prelude::*;
+
+use std::sync::Arc;
+
+use crate::{Sample, WeakLearner};
+use super::rff_classifier::{
+    FourierFeatures,
+    RffClassifier,
+};
+
+
+/// The weak learner on the random Fourier features.
+/// [`RffLearner`] maps the examples to the random Fourier features
+/// that approximate the RBF (Gaussian) kernel
+/// `k(x, x') = exp(-|x - x'|^2 / (2 bandwidth^2))`
+/// on the standardized features
+/// (Rahimi and Recht, 2007),
+/// and fits a linear SVM on them.
+/// The SVM minimizes
+/// ```text
+/// lambda / 2 * |w|^2 + Σ_i dist[i] * max(0, 1 - y_i (w^T z(x_i) + b))
+/// ```
+/// by the dual coordinate descent (Hsieh et al., 2008),
+/// where `z(x)` is the random features of `x`.
+/// The intercept `b` is regularized as an extra coefficient.
+///
+/// The random features are drawn once by [`RffLearnerBuilder`]
+/// and shared by all the hypotheses
+/// so that the combined hypothesis of boosting
+/// is again a linear function of them.
+/// The features are standardized by the mean and the standard deviation
+/// over the sample given to [`RffLearnerBuilder`],
+/// and the missing values are replaced by the means.
+/// The features that take a single value are not used.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let rff = RffLearnerBuilder::new(&sample)
+///     .n_components(200)
+///     .bandwidth(0.5)
+///     .seed(777)
+///     .build();
+///
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&rff);
+/// ```
+///
+/// [`RffLearnerBuilder`]: crate::weak_learner::RffLearnerBuilder
+pub struct RffLearner {
+    map: Arc<FourierFeatures>,
+    bandwidth: f64,
+    lambda: f64,
+    max_iter: usize,
+    tolerance: f64,
+    seed: u64,
+}
+
+
+impl RffLearner {
+    /// Initialize [`RffLearner`].
+    /// This method is called only via `RffLearnerBuilder::build`.
+    #[inline]
+    pub(super) fn from_components(
+        map: FourierFeatures,
+        bandwidth: f64,
+        (lambda, max_iter, tolerance): (f64, usize, f64),
+        seed: u64,
+    ) -> Self
+    {
+        let map = Arc::new(map);
+        Self { map, bandwidth, lambda, max_iter, tolerance, seed, }
+    }
+
+
+    /// Returns the coefficients and the intercept of the linear SVM
+    /// on the random features `features` weighted by `dist`.
+    fn svm(&self, features: &[Vec<f64>], labels: &[f64], dist: &[f64])
+        -> (Vec<f64>, f64)
+    {
+        let mut weights = vec![0f64; self.map.dim()];
+        let mut intercept = 0f64;
+
+        // The dual variables are bounded by `dist[i] / lambda`.
+        let upper = dist.iter()
+            .map(|d| d / self.lambda)
+            .collect::<Vec<_>>();
+        let diagonal = features.iter()
+            .map(|z| z.iter().map(|v| v * v).sum::<f64>() + 1f64)
+            .collect::<Vec<_>>();
+        let mut alpha = vec![0f64; labels.len()];
+        let mut order = (0..labels.len())
+            .filter(|&i| upper[i] > 0f64)
+            .collect::<Vec<_>>();
+
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        for _ in 0..self.max_iter {
+            order.shuffle(&mut rng);
+            let mut violation = 0f64;
+            for &i in order.iter() {
+                let y = labels[i];
+                let z = &features[i];
+                let score = z.iter()
+                    .zip(&weights)
+                    .map(|(z, w)| z * w)
+                    .sum::<f64>()
+                    + intercept;
+                let gradient = y * score - 1f64;
+
+                // The gradient projected onto the box constraint.
+                let projected = if alpha[i] <= 0f64 {
+                    gradient.min(0f64)
+                } else if alpha[i] >= upper[i] {
+                    gradient.max(0f64)
+                } else {
+                    gradient
+                };
+                violation = violation.max(projected.abs());
+                if projected == 0f64 { continue; }
+
+                let next = (alpha[i] - gradient / diagonal[i])
+                    .clamp(0f64, upper[i]);
+                let delta = (next - alpha[i]) * y;
+                alpha[i] = next;
+                weights.iter_mut()
+                    .zip(z)
+                    .for_each(|(w, z)| { *w += delta * z; });
+                intercept += delta;
+            }
+            if violation < self.tolerance { break; }
+        }
+        (weights, intercept)
+    }
+}
+
+
+impl WeakLearner for RffLearner {
+    type Hypothesis = RffClassifier;
+
+
+    fn name(&self) -> &str {
+        "Random Fourier Features + Linear SVM"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let info = Vec::from([
+            ("# of features", format!("{}", self.map.features.len())),
+            ("# of components", format!("{}", self.map.dim())),
+            ("Bandwidth", format!("{}", self.bandwidth)),
+            ("Regularization param.", format!("{}", self.lambda)),
+            ("Max iterations", format!("{}", self.max_iter)),
+            ("Tolerance", format!("{}", self.tolerance)),
+            ("Seed", format!("{}", self.seed)),
+        ]);
+        Some(info)
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_sample = sample.shape().0;
+        let features = (0..n_sample).into_par_iter()
+            .map(|i| self.map.transform(sample, i))
+            .collect::<Vec<_>>();
+        let (weights, intercept) = self.svm(
+            &features[..], sample.target(), dist
+        );
+        RffClassifier::from_components(
+            Arc::clone(&self.map), weights, intercept
+        )
+    }
+}
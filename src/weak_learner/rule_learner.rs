@@ -0,0 +1,7 @@
+/// Defines `RuleLearner`.
+mod rule_algorithm;
+/// Defines `RuleClassifier` returned by `RuleLearner`.
+mod rule_classifier;
+
+pub use rule_algorithm::RuleLearner;
+pub use rule_classifier::{Condition, RuleClassifier};
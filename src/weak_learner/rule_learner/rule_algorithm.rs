@@ -0,0 +1,244 @@
+use rayon::prelude::*;
+
+use crate::{Sample, WeakLearner};
+use super::rule_classifier::{Condition, RuleClassifier};
+
+
+/// The maximal number of conditions of a rule set as default.
+pub const DEFAULT_MAX_CONDITIONS: usize = 3;
+
+
+/// A weak learner that returns a conjunctive rule
+/// `IF c_1 AND c_2 AND ... THEN sign ELSE -sign`,
+/// where each `c_k` compares a feature with a threshold.
+///
+/// For each `sign` in `{+1, -1}`,
+/// [`RuleLearner`] starts from the rule without conditions
+/// and greedily appends the condition
+/// that increases the edge `Σ_i d_i y_i h(x_i)` the most,
+/// as in SLIPPER (Cohen and Singer, 1999).
+/// The growth stops when no condition increases the edge
+/// or the rule has [`RuleLearner::max_conditions`] conditions.
+/// Then, [`RuleLearner`] returns the rule of the larger edge.
+/// Since a rule of one condition is a decision stump,
+/// the edge of the returned rule is no less than
+/// the one of the best decision stump.
+///
+/// Like [`DecisionStump`](crate::DecisionStump),
+/// [`RuleLearner`] sorts the examples by each feature
+/// only once in [`RuleLearner::init`]
+/// and each condition is found in `O(nd)` time.
+///
+/// # Example
+/// ```no_run
+/// use miniboosts::prelude::*;
+///
+/// # let sample = SampleReader::new().file("train.csv").has_header(true).target_feature("class").read().unwrap();
+/// let rule = RuleLearner::init(&sample)
+///     .max_conditions(2);
+///
+/// let mut booster = AdaBoost::init(&sample)
+///     .tolerance(0.01);
+/// let f = booster.run(&rule);
+///
+/// // Print the rules.
+/// for (w, h) in f.weights.iter().zip(&f.hypotheses) {
+///     println!("{w:.3}\t{h}");
+/// }
+/// ```
+pub struct RuleLearner<'a> {
+    sample: &'a Sample,
+    // `sorted[j]` is the pairs `(row, value)` of the `j`-th feature
+    // sorted in the ascending order of the values.
+    sorted: Vec<Vec<(usize, f64)>>,
+    max_conditions: usize,
+}
+
+
+impl<'a> RuleLearner<'a> {
+    /// Construct a new instance of `RuleLearner`
+    /// and sort the examples by each feature.
+    ///
+    /// Time complexity: `O(n d log n)`.
+    pub fn init(sample: &'a Sample) -> Self {
+        let sorted = sort_features(sample);
+        Self {
+            sample,
+            sorted,
+            max_conditions: DEFAULT_MAX_CONDITIONS,
+        }
+    }
+
+
+    /// Set the maximal number of conditions of a rule.
+    /// Default value is `3`.
+    pub fn max_conditions(mut self, max_conditions: usize) -> Self {
+        assert!(
+            max_conditions > 0,
+            "The number of conditions must be positive"
+        );
+        self.max_conditions = max_conditions;
+        self
+    }
+
+
+    /// Grows a rule predicting `sign` on the covered examples
+    /// and returns the pair of its edge and conditions.
+    fn grow(
+        &self,
+        sample: &Sample,
+        sorted: &[Vec<(usize, f64)>],
+        dist: &[f64],
+        sign: f64,
+    ) -> (f64, Vec<Condition>)
+    {
+        let target = sample.target();
+        let total = target.iter()
+            .zip(dist)
+            .map(|(y, d)| d * y)
+            .sum::<f64>();
+
+        // `mass` is the sum of `d_i y_i` over the covered examples.
+        let mut covered = vec![true; target.len()];
+        let mut mass = total;
+        let mut conditions = Vec::new();
+        while conditions.len() < self.max_conditions {
+            let candidates = sorted.par_iter()
+                .map(|pairs| {
+                    best_condition_on(pairs, &covered, target, dist, sign)
+                })
+                .collect::<Vec<_>>();
+
+            // Ties are broken by the position of the feature.
+            let mut best: Option<(usize, (f64, f64, bool))> = None;
+            for (j, candidate) in candidates.into_iter().enumerate() {
+                let Some(candidate) = candidate else { continue; };
+                let better = match best {
+                    Some((_, (m, _, _))) => sign * candidate.0 > sign * m,
+                    None => sign * candidate.0 > sign * mass,
+                };
+                if better { best = Some((j, candidate)); }
+            }
+            let Some((j, (next, threshold, greater))) = best else { break; };
+
+            let feature = &sample.features()[j];
+            let condition = Condition::new(feature.name(), threshold, greater);
+            covered.iter_mut()
+                .enumerate()
+                .for_each(|(i, c)| {
+                    *c = *c && condition.holds(feature[i]);
+                });
+            mass = next;
+            conditions.push(condition);
+        }
+        (sign * (2f64 * mass - total), conditions)
+    }
+}
+
+
+/// Returns the pairs `(row, value)` of each feature
+/// sorted in the ascending order of the values.
+fn sort_features(sample: &Sample) -> Vec<Vec<(usize, f64)>> {
+    let n_sample = sample.shape().0;
+    sample.features()
+        .par_iter()
+        .map(|feature| {
+            let mut pairs = (0..n_sample)
+                .map(|row| (row, feature[row]))
+                .collect::<Vec<_>>();
+            pairs.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+            pairs
+        })
+        .collect()
+}
+
+
+/// Returns the condition on a single feature
+/// that maximizes `sign * Σ_i d_i y_i` over the examples
+/// that remain covered,
+/// as the triplet `(mass, threshold, greater)`.
+/// Returns `None` if the covered examples take a single value.
+fn best_condition_on(
+    sorted: &[(usize, f64)],
+    covered: &[bool],
+    target: &[f64],
+    dist: &[f64],
+    sign: f64,
+) -> Option<(f64, f64, bool)>
+{
+    let pairs = sorted.iter()
+        .filter(|&&(i, value)| covered[i] && !value.is_nan())
+        .collect::<Vec<_>>();
+    let inside = pairs.iter()
+        .map(|&&(i, _)| dist[i] * target[i])
+        .sum::<f64>();
+
+    let mut best: Option<(f64, f64, bool)> = None;
+    // `below` is the sum of `d_i y_i` over the examples
+    // below the current threshold.
+    let mut below = 0f64;
+    for (k, &&(i, value)) in pairs.iter().enumerate() {
+        below += dist[i] * target[i];
+
+        let Some(&&(_, next)) = pairs.get(k + 1) else { break; };
+        if value >= next { continue; }
+
+        let threshold = value / 2f64 + next / 2f64;
+        for (mass, greater) in [(below, false), (inside - below, true)] {
+            let better = match best {
+                Some((m, _, _)) => sign * mass > sign * m,
+                None => true,
+            };
+            if better { best = Some((mass, threshold, greater)); }
+        }
+    }
+    best
+}
+
+
+impl WeakLearner for RuleLearner<'_> {
+    type Hypothesis = RuleClassifier;
+
+
+    fn name(&self) -> &str {
+        "Rule Learner"
+    }
+
+
+    fn info(&self) -> Option<Vec<(&str, String)>> {
+        let (n_sample, n_feature) = self.sample.shape();
+        let info = Vec::from([
+            ("# of examples", format!("{n_sample}")),
+            ("# of features", format!("{n_feature}")),
+            ("Max conditions", format!("{}", self.max_conditions)),
+        ]);
+        Some(info)
+    }
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64])
+        -> Self::Hypothesis
+    {
+        let n_sample = sample.shape().0;
+        assert_eq!(
+            dist.len(), n_sample,
+            "The length of the distribution must be the number of examples"
+        );
+
+        let resorted;
+        let sorted = if sample.shares_features_with(self.sample) {
+            &self.sorted
+        } else {
+            resorted = sort_features(sample);
+            &resorted
+        };
+
+        let (positive, conditions) = self.grow(sample, sorted, dist, 1f64);
+        let (negative, others) = self.grow(sample, sorted, dist, -1f64);
+        if positive >= negative {
+            RuleClassifier::new(conditions, 1f64)
+        } else {
+            RuleClassifier::new(others, -1f64)
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Defines the conjunctive rule classifier.
+use serde::{Serialize, Deserialize};
+
+use std::fmt;
+
+use crate::{Classifier, Sample};
+
+
+/// A comparison of a feature with a threshold.
+/// A missing value satisfies no condition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    feature: String,
+    threshold: f64,
+    greater: bool,
+}
+
+
+impl Condition {
+    /// Construct the condition `feature > threshold`
+    /// if `greater` is `true`, and `feature <= threshold` otherwise.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn new<T: ToString>(feature: T, threshold: f64, greater: bool)
+        -> Self
+    {
+        Self { feature: feature.to_string(), threshold, greater, }
+    }
+
+
+    /// Returns the name of the feature that the condition compares.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn feature(&self) -> &str {
+        &self.feature
+    }
+
+
+    /// Returns the threshold of the condition.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+
+    /// Returns `true` if the condition is `feature > threshold`.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn greater(&self) -> bool {
+        self.greater
+    }
+
+
+    /// Returns `true` if `value` satisfies the condition.
+    #[inline]
+    pub(super) fn holds(&self, value: f64) -> bool {
+        if self.greater {
+            value > self.threshold
+        } else {
+            value <= self.threshold
+        }
+    }
+}
+
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = if self.greater { ">" } else { "<=" };
+        write!(f, "{} {op} {}", self.feature, self.threshold)
+    }
+}
+
+
+/// A conjunctive rule
+/// `IF c_1 AND c_2 AND ... THEN sign ELSE -sign`
+/// over the [`Condition`]s `c_1, c_2, ...`.
+/// The rule without conditions predicts `sign` for all examples.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleClassifier {
+    conditions: Vec<Condition>,
+    sign: f64,
+}
+
+
+impl RuleClassifier {
+    /// Construct a new rule.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn new(conditions: Vec<Condition>, sign: f64) -> Self {
+        assert!(
+            sign == 1.0 || sign == -1.0,
+            "The sign of a rule must be `1.0` or `-1.0`"
+        );
+        Self { conditions, sign, }
+    }
+
+
+    /// Returns the conditions of the rule.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn conditions(&self) -> &[Condition] {
+        &self.conditions[..]
+    }
+
+
+    /// Returns the prediction for the examples
+    /// that satisfy all the conditions.
+    ///
+    /// Time complexity: `O(1)`.
+    pub fn sign(&self) -> f64 {
+        self.sign
+    }
+}
+
+
+impl fmt::Display for RuleClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.sign > 0f64 { "+1" } else { "-1" };
+        let other = if self.sign > 0f64 { "-1" } else { "+1" };
+        if self.conditions.is_empty() {
+            return write!(f, "{sign}");
+        }
+        let conditions = self.conditions.iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        write!(f, "IF {conditions} THEN {sign} ELSE {other}")
+    }
+}
+
+
+impl Classifier for RuleClassifier {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let covered = self.conditions.iter()
+            .all(|c| c.holds(sample[&c.feature][row]));
+        if covered { self.sign } else { -self.sign }
+    }
+}
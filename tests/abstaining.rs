@@ -0,0 +1,156 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::training_error;
+
+
+// A specialist that predicts `sign` on the examples
+// with `x > threshold` if `above` is `true`,
+// and with `x <= threshold` otherwise.
+// It abstains on the other examples.
+#[derive(Clone, Debug, PartialEq)]
+struct Specialist {
+    threshold: f64,
+    above: bool,
+    sign: f64,
+}
+
+
+impl Classifier for Specialist {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let x = sample["x"][row];
+        if (x > self.threshold) == self.above { self.sign } else { 0.0 }
+    }
+}
+
+
+// A random sample whose label is positive iff `x > 0.5`,
+// with 10% label noise.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|&a| {
+            let y = if a > 0.5 { 1.0 } else { -1.0 };
+            if rng.gen::<f64>() < 0.1 { -y } else { y }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// The specialists over the grid `{0.05, 0.1, ..., 0.95}`.
+fn specialists() -> Vec<Specialist> {
+    let mut specialists = Vec::new();
+    for k in 1..20 {
+        let threshold = k as f64 * 0.05;
+        for above in [true, false] {
+            for sign in [1.0, -1.0] {
+                specialists.push(Specialist { threshold, above, sign });
+            }
+        }
+    }
+    specialists
+}
+
+
+// Returns the weight of AdaBoost on the hypothesis of `margins`.
+fn adaboost_weight(margins: &[f64], dist: &[f64]) -> f64 {
+    let n_sample = margins.len() as f64;
+    let abstains = margins.contains(&0.0);
+    if abstains {
+        let eps = 0.5 / n_sample;
+        let (mut correct, mut wrong) = (0f64, 0f64);
+        for (&yh, &d) in margins.iter().zip(dist) {
+            if yh > 0.0 { correct += d; }
+            if yh < 0.0 { wrong += d; }
+        }
+        ((correct + eps) / (wrong + eps)).ln() / 2.0
+    } else {
+        let edge = margins.iter().zip(dist).map(|(yh, d)| yh * d).sum::<f64>();
+        ((1.0 + edge) / (1.0 - edge)).ln() / 2.0
+    }
+}
+
+
+#[test]
+fn adaboost_uses_the_weight_for_abstaining_hypotheses() {
+    let sample = random_sample();
+    let n_sample = sample.shape().0;
+    let pool = vec![
+        Specialist { threshold: 0.7, above: true, sign: 1.0 },
+        Specialist { threshold: 0.3, above: false, sign: -1.0 },
+    ];
+    let margins = pool.iter()
+        .map(|h| {
+            (0..n_sample)
+                .map(|i| sample.target()[i] * h.confidence(&sample, i))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // Run two rounds of AdaBoost by hand.
+    let mut dist = vec![1.0 / n_sample as f64; n_sample];
+    let mut expected = Vec::new();
+    for _ in 0..2 {
+        let edges = margins.iter()
+            .map(|m| m.iter().zip(&dist).map(|(yh, d)| yh * d).sum::<f64>())
+            .collect::<Vec<_>>();
+        let best = if edges[1] > edges[0] { 1 } else { 0 };
+        let weight = adaboost_weight(&margins[best], &dist);
+        dist.iter_mut()
+            .zip(&margins[best])
+            .for_each(|(d, yh)| { *d *= (- weight * yh).exp(); });
+        let z = dist.iter().sum::<f64>();
+        dist.iter_mut().for_each(|d| { *d /= z; });
+        expected.push(weight);
+    }
+    let total = expected.iter().sum::<f64>();
+    let expected = expected.into_iter()
+        .map(|w| w / total)
+        .collect::<Vec<_>>();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(2);
+    let f = booster.run(&HypothesisPool::new(pool));
+    assert_eq!(f.weights.len(), 2);
+    f.weights.iter()
+        .zip(&expected)
+        .for_each(|(w, e)| assert!((w - e).abs() < 1e-9, "{w} vs {e}"));
+}
+
+
+#[test]
+fn adaboost_keeps_the_weight_finite_without_mistakes() {
+    // A sample without noise, so that the specialist makes no mistake.
+    let x = (0..10).map(|i| i as f64 / 10.0).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|&a| if a > 0.5 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    let sample = Sample::from_dataframe(data, Series::new("class", target))
+        .unwrap();
+
+    let h = Specialist { threshold: 0.5, above: true, sign: 1.0 };
+    let pool = HypothesisPool::new(vec![h]);
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(1);
+    let f = booster.run(&pool);
+    assert!(f.weights[0].is_finite() && f.weights[0] > 0.0);
+}
+
+
+#[test]
+fn adaboost_combines_abstaining_hypotheses() {
+    let sample = random_sample();
+    let pool = HypothesisPool::new(specialists());
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(50);
+    let f = booster.run(&pool);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.15, "training error: {error}");
+}
@@ -0,0 +1,47 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+fn multiclass_sample() -> Sample {
+    let data = df!(
+        "x" => [9.0, 8.0, 7.0, 1.0, 2.0, 3.0, 2.0, 1.0, 3.0],
+        "y" => [1.0, 3.0, 2.0, 8.0, 9.0, 7.0, 3.0, 2.0, 1.0],
+        "z" => [2.0, 1.0, 3.0, 3.0, 1.0, 2.0, 7.0, 9.0, 8.0],
+    ).unwrap();
+    let target = Series::new(
+        "class", &[0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0]
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn multiclass_sample_has_one_relevant_label() {
+    let sample = MultiLabelSample::from_multiclass(&multiclass_sample())
+        .unwrap();
+    assert_eq!(sample.n_examples(), 9);
+    assert_eq!(sample.labels(), &["0", "1", "2"]);
+    for i in 0..sample.n_examples() {
+        assert_eq!(sample.relevant_labels(i), vec![i / 3]);
+    }
+}
+
+
+#[test]
+fn adaboost_mh_predicts_classes() {
+    let sample = MultiLabelSample::from_multiclass(&multiclass_sample())
+        .unwrap();
+    let dtree = DecisionTreeBuilder::new(sample.sample())
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = AdaBoostMH::init(&sample)
+        .force_quit_at(50);
+    let f = booster.run(&dtree);
+
+    assert!(f.hamming_loss(&sample) < 0.1);
+    let predictions = f.predict_classes(&sample);
+    let expected = (0..9).map(|i| i / 3).collect::<Vec<_>>();
+    assert_eq!(predictions, expected);
+}
@@ -0,0 +1,58 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+fn toy_sample() -> MultiLabelSample {
+    let data = df!(
+        "x" => [10.0, 14.0, 15.0, 5.0, 3.0,  8.0, 12.0, 1.0],
+        "y" => [ 5.0,  8.0,  3.0, 1.0, 9.0, 13.0, 11.0, 2.0],
+    ).unwrap();
+    let targets = df!(
+        "a" => [1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+        "b" => [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0],
+        "c" => [0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0],
+    ).unwrap();
+
+    MultiLabelSample::from_dataframe(data, targets).unwrap()
+}
+
+
+#[test]
+fn multi_label_sample_is_expanded() {
+    let sample = toy_sample();
+    assert_eq!(sample.n_examples(), 8);
+    assert_eq!(sample.n_labels(), 3);
+    assert_eq!(sample.sample().shape(), (24, 5));
+    assert_eq!(sample.relevant_labels(1), vec![0, 2]);
+    assert_eq!(sample.irrelevant_labels(1), vec![1]);
+}
+
+
+#[test]
+fn adaboost_mr_reduces_ranking_loss() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(sample.sample())
+        .max_depth(2)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let mut booster = AdaBoostMR::init(&sample)
+        .force_quit_at(1);
+    let f = booster.run(&dtree);
+    let loss_1 = f.ranking_loss(&sample);
+
+    let mut booster = AdaBoostMR::init(&sample)
+        .force_quit_at(50);
+    let f = booster.run(&dtree);
+    let loss_50 = f.ranking_loss(&sample);
+
+    assert!(loss_50 <= loss_1);
+    assert!(loss_50 < 0.5);
+
+    let rank = f.rank(&sample, 0);
+    assert_eq!(rank.len(), 3);
+
+    let ranks = f.rank_all(&sample);
+    assert_eq!(ranks.len(), 8);
+    assert_eq!(ranks[0], rank);
+}
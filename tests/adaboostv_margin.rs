@@ -0,0 +1,39 @@
+use miniboosts::prelude::*;
+use miniboosts::research::ObjectiveFunction;
+use miniboosts::HardMarginObjective;
+use polars::prelude::*;
+
+
+// The label is positive iff `x > 5`.
+fn toy_sample() -> Sample {
+    let data = df!(
+        "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0],
+        "y" => [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [-1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn adaboostv_reports_margin() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = AdaBoostV::<DecisionTreeClassifier>::init(&sample)
+        .tolerance(0.1);
+    assert!(booster.margin().is_none());
+    let f = booster.run(&tree);
+
+    let margin = booster.margin().unwrap();
+    let objective = HardMarginObjective::new().eval(&sample, &f);
+    assert!((margin - objective).abs() < 1e-9);
+    assert!(margin <= booster.edge_estimate() + 1e-9);
+    assert!(margin > 0.0);
+}
@@ -0,0 +1,79 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+use polars::prelude::*;
+
+
+fn toy_sample() -> Sample {
+    let s1 = Series::new("x", &[10.0, 14.0, 15.0, 5.0, 3.0,  8.0, 12.0]);
+    let s2 = Series::new("y", &[ 5.0,  8.0,  3.0, 1.0, 9.0, 13.0, 11.0]);
+    let df = DataFrame::new(vec![s1, s2]).unwrap();
+    let target = Series::new(
+        "class", &[1.0, 1.0, -1.0, -1.0, -1.0, 1.0, -1.0]
+    );
+
+    Sample::from_dataframe(df, target).unwrap()
+}
+
+
+#[test]
+fn lpboost_anneals_to_the_target_nu() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let nu = 1.0;
+    let mut booster = LPBoost::init(&sample)
+        .tolerance(0.1)
+        .nu(nu)
+        .anneal_nu(5.0, 4);
+    let f = booster.run(&dtree);
+    assert!(booster.n_rounds() > 4);
+
+    // The final weights are optimal for the target `nu`
+    // over the obtained hypotheses.
+    let g = f.refit_weights(&sample, nu);
+    let objective = SoftMarginObjective::new(nu);
+    let f_obj = objective.eval(&sample, &f);
+    let g_obj = objective.eval(&sample, &g);
+    assert!((f_obj - g_obj).abs() < 1e-4);
+}
+
+
+#[test]
+fn erlpboost_anneals_to_the_target_nu() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let (tolerance, nu) = (0.1, 1.0);
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu)
+        .anneal_nu(5.0, 4);
+    let f = booster.run(&dtree);
+    assert!(booster.n_rounds() > 4);
+
+    // The final weights are optimal for the target `nu`
+    // over the obtained hypotheses.
+    let g = f.refit_weights(&sample, nu);
+    let objective = SoftMarginObjective::new(nu);
+    let f_obj = objective.eval(&sample, &f);
+    let g_obj = objective.eval(&sample, &g);
+    assert!((f_obj - g_obj).abs() < 1e-4);
+
+    // The annealed run attains the soft margin of the cold run.
+    let h = ERLPBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu)
+        .run(&dtree);
+    let h_obj = objective.eval(&sample, &h);
+    assert!(
+        (f_obj - h_obj).abs() <= tolerance,
+        "the annealed run attains {f_obj} while the cold run attains {h_obj}"
+    );
+}
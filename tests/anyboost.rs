@@ -0,0 +1,144 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{quadrant_sample, zero_one_loss};
+
+
+// A user-defined loss function, `exp(-y F)`.
+struct ExponentialLoss;
+
+impl LossFunction for ExponentialLoss {
+    fn name(&self) -> &str {
+        "Exponential loss"
+    }
+
+
+    fn eval_at_point(&self, prediction: f64, true_value: f64) -> f64 {
+        (- true_value * prediction).exp()
+    }
+
+
+    fn gradient(&self, predictions: &[f64], target: &[f64]) -> Vec<f64> {
+        predictions.iter()
+            .zip(target)
+            .map(|(p, y)| - y * (- y * p).exp())
+            .collect()
+    }
+
+
+    fn hessian(&self, predictions: &[f64], target: &[f64]) -> Vec<f64> {
+        predictions.iter()
+            .zip(target)
+            .map(|(p, y)| (- y * p).exp())
+            .collect()
+    }
+
+
+    // The coefficient from the zero scores,
+    // where `target` plays the role of the residuals.
+    fn best_coefficient(&self, target: &[f64], predictions: &[f64]) -> f64 {
+        let zeros = vec![0.0; target.len()];
+        self.best_coefficient_at(&zeros[..], target, predictions)
+    }
+
+
+    // For the predictions in `{-1, +1}`, the exact line search is
+    // `½ ln(weighted correct / weighted wrong)`
+    // with the weights `exp(-y F)`.
+    fn best_coefficient_at(
+        &self,
+        scores: &[f64],
+        target: &[f64],
+        predictions: &[f64],
+    ) -> f64
+    {
+        let (correct, wrong) = scores.iter()
+            .zip(target)
+            .zip(predictions)
+            .fold((0.0, 0.0), |(correct, wrong), ((s, y), p)| {
+                let w = (- y * s).exp();
+                if y * p > 0.0 {
+                    (correct + w, wrong)
+                } else {
+                    (correct, wrong + w)
+                }
+            });
+        0.5 * (correct / wrong).ln()
+    }
+}
+
+
+fn run<L>(loss: L, step_size: StepSize) -> f64
+    where L: LossFunction,
+{
+    let sample = quadrant_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = AnyBoost::init_with_loss(&sample, loss)
+        .step_size(step_size)
+        .max_iter(50);
+    let f = booster.run(&tree);
+
+    assert!(booster.terminated() <= 50);
+    assert!(booster.objective_value() < 2f64.ln());
+    zero_one_loss(&sample, &f)
+}
+
+
+#[test]
+fn exponential_loss_best_coefficient() {
+    let target = [1.0, 1.0, 1.0, -1.0];
+    let predictions = [1.0, 1.0, -1.0, -1.0];
+    let coef = ExponentialLoss.best_coefficient(&target, &predictions);
+    assert!((coef - 0.5 * 3f64.ln()).abs() < 1e-12);
+
+    // The same coefficient minimizes the loss along `predictions`.
+    let scores = [0.5, -0.5, 0.0, 0.2];
+    let coef = ExponentialLoss.best_coefficient_at(
+        &scores, &target, &predictions
+    );
+    let loss = |c: f64| {
+        scores.iter()
+            .zip(&target)
+            .zip(&predictions)
+            .map(|((s, y), p)| ExponentialLoss.eval_at_point(s + c * p, *y))
+            .sum::<f64>()
+    };
+    assert!(loss(coef) <= loss(coef + 1e-3));
+    assert!(loss(coef) <= loss(coef - 1e-3));
+}
+
+
+#[test]
+fn anyboost_exponential_line_search() {
+    let error = run(ExponentialLoss, StepSize::LineSearch);
+    assert_eq!(error, 0.0);
+}
+
+
+#[test]
+fn anyboost_exponential_armijo() {
+    let error = run(ExponentialLoss, StepSize::Armijo);
+    assert_eq!(error, 0.0);
+}
+
+
+#[test]
+fn anyboost_logistic_fixed() {
+    let error = run(GBMLoss::LogLoss, StepSize::Fixed(0.5));
+    assert_eq!(error, 0.0);
+}
+
+
+#[test]
+#[should_panic]
+fn anyboost_non_positive_step() {
+    let sample = quadrant_sample();
+    let _ = AnyBoost::<DecisionTreeClassifier, _>::init_with_loss(
+            &sample, GBMLoss::LogLoss
+        )
+        .step_size(StepSize::Fixed(0.0));
+}
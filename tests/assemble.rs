@@ -0,0 +1,102 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random sample whose label is positive iff `x + y > 1`
+// with the margin `|x + y - 1| > 0.2`.
+// Returns the sample with the first `n_labeled` rows labeled
+// and the true labels.
+fn random_sample(n_labeled: usize) -> (Sample, Vec<f64>) {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 400;
+    let (mut x, mut y) = (Vec::new(), Vec::new());
+    while x.len() < n_sample {
+        let (a, b) = (rng.gen::<f64>(), rng.gen::<f64>());
+        if (a + b - 1.0).abs() > 0.2 {
+            x.push(a);
+            y.push(b);
+        }
+    }
+    let labels = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| if a + b > 1.0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let target = labels.iter()
+        .enumerate()
+        .map(|(i, y)| (i < n_labeled).then_some(*y))
+        .collect::<Vec<_>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    let sample = Sample::from_dataframe(data, Series::new("class", target))
+        .unwrap();
+    (sample, labels)
+}
+
+
+fn run(sample: &Sample, booster: ASSEMBLE<'_, DecisionTreeClassifier>)
+    -> WeightedMajority<DecisionTreeClassifier>
+{
+    let mut booster = booster.max_iter(50);
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+    booster.run(&tree)
+}
+
+
+#[test]
+fn null_targets_are_unlabeled() {
+    let (sample, _) = random_sample(30);
+    assert_eq!(sample.labeled_rows(), (0..30).collect::<Vec<_>>());
+    assert_eq!(sample.unlabeled_rows(), (30..400).collect::<Vec<_>>());
+    assert!(sample.is_unlabeled(30));
+    assert!(!sample.is_unlabeled(29));
+
+    let sample = sample.with_unlabeled([0, 1]);
+    assert_eq!(sample.labeled_rows(), (2..30).collect::<Vec<_>>());
+}
+
+
+#[test]
+fn accuracy_on_unlabeled_examples() {
+    let (sample, labels) = random_sample(40);
+    let f = run(&sample, ASSEMBLE::init(&sample).unlabeled_weight(0.1));
+
+    let predictions = f.predict_all(&sample);
+    let n_correct = predictions.iter()
+        .zip(&labels[..])
+        .skip(40)
+        .filter(|(p, y)| **p as f64 == **y)
+        .count();
+    let accuracy = n_correct as f64 / 360.0;
+    assert!(accuracy > 0.9, "accuracy: {accuracy}");
+}
+
+
+#[test]
+fn zero_weight_ignores_unlabeled_examples() {
+    let (sample, _) = random_sample(40);
+    let f = run(&sample, ASSEMBLE::init(&sample).unlabeled_weight(0.0));
+
+
+    // Labeling the unlabeled examples arbitrarily changes nothing.
+    let (other, _) = random_sample(400);
+    let other = other.with_unlabeled((40..400).collect::<Vec<_>>());
+    let g = run(&other, ASSEMBLE::init(&other).unlabeled_weight(0.0));
+    assert_eq!(f.weights, g.weights);
+
+
+    // Only the labeled examples are used.
+    let h = run(&sample, ASSEMBLE::init(&sample).unlabeled_weight(0.2));
+    assert_ne!(f.weights, h.weights);
+}
+
+
+#[test]
+#[should_panic]
+fn no_labeled_example() {
+    let (sample, _) = random_sample(0);
+    let _ = run(&sample, ASSEMBLE::init(&sample));
+}
+
@@ -0,0 +1,98 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+// Returns the number of false negatives and false positives.
+fn errors<H: Classifier>(sample: &Sample, f: &H) -> (usize, usize) {
+    let predictions = f.predict_all(sample);
+    let mut fn_fp = (0, 0);
+    for (y, p) in sample.target().iter().zip(predictions) {
+        if *y > 0.0 && p < 0 { fn_fp.0 += 1; }
+        if *y < 0.0 && p > 0 { fn_fp.1 += 1; }
+    }
+    fn_fp
+}
+
+
+fn run(sample: &Sample, booster: AsymmetricAdaBoost<'_, DecisionTreeClassifier>)
+    -> ThresholdClassifier<DecisionTreeClassifier>
+{
+    let mut booster = booster.max_iter(50);
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(2)
+        .criterion(Criterion::Entropy)
+        .build();
+    booster.run(&tree)
+}
+
+
+#[test]
+fn costly_positives() {
+    let sample = diagonal_sample(400, 0.2, 1234);
+
+    let uniform = run(&sample, AsymmetricAdaBoost::init(&sample));
+    let costly = run(&sample, AsymmetricAdaBoost::init(&sample).class_costs(10.0, 1.0));
+
+    let (fn_u, fp_u) = errors(&sample, &uniform);
+    let (fn_c, fp_c) = errors(&sample, &costly);
+
+    // AsymmetricAdaBoost trades false positives for false negatives.
+    assert!(fn_c < fn_u, "FN: {fn_c} (costly), {fn_u} (uniform)");
+    let cost = |fneg: usize, fpos: usize| 10 * fneg + fpos;
+    assert!(
+        cost(fn_c, fp_c) < cost(fn_u, fp_u),
+        "cost: {} (costly), {} (uniform)",
+        cost(fn_c, fp_c), cost(fn_u, fp_u)
+    );
+}
+
+
+#[test]
+fn class_costs_are_example_costs() {
+    let sample = diagonal_sample(400, 0.2, 1234);
+    let costs = sample.target()
+        .iter()
+        .map(|y| if *y > 0.0 { 10.0 } else { 1.0 })
+        .collect::<Vec<_>>();
+
+    let f = run(&sample, AsymmetricAdaBoost::init(&sample).class_costs(10.0, 1.0));
+    let g = run(&sample, AsymmetricAdaBoost::init(&sample).costs(costs));
+    assert_eq!(f.majority.weights, g.majority.weights);
+    assert_eq!(f.threshold, g.threshold);
+}
+
+
+#[test]
+fn threshold_minimizes_training_cost() {
+    let sample = diagonal_sample(400, 0.2, 1234);
+    let f = run(&sample, AsymmetricAdaBoost::init(&sample).class_costs(10.0, 1.0));
+    let (fneg, fpos) = errors(&sample, &f);
+    let best = 10 * fneg + fpos;
+
+
+    // No other threshold achieves a smaller cost.
+    let confidences = f.majority.confidence_all(&sample);
+    for theta in confidences.iter() {
+        let cost = confidences.iter()
+            .zip(sample.target())
+            .map(|(c, y)| {
+                let positive = *c >= *theta;
+                if *y > 0.0 && !positive { 10 }
+                else if *y < 0.0 && positive { 1 }
+                else { 0 }
+            })
+            .sum::<usize>();
+        assert!(best <= cost, "threshold {theta}: {cost} < {best}");
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn negative_costs() {
+    let sample = diagonal_sample(400, 0.2, 1234);
+    let _ = AsymmetricAdaBoost::<DecisionTreeClassifier>::init(&sample)
+        .class_costs(-1.0, 1.0);
+}
@@ -0,0 +1,131 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+use miniboosts::datasets::make_gaussian_blobs;
+
+mod common;
+use common::Stump;
+
+
+const GRID: [f64; 6] = [8.0, 1.0, 3.0, 20.0, 5.0, 10.0];
+const TOLERANCE: f64 = 1e-3;
+
+
+fn stumps() -> HypothesisPool<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["Feat. [1]", "Feat. [2]"] {
+        for threshold in -10..=10 {
+            for sign in [1.0, -1.0] {
+                let threshold = threshold as f64;
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    HypothesisPool::new(stumps)
+}
+
+
+// The training and the validation samples share the centers,
+// which depend only on the seed.
+fn samples() -> (Sample, Sample) {
+    let train = make_gaussian_blobs(60, 2, 2, 4.0, 1);
+    let validation = make_gaussian_blobs(300, 2, 2, 3.0, 1);
+    (train, validation)
+}
+
+
+// The pair of the accuracy and the average margin of `f` on `sample`,
+// by which `auto_nu` compares the models.
+fn validation_score<H: Classifier>(sample: &Sample, f: &H) -> (f64, f64) {
+    let n_sample = sample.shape().0 as f64;
+    let margins = sample.target()
+        .iter()
+        .enumerate()
+        .map(|(i, y)| y * f.confidence(sample, i))
+        .collect::<Vec<_>>();
+    let accuracy = margins.iter()
+        .filter(|&&yh| yh > 0.0)
+        .count() as f64
+        / n_sample;
+    let margin = margins.iter().sum::<f64>() / n_sample;
+    (accuracy, margin)
+}
+
+
+// Checks that `chosen` is the best score among `scores`
+// up to the precision of the solver.
+fn assert_best_score(chosen: (f64, f64), scores: &[(f64, f64)]) {
+    for &score in scores {
+        let is_better = chosen.0 > score.0
+            || chosen.0 == score.0 && chosen.1 >= score.1 - 1e-6;
+        assert!(is_better, "{chosen:?} is worse than {score:?}");
+    }
+}
+
+
+#[test]
+fn lpboost_chooses_the_best_nu_on_the_grid() {
+    let (sample, validation) = samples();
+    let pool = stumps();
+
+    let mut booster = LPBoost::init(&sample)
+        .tolerance(TOLERANCE);
+    let (nu, f) = booster.auto_nu(&pool, &validation, &GRID);
+    assert!(GRID.contains(&nu));
+
+    let scores = GRID.map(|nu| {
+        let f = LPBoost::init(&sample)
+            .tolerance(TOLERANCE)
+            .nu(nu)
+            .run(&pool);
+        validation_score(&validation, &f)
+    });
+    assert_best_score(validation_score(&validation, &f), &scores);
+}
+
+
+#[test]
+fn erlpboost_chooses_the_best_nu_on_the_grid() {
+    let (sample, validation) = samples();
+    let pool = stumps();
+
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(TOLERANCE);
+    let (nu, f) = booster.auto_nu(&pool, &validation, &GRID);
+    assert!(GRID.contains(&nu));
+
+    let scores = GRID.map(|nu| {
+        let f = ERLPBoost::init(&sample)
+            .tolerance(TOLERANCE)
+            .nu(nu)
+            .run(&pool);
+        validation_score(&validation, &f)
+    });
+    assert_best_score(validation_score(&validation, &f), &scores);
+}
+
+
+#[test]
+fn erlpboost_warm_start_attains_the_soft_margin() {
+    let (sample, validation) = samples();
+    let pool = stumps();
+
+    // The models for the `nu` but the smallest are warm-started.
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(TOLERANCE);
+    let (nu, f) = booster.auto_nu(&pool, &validation, &GRID);
+    assert!(nu > 1.0, "the model for nu = {nu} is not warm-started");
+
+    let g = LPBoost::init(&sample)
+        .tolerance(TOLERANCE)
+        .nu(nu)
+        .run(&pool);
+    let objective = SoftMarginObjective::new(nu);
+    let warm = objective.eval(&sample, &f);
+    let cold = objective.eval(&sample, &g);
+    assert!(
+        (warm - cold).abs() <= TOLERANCE,
+        "nu = {nu}: warm-started ERLPBoost attains {warm}, \
+         LPBoost attains {cold}"
+    );
+}
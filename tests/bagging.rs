@@ -0,0 +1,58 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{accuracy, diagonal_sample};
+
+
+#[test]
+fn bagging_fits() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let test = diagonal_sample(300, 0.1, 5678);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut bagging = Bagging::init(&sample)
+        .n_estimators(50)
+        .sample_ratio(0.8);
+    let f = bagging.run(&tree);
+    assert_eq!(f.hypotheses.len(), 50);
+    assert_eq!(bagging.terminated(), 50);
+    assert!(f.weights.iter().all(|w| (w - 1.0 / 50.0).abs() < 1e-12));
+
+    let accuracy = accuracy(&f, &test);
+    assert!(accuracy > 0.8, "test accuracy: {accuracy}");
+}
+
+
+#[test]
+fn parallel_run_agrees_with_sequential_run() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut sequential = Bagging::init(&sample)
+        .n_estimators(20)
+        .seed(7);
+    let f = sequential.run(&tree);
+
+    let mut parallel = Bagging::init(&sample)
+        .n_estimators(20)
+        .seed(7);
+    let g = parallel.run_parallel(&tree);
+
+    assert_eq!(f.hypotheses, g.hypotheses);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+}
+
+
+#[test]
+#[should_panic]
+fn zero_estimators_panics() {
+    let sample = diagonal_sample(10, 0.1, 1234);
+    let _ = Bagging::<DecisionTreeClassifier>::init(&sample)
+        .n_estimators(0);
+}
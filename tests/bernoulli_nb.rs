@@ -0,0 +1,94 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{training_error, uniform};
+
+
+// A random sample of binary features `a, b, c`
+// whose label is positive iff `a` and `b` are both on.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let mut bits = || {
+        (0..n_sample)
+            .map(|_| if rng.gen::<bool>() { 1.0 } else { 0.0 })
+            .collect::<Vec<f64>>()
+    };
+    let a = bits();
+    let b = bits();
+    let c = bits();
+    let target = a.iter()
+        .zip(&b[..])
+        .map(|(x, y)| if x + y > 1.5 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("a" => a, "b" => b, "c" => c).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn bernoulli_nb_fits_binary_features() {
+    let sample = random_sample();
+    let f = BernoulliNB::init()
+        .binarize(0.5)
+        .produce(&sample, &uniform(&sample));
+    let error = training_error(&f, &sample);
+    assert!(error < 0.2, "training error: {error}");
+}
+
+
+#[test]
+fn bernoulli_nb_follows_the_distribution() {
+    let sample = random_sample();
+    let target = sample.target();
+    // Put all the weight on the positive examples.
+    let n_positive = target.iter().filter(|&&y| y > 0.0).count() as f64;
+    let dist = target.iter()
+        .map(|&y| if y > 0.0 { 1.0 / n_positive } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    let f = BernoulliNB::init()
+        .binarize(0.5)
+        .produce(&sample, &dist);
+    assert!(f.predict_all(&sample).into_iter().all(|p| p == 1));
+}
+
+
+#[test]
+fn missing_values_are_ignored() {
+    let sample = random_sample();
+    let f = BernoulliNB::init()
+        .binarize(0.5)
+        .produce(&sample, &uniform(&sample));
+
+    let data = df!(
+        "a" => [Some(1.0), None],
+        "b" => [Some(1.0), None],
+        "c" => [None, Some(0.0)],
+    ).unwrap();
+    let test = Sample::from_dataframe(data, Series::new("class", [1.0, -1.0]))
+        .unwrap();
+    assert_eq!(f.predict(&test, 0), 1);
+}
+
+
+#[test]
+fn adaboost_fits_with_bernoulli_nb() {
+    let sample = random_sample();
+    let nbayes = BernoulliNB::init().binarize(0.5);
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&nbayes);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.2, "training error: {error}");
+}
+
+
+#[test]
+#[should_panic]
+fn non_positive_alpha_panics() {
+    let _ = BernoulliNB::init().alpha(0.0);
+}
@@ -0,0 +1,113 @@
+use miniboosts::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::diagonal_sample_with_missing_values;
+
+
+fn random_distribution(n_sample: usize, rng: &mut StdRng) -> Vec<f64> {
+    let dist = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let sum = dist.iter().sum::<f64>();
+    dist.into_iter().map(|d| d / sum).collect()
+}
+
+
+#[test]
+fn cached_decision_tree_matches_the_uncached_one() {
+    let sample = diagonal_sample_with_missing_values(300);
+    // `copy` has the values of `sample` but does not share its features.
+    let copy = diagonal_sample_with_missing_values(300);
+    let n_sample = sample.shape().0;
+    for n_bins in [None, Some(16)] {
+        let mut builder = DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .criterion(Criterion::Entropy);
+        if let Some(n_bins) = n_bins {
+            builder = builder.bins(n_bins);
+        }
+        let tree = builder.build();
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..5 {
+            let dist = random_distribution(n_sample, &mut rng);
+            // `sample` hits the cache, while `copy` does not.
+            let f = tree.produce(&sample, &dist);
+            let g = tree.produce(&copy, &dist);
+            assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+        }
+    }
+}
+
+
+#[test]
+fn cached_bundled_tree_matches_the_uncached_one() {
+    let sample = diagonal_sample_with_missing_values(300);
+    let copy = diagonal_sample_with_missing_values(300);
+    let n_sample = sample.shape().0;
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .criterion(Criterion::Entropy)
+        .bundle_exclusive_features(true)
+        .build();
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..5 {
+        let dist = random_distribution(n_sample, &mut rng);
+        let f = tree.produce(&sample, &dist);
+        let g = tree.produce(&copy, &dist);
+        assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+    }
+}
+
+
+#[test]
+fn cached_regression_tree_matches_the_uncached_one() {
+    let sample = diagonal_sample_with_missing_values(300);
+    let copy = diagonal_sample_with_missing_values(300);
+    let n_sample = sample.shape().0;
+    for n_bins in [None, Some(16)] {
+        let mut builder = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2);
+        if let Some(n_bins) = n_bins {
+            builder = builder.bins(n_bins);
+        }
+        let tree = builder.build();
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..5 {
+            let predictions = (0..n_sample)
+                .map(|_| rng.gen::<f64>() - 0.5)
+                .collect::<Vec<_>>();
+            let f = tree.produce(&sample, &predictions);
+            let g = tree.produce(&copy, &predictions);
+            f.predict_all(&sample)
+                .into_iter()
+                .zip(g.predict_all(&sample))
+                .for_each(|(p, q)| assert!((p - q).abs() < 1e-9, "{p} != {q}"));
+        }
+    }
+}
+
+
+#[test]
+fn adaboost_with_cached_trees() {
+    let sample = diagonal_sample_with_missing_values(300);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(50);
+    let f = booster.run(&tree);
+
+    let n_sample = sample.shape().0 as f64;
+    let error = f.predict_all(&sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(p, y)| *p as f64 != **y)
+        .count() as f64
+        / n_sample;
+    assert!(error < 0.1, "training error: {error}");
+}
@@ -0,0 +1,44 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+// The label is positive iff `x > 5`, except the last two noisy examples.
+fn noisy_sample() -> Sample {
+    let data = df!(
+        "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 2.5, 8.5],
+        "y" => [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [-1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0],
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn brownboost_runs_out_of_time() {
+    let sample = noisy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = BrownBoost::init(&sample)
+        .c(2.0)
+        .force_quit_at(200);
+    let f = booster.run(&tree);
+
+    assert!(booster.terminated() < 200);
+    assert_eq!(booster.remaining_time(), 0.0);
+
+    // The noisy examples are given up.
+    let target = sample.target();
+    let predictions = f.predict_all(&sample);
+    let n_correct = target.iter()
+        .zip(&predictions[..])
+        .take(10)
+        .filter(|(y, p)| **y as i64 == **p)
+        .count();
+    assert_eq!(n_correct, 10);
+}
@@ -0,0 +1,65 @@
+use miniboosts::prelude::*;
+
+use std::io::Write;
+
+
+// Toy example with two one-hot encoded categorical features
+// (`Feat. [1]` -- `Feat. [3]` and `Feat. [4]` -- `Feat. [5]`)
+// and a numerical feature `Feat. [6]` that never takes zero.
+const SVMLIGHT: &str = "\
++1 0:1 3:1 5:2.0
++1 0:1 4:1 5:1.0
++1 0:1 3:1 5:3.0
+-1 1:1 3:1 5:2.5
+-1 1:1 4:1 5:0.5
++1 2:1 4:1 5:4.0
+-1 2:1 3:1 5:1.5
+-1 1:1 4:1 5:3.5
++1 2:1 4:1 5:2.0
+-1 2:1 3:1 5:0.5
+";
+
+
+fn one_hot_sample() -> Sample {
+    let mut path = std::env::temp_dir();
+    path.push(format!("miniboosts_bundle_{}.svmlight", std::process::id()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    file.write_all(SVMLIGHT.as_bytes()).unwrap();
+
+    let sample = SampleReader::<_, &str>::new()
+        .file(&path)
+        .read()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    sample
+}
+
+
+#[test]
+fn bundled_tree_is_same_as_plain_tree() {
+    let sample = one_hot_sample();
+    let n_sample = sample.shape().0;
+    let dist = vec![1f64 / n_sample as f64; n_sample];
+
+    for criterion in [Criterion::Entropy, Criterion::Edge] {
+        let plain = DecisionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .criterion(criterion)
+            .build();
+        let bundled = DecisionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .criterion(criterion)
+            .bundle_exclusive_features(true)
+            .build();
+
+        let info = bundled.info().unwrap();
+        let (_, n_bundles) = info.iter()
+            .find(|(key, _)| *key == "# of bundles")
+            .unwrap();
+        assert_eq!(n_bundles, "3 (6 features)");
+
+        let f = plain.produce(&sample, &dist);
+        let g = bundled.produce(&sample, &dist);
+        assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+    }
+}
@@ -0,0 +1,111 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{training_error, uniform};
+
+
+// A random sample with a categorical feature `c` of 8 categories
+// and a numerical feature `x`.
+// The label is positive iff `c` is in `{1, 4, 6}`.
+fn categorical_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 400;
+    let c = (0..n_sample)
+        .map(|_| rng.gen_range(0..8) as f64)
+        .collect::<Vec<_>>();
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = c.iter()
+        .map(|&c| if [1.0, 4.0, 6.0].contains(&c) { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("c" => c, "x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn set_categorical_marks_the_features() {
+    let sample = categorical_sample().set_categorical(["c"]);
+    assert!(sample.is_categorical("c"));
+    assert!(!sample.is_categorical("x"));
+}
+
+
+#[test]
+fn a_subset_split_separates_the_categories() {
+    let numerical = categorical_sample();
+    let categorical = categorical_sample().set_categorical(["c"]);
+
+    for criterion in [Criterion::Entropy, Criterion::Gini, Criterion::Edge] {
+        let tree = DecisionTreeBuilder::new(&categorical)
+            .max_depth(1)
+            .criterion(criterion)
+            .build();
+        let f = tree.produce(&categorical, &uniform(&categorical));
+        assert_eq!(training_error(&f, &categorical), 0.0, "{criterion}");
+
+        // A threshold on the codes cannot separate `{1, 4, 6}`.
+        let tree = DecisionTreeBuilder::new(&numerical)
+            .max_depth(1)
+            .criterion(criterion)
+            .build();
+        let f = tree.produce(&numerical, &uniform(&numerical));
+        assert!(training_error(&f, &numerical) > 0.0, "{criterion}");
+    }
+}
+
+
+#[test]
+fn categorical_splits_are_deterministic() {
+    let sample = categorical_sample().set_categorical(["c"]);
+    let dist = uniform(&sample);
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .criterion(Criterion::Entropy)
+        .build();
+    let f = tree.produce(&sample, &dist);
+    let g = tree.produce(&sample, &dist);
+    assert_eq!(f, g);
+
+    let hist = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .criterion(Criterion::Entropy)
+        .bins(64)
+        .build();
+    let h = hist.produce(&sample, &dist);
+    assert_eq!(f.predict_all(&sample), h.predict_all(&sample));
+}
+
+
+#[test]
+fn adaboost_with_categorical_features() {
+    let sample = categorical_sample().set_categorical(["c"]);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(10);
+    let f = booster.run(&tree);
+    assert_eq!(training_error(&f, &sample), 0.0);
+}
+
+
+#[test]
+#[should_panic]
+fn too_many_categories_panics() {
+    let sample = categorical_sample().set_categorical(["c"]);
+    let _ = DecisionTreeBuilder::new(&sample)
+        .max_categories(4)
+        .build();
+}
+
+
+#[test]
+#[should_panic]
+fn non_integer_categories_panic() {
+    let _ = categorical_sample().set_categorical(["x"]);
+}
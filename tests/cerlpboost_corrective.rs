@@ -0,0 +1,39 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+// The label is positive iff `x > 4`.
+fn toy_sample() -> Sample {
+    let data = df!(
+        "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+        "y" => [2.0, 7.0, 1.0, 8.0, 3.0, 6.0, 4.0, 5.0],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [-1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0],
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn cerlpboost_separable() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = CERLPBoost::init(&sample)
+        .tolerance(0.1)
+        .nu(1.0);
+    let f = booster.run(&tree);
+
+    assert!(booster.terminated() <= booster.max_loop());
+
+    let target = sample.target();
+    let predictions = f.predict_all(&sample);
+    target.iter()
+        .zip(predictions)
+        .for_each(|(y, p)| { assert_eq!(*y as i64, p); });
+}
@@ -0,0 +1,125 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+use miniboosts::datasets::{make_noisy_margin, noisy_margin_optimum};
+
+mod common;
+use common::{Stump, toy_sample};
+
+
+#[test]
+fn lpboost_certifies_the_tolerance() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let tolerance = 0.1;
+    let mut booster = LPBoost::init(&sample)
+        .tolerance(tolerance);
+    let _ = booster.run(&dtree);
+
+    let certificate = booster.certificate();
+    assert_eq!(certificate.gap(), booster.gap());
+    assert!(certificate.is_within(tolerance));
+}
+
+
+#[test]
+fn erlpboost_certifies_the_tolerance() {
+    let (n_sample, n_flipped, nu) = (100, 10, 40.0);
+    let sample = make_noisy_margin(n_sample, n_flipped, 1234);
+    let feature = "Feat. [1]";
+    let mut stumps = Vec::new();
+    for threshold in [-2.0, 0.0, 2.0] {
+        for sign in [1.0, -1.0] {
+            stumps.push(Stump { feature, threshold, sign });
+        }
+    }
+
+    let tolerance = 0.1;
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu);
+    let f = booster.run(&HypothesisPool::new(stumps));
+
+    assert!(booster.terminated() < usize::MAX);
+    assert!(booster.certificate().is_within(tolerance / 2.0));
+
+    let optimum = noisy_margin_optimum(n_sample, n_flipped, nu);
+    let objective = SoftMarginObjective::new(nu).eval(&sample, &f);
+    assert!(
+        optimum - objective <= tolerance,
+        "soft margin {objective} is not within {tolerance} of {optimum}"
+    );
+}
+
+
+#[test]
+fn erlpboost_matches_lpboost() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let (tolerance, nu) = (0.1, 2.0);
+    let f = LPBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu)
+        .run(&dtree);
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu);
+    let g = booster.run(&dtree);
+
+    assert!(booster.terminated() < usize::MAX);
+    assert!(booster.certificate().is_within(tolerance / 2.0));
+
+    let objective = SoftMarginObjective::new(nu);
+    let lpboost = objective.eval(&sample, &f);
+    let erlpboost = objective.eval(&sample, &g);
+    assert!(
+        (lpboost - erlpboost).abs() <= tolerance,
+        "ERLPBoost attains {erlpboost} while LPBoost attains {lpboost}"
+    );
+}
+
+
+#[test]
+fn erlpboost_with_fixed_sub_tolerance() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let (tolerance, nu) = (0.1, 2.0);
+    let mut adaptive = ERLPBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu);
+    let f = adaptive.run(&dtree);
+
+    let mut fixed = ERLPBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu)
+        .sub_tolerance(1e-9);
+    let g = fixed.run(&dtree);
+
+    assert!(!f.hypotheses.is_empty());
+    assert!(!g.hypotheses.is_empty());
+    assert!(adaptive.terminated() < usize::MAX);
+    assert!(fixed.terminated() < usize::MAX);
+    assert!(adaptive.certificate().is_within(tolerance / 2.0));
+    assert!(fixed.certificate().is_within(tolerance / 2.0));
+
+    let objective = SoftMarginObjective::new(nu);
+    let adaptive = objective.eval(&sample, &f);
+    let fixed = objective.eval(&sample, &g);
+    assert!(
+        (adaptive - fixed).abs() <= tolerance,
+        "the adaptive sub-tolerance attains {adaptive} \
+         while the fixed one attains {fixed}"
+    );
+}
@@ -0,0 +1,163 @@
+use miniboosts::prelude::*;
+use rand::prelude::*;
+
+use std::io::Write;
+use std::path::PathBuf;
+
+
+// Writes a random CSV file of `n_sample` rows
+// whose label is positive iff `x + y > 1`,
+// where some values of `y` are missing.
+fn write_csv(name: &str, n_sample: usize, has_header: bool) -> PathBuf {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let mut path = std::env::temp_dir();
+    path.push(format!("miniboosts_{name}_{}.csv", std::process::id()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    if has_header { writeln!(file, "x,class,y").unwrap(); }
+    for i in 0..n_sample {
+        let x = rng.gen::<f64>();
+        let y = rng.gen::<f64>();
+        let class = if x + y > 1.0 { 1 } else { -1 };
+        if i % 9 == 0 {
+            writeln!(file, "{x},{class},").unwrap();
+        } else {
+            writeln!(file, "{x},{class},{y}").unwrap();
+        }
+    }
+    path
+}
+
+
+fn same(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+
+fn assert_same_samples(a: &Sample, b: &Sample) {
+    assert_eq!(a.shape(), b.shape());
+    assert_eq!(a.target(), b.target());
+    for (f, g) in a.features().iter().zip(b.features()) {
+        assert_eq!(f.name(), g.name());
+        (0..a.shape().0).for_each(|i| assert!(same(f[i], g[i])));
+    }
+}
+
+
+#[test]
+fn chunks_make_up_the_whole_file() {
+    let path = write_csv("chunks", 1000, true);
+    let whole = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .read()
+        .unwrap();
+    let chunks = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .chunk_size(300)
+        .read_chunks()
+        .unwrap();
+    assert_eq!(chunks.feature_names(), ["x", "y"]);
+
+    let chunks = chunks.collect::<std::io::Result<Vec<_>>>().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let sizes = chunks.iter().map(|c| c.shape().0).collect::<Vec<_>>();
+    assert_eq!(sizes, [300, 300, 300, 100]);
+
+    let mut chunks = chunks.into_iter();
+    let mut sample = chunks.next().unwrap();
+    chunks.for_each(|chunk| sample.extend_from(&chunk).unwrap());
+    assert_same_samples(&sample, &whole);
+}
+
+
+#[test]
+fn chunks_of_a_file_without_header() {
+    let path = write_csv("chunks_no_header", 100, false);
+    let chunks = SampleReader::new()
+        .file(&path)
+        .target_feature("Feat. [2]")
+        .chunk_size(64)
+        .read_chunks()
+        .unwrap();
+    assert_eq!(chunks.feature_names(), ["Feat. [1]", "Feat. [3]"]);
+    let sizes = chunks.map(|c| c.unwrap().shape()).collect::<Vec<_>>();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(sizes, [(64, 2), (36, 2)]);
+}
+
+
+#[test]
+fn column_store_is_built_block_by_block() {
+    let path = write_csv("store", 1000, true);
+    let mut store = std::env::temp_dir();
+    store.push(format!("miniboosts_store_{}.bin", std::process::id()));
+
+    SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .chunk_size(128)
+        .build_store(&store)
+        .unwrap();
+    let whole = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .read()
+        .unwrap();
+    let sample = Sample::load_store(&store).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&store).unwrap();
+    assert_same_samples(&sample, &whole);
+
+    let dist = vec![1f64 / 1000f64; 1000];
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .build()
+        .produce(&sample, &dist);
+    let g = DecisionTreeBuilder::new(&whole)
+        .max_depth(3)
+        .build()
+        .produce(&whole, &dist);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&whole));
+}
+
+
+#[test]
+fn invalid_files_are_rejected() {
+    let path = write_csv("invalid", 10, true);
+    let unknown = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("label")
+        .read_chunks();
+    assert!(unknown.is_err());
+
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    writeln!(file, "0.5,1,abc").unwrap();
+    let results = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .chunk_size(8)
+        .read_chunks()
+        .unwrap()
+        .map(|chunk| chunk.is_ok())
+        .collect::<Vec<_>>();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(results, [true, false]);
+
+    let mut svmlight = std::env::temp_dir();
+    svmlight.push("miniboosts_invalid.svmlight");
+    let chunks = SampleReader::new()
+        .file(&svmlight)
+        .target_feature("class")
+        .read_chunks();
+    assert!(chunks.is_err());
+}
@@ -0,0 +1,130 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::uniform;
+
+
+// A random sample whose label is positive
+// with probability `0.4` if `x > 0.8` and `0.02` otherwise,
+// with a noise feature `y`.
+fn imbalanced_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|&a| {
+            let p = if a > 0.8 { 0.4 } else { 0.02 };
+            if rng.gen::<f64>() < p { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// The fraction of the positive examples predicted as positive.
+fn recall<H: Classifier>(f: &H, sample: &Sample) -> f64 {
+    let predictions = f.predict_all(sample);
+    let positives = sample.target()
+        .iter()
+        .zip(predictions)
+        .filter(|(y, _)| **y > 0.0)
+        .collect::<Vec<_>>();
+    let n_positive = positives.len() as f64;
+    positives.into_iter()
+        .filter(|(_, p)| *p > 0)
+        .count() as f64
+        / n_positive
+}
+
+
+#[test]
+fn balanced_weights_raise_the_minority_recall() {
+    let sample = imbalanced_sample();
+    let dist = uniform(&sample);
+
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .build()
+        .produce(&sample, &dist);
+    let g = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .class_weight(ClassWeight::Balanced)
+        .build()
+        .produce(&sample, &dist);
+
+    let unweighted = recall(&f, &sample);
+    let balanced = recall(&g, &sample);
+    assert!(balanced > unweighted, "{balanced} <= {unweighted}");
+}
+
+
+#[test]
+fn explicit_weights_scale_the_distribution() {
+    let sample = imbalanced_sample();
+    let dist = uniform(&sample);
+
+    // The classes of the same weight do not change the trees.
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .build()
+        .produce(&sample, &dist);
+    let g = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .class_weight(ClassWeight::Explicit(vec![(-1, 2.0), (1, 2.0)]))
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+
+    // Weighting the positive class is
+    // the same as scaling its mass on the distribution.
+    let scaled = sample.target()
+        .iter()
+        .zip(&dist)
+        .map(|(&y, &d)| if y > 0.0 { 5.0 * d } else { d })
+        .collect::<Vec<_>>();
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .build()
+        .produce(&sample, &scaled);
+    let g = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .class_weight(ClassWeight::Explicit(vec![(1, 5.0)]))
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+}
+
+
+#[test]
+fn adaboost_runs_with_class_weights() {
+    let sample = imbalanced_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .class_weight(ClassWeight::Balanced)
+        .build();
+    assert!(
+        tree.info()
+            .unwrap()
+            .iter()
+            .any(|(key, val)| *key == "Class weight" && val == "Balanced")
+    );
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&tree);
+    let r = recall(&f, &sample);
+    assert!(r > 0.5, "recall: {r}");
+}
+
+
+#[test]
+#[should_panic]
+fn non_positive_class_weight_panics() {
+    let sample = imbalanced_sample();
+    let _ = DecisionTreeBuilder::new(&sample)
+        .class_weight(ClassWeight::Explicit(vec![(1, 0.0)]));
+}
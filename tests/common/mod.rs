@@ -0,0 +1,178 @@
+// The fixtures shared by the integration tests.
+// Each test crate uses a part of them.
+#![allow(dead_code)]
+
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A decision stump `sign * (x_feature > threshold ? +1 : -1)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stump {
+    pub feature: &'static str,
+    pub threshold: f64,
+    pub sign: f64,
+}
+
+
+impl Classifier for Stump {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        let x = sample[self.feature][row];
+        if x > self.threshold { self.sign } else { - self.sign }
+    }
+}
+
+
+// A sample of seven examples over the features `x` and `y`.
+pub fn toy_sample() -> Sample {
+    let s1 = Series::new("x", &[10.0, 14.0, 15.0, 5.0, 3.0,  8.0, 12.0]);
+    let s2 = Series::new("y", &[ 5.0,  8.0,  3.0, 1.0, 9.0, 13.0, 11.0]);
+    let df = DataFrame::new(vec![s1, s2]).unwrap();
+    let target = Series::new(
+        "class", &[1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0]
+    );
+
+    Sample::from_dataframe(df, target).unwrap()
+}
+
+
+// A sample whose label is positive iff `x > 4` and `y > 4`,
+// so that no single stump classifies all the examples.
+pub fn quadrant_sample() -> Sample {
+    let data = df!(
+        "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 5.5, 6.5, 2.5, 7.5],
+        "y" => [2.0, 7.0, 1.0, 8.0, 3.0, 6.0, 1.5, 5.0, 7.5, 2.5, 6.5, 8.5],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [-1.0, -1.0, -1.0, -1.0, -1.0, 1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0],
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+// A random sample of `n_sample` examples
+// whose label is positive iff `x + y > 1`,
+// where each label is flipped with probability `noise`.
+pub fn diagonal_sample(n_sample: usize, noise: f64, seed: u64) -> Sample {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| {
+            let label = if a + b > 1.0 { 1.0 } else { -1.0 };
+            if rng.gen::<f64>() < noise { - label } else { label }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// A random sample whose label is positive iff `x + y > 1`,
+// with a noise feature `z`.
+pub fn diagonal_sample_with_noise_feature() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| if a + b > 1.0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y, "z" => z).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// A random sample of `n_sample` examples
+// whose label is positive iff `x + y > 1`,
+// where every tenth value of `x` is missing.
+pub fn diagonal_sample_with_missing_values(n_sample: usize) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| if a + b > 1.0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let x = x.into_iter()
+        .enumerate()
+        .map(|(i, a)| if i % 10 == 0 { None } else { Some(a) })
+        .collect::<Vec<_>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// A random sample whose label is positive
+// iff `(x, y)` is in the disk of radius `0.35` centered at `(0.5, 0.5)`,
+// which no linear classifier fits.
+pub fn disk_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| {
+            let r2 = (a - 0.5).powi(2) + (b - 0.5).powi(2);
+            if r2 < 0.35 * 0.35 { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// A random sample with `y = sin(6x) + ε`, where `ε ~ U[-0.2, 0.2]`.
+pub fn sine_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|a| (6.0 * a).sin() + rng.gen_range(-0.2..0.2))
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("y", target)).unwrap()
+}
+
+
+// A regression tree of depth `2` for the squared loss.
+pub fn tree(sample: &Sample) -> RegressionTree<'_, GBMLoss> {
+    RegressionTreeBuilder::new(sample)
+        .max_depth(2)
+        .loss(GBMLoss::L2)
+        .build()
+}
+
+
+pub fn uniform(sample: &Sample) -> Vec<f64> {
+    let n_sample = sample.shape().0;
+    vec![1.0 / n_sample as f64; n_sample]
+}
+
+
+pub fn training_error<H: Classifier>(f: &H, sample: &Sample) -> f64 {
+    let n_sample = sample.shape().0 as f64;
+    f.predict_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(p, y)| *p as f64 != **y)
+        .count() as f64
+        / n_sample
+}
+
+
+pub fn accuracy<H: Classifier>(f: &H, sample: &Sample) -> f64 {
+    1.0 - training_error(f, sample)
+}
+
+
+// The zero-one loss in the argument order of the evaluation metrics.
+pub fn zero_one_loss<H: Classifier>(sample: &Sample, f: &H) -> f64 {
+    training_error(f, sample)
+}
@@ -0,0 +1,69 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::toy_sample;
+
+
+#[test]
+fn compress_adaboost_ensemble() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(30);
+    let f = booster.run(&dtree);
+
+    let (g, report) = f.compress(&sample, Compression::new());
+    assert_eq!(report.trees_before, f.hypotheses.len());
+    assert_eq!(report.trees_after, g.hypotheses.len());
+    assert!(report.trees_after <= report.trees_before);
+    assert!(report.nodes_after <= report.nodes_before);
+    assert!(report.delta().abs() < 1e-9);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+
+    // Identical trees are merged into one.
+    for (i, h) in g.hypotheses.iter().enumerate() {
+        assert!(g.hypotheses[i + 1..].iter().all(|k| k != h));
+    }
+}
+
+
+#[test]
+fn compress_prunes_small_weights() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(30);
+    let f = booster.run(&dtree);
+
+    let config = Compression::new()
+        .step(0.0)
+        .prune_threshold(0.5);
+    let (g, report) = f.compress(&sample, config);
+    assert!(g.hypotheses.len() <= 1);
+    assert_eq!(report.trees_after, g.hypotheses.len());
+}
+
+
+#[test]
+fn compress_gbm_ensemble() {
+    let sample = toy_sample();
+    let rtree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::L2)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2);
+    let f = booster.run(&rtree);
+
+    let (g, report) = f.compress(&sample, Compression::new().step(1e-2));
+    assert_eq!(report.metric, "L2 loss");
+    assert!(report.nodes_after <= report.nodes_before);
+    assert!(report.delta().abs() < 1e-1);
+    assert_eq!(report.trees_after, g.hypotheses.len());
+}
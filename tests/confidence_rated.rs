@@ -0,0 +1,82 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+use std::cell::Cell;
+
+
+// A hypothesis with the real-valued confidence `scale * x`.
+#[derive(Clone)]
+struct Linear {
+    scale: f64,
+}
+
+
+impl Classifier for Linear {
+    fn confidence(&self, sample: &Sample, row: usize) -> f64 {
+        self.scale * sample["x"][row]
+    }
+}
+
+
+// A weak learner that returns the given hypotheses in turn.
+struct RoundRobin {
+    scales: Vec<f64>,
+    round: Cell<usize>,
+}
+
+
+impl WeakLearner for RoundRobin {
+    type Hypothesis = Linear;
+    fn produce(&self, _sample: &Sample, _dist: &[f64]) -> Self::Hypothesis {
+        let t = self.round.get();
+        self.round.set(t + 1);
+        Linear { scale: self.scales[t % self.scales.len()] }
+    }
+}
+
+
+fn toy_sample() -> Sample {
+    let data = df!("x" => [0.9, 0.4, -0.2, -0.7, 0.1, -0.5]).unwrap();
+    let target = Series::new("class", [1.0, 1.0, -1.0, -1.0, -1.0, 1.0]);
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn real_valued_weights_and_update() {
+    let sample = toy_sample();
+    let scales = vec![1.0, 0.5];
+    let weak_learner = RoundRobin { scales: scales.clone(), round: Cell::new(0) };
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(2);
+    let f = booster.run(&weak_learner);
+
+
+    // Compute the expected weights by the Schapire-Singer rule.
+    let x = (0..6).map(|i| sample["x"][i]).collect::<Vec<f64>>();
+    let y = sample.target();
+    let mut dist = [1.0 / 6.0; 6];
+    let mut alphas = Vec::new();
+    for scale in scales {
+        let margins = (0..6).map(|i| y[i] * scale * x[i]).collect::<Vec<_>>();
+        let edge = (0..6).map(|i| dist[i] * margins[i]).sum::<f64>();
+        let alpha = 0.5 * ((1.0 + edge) / (1.0 - edge)).ln();
+        dist.iter_mut()
+            .zip(&margins[..])
+            .for_each(|(d, m)| { *d *= (- alpha * m).exp(); });
+        let z = dist.iter().sum::<f64>();
+        dist.iter_mut().for_each(|d| { *d /= z; });
+        alphas.push(alpha);
+    }
+    let total = alphas.iter().sum::<f64>();
+
+
+    assert_eq!(f.weights.len(), 2);
+    for (w, a) in f.weights.iter().zip(alphas) {
+        assert!((w - a / total).abs() < 1e-9, "{w} != {}", a / total);
+    }
+    let conf = f.confidence(&sample, 0);
+    let expected = f.weights[0] * 0.9 + f.weights[1] * 0.45;
+    assert!((conf - expected).abs() < 1e-9);
+}
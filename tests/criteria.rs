@@ -0,0 +1,80 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+mod common;
+use common::{diagonal_sample, training_error};
+
+
+const CRITERIA: [Criterion; 5] = [
+    Criterion::Entropy,
+    Criterion::Edge,
+    Criterion::Gini,
+    Criterion::Twoing,
+    Criterion::Misclassification,
+];
+
+
+// `x = 1, 2, ..., 10` with the labels `+ + + + - - - - - +`.
+fn toy_sample() -> Sample {
+    let x = (1..=10).map(|x| x as f64).collect::<Vec<_>>();
+    let target = [1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0, -1.0, 1.0];
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn every_criterion_grows_a_tree() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = vec![1.0 / 300.0; 300];
+    for criterion in CRITERIA {
+        let f = DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .criterion(criterion)
+            .build()
+            .produce(&sample, &dist);
+        let error = training_error(&f, &sample);
+        assert!(error < 0.2, "{criterion}: training error {error}");
+    }
+}
+
+
+#[test]
+fn misclassification_follows_the_distribution() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Misclassification)
+        .build();
+
+    // The uniform distribution separates the first four examples.
+    let uniform = vec![0.1; 10];
+    let f = tree.produce(&sample, &uniform);
+    assert_eq!(training_error(&f, &sample), 0.1);
+    assert_eq!(f.predict(&sample, 9), -1);
+
+    // A heavy weight on the last example moves the threshold.
+    let mut dist = vec![0.01; 10];
+    dist[9] = 0.91;
+    let f = tree.produce(&sample, &dist);
+    assert_eq!(f.predict(&sample, 9), 1);
+    assert_eq!(f.predict(&sample, 8), -1);
+}
+
+
+#[test]
+fn misclassification_does_not_split_without_a_decrease() {
+    // Both children of any split have the same majority label.
+    let x = (1..=6).map(|x| x as f64).collect::<Vec<_>>();
+    let target = [1.0, 1.0, -1.0, 1.0, 1.0, 1.0];
+    let data = df!("x" => x).unwrap();
+    let sample = Sample::from_dataframe(data, Series::new("class", target))
+        .unwrap();
+
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Misclassification)
+        .build()
+        .produce(&sample, &[1.0 / 6.0; 6]);
+    assert_eq!(f.n_leaves(), 1);
+}
@@ -0,0 +1,65 @@
+use miniboosts::prelude::*;
+use miniboosts::research::{cross_validate, CrossValidationReport};
+
+mod common;
+use common::{diagonal_sample, zero_one_loss};
+
+
+fn train(sample: &Sample) -> WeightedMajority<DecisionTreeClassifier> {
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(2)
+        .build();
+    AdaBoost::init(sample)
+        .force_quit_at(20)
+        .run(&tree)
+}
+
+
+#[test]
+fn each_fold_is_evaluated() {
+    let sample = diagonal_sample(503, 0.0, 1234);
+    let test_size = |test: &Sample, _: &WeightedMajority<_>| {
+        test.shape().0 as f64
+    };
+    let report = cross_validate(
+        train,
+        &sample,
+        5,
+        &[&zero_one_loss, &test_size],
+    );
+
+    assert_eq!(report.folds.len(), 5);
+    let sizes = report.folds.iter().map(|fold| fold[1]).sum::<f64>();
+    assert_eq!(sizes, 503.0);
+
+    let mean = report.mean();
+    let std = report.std();
+    assert_eq!(mean.len(), 2);
+    assert!(mean[0] < 0.2, "The test loss is too large: {}", mean[0]);
+    assert!(std[0] < 0.1);
+    assert!((mean[1] - 100.6).abs() < 1e-9);
+
+    // The folds do not depend on the parallel execution.
+    let again = cross_validate(train, &sample, 5, &[&zero_one_loss]);
+    let losses = report.folds.iter().map(|fold| fold[0]);
+    assert!(losses.eq(again.folds.iter().map(|fold| fold[0])));
+}
+
+
+#[test]
+fn mean_and_std_of_a_report() {
+    let report = CrossValidationReport {
+        folds: vec![vec![1.0, 0.0], vec![3.0, 0.0]],
+    };
+    assert_eq!(report.mean(), [2.0, 0.0]);
+    assert_eq!(report.std(), [1.0, 0.0]);
+    assert!(report.to_string().contains("2.000000 ± 1.000000"));
+}
+
+
+#[test]
+#[should_panic]
+fn a_single_fold_is_rejected() {
+    let sample = diagonal_sample(10, 0.0, 1234);
+    cross_validate(train, &sample, 1, &[&zero_one_loss]);
+}
@@ -0,0 +1,67 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{sine_sample, tree};
+
+
+#[test]
+fn dart_fits() {
+    let sample = sine_sample();
+    let target = sample.target();
+
+    let mut booster = DART::init_with_loss(&sample, GBMLoss::L2)
+        .drop_rate(0.2)
+        .max_iter(50);
+    let f = booster.run(&tree(&sample));
+    assert_eq!(f.hypotheses.len(), 50);
+
+
+    let predictions = f.predict_all(&sample);
+    let zeros = vec![0f64; target.len()];
+    let loss = GBMLoss::L2.eval(&predictions[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.1 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+fn skipping_all_dropouts_is_gbm() {
+    let sample = sine_sample();
+
+    let mut dart = DART::init_with_loss(&sample, GBMLoss::L2)
+        .drop_rate(0.5)
+        .skip_drop(1.0);
+    let f = dart.run(&tree(&sample));
+
+    let mut gbm = GBM::init_with_loss(&sample, GBMLoss::L2);
+    let g = gbm.run(&tree(&sample));
+
+    let p = f.predict_all(&sample);
+    let q = g.predict_all(&sample);
+    assert!(p.iter().zip(&q[..]).all(|(a, b)| (a - b).abs() < 1e-9));
+}
+
+
+#[test]
+fn dropouts_are_reproducible() {
+    let sample = sine_sample();
+
+    let run = |seed| {
+        let mut booster = DART::init_with_loss(&sample, GBMLoss::L2)
+            .drop_rate(0.3)
+            .max_iter(20)
+            .seed(seed);
+        booster.run(&tree(&sample)).weights
+    };
+    assert_eq!(run(1234), run(1234));
+    assert_ne!(run(1234), run(5678));
+}
+
+
+#[test]
+#[should_panic]
+fn invalid_drop_rate() {
+    let sample = sine_sample();
+    let _ = DART::<RegressionTreeRegressor, _>::init_with_loss(&sample, GBMLoss::L2)
+        .drop_rate(1.5);
+}
@@ -0,0 +1,103 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random data frame with an integer column `n`,
+// a float column `x` with some missing values,
+// and the target column `class`, which is positive iff `x + n > 2`.
+// The last row is unlabeled.
+fn random_frame(n_sample: usize) -> DataFrame {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n = (0..n_sample)
+        .map(|_| rng.gen_range(0..4))
+        .collect::<Vec<i32>>();
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let mut class = n.iter()
+        .zip(&x[..])
+        .map(|(&n, x)| {
+            let y = if x + n as f64 > 2.0 { 1.0 } else { -1.0 };
+            Some(y)
+        })
+        .collect::<Vec<_>>();
+    class[n_sample - 1] = None;
+    let x = x.into_iter()
+        .enumerate()
+        .map(|(i, x)| if i % 10 == 0 { None } else { Some(x) })
+        .collect::<Vec<_>>();
+    df!("n" => n, "class" => class, "x" => x).unwrap()
+}
+
+
+fn same(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+
+#[test]
+fn dataframe_is_converted_with_the_target_name() {
+    let frame = random_frame(100);
+    let sample = Sample::try_from((&frame, "class")).unwrap();
+    assert_eq!(sample.shape(), (100, 2));
+    assert!(sample.is_unlabeled(99));
+    assert_eq!(sample.labeled_rows().len(), 99);
+
+    let names = sample.features()
+        .iter()
+        .map(|feat| feat.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["n", "x"]);
+
+    let n = frame.column("n").unwrap().i32().unwrap();
+    let x = frame.column("x").unwrap().f64().unwrap();
+    for i in 0..100 {
+        assert_eq!(sample["n"][i], n.get(i).unwrap() as f64);
+        assert!(same(sample["x"][i], x.get(i).unwrap_or(f64::NAN)));
+    }
+}
+
+
+#[test]
+fn dataframe_conversion_rejects_invalid_frames() {
+    let frame = random_frame(10);
+    assert!(Sample::try_from((&frame, "label")).is_err());
+
+    let mut frame = frame;
+    let names = (0..10).map(|i| format!("name {i}")).collect::<Vec<_>>();
+    frame.with_column(Series::new("name", names)).unwrap();
+    assert!(Sample::try_from((&frame, "class")).is_err());
+}
+
+
+#[test]
+fn sample_is_converted_back_to_a_dataframe() {
+    let frame = random_frame(200);
+    let sample = Sample::try_from((&frame, "class")).unwrap();
+    let (data, target) = sample.to_dataframe();
+    assert_eq!(data.shape(), (200, 2));
+    assert_eq!(target.name(), "target");
+    assert_eq!(target.null_count(), 1);
+    assert_eq!(data.column("x").unwrap().null_count(), 20);
+
+    let other = Sample::from_dataframe(data, target).unwrap();
+    assert_eq!(other.shape(), sample.shape());
+    for i in 0..200 {
+        assert!(same(other.target()[i], sample.target()[i]));
+        let (x, _) = other.at(i);
+        let (z, _) = sample.at(i);
+        x.iter().zip(&z[..]).for_each(|(&a, &b)| assert!(same(a, b)));
+    }
+
+    let labeled = sample.labeled_rows();
+    let dist = vec![1f64 / 200f64; 200];
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .build()
+        .produce(&sample, &dist);
+    let g = DecisionTreeBuilder::new(&other)
+        .max_depth(2)
+        .build()
+        .produce(&other, &dist);
+    let (p, q) = (f.predict_all(&sample), g.predict_all(&other));
+    labeled.into_iter().for_each(|i| assert_eq!(p[i], q[i]));
+}
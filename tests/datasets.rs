@@ -0,0 +1,183 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+use miniboosts::datasets::{
+    make_gaussian_blobs,
+    make_two_spirals,
+    make_xor,
+    make_noisy_margin,
+    noisy_margin_optimum,
+};
+
+mod common;
+use common::{Stump, accuracy};
+
+
+fn boosted_trees(sample: &Sample, depth: usize)
+    -> WeightedMajority<DecisionTreeClassifier>
+{
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(depth)
+        .build();
+    AdaBoost::init(sample)
+        .force_quit_at(50)
+        .run(&tree)
+}
+
+
+#[test]
+fn generators_are_deterministic() {
+    let blobs = make_gaussian_blobs(90, 4, 3, 1.0, 1234);
+    assert_eq!(blobs.shape(), (90, 4));
+    assert_eq!(blobs.feature_names()[0], "Feat. [1]");
+    assert_eq!(blobs.target()[..4], [0.0, 1.0, 2.0, 0.0]);
+    let other = make_gaussian_blobs(90, 4, 3, 1.0, 1234);
+    assert_eq!(blobs.at(17), other.at(17));
+    let other = make_gaussian_blobs(90, 4, 3, 1.0, 5678);
+    assert_ne!(blobs.at(17), other.at(17));
+
+    let blobs = make_gaussian_blobs(10, 2, 2, 1.0, 1234);
+    assert_eq!(blobs.target()[..2], [-1.0, 1.0]);
+
+    let spirals = make_two_spirals(100, 0.0, 1234);
+    assert_eq!(spirals.shape(), (100, 2));
+    for i in 0..100 {
+        let (x, _) = spirals.at(i);
+        assert!(x[0].hypot(x[1]) <= 1.0 + 1e-12);
+    }
+    assert_eq!(spirals.at(3), make_two_spirals(100, 0.0, 1234).at(3));
+
+    let xor = make_xor(100, 0.0, 1234);
+    assert_eq!(xor.shape(), (100, 2));
+    for i in 0..100 {
+        let (x, y) = xor.at(i);
+        assert_eq!(y, (x[0] * x[1]).signum());
+    }
+}
+
+
+#[test]
+fn generated_samples_are_learnable() {
+    // The centers depend on the seed.
+    let train = make_gaussian_blobs(200, 2, 2, 1.0, 1234);
+    let f = boosted_trees(&train, 1);
+    assert!(accuracy(&f, &train) > 0.95);
+
+    // The stumps cannot fit the XOR pattern, but the trees can.
+    let train = make_xor(400, 0.0, 1234);
+    let test = make_xor(400, 0.0, 5678);
+    let stump = DecisionTreeBuilder::new(&train)
+        .max_depth(1)
+        .build();
+    let f = AdaBoost::init(&train)
+        .force_quit_at(1)
+        .run(&stump);
+    assert!(accuracy(&f, &test) < 0.7);
+    let f = boosted_trees(&train, 2);
+    assert!(accuracy(&f, &test) > 0.9);
+
+    let train = make_two_spirals(600, 0.0, 1234);
+    let f = boosted_trees(&train, 4);
+    assert!(accuracy(&f, &train) > 0.9);
+}
+
+
+// Checks that `boost` attains the optimal soft margin
+// of `make_noisy_margin` for the capping parameters `nu`.
+fn assert_attains_noisy_margin_optimum<B>(name: &str, boost: B)
+    where B: Fn(&Sample, f64, &HypothesisPool<Stump>)
+        -> WeightedMajority<Stump>,
+{
+    let (n_sample, n_flipped) = (100, 10);
+    let sample = make_noisy_margin(n_sample, n_flipped, 1234);
+
+    // The convex hull of these stumps contains
+    // all the hypotheses over the feature values `{-1, +1}`.
+    let feature = "Feat. [1]";
+    let mut stumps = Vec::new();
+    for threshold in [-2.0, 0.0, 2.0] {
+        for sign in [1.0, -1.0] {
+            stumps.push(Stump { feature, threshold, sign });
+        }
+    }
+    let pool = HypothesisPool::new(stumps);
+    for nu in [10.0, 20.0, 40.0, 100.0] {
+        let optimum = noisy_margin_optimum(n_sample, n_flipped, nu);
+        let f = boost(&sample, nu, &pool);
+        let value = SoftMarginObjective::new(nu).eval(&sample, &f);
+        assert!(
+            (value - optimum).abs() < 1e-3,
+            "{name}, nu = {nu}: \
+             the soft margin is {value}, the optimum is {optimum}"
+        );
+    }
+}
+
+
+#[test]
+fn noisy_margin_flips_the_labels() {
+    let (n_sample, n_flipped) = (100, 10);
+    let sample = make_noisy_margin(n_sample, n_flipped, 1234);
+    assert_eq!(sample.shape(), (100, 1));
+    let n_disagree = (0..n_sample)
+        .filter(|&i| {
+            let (x, y) = sample.at(i);
+            x[0] != y
+        })
+        .count();
+    assert_eq!(n_disagree, n_flipped);
+
+    assert_eq!(noisy_margin_optimum(100, 10, 40.0), 0.5);
+    assert_eq!(noisy_margin_optimum(100, 10, 20.0), 0.0);
+}
+
+
+#[test]
+fn lpboost_attains_the_noisy_margin_optimum() {
+    assert_attains_noisy_margin_optimum("LPBoost", |sample, nu, pool| {
+        LPBoost::init(sample)
+            .tolerance(1e-4)
+            .nu(nu)
+            .run(pool)
+    });
+}
+
+
+#[test]
+fn erlpboost_attains_the_noisy_margin_optimum() {
+    assert_attains_noisy_margin_optimum("ERLPBoost", |sample, nu, pool| {
+        ERLPBoost::init(sample)
+            .tolerance(1e-4)
+            .nu(nu)
+            .run(pool)
+    });
+}
+
+
+#[test]
+fn mlpboost_attains_the_noisy_margin_optimum() {
+    assert_attains_noisy_margin_optimum("MLPBoost", |sample, nu, pool| {
+        MLPBoost::init(sample)
+            .tolerance(1e-4)
+            .nu(nu)
+            .run(pool)
+    });
+}
+
+
+#[test]
+fn softboost_attains_the_noisy_margin_optimum() {
+    assert_attains_noisy_margin_optimum("SoftBoost", |sample, nu, pool| {
+        SoftBoost::init(sample)
+            .tolerance(1e-2)
+            .nu(nu)
+            .run(pool)
+    });
+}
+
+
+#[test]
+#[should_panic]
+fn odd_flips_panic() {
+    make_noisy_margin(100, 3, 1234);
+}
@@ -0,0 +1,114 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+fn edge<H: Classifier>(h: &H, sample: &Sample, dist: &[f64]) -> f64 {
+    h.confidence_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .zip(dist)
+        .map(|((p, y), d)| d * y * p)
+        .sum::<f64>()
+}
+
+
+// The maximum edge over all the stumps
+// with thresholds on the midpoints of the distinct values.
+fn brute_force_edge(sample: &Sample, dist: &[f64]) -> f64 {
+    let mut best = f64::MIN;
+    for feature in ["x", "y"] {
+        let mut values = (0..sample.shape().0)
+            .map(|row| sample[feature][row])
+            .collect::<Vec<_>>();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.dedup();
+        let thresholds = values.windows(2)
+            .map(|w| (w[0] + w[1]) / 2.0)
+            .chain(std::iter::once(f64::MIN));
+        for threshold in thresholds {
+            for sign in [1.0, -1.0] {
+                let h = StumpClassifier::new(feature, threshold, sign);
+                best = best.max(edge(&h, sample, dist));
+            }
+        }
+    }
+    best
+}
+
+
+#[test]
+fn decision_stump_maximizes_the_edge() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let n_sample = sample.shape().0;
+    let stump = DecisionStump::init(&sample);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..5 {
+        let mut dist = (0..n_sample)
+            .map(|_| rng.gen::<f64>())
+            .collect::<Vec<_>>();
+        let sum = dist.iter().sum::<f64>();
+        dist.iter_mut().for_each(|d| *d /= sum);
+
+        let h = stump.produce(&sample, &dist);
+        let found = edge(&h, &sample, &dist);
+        let expected = brute_force_edge(&sample, &dist);
+        assert!(
+            (found - expected).abs() < 1e-12,
+            "found: {found}, expected: {expected}"
+        );
+    }
+}
+
+
+#[test]
+fn decision_stump_on_another_sample() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let other = diagonal_sample(300, 0.0, 1234);
+    let n_sample = sample.shape().0;
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+
+    let stump = DecisionStump::init(&sample);
+    let f = stump.produce(&sample, &dist);
+    let g = stump.produce(&other, &dist);
+    assert_eq!(f, g);
+}
+
+
+#[test]
+fn adaboost_with_decision_stumps() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let stump = DecisionStump::init(&sample);
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(300);
+    let f = booster.run(&stump);
+
+    let n_sample = sample.shape().0 as f64;
+    let error = f.predict_all(&sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(p, y)| *p as f64 != **y)
+        .count() as f64
+        / n_sample;
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+fn decision_stump_splits_on_the_threshold() {
+    let data = df!("x" => [0.0, 1.0, 2.0, 3.0]).unwrap();
+    let target = Series::new("class", [-1.0, -1.0, 1.0, 1.0]);
+    let sample = Sample::from_dataframe(data, target).unwrap();
+    let dist = vec![0.25; 4];
+
+    let h = DecisionStump::init(&sample).produce(&sample, &dist);
+    assert_eq!(h.feature(), "x");
+    assert_eq!(h.threshold(), 1.5);
+    assert_eq!(h.sign(), 1.0);
+    assert_eq!(h.predict_all(&sample), vec![-1, -1, 1, 1]);
+}
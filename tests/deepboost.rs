@@ -0,0 +1,113 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+// The label is positive iff `x > 4` and `y > 4`,
+// except the last noisy example.
+fn toy_sample() -> Sample {
+    let data = df!(
+        "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 5.5, 6.5, 2.5, 7.5, 4.5],
+        "y" => [2.0, 7.0, 1.0, 8.0, 3.0, 6.0, 1.5, 5.0, 7.5, 2.5, 6.5, 8.5, 4.5],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [
+            -1.0, -1.0, -1.0, -1.0, -1.0, 1.0, -1.0,
+            1.0, 1.0, -1.0, -1.0, 1.0, -1.0,
+        ],
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn deepboost_decreases_objective() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut prev = f64::MAX;
+    for it in 1..=10 {
+        let mut booster = DeepBoost::init(&sample)
+            .lambda(1e-2)
+            .beta(1e-3)
+            .max_iter(it);
+        let _ = booster.run(&tree);
+        let objval = booster.objective_value();
+        assert!(objval <= prev + 1e-9, "{objval} > {prev}");
+        prev = objval;
+    }
+}
+
+
+#[test]
+fn deepboost_fits_sample() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = DeepBoost::init(&sample)
+        .max_iter(50);
+    let f = booster.run(&tree);
+
+    let target = sample.target();
+    let n_correct = f.predict_all(&sample)
+        .into_iter()
+        .zip(target)
+        .filter(|(p, y)| *p == **y as i64)
+        .count();
+    assert!(n_correct >= 12);
+}
+
+
+#[test]
+fn deepboost_penalizes_complex_trees() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let total_leaves = |lambda: f64| {
+        let mut booster = DeepBoost::init(&sample)
+            .lambda(lambda)
+            .max_iter(30);
+        let f = booster.run(&tree);
+        f.hypotheses.iter()
+            .map(|h| h.n_leaves())
+            .sum::<usize>()
+    };
+
+    assert!(total_leaves(0.3) < total_leaves(0.01));
+}
+
+
+#[test]
+fn tree_complexity_grows_with_leaves() {
+    let sample = toy_sample();
+    let n_sample = sample.shape().0;
+    let dist = vec![1f64 / n_sample as f64; n_sample];
+
+    let shallow = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+    let deep = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let h = shallow.produce(&sample, &dist[..]);
+    let g = deep.produce(&sample, &dist[..]);
+    assert!(h.n_leaves() < g.n_leaves());
+    assert!(
+        shallow.complexity(&sample, &h) < deep.complexity(&sample, &g)
+    );
+}
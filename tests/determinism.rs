@@ -0,0 +1,127 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random sample whose label is positive iff `x + y` is large,
+// with some noise and the noise features `z` and `w`.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let w = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| {
+            let s = a + b + 0.2 * rng.gen::<f64>();
+            if s > 1.1 { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y, "z" => z, "w" => w).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+fn confidences<H: Classifier>(f: &H, sample: &Sample) -> Vec<f64> {
+    f.confidence_all(sample)
+}
+
+
+fn run_adaboost(sample: &Sample, tree: &DecisionTree, seed: u64)
+    -> WeightedMajority<DecisionTreeClassifier>
+{
+    let mut booster = AdaBoost::init(sample)
+        .selection(Selection::Uniform(3))
+        .seed(seed)
+        .force_quit_at(20);
+    booster.run(tree)
+}
+
+
+#[test]
+fn adaboost_runs_are_identical_for_the_same_seed() {
+    let sample = random_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .feature_subsample(0.5)
+        .seed(7)
+        .build();
+
+    let f = run_adaboost(&sample, &tree, 42);
+    tree.reset_feature_usage();
+    let g = run_adaboost(&sample, &tree, 42);
+    assert_eq!(f.weights, g.weights);
+    assert_eq!(confidences(&f, &sample), confidences(&g, &sample));
+
+    tree.reset_feature_usage();
+    let h = run_adaboost(&sample, &tree, 43);
+    assert_ne!(f.weights, h.weights);
+}
+
+
+#[test]
+fn subsampled_trees_are_identical_for_the_same_seed() {
+    let sample = random_sample();
+    let n_sample = sample.shape().0;
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+
+    let build = |seed: u64| {
+        DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .criterion(Criterion::Twoing)
+            .feature_subsample_per_node(0.5)
+            .seed(seed)
+            .build()
+    };
+
+    let first = build(1);
+    let second = build(1);
+    for _ in 0..5 {
+        let f = first.produce(&sample, &dist);
+        let g = second.produce(&sample, &dist);
+        assert_eq!(confidences(&f, &sample), confidences(&g, &sample));
+    }
+}
+
+
+#[test]
+fn gbm_runs_are_identical_for_the_same_seed() {
+    let sample = random_sample();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::L2)
+        .feature_subsample(0.5)
+        .build();
+
+    let run = |seed: u64| {
+        tree.reset_feature_usage();
+        let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .subsample(0.5)
+            .seed(seed)
+            .max_iter(20);
+        booster.run(&tree).predict_all(&sample)
+    };
+    assert_eq!(run(5), run(5));
+}
+
+
+#[test]
+fn neural_networks_are_identical_for_the_same_seed() {
+    let sample = random_sample();
+    let n_sample = sample.shape().0;
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+
+    let build = |seed: u64| {
+        NeuralNetwork::init(&sample)
+            .append(8, Activation::ReLu(1.0))
+            .append(2, Activation::SoftMax(1.0))
+            .n_epoch(3)
+            .n_iter(5)
+            .seed(seed)
+    };
+    let f = build(3).produce(&sample, &dist);
+    let g = build(3).produce(&sample, &dist);
+    assert_eq!(confidences(&f, &sample), confidences(&g, &sample));
+}
@@ -0,0 +1,91 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{diagonal_sample_with_missing_values, uniform};
+
+
+#[test]
+fn cpu_is_the_default_device() {
+    let sample = diagonal_sample_with_missing_values(300);
+    let dist = uniform(&sample);
+    assert_eq!(Device::default(), Device::Cpu);
+    assert!(Device::Cpu.is_available());
+
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .bins(16)
+        .build()
+        .produce(&sample, &dist);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .bins(16)
+        .device(Device::Cpu)
+        .build();
+    assert!(
+        tree.info()
+            .unwrap()
+            .iter()
+            .all(|(key, _)| *key != "Device")
+    );
+    let g = tree.produce(&sample, &dist);
+    assert_eq!(f.confidence_all(&sample), g.confidence_all(&sample));
+}
+
+
+#[cfg(feature = "gpu")]
+#[test]
+fn gpu_decision_tree_matches_the_cpu_one() {
+    if !Device::Gpu.is_available() { return; }
+    let sample = diagonal_sample_with_missing_values(50_000);
+    let dist = uniform(&sample);
+
+    let build = |device| {
+        DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .bins(64)
+            .device(device)
+            .build()
+    };
+    let tree = build(Device::Gpu);
+    assert!(
+        tree.info()
+            .unwrap()
+            .iter()
+            .any(|(key, val)| *key == "Device" && val.starts_with("GPU"))
+    );
+    let f = build(Device::Cpu).produce(&sample, &dist);
+    let g = tree.produce(&sample, &dist);
+
+    let n_agree = f.predict_all(&sample)
+        .into_iter()
+        .zip(g.predict_all(&sample))
+        .filter(|(p, q)| p == q)
+        .count();
+    let agreement = n_agree as f64 / sample.shape().0 as f64;
+    assert!(agreement > 0.99, "agreement: {agreement}");
+}
+
+
+#[cfg(feature = "gpu")]
+#[test]
+fn gpu_regression_tree_matches_the_cpu_one() {
+    if !Device::Gpu.is_available() { return; }
+    let sample = diagonal_sample_with_missing_values(50_000);
+    let target = sample.target();
+
+    let loss = |device| {
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2)
+            .bins(64)
+            .device(device)
+            .build();
+        let f = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .max_iter(10)
+            .run(&tree);
+        GBMLoss::L2.eval(&f.predict_all(&sample)[..], target)
+    };
+    let cpu = loss(Device::Cpu);
+    let gpu = loss(Device::Gpu);
+    assert!((cpu - gpu).abs() < 1e-2 * cpu, "CPU: {cpu}, GPU: {gpu}");
+}
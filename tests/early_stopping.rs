@@ -0,0 +1,89 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{diagonal_sample, zero_one_loss};
+
+
+// A random sample with `y = sin(6x) + ε`, where `ε ~ U[-0.5, 0.5]`.
+fn regression_sample(seed: u64) -> Sample {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|a| (6.0 * a).sin() + rng.gen_range(-0.5..0.5))
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("y", target)).unwrap()
+}
+
+
+fn squared_error<H: Regressor>(sample: &Sample, f: &H) -> f64 {
+    let predictions = f.predict_all(sample);
+    GBMLoss::L2.eval(&predictions[..], sample.target())
+}
+
+
+#[test]
+fn adaboost_stops_at_the_best_round() {
+    let train = diagonal_sample(300, 0.2, 1234);
+    let valid = diagonal_sample(300, 0.0, 5678);
+    let tree = DecisionTreeBuilder::new(&train)
+        .max_depth(3)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = AdaBoost::init(&train)
+        .force_quit_at(500)
+        .validation(&valid, 10);
+    let f = booster.run(&tree);
+
+
+    let best = booster.best_round();
+    let n_rounds = booster.booster().n_rounds();
+    assert!(0 < best && best < n_rounds, "best: {best}, rounds: {n_rounds}");
+    assert_eq!(n_rounds, best + 10);
+    assert_eq!(f.hypotheses.len(), best);
+    assert!((zero_one_loss(&valid, &f) - booster.best_loss()).abs() < 1e-12);
+}
+
+
+#[test]
+fn gbm_stops_by_custom_loss() {
+    let train = regression_sample(1234);
+    let valid = regression_sample(5678);
+    let tree = RegressionTreeBuilder::new(&train)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&train, GBMLoss::L2)
+        .max_iter(1_000)
+        .validation(&valid, 5)
+        .loss(squared_error);
+    let f = booster.run(&tree);
+
+
+    let best = booster.best_round();
+    assert!(best < 1_000);
+    assert_eq!(f.hypotheses.len(), best);
+    assert!((squared_error(&valid, &f) - booster.best_loss()).abs() < 1e-12);
+
+
+    // The early stopped model is at least as good
+    // as the full model on the validation sample.
+    let mut full = GBM::init_with_loss(&train, GBMLoss::L2)
+        .max_iter(1_000);
+    let g = full.run(&tree);
+    assert!(squared_error(&valid, &f) <= squared_error(&valid, &g));
+}
+
+
+#[test]
+#[should_panic]
+fn zero_patience_panics() {
+    let train = diagonal_sample(300, 0.2, 1234);
+    let _ = AdaBoost::<DecisionTreeClassifier>::init(&train)
+        .validation(&train, 0);
+}
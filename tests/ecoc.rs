@@ -0,0 +1,136 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::Stump;
+
+
+// All the stumps over the grid `{0.05, 0.1, ..., 0.95}` and their negations.
+fn stumps() -> Vec<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["x", "y"] {
+        for k in 1..20 {
+            let threshold = k as f64 * 0.05;
+            for sign in [1.0, -1.0] {
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    stumps
+}
+
+
+// A random sample with four classes, one per quadrant of the unit square.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(&a, &b)| {
+            let i = if a < 0.5 { 0.0 } else { 1.0 };
+            let j = if b < 0.5 { 0.0 } else { 2.0 };
+            i + j
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+fn accuracy<H: MulticlassClassifier>(f: &H, sample: &Sample) -> f64 {
+    let n_sample = sample.shape().0 as f64;
+    f.predict_class_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(p, y)| *p == **y as i64)
+        .count() as f64
+        / n_sample
+}
+
+
+fn train(binary: &Sample) -> WeightedMajority<Stump> {
+    AdaBoost::init(binary)
+        .force_quit_at(20)
+        .run(&HypothesisPool::new(stumps()))
+}
+
+
+#[test]
+fn random_code_is_valid() {
+    let sample = random_sample();
+    let ecoc = ECOC::new(&sample);
+    let code = ecoc.coding_matrix();
+
+    // `⌈10 log₂ 4⌉ = 20` columns.
+    assert_eq!(code.len(), 4);
+    assert!(code.iter().all(|word| word.len() == 20));
+    for l in 0..20 {
+        assert!(code.iter().any(|word| word[l] == 1));
+        assert!(code.iter().any(|word| word[l] == -1));
+    }
+    assert_eq!(code, ECOC::new(&sample).coding_matrix());
+}
+
+
+#[test]
+fn ecoc_fits_with_both_decodings() {
+    let sample = random_sample();
+    for decoding in [Decoding::Hamming, Decoding::Loss] {
+        let f = ECOC::new(&sample)
+            .n_columns(7)
+            .decoding(decoding)
+            .run(train);
+        assert_eq!(f.hypotheses.len(), 7);
+        let acc = accuracy(&f, &sample);
+        assert!(acc > 0.95, "{decoding}: accuracy {acc}");
+    }
+}
+
+
+#[test]
+fn ternary_code_drops_examples() {
+    let sample = random_sample();
+
+    // The one-vs-one code.
+    let code = vec![
+        vec![ 1,  1,  1,  0,  0,  0],
+        vec![-1,  0,  0,  1,  1,  0],
+        vec![ 0, -1,  0, -1,  0,  1],
+        vec![ 0,  0, -1,  0, -1, -1],
+    ];
+    let ecoc = ECOC::new(&sample)
+        .code(code.clone());
+    let binary = ecoc.binary_sample(&code[..], 0);
+    let n_kept = sample.target()
+        .iter()
+        .filter(|&&y| y == 0.0 || y == 1.0)
+        .count();
+    assert_eq!(binary.shape().0, n_kept);
+    assert!(binary.target().iter().all(|&y| y == 1.0 || y == -1.0));
+
+
+    let f = ecoc.run(train);
+    let acc = accuracy(&f, &sample);
+    assert!(acc > 0.95, "accuracy {acc}");
+}
+
+
+#[test]
+#[should_panic]
+fn constant_column_panics() {
+    let sample = random_sample();
+    let _ = ECOC::new(&sample)
+        .code(vec![vec![1, 1], vec![1, -1], vec![1, 0], vec![0, 1]]);
+}
+
+
+#[test]
+#[should_panic]
+fn duplicated_code_words_panic() {
+    let sample = random_sample();
+    let _ = ECOC::new(&sample)
+        .code(vec![vec![1, 1], vec![1, 1], vec![-1, 1], vec![-1, -1]]);
+}
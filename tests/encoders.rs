@@ -0,0 +1,159 @@
+use miniboosts::prelude::*;
+use miniboosts::{Scaler, OneHotEncoder, TargetEncoder};
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+fn column(sample: &Sample, name: &str) -> Vec<f64> {
+    let feature = &sample[name];
+    (0..sample.shape().0).map(|i| feature[i]).collect()
+}
+
+
+fn small_sample() -> Sample {
+    let x = [0.5, 1.5, 2.5, 3.5, 4.5, 5.5];
+    let c = [Some(0.0), Some(0.0), Some(1.0), Some(1.0), Some(1.0), None];
+    let h = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+    let data = df!("x" => x, "c" => c, "h" => h).unwrap();
+    let target = Series::new("class", [1.0, 1.0, -1.0, -1.0, 1.0, -1.0]);
+    Sample::from_dataframe(data, target)
+        .unwrap()
+        .set_categorical(["c", "h"])
+}
+
+
+#[test]
+fn one_hot_encoder_expands_the_categories() {
+    let sample = small_sample();
+    let encoder = OneHotEncoder::fit_with(&sample, 3);
+    assert_eq!(encoder.categories("c"), Some(&[0, 1][..]));
+    // `h` has too many categories.
+    assert_eq!(encoder.categories("h"), None);
+    assert_eq!(encoder.categories("x"), None);
+
+    let encoded = encoder.transform(&sample);
+    assert_eq!(encoded.feature_names(), ["x", "c=0", "c=1", "h"]);
+    assert_eq!(column(&encoded, "x"), column(&sample, "x"));
+    assert_eq!(column(&encoded, "c=0")[..5], [1.0, 1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(column(&encoded, "c=1")[..5], [0.0, 0.0, 1.0, 1.0, 1.0]);
+    assert!(column(&encoded, "c=0")[5].is_nan());
+    assert!(!encoded.is_categorical("c=0"));
+    assert!(encoded.is_categorical("h"));
+    assert_eq!(encoded.target(), sample.target());
+
+    // An unseen category is mapped to all zeros.
+    let data = df!("x" => [0.0], "c" => [7.0], "h" => [0.0]).unwrap();
+    let test = Sample::from_dataframe(data, Series::new("class", [1.0]))
+        .unwrap();
+    let encoded = encoder.transform(&test);
+    assert_eq!(encoded.at(0).0, [0.0, 0.0, 0.0, 0.0]);
+
+    let json = serde_json::to_string(&encoder).unwrap();
+    let loaded: OneHotEncoder = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded, encoder);
+}
+
+
+#[test]
+fn target_encoder_replaces_the_categories() {
+    let sample = small_sample();
+    let encoder = TargetEncoder::fit_with(&sample, 2.0);
+    let prior = 0.0;
+    assert_eq!(encoder.prior(), prior);
+    // (1 + 1 + 2 * 0) / (2 + 2)
+    assert_eq!(encoder.encoding("c", 0), Some(0.5));
+    // (-1 - 1 + 1 + 2 * 0) / (3 + 2)
+    assert_eq!(encoder.encoding("c", 1), Some(-0.2));
+    assert_eq!(encoder.encoding("c", 7), Some(prior));
+    assert_eq!(encoder.encoding("x", 0), None);
+
+    let encoded = encoder.transform(&sample);
+    assert_eq!(encoded.feature_names(), ["x", "c", "h"]);
+    let c = column(&encoded, "c");
+    assert_eq!(c[..5], [0.5, 0.5, -0.2, -0.2, -0.2]);
+    assert!(c[5].is_nan());
+    assert!(!encoded.is_categorical("c"));
+    assert!(!encoded.is_categorical("h"));
+    assert_eq!(column(&encoded, "x"), column(&sample, "x"));
+}
+
+
+// A random sample whose label is positive with probability
+// `0.1 + 0.2 * c` for the category `c` of 5 categories.
+fn random_sample(n_sample: usize, seed: u64) -> Sample {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let c = (0..n_sample).map(|_| rng.gen_range(0..5) as f64)
+        .collect::<Vec<_>>();
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = c.iter()
+        .map(|&c| {
+            let p = 0.1 + 0.2 * c;
+            if rng.gen::<f64>() < p { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    to_sample(c, x, target)
+}
+
+
+fn to_sample(c: Vec<f64>, x: Vec<f64>, target: Vec<f64>) -> Sample {
+    let data = df!("c" => c, "x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target))
+        .unwrap()
+        .set_categorical(["c"])
+}
+
+
+#[test]
+fn out_of_fold_encodings_ignore_their_own_targets() {
+    let sample = random_sample(200, 1234);
+    let mut target = sample.target().to_vec();
+    target[0] = -target[0];
+    let flipped = to_sample(
+        column(&sample, "c"),
+        column(&sample, "x"),
+        target,
+    );
+
+    let encoder = TargetEncoder::fit(&sample);
+    let other = TargetEncoder::fit(&flipped);
+    let encoded = encoder.out_of_fold(&sample, 5);
+    let flipped = other.out_of_fold(&flipped, 5);
+    assert_eq!(column(&encoded, "c")[0], column(&flipped, "c")[0]);
+    assert_ne!(
+        column(&encoder.transform(&sample), "c")[0],
+        column(&other.transform(&sample), "c")[0],
+    );
+
+    assert_eq!(encoded.shape(), (200, 2));
+    assert!(!encoded.is_categorical("c"));
+    assert_eq!(column(&encoded, "x"), column(&sample, "x"));
+    // The encodings increase with the categories.
+    for k in 0..4 {
+        let lo = encoder.encoding("c", k).unwrap();
+        let hi = encoder.encoding("c", k + 1).unwrap();
+        assert!(lo < hi, "encoding({k}) = {lo}, encoding({}) = {hi}", k + 1);
+    }
+}
+
+
+#[test]
+fn encoded_hypotheses_encode_the_samples_to_predict() {
+    let train = random_sample(300, 1234);
+    let test = random_sample(100, 5678);
+
+    let encoder = OneHotEncoder::fit(&train);
+    let encoded = encoder.transform(&train);
+    let tree = DecisionTreeBuilder::new(&encoded)
+        .max_depth(2)
+        .build();
+    let f = AdaBoost::init(&encoded)
+        .force_quit_at(5)
+        .run(&tree);
+
+    let expected = f.confidence_all(&encoder.transform(&test));
+    let f = ScaledHypothesis::new(encoder, f);
+    assert_eq!(f.confidence_all(&test), expected);
+    (0..100).for_each(|i| {
+        assert!((f.confidence(&test, i) - expected[i]).abs() < 1e-12);
+    });
+}
@@ -0,0 +1,73 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{Stump, diagonal_sample};
+
+
+// All the stumps over the grid `{0.1, 0.2, ..., 0.9}` and their negations.
+fn stumps() -> HypothesisPool<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["x", "y"] {
+        for k in 1..10 {
+            let threshold = k as f64 * 0.1;
+            for sign in [1.0, -1.0] {
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    HypothesisPool::new(stumps)
+}
+
+
+#[test]
+fn epsilonboost_coefficient_path() {
+    let sample = diagonal_sample(200, 0.0, 1234);
+    let pool = stumps();
+    let epsilon = 0.01;
+
+    let mut booster = EpsilonBoost::init_with_loss(&sample, GBMLoss::Exponential)
+        .epsilon(epsilon)
+        .max_iter(500);
+    let f = booster.run(&pool);
+    assert_eq!(booster.terminated(), 500);
+
+
+    // Repeated hypotheses are merged.
+    let hypotheses = booster.hypotheses();
+    assert!(hypotheses.len() < 500);
+    assert_eq!(f.hypotheses.len(), hypotheses.len());
+
+
+    let path = booster.coefficient_path();
+    assert_eq!(path.len(), 500);
+    for (t, coefficients) in path.iter().enumerate() {
+        assert_eq!(coefficients.len(), hypotheses.len());
+        let l1_norm = coefficients.iter().sum::<f64>();
+        assert!((l1_norm - epsilon * (t + 1) as f64).abs() < 1e-9);
+    }
+    assert_eq!(path.last().unwrap(), booster.coefficients());
+
+
+    // The training loss decreases.
+    let target = sample.target();
+    let zeros = vec![0f64; target.len()];
+    let initial = GBMLoss::Exponential.eval(&zeros[..], target);
+    assert!(booster.objective_value() < 0.5 * initial);
+
+
+    let n_correct = f.predict_all(&sample)
+        .into_iter()
+        .zip(target)
+        .filter(|(p, y)| *p as f64 == **y)
+        .count();
+    assert!(n_correct >= 180, "{n_correct} of 200 examples are correct");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_epsilon_panics() {
+    let sample = diagonal_sample(200, 0.0, 1234);
+    let _ = EpsilonBoost::<Stump, _>::init_with_loss(&sample, GBMLoss::Exponential)
+        .epsilon(0.0);
+}
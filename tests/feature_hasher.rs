@@ -0,0 +1,51 @@
+use miniboosts::prelude::*;
+use miniboosts::FeatureHasher;
+use polars::prelude::*;
+
+
+fn toy_dataframe() -> DataFrame {
+    let s1 = Series::new("x", &[1.0, 2.0, 3.0, 4.0]);
+    let s2 = Series::new("city", &["Tokyo", "Paris", "Tokyo", "Lima"]);
+    DataFrame::new(vec![s1, s2]).unwrap()
+}
+
+
+#[test]
+fn hashed_columns_replace_categorical_ones() {
+    let df = toy_dataframe();
+    let hasher = FeatureHasher::new(8)
+        .columns(["city"]);
+    let hashed = hasher.transform(&df).unwrap();
+
+    // `x` and 8 hashed columns.
+    assert_eq!(hashed.shape(), (4, 9));
+    assert!(hashed.column("city").is_err());
+
+    // Each row has exactly one non-zero hashed value of magnitude 1.
+    let names = hasher.feature_names();
+    for i in 0..4 {
+        let total = names.iter()
+            .map(|name| {
+                hashed.column(name).unwrap().f64().unwrap().get(i).unwrap()
+            })
+            .map(f64::abs)
+            .sum::<f64>();
+        assert_eq!(total, 1.0);
+    }
+
+    // The same value is hashed into the same column.
+    let row = |i: usize| {
+        names.iter()
+            .map(|name| {
+                hashed.column(name).unwrap().f64().unwrap().get(i).unwrap()
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(row(0), row(2));
+    assert_eq!(hasher.transform(&df).unwrap(), hashed);
+
+
+    let target = Series::new("class", &[1.0, -1.0, 1.0, -1.0]);
+    let sample = Sample::from_dataframe(hashed, target).unwrap();
+    assert_eq!(sample.shape(), (4, 9));
+}
@@ -0,0 +1,86 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random sample whose label is positive iff `x + y > 1`,
+// with a noise feature `z` and a categorical feature `c`.
+fn random_sample(n_sample: usize) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let c = (0..n_sample).map(|i| (i % 3) as f64).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| if a + b > 1.0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let weights = (0..n_sample).map(|i| 1.0 + (i % 2) as f64).collect();
+    let data = df!("x" => x, "y" => y, "z" => z, "c" => c).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target))
+        .unwrap()
+        .set_categorical(["c"])
+        .with_weights(weights)
+}
+
+
+#[test]
+fn feature_names_and_indices() {
+    let sample = random_sample(10);
+    assert_eq!(sample.feature_names(), ["x", "y", "z", "c"]);
+    assert_eq!(sample.feature_index("z"), Some(2));
+    assert_eq!(sample.feature_index("w"), None);
+}
+
+
+#[test]
+fn selected_features_keep_the_rows() {
+    let sample = random_sample(100);
+    let selected = sample.select_features(["c", "x"]);
+    assert_eq!(selected.shape(), (100, 2));
+    assert_eq!(selected.feature_names(), ["c", "x"]);
+    assert_eq!(selected.feature_index("x"), Some(1));
+    assert_eq!(selected.target(), sample.target());
+    assert_eq!(selected.weights(), sample.weights());
+    assert!(selected.is_categorical("c"));
+    for i in 0..100 {
+        let (row, _) = selected.at(i);
+        let (full, _) = sample.at(i);
+        assert_eq!(row, [full[3], full[0]]);
+    }
+
+    let dropped = sample.drop_features(["z", "c"]);
+    assert_eq!(dropped.feature_names(), ["x", "y"]);
+    assert!(!dropped.is_categorical("c"));
+    assert_eq!(dropped.shape(), (100, 2));
+    // `self` is unchanged.
+    assert_eq!(sample.shape(), (100, 4));
+}
+
+
+#[test]
+fn hypotheses_trained_on_a_selection_predict_the_full_sample() {
+    let sample = random_sample(300);
+    let selected = sample.drop_features(["z"]);
+    let tree = DecisionTreeBuilder::new(&selected)
+        .max_depth(2)
+        .build();
+    let f = AdaBoost::init(&selected)
+        .force_quit_at(10)
+        .run(&tree);
+    assert_eq!(f.predict_all(&sample), f.predict_all(&selected));
+}
+
+
+#[test]
+#[should_panic]
+fn unknown_features_panic() {
+    random_sample(10).select_features(["x", "w"]);
+}
+
+
+#[test]
+#[should_panic]
+fn duplicate_features_panic() {
+    random_sample(10).select_features(["x", "x"]);
+}
@@ -0,0 +1,170 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+const NAMES: [&str; 6] = ["a", "b", "c", "d", "e", "f"];
+
+
+// A random sample with 6 features
+// whose label is positive iff `a + b > 1`.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let columns = NAMES.iter()
+        .map(|name| {
+            let values = (0..n_sample)
+                .map(|_| rng.gen::<f64>())
+                .collect::<Vec<_>>();
+            Series::new(name, values)
+        })
+        .collect::<Vec<_>>();
+    let target = (0..n_sample)
+        .map(|i| {
+            let a = columns[0].f64().unwrap().get(i).unwrap();
+            let b = columns[1].f64().unwrap().get(i).unwrap();
+            if a + b > 1.0 { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = DataFrame::new(columns).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+fn random_dist(sample: &Sample, rng: &mut StdRng) -> Vec<f64> {
+    let n_sample = sample.shape().0;
+    let dist = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let total = dist.iter().sum::<f64>();
+    dist.into_iter().map(|d| d / total).collect()
+}
+
+
+fn used_features_of_trees(tree: &DecisionTree, sample: &Sample)
+    -> Vec<Vec<String>>
+{
+    let mut rng = StdRng::seed_from_u64(0);
+    (0..10).map(|_| {
+            let dist = random_dist(sample, &mut rng);
+            tree.produce(sample, &dist)
+                .used_features()
+                .into_iter()
+                .map(|name| name.to_string())
+                .collect()
+        })
+        .collect()
+}
+
+
+#[test]
+fn each_tree_uses_a_subset_of_the_features() {
+    let sample = random_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .feature_subsample(0.5)
+        .build();
+
+    let used = used_features_of_trees(&tree, &sample);
+    let mut all = used.iter().flatten().collect::<Vec<_>>();
+    all.sort();
+    all.dedup();
+
+    assert!(used.iter().all(|names| names.len() <= 3));
+    assert!(all.len() > 3, "used features: {all:?}");
+}
+
+
+#[test]
+fn each_node_uses_a_subset_of_the_features() {
+    let sample = random_sample();
+    let dist = vec![1.0 / 300.0; 300];
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .feature_subsample_per_node(1.0 / 6.0)
+        .build();
+
+    // Each root only considers one feature,
+    // so that the roots split on various features.
+    let mut roots = (0..20)
+        .flat_map(|_| {
+            tree.produce(&sample, &dist)
+                .used_features()
+                .into_iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    roots.sort();
+    roots.dedup();
+    assert!(roots.len() > 1, "root features: {roots:?}");
+}
+
+
+#[test]
+fn forbidden_features_are_not_sampled() {
+    let sample = random_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .forbid_features(["a", "b", "c", "d"])
+        .feature_subsample(0.5)
+        .feature_subsample_per_node(0.5)
+        .build();
+
+    for names in used_features_of_trees(&tree, &sample) {
+        assert!(names.len() == 1, "used features: {names:?}");
+        assert!(names[0] == "e" || names[0] == "f");
+    }
+}
+
+
+#[test]
+fn the_seed_determines_the_subsets() {
+    let sample = random_sample();
+    let build = |seed| {
+        DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .feature_subsample(0.5)
+            .feature_subsample_per_node(0.5)
+            .seed(seed)
+            .build()
+    };
+
+    let x = used_features_of_trees(&build(7), &sample);
+    let y = used_features_of_trees(&build(7), &sample);
+    let z = used_features_of_trees(&build(8), &sample);
+    assert_eq!(x, y);
+    assert_ne!(x, z);
+}
+
+
+#[test]
+fn gbm_fits_with_the_feature_subsampling() {
+    let sample = random_sample();
+    let target = sample.target();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .feature_subsample(0.5)
+        .feature_subsample_per_node(0.5)
+        .seed(7)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .max_iter(100);
+    let f = booster.run(&tree);
+
+    let n_correct = f.predict_all(&sample)
+        .into_iter()
+        .zip(target)
+        .filter(|(p, y)| p.signum() == **y)
+        .count();
+    assert!(n_correct >= 270, "{n_correct} of 300 examples are correct");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_fraction_panics() {
+    let sample = random_sample();
+    let _ = DecisionTreeBuilder::new(&sample)
+        .feature_subsample(0.0);
+}
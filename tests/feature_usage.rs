@@ -0,0 +1,57 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+fn toy_sample() -> Sample {
+    let s1 = Series::new("x", &[10.0, 14.0, 15.0, 5.0, 3.0,  8.0, 12.0]);
+    let s2 = Series::new("y", &[ 5.0,  8.0,  3.0, 1.0, 9.0, 13.0, 11.0]);
+    let s3 = Series::new("z", &[ 1.0,  2.0,  1.0, 2.0, 1.0,  2.0,  1.0]);
+    let df = DataFrame::new(vec![s1, s2, s3]).unwrap();
+    let target = Series::new(
+        "class", &[1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0]
+    );
+
+    Sample::from_dataframe(df, target).unwrap()
+}
+
+
+#[test]
+fn forbidden_features_are_not_used() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .forbid_features(["x"])
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&dtree);
+
+    assert!(!f.used_features().contains(&"x"));
+}
+
+
+#[test]
+fn feature_budget_is_respected() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .feature_budget(1)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&dtree);
+    assert_eq!(f.used_features().len(), 1);
+
+
+    let rtree = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .feature_budget(2)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2);
+    let f = booster.run(&rtree);
+    assert!(f.used_features().len() <= 2);
+}
@@ -0,0 +1,61 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+// The label is positive iff `x > 50`.
+fn toy_sample() -> Sample {
+    let x = (0..100).map(|i| i as f64).collect::<Vec<_>>();
+    let y = (0..100).map(|i| ((i * 37) % 100) as f64).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|&x| if x > 50.0 { 1.0 } else { -1.0 })
+        .collect::<Vec<_>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    let target = Series::new("class", target);
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn filterboost_learns_from_filtered_examples() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = FilterBoost::init(&sample)
+        .budget(30)
+        .tolerance(0.05)
+        .force_quit_at(20);
+    let f = booster.run(&tree);
+
+    assert!(booster.n_draws() > 0);
+    let target = sample.target();
+    let n_correct = f.predict_all(&sample)
+        .into_iter()
+        .zip(target.iter())
+        .filter(|(p, y)| *p == **y as i64)
+        .count();
+    assert!(n_correct >= 95);
+}
+
+
+#[test]
+fn filterboost_is_reproducible() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = FilterBoost::init(&sample)
+        .budget(20)
+        .seed(7)
+        .force_quit_at(5);
+    let f = booster.run(&tree);
+    let n_draws = booster.n_draws();
+    let g = booster.run(&tree);
+
+    assert_eq!(n_draws, booster.n_draws());
+    assert_eq!(f.weights, g.weights);
+}
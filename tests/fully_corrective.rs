@@ -0,0 +1,109 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+
+mod common;
+use common::diagonal_sample;
+
+
+// The soft margin objective of the normalized additive model `F / ||α||_1`.
+fn soft_margin<H: Regressor>(
+    sample: &Sample,
+    f: &WeightedMajority<H>,
+    nu: f64,
+) -> f64
+{
+    let norm = f.weights.iter().map(|a| a.abs()).sum::<f64>();
+    let mut margins = f.predict_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .map(|(p, y)| y * p / norm)
+        .collect::<Vec<_>>();
+    margins.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut weight_left = 1.0;
+    let mut value = 0.0;
+    for yh in margins {
+        let w = (1.0 / nu).min(weight_left);
+        value += w * yh;
+        weight_left -= w;
+        if weight_left <= 0.0 { break; }
+    }
+    value
+}
+
+
+#[test]
+fn adaboost_corrective_postprocess() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let nu = 20.0;
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut plain = AdaBoost::init(&sample)
+        .force_quit_at(100);
+    let f = plain.run(&tree);
+
+    let mut corrective = AdaBoost::init(&sample)
+        .force_quit_at(100)
+        .fully_corrective_postprocess(nu);
+    let g = corrective.run(&tree);
+
+
+    // The weak-learning loop is the same.
+    assert_eq!(plain.n_rounds(), corrective.n_rounds());
+
+    assert!(
+        g.hypotheses.len() < f.hypotheses.len(),
+        "{} >= {}", g.hypotheses.len(), f.hypotheses.len()
+    );
+    assert!((g.weights.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+    let objective = SoftMarginObjective::new(nu);
+    let before = objective.eval(&sample, &f);
+    let after = objective.eval(&sample, &g);
+    assert!(after >= before - 1e-6, "{after} < {before}");
+}
+
+
+#[test]
+fn gbm_corrective_postprocess() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let nu = 20.0;
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::LogLoss)
+        .build();
+
+    let mut plain = GBM::init_with_loss(&sample, GBMLoss::LogLoss)
+        .max_iter(50);
+    let f = plain.run(&tree);
+
+    let mut corrective = GBM::init_with_loss(&sample, GBMLoss::LogLoss)
+        .max_iter(50)
+        .fully_corrective_postprocess(nu);
+    let g = corrective.run(&tree);
+
+
+    assert!(g.hypotheses.len() <= f.hypotheses.len());
+
+    // The L1-norm of the coefficients is kept.
+    let norm_f = f.weights.iter().map(|a| a.abs()).sum::<f64>();
+    let norm_g = g.weights.iter().map(|a| a.abs()).sum::<f64>();
+    assert!((norm_f - norm_g).abs() < 1e-6 * norm_f);
+
+    let before = soft_margin(&sample, &f, nu);
+    let after = soft_margin(&sample, &g, nu);
+    assert!(after >= before - 1e-6, "{after} < {before}");
+}
+
+
+#[test]
+#[should_panic]
+fn invalid_nu_panics() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let _ = AdaBoost::<DecisionTreeClassifier>::init(&sample)
+        .fully_corrective_postprocess(0.5);
+}
@@ -0,0 +1,71 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+
+mod common;
+use common::{Stump, diagonal_sample};
+
+
+// All the stumps over the grid `{0.05, 0.1, ..., 0.95}` and their negations.
+fn stumps() -> HypothesisPool<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["x", "y"] {
+        for k in 1..20 {
+            let threshold = k as f64 * 0.05;
+            for sign in [1.0, -1.0] {
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    HypothesisPool::new(stumps)
+}
+
+
+fn run(optimistic: bool) {
+    let sample = diagonal_sample(200, 0.1, 1234);
+    let pool = stumps();
+    let nu = 20.0;
+    let tolerance = 0.05;
+
+    let mut booster = GameBoost::init(&sample)
+        .tolerance(tolerance)
+        .nu(nu)
+        .optimistic(optimistic);
+    let f = booster.run(&pool);
+    assert!(booster.terminated() <= booster.max_loop());
+    assert!(booster.certificate().is_within(tolerance));
+
+
+    // The objective is close to the optimal one found by `LPBoost`.
+    let mut lpboost = LPBoost::init(&sample)
+        .tolerance(1e-4)
+        .nu(nu);
+    let g = lpboost.run(&pool);
+
+    let objective = SoftMarginObjective::new(nu);
+    let game = objective.eval(&sample, &f);
+    let lp = objective.eval(&sample, &g);
+    assert!(game >= lp - tolerance - 1e-4, "{game} < {lp}");
+    assert!((booster.certificate().gamma_star - game).abs() < 1e-9);
+}
+
+
+#[test]
+fn gameboost_approximates_the_soft_margin() {
+    run(false);
+}
+
+
+#[test]
+fn optimistic_gameboost_approximates_the_soft_margin() {
+    run(true);
+}
+
+
+#[test]
+#[should_panic]
+fn nonpositive_tolerance_panics() {
+    let sample = diagonal_sample(200, 0.1, 1234);
+    let _ = GameBoost::<Stump>::init(&sample)
+        .tolerance(0.0);
+}
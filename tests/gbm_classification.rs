@@ -0,0 +1,58 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+fn run(loss: GBMLoss) {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let n_sample = sample.shape().0 as f64;
+
+    let mut booster = GBM::init_with_loss(&sample, loss);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(loss)
+        .build();
+    let f = booster.run(&tree);
+
+
+    // The training loss is less than the one of `F = 0`.
+    let target = sample.target();
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    assert!(loss.eval(&scores[..], target) < loss.eval(&zeros[..], target));
+
+
+    let probabilities = f.predict_proba_all(&sample, loss);
+    assert!(probabilities.iter().all(|p| (0.0..=1.0).contains(p)));
+
+    let n_correct = probabilities.iter()
+        .zip(&scores[..])
+        .zip(target)
+        .filter(|((p, s), y)| {
+            // The probability is consistent with the score.
+            assert_eq!(**p > 0.5, **s > 0.0);
+            (**p > 0.5) == (**y > 0.0)
+        })
+        .count() as f64;
+    assert!(n_correct / n_sample > 0.8, "accuracy: {}", n_correct / n_sample);
+}
+
+
+#[test]
+fn gbm_logloss() {
+    run(GBMLoss::LogLoss);
+}
+
+
+#[test]
+fn gbm_exponential() {
+    run(GBMLoss::Exponential);
+}
+
+
+#[test]
+#[should_panic]
+fn probability_for_regression_loss() {
+    let _ = GBMLoss::L2.probability(0.0);
+}
@@ -0,0 +1,82 @@
+use miniboosts::prelude::*;
+use miniboosts::research::Research;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::tree;
+
+
+// A random sample with `y = sin(6x) + ε`, where `ε ~ U[-0.2, 0.2]`.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 1_000;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|a| (6.0 * a).sin() + rng.gen_range(-0.2..0.2))
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("y", target)).unwrap()
+}
+
+
+#[test]
+fn goss_fits() {
+    let sample = random_sample();
+    let target = sample.target();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .goss(0.2, 0.1);
+    let f = booster.run(&tree(&sample));
+
+
+    let predictions = f.predict_all(&sample);
+    let zeros = vec![0f64; target.len()];
+    let loss = GBMLoss::L2.eval(&predictions[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.1 * initial, "loss: {loss}, initial: {initial}");
+
+
+    // The examples not chosen by GOSS are out-of-bag.
+    let oob_loss = booster.out_of_bag_loss()
+        .expect("The out-of-bag loss is not reported");
+    assert!(oob_loss < 0.1 * initial, "out-of-bag loss: {oob_loss}");
+}
+
+
+#[test]
+fn goss_is_reproducible() {
+    let sample = random_sample();
+
+    let run = |seed| {
+        let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .goss(0.2, 0.1)
+            .seed(seed);
+        booster.run(&tree(&sample)).predict_all(&sample)
+    };
+    assert_eq!(run(1234), run(1234));
+    assert_ne!(run(1234), run(5678));
+}
+
+
+#[test]
+fn unit_weights_do_nothing() {
+    let sample = random_sample();
+    let wl = tree(&sample);
+
+    let n_sample = sample.shape().0;
+    let predictions = vec![0f64; n_sample];
+    let ones = vec![1f64; n_sample];
+    let h = wl.produce(&sample, &predictions[..]);
+    let g = wl.produce_with_weights(&sample, &predictions[..], &ones[..]);
+    assert_eq!(h.predict_all(&sample), g.predict_all(&sample));
+}
+
+
+#[test]
+#[should_panic]
+fn invalid_rates() {
+    let sample = random_sample();
+    let _ = GBM::<RegressionTreeRegressor, _>::init_with_loss(&sample, GBMLoss::L2)
+        .goss(0.6, 0.5);
+}
@@ -0,0 +1,119 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// Draws a sample from the Poisson distribution with mean `mean`.
+fn poisson(rng: &mut StdRng, mean: f64) -> f64 {
+    let threshold = (-mean).exp();
+    let mut count = 0;
+    let mut prod = rng.gen::<f64>();
+    while prod > threshold {
+        count += 1;
+        prod *= rng.gen::<f64>();
+    }
+    count as f64
+}
+
+
+// A random sample of counts with mean `exp(0.5 + 1.5 x)`.
+// Returns the sample and the true means.
+fn random_sample() -> (Sample, Vec<f64>) {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 500;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let means = x.iter()
+        .map(|a| (0.5 + 1.5 * a).exp())
+        .collect::<Vec<_>>();
+    let target = means.iter()
+        .map(|m| poisson(&mut rng, *m))
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    let sample = Sample::from_dataframe(data, Series::new("count", target))
+        .unwrap();
+    (sample, means)
+}
+
+
+fn run(loss: GBMLoss) {
+    let (sample, means) = random_sample();
+    let target = sample.target();
+    let n_sample = target.len() as f64;
+
+    let mut booster = GBM::init_with_loss(&sample, loss);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(loss)
+        .build();
+    let f = booster.run(&tree);
+
+
+    // The training loss is less than the one of `F = 0`.
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    assert!(loss.eval(&scores[..], target) < loss.eval(&zeros[..], target));
+
+
+    // The predicted means are closer to the true means
+    // than the average of the targets.
+    let predicted = f.predict_mean_all(&sample, loss);
+    assert!(predicted.iter().all(|m| *m > 0.0));
+    let average = target.iter().sum::<f64>() / n_sample;
+    let model_error = predicted.iter()
+        .zip(&means[..])
+        .map(|(p, m)| (p - m).abs())
+        .sum::<f64>()
+        / n_sample;
+    let constant_error = means.iter()
+        .map(|m| (average - m).abs())
+        .sum::<f64>()
+        / n_sample;
+    assert!(
+        model_error < constant_error,
+        "model: {model_error}, constant: {constant_error}"
+    );
+}
+
+
+#[test]
+fn poisson_loss() {
+    run(GBMLoss::Poisson);
+}
+
+
+#[test]
+fn tweedie_loss() {
+    run(GBMLoss::Tweedie(1.5));
+}
+
+
+#[test]
+#[should_panic(expected = "nonnegative")]
+fn negative_target() {
+    let x = vec![0.0, 1.0, 2.0];
+    let target = vec![1.0, -1.0, 3.0];
+    let data = df!("x" => x).unwrap();
+    let sample = Sample::from_dataframe(data, Series::new("count", target))
+        .unwrap();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::Poisson);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::Poisson)
+        .build();
+    let _ = booster.run(&tree);
+}
+
+
+#[test]
+#[should_panic]
+fn invalid_tweedie_power() {
+    let _ = GBMLoss::Tweedie(2.5).gradient(&[0.0], &[1.0]);
+}
+
+
+#[test]
+#[should_panic]
+fn mean_of_l2() {
+    let _ = GBMLoss::L2.mean(0.0);
+}
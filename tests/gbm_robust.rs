@@ -0,0 +1,104 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random sample with `y = 2x + ε`, where `ε ~ U[-1, 1]`.
+// If `n_outlier > 0`, the first `n_outlier` targets are shifted by `100`.
+fn random_sample(n_outlier: usize) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 500;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .enumerate()
+        .map(|(i, a)| {
+            let noise = rng.gen_range(-1.0..1.0);
+            let shift = if i < n_outlier { 100.0 } else { 0.0 };
+            2.0 * a + noise + shift
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("y", target)).unwrap()
+}
+
+
+fn fit(sample: &Sample, loss: GBMLoss) -> Vec<f64> {
+    let mut booster = GBM::init_with_loss(sample, loss);
+    let tree = RegressionTreeBuilder::new(sample)
+        .max_depth(2)
+        .loss(loss)
+        .build();
+    let f = booster.run(&tree);
+    f.predict_all(sample)
+}
+
+
+#[test]
+fn quantile() {
+    let sample = random_sample(0);
+    let target = sample.target();
+    let n_sample = target.len() as f64;
+
+    let lower = fit(&sample, GBMLoss::Quantile(0.1));
+    let upper = fit(&sample, GBMLoss::Quantile(0.9));
+
+
+    // About 10% of targets lie below `lower`,
+    // and about 90% lie below `upper`.
+    let below = |pred: &[f64]| {
+        target.iter()
+            .zip(pred)
+            .filter(|(y, p)| y < p)
+            .count() as f64
+            / n_sample
+    };
+    let ratio = below(&lower[..]);
+    assert!((ratio - 0.1).abs() < 0.05, "ratio below lower: {ratio}");
+    let ratio = below(&upper[..]);
+    assert!((ratio - 0.9).abs() < 0.05, "ratio below upper: {ratio}");
+
+    // The lower quantile is below the upper one.
+    assert!(lower.iter().zip(&upper[..]).all(|(l, u)| l < u));
+}
+
+
+#[test]
+fn huber_is_robust_to_outliers() {
+    let n_outlier = 25;
+    let sample = random_sample(n_outlier);
+    let target = sample.target();
+
+
+    // The mean absolute error on the clean examples.
+    let clean_error = |pred: &[f64]| {
+        target.iter()
+            .zip(pred)
+            .skip(n_outlier)
+            .map(|(y, p)| (y - p).abs())
+            .sum::<f64>()
+            / (target.len() - n_outlier) as f64
+    };
+
+
+    let huber = fit(&sample, GBMLoss::Huber(1.0));
+    let l2 = fit(&sample, GBMLoss::L2);
+
+    let huber_error = clean_error(&huber[..]);
+    let l2_error = clean_error(&l2[..]);
+    assert!(
+        huber_error < l2_error,
+        "Huber: {huber_error}, L2: {l2_error}"
+    );
+
+    let loss = GBMLoss::Huber(1.0);
+    let zeros = vec![0f64; target.len()];
+    assert!(loss.eval(&huber[..], target) < loss.eval(&zeros[..], target));
+}
+
+
+#[test]
+#[should_panic]
+fn invalid_quantile() {
+    let sample = random_sample(0);
+    let _ = fit(&sample, GBMLoss::Quantile(1.5));
+}
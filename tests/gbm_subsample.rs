@@ -0,0 +1,80 @@
+use miniboosts::prelude::*;
+use miniboosts::research::Research;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random sample with `y = sin(6x) + ε`, where `ε ~ U[-0.2, 0.2]`.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 400;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|a| (6.0 * a).sin() + rng.gen_range(-0.2..0.2))
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("y", target)).unwrap()
+}
+
+
+fn run(sample: &Sample, fraction: f64, seed: u64) -> (Vec<f64>, Option<f64>) {
+    let mut booster = GBM::init_with_loss(sample, GBMLoss::L2)
+        .subsample(fraction)
+        .seed(seed);
+    let tree = RegressionTreeBuilder::new(sample)
+        .max_depth(2)
+        .loss(GBMLoss::L2)
+        .build();
+    let f = booster.run(&tree);
+    (f.predict_all(sample), booster.out_of_bag_loss())
+}
+
+
+#[test]
+fn stochastic_gradient_boosting() {
+    let sample = random_sample();
+    let target = sample.target();
+    let zeros = vec![0f64; target.len()];
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+
+
+    let (predictions, oob_loss) = run(&sample, 0.5, 1234);
+    let train_loss = GBMLoss::L2.eval(&predictions[..], target);
+    assert!(train_loss < 0.1 * initial, "train loss: {train_loss}");
+
+
+    // The out-of-bag loss estimates the loss of the resulting model.
+    let oob_loss = oob_loss.expect("The out-of-bag loss is not reported");
+    assert!(oob_loss < 0.1 * initial, "out-of-bag loss: {oob_loss}");
+}
+
+
+#[test]
+fn subsampling_is_reproducible() {
+    let sample = random_sample();
+
+    let (p1, l1) = run(&sample, 0.5, 1234);
+    let (p2, l2) = run(&sample, 0.5, 1234);
+    assert_eq!(p1, p2);
+    assert_eq!(l1, l2);
+
+    let (p3, _) = run(&sample, 0.5, 5678);
+    assert_ne!(p1, p3);
+}
+
+
+#[test]
+fn no_out_of_bag_examples() {
+    let sample = random_sample();
+    let (_, oob_loss) = run(&sample, 1.0, 1234);
+    assert!(oob_loss.is_none());
+}
+
+
+#[test]
+#[should_panic]
+fn invalid_fraction() {
+    let sample = random_sample();
+    let _ = GBM::<RegressionTreeRegressor, _>::init_with_loss(&sample, GBMLoss::L2)
+        .subsample(0.0);
+}
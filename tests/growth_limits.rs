@@ -0,0 +1,124 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{diagonal_sample, training_error, uniform};
+
+
+#[test]
+fn max_leaves_bounds_the_leaves() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+
+    let mut prev_error = f64::MAX;
+    for max_leaves in 1..=8 {
+        let tree = DecisionTreeBuilder::new(&sample)
+            .max_depth(10)
+            .max_leaves(max_leaves)
+            .criterion(Criterion::Entropy)
+            .build();
+        let f = tree.produce(&sample, &dist);
+        assert!(f.n_leaves() <= max_leaves);
+
+        // The best-first trees are nested.
+        let error = training_error(&f, &sample);
+        assert!(error <= prev_error, "{error} > {prev_error}");
+        prev_error = error;
+    }
+}
+
+
+#[test]
+fn best_first_growth_reaches_the_depth_first_tree() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+
+    for criterion in [Criterion::Entropy, Criterion::Gini, Criterion::Edge] {
+        let depth_first = DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .criterion(criterion)
+            .build()
+            .produce(&sample, &dist);
+        let best_first = DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .max_leaves(100)
+            .criterion(criterion)
+            .build()
+            .produce(&sample, &dist);
+        assert_eq!(
+            depth_first.predict_all(&sample),
+            best_first.predict_all(&sample),
+        );
+    }
+}
+
+
+#[test]
+fn min_samples_leaf_bounds_the_leaves() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(10)
+        .min_samples_leaf(50)
+        .build();
+    let f = tree.produce(&sample, &dist);
+    assert!(f.n_leaves() <= 6, "# of leaves: {}", f.n_leaves());
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(10)
+        .max_leaves(20)
+        .min_samples_leaf(50)
+        .build();
+    let f = tree.produce(&sample, &dist);
+    assert!(f.n_leaves() <= 6, "# of leaves: {}", f.n_leaves());
+}
+
+
+#[test]
+fn min_split_gain_stops_the_growth() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(10)
+        .min_split_gain(1.0)
+        .build();
+    let f = tree.produce(&sample, &dist);
+    assert_eq!(f.n_leaves(), 1);
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(10)
+        .min_split_gain(0.01)
+        .build();
+    let f = tree.produce(&sample, &dist);
+    let unlimited = DecisionTreeBuilder::new(&sample)
+        .max_depth(10)
+        .build()
+        .produce(&sample, &dist);
+    assert!(f.n_leaves() > 1);
+    assert!(f.n_leaves() < unlimited.n_leaves());
+}
+
+
+#[test]
+fn produce_many_respects_max_leaves() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(10)
+        .max_leaves(4)
+        .build();
+    let trees = tree.produce_many(&sample, &dist, 3);
+    assert!(!trees.is_empty());
+    assert!(trees.iter().all(|f| f.n_leaves() <= 4));
+}
+
+
+#[test]
+#[should_panic]
+fn zero_max_leaves_panics() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let _ = DecisionTreeBuilder::new(&sample)
+        .max_leaves(0);
+}
@@ -0,0 +1,147 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+// A random sample over the grid `{0, 1, ..., 9}^3` with noisy labels.
+// The default bins of the trees separate the distinct values,
+// and so do the quantile bins.
+fn grid_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 500;
+    let mut features = (0..3).map(|_| {
+            (0..n_sample)
+                .map(|_| rng.gen_range(0..10) as f64)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let target = (0..n_sample)
+        .map(|i| {
+            let sum = features[0][i] + features[1][i];
+            let y = if sum > 9.0 { 1.0 } else { -1.0 };
+            if rng.gen::<f64>() < 0.1 { -y } else { y }
+        })
+        .collect::<Vec<f64>>();
+    let z = features.pop().unwrap();
+    let y = features.pop().unwrap();
+    let x = features.pop().unwrap();
+    let data = df!("x" => x, "y" => y, "z" => z).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+fn random_distribution(n_sample: usize, rng: &mut StdRng) -> Vec<f64> {
+    let dist = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let sum = dist.iter().sum::<f64>();
+    dist.into_iter().map(|d| d / sum).collect()
+}
+
+
+#[test]
+fn histogram_decision_tree_matches_the_scan() {
+    let sample = grid_sample();
+    let n_sample = sample.shape().0;
+    let scan = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .build();
+    let hist = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .bins(256)
+        .build();
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..5 {
+        let dist = random_distribution(n_sample, &mut rng);
+        let f = scan.produce(&sample, &dist);
+        let g = hist.produce(&sample, &dist);
+        assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+    }
+}
+
+
+#[test]
+fn histogram_regression_tree_matches_the_scan() {
+    let sample = grid_sample();
+    let n_sample = sample.shape().0;
+    let scan = RegressionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .loss(GBMLoss::L2)
+        .build();
+    let hist = RegressionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .loss(GBMLoss::L2)
+        .bins(256)
+        .build();
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..5 {
+        let predictions = (0..n_sample)
+            .map(|_| rng.gen::<f64>() - 0.5)
+            .collect::<Vec<_>>();
+        let f = scan.produce(&sample, &predictions);
+        let g = hist.produce(&sample, &predictions);
+        f.predict_all(&sample)
+            .into_iter()
+            .zip(g.predict_all(&sample))
+            .for_each(|(p, q)| assert!((p - q).abs() < 1e-9, "{p} != {q}"));
+    }
+}
+
+
+#[test]
+fn adaboost_with_histogram_trees() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .criterion(Criterion::Entropy)
+        .bins(16)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(100);
+    let f = booster.run(&tree);
+
+    let n_sample = sample.shape().0 as f64;
+    let error = f.predict_all(&sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(p, y)| *p as f64 != **y)
+        .count() as f64
+        / n_sample;
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+fn gbm_with_histogram_trees() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let target = sample.target();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::LogLoss)
+        .bins(16)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::LogLoss)
+        .max_iter(100);
+    let f = booster.run(&tree);
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::LogLoss.eval(&scores[..], target);
+    let initial = GBMLoss::LogLoss.eval(&zeros[..], target);
+    assert!(loss < 0.5 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_bins_panics() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let _ = DecisionTreeBuilder::new(&sample).bins(0);
+}
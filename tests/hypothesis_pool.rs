@@ -0,0 +1,89 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{Stump, diagonal_sample, training_error};
+
+
+// All the stumps over the grid `{0.05, 0.1, ..., 0.95}` and their negations.
+fn stumps() -> Vec<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["x", "y"] {
+        for k in 1..20 {
+            let threshold = k as f64 * 0.05;
+            for sign in [1.0, -1.0] {
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    stumps
+}
+
+
+fn edge(sample: &Sample, dist: &[f64], h: &Stump) -> f64 {
+    sample.target()
+        .iter()
+        .zip(dist)
+        .enumerate()
+        .map(|(i, (y, d))| d * y * h.confidence(sample, i))
+        .sum::<f64>()
+}
+
+
+#[test]
+fn pool_returns_the_best_edge() {
+    let sample = diagonal_sample(200, 0.0, 1234);
+    let n_sample = sample.shape().0;
+    let pool = HypothesisPool::new(stumps());
+    assert_eq!(pool.len(), 76);
+
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+    let best = pool.produce(&sample, &dist[..]);
+    let best_edge = edge(&sample, &dist[..], &best);
+    for h in pool.hypotheses() {
+        assert!(edge(&sample, &dist[..], h) <= best_edge);
+    }
+
+
+    let candidates = pool.produce_many(&sample, &dist[..], 5);
+    assert_eq!(candidates.len(), 5);
+    assert_eq!(candidates[0], best);
+    let edges = candidates.iter()
+        .map(|h| edge(&sample, &dist[..], h))
+        .collect::<Vec<_>>();
+    assert!(edges.windows(2).all(|w| w[0] >= w[1]));
+}
+
+
+#[test]
+fn boosters_run_over_the_pool() {
+    let sample = diagonal_sample(200, 0.0, 1234);
+    let pool = HypothesisPool::new(stumps());
+
+    let mut adaboost = AdaBoost::init(&sample)
+        .force_quit_at(100);
+    let f = adaboost.run(&pool);
+    assert!(f.hypotheses.iter().all(|h| pool.hypotheses().contains(h)));
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "AdaBoost: {error}");
+
+
+    let mut lpboost = LPBoost::init(&sample)
+        .tolerance(0.01);
+    let f = lpboost.run(&pool);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "LPBoost: {error}");
+
+
+    let mut erlpboost = ERLPBoost::init(&sample)
+        .tolerance(0.1);
+    let f = erlpboost.run(&pool);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "ERLPBoost: {error}");
+}
+
+
+#[test]
+#[should_panic]
+fn empty_pool_panics() {
+    let _ = HypothesisPool::<Stump>::new(Vec::new());
+}
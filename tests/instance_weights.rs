@@ -0,0 +1,163 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{Stump, diagonal_sample, training_error};
+
+
+// All the stumps over the grid `{0.05, 0.1, ..., 0.95}` and their negations.
+fn stumps() -> Vec<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["x", "y"] {
+        for k in 1..20 {
+            let threshold = k as f64 * 0.05;
+            for sign in [1.0, -1.0] {
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    stumps
+}
+
+
+#[test]
+fn weights_are_read_from_a_column() {
+    let data = df!(
+        "x" => [0.1, 0.2, 0.3],
+        "w" => [1.0, 2.0, 3.0],
+    ).unwrap();
+    let target = Series::new("class", [1.0, -1.0, 1.0]);
+    let sample = Sample::from_dataframe(data, target).unwrap()
+        .set_weights("w");
+
+    assert_eq!(sample.shape(), (3, 1));
+    assert_eq!(sample.weights(), Some(&[1.0, 2.0, 3.0][..]));
+
+
+    let mut other = sample.clone();
+    other.extend_from(&sample).unwrap();
+    assert_eq!(other.weights().unwrap().len(), 6);
+
+    let data = df!("x" => [0.4]).unwrap();
+    let target = Series::new("class", [1.0]);
+    assert!(other.push_rows(data, target).is_err());
+}
+
+
+#[test]
+fn adaboost_weights_act_as_duplicates() {
+    let sample = diagonal_sample(100, 0.0, 1234);
+    let weights = (0..100)
+        .map(|i| if i % 3 == 0 { 2.0 } else { 1.0 })
+        .collect::<Vec<_>>();
+    let weighted = sample.clone()
+        .with_weights(weights);
+
+
+    // Duplicate every third row.
+    let mut duplicated = sample.clone();
+    for i in (0..100).step_by(3) {
+        let (x, y) = sample.at(i);
+        let data = df!("x" => [x[0]], "y" => [x[1]]).unwrap();
+        let target = Series::new("class", [y]);
+        duplicated.push_rows(data, target).unwrap();
+    }
+
+
+    let mut booster = AdaBoost::init(&weighted)
+        .force_quit_at(20);
+    let f = booster.run(&HypothesisPool::new(stumps()));
+
+    let mut booster = AdaBoost::init(&duplicated)
+        .force_quit_at(20);
+    let g = booster.run(&HypothesisPool::new(stumps()));
+
+    assert_eq!(f.hypotheses, g.hypotheses);
+    for (a, b) in f.weights.iter().zip(&g.weights) {
+        assert!((a - b).abs() < 1e-9, "{a} vs {b}");
+    }
+}
+
+
+#[test]
+fn constant_weights_change_nothing() {
+    let plain = diagonal_sample(100, 0.0, 1234);
+    let weighted = plain.clone()
+        .with_weights(vec![3.0; 100]);
+
+
+    let mut booster = LPBoost::init(&plain)
+        .nu(5.0);
+    let f = booster.run(&HypothesisPool::new(stumps()));
+    let mut booster = LPBoost::init(&weighted)
+        .nu(5.0);
+    let g = booster.run(&HypothesisPool::new(stumps()));
+    assert_eq!(f.predict_all(&plain), g.predict_all(&plain));
+
+
+    let mut booster = ERLPBoost::init(&plain)
+        .tolerance(0.1)
+        .nu(5.0);
+    let f = booster.run(&HypothesisPool::new(stumps()));
+    let mut booster = ERLPBoost::init(&weighted)
+        .tolerance(0.1)
+        .nu(5.0);
+    let g = booster.run(&HypothesisPool::new(stumps()));
+    assert_eq!(f.predict_all(&plain), g.predict_all(&plain));
+}
+
+
+#[test]
+fn soft_margin_boosters_fit_weighted_samples() {
+    let mut rng = StdRng::seed_from_u64(5678);
+    let weights = (0..100)
+        .map(|_| rng.gen_range(0.5..2.0))
+        .collect::<Vec<_>>();
+    let sample = diagonal_sample(100, 0.0, 1234)
+        .with_weights(weights);
+
+
+    let mut booster = LPBoost::init(&sample)
+        .nu(5.0);
+    let f = booster.run(&HypothesisPool::new(stumps()));
+    let error = training_error(&f, &sample);
+    assert!(error < 0.2, "LPBoost: training error {error}");
+
+
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(0.1)
+        .nu(5.0);
+    let f = booster.run(&HypothesisPool::new(stumps()));
+    let error = training_error(&f, &sample);
+    assert!(error < 0.2, "ERLPBoost: training error {error}");
+}
+
+
+#[test]
+fn gbm_leaves_are_weighted_means() {
+    let data = df!("x" => [0.0, 0.1, 0.9, 1.0]).unwrap();
+    let target = Series::new("y", [0.0, 4.0, 10.0, 10.0]);
+    let sample = Sample::from_dataframe(data, target).unwrap()
+        .with_weights(vec![3.0, 1.0, 1.0, 1.0]);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .lambda_l2(0.0)
+        .loss(GBMLoss::L2)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .max_iter(1);
+    let f = booster.run(&tree);
+    let predictions = f.predict_all(&sample);
+    assert!((predictions[0] - 1.0).abs() < 1e-9, "{predictions:?}");
+    assert!((predictions[2] - 10.0).abs() < 1e-9, "{predictions:?}");
+}
+
+
+#[test]
+#[should_panic]
+fn nonpositive_weight_panics() {
+    let _ = diagonal_sample(3, 0.0, 1234)
+        .with_weights(vec![1.0, 0.0, 1.0]);
+}
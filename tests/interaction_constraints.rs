@@ -0,0 +1,158 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::uniform;
+
+
+// Three random features `x`, `y`, and `z`.
+fn random_features() -> DataFrame {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    df!("x" => x, "y" => y, "z" => z).unwrap()
+}
+
+
+// Returns `2 x y + z` for each example.
+fn product(data: &DataFrame) -> Vec<f64> {
+    let column = |name: &str| {
+        data.column(name).unwrap()
+            .f64().unwrap()
+            .into_no_null_iter()
+            .collect::<Vec<_>>()
+    };
+    let (x, y, z) = (column("x"), column("y"), column("z"));
+    (0..data.height())
+        .map(|i| 2.0 * x[i] * y[i] + z[i])
+        .collect()
+}
+
+
+// A random sample whose target is `2 x y + z`.
+fn product_sample() -> Sample {
+    let data = random_features();
+    let target = product(&data);
+    Sample::from_dataframe(data, Series::new("target", target)).unwrap()
+}
+
+
+// A random sample whose label is positive iff `2 x y + z > 1`.
+fn product_class_sample() -> Sample {
+    let data = random_features();
+    let target = product(&data)
+        .into_iter()
+        .map(|t| if t > 1.0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn regression_tree_without_interactions_uses_one_feature() {
+    let sample = product_sample();
+    let predictions = vec![0f64; sample.shape().0];
+
+    let free = RegressionTreeBuilder::new(&sample)
+        .max_depth(5)
+        .loss(GBMLoss::L2)
+        .build()
+        .produce(&sample, &predictions);
+    assert_eq!(free.used_features().len(), 3);
+
+    let f = RegressionTreeBuilder::new(&sample)
+        .max_depth(5)
+        .loss(GBMLoss::L2)
+        .interaction_constraints([["x"], ["y"], ["z"]])
+        .build()
+        .produce(&sample, &predictions);
+    assert_eq!(f.used_features().len(), 1);
+}
+
+
+#[test]
+fn regression_tree_paths_stay_in_a_group() {
+    let sample = product_sample();
+    let mut rng = StdRng::seed_from_u64(42);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(5)
+        .loss(GBMLoss::L2)
+        .interaction_constraints([vec!["x", "y"]])
+        .build();
+
+    // `z` is in no group, so it is never used with `x` or `y`.
+    for _ in 0..5 {
+        let predictions = (0..sample.shape().0)
+            .map(|_| rng.gen::<f64>())
+            .collect::<Vec<_>>();
+        let f = tree.produce(&sample, &predictions);
+        let used = f.used_features();
+        let in_group = used.iter().all(|&name| name == "x" || name == "y");
+        assert!(in_group || used == vec!["z"], "used: {used:?}");
+    }
+}
+
+
+#[test]
+fn decision_tree_without_interactions_uses_one_feature() {
+    let sample = product_class_sample();
+    let dist = uniform(&sample);
+
+    let free = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .build()
+        .produce(&sample, &dist);
+    assert!(free.used_features().len() > 1);
+
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .interaction_constraints([["x"], ["y"], ["z"]])
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f.used_features().len(), 1);
+
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .max_leaves(8)
+        .criterion(Criterion::Entropy)
+        .interaction_constraints([["x"], ["y"], ["z"]])
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f.used_features().len(), 1);
+}
+
+
+#[test]
+fn gbm_fits_with_interaction_constraints() {
+    let sample = product_sample();
+    let target = sample.target();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .interaction_constraints([vec!["x", "y"], vec!["z"]])
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .max_iter(50);
+    let f = booster.run(&tree);
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::L2.eval(&scores[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.1 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+#[should_panic]
+fn unknown_feature_panics() {
+    let sample = product_sample();
+    let _ = DecisionTreeBuilder::new(&sample)
+        .interaction_constraints([["x", "w"]]);
+}
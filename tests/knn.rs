@@ -0,0 +1,135 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{training_error, uniform};
+
+
+// A random sample whose label is positive
+// iff `(x, y)` is in the disk of radius `0.35` centered at `(0.5, 0.5)`,
+// with a feature `s` of a larger scale.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let s = (0..n_sample)
+        .map(|_| 100.0 * rng.gen::<f64>())
+        .collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| {
+            let r2 = (a - 0.5).powi(2) + (b - 0.5).powi(2);
+            if r2 < 0.35 * 0.35 { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y, "s" => s).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// The `k` nearest examples to `row` on the standardized features
+// by the exhaustive search.
+fn brute_force_neighbors(sample: &Sample, row: usize, k: usize)
+    -> Vec<usize>
+{
+    let n_sample = sample.shape().0;
+    let stats = ["x", "y", "s"].into_iter()
+        .map(|name| {
+            let values = (0..n_sample)
+                .map(|i| sample[name][i])
+                .collect::<Vec<_>>();
+            let mean = values.iter().sum::<f64>() / n_sample as f64;
+            let var = values.iter()
+                .map(|v| (v - mean).powi(2))
+                .sum::<f64>()
+                / n_sample as f64;
+            (name, mean, var.sqrt())
+        })
+        .collect::<Vec<_>>();
+    let point = |i: usize| {
+        stats.iter()
+            .map(|(name, mean, std)| (sample[*name][i] - mean) / std)
+            .collect::<Vec<_>>()
+    };
+    let target = point(row);
+    let mut distances = (0..n_sample)
+        .map(|i| {
+            let distance = point(i).into_iter()
+                .zip(&target)
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f64>();
+            (distance, i)
+        })
+        .collect::<Vec<_>>();
+    distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    distances.into_iter().take(k).map(|(_, i)| i).collect()
+}
+
+
+#[test]
+fn kd_tree_finds_the_nearest_neighbors() {
+    let sample = random_sample();
+    let n_sample = sample.shape().0;
+    for k in [1, 5, 20] {
+        let h = KnnLearner::init(&sample)
+            .k(k)
+            .produce(&sample, &uniform(&sample));
+        for row in (0..n_sample).step_by(7) {
+            let found = h.neighbors(&sample, row);
+            let expected = brute_force_neighbors(&sample, row, k);
+            assert_eq!(found, expected, "row: {row}, k: {k}");
+        }
+    }
+}
+
+
+#[test]
+fn one_nearest_neighbor_memorizes_the_sample() {
+    let sample = random_sample();
+    let h = KnnLearner::init(&sample)
+        .k(1)
+        .produce(&sample, &uniform(&sample));
+    assert_eq!(training_error(&h, &sample), 0.0);
+}
+
+
+#[test]
+fn votes_are_weighted_by_the_distribution() {
+    let sample = random_sample();
+    let n_sample = sample.shape().0;
+    let target = sample.target();
+    // Put all the weight on the positive examples.
+    let n_positive = target.iter().filter(|&&y| y > 0.0).count() as f64;
+    let dist = target.iter()
+        .map(|&y| if y > 0.0 { 1.0 / n_positive } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    let h = KnnLearner::init(&sample)
+        .k(n_sample)
+        .produce(&sample, &dist);
+    assert!(h.confidence_all(&sample).into_iter().all(|c| c == 1.0));
+}
+
+
+#[test]
+fn adaboost_fits_with_knn() {
+    let sample = random_sample();
+    let knn = KnnLearner::init(&sample)
+        .k(15);
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&knn);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_neighbors_panics() {
+    let sample = random_sample();
+    let _ = KnnLearner::init(&sample).k(0);
+}
@@ -0,0 +1,175 @@
+use miniboosts::prelude::*;
+use miniboosts::Label;
+use polars::prelude::*;
+use rand::prelude::*;
+
+use std::io::Write;
+use std::path::PathBuf;
+
+
+// Writes a random CSV file of `n_sample` rows
+// whose label is `labels[1]` iff `x + y > 1`
+// and `labels[0]` otherwise.
+fn write_csv(name: &str, n_sample: usize, labels: [&str; 2]) -> PathBuf {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let mut path = std::env::temp_dir();
+    path.push(format!("miniboosts_{name}_{}.csv", std::process::id()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    writeln!(file, "x,class,y").unwrap();
+    for _ in 0..n_sample {
+        let x = rng.gen::<f64>();
+        let y = rng.gen::<f64>();
+        let class = if x + y > 1.0 { labels[1] } else { labels[0] };
+        writeln!(file, "{x},{class},{y}").unwrap();
+    }
+    path
+}
+
+
+fn read(path: &PathBuf, normalize: bool) -> Sample {
+    SampleReader::new()
+        .file(path)
+        .has_header(true)
+        .target_feature("class")
+        .normalize_labels(normalize)
+        .read()
+        .unwrap()
+}
+
+
+#[test]
+fn zero_one_labels_are_mapped_to_plus_minus_one() {
+    let path = write_csv("labels_01", 200, ["0", "1"]);
+    let sample = read(&path, true);
+    let raw = read(&path, false);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sample.unique_target(), [-1.0, 1.0]);
+    assert_eq!(raw.unique_target(), [0.0, 1.0]);
+    assert!(raw.label_map().is_none());
+    sample.target()
+        .iter()
+        .zip(raw.target())
+        .for_each(|(&y, &z)| assert_eq!(y, 2.0 * z - 1.0));
+
+    let map = sample.label_map().unwrap().clone();
+    assert_eq!(map.labels(), [Label::Number(0.0), Label::Number(1.0)]);
+    assert_eq!(map.value(&Label::Number(1.0)), Some(1.0));
+    assert_eq!(map.label(-1.0), Some(&Label::Number(0.0)));
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .build();
+    let f = AdaBoost::init(&sample)
+        .tolerance(0.1)
+        .run(&tree);
+    let predictions = f.predict_all(&sample);
+    let f = LabeledClassifier::new(f, map);
+    assert_eq!(f.predict_all(&sample), predictions);
+    let labels = f.predict_label_all(&sample);
+    labels.iter()
+        .zip(predictions)
+        .for_each(|(label, p)| {
+            let expected = if p > 0 { 1.0 } else { 0.0 };
+            assert_eq!(label, &Label::Number(expected));
+        });
+}
+
+
+#[test]
+fn string_labels_are_mapped_and_reported() {
+    let path = write_csv("labels_text", 100, ["no", "yes"]);
+    let sample = read(&path, true);
+    let raw = read(&path, false);
+    std::fs::remove_file(&path).unwrap();
+
+    // String labels are mapped even if the normalization is disabled.
+    assert_eq!(sample.target(), raw.target());
+    assert_eq!(sample.shape(), (100, 2));
+    assert_eq!(sample.unique_target(), [-1.0, 1.0]);
+
+    let map = sample.label_map().unwrap();
+    let no = Label::Text("no".to_string());
+    let yes = Label::Text("yes".to_string());
+    assert_eq!(map.labels(), [no.clone(), yes.clone()]);
+    assert_eq!(map.value(&no), Some(-1.0));
+    assert_eq!(map.value(&yes), Some(1.0));
+    let report = "Label mapping (2 classes):\n    no -> -1\n    yes -> +1\n";
+    assert_eq!(map.to_string(), report);
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .build();
+    let f = AdaBoost::init(&sample)
+        .tolerance(0.1)
+        .run(&tree);
+    let f = LabeledClassifier::new(f, map.clone());
+    let labels = f.predict_label_all(&sample);
+    let n_correct = labels.iter()
+        .zip(sample.target())
+        .filter(|(label, &y)| map.value(label) == Some(y))
+        .count();
+    assert!(n_correct > 80);
+}
+
+
+// Predicts the class `0` for every row.
+struct Constant;
+
+
+impl MulticlassClassifier for Constant {
+    fn predict_class(&self, _sample: &Sample, _row: usize) -> i64 {
+        0
+    }
+}
+
+
+#[test]
+fn multiclass_string_labels_are_mapped_to_integers() {
+    let mut path = std::env::temp_dir();
+    let id = std::process::id();
+    path.push(format!("miniboosts_labels_multi_{id}.csv"));
+    std::fs::write(&path, "x,class\n1,cat\n2,dog\n3,dog\n4,bird\n5,cat\n")
+        .unwrap();
+    let sample = read(&path, true);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sample.target(), [1.0, 2.0, 2.0, 0.0, 1.0]);
+
+    let map = sample.label_map().unwrap().clone();
+    let f = LabeledClassifier::new(Constant, map);
+    let bird = Label::Text("bird".to_string());
+    assert_eq!(f.predict_class_label_all(&sample), vec![bird; 5]);
+}
+
+
+#[test]
+fn numerical_targets_are_kept_unless_binary() {
+    let x = (0..6).map(|i| i as f64).collect::<Vec<_>>();
+    let sample = |target: Vec<f64>| {
+        let data = df!("x" => x.clone()).unwrap();
+        Sample::from_dataframe(data, Series::new("class", target))
+            .unwrap()
+            .normalize_labels()
+    };
+
+    // Multiclass labels
+    let multiclass = sample(vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+    assert_eq!(multiclass.target(), [1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+    assert_eq!(multiclass.label_map().unwrap().labels().len(), 3);
+
+    // Regression targets
+    let regression = sample(vec![0.5, 1.5, 2.5, 0.1, 0.2, 0.3]);
+    assert_eq!(regression.target(), [0.5, 1.5, 2.5, 0.1, 0.2, 0.3]);
+    assert!(regression.label_map().is_none());
+
+    // Binary labels with an unlabeled row
+    let binary = sample(vec![3.0, 7.0, f64::NAN, 7.0, 3.0, 3.0]);
+    assert_eq!(binary.target()[..2], [-1.0, 1.0]);
+    assert!(binary.target()[2].is_nan());
+    assert_eq!(binary.target()[3..], [1.0, -1.0, -1.0]);
+
+    // Mapping twice does nothing.
+    let twice = binary.clone().normalize_labels();
+    assert_eq!(twice.label_map(), binary.label_map());
+}
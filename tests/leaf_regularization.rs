@@ -0,0 +1,97 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+fn max_abs(values: &[f64]) -> f64 {
+    values.iter().fold(0f64, |acc, v| acc.max(v.abs()))
+}
+
+
+#[test]
+fn zero_l1_keeps_the_leaf_values() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let predictions = vec![0f64; sample.shape().0];
+    let plain = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .build();
+    let zero = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .lambda_l1(0.0)
+        .build();
+
+    let f = plain.produce(&sample, &predictions);
+    let g = zero.produce(&sample, &predictions);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+}
+
+
+#[test]
+fn large_l1_zeroes_the_leaf_values() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let predictions = vec![0f64; sample.shape().0];
+    let f = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .lambda_l1(1e6)
+        .build()
+        .produce(&sample, &predictions);
+    assert!(f.predict_all(&sample).into_iter().all(|p| p == 0.0));
+}
+
+
+#[test]
+fn regularization_shrinks_the_leaf_values() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let predictions = vec![0f64; sample.shape().0];
+    let tree = |l1: f64, l2: f64| {
+        RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2)
+            .lambda_l1(l1)
+            .lambda_l2(l2)
+            .build()
+            .produce(&sample, &predictions)
+            .predict_all(&sample)
+    };
+
+    let plain = max_abs(&tree(0.0, 0.01)[..]);
+    let l1 = max_abs(&tree(5.0, 0.01)[..]);
+    let l2 = max_abs(&tree(0.0, 10.0)[..]);
+    assert!(l1 < plain, "l1: {l1}, plain: {plain}");
+    assert!(l2 < plain, "l2: {l2}, plain: {plain}");
+}
+
+
+#[test]
+fn gbm_fits_with_l1_regularized_trees() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let target = sample.target();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .lambda_l1(1.0)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .max_iter(50);
+    let f = booster.run(&tree);
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::L2.eval(&scores[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.5 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+#[should_panic]
+fn negative_l1_panics() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let _ = RegressionTreeBuilder::<GBMLoss>::new(&sample)
+        .lambda_l1(-1.0);
+}
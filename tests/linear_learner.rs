@@ -0,0 +1,146 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{diagonal_sample_with_noise_feature, training_error, uniform};
+
+
+// A random sample whose target is `2 x - 3 z + 1`
+// with a constant feature `c`.
+fn linear_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let c = vec![5f64; n_sample];
+    let target = x.iter()
+        .zip(&z[..])
+        .map(|(a, b)| 2.0 * a - 3.0 * b + 1.0)
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "z" => z, "c" => c).unwrap();
+    Sample::from_dataframe(data, Series::new("target", target)).unwrap()
+}
+
+
+#[test]
+fn logistic_regression_fits_a_linear_boundary() {
+    let sample = diagonal_sample_with_noise_feature();
+    let f = LinearLearnerBuilder::new(&sample)
+        .lambda(1e-4)
+        .build()
+        .produce(&sample, &uniform(&sample));
+    let error = training_error(&f, &sample);
+    assert!(error < 0.03, "training error: {error}");
+
+    let weights = f.weights();
+    assert_eq!(weights.len(), 3);
+    let (wx, wy, wz) = (weights[0].1, weights[1].1, weights[2].1);
+    assert!(wx > 0.0 && wy > 0.0, "weights: {weights:?}");
+    assert!(wz.abs() < 0.2 * wx, "weights: {weights:?}");
+}
+
+
+#[test]
+fn logistic_regression_follows_the_distribution() {
+    let sample = diagonal_sample_with_noise_feature();
+    let target = sample.target();
+    // Put all the weight on the positive examples.
+    let n_positive = target.iter().filter(|&&y| y > 0.0).count() as f64;
+    let dist = target.iter()
+        .map(|&y| if y > 0.0 { 1.0 / n_positive } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    let f = LinearLearnerBuilder::new(&sample)
+        .build()
+        .produce(&sample, &dist);
+    assert!(f.predict_all(&sample).into_iter().all(|p| p == 1));
+}
+
+
+#[test]
+fn adaboost_fits_with_linear_classifiers() {
+    let sample = diagonal_sample_with_noise_feature();
+    let linear = LinearLearnerBuilder::new(&sample).build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(10);
+    let f = booster.run(&linear);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.05, "training error: {error}");
+}
+
+
+#[test]
+fn ridge_regression_recovers_a_linear_target() {
+    let sample = linear_sample();
+    let n_sample = sample.shape().0;
+    let f = LinearLearnerBuilder::new(&sample)
+        .lambda(0.0)
+        .loss(GBMLoss::L2)
+        .build()
+        .produce(&sample, &vec![0f64; n_sample]);
+
+    // The constant feature `c` is not used.
+    let weights = f.weights();
+    assert_eq!(weights.len(), 2);
+    assert!((weights[0].1 - 2.0).abs() < 1e-6, "weights: {weights:?}");
+    assert!((weights[1].1 + 3.0).abs() < 1e-6, "weights: {weights:?}");
+    assert!((f.intercept() - 1.0).abs() < 1e-6);
+
+    // A larger regularization shrinks the coefficients.
+    let g = LinearLearnerBuilder::new(&sample)
+        .lambda(1.0)
+        .loss(GBMLoss::L2)
+        .build()
+        .produce(&sample, &vec![0f64; n_sample]);
+    assert!(g.weights()[0].1.abs() < weights[0].1.abs());
+}
+
+
+#[test]
+fn gbm_fits_with_linear_regressors() {
+    let sample = linear_sample();
+    let target = sample.target();
+    let linear = LinearLearnerBuilder::new(&sample)
+        .loss(GBMLoss::Huber(1.0))
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::Huber(1.0))
+        .max_iter(50);
+    let f = booster.run(&linear);
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::L2.eval(&scores[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.01 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+fn missing_values_are_filled_by_the_means() {
+    let sample = linear_sample();
+    let n_sample = sample.shape().0;
+    let f = LinearLearnerBuilder::new(&sample)
+        .loss(GBMLoss::L2)
+        .build()
+        .produce(&sample, &vec![0f64; n_sample]);
+
+    let data = df!(
+        "x" => [Some(0.5), None],
+        "z" => [None, Some(0.5)],
+        "c" => [Some(5.0), Some(5.0)],
+    ).unwrap();
+    let test = Sample::from_dataframe(data, Series::new("target", [0.0, 0.0]))
+        .unwrap();
+    assert!(f.predict_all(&test).into_iter().all(|p| p.is_finite()));
+}
+
+
+#[test]
+#[should_panic]
+fn negative_lambda_panics() {
+    let sample = diagonal_sample_with_noise_feature();
+    let _ = LinearLearnerBuilder::new(&sample).lambda(-1.0);
+}
@@ -0,0 +1,63 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+// The label is positive iff `x + y > 10`.
+fn toy_sample() -> Sample {
+    let data = df!(
+        "x" => [1.0, 2.0, 3.0, 8.0, 9.0, 7.0, 4.0, 6.0, 2.0, 9.0, 5.0, 8.0],
+        "y" => [2.0, 1.0, 4.0, 7.0, 3.0, 9.0, 2.0, 8.0, 5.0, 6.0, 3.0, 1.0],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [-1.0, -1.0, -1.0, 1.0, 1.0, 1.0, -1.0, 1.0, -1.0, 1.0, -1.0, -1.0],
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn logitboost_fits_training_sample() {
+    let sample = toy_sample();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::LogLoss)
+        .build();
+
+    let mut booster = LogitBoost::init(&sample)
+        .max_iter(50)
+        .shrinkage(0.5);
+    let f = booster.run(&tree);
+
+    assert!(booster.logistic_loss() < 2f64.ln());
+    let target = sample.target();
+    let predictions = f.predict_all(&sample);
+    assert!(
+        target.iter()
+            .zip(predictions)
+            .all(|(y, p)| *y as i64 == p)
+    );
+    assert!(
+        f.probability_all(&sample)
+            .into_iter()
+            .all(|p| (0f64..=1f64).contains(&p))
+    );
+}
+
+
+#[test]
+fn logitboost_stops_by_tolerance() {
+    let sample = toy_sample();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .loss(GBMLoss::LogLoss)
+        .build();
+
+    let mut booster = LogitBoost::init(&sample)
+        .tolerance(1e-2)
+        .max_iter(1_000);
+    let f = booster.run(&tree);
+
+    assert!(booster.terminated() < 1_000);
+    assert_eq!(f.hypotheses.len(), booster.terminated());
+}
@@ -0,0 +1,168 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+use std::cell::Cell;
+
+
+// A random sample of 10 features
+// whose label is positive iff the sum of the first 5 features exceeds `2.5`.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let n_features = 10;
+    let columns = (0..n_features)
+        .map(|_| {
+            (0..n_sample)
+                .map(|_| rng.gen::<f64>())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let target = (0..n_sample)
+        .map(|i| {
+            let sum = columns[..5].iter().map(|c| c[i]).sum::<f64>();
+            if sum > 2.5 { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let columns = columns.into_iter()
+        .enumerate()
+        .map(|(j, values)| Series::new(&format!("x{j}"), values))
+        .collect::<Vec<_>>();
+    let data = DataFrame::new(columns).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+fn edge<H: Classifier>(h: &H, sample: &Sample, dist: &[f64]) -> f64 {
+    h.confidence_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .zip(dist)
+        .map(|((p, y), d)| d * y * p)
+        .sum::<f64>()
+}
+
+
+// A weak learner that counts the number of calls,
+// i.e., the number of rounds.
+struct Counting<'a, W> {
+    inner: &'a W,
+    calls: Cell<usize>,
+}
+
+
+impl<'a, W> Counting<'a, W> {
+    fn new(inner: &'a W) -> Self {
+        Self { inner, calls: Cell::new(0) }
+    }
+}
+
+
+impl<W: WeakLearner> WeakLearner for Counting<'_, W> {
+    type Hypothesis = W::Hypothesis;
+
+
+    fn produce(&self, sample: &Sample, dist: &[f64]) -> Self::Hypothesis {
+        self.calls.set(self.calls.get() + 1);
+        self.inner.produce(sample, dist)
+    }
+
+
+    fn produce_many(&self, sample: &Sample, dist: &[f64], k: usize)
+        -> Vec<Self::Hypothesis>
+    {
+        self.calls.set(self.calls.get() + 1);
+        self.inner.produce_many(sample, dist, k)
+    }
+}
+
+
+#[test]
+fn stumps_are_sorted_by_the_edge() {
+    let sample = random_sample();
+    let n_sample = sample.shape().0;
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+    let stump = DecisionStump::init(&sample);
+
+    let stumps = stump.produce_many(&sample, &dist, 4);
+    assert_eq!(stumps.len(), 4);
+    assert_eq!(stumps[0], stump.produce(&sample, &dist));
+
+    let edges = stumps.iter()
+        .map(|h| edge(h, &sample, &dist))
+        .collect::<Vec<_>>();
+    assert!(edges.windows(2).all(|w| w[0] >= w[1]), "edges: {edges:?}");
+
+    let mut features = stumps.iter()
+        .map(|h| h.feature())
+        .collect::<Vec<_>>();
+    features.sort();
+    features.dedup();
+    assert_eq!(features.len(), 4);
+
+    // At most one stump per feature.
+    assert_eq!(stump.produce_many(&sample, &dist, 20).len(), 10);
+}
+
+
+#[test]
+fn lpboost_adds_several_columns_per_round() {
+    let sample = random_sample();
+    let stump = DecisionStump::init(&sample);
+    let nu = 0.1 * sample.shape().0 as f64;
+
+    let single = Counting::new(&stump);
+    let mut booster = LPBoost::init(&sample)
+        .tolerance(0.01)
+        .nu(nu);
+    let _ = booster.run(&single);
+    assert!(booster.gap() <= 0.01, "gap: {}", booster.gap());
+    let expected = booster.certificate().gamma_star;
+
+    let batched = Counting::new(&stump);
+    let mut booster = LPBoost::init(&sample)
+        .tolerance(0.01)
+        .nu(nu)
+        .columns_per_round(5);
+    let _ = booster.run(&batched);
+    assert!(booster.gap() <= 0.01, "gap: {}", booster.gap());
+    let optimal = booster.certificate().gamma_star;
+    assert!((optimal - expected).abs() <= 0.02, "{optimal} vs {expected}");
+
+    let (single, batched) = (single.calls.get(), batched.calls.get());
+    assert!(batched < single, "rounds: {batched} vs {single}");
+}
+
+
+#[test]
+fn erlpboost_adds_several_columns_per_round() {
+    let sample = random_sample();
+    let stump = DecisionStump::init(&sample);
+    let nu = 0.1 * sample.shape().0 as f64;
+
+    let single = Counting::new(&stump);
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(0.1)
+        .nu(nu);
+    let _ = booster.run(&single);
+
+    let batched = Counting::new(&stump);
+    let mut booster = ERLPBoost::init(&sample)
+        .tolerance(0.1)
+        .nu(nu)
+        .columns_per_round(5);
+    let f = booster.run(&batched);
+    assert!(booster.gap() <= 0.05, "gap: {}", booster.gap());
+    assert!(f.hypotheses.len() > batched.calls.get());
+
+    let (single, batched) = (single.calls.get(), batched.calls.get());
+    assert!(batched <= single, "rounds: {batched} vs {single}");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_columns_per_round_panics() {
+    let sample = random_sample();
+    let _ = LPBoost::<StumpClassifier>::init(&sample).columns_per_round(0);
+}
@@ -0,0 +1,53 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+fn run(sample: &Sample, booster: &mut LPBoost<'_, DecisionTreeClassifier>)
+    -> WeightedMajority<DecisionTreeClassifier>
+{
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+    booster.run(&tree)
+}
+
+
+#[test]
+fn aging_keeps_the_solution() {
+    let sample = diagonal_sample(200, 0.0, 1234);
+
+    let mut plain = LPBoost::init(&sample)
+        .tolerance(0.01);
+    let f = run(&sample, &mut plain);
+
+    let mut aging = LPBoost::init(&sample)
+        .tolerance(0.01)
+        .column_aging(1);
+    let g = run(&sample, &mut aging);
+
+
+    // The aging drops some columns from the LP.
+    assert_eq!(plain.n_active_hypotheses(), plain.terminated());
+    assert!(
+        aging.n_active_hypotheses() < aging.terminated(),
+        "{} columns of {} hypotheses",
+        aging.n_active_hypotheses(), aging.terminated()
+    );
+
+
+    // The optimal value is the same as the one without the aging.
+    let gap = (plain.certificate().gamma_star - aging.certificate().gamma_star).abs();
+    assert!(gap < 1e-3, "gamma star differs by {gap}");
+    assert!(aging.gap() <= 0.01);
+
+    let predictions_f = f.predict_all(&sample);
+    let predictions_g = g.predict_all(&sample);
+    let n_diff = predictions_f.iter()
+        .zip(&predictions_g[..])
+        .filter(|(a, b)| a != b)
+        .count();
+    assert!(n_diff <= 10, "{n_diff} predictions differ");
+}
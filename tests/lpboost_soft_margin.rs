@@ -0,0 +1,59 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+use polars::prelude::*;
+
+mod common;
+use common::Stump;
+
+
+// A sample over `x ∈ {-1, +1}` labeled by `x`,
+// where the labels of one positive and one negative example are flipped.
+// No combination of hypotheses separates it.
+fn flipped_sample(n_sample: usize) -> Sample {
+    let x = (0..n_sample)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let target = x.iter()
+        .enumerate()
+        .map(|(i, &x)| if i < 2 { - x } else { x })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// The convex hull of these stumps contains
+// all the hypotheses over `x ∈ {-1, +1}`.
+fn stumps() -> HypothesisPool<Stump> {
+    let mut stumps = Vec::new();
+    for threshold in [-2.0, 0.0, 2.0] {
+        for sign in [1.0, -1.0] {
+            stumps.push(Stump { feature: "x", threshold, sign });
+        }
+    }
+    HypothesisPool::new(stumps)
+}
+
+
+#[test]
+fn lpboost_attains_the_soft_margin_optimum() {
+    let n_sample = 20;
+    let sample = flipped_sample(n_sample);
+    let pool = stumps();
+
+    // The optimal combination is `t * x` for some `t ∈ [0, 1]`,
+    // whose soft margin is `t * (1 - 2 * 2 / nu)`.
+    for nu in [2.0_f64, 8.0, 20.0] {
+        let optimum = (1.0 - 4.0 / nu).max(0.0);
+        let f = LPBoost::init(&sample)
+            .tolerance(1e-4)
+            .nu(nu)
+            .run(&pool);
+        let value = SoftMarginObjective::new(nu).eval(&sample, &f);
+        assert!(
+            (value - optimum).abs() < 1e-3,
+            "nu = {nu}: the soft margin is {value}, the optimum is {optimum}"
+        );
+    }
+}
@@ -50,3 +50,43 @@ pub mod madaboost_tests {
 }
 
 
+
+
+/// Tests for `MadaBoost` on a small noisy sample.
+#[cfg(test)]
+pub mod madaboost_toy_tests {
+    use super::*;
+    use polars::prelude::*;
+
+    #[test]
+    fn noisy_toy() {
+        // The label is positive iff `x > 5`,
+        // except the last two noisy examples.
+        let data = df!(
+            "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 2.5, 8.5],
+            "y" => [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 5.0, 8.0],
+        ).unwrap();
+        let target = Series::new(
+            "class",
+            [-1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0],
+        );
+        let sample = Sample::from_dataframe(data, target).unwrap();
+
+        let mut booster = MadaBoost::init(&sample)
+            .force_quit_at(50);
+        let wl = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .criterion(Criterion::Entropy)
+            .build();
+        let f = booster.run(&wl);
+
+        let predictions = f.predict_all(&sample);
+        let n_correct = sample.target()
+            .iter()
+            .zip(predictions)
+            .take(10)
+            .filter(|(t, p)| **t as i64 == *p)
+            .count();
+        assert_eq!(n_correct, 10);
+    }
+}
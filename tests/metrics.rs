@@ -0,0 +1,52 @@
+use miniboosts::prelude::*;
+use miniboosts::research::ConfusionMatrix;
+use polars::prelude::*;
+
+
+#[test]
+fn multiclass_metrics() {
+    let target      = [0.0, 0.0, 1.0, 1.0, 2.0, 2.0];
+    let predictions = [0,   1,   1,   1,   2,   0];
+    let matrix = ConfusionMatrix::new(&target, &predictions);
+
+    assert_eq!(matrix.classes(), &[0, 1, 2]);
+    assert_eq!(matrix.count(0, 1), 1);
+    assert_eq!(matrix.total(), 6);
+    assert!((matrix.accuracy() - 4.0 / 6.0).abs() < 1e-9);
+
+    assert!((matrix.precision(0) - 0.5).abs() < 1e-9);
+    assert!((matrix.recall(1) - 1.0).abs() < 1e-9);
+    assert!((matrix.precision(1) - 2.0 / 3.0).abs() < 1e-9);
+    assert!((matrix.f1(2) - 2.0 / 3.0).abs() < 1e-9);
+
+    let macro_recall = (0.5 + 1.0 + 0.5) / 3.0;
+    assert!((matrix.macro_recall() - macro_recall).abs() < 1e-9);
+    // Micro averages coincide with the accuracy
+    // for single-label multi-class problems.
+    assert!((matrix.micro_f1() - matrix.accuracy()).abs() < 1e-9);
+    assert!(!format!("{matrix}").is_empty());
+}
+
+
+#[test]
+fn binary_classifier_metrics() {
+    let s1 = Series::new("x", &[10.0, 14.0, 15.0, 5.0, 3.0,  8.0, 12.0]);
+    let s2 = Series::new("y", &[ 5.0,  8.0,  3.0, 1.0, 9.0, 13.0, 11.0]);
+    let df = DataFrame::new(vec![s1, s2]).unwrap();
+    let target = Series::new(
+        "class", &[1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0]
+    );
+    let sample = Sample::from_dataframe(df, target).unwrap();
+
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .build();
+    let f = AdaBoost::init(&sample)
+        .force_quit_at(10)
+        .run(&dtree);
+
+    let matrix = ConfusionMatrix::from_classifier(&sample, &f);
+    assert_eq!(matrix.classes(), &[-1, 1]);
+    assert_eq!(matrix.support(1), 3);
+    assert_eq!(matrix.support(-1), 4);
+}
@@ -0,0 +1,60 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+// Each bag has three instances.
+// A bag is positive iff one of its instances has `x > 10`.
+fn toy_sample() -> Sample {
+    let data = df!(
+        "x"   => [12.0, 2.0, 3.0, 1.0, 4.0, 14.0, 5.0, 3.0, 2.0,
+                  6.0, 1.0, 2.0, 11.0, 3.0, 4.0, 2.0, 7.0, 1.0],
+        "y"   => [ 5.0, 8.0, 3.0, 1.0, 9.0, 13.0, 11.0, 2.0, 6.0,
+                   4.0, 7.0, 3.0, 2.0, 8.0, 1.0, 9.0, 5.0, 6.0],
+        "bag" => [0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0,
+                  3.0, 3.0, 3.0, 4.0, 4.0, 4.0, 5.0, 5.0, 5.0],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0,
+         -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0],
+    );
+
+    Sample::from_dataframe(data, target)
+        .unwrap()
+        .set_bags("bag")
+}
+
+
+#[test]
+fn set_bags_removes_the_column() {
+    let sample = toy_sample();
+    assert_eq!(sample.shape(), (18, 2));
+    assert_eq!(sample.bags().unwrap()[5], 1);
+    assert!(sample.features().iter().all(|feat| feat.name() != "bag"));
+}
+
+
+#[test]
+fn milboost_classifies_bags() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = MILBoost::init(&sample)
+        .force_quit_at(1);
+    let _ = booster.run(&dtree);
+    let likelihood_1 = booster.log_likelihood();
+
+    let mut booster = MILBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&dtree);
+    let likelihood_20 = booster.log_likelihood();
+    assert_eq!(booster.n_bags(), 6);
+    assert!(likelihood_20 >= likelihood_1);
+
+    let predictions = f.predict_bags(&sample);
+    let expected = vec![(0, 1), (1, 1), (2, -1), (3, -1), (4, 1), (5, -1)];
+    assert_eq!(predictions, expected);
+}
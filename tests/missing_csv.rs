@@ -0,0 +1,131 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+use std::path::PathBuf;
+
+
+const CSV: &str = "x,y,class\n\
+    1.0,NA,1\n\
+    ,4.0,-1\n\
+    3.0,?,1\n\
+    5.0,2.0,-1\n\
+    N/A,0.0,1\n";
+
+
+fn write_csv(name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("miniboosts_{name}_{}.csv", std::process::id()));
+    std::fs::write(&path, CSV).unwrap();
+    path
+}
+
+
+fn read(path: &PathBuf, policy: MissingPolicy) -> std::io::Result<Sample> {
+    SampleReader::new()
+        .file(path)
+        .has_header(true)
+        .target_feature("class")
+        .missing_tokens(["?"])
+        .missing_policy(policy)
+        .read()
+}
+
+
+fn column(sample: &Sample, j: usize) -> Vec<f64> {
+    let feature = &sample.features()[j];
+    (0..sample.shape().0).map(|i| feature[i]).collect()
+}
+
+
+#[test]
+fn missing_tokens_are_nan() {
+    let path = write_csv("missing_keep");
+    let sample = read(&path, MissingPolicy::KeepNaN).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let x = column(&sample, 0);
+    let y = column(&sample, 1);
+    assert!(x[1].is_nan() && x[4].is_nan());
+    assert_eq!([x[0], x[2], x[3]], [1.0, 3.0, 5.0]);
+    assert!(y[0].is_nan() && y[2].is_nan());
+    assert_eq!([y[1], y[3], y[4]], [4.0, 2.0, 0.0]);
+
+    assert!(sample.unlabeled_rows().is_empty());
+    assert_eq!(sample.target(), [1.0, -1.0, 1.0, -1.0, 1.0]);
+}
+
+
+#[test]
+fn missing_values_are_imputed() {
+    let path = write_csv("missing_impute");
+    let mean = read(&path, MissingPolicy::ImputeMean).unwrap();
+    let median = read(&path, MissingPolicy::ImputeMedian).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(column(&mean, 0), [1.0, 3.0, 3.0, 5.0, 3.0]);
+    assert_eq!(column(&mean, 1), [2.0, 4.0, 2.0, 2.0, 0.0]);
+    assert_eq!(column(&median, 0), [1.0, 3.0, 3.0, 5.0, 3.0]);
+    assert_eq!(column(&median, 1), [2.0, 4.0, 2.0, 2.0, 0.0]);
+}
+
+
+#[test]
+fn missing_values_are_rejected() {
+    let path = write_csv("missing_error");
+    let error = read(&path, MissingPolicy::Error);
+    std::fs::remove_file(&path).unwrap();
+    assert!(error.is_err());
+
+    // A cell that is neither a number nor a missing token.
+    let mut path = std::env::temp_dir();
+    path.push(format!("miniboosts_missing_bad_{}.csv", std::process::id()));
+    std::fs::write(&path, "x,class\n1.0,1\nabc,-1\n").unwrap();
+    let bad = read(&path, MissingPolicy::KeepNaN);
+    std::fs::remove_file(&path).unwrap();
+    assert!(bad.is_err());
+}
+
+
+#[test]
+fn missing_targets_are_rejected() {
+    for (name, target) in [("na", "NA"), ("empty", ""), ("token", "?")] {
+        let mut path = std::env::temp_dir();
+        let id = std::process::id();
+        path.push(format!("miniboosts_missing_target_{name}_{id}.csv"));
+        let csv = format!("x,class\n1.0,1\n2.0,{target}\n3.0,-1\n");
+        std::fs::write(&path, csv).unwrap();
+
+        let error = read(&path, MissingPolicy::KeepNaN).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+        let mut chunks = SampleReader::new()
+            .file(&path)
+            .has_header(true)
+            .target_feature("class")
+            .missing_tokens(["?"])
+            .read_chunks()
+            .unwrap();
+        let error = chunks.next().unwrap().unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
+
+
+#[test]
+fn sparse_and_categorical_features_are_imputed() {
+    let x = [Some(0.0), None, Some(0.0), Some(0.0), Some(4.0), Some(0.0)];
+    let c = [Some(1.0), None, Some(2.0), Some(1.0), Some(2.0), Some(1.0)];
+    let data = df!("x" => x, "c" => c).unwrap();
+    let target = Series::new("class", [1.0, -1.0, 1.0, -1.0, 1.0, -1.0]);
+    let sample = Sample::from_dataframe(data, target)
+        .unwrap()
+        .with_sparse_threshold(1.1)
+        .set_categorical(["c"])
+        .impute_missing(MissingPolicy::ImputeMedian)
+        .unwrap();
+
+    assert_eq!(column(&sample, 0), [0.0, 0.0, 0.0, 0.0, 4.0, 0.0]);
+    assert!(column(&sample, 1)[1].is_nan());
+    assert_eq!(sample.at(1).0[0], 0.0);
+}
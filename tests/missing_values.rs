@@ -0,0 +1,150 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{training_error, uniform};
+
+
+// A random sample with a feature `x` whose values are missing
+// for every 5th example.
+// The label of a missing example is `missing_label`.
+// Otherwise, the label is positive iff `x >= 0.5`.
+// No value of `x` lies in `[0.4, 0.6)` so that the bins separate the labels.
+fn missing_sample(missing_label: f64) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample)
+        .map(|i| {
+            if i % 5 == 0 { return None; }
+            let x = 0.8 * rng.gen::<f64>();
+            Some(if x < 0.4 { x } else { x + 0.2 })
+        })
+        .collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|x| match x {
+            None => missing_label,
+            Some(x) => if *x >= 0.5 { 1.0 } else { -1.0 },
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn nulls_in_a_dataframe_are_missing_values() {
+    let sample = missing_sample(1.0);
+    for i in 0..sample.shape().0 {
+        assert_eq!(sample["x"][i].is_nan(), i % 5 == 0);
+    }
+}
+
+
+#[test]
+fn empty_cells_in_a_csv_file_are_missing_values() {
+    let path = std::env::temp_dir()
+        .join("miniboosts_missing_values.csv");
+    std::fs::write(&path, "x,y,class\n1.0,,1\n,2.5,-1\n3.0,4.0,1\n")
+        .unwrap();
+
+    let sample = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .read()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sample.shape(), (3, 2));
+    assert!(sample["x"][1].is_nan());
+    assert!(sample["y"][0].is_nan());
+    assert_eq!(sample["x"][2], 3.0);
+    assert_eq!(sample.target(), &[1.0, -1.0, 1.0]);
+}
+
+
+#[test]
+fn decision_tree_learns_the_missing_direction() {
+    for missing_label in [1.0, -1.0] {
+        let sample = missing_sample(missing_label);
+        for criterion in [Criterion::Entropy, Criterion::Gini, Criterion::Edge] {
+            let tree = DecisionTreeBuilder::new(&sample)
+                .max_depth(1)
+                .criterion(criterion)
+                .build();
+            let f = tree.produce(&sample, &uniform(&sample));
+            let error = training_error(&f, &sample);
+            assert_eq!(error, 0.0, "{criterion}, missing: {missing_label}");
+        }
+    }
+}
+
+
+// The quantile bins may not separate the values of `x`,
+// so that only the examples with missing values are checked.
+#[test]
+fn decision_tree_histograms_handle_missing_values() {
+    for missing_label in [1.0, -1.0] {
+        let sample = missing_sample(missing_label);
+        let tree = DecisionTreeBuilder::new(&sample)
+            .max_depth(1)
+            .bins(64)
+            .criterion(Criterion::Entropy)
+            .build();
+        let f = tree.produce(&sample, &uniform(&sample));
+        for i in (0..sample.shape().0).step_by(5) {
+            assert_eq!(f.predict(&sample, i) as f64, missing_label);
+        }
+    }
+}
+
+
+#[test]
+fn regression_tree_learns_the_missing_direction() {
+    for missing_label in [1.0, -1.0] {
+        let sample = missing_sample(missing_label);
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(GBMLoss::L2)
+            .build();
+        let predictions = vec![0f64; sample.shape().0];
+        let f = tree.produce(&sample, &predictions);
+
+        f.predict_all(&sample)
+            .into_iter()
+            .zip(sample.target())
+            .for_each(|(p, y)| { assert_eq!(p.signum(), *y); });
+
+
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .bins(64)
+            .loss(GBMLoss::L2)
+            .build();
+        let f = tree.produce(&sample, &predictions);
+        for i in (0..sample.shape().0).step_by(5) {
+            assert_eq!(f.predict(&sample, i).signum(), missing_label);
+        }
+    }
+}
+
+
+#[test]
+fn gbm_fits_a_sample_with_missing_values() {
+    let sample = missing_sample(1.0);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::LogLoss)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::LogLoss)
+        .max_iter(20);
+    let f = booster.run(&tree);
+    let n_correct = f.predict_all(&sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(s, y)| s.signum() == **y)
+        .count();
+    assert_eq!(n_correct, sample.shape().0);
+}
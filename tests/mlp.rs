@@ -0,0 +1,116 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{disk_sample, training_error, uniform};
+
+
+// A random sample whose target is `sin(3 x) + z^2`.
+fn regression_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&z[..])
+        .map(|(a, b)| (3.0 * a).sin() + b * b)
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "z" => z).unwrap();
+    Sample::from_dataframe(data, Series::new("target", target)).unwrap()
+}
+
+
+#[test]
+fn mlp_fits_a_nonlinear_boundary() {
+    let sample = disk_sample();
+    let f = MlpLearnerBuilder::new(&sample)
+        .epochs(300)
+        .build()
+        .produce(&sample, &uniform(&sample));
+    assert_eq!(f.hidden_units(), 16);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+fn mlp_is_reproducible_with_a_seed() {
+    let sample = disk_sample();
+    let dist = uniform(&sample);
+    let f = MlpLearnerBuilder::new(&sample)
+        .seed(777)
+        .build()
+        .produce(&sample, &dist);
+    let g = MlpLearnerBuilder::new(&sample)
+        .seed(777)
+        .build()
+        .produce(&sample, &dist);
+    let h = MlpLearnerBuilder::new(&sample)
+        .seed(778)
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f, g);
+    assert_ne!(f, h);
+}
+
+
+#[test]
+fn mlp_follows_the_distribution() {
+    let sample = disk_sample();
+    let target = sample.target();
+    // Put all the weight on the positive examples.
+    let n_positive = target.iter().filter(|&&y| y > 0.0).count() as f64;
+    let dist = target.iter()
+        .map(|&y| if y > 0.0 { 1.0 / n_positive } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    let f = MlpLearnerBuilder::new(&sample)
+        .build()
+        .produce(&sample, &dist);
+    assert!(f.predict_all(&sample).into_iter().all(|p| p == 1));
+}
+
+
+#[test]
+fn adaboost_fits_with_mlp() {
+    let sample = disk_sample();
+    let mlp = MlpLearnerBuilder::new(&sample)
+        .hidden_units(8)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&mlp);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+fn gbm_fits_with_mlp_regressors() {
+    let sample = regression_sample();
+    let target = sample.target();
+    let mlp = MlpLearnerBuilder::new(&sample)
+        .hidden_units(8)
+        .loss(GBMLoss::L2)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .max_iter(20);
+    let f = booster.run(&mlp);
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::L2.eval(&scores[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.01 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_hidden_units_panics() {
+    let sample = disk_sample();
+    let _ = MlpLearnerBuilder::new(&sample).hidden_units(0);
+}
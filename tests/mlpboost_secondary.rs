@@ -0,0 +1,60 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::quadrant_sample;
+
+
+fn run(secondary: SecondaryType) -> usize {
+    let sample = quadrant_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = MLPBoost::init(&sample)
+        .tolerance(0.1)
+        .secondary(secondary);
+    let f = booster.run(&tree);
+
+    let target = sample.target();
+    let predictions = f.predict_all(&sample);
+    target.iter()
+        .zip(predictions)
+        .for_each(|(y, p)| { assert_eq!(*y as i64, p); });
+
+    // `terminated` is `max_loop` if the stopping criterion is not met.
+    assert!(booster.terminated() < booster.max_loop());
+    booster.terminated()
+}
+
+
+#[test]
+fn mlpboost_pure_frank_wolfe() {
+    run(SecondaryType::Never);
+}
+
+
+#[test]
+fn mlpboost_lp_every_k_rounds() {
+    let pure = run(SecondaryType::Never);
+    let every = run(SecondaryType::Every(3));
+    assert!(
+        every <= pure,
+        "LP every 3 rounds takes {every} rounds, pure FW takes {pure} rounds"
+    );
+}
+
+
+#[test]
+fn mlpboost_adaptive() {
+    run(SecondaryType::Adaptive);
+}
+
+
+#[test]
+#[should_panic]
+fn mlpboost_zero_interval() {
+    let sample = quadrant_sample();
+    let _ = MLPBoost::<DecisionTreeClassifier>::init(&sample)
+        .secondary(SecondaryType::Every(0));
+}
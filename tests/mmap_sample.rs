@@ -0,0 +1,125 @@
+use miniboosts::prelude::*;
+use miniboosts::Feature;
+
+use std::path::PathBuf;
+
+mod common;
+use common::diagonal_sample_with_missing_values;
+
+
+fn store_path(name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("miniboosts_{name}_{}.bin", std::process::id()));
+    path
+}
+
+
+fn same(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+
+fn assert_same_samples(a: &Sample, b: &Sample) {
+    assert_eq!(a.shape(), b.shape());
+    assert_eq!(a.target(), b.target());
+    for (f, g) in a.features().iter().zip(b.features()) {
+        assert_eq!(f.name(), g.name());
+        (0..a.shape().0).for_each(|i| assert!(same(f[i], g[i])));
+    }
+}
+
+
+#[test]
+fn mapped_sample_is_the_same_as_the_original() {
+    let sample = diagonal_sample_with_missing_values(1000);
+    let path = store_path("mmap");
+    sample.to_mmap(&path).unwrap();
+
+    let mapped = Sample::open_mmap(&path).unwrap();
+    assert!(
+        mapped.features()
+            .iter()
+            .all(|feature| matches!(feature, Feature::Mapped(_)))
+    );
+    assert_same_samples(&mapped, &sample);
+    assert_same_samples(&Sample::load_store(&path).unwrap(), &sample);
+
+    // Another handle shares the same file.
+    let other = Sample::open_mmap(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_same_samples(&other, &sample);
+}
+
+
+#[test]
+fn mapped_sample_trains_the_same_hypotheses() {
+    let sample = diagonal_sample_with_missing_values(1000);
+    let path = store_path("mmap_train");
+    sample.to_mmap(&path).unwrap();
+    let mapped = Sample::open_mmap(&path).unwrap();
+    let dist = vec![1f64 / 1000f64; 1000];
+
+    for bins in [None, Some(16)] {
+        let confidence = |sample: &Sample| {
+            let builder = DecisionTreeBuilder::new(sample).max_depth(3);
+            let tree = match bins {
+                Some(n) => builder.bins(n).build(),
+                None => builder.build(),
+            };
+            tree.produce(sample, &dist).confidence_all(sample)
+        };
+        assert_eq!(confidence(&mapped), confidence(&sample));
+    }
+
+    let gbm = |sample: &Sample| {
+        let tree = RegressionTreeBuilder::new(sample)
+            .max_depth(2)
+            .loss(GBMLoss::L2)
+            .build();
+        GBM::init_with_loss(sample, GBMLoss::L2)
+            .max_iter(5)
+            .run(&tree)
+            .predict_all(sample)
+    };
+    assert_eq!(gbm(&mapped), gbm(&sample));
+    std::fs::remove_file(&path).unwrap();
+}
+
+
+#[test]
+fn mapped_features_are_copied_on_modification() {
+    let sample = diagonal_sample_with_missing_values(100);
+    let path = store_path("mmap_modify");
+    sample.to_mmap(&path).unwrap();
+    let mut mapped = Sample::open_mmap(&path).unwrap();
+
+    mapped.extend_from(&sample).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(mapped.shape(), (200, 2));
+    assert!(
+        mapped.features()
+            .iter()
+            .all(|feature| matches!(feature, Feature::Dense(_)))
+    );
+    for i in 0..100 {
+        let (x, y) = mapped.at(i + 100);
+        let (z, w) = sample.at(i);
+        assert_eq!(y, w);
+        x.iter().zip(&z[..]).for_each(|(&a, &b)| assert!(same(a, b)));
+    }
+}
+
+
+#[test]
+fn invalid_stores_are_rejected() {
+    let path = store_path("mmap_invalid");
+    std::fs::write(&path, b"not a column store").unwrap();
+    assert!(Sample::open_mmap(&path).is_err());
+
+    diagonal_sample_with_missing_values(100).to_mmap(&path).unwrap();
+    let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+    let len = file.metadata().unwrap().len();
+    file.set_len(len - 8).unwrap();
+    assert!(Sample::open_mmap(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,169 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random sample whose target is `x + 0.5 * sin(20 x) + noise`
+// with a noise feature `z`.
+// The target is not monotone in `x`.
+fn wavy_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|&a| a + 0.5 * (20.0 * a).sin() + 0.1 * rng.gen::<f64>())
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "z" => z).unwrap();
+    Sample::from_dataframe(data, Series::new("target", target)).unwrap()
+}
+
+
+// A random sample whose label is positive iff `x > 0.5`,
+// except for the negative band `0.7 < x < 0.8`.
+fn banded_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|&a| {
+            if a > 0.5 && !(0.7..0.8).contains(&a) { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "z" => z).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// The grid `x = 0.00, 0.01, ..., 1.00` for each `z = 0.1, 0.5, 0.9`.
+fn grid() -> Sample {
+    let mut x = Vec::new();
+    let mut z = Vec::new();
+    for zz in [0.1, 0.5, 0.9] {
+        for k in 0..=100 {
+            x.push(k as f64 / 100.0);
+            z.push(zz);
+        }
+    }
+    let target = vec![0f64; x.len()];
+    let data = df!("x" => x, "z" => z).unwrap();
+    Sample::from_dataframe(data, Series::new("target", target)).unwrap()
+}
+
+
+// Returns `true` if `values` is non-decreasing
+// on each block of 101 points of the grid.
+fn is_non_decreasing(values: &[f64]) -> bool {
+    values.chunks(101)
+        .all(|block| block.windows(2).all(|w| w[0] <= w[1]))
+}
+
+
+#[test]
+fn regression_tree_respects_the_directions() {
+    let sample = wavy_sample();
+    let grid = grid();
+    let predictions = vec![0f64; sample.shape().0];
+    let tree = |direction: Monotone| {
+        RegressionTreeBuilder::new(&sample)
+            .max_depth(5)
+            .loss(GBMLoss::L2)
+            .monotone(&[("x", direction)])
+            .build()
+            .produce(&sample, &predictions)
+            .predict_all(&grid)
+    };
+
+    let increasing = tree(Monotone::Increasing);
+    assert!(is_non_decreasing(&increasing[..]));
+
+    let decreasing = tree(Monotone::Decreasing)
+        .into_iter()
+        .map(|p| -p)
+        .collect::<Vec<_>>();
+    assert!(is_non_decreasing(&decreasing[..]));
+
+    let free = RegressionTreeBuilder::new(&sample)
+        .max_depth(5)
+        .loss(GBMLoss::L2)
+        .build()
+        .produce(&sample, &predictions)
+        .predict_all(&grid);
+    assert!(!is_non_decreasing(&free[..]));
+}
+
+
+#[test]
+fn gbm_with_monotone_trees_is_monotone() {
+    let sample = wavy_sample();
+    let target = sample.target();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .monotone(&[("x", Monotone::Increasing)])
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .max_iter(50);
+    let f = booster.run(&tree);
+    assert!(is_non_decreasing(&f.predict_all(&grid())[..]));
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::L2.eval(&scores[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.5 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+fn decision_tree_respects_the_direction() {
+    let sample = banded_sample();
+    let grid = grid();
+    let n_sample = sample.shape().0;
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+
+    let free = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .build()
+        .produce(&sample, &dist);
+    assert!(!is_non_decreasing(&free.confidence_all(&grid)[..]));
+
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .monotone(&[("x", Monotone::Increasing)])
+        .build()
+        .produce(&sample, &dist);
+    assert!(is_non_decreasing(&f.confidence_all(&grid)[..]));
+    assert_eq!(f.predict(&grid, 20), -1);
+    assert_eq!(f.predict(&grid, 95), 1);
+}
+
+
+#[test]
+fn best_first_decision_tree_respects_the_direction() {
+    let sample = banded_sample();
+    let n_sample = sample.shape().0;
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .max_leaves(6)
+        .criterion(Criterion::Gini)
+        .monotone(&[("x", Monotone::Increasing)])
+        .build()
+        .produce(&sample, &dist);
+    assert!(is_non_decreasing(&f.confidence_all(&grid())[..]));
+}
+
+
+#[test]
+#[should_panic]
+fn unknown_feature_panics() {
+    let sample = wavy_sample();
+    let _ = RegressionTreeBuilder::<GBMLoss>::new(&sample)
+        .monotone(&[("w", Monotone::Increasing)]);
+}
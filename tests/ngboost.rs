@@ -0,0 +1,91 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// Draws a sample from the standard normal distribution.
+fn normal(rng: &mut StdRng) -> f64 {
+    let u = 1f64 - rng.gen::<f64>();
+    let v = rng.gen::<f64>();
+    (-2f64 * u.ln()).sqrt() * (2f64 * std::f64::consts::PI * v).cos()
+}
+
+
+// A random sample with `y ~ N(x, (0.1 + x)²)`,
+// whose noise grows with `x`.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 1_000;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|a| a + (0.1 + a) * normal(&mut rng))
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("y", target)).unwrap()
+}
+
+
+#[test]
+fn ngboost_predicts_distributions() {
+    let sample = random_sample();
+    let target = sample.target();
+    let n_sample = target.len() as f64;
+
+    let mut booster = NGBoost::init(&sample)
+        .learning_rate(0.1)
+        .max_iter(200);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::L2)
+        .build();
+    let f = booster.run(&tree);
+    let dists = f.predict_dist_all(&sample);
+    assert!(dists.iter().all(|(_, v)| *v > 0.0));
+
+
+    // The predicted variance grows with `x`.
+    let x = &sample["x"];
+    let (mut small, mut large) = (Vec::new(), Vec::new());
+    for (i, (_, v)) in dists.iter().enumerate() {
+        if x[i] < 0.5 { small.push(*v); } else { large.push(*v); }
+    }
+    let average = |vs: &[f64]| vs.iter().sum::<f64>() / vs.len() as f64;
+    assert!(
+        average(&large[..]) > 2.0 * average(&small[..]),
+        "small: {}, large: {}", average(&small[..]), average(&large[..])
+    );
+
+
+    // About 95% of the targets lie in `μ ± 2σ`.
+    let coverage = target.iter()
+        .zip(&dists[..])
+        .filter(|(y, (m, v))| (*y - m).abs() <= 2.0 * v.sqrt())
+        .count() as f64
+        / n_sample;
+    assert!((0.9..=0.99).contains(&coverage), "coverage: {coverage}");
+
+
+    // `predict` returns the mean.
+    let means = f.predict_all(&sample);
+    assert!(means.iter().zip(&dists[..]).all(|(m, (d, _))| m == d));
+}
+
+
+#[test]
+fn ngboost_decreases_likelihood_loss() {
+    let sample = random_sample();
+
+    let mut booster = NGBoost::init(&sample)
+        .max_iter(0);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::L2)
+        .build();
+    let _ = booster.run(&tree);
+    let initial = booster.negative_log_likelihood();
+
+    let mut booster = NGBoost::init(&sample)
+        .max_iter(50);
+    let _ = booster.run(&tree);
+    assert!(booster.negative_log_likelihood() < initial);
+}
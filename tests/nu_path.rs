@@ -0,0 +1,55 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+
+mod common;
+use common::diagonal_sample;
+
+
+#[test]
+fn lpboost_nu_path() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let tolerance = 0.01;
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let grid = [10.0, 60.0, 30.0];
+    let mut booster = LPBoost::init(&sample)
+        .tolerance(tolerance);
+    let path = booster.nu_path(&tree, &grid);
+
+
+    // The path is in descending order of `nu`.
+    let nus = path.iter().map(|(nu, _)| *nu).collect::<Vec<_>>();
+    assert_eq!(nus, vec![60.0, 30.0, 10.0]);
+
+
+    // Each model is as good as the one trained from scratch.
+    for (nu, f) in path {
+        assert!((f.weights.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+
+        let mut cold = LPBoost::init(&sample)
+            .tolerance(tolerance)
+            .nu(nu);
+        let g = cold.run(&tree);
+
+        let objective = SoftMarginObjective::new(nu);
+        let warm = objective.eval(&sample, &f);
+        let cold = objective.eval(&sample, &g);
+        assert!(warm >= cold - 2.0 * tolerance, "nu = {nu}: {warm} < {cold}");
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn empty_grid_panics() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .build();
+    let mut booster = LPBoost::init(&sample);
+    let _ = booster.nu_path(&tree, &[]);
+}
@@ -0,0 +1,85 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{diagonal_sample_with_noise_feature, training_error, uniform};
+
+
+#[test]
+fn an_oblique_split_fits_a_diagonal_boundary() {
+    let sample = diagonal_sample_with_noise_feature();
+    let dist = uniform(&sample);
+
+    let oblique = ObliqueTreeBuilder::new(&sample)
+        .max_depth(1)
+        .max_features(2)
+        .build()
+        .produce(&sample, &dist);
+    let axis = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Gini)
+        .build()
+        .produce(&sample, &dist);
+
+    let oblique_error = training_error(&oblique, &sample);
+    let axis_error = training_error(&axis, &sample);
+    assert!(oblique_error < 0.03, "oblique error: {oblique_error}");
+    assert!(axis_error > 0.1, "axis-aligned error: {axis_error}");
+    assert_eq!(oblique.used_features(), vec!["x", "y"]);
+}
+
+
+#[test]
+fn max_features_bounds_the_splitting_rules() {
+    let sample = diagonal_sample_with_noise_feature();
+    let dist = uniform(&sample);
+
+    let f = ObliqueTreeBuilder::new(&sample)
+        .max_depth(1)
+        .max_features(1)
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f.n_leaves(), 2);
+    assert_eq!(f.used_features().len(), 1);
+}
+
+
+#[test]
+fn adaboost_fits_with_oblique_trees() {
+    let sample = diagonal_sample_with_noise_feature();
+    let tree = ObliqueTreeBuilder::new(&sample)
+        .max_depth(2)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&tree);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.02, "training error: {error}");
+}
+
+
+#[test]
+fn a_pure_node_is_a_leaf() {
+    let sample = diagonal_sample_with_noise_feature();
+    let target = sample.target();
+    // Put all the weight on the positive examples.
+    let n_positive = target.iter().filter(|&&y| y > 0.0).count() as f64;
+    let dist = target.iter()
+        .map(|&y| if y > 0.0 { 1.0 / n_positive } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    let f = ObliqueTreeBuilder::new(&sample)
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f.n_leaves(), 1);
+    assert_eq!(f.confidence(&sample, 0), 1.0);
+}
+
+
+#[test]
+#[should_panic]
+fn too_many_features_panics() {
+    let sample = diagonal_sample_with_noise_feature();
+    let _ = ObliqueTreeBuilder::new(&sample)
+        .max_features(4);
+}
@@ -0,0 +1,99 @@
+use miniboosts::prelude::*;
+use miniboosts::MarginDistributionObjective;
+use miniboosts::research::ObjectiveFunction;
+
+mod common;
+use common::diagonal_sample;
+
+
+fn run(sample: &Sample, booster: &mut ODMBoost<'_, DecisionTreeClassifier>)
+    -> WeightedMajority<DecisionTreeClassifier>
+{
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+    booster.run(&tree)
+}
+
+
+fn margin_variance(sample: &Sample, f: &WeightedMajority<DecisionTreeClassifier>)
+    -> f64
+{
+    let margins = f.confidence_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .map(|(fx, y)| y * fx)
+        .collect::<Vec<_>>();
+    let n_sample = margins.len() as f64;
+    let mean = margins.iter().sum::<f64>() / n_sample;
+    margins.iter()
+        .map(|rho| (rho - mean).powi(2))
+        .sum::<f64>()
+        / n_sample
+}
+
+
+#[test]
+fn odmboost_beats_every_single_hypothesis() {
+    let sample = diagonal_sample(200, 0.1, 1234);
+    let lambda = 10.0;
+
+    let mut booster = ODMBoost::init(&sample)
+        .variance_weight(lambda)
+        .tolerance(1e-4);
+    let f = run(&sample, &mut booster);
+
+    assert!(booster.terminated() < 1_000);
+    assert!(booster.gap() <= 1e-4);
+    assert!((f.weights.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+
+
+    // The objective of the booster agrees with the research objective.
+    let objective = MarginDistributionObjective::new(lambda);
+    let value = objective.eval(&sample, &f);
+    assert!((value - booster.objective()).abs() < 1e-6);
+
+
+    // The combined hypothesis is at least as good as any single one.
+    for h in f.hypotheses.iter() {
+        assert!(objective.eval(&sample, h) <= value + 1e-6);
+    }
+
+
+    let predictions = f.predict_all(&sample);
+    let n_correct = predictions.iter()
+        .zip(sample.target())
+        .filter(|(p, y)| **p as f64 == **y)
+        .count();
+    assert!(n_correct >= 160, "{n_correct} of 200 examples are correct");
+}
+
+
+#[test]
+fn larger_variance_weight_shrinks_variance() {
+    let sample = diagonal_sample(200, 0.1, 1234);
+
+    let mut mean_only = ODMBoost::init(&sample)
+        .variance_weight(0.0)
+        .tolerance(1e-4);
+    let f = run(&sample, &mut mean_only);
+
+    let mut odm = ODMBoost::init(&sample)
+        .variance_weight(10.0)
+        .tolerance(1e-4);
+    let g = run(&sample, &mut odm);
+
+    let var_f = margin_variance(&sample, &f);
+    let var_g = margin_variance(&sample, &g);
+    assert!(var_g < var_f, "variance {var_g} >= {var_f}");
+}
+
+
+#[test]
+#[should_panic]
+fn negative_variance_weight_panics() {
+    let sample = diagonal_sample(200, 0.1, 1234);
+    let _ = ODMBoost::<DecisionTreeClassifier>::init(&sample)
+        .variance_weight(-1.0);
+}
@@ -0,0 +1,101 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::Stump;
+
+
+// All the stumps over the grid `{0.05, 0.1, ..., 0.95}` and their negations.
+fn stumps() -> Vec<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["x", "y"] {
+        for k in 1..20 {
+            let threshold = k as f64 * 0.05;
+            for sign in [1.0, -1.0] {
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    stumps
+}
+
+
+// A random sample with three classes:
+// `0` if `x < 0.3`, `1` if `x ≥ 0.3` and `y < 0.5`, and `2` otherwise.
+fn random_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(&a, &b)| {
+            if a < 0.3 { 0.0 } else if b < 0.5 { 1.0 } else { 2.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn binary_samples_relabel_one_class() {
+    let sample = random_sample();
+    let ova = OneVsAll::new(&sample);
+    assert_eq!(ova.classes(), &[0, 1, 2]);
+
+    let binary = ova.binary_sample(1);
+    assert_eq!(binary.shape(), sample.shape());
+    for (row, (&y, &z)) in sample.target().iter()
+        .zip(binary.target())
+        .enumerate()
+    {
+        assert_eq!(z, if y == 1.0 { 1.0 } else { -1.0 });
+        assert_eq!(binary["x"][row], sample["x"][row]);
+    }
+}
+
+
+#[test]
+fn one_vs_all_lpboost_fits() {
+    let sample = random_sample();
+    let f = OneVsAll::new(&sample)
+        .run(|binary| {
+            LPBoost::init(binary)
+                .tolerance(0.01)
+                .nu(3.0)
+                .run(&HypothesisPool::new(stumps()))
+        });
+    assert_eq!(f.classes, vec![0, 1, 2]);
+    assert_eq!(f.hypotheses.len(), 3);
+
+
+    let n_correct = f.predict_class_all(&sample)
+        .into_iter()
+        .zip(sample.target())
+        .filter(|(p, y)| *p == **y as i64)
+        .count();
+    assert!(n_correct >= 285, "{n_correct} of 300 examples are correct");
+
+
+    for row in 0..10 {
+        let margins = f.margins(&sample, row);
+        let best = margins.iter()
+            .cloned()
+            .fold(f64::MIN, f64::max);
+        let class = f.predict_class(&sample, row) as usize;
+        assert_eq!(margins[class], best);
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn single_class_panics() {
+    let data = df!("x" => [0.1, 0.2]).unwrap();
+    let sample = Sample::from_dataframe(
+        data, Series::new("class", [1.0, 1.0])
+    ).unwrap();
+    let _ = OneVsAll::new(&sample);
+}
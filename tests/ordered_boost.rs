@@ -0,0 +1,57 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::tree;
+
+
+// A random sample with `y = sin(6x) + ε`, where `ε ~ U[-0.2, 0.2]`.
+fn random_sample(n_sample: usize, seed: u64) -> Sample {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .map(|a| (6.0 * a).sin() + rng.gen_range(-0.2..0.2))
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("y", target)).unwrap()
+}
+
+
+#[test]
+fn ordered_boosting_fits() {
+    let train = random_sample(300, 1234);
+    let test = random_sample(300, 5678);
+
+    let mut booster = OrderedBoost::init_with_loss(&train, GBMLoss::L2)
+        .max_iter(50);
+    // `2^8 < 300 <= 2^9`.
+    assert_eq!(booster.n_folds(), 9);
+    let f = booster.run(&tree(&train));
+    assert_eq!(f.hypotheses.len(), 50);
+
+
+    for sample in [&train, &test] {
+        let target = sample.target();
+        let predictions = f.predict_all(sample);
+        let zeros = vec![0f64; target.len()];
+        let loss = GBMLoss::L2.eval(&predictions[..], target);
+        let initial = GBMLoss::L2.eval(&zeros[..], target);
+        assert!(loss < 0.2 * initial, "loss: {loss}, initial: {initial}");
+    }
+}
+
+
+#[test]
+fn permutation_is_reproducible() {
+    let sample = random_sample(200, 1234);
+
+    let run = |seed| {
+        let mut booster = OrderedBoost::init_with_loss(&sample, GBMLoss::L2)
+            .max_iter(10)
+            .seed(seed);
+        booster.run(&tree(&sample)).predict_all(&sample)
+    };
+    assert_eq!(run(1234), run(1234));
+    assert_ne!(run(1234), run(5678));
+}
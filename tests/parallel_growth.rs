@@ -0,0 +1,105 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::uniform;
+
+
+// A random sample of `n_features` features
+// whose label is positive iff the sum of the first three features
+// exceeds `1.5`, with 10% label noise.
+// The sample is large enough to grow deep trees.
+fn large_sample(n_features: usize) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 2_000;
+    let columns = (0..n_features)
+        .map(|j| {
+            let values = (0..n_sample)
+                .map(|_| rng.gen::<f64>())
+                .collect::<Vec<_>>();
+            Series::new(&format!("x{j}"), values)
+        })
+        .collect::<Vec<_>>();
+    let target = (0..n_sample)
+        .map(|i| {
+            let sum = (0..3)
+                .map(|j| columns[j].f64().unwrap().get(i).unwrap())
+                .sum::<f64>();
+            let y = if sum > 1.5 { 1.0 } else { -1.0 };
+            if rng.gen::<f64>() < 0.1 { -y } else { y }
+        })
+        .collect::<Vec<f64>>();
+    let data = DataFrame::new(columns).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// Runs `f` on a thread pool of `n_threads` threads.
+fn with_threads<T, F>(n_threads: usize, f: F) -> T
+    where T: Send,
+          F: FnOnce() -> T + Send,
+{
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(n_threads)
+        .build()
+        .unwrap()
+        .install(f)
+}
+
+
+#[test]
+fn deep_decision_trees_do_not_depend_on_the_threads() {
+    let sample = large_sample(6);
+    let dist = uniform(&sample);
+
+    for criterion in [Criterion::Entropy, Criterion::Gini] {
+        let tree = DecisionTreeBuilder::new(&sample)
+            .max_depth(8)
+            .criterion(criterion)
+            .build();
+        let single = with_threads(1, || tree.produce(&sample, &dist));
+        let multi = with_threads(8, || tree.produce(&sample, &dist));
+
+        assert!(single.n_leaves() > 32, "{} leaves", single.n_leaves());
+        assert_eq!(single.n_leaves(), multi.n_leaves());
+        assert_eq!(
+            single.confidence_all(&sample),
+            multi.confidence_all(&sample),
+        );
+        assert_eq!(single.used_features(), multi.used_features());
+    }
+}
+
+
+#[test]
+fn deep_regression_trees_do_not_depend_on_the_threads() {
+    let sample = large_sample(6);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(7)
+        .loss(GBMLoss::L2)
+        .bins(32)
+        .build();
+
+    let run = || {
+        let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .max_iter(10);
+        booster.run(&tree).predict_all(&sample)
+    };
+    let single = with_threads(1, run);
+    let multi = with_threads(8, run);
+    assert_eq!(single, multi);
+}
+
+
+#[test]
+fn deep_trees_respect_the_feature_budget() {
+    let sample = large_sample(6);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(8)
+        .feature_budget(2)
+        .build();
+
+    let f = with_threads(8, || tree.produce(&sample, &uniform(&sample)));
+    assert!(f.used_features().len() <= 2);
+}
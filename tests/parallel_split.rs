@@ -0,0 +1,126 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random sample of `n_features` features
+// whose label is positive iff the sum of the first three features
+// exceeds `1.5`, with 10% label noise.
+fn wide_sample(n_features: usize) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let columns = (0..n_features)
+        .map(|j| {
+            let values = (0..n_sample)
+                .map(|_| rng.gen::<f64>())
+                .collect::<Vec<_>>();
+            Series::new(&format!("x{j}"), values)
+        })
+        .collect::<Vec<_>>();
+    let target = (0..n_sample)
+        .map(|i| {
+            let sum = (0..3)
+                .map(|j| columns[j].f64().unwrap().get(i).unwrap())
+                .sum::<f64>();
+            let y = if sum > 1.5 { 1.0 } else { -1.0 };
+            if rng.gen::<f64>() < 0.1 { -y } else { y }
+        })
+        .collect::<Vec<f64>>();
+    let data = DataFrame::new(columns).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// A sample with the identical features `a` and `b`.
+fn duplicated_sample() -> Sample {
+    let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let data = df!("a" => x, "b" => x).unwrap();
+    let target = Series::new("class", [-1.0, -1.0, 1.0, 1.0, 1.0, 1.0]);
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+fn random_distribution(n_sample: usize, rng: &mut StdRng) -> Vec<f64> {
+    let dist = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let sum = dist.iter().sum::<f64>();
+    dist.into_iter().map(|d| d / sum).collect()
+}
+
+
+// Runs `f` on a thread pool of `n_threads` threads.
+fn with_threads<T, F>(n_threads: usize, f: F) -> T
+    where T: Send,
+          F: FnOnce() -> T + Send,
+{
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(n_threads)
+        .build()
+        .unwrap()
+        .install(f)
+}
+
+
+#[test]
+fn decision_tree_does_not_depend_on_the_threads() {
+    let sample = wide_sample(40);
+    let n_sample = sample.shape().0;
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..3 {
+        let dist = random_distribution(n_sample, &mut rng);
+        let f = with_threads(1, || tree.produce(&sample, &dist));
+        let g = with_threads(8, || tree.produce(&sample, &dist));
+        assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+        assert_eq!(f.used_features(), g.used_features());
+    }
+}
+
+
+#[test]
+fn regression_tree_does_not_depend_on_the_threads() {
+    let sample = wide_sample(40);
+    let n_sample = sample.shape().0;
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(4)
+        .loss(GBMLoss::L2)
+        .bins(32)
+        .build();
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..3 {
+        let predictions = (0..n_sample)
+            .map(|_| rng.gen::<f64>() - 0.5)
+            .collect::<Vec<_>>();
+        let f = with_threads(1, || tree.produce(&sample, &predictions));
+        let g = with_threads(8, || tree.produce(&sample, &predictions));
+        assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+        assert_eq!(f.used_features(), g.used_features());
+    }
+}
+
+
+#[test]
+fn ties_are_broken_by_the_order_of_the_features() {
+    let sample = duplicated_sample();
+    let n_sample = sample.shape().0;
+    let dist = vec![1.0 / n_sample as f64; n_sample];
+
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .build();
+    let f = with_threads(8, || dtree.produce(&sample, &dist));
+    assert_eq!(f.used_features(), vec!["a"]);
+
+    // A regression tree of depth `2` has a single split.
+    let rtree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::L2)
+        .build();
+    let predictions = vec![0f64; n_sample];
+    let f = with_threads(8, || rtree.produce(&sample, &predictions));
+    assert_eq!(f.used_features(), vec!["a"]);
+}
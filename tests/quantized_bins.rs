@@ -0,0 +1,57 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{diagonal_sample_with_missing_values, uniform};
+
+
+#[test]
+fn quantized_and_wide_positions_match_the_uncached_ones() {
+    let sample = diagonal_sample_with_missing_values(2_000);
+    // `copy` has the values of `sample` but does not share its features.
+    let copy = diagonal_sample_with_missing_values(2_000);
+    let dist = uniform(&sample);
+
+    // The positions on `255` bins fit in 8 bits,
+    // while the ones on `1000` bins do not.
+    for n_bins in [16, 255, 1_000] {
+        let tree = DecisionTreeBuilder::new(&sample)
+            .max_depth(4)
+            .criterion(Criterion::Entropy)
+            .bins(n_bins)
+            .build();
+        // `sample` hits the cache, while `copy` does not.
+        let f = tree.produce(&sample, &dist);
+        let g = tree.produce(&copy, &dist);
+        assert_eq!(
+            f.confidence_all(&sample),
+            g.confidence_all(&sample),
+            "{n_bins} bins",
+        );
+    }
+}
+
+
+#[test]
+fn quantized_regression_trees_match_the_uncached_ones() {
+    let sample = diagonal_sample_with_missing_values(2_000);
+    let copy = diagonal_sample_with_missing_values(2_000);
+
+    for n_bins in [255, 1_000] {
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2)
+            .bins(n_bins)
+            .build();
+        let f = GBM::init_with_loss(&sample, GBMLoss::L2)
+            .max_iter(10)
+            .run(&tree);
+        let g = GBM::init_with_loss(&copy, GBMLoss::L2)
+            .max_iter(10)
+            .run(&tree);
+        assert_eq!(
+            f.predict_all(&sample),
+            g.predict_all(&sample),
+            "{n_bins} bins",
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{training_error, uniform};
+
+
+// A random sample whose label is positive
+// iff `(x, y)` is in the disk of radius `0.35` centered at `(0.5, 0.5)`.
+fn disk_sample(n_sample: usize) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| {
+            let r2 = (a - 0.5).powi(2) + (b - 0.5).powi(2);
+            if r2 < 0.35 * 0.35 { 1.0 } else { -1.0 }
+        })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+fn edge<H: Classifier>(h: &H, sample: &Sample, dist: &[f64]) -> f64 {
+    h.confidence_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .zip(dist)
+        .map(|((p, y), d)| d * y * p)
+        .sum::<f64>()
+}
+
+
+// The maximum edge over all the balls centered at the examples
+// whose boundaries pass through the examples.
+fn brute_force_edge(sample: &Sample, dist: &[f64]) -> f64 {
+    let n_sample = sample.shape().0;
+    let columns = ["x", "y"].into_iter()
+        .map(|name| {
+            let values = (0..n_sample)
+                .map(|i| sample[name][i])
+                .collect::<Vec<_>>();
+            let mean = values.iter().sum::<f64>() / n_sample as f64;
+            let var = values.iter()
+                .map(|v| (v - mean).powi(2))
+                .sum::<f64>()
+                / n_sample as f64;
+            values.into_iter()
+                .map(|v| (v - mean) / var.sqrt())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let distance = |i: usize, j: usize| {
+        columns.iter()
+            .map(|c| (c[i] - c[j]).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    };
+
+    let target = sample.target();
+    let mut best = f64::MIN;
+    for c in 0..n_sample {
+        for r in 0..n_sample {
+            let radius = distance(c, r);
+            let edge = (0..n_sample)
+                .map(|i| {
+                    let p = if distance(c, i) <= radius { 1.0 } else { -1.0 };
+                    dist[i] * target[i] * p
+                })
+                .sum::<f64>();
+            best = best.max(edge.abs());
+        }
+    }
+    best
+}
+
+
+#[test]
+fn radial_learner_maximizes_the_edge() {
+    let sample = disk_sample(100);
+    let n_sample = sample.shape().0;
+    let radial = RadialLearner::init(&sample);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..3 {
+        let mut dist = (0..n_sample)
+            .map(|_| rng.gen::<f64>())
+            .collect::<Vec<_>>();
+        let sum = dist.iter().sum::<f64>();
+        dist.iter_mut().for_each(|d| *d /= sum);
+
+        let h = radial.produce(&sample, &dist);
+        let found = edge(&h, &sample, &dist);
+        let expected = brute_force_edge(&sample, &dist);
+        assert!(
+            (found - expected).abs() < 1e-12,
+            "found: {found}, expected: {expected}"
+        );
+    }
+}
+
+
+#[test]
+fn radial_learner_finds_a_disk() {
+    let sample = disk_sample(300);
+    let h = RadialLearner::init(&sample)
+        .produce(&sample, &uniform(&sample));
+    // The center is restricted to the examples.
+    let error = training_error(&h, &sample);
+    assert!(error < 0.1, "training error: {error}");
+
+    assert_eq!(h.sign(), 1.0);
+    let center = h.center();
+    assert_eq!(center.len(), 2);
+    assert!(center.iter().all(|(_, c)| (c - 0.5).abs() < 0.1));
+}
+
+
+#[test]
+fn prototypes_are_the_heaviest_examples() {
+    let sample = disk_sample(300);
+    let n_sample = sample.shape().0;
+    // Put the largest weight on the first example.
+    let mut dist = vec![1.0; n_sample];
+    dist[0] = 2.0;
+    let sum = dist.iter().sum::<f64>();
+    dist.iter_mut().for_each(|d| *d /= sum);
+
+    let h = RadialLearner::init(&sample)
+        .max_prototypes(1)
+        .produce(&sample, &dist);
+    assert_eq!(h.distance(&sample, 0), 0.0);
+}
+
+
+#[test]
+fn adaboost_fits_with_radial_thresholds() {
+    let sample = disk_sample(300);
+    let radial = RadialLearner::init(&sample)
+        .max_prototypes(50);
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&radial);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.03, "training error: {error}");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_prototypes_panics() {
+    let sample = disk_sample(10);
+    let _ = RadialLearner::init(&sample).max_prototypes(0);
+}
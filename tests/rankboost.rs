@@ -0,0 +1,65 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+// The ranking loss by enumerating all the pairs.
+fn naive_ranking_loss(target: &[f64], scores: &[f64]) -> f64 {
+    let mut misordered = 0f64;
+    let mut n_pairs = 0f64;
+    for (_, sp) in target.iter().zip(scores).filter(|(y, _)| **y > 0.0) {
+        for (_, sn) in target.iter().zip(scores).filter(|(y, _)| **y < 0.0) {
+            n_pairs += 1.0;
+            if sp < sn {
+                misordered += 1.0;
+            } else if sp == sn {
+                misordered += 0.5;
+            }
+        }
+    }
+    misordered / n_pairs
+}
+
+
+#[test]
+fn rankboost_reduces_ranking_loss() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = RankBoost::init(&sample)
+        .max_iter(5);
+    let _ = booster.run(&tree);
+    let early = booster.ranking_loss();
+
+    let mut booster = RankBoost::init(&sample)
+        .max_iter(100);
+    let f = booster.run(&tree);
+    let loss = booster.ranking_loss();
+    assert!(loss < early, "{loss} >= {early}");
+
+
+    // The loss is computed on the scores of the output.
+    let scores = f.confidence_all(&sample);
+    let naive = naive_ranking_loss(sample.target(), &scores[..]);
+    assert!((loss - naive).abs() < 1e-9, "{loss} != {naive}");
+}
+
+
+#[test]
+#[should_panic]
+fn rankboost_requires_both_classes() {
+    let data = df!("x" => [1.0, 2.0, 3.0]).unwrap();
+    let target = Series::new("class", [1.0, 1.0, 1.0]);
+    let sample = Sample::from_dataframe(data, target).unwrap();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let _ = RankBoost::init(&sample).run(&tree);
+}
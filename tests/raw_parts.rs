@@ -0,0 +1,125 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// A random row-major matrix of 3 columns with some missing values
+// and the labels that are positive iff `x + y > 1`.
+fn random_parts(n_sample: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let mut data = Vec::with_capacity(n_sample * 3);
+    let mut target = Vec::with_capacity(n_sample);
+    for i in 0..n_sample {
+        let x = rng.gen::<f64>();
+        let y = rng.gen::<f64>();
+        let z = if i % 7 == 0 { f64::NAN } else { rng.gen::<f64>() };
+        data.extend([x, y, z]);
+        target.push(if x + y > 1.0 { 1.0 } else { -1.0 });
+    }
+    (data, target)
+}
+
+
+fn same(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+
+#[test]
+fn raw_parts_are_read_row_by_row() {
+    let (data, target) = random_parts(100);
+    let sample = Sample::from_raw_parts(&data, 100, 3, &target).unwrap();
+    assert_eq!(sample.shape(), (100, 3));
+    assert_eq!(sample.target(), &target[..]);
+
+    let names = sample.features()
+        .iter()
+        .map(|feat| feat.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Feat. [1]", "Feat. [2]", "Feat. [3]"]);
+
+    for i in 0..100 {
+        let (x, y) = sample.at(i);
+        assert_eq!(y, target[i]);
+        x.iter()
+            .zip(&data[3 * i..3 * (i + 1)])
+            .for_each(|(&a, &b)| assert!(same(a, b)));
+    }
+}
+
+
+#[test]
+fn raw_parts_give_the_same_tree_as_a_dataframe() {
+    let (data, target) = random_parts(500);
+    let mut sample = Sample::from_raw_parts(&data, 500, 3, &target)
+        .unwrap();
+    sample.replace_names(["x", "y", "z"]);
+
+    let column = |j: usize| {
+        data.iter().skip(j).step_by(3).copied().collect::<Vec<_>>()
+    };
+    let frame = df!(
+        "x" => column(0),
+        "y" => column(1),
+        "z" => column(2)
+    ).unwrap();
+    let other = Sample::from_dataframe(frame, Series::new("class", target))
+        .unwrap();
+
+    let dist = vec![1f64 / 500f64; 500];
+    let f = DecisionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .build()
+        .produce(&sample, &dist);
+    let g = DecisionTreeBuilder::new(&other)
+        .max_depth(3)
+        .build()
+        .produce(&other, &dist);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&other));
+    assert_eq!(f.predict_all(&other), g.predict_all(&sample));
+}
+
+
+#[test]
+fn mismatched_raw_parts_are_rejected() {
+    let (data, target) = random_parts(10);
+    assert!(Sample::from_raw_parts(&data, 10, 4, &target).is_err());
+    assert!(Sample::from_raw_parts(&data, 10, 3, &target[1..]).is_err());
+    assert!(Sample::from_raw_parts(&data[3..], 9, 3, &target).is_err());
+    assert!(Sample::from_raw_parts(&[], 0, 3, &[]).is_ok());
+}
+
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray_gives_the_same_sample_as_raw_parts() {
+    use ndarray::{Array1, Array2};
+
+    let (data, target) = random_parts(100);
+    let sample = Sample::from_raw_parts(&data, 100, 3, &target).unwrap();
+
+    let matrix = Array2::from_shape_vec((100, 3), data.clone()).unwrap();
+    let labels = Array1::from_vec(target.clone());
+    let other = Sample::from_ndarray(matrix.view(), labels.view())
+        .unwrap();
+    assert_eq!(other.shape(), (100, 3));
+    assert_eq!(other.target(), &target[..]);
+    for i in 0..100 {
+        let (x, _) = sample.at(i);
+        let (z, _) = other.at(i);
+        x.iter().zip(&z[..]).for_each(|(&a, &b)| assert!(same(a, b)));
+    }
+
+    // A column-major view is read in the same way.
+    let transposed = matrix.t().to_owned();
+    let other = Sample::from_ndarray(transposed.t(), labels.view())
+        .unwrap();
+    for i in 0..100 {
+        let (x, _) = sample.at(i);
+        let (z, _) = other.at(i);
+        x.iter().zip(&z[..]).for_each(|(&a, &b)| assert!(same(a, b)));
+    }
+
+    let short = Array1::from_vec(target[1..].to_vec());
+    assert!(Sample::from_ndarray(matrix.view(), short.view()).is_err());
+}
@@ -0,0 +1,54 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+mod common;
+use common::toy_sample;
+
+
+fn shifted_sample() -> Sample {
+    let s1 = Series::new("x", &[11.0, 13.0, 16.0, 4.0, 2.0,  9.0, 12.0]);
+    let s2 = Series::new("y", &[ 6.0,  7.0,  2.0, 1.0, 8.0, 14.0, 12.0]);
+    let df = DataFrame::new(vec![s1, s2]).unwrap();
+    let target = Series::new(
+        "class", &[1.0, 1.0, 1.0, -1.0, -1.0, -1.0, 1.0]
+    );
+
+    Sample::from_dataframe(df, target).unwrap()
+}
+
+
+#[test]
+fn refit_keeps_the_hypotheses() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(10);
+    let f = booster.run(&dtree);
+
+    let new_sample = shifted_sample();
+    let g = f.refit_weights(&new_sample, 1.0);
+
+    assert!(!g.hypotheses.is_empty());
+    assert!(g.hypotheses.len() <= f.hypotheses.len());
+    let wsum = g.weights.iter().sum::<f64>();
+    assert!((wsum - 1.0).abs() < 1e-6);
+    assert!(g.weights.iter().all(|w| *w > 0.0));
+
+
+    // The re-fitted weights maximize the minimum margin on `new_sample`,
+    // so the minimum margin is at least that of the original weights.
+    let min_margin = |h: &WeightedMajority<_>| {
+        let target = new_sample.target();
+        h.confidence_all(&new_sample)
+            .into_iter()
+            .zip(target.iter())
+            .map(|(p, y)| p * y)
+            .reduce(f64::min)
+            .unwrap()
+    };
+    assert!(min_margin(&g) >= min_margin(&f) - 1e-6);
+}
@@ -0,0 +1,48 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::toy_sample;
+
+
+#[test]
+fn adaboost_fit_more_matches_longer_run() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(5);
+    let f = booster.run(&dtree);
+
+    let mut resumed = AdaBoost::init(&sample)
+        .force_quit_at(3);
+    let _ = resumed.run(&dtree);
+    assert_eq!(resumed.n_rounds(), 3);
+    let g = resumed.fit_more(&dtree, 2);
+    assert_eq!(resumed.n_rounds(), 5);
+
+    assert_eq!(f.weights, g.weights);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+}
+
+
+#[test]
+fn lpboost_fit_more_keeps_the_solver() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let mut booster = LPBoost::init(&sample)
+        .tolerance(0.1);
+    let _ = booster.run(&dtree);
+    let n_rounds = booster.n_rounds();
+
+    let f = booster.fit_more(&dtree, 3);
+    assert!(booster.n_rounds() >= n_rounds);
+    assert!(booster.n_rounds() <= n_rounds + 3);
+    assert!(!f.hypotheses.is_empty());
+}
@@ -0,0 +1,96 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{disk_sample, training_error, uniform};
+
+
+#[test]
+fn rff_learner_fits_a_nonlinear_boundary() {
+    let sample = disk_sample();
+    let f = RffLearnerBuilder::new(&sample)
+        .n_components(200)
+        .lambda(1e-4)
+        .build()
+        .produce(&sample, &uniform(&sample));
+    assert_eq!(f.weights().len(), 200);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+fn rff_learner_is_reproducible_with_a_seed() {
+    let sample = disk_sample();
+    let dist = uniform(&sample);
+    let f = RffLearnerBuilder::new(&sample)
+        .seed(777)
+        .build()
+        .produce(&sample, &dist);
+    let g = RffLearnerBuilder::new(&sample)
+        .seed(777)
+        .build()
+        .produce(&sample, &dist);
+    let h = RffLearnerBuilder::new(&sample)
+        .seed(778)
+        .build()
+        .produce(&sample, &dist);
+    assert_eq!(f, g);
+    assert_ne!(f, h);
+}
+
+
+#[test]
+fn rff_learner_follows_the_distribution() {
+    let sample = disk_sample();
+    let target = sample.target();
+    // Put all the weight on the positive examples.
+    let n_positive = target.iter().filter(|&&y| y > 0.0).count() as f64;
+    let dist = target.iter()
+        .map(|&y| if y > 0.0 { 1.0 / n_positive } else { 0.0 })
+        .collect::<Vec<_>>();
+
+    let f = RffLearnerBuilder::new(&sample)
+        .build()
+        .produce(&sample, &dist);
+    let positives = f.predict_all(&sample)
+        .into_iter()
+        .zip(target)
+        .filter(|(_, y)| **y > 0.0)
+        .all(|(p, _)| p == 1);
+    assert!(positives);
+}
+
+
+#[test]
+fn adaboost_fits_with_rff_learner() {
+    let sample = disk_sample();
+    let rff = RffLearnerBuilder::new(&sample)
+        .bandwidth(0.5)
+        .build();
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(10);
+    let f = booster.run(&rff);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.05, "training error: {error}");
+}
+
+
+#[test]
+fn confidences_are_in_the_unit_interval() {
+    let sample = disk_sample();
+    let f = RffLearnerBuilder::new(&sample)
+        .lambda(1e-5)
+        .build()
+        .produce(&sample, &uniform(&sample));
+    let confidences = f.confidence_all(&sample);
+    assert!(confidences.iter().all(|c| (-1.0..=1.0).contains(c)));
+}
+
+
+#[test]
+#[should_panic]
+fn non_positive_bandwidth_panics() {
+    let sample = disk_sample();
+    let _ = RffLearnerBuilder::new(&sample).bandwidth(0.0);
+}
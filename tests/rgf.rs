@@ -0,0 +1,108 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{sine_sample, tree};
+
+
+fn sum_of_squared_leaves(f: &WeightedMajority<RegressionTreeRegressor>)
+    -> f64
+{
+    f.hypotheses.iter()
+        .flat_map(|h| h.leaf_values())
+        .map(|v| v * v)
+        .sum::<f64>()
+}
+
+
+#[test]
+fn rgf_fits() {
+    let sample = sine_sample();
+    let target = sample.target();
+
+    let mut booster = RGF::init_with_loss(&sample, GBMLoss::L2)
+        .l2_regularization(0.1)
+        .corrective_steps(3)
+        .max_iter(30);
+    let f = booster.run(&tree(&sample));
+    assert!(f.hypotheses.len() <= 30);
+    assert_eq!(
+        booster.n_leaves(),
+        f.hypotheses.iter().map(|h| h.n_leaves()).sum::<usize>()
+    );
+
+
+    let predictions = f.predict_all(&sample);
+    let zeros = vec![0f64; target.len()];
+    let loss = GBMLoss::L2.eval(&predictions[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.1 * initial, "loss: {loss}, initial: {initial}");
+    assert!(booster.objective() < target.len() as f64 * initial);
+}
+
+
+#[test]
+fn more_corrective_steps_decrease_the_objective() {
+    let sample = sine_sample();
+
+    let mut once = RGF::init_with_loss(&sample, GBMLoss::L2)
+        .corrective_steps(1)
+        .max_iter(10);
+    let _ = once.run(&tree(&sample));
+
+    let mut many = RGF::init_with_loss(&sample, GBMLoss::L2)
+        .corrective_steps(10)
+        .max_iter(10);
+    let _ = many.run(&tree(&sample));
+
+    assert!(
+        many.objective() <= once.objective() + 1e-9,
+        "{} > {}", many.objective(), once.objective()
+    );
+}
+
+
+#[test]
+fn regularization_shrinks_leaf_values() {
+    let sample = sine_sample();
+
+    let mut weak = RGF::init_with_loss(&sample, GBMLoss::L2)
+        .l2_regularization(0.01)
+        .max_iter(10);
+    let f = weak.run(&tree(&sample));
+
+    let mut strong = RGF::init_with_loss(&sample, GBMLoss::L2)
+        .l2_regularization(100.0)
+        .max_iter(10);
+    let g = strong.run(&tree(&sample));
+
+    assert!(sum_of_squared_leaves(&g) < sum_of_squared_leaves(&f));
+}
+
+
+#[test]
+fn leaf_values_agree_with_predictions() {
+    let sample = sine_sample();
+    let mut h = tree(&sample).produce(&sample, &vec![0f64; 300][..]);
+
+    let values = h.leaf_values();
+    assert_eq!(values.len(), h.n_leaves());
+    for i in 0..300 {
+        assert_eq!(h.predict(&sample, i), values[h.leaf_index(&sample, i)]);
+    }
+
+
+    let doubled = values.iter().map(|v| 2.0 * v).collect::<Vec<_>>();
+    h.set_leaf_values(&doubled[..]);
+    for i in 0..300 {
+        assert_eq!(h.predict(&sample, i), doubled[h.leaf_index(&sample, i)]);
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn negative_regularization_panics() {
+    let sample = sine_sample();
+    let _ = RGF::init_with_loss(&sample, GBMLoss::L2)
+        .l2_regularization(-1.0);
+}
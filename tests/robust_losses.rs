@@ -0,0 +1,81 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::diagonal_sample;
+
+
+const LOSSES: [GBMLoss; 3] = [
+    GBMLoss::Savage,
+    GBMLoss::TruncatedLogLoss(1.0),
+    GBMLoss::TruncatedLogLoss(3.0),
+];
+
+
+#[test]
+fn derivatives_agree_with_finite_differences() {
+    let h = 1e-5;
+    for loss in LOSSES {
+        for y in [-1.0, 1.0] {
+            for p in [-3.0, -1.0, -0.2, 0.0, 0.4, 2.0] {
+                let g = loss.gradient(&[p], &[y])[0];
+                let numer = (loss.eval_at_point(p + h, y)
+                    - loss.eval_at_point(p - h, y)) / (2.0 * h);
+                assert!((g - numer).abs() < 1e-6, "{}: {g} vs {numer}", loss.name());
+
+                let hess = loss.hessian(&[p], &[y])[0];
+                let numer = (loss.gradient(&[p + h], &[y])[0]
+                    - loss.gradient(&[p - h], &[y])[0]) / (2.0 * h);
+                assert!(hess >= 0.0);
+                assert!((hess - numer.abs()).abs() < 1e-6, "{}: {hess} vs {numer}", loss.name());
+            }
+        }
+    }
+}
+
+
+#[test]
+fn losses_are_bounded() {
+    for p in [-1e3, -10.0, 0.0, 10.0] {
+        assert!(GBMLoss::Savage.eval_at_point(p, 1.0) <= 1.0);
+        assert!(GBMLoss::TruncatedLogLoss(2.0).eval_at_point(p, 1.0) <= 2.0);
+    }
+    assert!((GBMLoss::Savage.eval_at_point(0.0, 1.0) - 0.25).abs() < 1e-12);
+    assert!(GBMLoss::TruncatedLogLoss(2.0).eval_at_point(1e3, 1.0) < 1e-12);
+}
+
+
+#[test]
+fn gbm_fits_noisy_labels() {
+    let sample = diagonal_sample(400, 0.2, 1234);
+    let test = diagonal_sample(400, 0.0, 5678);
+
+    for loss in LOSSES {
+        let tree = RegressionTreeBuilder::new(&sample)
+            .max_depth(2)
+            .loss(loss)
+            .build();
+        let mut booster = GBM::init_with_loss(&sample, loss)
+            .max_iter(50);
+        let f = booster.run(&tree);
+
+
+        let target = sample.target();
+        let scores = f.predict_all(&sample);
+        let zeros = vec![0f64; scores.len()];
+        assert!(loss.eval(&scores[..], target) < loss.eval(&zeros[..], target));
+
+        let n_correct = f.predict_all(&test)
+            .into_iter()
+            .zip(test.target())
+            .filter(|(s, y)| s.signum() == **y)
+            .count();
+        assert!(n_correct >= 340, "{}: {n_correct} of 400", loss.name());
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn nonpositive_truncation_level_panics() {
+    let _ = GBMLoss::TruncatedLogLoss(0.0).eval_at_point(0.0, 1.0);
+}
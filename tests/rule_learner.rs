@@ -0,0 +1,120 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{diagonal_sample, training_error, uniform};
+
+
+// A random sample whose label is positive iff `x > 0.5` and `y <= 0.3`,
+// with a noise feature `z`.
+fn conjunction_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 300;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let z = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| if *a > 0.5 && *b <= 0.3 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y, "z" => z).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+fn edge<H: Classifier>(h: &H, sample: &Sample, dist: &[f64]) -> f64 {
+    h.confidence_all(sample)
+        .into_iter()
+        .zip(sample.target())
+        .zip(dist)
+        .map(|((p, y), d)| d * y * p)
+        .sum::<f64>()
+}
+
+
+#[test]
+fn rule_learner_finds_a_conjunction() {
+    let sample = conjunction_sample();
+    let h = RuleLearner::init(&sample)
+        .produce(&sample, &uniform(&sample));
+    // The greedy growth may cut a few examples near the boundary.
+    let error = training_error(&h, &sample);
+    assert!(error < 0.01, "training error: {error}, rule: {h}");
+
+    assert_eq!(h.sign(), 1.0);
+    let mut features = h.conditions()
+        .iter()
+        .map(|c| (c.feature(), c.greater()))
+        .collect::<Vec<_>>();
+    features.sort();
+    assert_eq!(features, vec![("x", true), ("y", false)]);
+}
+
+
+#[test]
+fn rules_are_no_worse_than_stumps() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let n_sample = sample.shape().0;
+    let rule = RuleLearner::init(&sample);
+    let stump = DecisionStump::init(&sample);
+
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..5 {
+        let mut dist = (0..n_sample)
+            .map(|_| rng.gen::<f64>())
+            .collect::<Vec<_>>();
+        let sum = dist.iter().sum::<f64>();
+        dist.iter_mut().for_each(|d| *d /= sum);
+
+        let h = rule.produce(&sample, &dist);
+        let g = stump.produce(&sample, &dist);
+        let found = edge(&h, &sample, &dist);
+        let bound = edge(&g, &sample, &dist);
+        assert!(found >= bound - 1e-12, "rule: {found}, stump: {bound}");
+    }
+}
+
+
+#[test]
+fn rules_respect_the_maximal_number_of_conditions() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+    for k in 1..=3 {
+        let h = RuleLearner::init(&sample)
+            .max_conditions(k)
+            .produce(&sample, &dist);
+        assert!(h.conditions().len() <= k);
+    }
+}
+
+
+#[test]
+fn adaboost_fits_with_rules() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let rule = RuleLearner::init(&sample);
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(100);
+    let f = booster.run(&rule);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.05, "training error: {error}");
+}
+
+
+#[test]
+fn rules_are_printed_as_text() {
+    let h = RuleClassifier::new(
+        vec![Condition::new("x", 0.5, true), Condition::new("y", 0.3, false)],
+        -1.0,
+    );
+    assert_eq!(h.to_string(), "IF x > 0.5 AND y <= 0.3 THEN -1 ELSE +1");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_conditions_panics() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let _ = RuleLearner::init(&sample).max_conditions(0);
+}
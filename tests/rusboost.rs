@@ -0,0 +1,80 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+// An imbalanced random sample whose label is positive iff `x + y > 1.6`,
+// so that about 8% of the examples are positive.
+fn imbalanced_sample() -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_sample = 500;
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| if a + b > 1.6 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+// Returns the fraction of the positive examples predicted as positive.
+fn recall<H: Classifier>(sample: &Sample, f: &H) -> f64 {
+    let predictions = f.predict_all(sample);
+    let (tp, p) = sample.target()
+        .iter()
+        .zip(predictions)
+        .filter(|(y, _)| **y > 0.0)
+        .fold((0, 0), |(tp, p), (_, h)| (tp + (h > 0) as usize, p + 1));
+    tp as f64 / p as f64
+}
+
+
+fn run(sample: &Sample, booster: RUSBoost<'_, DecisionTreeClassifier>)
+    -> WeightedMajority<DecisionTreeClassifier>
+{
+    let mut booster = booster.max_iter(20);
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+    booster.run(&tree)
+}
+
+
+#[test]
+fn under_sampling() {
+    let sample = imbalanced_sample();
+    let f = run(&sample, RUSBoost::init(&sample));
+    let r = recall(&sample, &f);
+    assert!(r > 0.8, "recall: {r}");
+}
+
+
+#[test]
+fn smote() {
+    let sample = imbalanced_sample();
+    let f = run(&sample, RUSBoost::init(&sample).resampling(Resampling::Smote(5)));
+    let r = recall(&sample, &f);
+    assert!(r > 0.8, "recall: {r}");
+}
+
+
+#[test]
+fn same_seed_same_hypothesis() {
+    let sample = imbalanced_sample();
+    for resampling in [Resampling::Under, Resampling::Smote(3)] {
+        let f = run(&sample, RUSBoost::init(&sample).resampling(resampling).seed(7));
+        let g = run(&sample, RUSBoost::init(&sample).resampling(resampling).seed(7));
+        assert_eq!(f.weights, g.weights);
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn invalid_ratio() {
+    let sample = imbalanced_sample();
+    let _ = RUSBoost::<DecisionTreeClassifier>::init(&sample).ratio(1.5);
+}
@@ -0,0 +1,50 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+fn multiclass_sample() -> Sample {
+    let data = df!(
+        "x" => [9.0, 8.0, 7.0, 1.0, 2.0, 3.0, 2.0, 1.0, 3.0],
+        "y" => [1.0, 3.0, 2.0, 8.0, 9.0, 7.0, 3.0, 2.0, 1.0],
+        "z" => [2.0, 1.0, 3.0, 3.0, 1.0, 2.0, 7.0, 9.0, 8.0],
+    ).unwrap();
+    let target = Series::new(
+        "class", &[0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0]
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn samme_predicts_classes() {
+    let sample = multiclass_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = SAMME::init(&sample)
+        .force_quit_at(20);
+    assert_eq!(booster.classes(), &[0, 1, 2]);
+    let f = booster.run(&dtree);
+
+    let expected = (0..9).map(|i| (i / 3) as i64).collect::<Vec<_>>();
+    assert_eq!(f.predict_class_all(&sample), expected);
+}
+
+
+#[test]
+fn sammer_predicts_classes() {
+    let sample = multiclass_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = SAMMER::init(&sample)
+        .force_quit_at(20);
+    let f = booster.run(&dtree);
+
+    let expected = (0..9).map(|i| (i / 3) as i64).collect::<Vec<_>>();
+    assert_eq!(f.predict_class_all(&sample), expected);
+}
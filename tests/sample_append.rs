@@ -0,0 +1,70 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+
+fn toy_sample() -> Sample {
+    let s1 = Series::new("x", &[10.0, 14.0, 15.0, 5.0]);
+    let s2 = Series::new("y", &[ 5.0,  8.0,  3.0, 1.0]);
+    let df = DataFrame::new(vec![s1, s2]).unwrap();
+    let target = Series::new("class", &[1.0, 1.0, 1.0, -1.0]);
+
+    Sample::from_dataframe(df, target).unwrap()
+}
+
+
+#[test]
+fn push_rows_appends_in_column_order() {
+    let mut sample = toy_sample();
+
+    // The columns can be given in any order.
+    let df = df!(
+        "y" => [9.0, 13.0, 11.0],
+        "x" => [3_i64, 8, 12],
+    ).unwrap();
+    let target = Series::new("class", &[-1.0, -1.0, -1.0]);
+    sample.push_rows(df, target).unwrap();
+
+    assert_eq!(sample.shape(), (7, 2));
+    assert_eq!(sample.at(4), (vec![3.0, 9.0], -1.0));
+    assert_eq!(sample.at(6), (vec![12.0, 11.0], -1.0));
+}
+
+
+#[test]
+fn push_rows_rejects_mismatched_rows() {
+    let mut sample = toy_sample();
+
+    let df = df!("x" => [3.0], "z" => [9.0]).unwrap();
+    let target = Series::new("class", &[-1.0]);
+    assert!(sample.push_rows(df, target).is_err());
+
+    let df = df!("x" => [3.0], "y" => ["a"]).unwrap();
+    let target = Series::new("class", &[-1.0]);
+    assert!(sample.push_rows(df, target).is_err());
+
+    let df = df!("x" => [3.0], "y" => [9.0]).unwrap();
+    let target = Series::new("class", &[-1_i64]);
+    assert!(sample.push_rows(df, target).is_err());
+
+    assert_eq!(sample.shape(), (4, 2));
+}
+
+
+#[test]
+fn extend_from_appends_other_sample() {
+    let mut sample = toy_sample();
+    let other = toy_sample();
+    sample.extend_from(&other).unwrap();
+
+    assert_eq!(sample.shape(), (8, 2));
+    assert_eq!(sample.at(5), other.at(1));
+    assert_eq!(sample.target().len(), 8);
+
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .build();
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(5);
+    let f = booster.run(&tree);
+    assert_eq!(f.predict_all(&sample).len(), 8);
+}
@@ -0,0 +1,138 @@
+use miniboosts::prelude::*;
+use miniboosts::{Scaler, StandardScaler, MinMaxScaler, QuantileScaler};
+use polars::prelude::*;
+use rand::prelude::*;
+
+
+fn column(sample: &Sample, name: &str) -> Vec<f64> {
+    let feature = &sample[name];
+    (0..sample.shape().0).map(|i| feature[i]).collect()
+}
+
+
+fn small_sample() -> Sample {
+    let x = [Some(1.0), Some(2.0), None, Some(3.0), Some(6.0)];
+    let c = [0.0, 1.0, 2.0, 1.0, 0.0];
+    let data = df!("x" => x, "c" => c).unwrap();
+    let target = Series::new("class", [1.0, -1.0, 1.0, -1.0, 1.0]);
+    Sample::from_dataframe(data, target)
+        .unwrap()
+        .set_categorical(["c"])
+}
+
+
+#[test]
+fn standard_scaler_centers_and_scales() {
+    let sample = small_sample();
+    let scaler = StandardScaler::fit(&sample);
+    assert_eq!(scaler.mean("x"), Some(3.0));
+    assert_eq!(scaler.std("x"), Some(3.5f64.sqrt()));
+    assert_eq!(scaler.mean("c"), None);
+
+    let scaled = scaler.transform(&sample);
+    let x = column(&scaled, "x");
+    assert!(x[2].is_nan());
+    let values = [x[0], x[1], x[3], x[4]];
+    let mean = values.iter().sum::<f64>() / 4.0;
+    let var = values.iter().map(|v| v * v).sum::<f64>() / 4.0;
+    assert!(mean.abs() < 1e-12);
+    assert!((var - 1.0).abs() < 1e-12);
+
+    // The categorical feature is not changed.
+    assert_eq!(column(&scaled, "c"), column(&sample, "c"));
+    assert!(scaled.is_categorical("c"));
+    assert_eq!(scaled.target(), sample.target());
+
+    let json = serde_json::to_string(&scaler).unwrap();
+    let loaded: StandardScaler = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.mean("x"), scaler.mean("x"));
+    let std = loaded.std("x").unwrap();
+    assert!((std - scaler.std("x").unwrap()).abs() < 1e-12);
+    assert_eq!(loaded.mean("c"), None);
+}
+
+
+#[test]
+fn min_max_scaler_maps_to_the_unit_interval() {
+    let sample = small_sample();
+    let scaler = MinMaxScaler::fit(&sample);
+    let x = column(&scaler.transform(&sample), "x");
+    assert_eq!([x[0], x[1], x[3], x[4]], [0.0, 0.2, 0.4, 1.0]);
+
+    // The values out of the training range are mapped out of `[0, 1]`.
+    let data = df!("x" => [11.0], "c" => [0.0]).unwrap();
+    let test = Sample::from_dataframe(data, Series::new("class", [1.0]))
+        .unwrap();
+    assert_eq!(column(&scaler.transform(&test), "x"), [2.0]);
+}
+
+
+#[test]
+fn quantile_scaler_maps_to_the_ranks() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let x = (0..1001).map(|_| rng.gen::<f64>().powi(5) * 1e6)
+        .collect::<Vec<_>>();
+    let target = vec![1.0; 1001];
+    let data = df!("x" => x.clone()).unwrap();
+    let sample = Sample::from_dataframe(data, Series::new("class", target))
+        .unwrap();
+
+    let scaler = QuantileScaler::fit_with(&sample, 101);
+    let scaled = column(&scaler.transform(&sample), "x");
+    assert!(scaled.iter().all(|v| (0.0..=1.0).contains(v)));
+
+    let mut order = (0..1001).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| x[i].total_cmp(&x[j]));
+    assert_eq!(scaled[order[0]], 0.0);
+    assert_eq!(scaled[order[1000]], 1.0);
+    assert!((scaled[order[500]] - 0.5).abs() < 1e-12);
+    assert!(order.windows(2).all(|w| scaled[w[0]] <= scaled[w[1]]));
+
+    // Ties are mapped to the middle of their ranks.
+    let scaler = QuantileScaler::fit(&small_sample());
+    let x = column(&scaler.transform(&small_sample()), "x");
+    assert_eq!([x[0], x[1], x[3], x[4]], [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+}
+
+
+// A random sample whose label is positive iff `x + y / 1000 > 1`.
+fn random_sample(n_sample: usize, seed: u64) -> Sample {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let y = (0..n_sample).map(|_| 1000.0 * rng.gen::<f64>())
+        .collect::<Vec<_>>();
+    let target = x.iter()
+        .zip(&y[..])
+        .map(|(a, b)| if a + b / 1000.0 > 1.0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x, "y" => y).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn scaled_hypotheses_scale_the_samples_to_predict() {
+    let train = random_sample(300, 1234);
+    let test = random_sample(100, 5678);
+
+    let scaler = StandardScaler::fit(&train);
+    let scaled = scaler.transform(&train);
+    let learner = LinearLearnerBuilder::new(&scaled).build();
+    let f = AdaBoost::init(&scaled)
+        .force_quit_at(10)
+        .run(&learner);
+
+    let expected = f.confidence_all(&scaler.transform(&test));
+    let f = ScaledHypothesis::new(scaler, f);
+    assert_eq!(f.confidence_all(&test), expected);
+    (0..100).for_each(|i| {
+        assert!((f.confidence(&test, i) - expected[i]).abs() < 1e-12);
+    });
+
+    let predictions = f.predict_all(&test);
+    let n_correct = predictions.iter()
+        .zip(test.target())
+        .filter(|&(&p, &y)| p == y as i64)
+        .count();
+    assert!(n_correct > 90, "Only {n_correct} correct predictions");
+}
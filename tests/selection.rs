@@ -0,0 +1,50 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::toy_sample;
+
+
+#[test]
+fn produce_many_starts_from_the_best() {
+    let sample = toy_sample();
+    let dist = vec![1.0/7.0; 7];
+
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+    let f = dtree.produce(&sample, &dist);
+    let fs = dtree.produce_many(&sample, &dist, 5);
+
+    // The toy sample has only two features.
+    assert_eq!(fs.len(), 2);
+    assert_eq!(f.predict_all(&sample), fs[0].predict_all(&sample));
+}
+
+
+#[test]
+fn adaboost_with_random_selection() {
+    let sample = toy_sample();
+    let dtree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Edge)
+        .build();
+
+    let selections = [
+        Selection::Uniform(2),
+        Selection::Softmax { k: 2, temperature: 0.1 },
+    ];
+    for selection in selections {
+        let mut booster = AdaBoost::init(&sample)
+            .selection(selection)
+            .force_quit_at(100);
+        let f = booster.run(&dtree);
+
+        let loss = f.predict_all(&sample)
+            .into_iter()
+            .zip(sample.target().iter())
+            .filter(|(p, y)| *p != **y as i64)
+            .count();
+        assert_eq!(loss, 0);
+    }
+}
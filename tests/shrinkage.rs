@@ -0,0 +1,108 @@
+use miniboosts::prelude::*;
+
+mod common;
+use common::{diagonal_sample, training_error};
+
+
+#[test]
+fn adaboost_shrinkage_extends_the_bound() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+
+    let plain = AdaBoost::<DecisionTreeClassifier>::init(&sample)
+        .tolerance(0.1);
+    let shrunk = AdaBoost::<DecisionTreeClassifier>::init(&sample)
+        .tolerance(0.1)
+        .shrinkage(0.5);
+    let ratio = shrunk.max_loop() as f64 / plain.max_loop() as f64;
+    assert!((ratio - 2.0).abs() < 0.05, "ratio: {ratio}");
+}
+
+
+#[test]
+fn adaboost_shrinkage_fits() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut plain = AdaBoost::init(&sample)
+        .force_quit_at(50);
+    let f = plain.run(&tree);
+
+    let mut same = AdaBoost::init(&sample)
+        .shrinkage(1.0)
+        .force_quit_at(50);
+    let g = same.run(&tree);
+    assert_eq!(f.weights, g.weights);
+
+
+    let mut shrunk = AdaBoost::init(&sample)
+        .shrinkage(0.3)
+        .force_quit_at(100);
+    let h = shrunk.run(&tree);
+    let error = training_error(&h, &sample);
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+fn gbm_shrinkage_scales_coefficients() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::LogLoss)
+        .build();
+
+    let mut plain = GBM::init_with_loss(&sample, GBMLoss::LogLoss)
+        .max_iter(1);
+    let f = plain.run(&tree);
+
+    let mut shrunk = GBM::init_with_loss(&sample, GBMLoss::LogLoss)
+        .shrinkage(0.1)
+        .max_iter(1);
+    let g = shrunk.run(&tree);
+
+    assert_eq!(f.weights.len(), 1);
+    assert!((g.weights[0] - 0.1 * f.weights[0]).abs() < 1e-12);
+}
+
+
+#[test]
+fn gbm_shrinkage_fits_with_more_rounds() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let target = sample.target();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(2)
+        .loss(GBMLoss::LogLoss)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::LogLoss)
+        .shrinkage(0.1)
+        .max_iter(500);
+    let f = booster.run(&tree);
+    assert!(booster.terminated() <= 500);
+
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::LogLoss.eval(&scores[..], target);
+    let initial = GBMLoss::LogLoss.eval(&zeros[..], target);
+    assert!(loss < 0.5 * initial, "loss: {loss}, initial: {initial}");
+
+    let n_correct = scores.iter()
+        .zip(target)
+        .filter(|(s, y)| s.signum() == **y)
+        .count();
+    assert!(n_correct >= 270, "{n_correct} of 300 examples are correct");
+}
+
+
+#[test]
+#[should_panic]
+fn zero_shrinkage_panics() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let _ = GBM::<RegressionTreeRegressor, _>::init_with_loss(
+        &sample, GBMLoss::L2
+    ).shrinkage(0.0);
+}
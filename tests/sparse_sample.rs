@@ -0,0 +1,173 @@
+use miniboosts::prelude::*;
+use miniboosts::{Feature, DEFAULT_SPARSE_THRESHOLD};
+use polars::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::uniform;
+
+
+// A random sample with a one-hot encoded feature of 20 categories
+// (`c0` -- `c19`), a rarely non-zero feature `rare`
+// with some missing values if `missing` is `true`,
+// and a dense feature `x`.
+// The label is positive iff the category is even and `x > 0.3`.
+fn one_hot_sample(n_sample: usize, missing: bool) -> Sample {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let category = (0..n_sample)
+        .map(|_| rng.gen_range(0..20))
+        .collect::<Vec<usize>>();
+    let x = (0..n_sample).map(|_| rng.gen::<f64>()).collect::<Vec<_>>();
+    let rare = (0..n_sample)
+        .map(|i| match i % 40 {
+            0 if missing => None,
+            0 | 1 => Some(rng.gen::<f64>()),
+            _ => Some(0f64),
+        })
+        .collect::<Vec<_>>();
+    let target = category.iter()
+        .zip(&x[..])
+        .map(|(c, x)| if c % 2 == 0 && *x > 0.3 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+
+    let mut columns = (0..20)
+        .map(|k| {
+            let column = category.iter()
+                .map(|&c| if c == k { 1f64 } else { 0f64 })
+                .collect::<Vec<_>>();
+            Series::new(&format!("c{k}"), column)
+        })
+        .collect::<Vec<_>>();
+    columns.push(Series::new("rare", rare));
+    columns.push(Series::new("x", x));
+    let data = DataFrame::new(columns).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target)).unwrap()
+}
+
+
+#[test]
+fn sparse_features_are_selected_by_density() {
+    let sample = one_hot_sample(1000, true);
+    let is_sparse = |sample: &Sample, name: &str| {
+        matches!(sample[name], Feature::Sparse(_))
+    };
+
+    (0..20).for_each(|k| assert!(is_sparse(&sample, &format!("c{k}"))));
+    assert!(is_sparse(&sample, "rare"));
+    assert!(!is_sparse(&sample, "x"));
+    assert!(sample["x"].density() > DEFAULT_SPARSE_THRESHOLD);
+    assert!(sample.density() < 0.2);
+
+    let n_sparse = |sample: &Sample| {
+        sample.features()
+            .iter()
+            .filter(|f| matches!(f, Feature::Sparse(_)))
+            .count()
+    };
+    assert_eq!(n_sparse(&sample), 21);
+    assert_eq!(n_sparse(&sample.clone().with_sparse_threshold(0.0)), 0);
+    assert_eq!(n_sparse(&sample.with_sparse_threshold(2.0)), 22);
+}
+
+
+#[test]
+fn sparse_and_dense_samples_have_the_same_rows() {
+    let sparse = one_hot_sample(500, true).with_sparse_threshold(2.0);
+    let dense = sparse.clone().with_sparse_threshold(0.0);
+    let n_sample = sparse.shape().0;
+    assert_eq!(sparse.shape(), dense.shape());
+
+    for i in 0..n_sample {
+        let (x, y) = sparse.at(i);
+        let (z, w) = dense.at(i);
+        assert_eq!(y, w);
+        assert_eq!(x.len(), z.len());
+        x.iter()
+            .zip(&z[..])
+            .for_each(|(a, b)| {
+                assert!(a == b || (a.is_nan() && b.is_nan()));
+            });
+    }
+
+    for feature in sparse.features() {
+        let name = feature.name();
+        for i in 0..n_sample {
+            let (a, b) = (sparse[name][i], dense[name][i]);
+            assert!(a == b || (a.is_nan() && b.is_nan()));
+        }
+    }
+}
+
+
+#[test]
+fn sparse_and_dense_samples_have_the_same_statistics() {
+    let sample = one_hot_sample(500, true).with_sparse_threshold(2.0);
+    let dense = sample.clone().with_sparse_threshold(0.0);
+    let dist = uniform(&sample);
+
+    // Drop the feature with missing values.
+    let close = |a: Vec<(f64, f64)>, b: Vec<(f64, f64)>| {
+        a.into_iter()
+            .zip(b)
+            .filter(|((m, _), _)| !m.is_nan())
+            .for_each(|((m, v), (n, u))| {
+                assert!((m - n).abs() < 1e-12, "mean: {m} vs {n}");
+                assert!((v - u).abs() < 1e-12, "variance: {v} vs {u}");
+            });
+    };
+    close(
+        sample.weighted_mean_and_variance(&dist),
+        dense.weighted_mean_and_variance(&dist),
+    );
+    for y in [-1.0, 1.0] {
+        close(
+            sample.weighted_mean_and_variance_for_label(y, &dist),
+            dense.weighted_mean_and_variance_for_label(y, &dist),
+        );
+    }
+}
+
+
+#[test]
+fn sparse_and_dense_samples_grow_the_same_trees() {
+    let sample = one_hot_sample(1000, true);
+    let dense = sample.clone().with_sparse_threshold(0.0);
+    let dist = uniform(&sample);
+
+    for bins in [None, Some(32)] {
+        let confidence = |sample: &Sample| {
+            let builder = DecisionTreeBuilder::new(sample).max_depth(4);
+            let tree = match bins {
+                Some(n) => builder.bins(n).build(),
+                None => builder.build(),
+            };
+            tree.produce(sample, &dist).confidence_all(sample)
+        };
+        assert_eq!(confidence(&sample), confidence(&dense));
+    }
+
+    let gbm = |sample: &Sample| {
+        let tree = RegressionTreeBuilder::new(sample)
+            .max_depth(3)
+            .loss(GBMLoss::L2)
+            .build();
+        let f = GBM::init_with_loss(sample, GBMLoss::L2)
+            .max_iter(10)
+            .run(&tree);
+        f.predict_all(sample)
+    };
+    assert_eq!(gbm(&sample), gbm(&dense));
+}
+
+
+#[test]
+fn sparse_and_dense_samples_train_the_same_naive_bayes() {
+    let sample = one_hot_sample(1000, false).with_sparse_threshold(2.0);
+    let dense = sample.clone().with_sparse_threshold(0.0);
+    let dist = uniform(&sample);
+
+    let nb = GaussianNB::init();
+    let f = nb.produce(&sample, &dist);
+    let g = nb.produce(&dense, &dist);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&dense));
+}
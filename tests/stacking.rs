@@ -0,0 +1,91 @@
+use miniboosts::prelude::*;
+use miniboosts::Stacking;
+
+mod common;
+use common::{accuracy, diagonal_sample};
+
+
+// A classifier that ignores the input.
+#[derive(Clone)]
+struct Constant(f64);
+
+
+impl Classifier for Constant {
+    fn confidence(&self, _sample: &Sample, _row: usize) -> f64 {
+        self.0
+    }
+}
+
+
+fn adaboost(train: &Sample) -> WeightedMajority<DecisionTreeClassifier> {
+    let tree = DecisionTreeBuilder::new(train)
+        .max_depth(2)
+        .criterion(Criterion::Entropy)
+        .build();
+    AdaBoost::init(train)
+        .force_quit_at(30)
+        .run(&tree)
+}
+
+
+#[test]
+fn stacking_prefers_the_better_learner() {
+    let sample = diagonal_sample(300, 0.1, 1234);
+    let test = diagonal_sample(300, 0.1, 5678);
+
+    let stacking = Stacking::<Box<dyn Classifier>>::new(&sample)
+        .n_folds(4)
+        .l2_regularization(0.1)
+        .learner(|train: &Sample| Box::new(adaboost(train)))
+        .learner(|_: &Sample| Box::new(Constant(0.5)));
+
+
+    let confidences = stacking.out_of_fold_confidences();
+    assert_eq!(confidences.len(), 300);
+    assert!(confidences.iter().all(|z| z.len() == 2 && z[1] == 0.5));
+
+
+    let f = stacking.fit();
+    assert_eq!(f.hypotheses.len(), 2);
+    assert!(f.weights[0] > 0.0);
+    assert!(f.weights[0] > f.weights[1].abs());
+
+    let probabilities = f.probability_all(&test);
+    assert!(probabilities.iter().all(|p| (0.0..=1.0).contains(p)));
+
+    let accuracy = accuracy(&f, &test);
+    assert!(accuracy > 0.8, "test accuracy: {accuracy}");
+}
+
+
+#[test]
+fn stacking_of_the_same_type() {
+    let sample = diagonal_sample(200, 0.1, 1234);
+
+    let f = Stacking::new(&sample)
+        .learner(adaboost)
+        .learner(|train: &Sample| {
+            let tree = DecisionTreeBuilder::new(train)
+                .max_depth(1)
+                .criterion(Criterion::Entropy)
+                .build();
+            let nu = 0.1 * train.shape().0 as f64;
+            LPBoost::init(train)
+                .tolerance(0.01)
+                .nu(nu)
+                .run(&tree)
+        })
+        .fit();
+
+    assert_eq!(f.weights.len(), 2);
+    assert!(accuracy(&f, &sample) > 0.8);
+}
+
+
+#[test]
+#[should_panic]
+fn one_fold_panics() {
+    let sample = diagonal_sample(10, 0.1, 1234);
+    let _ = Stacking::<Constant>::new(&sample)
+        .n_folds(1);
+}
@@ -0,0 +1,97 @@
+use miniboosts::prelude::*;
+use miniboosts::SoftMarginObjective;
+use miniboosts::research::ObjectiveFunction;
+use polars::prelude::*;
+
+mod common;
+use common::Stump;
+
+
+// The label is positive iff `x > 5`, except the last noisy example.
+fn toy_sample() -> Sample {
+    let data = df!(
+        "x" => [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 8.5],
+        "y" => [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0, 8.0],
+    ).unwrap();
+    let target = Series::new(
+        "class",
+        [-1.0, -1.0, -1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0, -1.0],
+    );
+    Sample::from_dataframe(data, target).unwrap()
+}
+
+
+#[test]
+fn totalboost_with_capping() {
+    let sample = toy_sample();
+    let tree = DecisionTreeBuilder::new(&sample)
+        .max_depth(1)
+        .criterion(Criterion::Entropy)
+        .build();
+
+    let mut booster = TotalBoost::init(&sample)
+        .tolerance(0.1)
+        .nu(2.0);
+    let f = booster.run(&tree);
+
+    let target = sample.target();
+    let predictions = f.predict_all(&sample);
+    let n_correct = target.iter()
+        .zip(predictions)
+        .take(10)
+        .filter(|(y, p)| **y as i64 == *p)
+        .count();
+    assert_eq!(n_correct, 10);
+}
+
+
+// All the stumps over the grid `{0.5, 1.5, ..., 10.5}`
+// and their negations.
+fn stumps() -> HypothesisPool<Stump> {
+    let mut stumps = Vec::new();
+    for feature in ["x", "y"] {
+        for k in 0..=10 {
+            let threshold = k as f64 + 0.5;
+            for sign in [1.0, -1.0] {
+                stumps.push(Stump { feature, threshold, sign });
+            }
+        }
+    }
+    HypothesisPool::new(stumps)
+}
+
+
+#[test]
+fn totalboost_attains_the_soft_margin() {
+    let sample = toy_sample();
+    let pool = stumps();
+    let tolerance = 0.01;
+
+    for nu in [1.0, 5.0, 11.0] {
+        let f = TotalBoost::init(&sample)
+            .tolerance(tolerance)
+            .nu(nu)
+            .run(&pool);
+        let g = LPBoost::init(&sample)
+            .tolerance(1e-4)
+            .nu(nu)
+            .run(&pool);
+
+        let objective = SoftMarginObjective::new(nu);
+        let total = objective.eval(&sample, &f);
+        let optimum = objective.eval(&sample, &g);
+        assert!(
+            (total - optimum).abs() <= tolerance,
+            "nu = {nu}: TotalBoost attains {total}, the optimum is {optimum}"
+        );
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn totalboost_rejects_invalid_nu() {
+    let sample = toy_sample();
+    let _ = TotalBoost::<DecisionTreeClassifier>::init(&sample)
+        .nu(0.5);
+}
@@ -0,0 +1,86 @@
+use miniboosts::prelude::*;
+use miniboosts::tuning::{GridSearch, RandomSearch, Params};
+
+mod common;
+use common::{diagonal_sample, zero_one_loss};
+
+
+fn train(sample: &Sample, params: &Params)
+    -> WeightedMajority<DecisionTreeClassifier>
+{
+    let tree = DecisionTreeBuilder::new(sample)
+        .max_depth(params["max_depth"] as usize)
+        .build();
+    AdaBoost::init(sample)
+        .force_quit_at(params["iterations"] as usize)
+        .run(&tree)
+}
+
+
+#[test]
+fn grid_search_evaluates_every_combination() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let search = GridSearch::new(&sample)
+        .n_folds(3)
+        .param("max_depth", &[1.0, 3.0])
+        .param("iterations", &[1.0, 2.0, 30.0]);
+    let candidates = search.candidates();
+    assert_eq!(candidates.len(), 6);
+    assert_eq!(candidates[0].to_string(), "max_depth=1, iterations=1");
+    assert_eq!(candidates[5].get("max_depth"), Some(3.0));
+    assert_eq!(candidates[5].get("iterations"), Some(30.0));
+    assert_eq!(candidates[5].get("nu"), None);
+
+    let report = search.run(train, &zero_one_loss);
+    assert_eq!(report.trials.len(), 6);
+    assert!(report.trials.windows(2).all(|w| w[0].mean <= w[1].mean));
+    report.trials.iter()
+        .for_each(|trial| assert_eq!(trial.folds.len(), 3));
+
+    // A single stump is worse than the boosted trees.
+    let best = report.best();
+    assert_ne!(best.params.to_string(), "max_depth=1, iterations=1");
+    let worst = report.trials.last().unwrap();
+    assert!(best.mean < worst.mean);
+
+    let table = report.to_string();
+    assert_eq!(table.lines().count(), 7);
+    assert!(table.contains("max_depth=3, iterations=30"));
+}
+
+
+#[test]
+fn random_search_draws_from_the_distributions() {
+    let sample = diagonal_sample(200, 0.0, 1234);
+    let search = RandomSearch::new(&sample)
+        .n_folds(4)
+        .n_trials(8)
+        .seed(777)
+        .choice("max_depth", &[1.0, 2.0])
+        .uniform("iterations", 1.0, 10.0)
+        .log_uniform("tolerance", 1e-3, 1e-1);
+    let candidates = search.candidates();
+    assert_eq!(candidates.len(), 8);
+    assert_eq!(candidates, search.candidates());
+    for params in candidates.iter() {
+        assert!([1.0, 2.0].contains(&params["max_depth"]));
+        assert!((1.0..=10.0).contains(&params["iterations"]));
+        assert!((1e-3..=1e-1).contains(&params["tolerance"]));
+    }
+
+    let report = search.run(train, &zero_one_loss);
+    assert_eq!(report.trials.len(), 8);
+    assert!(report.trials.iter().all(|trial| trial.folds.len() == 4));
+    assert!(report.best().mean <= report.trials[7].mean);
+}
+
+
+#[test]
+#[should_panic]
+fn unknown_parameters_panic() {
+    let sample = diagonal_sample(10, 0.0, 1234);
+    let params = GridSearch::new(&sample)
+        .param("max_depth", &[1.0])
+        .candidates();
+    let _ = params[0]["nu"];
+}
@@ -0,0 +1,113 @@
+use miniboosts::prelude::*;
+use rand::prelude::*;
+
+mod common;
+use common::{diagonal_sample, training_error, uniform};
+
+
+#[test]
+fn sampler_follows_the_weights() {
+    let weights = [4.0, 0.0, 1.0, 3.0, 0.0, 2.0];
+    let total = weights.iter().sum::<f64>();
+    let sampler = WeightedSampler::new(&weights);
+
+    let mut rng = StdRng::seed_from_u64(1234);
+    let n_draws = 100_000;
+    let mut counts = vec![0usize; weights.len()];
+    for _ in 0..n_draws {
+        counts[sampler.sample(&mut rng)] += 1;
+    }
+    for (w, n) in weights.iter().zip(counts) {
+        let frequency = n as f64 / n_draws as f64;
+        assert!((frequency - w / total).abs() < 0.01, "{frequency}");
+        if *w == 0.0 { assert_eq!(n, 0); }
+    }
+}
+
+
+#[test]
+fn bootstrap_is_a_distribution_over_the_draws() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+    let sampler = WeightedSampler::new(&dist);
+
+    let mut rng = StdRng::seed_from_u64(1234);
+    let subsample = sampler.bootstrap(50, &mut rng);
+    assert_eq!(subsample.len(), dist.len());
+
+    let total = subsample.iter().sum::<f64>();
+    assert!((total - 1.0).abs() < 1e-9, "{total}");
+    let n_drawn = subsample.iter().filter(|&&d| d > 0.0).count();
+    assert!(0 < n_drawn && n_drawn <= 50);
+    for d in subsample {
+        let draws = d * 50.0;
+        assert!((draws - draws.round()).abs() < 1e-9);
+    }
+}
+
+
+#[test]
+fn decision_tree_trains_on_a_subsample() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let dist = uniform(&sample);
+
+    let build = |seed: u64| {
+        DecisionTreeBuilder::new(&sample)
+            .max_depth(3)
+            .train_on_subsample(100)
+            .seed(seed)
+            .build()
+    };
+    let tree = build(1);
+    assert!(
+        tree.info()
+            .unwrap()
+            .iter()
+            .any(|(key, val)| key.starts_with("Rows per tree") && val == "100")
+    );
+
+    let f = tree.produce(&sample, &dist);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.2, "training error: {error}");
+
+    // The subsamples are reproducible from the seed.
+    let g = build(1).produce(&sample, &dist);
+    assert_eq!(f.predict_all(&sample), g.predict_all(&sample));
+
+    let mut booster = AdaBoost::init(&sample)
+        .force_quit_at(30);
+    let f = booster.run(&tree);
+    let error = training_error(&f, &sample);
+    assert!(error < 0.1, "training error: {error}");
+}
+
+
+#[test]
+fn regression_tree_trains_on_a_subsample() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let target = sample.target();
+    let tree = RegressionTreeBuilder::new(&sample)
+        .max_depth(3)
+        .loss(GBMLoss::L2)
+        .train_on_subsample(100)
+        .build();
+
+    let mut booster = GBM::init_with_loss(&sample, GBMLoss::L2)
+        .max_iter(50);
+    let f = booster.run(&tree);
+
+    let scores = f.predict_all(&sample);
+    let zeros = vec![0f64; scores.len()];
+    let loss = GBMLoss::L2.eval(&scores[..], target);
+    let initial = GBMLoss::L2.eval(&zeros[..], target);
+    assert!(loss < 0.5 * initial, "loss: {loss}, initial: {initial}");
+}
+
+
+#[test]
+#[should_panic]
+fn empty_subsample_panics() {
+    let sample = diagonal_sample(300, 0.0, 1234);
+    let _ = DecisionTreeBuilder::new(&sample)
+        .train_on_subsample(0);
+}
@@ -0,0 +1,116 @@
+use miniboosts::prelude::*;
+use polars::prelude::*;
+
+use std::path::PathBuf;
+
+
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("miniboosts_{}_{name}", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+
+#[test]
+fn csv_weight_and_group_columns() {
+    let path = temp_file(
+        "groups.csv",
+        "x,weight,query,class\n\
+        0.1,1.0,7,1\n\
+        0.2,2.0,7,-1\n\
+        0.3,0.5,3,1\n\
+        0.4,1.5,3,-1\n",
+    );
+    let sample = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .weight_feature("weight")
+        .group_feature("query")
+        .read()
+        .unwrap();
+    let plain = SampleReader::new()
+        .file(&path)
+        .has_header(true)
+        .target_feature("class")
+        .read()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sample.shape(), (4, 1));
+    assert_eq!(sample.features()[0].name(), "x");
+    assert_eq!(sample.target(), [1.0, -1.0, 1.0, -1.0]);
+    assert_eq!(sample.weights(), Some(&[1.0, 2.0, 0.5, 1.5][..]));
+    assert_eq!(sample.groups(), Some(&[7, 7, 3, 3][..]));
+
+    assert_eq!(plain.shape(), (4, 3));
+    assert!(plain.weights().is_none());
+    assert!(plain.groups().is_none());
+}
+
+
+#[test]
+fn svmlight_query_ids_are_groups() {
+    let path = temp_file(
+        "groups.svmlight",
+        "1 qid:1 1:0.5 2:1.0\n\
+        -1 qid:1 1:0.25\n\
+        1 qid:2 2:0.75\n",
+    );
+    let sample = SampleReader::new()
+        .file(&path)
+        .target_feature("class")
+        .read()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sample.shape(), (3, 2));
+    assert_eq!(sample.groups(), Some(&[1, 1, 2][..]));
+    assert_eq!(sample.at(0).0, [0.5, 1.0]);
+}
+
+
+fn sample_with_groups(groups: Vec<u32>) -> Sample {
+    let n_sample = groups.len();
+    let x = (0..n_sample).map(|i| i as f64).collect::<Vec<_>>();
+    let target = (0..n_sample)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect::<Vec<f64>>();
+    let data = df!("x" => x).unwrap();
+    Sample::from_dataframe(data, Series::new("class", target))
+        .unwrap()
+        .with_groups(groups)
+}
+
+
+#[test]
+fn group_ids_are_concatenated() {
+    let mut sample = sample_with_groups(vec![0, 0, 1]);
+    let other = sample_with_groups(vec![2, 2]);
+    sample.extend_from(&other).unwrap();
+    assert_eq!(sample.groups(), Some(&[0, 0, 1, 2, 2][..]));
+
+    let plain = Sample::from_dataframe(
+        df!("x" => [0.0]).unwrap(),
+        Series::new("class", [1.0]),
+    ).unwrap();
+    assert!(sample.extend_from(&plain).is_err());
+    assert_eq!(sample.shape(), (5, 1));
+
+    let rows = sample.push_rows(
+        df!("x" => [1.0]).unwrap(),
+        Series::new("class", [1.0]),
+    );
+    assert!(rows.is_err());
+}
+
+
+#[test]
+#[should_panic]
+fn negative_group_ids_panic() {
+    let data = df!("x" => [0.1, 0.2], "q" => [1.0, -1.0]).unwrap();
+    let _ = Sample::from_dataframe(data, Series::new("class", [1.0, -1.0]))
+        .unwrap()
+        .set_groups("q");
+}